@@ -0,0 +1,3 @@
+-- RPC URLs embedding literal secrets are stored envelope-encrypted,
+-- which makes them longer than the 500 characters accepted through the API
+ALTER TABLE networks ALTER COLUMN rpc_url TYPE TEXT;
@@ -0,0 +1,9 @@
+-- Block explorer metadata used to build canonical explorer links
+ALTER TABLE networks ADD COLUMN explorer_kind VARCHAR(20) NOT NULL DEFAULT 'other';
+ALTER TABLE networks ADD COLUMN explorer_api_url VARCHAR(500);
+ALTER TABLE networks ADD COLUMN explorer_standard VARCHAR(20) NOT NULL DEFAULT 'EIP3091';
+
+ALTER TABLE networks ADD CONSTRAINT chk_explorer_kind
+    CHECK (explorer_kind IN ('etherscan', 'blockscout', 'other'));
+ALTER TABLE networks ADD CONSTRAINT chk_explorer_standard
+    CHECK (explorer_standard IN ('EIP3091', 'none'));
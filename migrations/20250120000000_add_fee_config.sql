@@ -0,0 +1,24 @@
+-- Per-network fee configuration for the transaction sender
+-- Fee amounts are stored in wei
+ALTER TABLE networks ADD COLUMN fee_model VARCHAR(10) NOT NULL DEFAULT 'legacy';
+ALTER TABLE networks ADD COLUMN max_fee_per_gas_floor NUMERIC(30,0);
+ALTER TABLE networks ADD COLUMN max_fee_per_gas_cap NUMERIC(30,0);
+ALTER TABLE networks ADD COLUMN max_priority_fee_per_gas_floor NUMERIC(30,0);
+ALTER TABLE networks ADD COLUMN max_priority_fee_per_gas_cap NUMERIC(30,0);
+ALTER TABLE networks ADD COLUMN base_fee_multiplier DECIMAL(10,4);
+ALTER TABLE networks ADD COLUMN gas_limit_cap BIGINT;
+
+ALTER TABLE networks ADD CONSTRAINT chk_fee_model
+    CHECK (fee_model IN ('legacy', 'eip1559'));
+ALTER TABLE networks ADD CONSTRAINT chk_max_fee_per_gas_bounds
+    CHECK (max_fee_per_gas_floor IS NULL OR max_fee_per_gas_cap IS NULL
+           OR max_fee_per_gas_floor <= max_fee_per_gas_cap);
+ALTER TABLE networks ADD CONSTRAINT chk_max_priority_fee_per_gas_bounds
+    CHECK (max_priority_fee_per_gas_floor IS NULL OR max_priority_fee_per_gas_cap IS NULL
+           OR max_priority_fee_per_gas_floor <= max_priority_fee_per_gas_cap);
+ALTER TABLE networks ADD CONSTRAINT chk_eip1559_settings
+    CHECK (fee_model = 'eip1559'
+           OR (max_priority_fee_per_gas_floor IS NULL AND max_priority_fee_per_gas_cap IS NULL
+               AND base_fee_multiplier IS NULL));
+ALTER TABLE networks ADD CONSTRAINT chk_gas_limit_cap
+    CHECK (gas_limit_cap IS NULL OR gas_limit_cap >= 21000);
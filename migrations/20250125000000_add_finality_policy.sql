@@ -0,0 +1,13 @@
+-- Per-network finality and confirmation policy used by indexers
+ALTER TABLE networks ADD COLUMN average_block_time_ms INTEGER NOT NULL DEFAULT 12000;
+ALTER TABLE networks ADD COLUMN required_confirmations INTEGER NOT NULL DEFAULT 12;
+ALTER TABLE networks ADD COLUMN supports_safe_tag BOOLEAN NOT NULL DEFAULT false;
+ALTER TABLE networks ADD COLUMN supports_finalized_tag BOOLEAN NOT NULL DEFAULT false;
+ALTER TABLE networks ADD COLUMN max_reorg_depth INTEGER;
+
+ALTER TABLE networks ADD CONSTRAINT chk_average_block_time_ms
+    CHECK (average_block_time_ms BETWEEN 1 AND 3600000);
+ALTER TABLE networks ADD CONSTRAINT chk_required_confirmations
+    CHECK (required_confirmations BETWEEN 0 AND 10000);
+ALTER TABLE networks ADD CONSTRAINT chk_max_reorg_depth
+    CHECK (max_reorg_depth IS NULL OR max_reorg_depth BETWEEN 0 AND 10000);
@@ -0,0 +1,17 @@
+-- Replace the active flag with an explicit lifecycle state
+ALTER TABLE networks ADD COLUMN status VARCHAR(20) NOT NULL DEFAULT 'active';
+ALTER TABLE networks ADD COLUMN deprecated_at TIMESTAMPTZ;
+ALTER TABLE networks ADD COLUMN sunset_at TIMESTAMPTZ;
+
+-- Existing inactive networks were soft deleted, which is now retirement
+UPDATE networks
+SET status = CASE WHEN active THEN 'active' ELSE 'retired' END,
+    sunset_at = CASE WHEN active THEN NULL ELSE updated_at END;
+
+DROP INDEX IF EXISTS idx_networks_active;
+ALTER TABLE networks DROP COLUMN active;
+
+ALTER TABLE networks ADD CONSTRAINT chk_status
+    CHECK (status IN ('draft', 'active', 'deprecated', 'retired'));
+
+CREATE INDEX IF NOT EXISTS idx_networks_status ON networks(status);
@@ -0,0 +1,23 @@
+-- Scheduled lifecycle changes, kept after completion as an audit trail
+CREATE TABLE IF NOT EXISTS network_schedules (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    network_id UUID NOT NULL REFERENCES networks(id) ON DELETE CASCADE,
+    action VARCHAR(20) NOT NULL,
+    run_at TIMESTAMPTZ NOT NULL,
+    status VARCHAR(20) NOT NULL DEFAULT 'pending',
+    scheduled_by VARCHAR(255) NOT NULL,
+    cancelled_by VARCHAR(255),
+    outcome TEXT,
+    completed_at TIMESTAMPTZ,
+    -- Lease taken by a scheduler instance while applying the change
+    locked_until TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_network_schedules_network_id ON network_schedules(network_id, run_at);
+CREATE INDEX IF NOT EXISTS idx_network_schedules_due ON network_schedules(run_at) WHERE status = 'pending';
+
+ALTER TABLE network_schedules ADD CONSTRAINT chk_schedule_action
+    CHECK (action IN ('activate', 'deactivate'));
+ALTER TABLE network_schedules ADD CONSTRAINT chk_schedule_status
+    CHECK (status IN ('pending', 'applied', 'failed', 'cancelled'));
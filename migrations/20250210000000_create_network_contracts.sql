@@ -0,0 +1,22 @@
+-- Per-network address book of well-known contracts
+CREATE TABLE IF NOT EXISTS network_contracts (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    network_id UUID NOT NULL REFERENCES networks(id) ON DELETE CASCADE,
+    name VARCHAR(100) NOT NULL,
+    address VARCHAR(42) NOT NULL,
+    deployment_block BIGINT,
+    abi_ref VARCHAR(500),
+    tags TEXT[] NOT NULL DEFAULT '{}',
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- Names are unique per network regardless of case
+CREATE UNIQUE INDEX IF NOT EXISTS uq_network_contracts_name ON network_contracts(network_id, LOWER(name));
+-- Cross-network lookup by name
+CREATE INDEX IF NOT EXISTS idx_network_contracts_name ON network_contracts(LOWER(name));
+
+ALTER TABLE network_contracts ADD CONSTRAINT chk_contract_address
+    CHECK (address ~ '^0x[a-fA-F0-9]{40}$');
+ALTER TABLE network_contracts ADD CONSTRAINT chk_contract_deployment_block
+    CHECK (deployment_block IS NULL OR deployment_block >= 0);
@@ -0,0 +1,30 @@
+-- ERC-20 token metadata per network, exportable as a Uniswap token list
+CREATE TABLE IF NOT EXISTS network_tokens (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    network_id UUID NOT NULL REFERENCES networks(id) ON DELETE CASCADE,
+    address VARCHAR(42) NOT NULL,
+    symbol VARCHAR(20) NOT NULL,
+    name VARCHAR(40) NOT NULL,
+    decimals SMALLINT NOT NULL,
+    logo_uri VARCHAR(500),
+    tags TEXT[] NOT NULL DEFAULT '{}',
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- A token is listed once per network regardless of address checksum casing
+CREATE UNIQUE INDEX IF NOT EXISTS uq_network_tokens_address ON network_tokens(network_id, LOWER(address));
+
+ALTER TABLE network_tokens ADD CONSTRAINT chk_token_address
+    CHECK (address ~ '^0x[a-fA-F0-9]{40}$');
+ALTER TABLE network_tokens ADD CONSTRAINT chk_token_decimals
+    CHECK (decimals BETWEEN 0 AND 255);
+
+-- Semantic version of each network token list, bumped on every token change
+CREATE TABLE IF NOT EXISTS network_token_lists (
+    network_id UUID PRIMARY KEY REFERENCES networks(id) ON DELETE CASCADE,
+    major BIGINT NOT NULL,
+    minor BIGINT NOT NULL,
+    patch BIGINT NOT NULL,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
@@ -0,0 +1,26 @@
+-- Signer addresses of a network, labelled with the role they serve.
+-- networks.default_signer_address keeps serving the 'default' role when no signer is registered for it.
+CREATE TABLE IF NOT EXISTS network_signers (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    network_id UUID NOT NULL REFERENCES networks(id) ON DELETE CASCADE,
+    address VARCHAR(42) NOT NULL,
+    role VARCHAR(50) NOT NULL,
+    priority INTEGER NOT NULL DEFAULT 0,
+    enabled BOOLEAN NOT NULL DEFAULT TRUE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- An address holds a role at most once per network, regardless of case
+CREATE UNIQUE INDEX IF NOT EXISTS uq_network_signers_role_address
+    ON network_signers(network_id, role, LOWER(address));
+-- Resolution of the preferred enabled signer of a role
+CREATE INDEX IF NOT EXISTS idx_network_signers_role
+    ON network_signers(network_id, role, priority) WHERE enabled;
+
+ALTER TABLE network_signers ADD CONSTRAINT chk_signer_address
+    CHECK (address ~ '^0x[a-fA-F0-9]{40}$');
+ALTER TABLE network_signers ADD CONSTRAINT chk_signer_role
+    CHECK (role ~ '^[a-z0-9_-]{1,50}$');
+ALTER TABLE network_signers ADD CONSTRAINT chk_signer_priority
+    CHECK (priority BETWEEN 0 AND 1000);
@@ -0,0 +1,14 @@
+-- Parent-chain relationships: rollups, sidechains and testnets point at the network they belong to
+ALTER TABLE networks ADD COLUMN IF NOT EXISTS parent_network_id UUID REFERENCES networks(id);
+ALTER TABLE networks ADD COLUMN IF NOT EXISTS parent_relation VARCHAR(20);
+
+ALTER TABLE networks ADD CONSTRAINT chk_networks_parent_relation
+    CHECK (parent_relation IN ('rollup', 'sidechain', 'testnet-of'));
+ALTER TABLE networks ADD CONSTRAINT chk_networks_parent_pair
+    CHECK ((parent_network_id IS NULL) = (parent_relation IS NULL));
+ALTER TABLE networks ADD CONSTRAINT chk_networks_parent_not_self
+    CHECK (parent_network_id IS NULL OR parent_network_id <> id);
+
+-- Children lookup
+CREATE INDEX IF NOT EXISTS idx_networks_parent_network_id ON networks(parent_network_id)
+    WHERE parent_network_id IS NOT NULL;
@@ -0,0 +1,12 @@
+-- Tags and custom metadata used to group networks by product, tier, team, ...
+ALTER TABLE networks ADD COLUMN IF NOT EXISTS tags JSONB NOT NULL DEFAULT '[]';
+ALTER TABLE networks ADD COLUMN IF NOT EXISTS metadata JSONB NOT NULL DEFAULT '{}';
+
+ALTER TABLE networks ADD CONSTRAINT chk_networks_tags_array
+    CHECK (jsonb_typeof(tags) = 'array');
+ALTER TABLE networks ADD CONSTRAINT chk_networks_metadata_object
+    CHECK (jsonb_typeof(metadata) = 'object');
+
+-- Containment lookups (tags @> '["l2"]', metadata @> '{"team": "payments"}')
+CREATE INDEX IF NOT EXISTS idx_networks_tags ON networks USING GIN (tags jsonb_path_ops);
+CREATE INDEX IF NOT EXISTS idx_networks_metadata ON networks USING GIN (metadata jsonb_path_ops);
@@ -0,0 +1,14 @@
+-- Full-text and fuzzy search over network names and tags
+CREATE EXTENSION IF NOT EXISTS pg_trgm;
+
+-- Lowercased name and tags, matched by trigram similarity
+ALTER TABLE networks ADD COLUMN IF NOT EXISTS search_text TEXT
+    GENERATED ALWAYS AS (LOWER(name || ' ' || tags::text)) STORED;
+-- Words matched by prefix full-text queries ('simple' keeps chain names unstemmed)
+ALTER TABLE networks ADD COLUMN IF NOT EXISTS search_document TSVECTOR
+    GENERATED ALWAYS AS (
+        to_tsvector('simple', name) || jsonb_to_tsvector('simple', tags, '["string"]')
+    ) STORED;
+
+CREATE INDEX IF NOT EXISTS idx_networks_search_document ON networks USING GIN (search_document);
+CREATE INDEX IF NOT EXISTS idx_networks_search_text ON networks USING GIN (search_text gin_trgm_ops);
@@ -0,0 +1,86 @@
+-- Unique URL-safe slug and aliases sharing one namespace across networks
+ALTER TABLE networks ADD COLUMN IF NOT EXISTS slug TEXT;
+ALTER TABLE networks ADD COLUMN IF NOT EXISTS aliases JSONB NOT NULL DEFAULT '[]';
+
+-- Derive slugs for existing networks from their names. Duplicates are suffixed with
+-- the chain_id, then with a counter should that suffixed slug be taken as well.
+DO $$
+DECLARE
+    existing RECORD;
+    candidate TEXT;
+    attempt INTEGER;
+BEGIN
+    FOR existing IN
+        SELECT id, chain_id,
+               COALESCE(NULLIF(TRIM(BOTH '-' FROM LEFT(REGEXP_REPLACE(LOWER(name), '[^a-z0-9]+', '-', 'g'), 50)), ''),
+                        'network') AS base
+        FROM networks WHERE slug IS NULL ORDER BY chain_id
+    LOOP
+        candidate := existing.base;
+        attempt := 1;
+        WHILE EXISTS (SELECT 1 FROM networks WHERE slug = candidate) LOOP
+            candidate := existing.base || '-' || existing.chain_id
+                || CASE WHEN attempt > 1 THEN '-' || attempt ELSE '' END;
+            attempt := attempt + 1;
+        END LOOP;
+        UPDATE networks SET slug = candidate WHERE id = existing.id;
+    END LOOP;
+END;
+$$;
+
+ALTER TABLE networks ALTER COLUMN slug SET NOT NULL;
+ALTER TABLE networks ADD CONSTRAINT uq_networks_slug UNIQUE (slug);
+ALTER TABLE networks ADD CONSTRAINT chk_networks_aliases_array
+    CHECK (jsonb_typeof(aliases) = 'array');
+
+-- Slugs and aliases share one namespace; the primary key enforces it across networks
+CREATE TABLE IF NOT EXISTS network_identifiers (
+    identifier TEXT PRIMARY KEY,
+    network_id UUID NOT NULL REFERENCES networks (id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_network_identifiers_network_id ON network_identifiers (network_id);
+
+INSERT INTO network_identifiers (identifier, network_id)
+SELECT slug, id FROM networks;
+
+-- Keep the identifiers of a network in step with its slug and aliases
+CREATE OR REPLACE FUNCTION sync_network_identifiers() RETURNS TRIGGER AS $$
+BEGIN
+    DELETE FROM network_identifiers WHERE network_id = NEW.id;
+
+    INSERT INTO network_identifiers (identifier, network_id)
+    SELECT identifier, NEW.id
+    FROM (
+        SELECT NEW.slug AS identifier
+        UNION
+        SELECT jsonb_array_elements_text(NEW.aliases)
+    ) AS identifiers;
+
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER trg_networks_sync_identifiers
+    AFTER INSERT OR UPDATE OF slug, aliases ON networks
+    FOR EACH ROW EXECUTE FUNCTION sync_network_identifiers();
+
+-- Alias lookups (aliases ? 'matic')
+CREATE INDEX IF NOT EXISTS idx_networks_aliases ON networks USING GIN (aliases);
+
+-- Make slugs and aliases searchable alongside names and tags
+ALTER TABLE networks DROP COLUMN IF EXISTS search_text;
+ALTER TABLE networks DROP COLUMN IF EXISTS search_document;
+
+ALTER TABLE networks ADD COLUMN search_text TEXT
+    GENERATED ALWAYS AS (LOWER(name || ' ' || slug || ' ' || aliases::text || ' ' || tags::text)) STORED;
+ALTER TABLE networks ADD COLUMN search_document TSVECTOR
+    GENERATED ALWAYS AS (
+        to_tsvector('simple', name)
+            || to_tsvector('simple', REPLACE(slug, '-', ' '))
+            || jsonb_to_tsvector('simple', aliases, '["string"]')
+            || jsonb_to_tsvector('simple', tags, '["string"]')
+    ) STORED;
+
+CREATE INDEX IF NOT EXISTS idx_networks_search_document ON networks USING GIN (search_document);
+CREATE INDEX IF NOT EXISTS idx_networks_search_text ON networks USING GIN (search_text gin_trgm_ops);
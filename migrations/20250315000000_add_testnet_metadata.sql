@@ -0,0 +1,15 @@
+-- Testnet metadata: faucets, the mainnet a testnet mirrors and its expected deprecation
+ALTER TABLE networks ADD COLUMN IF NOT EXISTS faucet_urls JSONB NOT NULL DEFAULT '[]';
+ALTER TABLE networks ADD COLUMN IF NOT EXISTS mainnet_network_id UUID REFERENCES networks(id);
+ALTER TABLE networks ADD COLUMN IF NOT EXISTS expected_deprecation_at TIMESTAMPTZ;
+
+ALTER TABLE networks ADD CONSTRAINT chk_networks_faucet_urls_array
+    CHECK (jsonb_typeof(faucet_urls) = 'array');
+ALTER TABLE networks ADD CONSTRAINT chk_networks_testnet_metadata
+    CHECK (test_net OR (faucet_urls = '[]' AND mainnet_network_id IS NULL AND expected_deprecation_at IS NULL));
+ALTER TABLE networks ADD CONSTRAINT chk_networks_mainnet_not_self
+    CHECK (mainnet_network_id IS NULL OR mainnet_network_id <> id);
+
+-- Testnets lookup
+CREATE INDEX IF NOT EXISTS idx_networks_mainnet_network_id ON networks(mainnet_network_id)
+    WHERE mainnet_network_id IS NOT NULL;
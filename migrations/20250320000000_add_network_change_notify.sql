@@ -0,0 +1,33 @@
+-- Announce network changes to every registry replica. NOTIFY is only
+-- delivered on commit, so listeners never see rolled back changes.
+CREATE OR REPLACE FUNCTION notify_network_change() RETURNS TRIGGER AS $$
+DECLARE
+    was_usable BOOLEAN;
+    is_usable BOOLEAN := NEW.status IN ('active', 'deprecated');
+    kind TEXT;
+BEGIN
+    IF TG_OP = 'INSERT' THEN
+        kind := 'created';
+    ELSE
+        was_usable := OLD.status IN ('active', 'deprecated');
+        kind := CASE
+            WHEN was_usable AND NOT is_usable THEN 'deactivated'
+            WHEN is_usable AND NOT was_usable THEN 'restored'
+            ELSE 'updated'
+        END;
+    END IF;
+
+    -- The origin lets the replica that made the change skip its own notification
+    PERFORM pg_notify('network_changes', json_build_object(
+        'id', NEW.id,
+        'kind', kind,
+        'origin', current_setting('application_name', true)
+    )::text);
+
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER trg_networks_notify_change
+    AFTER INSERT OR UPDATE ON networks
+    FOR EACH ROW EXECUTE FUNCTION notify_network_change();
@@ -0,0 +1,59 @@
+-- Webhook subscriptions to network changes
+CREATE TABLE IF NOT EXISTS webhooks (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    url VARCHAR(500) NOT NULL,
+    -- Event kinds and networks delivered; empty means all
+    events JSONB NOT NULL DEFAULT '[]',
+    network_ids JSONB NOT NULL DEFAULT '[]',
+    -- HMAC-SHA256 signing secret, sealed when secret encryption is configured
+    secret TEXT NOT NULL,
+    enabled BOOLEAN NOT NULL DEFAULT TRUE,
+    created_by VARCHAR(255) NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+ALTER TABLE webhooks ADD CONSTRAINT chk_webhooks_events_array
+    CHECK (jsonb_typeof(events) = 'array');
+ALTER TABLE webhooks ADD CONSTRAINT chk_webhooks_network_ids_array
+    CHECK (jsonb_typeof(network_ids) = 'array');
+
+-- Network changes queued for delivery to a webhook, kept as a delivery log
+CREATE TABLE IF NOT EXISTS webhook_deliveries (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    webhook_id UUID NOT NULL REFERENCES webhooks(id) ON DELETE CASCADE,
+    event VARCHAR(20) NOT NULL,
+    network_id UUID NOT NULL REFERENCES networks(id) ON DELETE CASCADE,
+    -- JSON body, identical for every attempt
+    payload TEXT NOT NULL,
+    status VARCHAR(20) NOT NULL DEFAULT 'pending',
+    attempts INTEGER NOT NULL DEFAULT 0,
+    next_attempt_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    last_attempt_at TIMESTAMPTZ,
+    delivered_at TIMESTAMPTZ,
+    -- Lease taken by a dispatcher instance while sending the delivery
+    locked_until TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_webhook_deliveries_webhook_id ON webhook_deliveries(webhook_id, created_at DESC);
+CREATE INDEX IF NOT EXISTS idx_webhook_deliveries_due ON webhook_deliveries(next_attempt_at) WHERE status = 'pending';
+
+ALTER TABLE webhook_deliveries ADD CONSTRAINT chk_webhook_delivery_event
+    CHECK (event IN ('created', 'updated', 'deactivated', 'restored'));
+ALTER TABLE webhook_deliveries ADD CONSTRAINT chk_webhook_delivery_status
+    CHECK (status IN ('pending', 'succeeded', 'failed'));
+
+-- Every attempt at sending a delivery
+CREATE TABLE IF NOT EXISTS webhook_delivery_attempts (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    delivery_id UUID NOT NULL REFERENCES webhook_deliveries(id) ON DELETE CASCADE,
+    attempted_at TIMESTAMPTZ NOT NULL,
+    status_code INTEGER,
+    response_body TEXT,
+    error TEXT,
+    duration_ms BIGINT NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_webhook_delivery_attempts_delivery_id
+    ON webhook_delivery_attempts(delivery_id, attempted_at);
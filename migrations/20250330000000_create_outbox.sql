@@ -0,0 +1,23 @@
+-- Network changes recorded in the same transaction as the change, relayed
+-- to the configured sinks by the outbox relay
+CREATE TABLE IF NOT EXISTS outbox (
+    -- Stable across relay attempts, for sinks to deduplicate on
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    network_id UUID NOT NULL REFERENCES networks(id) ON DELETE CASCADE,
+    event VARCHAR(20) NOT NULL,
+    -- The networks row right after the change, sealed secrets included
+    payload JSONB NOT NULL,
+    occurred_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    attempts INTEGER NOT NULL DEFAULT 0,
+    next_attempt_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    last_error TEXT,
+    published_at TIMESTAMPTZ,
+    -- Lease taken by a relay instance while relaying the message
+    locked_until TIMESTAMPTZ
+);
+
+CREATE INDEX IF NOT EXISTS idx_outbox_due ON outbox(next_attempt_at, occurred_at) WHERE published_at IS NULL;
+CREATE INDEX IF NOT EXISTS idx_outbox_published ON outbox(published_at) WHERE published_at IS NOT NULL;
+
+ALTER TABLE outbox ADD CONSTRAINT chk_outbox_event
+    CHECK (event IN ('created', 'updated', 'deactivated', 'restored'));
@@ -0,0 +1,22 @@
+-- API keys authenticating service-to-service clients
+CREATE TABLE IF NOT EXISTS api_keys (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    name VARCHAR(100) NOT NULL,
+    owner VARCHAR(255) NOT NULL,
+    -- First characters of the key, kept in clear to tell keys apart
+    prefix VARCHAR(20) NOT NULL,
+    -- Hex SHA-256 of the key, which is never stored
+    key_hash CHAR(64) NOT NULL,
+    -- Permissions granted to the key
+    scopes JSONB NOT NULL DEFAULT '[]',
+    expires_at TIMESTAMPTZ,
+    last_used_at TIMESTAMPTZ,
+    revoked_at TIMESTAMPTZ,
+    created_by VARCHAR(255) NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+ALTER TABLE api_keys ADD CONSTRAINT chk_api_keys_scopes_array
+    CHECK (jsonb_typeof(scopes) = 'array');
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_api_keys_key_hash ON api_keys(key_hash);
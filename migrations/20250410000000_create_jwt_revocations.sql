@@ -0,0 +1,21 @@
+-- JWTs revoked before they expire, by token ID (jti claim)
+CREATE TABLE IF NOT EXISTS revoked_jwts (
+    jti VARCHAR(128) PRIMARY KEY,
+    subject VARCHAR(255),
+    reason VARCHAR(500),
+    -- Expiry of the token, after which the row can be purged
+    expires_at TIMESTAMPTZ NOT NULL,
+    revoked_by VARCHAR(255) NOT NULL,
+    revoked_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_revoked_jwts_expires_at ON revoked_jwts(expires_at);
+
+-- JWTs of a subject (sub claim) issued at or before revoked_before
+CREATE TABLE IF NOT EXISTS revoked_subjects (
+    subject VARCHAR(255) PRIMARY KEY,
+    revoked_before TIMESTAMPTZ NOT NULL,
+    reason VARCHAR(500),
+    revoked_by VARCHAR(255) NOT NULL,
+    revoked_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
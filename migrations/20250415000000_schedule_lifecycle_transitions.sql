@@ -0,0 +1,8 @@
+-- Scheduled changes are lifecycle transitions: deactivations retire the network,
+-- and deprecations can be scheduled as well
+ALTER TABLE network_schedules DROP CONSTRAINT chk_schedule_action;
+
+UPDATE network_schedules SET action = 'retire' WHERE action = 'deactivate';
+
+ALTER TABLE network_schedules ADD CONSTRAINT chk_schedule_action
+    CHECK (action IN ('activate', 'deprecate', 'retire'));
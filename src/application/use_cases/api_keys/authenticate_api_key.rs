@@ -0,0 +1,162 @@
+//! Authenticate API Key Use Case
+//!
+//! Resolves the API key presented by a client, recording when it was last
+//! used.
+
+use std::sync::Arc;
+
+use chrono::Utc;
+
+use crate::domain::gateways::ApiKeyRepository;
+use crate::domain::models::api_key::{hash_key, ApiKey};
+use crate::shared::errors::UseCaseError;
+
+/// Use case for authenticating a request with an API key
+pub struct AuthenticateApiKeyUseCase {
+    api_key_repository: Arc<dyn ApiKeyRepository>,
+}
+
+impl AuthenticateApiKeyUseCase {
+    /// Create a new AuthenticateApiKeyUseCase
+    #[must_use]
+    pub fn new(api_key_repository: Arc<dyn ApiKeyRepository>) -> Self {
+        Self { api_key_repository }
+    }
+
+    /// Execute the use case
+    ///
+    /// Uses are recorded at most once a minute per key; failing to record
+    /// one does not fail the authentication.
+    ///
+    /// # Errors
+    ///
+    /// Returns `UseCaseError::Unauthorized` if the key is unknown, expired or revoked.
+    /// Returns `UseCaseError::Repository` if there's a database error.
+    pub async fn execute(&self, key: &str) -> Result<ApiKey, UseCaseError> {
+        let now = Utc::now();
+
+        let api_key = self
+            .api_key_repository
+            .find_by_hash(&hash_key(key))
+            .await?
+            .filter(|api_key| api_key.is_valid_at(now))
+            .ok_or_else(|| UseCaseError::Unauthorized("Invalid, expired or revoked API key".to_string()))?;
+
+        if api_key.should_record_use(now) {
+            if let Err(e) = self.api_key_repository.record_use(api_key.id(), now).await {
+                tracing::warn!(error = %e, api_key_id = %api_key.id(), "Failed to record API key use");
+            }
+        }
+
+        tracing::debug!(api_key_id = %api_key.id(), owner = api_key.owner(), "Authenticated API key");
+        Ok(api_key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::models::api_key::{ApiKeyData, ApiKeyId};
+    use crate::shared::errors::RepositoryError;
+    use async_trait::async_trait;
+    use chrono::{DateTime, Duration};
+    use std::sync::Mutex;
+
+    #[derive(Default)]
+    struct MockApiKeyRepository {
+        api_keys: Mutex<Vec<ApiKey>>,
+        uses: Mutex<Vec<ApiKeyId>>,
+    }
+
+    #[async_trait]
+    impl ApiKeyRepository for MockApiKeyRepository {
+        async fn find_by_id(&self, id: &ApiKeyId) -> Result<Option<ApiKey>, RepositoryError> {
+            Ok(self.api_keys.lock().unwrap().iter().find(|k| k.id() == id).cloned())
+        }
+
+        async fn find_by_hash(&self, key_hash: &str) -> Result<Option<ApiKey>, RepositoryError> {
+            Ok(self
+                .api_keys
+                .lock()
+                .unwrap()
+                .iter()
+                .find(|k| k.key_hash() == key_hash)
+                .cloned())
+        }
+
+        async fn find_all(&self) -> Result<Vec<ApiKey>, RepositoryError> {
+            Ok(self.api_keys.lock().unwrap().clone())
+        }
+
+        async fn create(&self, api_key: &ApiKey) -> Result<ApiKey, RepositoryError> {
+            self.api_keys.lock().unwrap().push(api_key.clone());
+            Ok(api_key.clone())
+        }
+
+        async fn revoke(&self, _id: &ApiKeyId, _revoked_at: DateTime<Utc>) -> Result<Option<ApiKey>, RepositoryError> {
+            Ok(None)
+        }
+
+        async fn record_use(&self, id: &ApiKeyId, _used_at: DateTime<Utc>) -> Result<(), RepositoryError> {
+            self.uses.lock().unwrap().push(id.clone());
+            Ok(())
+        }
+    }
+
+    fn stored_key(expires_at: Option<DateTime<Utc>>, revoked_at: Option<DateTime<Utc>>) -> (ApiKey, String) {
+        let (api_key, key) = ApiKey::generate(
+            ApiKeyData {
+                name: "Nightly sync".to_string(),
+                owner: "data-platform".to_string(),
+                scopes: vec![],
+                expires_at: None,
+            },
+            "admin".to_string(),
+        )
+        .unwrap();
+
+        let api_key = ApiKey::restore(
+            api_key.id().clone(),
+            api_key.name().to_string(),
+            api_key.owner().to_string(),
+            api_key.prefix().to_string(),
+            api_key.key_hash().to_string(),
+            api_key.scopes().to_vec(),
+            expires_at,
+            None,
+            revoked_at,
+            api_key.created_by().to_string(),
+            api_key.created_at(),
+        );
+        (api_key, key)
+    }
+
+    #[tokio::test]
+    async fn test_authenticate_valid_key_records_use() {
+        let (api_key, key) = stored_key(Some(Utc::now() + Duration::hours(1)), None);
+        let repo = Arc::new(MockApiKeyRepository::default());
+        repo.create(&api_key).await.unwrap();
+        let use_case = AuthenticateApiKeyUseCase::new(repo.clone());
+
+        let authenticated = use_case.execute(&key).await.unwrap();
+
+        assert_eq!(authenticated.id(), api_key.id());
+        assert_eq!(*repo.uses.lock().unwrap(), vec![api_key.id().clone()]);
+    }
+
+    #[tokio::test]
+    async fn test_authenticate_rejects_unknown_expired_and_revoked_keys() {
+        let (expired, expired_key) = stored_key(Some(Utc::now() - Duration::seconds(1)), None);
+        let (revoked, revoked_key) = stored_key(None, Some(Utc::now()));
+        let repo = Arc::new(MockApiKeyRepository::default());
+        repo.create(&expired).await.unwrap();
+        repo.create(&revoked).await.unwrap();
+        let use_case = AuthenticateApiKeyUseCase::new(repo.clone());
+
+        for key in [expired_key.as_str(), revoked_key.as_str(), "nrk_unknown"] {
+            let result = use_case.execute(key).await;
+            assert!(matches!(result, Err(UseCaseError::Unauthorized(_))), "{key}");
+        }
+        assert!(repo.uses.lock().unwrap().is_empty());
+    }
+}
@@ -0,0 +1,162 @@
+//! Create API Key Use Case
+//!
+//! Issues an API key to a service-to-service client.
+
+use std::sync::Arc;
+
+use crate::domain::gateways::ApiKeyRepository;
+use crate::domain::models::api_key::{ApiKey, ApiKeyData};
+use crate::shared::errors::UseCaseError;
+
+/// Use case for issuing an API key
+pub struct CreateApiKeyUseCase {
+    api_key_repository: Arc<dyn ApiKeyRepository>,
+}
+
+impl CreateApiKeyUseCase {
+    /// Create a new CreateApiKeyUseCase
+    #[must_use]
+    pub fn new(api_key_repository: Arc<dyn ApiKeyRepository>) -> Self {
+        Self { api_key_repository }
+    }
+
+    /// Execute the use case, returning the API key along with the key itself
+    ///
+    /// The key is not stored and cannot be retrieved again. A caller can
+    /// only grant the scopes it holds itself.
+    ///
+    /// # Errors
+    ///
+    /// Returns `UseCaseError::Forbidden` if a scope is not among `granted_by`.
+    /// Returns `UseCaseError::Domain` if the API key data is invalid.
+    /// Returns `UseCaseError::Repository` if there's a database error.
+    pub async fn execute(
+        &self,
+        data: ApiKeyData,
+        created_by: &str,
+        granted_by: &[String],
+    ) -> Result<(ApiKey, String), UseCaseError> {
+        tracing::info!(name = %data.name, owner = %data.owner, created_by, "Creating API key");
+
+        if let Some(scope) = data.scopes.iter().find(|scope| !granted_by.contains(scope)) {
+            tracing::warn!(scope = %scope, created_by, "Refusing to grant a scope the caller does not hold");
+            return Err(UseCaseError::Forbidden(format!("cannot grant scope '{scope}'")));
+        }
+
+        let (api_key, key) = ApiKey::generate(data, created_by.to_string())?;
+        let created = self.api_key_repository.create(&api_key).await?;
+
+        tracing::info!(api_key_id = %created.id(), prefix = created.prefix(), "API key created successfully");
+        Ok((created, key))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::models::api_key::{hash_key, ApiKeyId};
+    use crate::shared::errors::{DomainError, RepositoryError};
+    use async_trait::async_trait;
+    use chrono::{DateTime, Utc};
+    use std::sync::Mutex;
+
+    #[derive(Default)]
+    struct MockApiKeyRepository {
+        api_keys: Mutex<Vec<ApiKey>>,
+    }
+
+    #[async_trait]
+    impl ApiKeyRepository for MockApiKeyRepository {
+        async fn find_by_id(&self, id: &ApiKeyId) -> Result<Option<ApiKey>, RepositoryError> {
+            Ok(self.api_keys.lock().unwrap().iter().find(|k| k.id() == id).cloned())
+        }
+
+        async fn find_by_hash(&self, key_hash: &str) -> Result<Option<ApiKey>, RepositoryError> {
+            Ok(self
+                .api_keys
+                .lock()
+                .unwrap()
+                .iter()
+                .find(|k| k.key_hash() == key_hash)
+                .cloned())
+        }
+
+        async fn find_all(&self) -> Result<Vec<ApiKey>, RepositoryError> {
+            Ok(self.api_keys.lock().unwrap().clone())
+        }
+
+        async fn create(&self, api_key: &ApiKey) -> Result<ApiKey, RepositoryError> {
+            self.api_keys.lock().unwrap().push(api_key.clone());
+            Ok(api_key.clone())
+        }
+
+        async fn revoke(&self, _id: &ApiKeyId, _revoked_at: DateTime<Utc>) -> Result<Option<ApiKey>, RepositoryError> {
+            Ok(None)
+        }
+
+        async fn record_use(&self, _id: &ApiKeyId, _used_at: DateTime<Utc>) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+    }
+
+    fn api_key_data(scopes: &[&str]) -> ApiKeyData {
+        ApiKeyData {
+            name: "Nightly sync".to_string(),
+            owner: "data-platform".to_string(),
+            scopes: scopes.iter().map(ToString::to_string).collect(),
+            expires_at: None,
+        }
+    }
+
+    fn admin_permissions() -> Vec<String> {
+        vec!["api_keys:manage".to_string(), "secrets:read".to_string()]
+    }
+
+    #[tokio::test]
+    async fn test_create_api_key_stores_only_the_hash() {
+        let repo = Arc::new(MockApiKeyRepository::default());
+        let use_case = CreateApiKeyUseCase::new(repo.clone());
+
+        let (api_key, key) = use_case
+            .execute(api_key_data(&["secrets:read"]), "admin-1", &admin_permissions())
+            .await
+            .unwrap();
+
+        assert_eq!(api_key.created_by(), "admin-1");
+        assert_eq!(api_key.scopes(), ["secrets:read"]);
+        let stored = repo.find_by_hash(&hash_key(&key)).await.unwrap().unwrap();
+        assert_eq!(stored.id(), api_key.id());
+        assert_ne!(stored.key_hash(), key);
+    }
+
+    #[tokio::test]
+    async fn test_create_api_key_cannot_grant_scopes_the_caller_lacks() {
+        let repo = Arc::new(MockApiKeyRepository::default());
+        let use_case = CreateApiKeyUseCase::new(repo.clone());
+
+        let result = use_case
+            .execute(
+                api_key_data(&["secrets:read"]),
+                "operator-1",
+                &["api_keys:manage".to_string()],
+            )
+            .await;
+
+        assert!(matches!(result, Err(UseCaseError::Forbidden(_))));
+        assert!(repo.find_all().await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_create_api_key_validates_data() {
+        let use_case = CreateApiKeyUseCase::new(Arc::new(MockApiKeyRepository::default()));
+
+        let mut data = api_key_data(&[]);
+        data.owner = String::new();
+        let result = use_case.execute(data, "admin-1", &admin_permissions()).await;
+
+        assert!(matches!(
+            result,
+            Err(UseCaseError::Domain(DomainError::ValidationError(_)))
+        ));
+    }
+}
@@ -0,0 +1,36 @@
+//! List API Keys Use Case
+//!
+//! Retrieves all API keys, revoked ones included.
+
+use std::sync::Arc;
+
+use crate::domain::gateways::ApiKeyRepository;
+use crate::domain::models::api_key::ApiKey;
+use crate::shared::errors::UseCaseError;
+
+/// Use case for listing API keys
+pub struct ListApiKeysUseCase {
+    api_key_repository: Arc<dyn ApiKeyRepository>,
+}
+
+impl ListApiKeysUseCase {
+    /// Create a new ListApiKeysUseCase
+    #[must_use]
+    pub fn new(api_key_repository: Arc<dyn ApiKeyRepository>) -> Self {
+        Self { api_key_repository }
+    }
+
+    /// Execute the use case
+    ///
+    /// # Errors
+    ///
+    /// Returns `UseCaseError::Repository` if there's a database error.
+    pub async fn execute(&self) -> Result<Vec<ApiKey>, UseCaseError> {
+        tracing::debug!("Listing API keys");
+
+        let api_keys = self.api_key_repository.find_all().await?;
+
+        tracing::debug!(count = api_keys.len(), "Retrieved API keys");
+        Ok(api_keys)
+    }
+}
@@ -0,0 +1,13 @@
+//! API Key Use Cases
+//!
+//! Business logic for issuing, listing, revoking and authenticating API keys.
+
+mod authenticate_api_key;
+mod create_api_key;
+mod list_api_keys;
+mod revoke_api_key;
+
+pub use authenticate_api_key::AuthenticateApiKeyUseCase;
+pub use create_api_key::CreateApiKeyUseCase;
+pub use list_api_keys::ListApiKeysUseCase;
+pub use revoke_api_key::RevokeApiKeyUseCase;
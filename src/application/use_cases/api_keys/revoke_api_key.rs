@@ -0,0 +1,48 @@
+//! Revoke API Key Use Case
+//!
+//! Permanently disables an API key. Revoked keys are kept for auditing.
+
+use std::sync::Arc;
+
+use chrono::Utc;
+
+use crate::domain::gateways::ApiKeyRepository;
+use crate::domain::models::api_key::{ApiKey, ApiKeyId};
+use crate::shared::errors::UseCaseError;
+
+/// Use case for revoking an API key
+pub struct RevokeApiKeyUseCase {
+    api_key_repository: Arc<dyn ApiKeyRepository>,
+}
+
+impl RevokeApiKeyUseCase {
+    /// Create a new RevokeApiKeyUseCase
+    #[must_use]
+    pub fn new(api_key_repository: Arc<dyn ApiKeyRepository>) -> Self {
+        Self { api_key_repository }
+    }
+
+    /// Execute the use case
+    ///
+    /// Revoking a key twice keeps its first revocation time.
+    ///
+    /// # Errors
+    ///
+    /// Returns `UseCaseError::NotFound` if the API key doesn't exist.
+    /// Returns `UseCaseError::Repository` if there's a database error.
+    pub async fn execute(&self, id: &ApiKeyId) -> Result<ApiKey, UseCaseError> {
+        tracing::info!(api_key_id = %id, "Revoking API key");
+
+        let api_key = self
+            .api_key_repository
+            .revoke(id, Utc::now())
+            .await?
+            .ok_or_else(|| UseCaseError::NotFound {
+                resource: "API key".to_string(),
+                id: id.to_string(),
+            })?;
+
+        tracing::info!(api_key_id = %id, "API key revoked successfully");
+        Ok(api_key)
+    }
+}
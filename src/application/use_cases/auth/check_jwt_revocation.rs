@@ -0,0 +1,141 @@
+//! Check JWT Revocation Use Case
+//!
+//! Rejects the JWTs revoked by their ID or by their subject, for every
+//! JWT-authenticated request.
+
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+
+use crate::domain::gateways::JwtRevocationRepository;
+use crate::domain::models::jwt_revocation::is_revoked_before;
+use crate::shared::errors::UseCaseError;
+
+/// Use case for checking whether a JWT has been revoked
+pub struct CheckJwtRevocationUseCase {
+    jwt_revocation_repository: Arc<dyn JwtRevocationRepository>,
+}
+
+impl CheckJwtRevocationUseCase {
+    /// Create a new CheckJwtRevocationUseCase
+    #[must_use]
+    pub fn new(jwt_revocation_repository: Arc<dyn JwtRevocationRepository>) -> Self {
+        Self {
+            jwt_revocation_repository,
+        }
+    }
+
+    /// Execute the use case
+    ///
+    /// JWTs without a `jti` claim can only be revoked through their subject.
+    ///
+    /// # Errors
+    ///
+    /// Returns `UseCaseError::Unauthorized` if the JWT has been revoked.
+    /// Returns `UseCaseError::Repository` if there's a database error.
+    pub async fn execute(
+        &self,
+        jti: Option<&str>,
+        subject: &str,
+        issued_at: DateTime<Utc>,
+    ) -> Result<(), UseCaseError> {
+        let revoked = match jti {
+            Some(jti) => self.jwt_revocation_repository.is_jwt_revoked(jti).await?,
+            None => false,
+        } || self
+            .jwt_revocation_repository
+            .subject_revoked_before(subject)
+            .await?
+            .is_some_and(|revoked_before| is_revoked_before(revoked_before, issued_at));
+
+        if revoked {
+            tracing::debug!(jti, subject, "Rejected revoked JWT");
+            return Err(UseCaseError::Unauthorized("Token has been revoked".to_string()));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::models::jwt_revocation::{JwtRevocation, SubjectRevocation};
+    use crate::shared::errors::RepositoryError;
+    use async_trait::async_trait;
+    use chrono::Duration;
+
+    struct MockJwtRevocationRepository {
+        revoked_jti: &'static str,
+        revoked_subject: &'static str,
+        revoked_before: DateTime<Utc>,
+    }
+
+    #[async_trait]
+    impl JwtRevocationRepository for MockJwtRevocationRepository {
+        async fn is_jwt_revoked(&self, jti: &str) -> Result<bool, RepositoryError> {
+            Ok(jti == self.revoked_jti)
+        }
+
+        async fn subject_revoked_before(&self, subject: &str) -> Result<Option<DateTime<Utc>>, RepositoryError> {
+            Ok((subject == self.revoked_subject).then_some(self.revoked_before))
+        }
+
+        async fn find_jwt_revocations(&self, _now: DateTime<Utc>) -> Result<Vec<JwtRevocation>, RepositoryError> {
+            Ok(vec![])
+        }
+
+        async fn find_subject_revocations(&self) -> Result<Vec<SubjectRevocation>, RepositoryError> {
+            Ok(vec![])
+        }
+
+        async fn revoke_jwt(&self, revocation: &JwtRevocation) -> Result<JwtRevocation, RepositoryError> {
+            Ok(revocation.clone())
+        }
+
+        async fn revoke_subject(&self, revocation: &SubjectRevocation) -> Result<SubjectRevocation, RepositoryError> {
+            Ok(revocation.clone())
+        }
+
+        async fn purge_expired_jwts(&self, _now: DateTime<Utc>) -> Result<u64, RepositoryError> {
+            Ok(0)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_check_jwt_revocation() {
+        let revoked_before = Utc::now();
+        let use_case = CheckJwtRevocationUseCase::new(Arc::new(MockJwtRevocationRepository {
+            revoked_jti: "jti-revoked",
+            revoked_subject: "user-revoked",
+            revoked_before,
+        }));
+        let earlier = revoked_before - Duration::minutes(5);
+        let later = revoked_before + Duration::minutes(5);
+
+        let revoked = [
+            (Some("jti-revoked"), "user-1", later),
+            (Some("jti-1"), "user-revoked", earlier),
+            (None, "user-revoked", revoked_before),
+        ];
+        for (jti, subject, issued_at) in revoked {
+            let result = use_case.execute(jti, subject, issued_at).await;
+            assert!(
+                matches!(result, Err(UseCaseError::Unauthorized(_))),
+                "{jti:?} {subject}"
+            );
+        }
+
+        let valid = [
+            (Some("jti-1"), "user-1", earlier),
+            (None, "user-1", earlier),
+            (Some("jti-1"), "user-revoked", later),
+        ];
+        for (jti, subject, issued_at) in valid {
+            assert!(
+                use_case.execute(jti, subject, issued_at).await.is_ok(),
+                "{jti:?} {subject}"
+            );
+        }
+    }
+}
@@ -0,0 +1,106 @@
+//! Issue Service Token Use Case
+//!
+//! Exchanges the client credentials of a configured service account for a
+//! JWT carrying the account's role (OAuth 2.0 client credentials grant).
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::domain::gateways::TokenIssuer;
+use crate::domain::models::service_account::{hash_secret, IssuedToken, ServiceAccount};
+use crate::shared::errors::UseCaseError;
+
+/// Use case for issuing a JWT to a service account
+pub struct IssueServiceTokenUseCase {
+    service_accounts: HashMap<String, ServiceAccount>,
+    token_issuer: Arc<dyn TokenIssuer>,
+}
+
+impl IssueServiceTokenUseCase {
+    /// Create a new IssueServiceTokenUseCase
+    #[must_use]
+    pub fn new(service_accounts: Vec<ServiceAccount>, token_issuer: Arc<dyn TokenIssuer>) -> Self {
+        let service_accounts = service_accounts
+            .into_iter()
+            .map(|account| (account.client_id().to_string(), account))
+            .collect();
+        Self {
+            service_accounts,
+            token_issuer,
+        }
+    }
+
+    /// Execute the use case
+    ///
+    /// Unknown clients and wrong secrets are reported alike. The secret is
+    /// hashed once whether the client exists or not, so both take as long.
+    ///
+    /// # Errors
+    ///
+    /// Returns `UseCaseError::Unauthorized` if the client credentials are invalid.
+    /// Returns `UseCaseError::Secret` if the token cannot be signed.
+    pub fn execute(&self, client_id: &str, client_secret: &str) -> Result<IssuedToken, UseCaseError> {
+        let secret_hash = hash_secret(client_secret);
+        let Some(account) = self
+            .service_accounts
+            .get(client_id)
+            .filter(|account| account.verify_secret_hash(&secret_hash))
+        else {
+            tracing::warn!(client_id, "Rejected client credentials");
+            return Err(UseCaseError::Unauthorized("Invalid client credentials".to_string()));
+        };
+
+        let issued = self
+            .token_issuer
+            .issue(account.client_id(), account.email(), account.role())?;
+
+        tracing::info!(client_id, role = account.role(), jti = %issued.jti, "Issued service token");
+        Ok(issued)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::errors::SecretError;
+    use chrono::Utc;
+
+    struct MockTokenIssuer;
+
+    impl TokenIssuer for MockTokenIssuer {
+        fn issue(&self, subject: &str, email: &str, role: &str) -> Result<IssuedToken, SecretError> {
+            Ok(IssuedToken {
+                token: format!("{subject}|{email}|{role}"),
+                jti: "jti".to_string(),
+                expires_at: Utc::now(),
+            })
+        }
+    }
+
+    fn use_case() -> IssueServiceTokenUseCase {
+        let account = ServiceAccount::new(
+            "indexer".to_string(),
+            hash_secret("s3cret"),
+            "service".to_string(),
+            Some("indexer@example.com".to_string()),
+        );
+        IssueServiceTokenUseCase::new(vec![account], Arc::new(MockTokenIssuer))
+    }
+
+    #[test]
+    fn test_issue_service_token() {
+        let issued = use_case().execute("indexer", "s3cret").unwrap();
+
+        assert_eq!(issued.token, "indexer|indexer@example.com|service");
+    }
+
+    #[test]
+    fn test_invalid_client_credentials() {
+        let use_case = use_case();
+
+        for (client_id, client_secret) in [("indexer", "wrong"), ("unknown", "s3cret"), ("indexer", "")] {
+            let result = use_case.execute(client_id, client_secret);
+            assert!(matches!(result, Err(UseCaseError::Unauthorized(_))), "{client_id}");
+        }
+    }
+}
@@ -0,0 +1,39 @@
+//! List JWT Revocations Use Case
+//!
+//! Retrieves the revoked JWTs that have not expired yet, and the revoked
+//! subjects.
+
+use std::sync::Arc;
+
+use chrono::Utc;
+
+use crate::domain::gateways::JwtRevocationRepository;
+use crate::domain::models::jwt_revocation::{JwtRevocation, SubjectRevocation};
+use crate::shared::errors::UseCaseError;
+
+/// Use case for listing JWT revocations
+pub struct ListJwtRevocationsUseCase {
+    jwt_revocation_repository: Arc<dyn JwtRevocationRepository>,
+}
+
+impl ListJwtRevocationsUseCase {
+    /// Create a new ListJwtRevocationsUseCase
+    #[must_use]
+    pub fn new(jwt_revocation_repository: Arc<dyn JwtRevocationRepository>) -> Self {
+        Self {
+            jwt_revocation_repository,
+        }
+    }
+
+    /// Execute the use case
+    ///
+    /// # Errors
+    ///
+    /// Returns `UseCaseError::Repository` if there's a database error.
+    pub async fn execute(&self) -> Result<(Vec<JwtRevocation>, Vec<SubjectRevocation>), UseCaseError> {
+        let jwts = self.jwt_revocation_repository.find_jwt_revocations(Utc::now()).await?;
+        let subjects = self.jwt_revocation_repository.find_subject_revocations().await?;
+
+        Ok((jwts, subjects))
+    }
+}
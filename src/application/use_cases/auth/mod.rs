@@ -0,0 +1,16 @@
+//! Auth Use Cases
+//!
+//! Business logic for issuing JWTs to service accounts, revoking JWTs and
+//! checking tokens against the revocations.
+
+mod check_jwt_revocation;
+mod issue_service_token;
+mod list_jwt_revocations;
+mod revoke_jwt;
+mod revoke_subject_jwts;
+
+pub use check_jwt_revocation::CheckJwtRevocationUseCase;
+pub use issue_service_token::IssueServiceTokenUseCase;
+pub use list_jwt_revocations::ListJwtRevocationsUseCase;
+pub use revoke_jwt::RevokeJwtUseCase;
+pub use revoke_subject_jwts::RevokeSubjectJwtsUseCase;
@@ -0,0 +1,155 @@
+//! Revoke JWT Use Case
+//!
+//! Rejects a single JWT, identified by its `jti` claim, before it expires.
+
+use std::sync::Arc;
+
+use chrono::{Duration, Utc};
+
+use crate::domain::gateways::JwtRevocationRepository;
+use crate::domain::models::jwt_revocation::{JwtRevocation, JwtRevocationData};
+use crate::shared::errors::UseCaseError;
+
+/// Use case for revoking a JWT
+pub struct RevokeJwtUseCase {
+    jwt_revocation_repository: Arc<dyn JwtRevocationRepository>,
+    max_token_lifetime: Duration,
+}
+
+impl RevokeJwtUseCase {
+    /// Create a new RevokeJwtUseCase
+    ///
+    /// `max_token_lifetime` bounds how long a JWT with an unknown expiry is kept revoked.
+    #[must_use]
+    pub fn new(jwt_revocation_repository: Arc<dyn JwtRevocationRepository>, max_token_lifetime: Duration) -> Self {
+        Self {
+            jwt_revocation_repository,
+            max_token_lifetime,
+        }
+    }
+
+    /// Execute the use case
+    ///
+    /// Revoking a JWT twice keeps its first revocation. Revocations of JWTs
+    /// that have expired since are purged on the way; failing to purge them
+    /// does not fail the revocation.
+    ///
+    /// # Errors
+    ///
+    /// Returns `UseCaseError::Domain` if validation fails.
+    /// Returns `UseCaseError::Repository` if there's a database error.
+    pub async fn execute(&self, data: JwtRevocationData, revoked_by: &str) -> Result<JwtRevocation, UseCaseError> {
+        let now = Utc::now();
+        let revocation = JwtRevocation::new(data, revoked_by.to_string(), now + self.max_token_lifetime)?;
+
+        tracing::info!(jti = revocation.jti(), revoked_by, "Revoking JWT");
+        let revocation = self.jwt_revocation_repository.revoke_jwt(&revocation).await?;
+
+        match self.jwt_revocation_repository.purge_expired_jwts(now).await {
+            Ok(0) => {}
+            Ok(purged) => tracing::debug!(purged, "Purged expired JWT revocations"),
+            Err(e) => tracing::warn!(error = %e, "Failed to purge expired JWT revocations"),
+        }
+
+        Ok(revocation)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::models::jwt_revocation::SubjectRevocation;
+    use crate::shared::errors::RepositoryError;
+    use async_trait::async_trait;
+    use chrono::DateTime;
+    use std::sync::Mutex;
+
+    #[derive(Default)]
+    struct MockJwtRevocationRepository {
+        jwts: Mutex<Vec<JwtRevocation>>,
+    }
+
+    #[async_trait]
+    impl JwtRevocationRepository for MockJwtRevocationRepository {
+        async fn is_jwt_revoked(&self, jti: &str) -> Result<bool, RepositoryError> {
+            Ok(self.jwts.lock().unwrap().iter().any(|r| r.jti() == jti))
+        }
+
+        async fn subject_revoked_before(&self, _subject: &str) -> Result<Option<DateTime<Utc>>, RepositoryError> {
+            Ok(None)
+        }
+
+        async fn find_jwt_revocations(&self, _now: DateTime<Utc>) -> Result<Vec<JwtRevocation>, RepositoryError> {
+            Ok(self.jwts.lock().unwrap().clone())
+        }
+
+        async fn find_subject_revocations(&self) -> Result<Vec<SubjectRevocation>, RepositoryError> {
+            Ok(vec![])
+        }
+
+        async fn revoke_jwt(&self, revocation: &JwtRevocation) -> Result<JwtRevocation, RepositoryError> {
+            let mut jwts = self.jwts.lock().unwrap();
+            if let Some(existing) = jwts.iter().find(|r| r.jti() == revocation.jti()) {
+                return Ok(existing.clone());
+            }
+            jwts.push(revocation.clone());
+            Ok(revocation.clone())
+        }
+
+        async fn revoke_subject(&self, revocation: &SubjectRevocation) -> Result<SubjectRevocation, RepositoryError> {
+            Ok(revocation.clone())
+        }
+
+        async fn purge_expired_jwts(&self, now: DateTime<Utc>) -> Result<u64, RepositoryError> {
+            let mut jwts = self.jwts.lock().unwrap();
+            let before = jwts.len();
+            jwts.retain(|r| r.expires_at() > now);
+            Ok((before - jwts.len()) as u64)
+        }
+    }
+
+    fn revocation_data(jti: &str) -> JwtRevocationData {
+        JwtRevocationData {
+            jti: jti.to_string(),
+            subject: Some("user-1".to_string()),
+            reason: Some("Leaked in a log".to_string()),
+            expires_at: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_revoke_jwt_keeps_it_for_the_max_lifetime() {
+        let repo = Arc::new(MockJwtRevocationRepository::default());
+        let use_case = RevokeJwtUseCase::new(repo.clone(), Duration::hours(1));
+
+        let revocation = use_case.execute(revocation_data("jti-1"), "admin").await.unwrap();
+
+        assert_eq!(revocation.revoked_by(), "admin");
+        assert!(revocation.expires_at() > Utc::now() + Duration::minutes(59));
+        assert!(repo.is_jwt_revoked("jti-1").await.unwrap());
+
+        // Revoking again keeps the first revocation
+        let again = use_case.execute(revocation_data("jti-1"), "other-admin").await.unwrap();
+        assert_eq!(again.revoked_by(), "admin");
+    }
+
+    #[tokio::test]
+    async fn test_revoke_jwt_purges_expired_revocations() {
+        let repo = Arc::new(MockJwtRevocationRepository::default());
+        let expired = JwtRevocation::restore(
+            "jti-old".to_string(),
+            None,
+            None,
+            Utc::now() - Duration::minutes(1),
+            "admin".to_string(),
+            Utc::now() - Duration::hours(2),
+        );
+        repo.revoke_jwt(&expired).await.unwrap();
+        let use_case = RevokeJwtUseCase::new(repo.clone(), Duration::hours(1));
+
+        use_case.execute(revocation_data("jti-new"), "admin").await.unwrap();
+
+        assert!(!repo.is_jwt_revoked("jti-old").await.unwrap());
+        assert!(repo.is_jwt_revoked("jti-new").await.unwrap());
+    }
+}
@@ -0,0 +1,51 @@
+//! Revoke Subject JWTs Use Case
+//!
+//! Rejects every JWT of a subject issued up to a point in time, such as all
+//! the tokens of a user whose device was lost.
+
+use std::sync::Arc;
+
+use crate::domain::gateways::JwtRevocationRepository;
+use crate::domain::models::jwt_revocation::{SubjectRevocation, SubjectRevocationData};
+use crate::shared::errors::UseCaseError;
+
+/// Use case for revoking the JWTs of a subject
+pub struct RevokeSubjectJwtsUseCase {
+    jwt_revocation_repository: Arc<dyn JwtRevocationRepository>,
+}
+
+impl RevokeSubjectJwtsUseCase {
+    /// Create a new RevokeSubjectJwtsUseCase
+    #[must_use]
+    pub fn new(jwt_revocation_repository: Arc<dyn JwtRevocationRepository>) -> Self {
+        Self {
+            jwt_revocation_repository,
+        }
+    }
+
+    /// Execute the use case
+    ///
+    /// Revoking a subject again keeps the latest of the two points in time.
+    ///
+    /// # Errors
+    ///
+    /// Returns `UseCaseError::Domain` if validation fails.
+    /// Returns `UseCaseError::Repository` if there's a database error.
+    pub async fn execute(
+        &self,
+        data: SubjectRevocationData,
+        revoked_by: &str,
+    ) -> Result<SubjectRevocation, UseCaseError> {
+        let revocation = SubjectRevocation::new(data, revoked_by.to_string())?;
+
+        tracing::info!(
+            subject = revocation.subject(),
+            revoked_before = %revocation.revoked_before(),
+            revoked_by,
+            "Revoking JWTs of subject"
+        );
+        let revocation = self.jwt_revocation_repository.revoke_subject(&revocation).await?;
+
+        Ok(revocation)
+    }
+}
@@ -3,9 +3,31 @@
 //! Application-specific business rules.
 //! Each use case is a single-purpose struct with an execute() method.
 
+pub mod api_keys;
+pub mod auth;
 pub mod networks;
+pub mod outbox;
+pub mod webhooks;
 
+pub use api_keys::{AuthenticateApiKeyUseCase, CreateApiKeyUseCase, ListApiKeysUseCase, RevokeApiKeyUseCase};
+pub use auth::{
+    CheckJwtRevocationUseCase, IssueServiceTokenUseCase, ListJwtRevocationsUseCase, RevokeJwtUseCase,
+    RevokeSubjectJwtsUseCase,
+};
 pub use networks::{
-    CreateNetworkUseCase, DeleteNetworkUseCase, GetActiveNetworksUseCase, GetNetworkByIdUseCase,
-    PartialUpdateNetworkUseCase, UpdateNetworkUseCase,
+    ApplyDueSchedulesUseCase, CancelLifecycleScheduleUseCase, CreateContractUseCase, CreateNetworkUseCase,
+    CreateSignerUseCase, CreateTokenUseCase, DeleteContractUseCase, DeleteNetworkUseCase, DeleteSignerUseCase,
+    DeleteTokenUseCase, ExportTokenListUseCase, FindContractsByNameUseCase, GetActiveNetworksUseCase,
+    GetContractUseCase, GetExplorerLinksUseCase, GetNetworkByIdUseCase, GetNetworkBySlugUseCase, ImportTokensUseCase,
+    ListContractsUseCase, ListLifecycleSchedulesUseCase, ListNetworkAncestorsUseCase, ListNetworkChildrenUseCase,
+    ListNetworkTestnetsUseCase, ListSignersUseCase, ListTokensUseCase, PartialUpdateNetworkUseCase,
+    PropagateNetworkChangeUseCase, ResolveSignerUseCase, ScheduleLifecycleChangeUseCase, SearchNetworksUseCase,
+    SetNetworkParentUseCase, TransitionNetworkUseCase, UpdateContractUseCase, UpdateNetworkUseCase,
+    UpdateSignerUseCase, UpdateTokenUseCase,
+};
+pub use outbox::{PurgeOutboxUseCase, RelayOutboxUseCase};
+pub use webhooks::{
+    CreateWebhookUseCase, DeleteWebhookUseCase, DeliverDueWebhooksUseCase, EnqueueWebhookDeliveriesUseCase,
+    GetWebhookDeliveryUseCase, GetWebhookUseCase, ListWebhookDeliveriesUseCase, ListWebhooksUseCase,
+    RedeliverWebhookUseCase, UpdateWebhookUseCase,
 };
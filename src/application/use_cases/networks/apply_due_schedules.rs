@@ -0,0 +1,331 @@
+//! Apply Due Schedules Use Case
+//!
+//! Applies lifecycle changes whose run time has passed. Invoked periodically by
+//! the lifecycle scheduler.
+
+use std::sync::Arc;
+
+use chrono::Utc;
+
+use crate::domain::gateways::{NetworkEventPublisher, NetworkRepository, ScheduleRepository};
+use crate::domain::models::event::NetworkEvent;
+use crate::domain::models::schedule::LifecycleSchedule;
+use crate::shared::errors::UseCaseError;
+
+/// Use case for applying due lifecycle schedules
+pub struct ApplyDueSchedulesUseCase {
+    network_repository: Arc<dyn NetworkRepository>,
+    schedule_repository: Arc<dyn ScheduleRepository>,
+    event_publisher: Arc<dyn NetworkEventPublisher>,
+}
+
+impl ApplyDueSchedulesUseCase {
+    /// Create a new ApplyDueSchedulesUseCase
+    #[must_use]
+    pub fn new(
+        network_repository: Arc<dyn NetworkRepository>,
+        schedule_repository: Arc<dyn ScheduleRepository>,
+        event_publisher: Arc<dyn NetworkEventPublisher>,
+    ) -> Self {
+        Self {
+            network_repository,
+            schedule_repository,
+            event_publisher,
+        }
+    }
+
+    /// Execute the use case, applying up to `limit` due schedules
+    ///
+    /// Schedules that cannot be applied (missing network, transition not allowed)
+    /// are marked as failed. Schedules hitting a repository error stay pending
+    /// and are retried once their claim expires.
+    ///
+    /// Returns the schedules completed by this run.
+    ///
+    /// # Errors
+    ///
+    /// Returns `UseCaseError::Repository` if claiming or completing schedules fails.
+    pub async fn execute(&self, limit: u32) -> Result<Vec<LifecycleSchedule>, UseCaseError> {
+        let due = self.schedule_repository.claim_due(Utc::now(), limit).await?;
+        if due.is_empty() {
+            return Ok(vec![]);
+        }
+
+        tracing::info!(count = due.len(), "Applying due lifecycle schedules");
+
+        let mut completed = Vec::with_capacity(due.len());
+        for schedule in due {
+            let schedule_id = schedule.id().clone();
+            let completion = match self.apply(&schedule).await {
+                Ok(()) => {
+                    tracing::info!(
+                        schedule_id = %schedule_id,
+                        network_id = %schedule.network_id(),
+                        action = %schedule.action(),
+                        scheduled_by = %schedule.scheduled_by(),
+                        "Scheduled lifecycle change applied"
+                    );
+                    schedule.mark_applied()?
+                }
+                Err(UseCaseError::Repository(e)) => {
+                    tracing::error!(schedule_id = %schedule_id, error = %e, "Scheduled lifecycle change will be retried");
+                    continue;
+                }
+                Err(e) => {
+                    tracing::warn!(schedule_id = %schedule_id, error = %e, "Scheduled lifecycle change failed");
+                    schedule.mark_failed(e.safe_message())?
+                }
+            };
+
+            if let Some(result) = self.schedule_repository.complete(&completion).await? {
+                completed.push(result);
+            } else {
+                tracing::warn!(schedule_id = %schedule_id, "Schedule was completed concurrently");
+            }
+        }
+
+        Ok(completed)
+    }
+
+    /// Apply the lifecycle transition of a single schedule to its network
+    ///
+    /// A network already in the target state is left unchanged.
+    async fn apply(&self, schedule: &LifecycleSchedule) -> Result<(), UseCaseError> {
+        let not_found = || UseCaseError::NotFound {
+            resource: "Network".to_string(),
+            id: schedule.network_id().to_string(),
+        };
+
+        let network = self
+            .network_repository
+            .find_by_id_for_update(schedule.network_id())
+            .await?
+            .ok_or_else(not_found)?;
+
+        let target = schedule.action().target_status();
+        if network.status() == target {
+            return Ok(());
+        }
+
+        let updated = network.clone().transition_to(target, None)?;
+        let result = self.network_repository.update(&updated).await?.ok_or_else(not_found)?;
+        self.event_publisher
+            .publish(NetworkEvent::changed(&network, result))
+            .await;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::use_cases::networks::test_support::{
+        network_data, InMemoryNetworkRepository, RecordingEventPublisher,
+    };
+
+    use crate::domain::models::lifecycle::NetworkStatus;
+    use crate::domain::models::network::{CreateNetworkData, Network, NetworkId};
+    use crate::domain::models::schedule::{ScheduleId, ScheduleStatus, ScheduledAction};
+    use crate::domain::models::url_policy::UrlPolicy;
+    use crate::shared::errors::RepositoryError;
+    use async_trait::async_trait;
+    use chrono::DateTime;
+    use std::sync::Mutex;
+
+    struct MockScheduleRepository {
+        due: Mutex<Vec<LifecycleSchedule>>,
+        completed: Mutex<Vec<LifecycleSchedule>>,
+    }
+
+    impl MockScheduleRepository {
+        fn with_due(due: Vec<LifecycleSchedule>) -> Self {
+            Self {
+                due: Mutex::new(due),
+                completed: Mutex::new(vec![]),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl ScheduleRepository for MockScheduleRepository {
+        async fn find_by_id(&self, _id: &ScheduleId) -> Result<Option<LifecycleSchedule>, RepositoryError> {
+            Ok(None)
+        }
+
+        async fn find_by_network(&self, _network_id: &NetworkId) -> Result<Vec<LifecycleSchedule>, RepositoryError> {
+            Ok(vec![])
+        }
+
+        async fn create(&self, schedule: &LifecycleSchedule) -> Result<LifecycleSchedule, RepositoryError> {
+            Ok(schedule.clone())
+        }
+
+        async fn complete(&self, schedule: &LifecycleSchedule) -> Result<Option<LifecycleSchedule>, RepositoryError> {
+            self.completed.lock().unwrap().push(schedule.clone());
+            Ok(Some(schedule.clone()))
+        }
+
+        async fn claim_due(&self, _now: DateTime<Utc>, _limit: u32) -> Result<Vec<LifecycleSchedule>, RepositoryError> {
+            Ok(std::mem::take(&mut *self.due.lock().unwrap()))
+        }
+    }
+
+    /// A schedule whose run time has already passed
+    fn due_schedule(network_id: &NetworkId, action: ScheduledAction) -> LifecycleSchedule {
+        let now = Utc::now();
+        LifecycleSchedule::restore(
+            ScheduleId::new(),
+            network_id.clone(),
+            action,
+            now - chrono::Duration::minutes(1),
+            ScheduleStatus::Pending,
+            "user-1".to_string(),
+            None,
+            None,
+            None,
+            now - chrono::Duration::hours(1),
+        )
+    }
+
+    fn create_test_network(status: NetworkStatus) -> Network {
+        let data = CreateNetworkData {
+            status,
+            ..network_data(1, "Ethereum Mainnet")
+        };
+        Network::new(data, &UrlPolicy::default()).expect("valid test data")
+    }
+
+    #[tokio::test]
+    async fn should_apply_due_activation() {
+        let network = create_test_network(NetworkStatus::Draft);
+        let network_repo = Arc::new(InMemoryNetworkRepository::with_networks([network.clone()]));
+        let schedule_repo = Arc::new(MockScheduleRepository::with_due(vec![due_schedule(
+            network.id(),
+            ScheduledAction::Activate,
+        )]));
+
+        let use_case = ApplyDueSchedulesUseCase::new(
+            network_repo.clone(),
+            schedule_repo,
+            Arc::new(RecordingEventPublisher::default()),
+        );
+        let completed = use_case.execute(10).await.unwrap();
+
+        assert_eq!(completed.len(), 1);
+        assert_eq!(completed[0].status(), ScheduleStatus::Applied);
+        assert_eq!(network_repo.get(network.id()).unwrap().status(), NetworkStatus::Active);
+    }
+
+    #[tokio::test]
+    async fn should_apply_due_deprecation() {
+        let network = create_test_network(NetworkStatus::Active);
+        let network_repo = Arc::new(InMemoryNetworkRepository::with_networks([network.clone()]));
+        let schedule_repo = Arc::new(MockScheduleRepository::with_due(vec![due_schedule(
+            network.id(),
+            ScheduledAction::Deprecate,
+        )]));
+
+        let use_case = ApplyDueSchedulesUseCase::new(
+            network_repo.clone(),
+            schedule_repo,
+            Arc::new(RecordingEventPublisher::default()),
+        );
+        let completed = use_case.execute(10).await.unwrap();
+
+        assert_eq!(completed[0].status(), ScheduleStatus::Applied);
+        let deprecated = network_repo.get(network.id()).unwrap();
+        assert_eq!(deprecated.status(), NetworkStatus::Deprecated);
+        assert!(deprecated.deprecated_at().is_some());
+        assert!(deprecated.status().is_usable());
+    }
+
+    #[tokio::test]
+    async fn should_apply_due_retirement() {
+        let network = create_test_network(NetworkStatus::Active)
+            .transition_to(NetworkStatus::Deprecated, None)
+            .unwrap();
+        let network_repo = Arc::new(InMemoryNetworkRepository::with_networks([network.clone()]));
+        let schedule_repo = Arc::new(MockScheduleRepository::with_due(vec![due_schedule(
+            network.id(),
+            ScheduledAction::Retire,
+        )]));
+
+        let use_case = ApplyDueSchedulesUseCase::new(
+            network_repo.clone(),
+            schedule_repo,
+            Arc::new(RecordingEventPublisher::default()),
+        );
+        let completed = use_case.execute(10).await.unwrap();
+
+        assert_eq!(completed[0].status(), ScheduleStatus::Applied);
+        let retired = network_repo.get(network.id()).unwrap();
+        assert_eq!(retired.status(), NetworkStatus::Retired);
+        assert!(retired.sunset_at().is_some());
+    }
+
+    #[tokio::test]
+    async fn should_leave_network_already_in_target_state_unchanged() {
+        let network = create_test_network(NetworkStatus::Active);
+        let network_repo = Arc::new(InMemoryNetworkRepository::with_networks([network.clone()]));
+        let schedule_repo = Arc::new(MockScheduleRepository::with_due(vec![due_schedule(
+            network.id(),
+            ScheduledAction::Activate,
+        )]));
+        let publisher = Arc::new(RecordingEventPublisher::default());
+
+        let use_case = ApplyDueSchedulesUseCase::new(network_repo.clone(), schedule_repo, publisher.clone());
+        let completed = use_case.execute(10).await.unwrap();
+
+        assert_eq!(completed[0].status(), ScheduleStatus::Applied);
+        assert_eq!(
+            network_repo.get(network.id()).unwrap().updated_at(),
+            network.updated_at()
+        );
+        assert!(publisher.events.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn should_mark_failed_when_transition_is_not_allowed() {
+        let network = create_test_network(NetworkStatus::Active)
+            .transition_to(NetworkStatus::Retired, None)
+            .unwrap();
+        let network_repo = Arc::new(InMemoryNetworkRepository::with_networks([network.clone()]));
+        let schedule_repo = Arc::new(MockScheduleRepository::with_due(vec![due_schedule(
+            network.id(),
+            ScheduledAction::Deprecate,
+        )]));
+
+        let use_case = ApplyDueSchedulesUseCase::new(
+            network_repo.clone(),
+            schedule_repo,
+            Arc::new(RecordingEventPublisher::default()),
+        );
+        let completed = use_case.execute(10).await.unwrap();
+
+        assert_eq!(completed.len(), 1);
+        assert_eq!(completed[0].status(), ScheduleStatus::Failed);
+        assert!(completed[0].outcome().is_some());
+        assert_eq!(network_repo.get(network.id()).unwrap().status(), NetworkStatus::Retired);
+    }
+
+    #[tokio::test]
+    async fn should_leave_schedule_pending_on_repository_error() {
+        let network_id = NetworkId::new();
+        let network_repo = Arc::new(InMemoryNetworkRepository::failing());
+        let schedule_repo = Arc::new(MockScheduleRepository::with_due(vec![due_schedule(
+            &network_id,
+            ScheduledAction::Retire,
+        )]));
+
+        let use_case = ApplyDueSchedulesUseCase::new(
+            network_repo,
+            schedule_repo.clone(),
+            Arc::new(RecordingEventPublisher::default()),
+        );
+        let completed = use_case.execute(10).await.unwrap();
+
+        assert!(completed.is_empty());
+        assert!(schedule_repo.completed.lock().unwrap().is_empty());
+    }
+}
@@ -0,0 +1,184 @@
+//! Cancel Lifecycle Schedule Use Case
+//!
+//! Withdraws a pending lifecycle change, recording who cancelled it.
+
+use std::sync::Arc;
+
+use crate::domain::gateways::ScheduleRepository;
+use crate::domain::models::network::NetworkId;
+use crate::domain::models::schedule::{LifecycleSchedule, ScheduleId};
+use crate::shared::errors::{DomainError, UseCaseError};
+
+/// Use case for cancelling a pending lifecycle schedule
+pub struct CancelLifecycleScheduleUseCase {
+    schedule_repository: Arc<dyn ScheduleRepository>,
+}
+
+impl CancelLifecycleScheduleUseCase {
+    /// Create a new CancelLifecycleScheduleUseCase
+    #[must_use]
+    pub fn new(schedule_repository: Arc<dyn ScheduleRepository>) -> Self {
+        Self { schedule_repository }
+    }
+
+    /// Execute the use case
+    ///
+    /// # Errors
+    ///
+    /// Returns `UseCaseError::NotFound` if the schedule doesn't exist for the network.
+    /// Returns `UseCaseError::Domain` if the schedule is no longer pending.
+    /// Returns `UseCaseError::Repository` if there's a database error.
+    pub async fn execute(
+        &self,
+        network_id: &NetworkId,
+        schedule_id: &ScheduleId,
+        cancelled_by: String,
+    ) -> Result<LifecycleSchedule, UseCaseError> {
+        tracing::info!(network_id = %network_id, schedule_id = %schedule_id, cancelled_by = %cancelled_by, "Cancelling lifecycle schedule");
+
+        let existing = self
+            .schedule_repository
+            .find_by_id(schedule_id)
+            .await?
+            .filter(|schedule| schedule.network_id() == network_id)
+            .ok_or_else(|| {
+                tracing::warn!(network_id = %network_id, schedule_id = %schedule_id, "Schedule not found for cancellation");
+                UseCaseError::NotFound {
+                    resource: "Schedule".to_string(),
+                    id: schedule_id.to_string(),
+                }
+            })?;
+
+        let cancelled = existing.cancel(cancelled_by)?;
+
+        // The scheduler may have completed the schedule since it was read
+        let result = self
+            .schedule_repository
+            .complete(&cancelled)
+            .await?
+            .ok_or_else(|| DomainError::InvalidState("schedule is no longer pending".to_string()))?;
+
+        tracing::info!(network_id = %network_id, schedule_id = %schedule_id, "Lifecycle schedule cancelled successfully");
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::models::schedule::{ScheduleStatus, ScheduledAction};
+    use crate::shared::errors::RepositoryError;
+    use async_trait::async_trait;
+    use chrono::{DateTime, Utc};
+    use std::sync::Mutex;
+
+    struct MockScheduleRepository {
+        find_by_id_result: Mutex<Option<Result<Option<LifecycleSchedule>, RepositoryError>>>,
+        complete_result: Mutex<Option<Result<Option<LifecycleSchedule>, RepositoryError>>>,
+    }
+
+    impl MockScheduleRepository {
+        fn new() -> Self {
+            Self {
+                find_by_id_result: Mutex::new(None),
+                complete_result: Mutex::new(None),
+            }
+        }
+
+        fn with_find_by_id(self, result: Result<Option<LifecycleSchedule>, RepositoryError>) -> Self {
+            *self.find_by_id_result.lock().unwrap() = Some(result);
+            self
+        }
+
+        fn with_complete(self, result: Result<Option<LifecycleSchedule>, RepositoryError>) -> Self {
+            *self.complete_result.lock().unwrap() = Some(result);
+            self
+        }
+    }
+
+    #[async_trait]
+    impl ScheduleRepository for MockScheduleRepository {
+        async fn find_by_id(&self, _id: &ScheduleId) -> Result<Option<LifecycleSchedule>, RepositoryError> {
+            self.find_by_id_result.lock().unwrap().take().unwrap_or(Ok(None))
+        }
+
+        async fn find_by_network(&self, _network_id: &NetworkId) -> Result<Vec<LifecycleSchedule>, RepositoryError> {
+            Ok(vec![])
+        }
+
+        async fn create(&self, schedule: &LifecycleSchedule) -> Result<LifecycleSchedule, RepositoryError> {
+            Ok(schedule.clone())
+        }
+
+        async fn complete(&self, schedule: &LifecycleSchedule) -> Result<Option<LifecycleSchedule>, RepositoryError> {
+            self.complete_result
+                .lock()
+                .unwrap()
+                .take()
+                .unwrap_or_else(|| Ok(Some(schedule.clone())))
+        }
+
+        async fn claim_due(&self, _now: DateTime<Utc>, _limit: u32) -> Result<Vec<LifecycleSchedule>, RepositoryError> {
+            Ok(vec![])
+        }
+    }
+
+    fn create_test_schedule(network_id: &NetworkId) -> LifecycleSchedule {
+        LifecycleSchedule::new(
+            network_id.clone(),
+            ScheduledAction::Activate,
+            Utc::now() + chrono::Duration::hours(1),
+            "user-1".to_string(),
+        )
+        .expect("valid schedule")
+    }
+
+    #[tokio::test]
+    async fn should_cancel_pending_schedule() {
+        let network_id = NetworkId::new();
+        let schedule = create_test_schedule(&network_id);
+        let repo = Arc::new(MockScheduleRepository::new().with_find_by_id(Ok(Some(schedule.clone()))));
+
+        let use_case = CancelLifecycleScheduleUseCase::new(repo);
+        let result = use_case
+            .execute(&network_id, schedule.id(), "user-2".to_string())
+            .await
+            .unwrap();
+
+        assert_eq!(result.status(), ScheduleStatus::Cancelled);
+        assert_eq!(result.scheduled_by(), "user-1");
+        assert_eq!(result.cancelled_by(), Some("user-2"));
+    }
+
+    #[tokio::test]
+    async fn should_return_not_found_for_schedule_of_other_network() {
+        let schedule = create_test_schedule(&NetworkId::new());
+        let repo = Arc::new(MockScheduleRepository::new().with_find_by_id(Ok(Some(schedule.clone()))));
+
+        let use_case = CancelLifecycleScheduleUseCase::new(repo);
+        let result = use_case
+            .execute(&NetworkId::new(), schedule.id(), "user-2".to_string())
+            .await;
+
+        assert!(matches!(result.unwrap_err(), UseCaseError::NotFound { .. }));
+    }
+
+    #[tokio::test]
+    async fn should_reject_schedule_completed_concurrently() {
+        let network_id = NetworkId::new();
+        let schedule = create_test_schedule(&network_id);
+        let repo = Arc::new(
+            MockScheduleRepository::new()
+                .with_find_by_id(Ok(Some(schedule.clone())))
+                .with_complete(Ok(None)),
+        );
+
+        let use_case = CancelLifecycleScheduleUseCase::new(repo);
+        let result = use_case.execute(&network_id, schedule.id(), "user-2".to_string()).await;
+
+        assert!(matches!(
+            result.unwrap_err(),
+            UseCaseError::Domain(DomainError::InvalidState(_))
+        ));
+    }
+}
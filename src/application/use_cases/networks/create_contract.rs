@@ -0,0 +1,194 @@
+//! Create Contract Use Case
+//!
+//! Registers a contract in the address book of a network.
+
+use std::sync::Arc;
+
+use crate::domain::gateways::{ContractRepository, NetworkRepository};
+use crate::domain::models::contract::{ContractData, NetworkContract};
+use crate::domain::models::network::NetworkId;
+use crate::shared::errors::UseCaseError;
+
+/// Use case for registering a contract on a network
+pub struct CreateContractUseCase {
+    network_repository: Arc<dyn NetworkRepository>,
+    contract_repository: Arc<dyn ContractRepository>,
+}
+
+impl CreateContractUseCase {
+    /// Create a new CreateContractUseCase
+    #[must_use]
+    pub fn new(
+        network_repository: Arc<dyn NetworkRepository>,
+        contract_repository: Arc<dyn ContractRepository>,
+    ) -> Self {
+        Self {
+            network_repository,
+            contract_repository,
+        }
+    }
+
+    /// Execute the use case
+    ///
+    /// # Errors
+    ///
+    /// Returns `UseCaseError::NotFound` if the network doesn't exist.
+    /// Returns `UseCaseError::Conflict` if the network already has a contract with the same name.
+    /// Returns `UseCaseError::Domain` if the contract data is invalid.
+    /// Returns `UseCaseError::Repository` if there's a database error.
+    pub async fn execute(&self, network_id: &NetworkId, data: ContractData) -> Result<NetworkContract, UseCaseError> {
+        tracing::info!(network_id = %network_id, name = %data.name, "Registering contract");
+
+        if self.network_repository.find_by_id(network_id).await?.is_none() {
+            return Err(UseCaseError::NotFound {
+                resource: "Network".to_string(),
+                id: network_id.to_string(),
+            });
+        }
+
+        let contract = NetworkContract::new(network_id.clone(), data)?;
+
+        if self
+            .contract_repository
+            .exists_by_name(network_id, contract.name(), None)
+            .await?
+        {
+            tracing::warn!(network_id = %network_id, name = %contract.name(), "Contract name already registered");
+            return Err(UseCaseError::Conflict(format!(
+                "Contract '{}' already exists on this network",
+                contract.name()
+            )));
+        }
+
+        let created = self.contract_repository.create(&contract).await?;
+
+        tracing::info!(network_id = %network_id, contract_id = %created.id(), "Contract registered successfully");
+        Ok(created)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::use_cases::networks::test_support::{network, InMemoryNetworkRepository};
+    use crate::domain::models::contract::ContractId;
+    use crate::shared::errors::{DomainError, RepositoryError};
+    use async_trait::async_trait;
+    use std::sync::Mutex;
+
+    struct MockContractRepository {
+        name_taken: bool,
+        created: Mutex<Vec<NetworkContract>>,
+    }
+
+    impl MockContractRepository {
+        fn new(name_taken: bool) -> Self {
+            Self {
+                name_taken,
+                created: Mutex::new(vec![]),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl ContractRepository for MockContractRepository {
+        async fn find_by_id(&self, _id: &ContractId) -> Result<Option<NetworkContract>, RepositoryError> {
+            Ok(None)
+        }
+
+        async fn find_by_network(&self, _network_id: &NetworkId) -> Result<Vec<NetworkContract>, RepositoryError> {
+            Ok(vec![])
+        }
+
+        async fn find_by_name(&self, _name: &str) -> Result<Vec<NetworkContract>, RepositoryError> {
+            Ok(vec![])
+        }
+
+        async fn create(&self, contract: &NetworkContract) -> Result<NetworkContract, RepositoryError> {
+            self.created.lock().unwrap().push(contract.clone());
+            Ok(contract.clone())
+        }
+
+        async fn update(&self, contract: &NetworkContract) -> Result<Option<NetworkContract>, RepositoryError> {
+            Ok(Some(contract.clone()))
+        }
+
+        async fn delete(&self, _id: &ContractId) -> Result<bool, RepositoryError> {
+            Ok(false)
+        }
+
+        async fn exists_by_name(
+            &self,
+            _network_id: &NetworkId,
+            _name: &str,
+            _exclude_id: Option<&ContractId>,
+        ) -> Result<bool, RepositoryError> {
+            Ok(self.name_taken)
+        }
+    }
+
+    fn contract_data(address: &str) -> ContractData {
+        ContractData {
+            name: "Multicall3".to_string(),
+            address: address.to_string(),
+            deployment_block: Some(14_353_601),
+            abi_ref: None,
+            tags: vec!["utility".to_string()],
+        }
+    }
+
+    const MULTICALL3: &str = "0xcA11bde05977b3631167028862bE2a173976CA11";
+
+    #[tokio::test]
+    async fn should_register_contract() {
+        let network = network(1, "Ethereum Mainnet");
+        let network_repo = Arc::new(InMemoryNetworkRepository::with_networks([network.clone()]));
+        let contract_repo = Arc::new(MockContractRepository::new(false));
+
+        let use_case = CreateContractUseCase::new(network_repo, contract_repo.clone());
+        let result = use_case.execute(network.id(), contract_data(MULTICALL3)).await.unwrap();
+
+        assert_eq!(result.network_id(), network.id());
+        assert_eq!(result.address(), MULTICALL3);
+        assert_eq!(contract_repo.created.lock().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn should_return_not_found_when_network_does_not_exist() {
+        let network_repo = Arc::new(InMemoryNetworkRepository::new());
+        let contract_repo = Arc::new(MockContractRepository::new(false));
+
+        let use_case = CreateContractUseCase::new(network_repo, contract_repo);
+        let result = use_case.execute(&NetworkId::new(), contract_data(MULTICALL3)).await;
+
+        assert!(matches!(result.unwrap_err(), UseCaseError::NotFound { .. }));
+    }
+
+    #[tokio::test]
+    async fn should_return_conflict_when_name_is_taken() {
+        let network = network(1, "Ethereum Mainnet");
+        let network_repo = Arc::new(InMemoryNetworkRepository::with_networks([network.clone()]));
+        let contract_repo = Arc::new(MockContractRepository::new(true));
+
+        let use_case = CreateContractUseCase::new(network_repo, contract_repo.clone());
+        let result = use_case.execute(network.id(), contract_data(MULTICALL3)).await;
+
+        assert!(matches!(result.unwrap_err(), UseCaseError::Conflict(_)));
+        assert!(contract_repo.created.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn should_reject_invalid_address() {
+        let network = network(1, "Ethereum Mainnet");
+        let network_repo = Arc::new(InMemoryNetworkRepository::with_networks([network.clone()]));
+        let contract_repo = Arc::new(MockContractRepository::new(false));
+
+        let use_case = CreateContractUseCase::new(network_repo, contract_repo);
+        let result = use_case.execute(network.id(), contract_data("0x1234")).await;
+
+        assert!(matches!(
+            result.unwrap_err(),
+            UseCaseError::Domain(DomainError::ValidationError(_))
+        ));
+    }
+}
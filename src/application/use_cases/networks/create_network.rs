@@ -4,20 +4,36 @@
 
 use std::sync::Arc;
 
-use crate::domain::gateways::NetworkRepository;
+use crate::domain::gateways::{NetworkEventPublisher, NetworkRepository};
+use crate::domain::models::event::NetworkEvent;
 use crate::domain::models::network::{CreateNetworkData, Network};
-use crate::shared::errors::UseCaseError;
+use crate::domain::models::relation::check_lineage;
+use crate::domain::models::slug::{is_reserved_slug, slugify};
+use crate::domain::models::url_policy::UrlPolicy;
+use crate::shared::errors::{DomainError, UseCaseError};
+
+use super::set_network_parent::load_lineage;
 
 /// Use case for creating a new network
 pub struct CreateNetworkUseCase {
     network_repository: Arc<dyn NetworkRepository>,
+    event_publisher: Arc<dyn NetworkEventPublisher>,
+    url_policy: Arc<UrlPolicy>,
 }
 
 impl CreateNetworkUseCase {
     /// Create a new CreateNetworkUseCase
     #[must_use]
-    pub fn new(network_repository: Arc<dyn NetworkRepository>) -> Self {
-        Self { network_repository }
+    pub fn new(
+        network_repository: Arc<dyn NetworkRepository>,
+        event_publisher: Arc<dyn NetworkEventPublisher>,
+        url_policy: Arc<UrlPolicy>,
+    ) -> Self {
+        Self {
+            network_repository,
+            event_publisher,
+            url_policy,
+        }
     }
 
     /// Execute the use case
@@ -25,8 +41,11 @@ impl CreateNetworkUseCase {
     /// # Errors
     ///
     /// Returns `UseCaseError::Conflict` if a network with the same chain_id already exists.
+    /// Returns `UseCaseError::Validation` if the parent network or the mirrored mainnet doesn't exist.
+    /// Returns `UseCaseError::Domain` if the slug or an alias is taken, the parent network
+    /// is already at the maximum depth or the mirrored mainnet is itself a testnet.
     /// Returns `UseCaseError::Repository` if there's a database error.
-    pub async fn execute(&self, data: CreateNetworkData) -> Result<Network, UseCaseError> {
+    pub async fn execute(&self, mut data: CreateNetworkData) -> Result<Network, UseCaseError> {
         tracing::info!(chain_id = data.chain_id, name = %data.name, "Creating new network");
 
         // Check if chain_id already exists
@@ -42,9 +61,37 @@ impl CreateNetworkUseCase {
             )));
         }
 
+        // Derive the slug from the name, suffixed with the chain_id if another network or a route
+        // took it, then with a counter should the suffixed slug be taken too
+        if data.slug.is_none() {
+            let base = slugify(&data.name);
+            let mut slug = base.clone();
+            let mut attempt = 1;
+            while is_reserved_slug(&slug) || self.network_repository.exists_by_slug(&slug, None).await? {
+                slug = match attempt {
+                    1 => format!("{base}-{}", data.chain_id),
+                    _ => format!("{base}-{}-{attempt}", data.chain_id),
+                };
+                attempt += 1;
+            }
+            data.slug = Some(slug);
+        }
+
         // Create the network (validates domain constraints)
-        let network = Network::new(data)?;
+        let network = Network::new(data, &self.url_policy)?;
+        ensure_identifiers_available(self.network_repository.as_ref(), &network).await?;
+        ensure_mainnet(self.network_repository.as_ref(), &network).await?;
+
+        // A new network cannot close a cycle, but its parent must exist and leave room below it
+        if let Some(parent) = network.parent() {
+            let lineage = load_lineage(self.network_repository.as_ref(), &parent.network_id).await?;
+            check_lineage(network.id(), &lineage, 0)?;
+        }
+
         let created = self.network_repository.create(&network).await?;
+        self.event_publisher
+            .publish(NetworkEvent::created(created.clone()))
+            .await;
 
         tracing::info!(
             network_id = %created.id(),
@@ -56,117 +103,210 @@ impl CreateNetworkUseCase {
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::domain::models::network::NetworkId;
-    use crate::shared::errors::RepositoryError;
-    use async_trait::async_trait;
-    use rust_decimal_macros::dec;
-    use std::sync::Mutex;
-
-    struct MockNetworkRepository {
-        exists_by_chain_id_result: Mutex<Option<Result<bool, RepositoryError>>>,
-        create_result: Mutex<Option<Result<Network, RepositoryError>>>,
-    }
-
-    impl MockNetworkRepository {
-        fn new() -> Self {
-            Self {
-                exists_by_chain_id_result: Mutex::new(None),
-                create_result: Mutex::new(None),
-            }
-        }
-
-        fn with_exists_by_chain_id(self, result: Result<bool, RepositoryError>) -> Self {
-            *self.exists_by_chain_id_result.lock().unwrap() = Some(result);
-            self
-        }
-
-        fn with_create(self, result: Result<Network, RepositoryError>) -> Self {
-            *self.create_result.lock().unwrap() = Some(result);
-            self
+/// Check that no other network answers to the slug or aliases of a network
+///
+/// # Errors
+///
+/// Returns `DomainError::SlugConflict` with the first identifier already taken.
+pub(super) async fn ensure_identifiers_available(
+    network_repository: &dyn NetworkRepository,
+    network: &Network,
+) -> Result<(), UseCaseError> {
+    let identifiers = std::iter::once(network.slug()).chain(network.aliases().iter().map(String::as_str));
+    for identifier in identifiers {
+        if network_repository
+            .exists_by_slug(identifier, Some(network.id()))
+            .await?
+        {
+            tracing::warn!(network_id = %network.id(), slug = identifier, "Network slug or alias already taken");
+            return Err(DomainError::SlugConflict(identifier.to_string()).into());
         }
     }
 
-    #[async_trait]
-    impl NetworkRepository for MockNetworkRepository {
-        async fn find_by_id(&self, _id: &NetworkId) -> Result<Option<Network>, RepositoryError> {
-            Ok(None)
-        }
-
-        async fn find_by_chain_id(&self, _chain_id: i32) -> Result<Option<Network>, RepositoryError> {
-            Ok(None)
-        }
-
-        async fn find_all_active(&self) -> Result<Vec<Network>, RepositoryError> {
-            Ok(vec![])
-        }
-
-        async fn create(&self, network: &Network) -> Result<Network, RepositoryError> {
-            self.create_result
-                .lock()
-                .unwrap()
-                .take()
-                .unwrap_or_else(|| Ok(network.clone()))
-        }
+    Ok(())
+}
 
-        async fn update(&self, _network: &Network) -> Result<Option<Network>, RepositoryError> {
-            Ok(None)
-        }
+/// Check that the mainnet a testnet mirrors exists and is not a testnet itself
+///
+/// # Errors
+///
+/// Returns `UseCaseError::Validation` if the mainnet doesn't exist.
+/// Returns `DomainError::ValidationError` if the network mirrors itself or another testnet.
+pub(super) async fn ensure_mainnet(
+    network_repository: &dyn NetworkRepository,
+    network: &Network,
+) -> Result<(), UseCaseError> {
+    let Some(mainnet_id) = &network.testnet().mainnet_id else {
+        return Ok(());
+    };
 
-        async fn soft_delete(&self, _id: &NetworkId) -> Result<bool, RepositoryError> {
-            Ok(false)
-        }
+    if mainnet_id == network.id() {
+        return Err(DomainError::ValidationError("a testnet cannot mirror itself".to_string()).into());
+    }
 
-        async fn exists_by_chain_id(
-            &self,
-            _chain_id: i32,
-            _exclude_id: Option<&NetworkId>,
-        ) -> Result<bool, RepositoryError> {
-            self.exists_by_chain_id_result
-                .lock()
-                .unwrap()
-                .take()
-                .unwrap_or(Ok(false))
+    match network_repository.find_by_id(mainnet_id).await? {
+        None => Err(UseCaseError::Validation(vec![format!(
+            "mainnet network {mainnet_id} does not exist"
+        )])),
+        Some(mainnet) if mainnet.test_net() => {
+            Err(DomainError::ValidationError(format!("mainnet network {mainnet_id} is a testnet")).into())
         }
+        Some(_) => Ok(()),
     }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::use_cases::networks::test_support::{
+        network, network_data, InMemoryNetworkRepository, RecordingEventPublisher,
+    };
+    use crate::domain::models::event::NetworkEventKind;
+    use crate::domain::models::network::NetworkId;
+    use crate::domain::models::relation::{NetworkParent, NetworkRelationKind};
+    use crate::domain::models::testnet::TestnetInfo;
 
     fn create_test_data() -> CreateNetworkData {
-        CreateNetworkData {
-            chain_id: 1,
-            name: "Ethereum Mainnet".to_string(),
-            rpc_url: "https://mainnet.infura.io".to_string(),
-            other_rpc_urls: vec![],
-            test_net: false,
-            block_explorer_url: "https://etherscan.io".to_string(),
-            fee_multiplier: dec!(1.0),
-            gas_limit_multiplier: dec!(1.2),
-            default_signer_address: "0x742d35Cc6634C0532925a3b844Bc9e7595f1dEaD".to_string(),
-        }
+        network_data(1, "Ethereum Mainnet")
     }
 
     #[tokio::test]
     async fn should_create_network_when_chain_id_does_not_exist() {
-        let repo = Arc::new(MockNetworkRepository::new().with_exists_by_chain_id(Ok(false)));
+        let repo = Arc::new(InMemoryNetworkRepository::new());
+        let publisher = Arc::new(RecordingEventPublisher::default());
 
-        let use_case = CreateNetworkUseCase::new(repo);
+        let use_case = CreateNetworkUseCase::new(repo, publisher.clone(), Arc::default());
         let result = use_case.execute(create_test_data()).await;
 
         assert!(result.is_ok());
         let network = result.unwrap();
         assert_eq!(network.chain_id(), 1);
         assert_eq!(network.name(), "Ethereum Mainnet");
+
+        let events = publisher.events.lock().unwrap();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].kind, NetworkEventKind::Created);
+        assert_eq!(events[0].network.id(), network.id());
+    }
+
+    #[tokio::test]
+    async fn should_not_publish_event_when_creation_fails() {
+        let repo = Arc::new(InMemoryNetworkRepository::with_networks([network(1, "Ethereum")]));
+        let publisher = Arc::new(RecordingEventPublisher::default());
+
+        let use_case = CreateNetworkUseCase::new(repo, publisher.clone(), Arc::default());
+        let result = use_case.execute(create_test_data()).await;
+
+        assert!(result.is_err());
+        assert!(publisher.events.lock().unwrap().is_empty());
     }
 
     #[tokio::test]
     async fn should_return_conflict_when_chain_id_exists() {
-        let repo = Arc::new(MockNetworkRepository::new().with_exists_by_chain_id(Ok(true)));
+        let repo = Arc::new(InMemoryNetworkRepository::with_networks([network(1, "Ethereum")]));
 
-        let use_case = CreateNetworkUseCase::new(repo);
+        let use_case = CreateNetworkUseCase::new(repo, Arc::new(RecordingEventPublisher::default()), Arc::default());
         let result = use_case.execute(create_test_data()).await;
 
         assert!(result.is_err());
         assert!(matches!(result.unwrap_err(), UseCaseError::Conflict(_)));
     }
+
+    #[tokio::test]
+    async fn should_reject_unknown_parent() {
+        let repo = Arc::new(InMemoryNetworkRepository::new());
+        let data = CreateNetworkData {
+            parent: Some(NetworkParent {
+                network_id: NetworkId::new(),
+                kind: NetworkRelationKind::TestnetOf,
+            }),
+            ..create_test_data()
+        };
+
+        let use_case = CreateNetworkUseCase::new(repo, Arc::new(RecordingEventPublisher::default()), Arc::default());
+        let result = use_case.execute(data).await;
+
+        assert!(matches!(result.unwrap_err(), UseCaseError::Validation(_)));
+    }
+
+    #[tokio::test]
+    async fn should_reject_unknown_mainnet() {
+        let repo = Arc::new(InMemoryNetworkRepository::new());
+        let data = CreateNetworkData {
+            chain_id: 11_155_111,
+            name: "Sepolia".to_string(),
+            test_net: true,
+            testnet: TestnetInfo {
+                faucet_urls: vec!["https://sepoliafaucet.com".to_string()],
+                mainnet_id: Some(NetworkId::new()),
+                expected_deprecation_at: None,
+            },
+            ..create_test_data()
+        };
+
+        let use_case = CreateNetworkUseCase::new(repo, Arc::new(RecordingEventPublisher::default()), Arc::default());
+        let result = use_case.execute(data).await;
+
+        assert!(matches!(result.unwrap_err(), UseCaseError::Validation(_)));
+    }
+
+    #[tokio::test]
+    async fn should_derive_slug_from_name() {
+        let use_case = CreateNetworkUseCase::new(
+            Arc::new(InMemoryNetworkRepository::new()),
+            Arc::new(RecordingEventPublisher::default()),
+            Arc::default(),
+        );
+        let created = use_case.execute(create_test_data()).await.unwrap();
+        assert_eq!(created.slug(), "ethereum-mainnet");
+
+        let repo = InMemoryNetworkRepository::with_networks([network(5, "Ethereum Mainnet")]);
+        let use_case = CreateNetworkUseCase::new(
+            Arc::new(repo),
+            Arc::new(RecordingEventPublisher::default()),
+            Arc::default(),
+        );
+        let created = use_case.execute(create_test_data()).await.unwrap();
+        assert_eq!(created.slug(), "ethereum-mainnet-1");
+
+        // The chain_id suffix can itself be taken by another network's name
+        let repo = InMemoryNetworkRepository::with_networks([
+            network(5, "Ethereum Mainnet"),
+            network(6, "Ethereum Mainnet 1"),
+        ]);
+        let use_case = CreateNetworkUseCase::new(
+            Arc::new(repo),
+            Arc::new(RecordingEventPublisher::default()),
+            Arc::default(),
+        );
+        let created = use_case.execute(create_test_data()).await.unwrap();
+        assert_eq!(created.slug(), "ethereum-mainnet-1-2");
+
+        // Names matching a route under /networks get the chain_id suffix too
+        let use_case = CreateNetworkUseCase::new(
+            Arc::new(InMemoryNetworkRepository::new()),
+            Arc::new(RecordingEventPublisher::default()),
+            Arc::default(),
+        );
+        let created = use_case.execute(network_data(1, "Search")).await.unwrap();
+        assert_eq!(created.slug(), "search-1");
+    }
+
+    #[tokio::test]
+    async fn should_return_conflict_when_alias_is_taken() {
+        let repo = Arc::new(InMemoryNetworkRepository::with_networks([network(10, "Ethereum")]));
+        let data = CreateNetworkData {
+            slug: Some("eth-mainnet".to_string()),
+            aliases: vec!["Ethereum".to_string()],
+            ..create_test_data()
+        };
+
+        let use_case = CreateNetworkUseCase::new(repo, Arc::new(RecordingEventPublisher::default()), Arc::default());
+        let result = use_case.execute(data).await;
+
+        assert!(matches!(
+            result.unwrap_err(),
+            UseCaseError::Domain(DomainError::SlugConflict(slug)) if slug == "ethereum"
+        ));
+    }
 }
@@ -0,0 +1,245 @@
+//! Create Signer Use Case
+//!
+//! Registers a signer address for a role on a network.
+
+use std::sync::Arc;
+
+use crate::domain::gateways::{NetworkRepository, SignerRepository};
+use crate::domain::models::network::NetworkId;
+use crate::domain::models::signer::{NetworkSigner, SignerData};
+use crate::shared::errors::UseCaseError;
+
+/// Use case for registering a signer on a network
+pub struct CreateSignerUseCase {
+    network_repository: Arc<dyn NetworkRepository>,
+    signer_repository: Arc<dyn SignerRepository>,
+}
+
+impl CreateSignerUseCase {
+    /// Create a new CreateSignerUseCase
+    #[must_use]
+    pub fn new(network_repository: Arc<dyn NetworkRepository>, signer_repository: Arc<dyn SignerRepository>) -> Self {
+        Self {
+            network_repository,
+            signer_repository,
+        }
+    }
+
+    /// Execute the use case
+    ///
+    /// # Errors
+    ///
+    /// Returns `UseCaseError::NotFound` if the network doesn't exist.
+    /// Returns `UseCaseError::Conflict` if the address already has the role on the network.
+    /// Returns `UseCaseError::Domain` if the signer data is invalid.
+    /// Returns `UseCaseError::Repository` if there's a database error.
+    pub async fn execute(&self, network_id: &NetworkId, data: SignerData) -> Result<NetworkSigner, UseCaseError> {
+        tracing::info!(network_id = %network_id, role = %data.role, "Registering signer");
+
+        if self.network_repository.find_by_id(network_id).await?.is_none() {
+            return Err(UseCaseError::NotFound {
+                resource: "Network".to_string(),
+                id: network_id.to_string(),
+            });
+        }
+
+        let signer = NetworkSigner::new(network_id.clone(), data)?;
+
+        if self
+            .signer_repository
+            .exists_by_role_and_address(network_id, signer.role(), signer.address(), None)
+            .await?
+        {
+            tracing::warn!(network_id = %network_id, role = %signer.role(), "Signer already registered for role");
+            return Err(UseCaseError::Conflict(format!(
+                "Signer {} already has role '{}' on this network",
+                signer.address(),
+                signer.role()
+            )));
+        }
+
+        let created = self.signer_repository.create(&signer).await?;
+
+        tracing::info!(network_id = %network_id, signer_id = %created.id(), "Signer registered successfully");
+        Ok(created)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::use_cases::networks::test_support::{network, InMemoryNetworkRepository};
+    use crate::domain::models::signer::SignerId;
+    use crate::shared::errors::{DomainError, RepositoryError};
+    use async_trait::async_trait;
+    use std::sync::Mutex;
+
+    struct MockSignerRepository {
+        signers: Mutex<Vec<NetworkSigner>>,
+    }
+
+    impl MockSignerRepository {
+        fn with_signers(signers: Vec<NetworkSigner>) -> Self {
+            Self {
+                signers: Mutex::new(signers),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl SignerRepository for MockSignerRepository {
+        async fn find_by_id(&self, id: &SignerId) -> Result<Option<NetworkSigner>, RepositoryError> {
+            Ok(self.signers.lock().unwrap().iter().find(|s| s.id() == id).cloned())
+        }
+
+        async fn find_by_network(&self, network_id: &NetworkId) -> Result<Vec<NetworkSigner>, RepositoryError> {
+            Ok(self
+                .signers
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|s| s.network_id() == network_id)
+                .cloned()
+                .collect())
+        }
+
+        async fn create(&self, signer: &NetworkSigner) -> Result<NetworkSigner, RepositoryError> {
+            self.signers.lock().unwrap().push(signer.clone());
+            Ok(signer.clone())
+        }
+
+        async fn update(&self, signer: &NetworkSigner) -> Result<Option<NetworkSigner>, RepositoryError> {
+            let mut signers = self.signers.lock().unwrap();
+            let Some(existing) = signers.iter_mut().find(|s| s.id() == signer.id()) else {
+                return Ok(None);
+            };
+            *existing = signer.clone();
+            Ok(Some(signer.clone()))
+        }
+
+        async fn delete(&self, id: &SignerId) -> Result<bool, RepositoryError> {
+            let mut signers = self.signers.lock().unwrap();
+            let before = signers.len();
+            signers.retain(|s| s.id() != id);
+            Ok(signers.len() != before)
+        }
+
+        async fn exists_by_role_and_address(
+            &self,
+            network_id: &NetworkId,
+            role: &str,
+            address: &str,
+            exclude_id: Option<&SignerId>,
+        ) -> Result<bool, RepositoryError> {
+            Ok(self.signers.lock().unwrap().iter().any(|s| {
+                s.network_id() == network_id
+                    && s.role() == role
+                    && s.address().eq_ignore_ascii_case(address)
+                    && Some(s.id()) != exclude_id
+            }))
+        }
+    }
+
+    fn create_test_signer(network_id: &NetworkId, role: &str, address: &str) -> NetworkSigner {
+        NetworkSigner::new(
+            network_id.clone(),
+            SignerData {
+                address: address.to_string(),
+                role: role.to_string(),
+                priority: 0,
+                enabled: true,
+            },
+        )
+        .expect("valid signer")
+    }
+
+    const RELAYER: &str = "0x1111111111111111111111111111111111111111";
+    const PAYOUTS: &str = "0x2222222222222222222222222222222222222222";
+
+    fn relayer(address: &str) -> SignerData {
+        SignerData {
+            address: address.to_string(),
+            role: "Relayer".to_string(),
+            priority: 10,
+            enabled: true,
+        }
+    }
+
+    #[tokio::test]
+    async fn should_register_signer() {
+        let network = network(1, "Ethereum Mainnet");
+        let network_repo = Arc::new(InMemoryNetworkRepository::with_networks([network.clone()]));
+        let signer_repo = Arc::new(MockSignerRepository::with_signers(vec![]));
+
+        let use_case = CreateSignerUseCase::new(network_repo, signer_repo.clone());
+        let result = use_case.execute(network.id(), relayer(RELAYER)).await.unwrap();
+
+        assert_eq!(result.network_id(), network.id());
+        assert_eq!(result.role(), "relayer");
+        assert_eq!(result.priority(), 10);
+        assert_eq!(signer_repo.signers.lock().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn should_return_not_found_when_network_does_not_exist() {
+        let network_repo = Arc::new(InMemoryNetworkRepository::new());
+        let signer_repo = Arc::new(MockSignerRepository::with_signers(vec![]));
+
+        let use_case = CreateSignerUseCase::new(network_repo, signer_repo);
+        let result = use_case.execute(&NetworkId::new(), relayer(RELAYER)).await;
+
+        assert!(matches!(result.unwrap_err(), UseCaseError::NotFound { .. }));
+    }
+
+    #[tokio::test]
+    async fn should_return_conflict_when_address_already_has_role() {
+        let network = network(1, "Ethereum Mainnet");
+        let network_repo = Arc::new(InMemoryNetworkRepository::with_networks([network.clone()]));
+        let existing = create_test_signer(network.id(), "relayer", RELAYER);
+        let signer_repo = Arc::new(MockSignerRepository::with_signers(vec![existing]));
+
+        let use_case = CreateSignerUseCase::new(network_repo, signer_repo.clone());
+        let result = use_case.execute(network.id(), relayer(RELAYER)).await;
+
+        assert!(matches!(result.unwrap_err(), UseCaseError::Conflict(_)));
+        assert_eq!(signer_repo.signers.lock().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn should_allow_same_address_with_another_role_and_same_role_with_another_address() {
+        let network = network(1, "Ethereum Mainnet");
+        let network_repo = Arc::new(InMemoryNetworkRepository::with_networks([network.clone()]));
+        let signer_repo = Arc::new(MockSignerRepository::with_signers(vec![
+            create_test_signer(network.id(), "payouts", RELAYER),
+            create_test_signer(network.id(), "relayer", PAYOUTS),
+        ]));
+
+        let use_case = CreateSignerUseCase::new(network_repo, signer_repo);
+        let result = use_case.execute(network.id(), relayer(RELAYER)).await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn should_reject_invalid_priority() {
+        let network = network(1, "Ethereum Mainnet");
+        let network_repo = Arc::new(InMemoryNetworkRepository::with_networks([network.clone()]));
+        let signer_repo = Arc::new(MockSignerRepository::with_signers(vec![]));
+
+        let use_case = CreateSignerUseCase::new(network_repo, signer_repo);
+        let result = use_case
+            .execute(
+                network.id(),
+                SignerData {
+                    priority: -1,
+                    ..relayer(RELAYER)
+                },
+            )
+            .await;
+
+        assert!(matches!(
+            result.unwrap_err(),
+            UseCaseError::Domain(DomainError::ValidationError(_))
+        ));
+    }
+}
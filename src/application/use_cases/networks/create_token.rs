@@ -0,0 +1,255 @@
+//! Create Token Use Case
+//!
+//! Adds an ERC-20 token to the token list of a network.
+
+use std::sync::Arc;
+
+use crate::domain::gateways::{NetworkRepository, TokenRepository};
+use crate::domain::models::network::NetworkId;
+use crate::domain::models::token::{Token, TokenMetadata};
+use crate::shared::errors::UseCaseError;
+
+/// Use case for adding a token to a network
+pub struct CreateTokenUseCase {
+    network_repository: Arc<dyn NetworkRepository>,
+    token_repository: Arc<dyn TokenRepository>,
+}
+
+impl CreateTokenUseCase {
+    /// Create a new CreateTokenUseCase
+    #[must_use]
+    pub fn new(network_repository: Arc<dyn NetworkRepository>, token_repository: Arc<dyn TokenRepository>) -> Self {
+        Self {
+            network_repository,
+            token_repository,
+        }
+    }
+
+    /// Execute the use case
+    ///
+    /// # Errors
+    ///
+    /// Returns `UseCaseError::NotFound` if the network doesn't exist.
+    /// Returns `UseCaseError::Conflict` if the token address is already listed on the network.
+    /// Returns `UseCaseError::Domain` if the token data is invalid.
+    /// Returns `UseCaseError::Repository` if there's a database error.
+    pub async fn execute(
+        &self,
+        network_id: &NetworkId,
+        address: String,
+        metadata: TokenMetadata,
+    ) -> Result<Token, UseCaseError> {
+        tracing::info!(network_id = %network_id, address = %address, symbol = %metadata.symbol, "Adding token");
+
+        if self.network_repository.find_by_id(network_id).await?.is_none() {
+            return Err(UseCaseError::NotFound {
+                resource: "Network".to_string(),
+                id: network_id.to_string(),
+            });
+        }
+
+        let token = Token::new(network_id.clone(), address, metadata)?;
+
+        if self
+            .token_repository
+            .exists_by_address(network_id, token.address())
+            .await?
+        {
+            tracing::warn!(network_id = %network_id, address = %token.address(), "Token already listed");
+            return Err(UseCaseError::Conflict(format!(
+                "Token {} already exists on this network",
+                token.address()
+            )));
+        }
+
+        let created = self.token_repository.create(&token).await?;
+
+        tracing::info!(network_id = %network_id, token_id = %created.id(), "Token added successfully");
+        Ok(created)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::use_cases::networks::test_support::{network, InMemoryNetworkRepository};
+    use crate::domain::models::token::TokenId;
+    use crate::domain::models::token_list::{TokenListChange, TokenListVersion};
+    use crate::shared::errors::{DomainError, RepositoryError};
+    use async_trait::async_trait;
+    use chrono::Utc;
+    use std::sync::Mutex;
+
+    struct MockTokenRepository {
+        tokens: Mutex<Vec<Token>>,
+        version: Mutex<Option<TokenListVersion>>,
+    }
+
+    impl MockTokenRepository {
+        fn with_tokens(tokens: Vec<Token>) -> Self {
+            Self {
+                tokens: Mutex::new(tokens),
+                version: Mutex::new(None),
+            }
+        }
+
+        fn bump(&self, change: TokenListChange) -> TokenListVersion {
+            let mut version = self.version.lock().unwrap();
+            let bumped = version
+                .unwrap_or_else(|| TokenListVersion::initial(Utc::now()))
+                .bump(change, Utc::now());
+            *version = Some(bumped);
+            bumped
+        }
+    }
+
+    #[async_trait]
+    impl TokenRepository for MockTokenRepository {
+        async fn find_by_id(&self, id: &TokenId) -> Result<Option<Token>, RepositoryError> {
+            Ok(self.tokens.lock().unwrap().iter().find(|t| t.id() == id).cloned())
+        }
+
+        async fn find_by_network(&self, network_id: &NetworkId) -> Result<Vec<Token>, RepositoryError> {
+            Ok(self
+                .tokens
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|t| t.network_id() == network_id)
+                .cloned()
+                .collect())
+        }
+
+        async fn exists_by_address(&self, network_id: &NetworkId, address: &str) -> Result<bool, RepositoryError> {
+            Ok(self
+                .tokens
+                .lock()
+                .unwrap()
+                .iter()
+                .any(|t| t.network_id() == network_id && t.address().eq_ignore_ascii_case(address)))
+        }
+
+        async fn create(&self, token: &Token) -> Result<Token, RepositoryError> {
+            self.tokens.lock().unwrap().push(token.clone());
+            self.bump(TokenListChange::Minor);
+            Ok(token.clone())
+        }
+
+        async fn update(&self, token: &Token) -> Result<Option<Token>, RepositoryError> {
+            let mut tokens = self.tokens.lock().unwrap();
+            let Some(existing) = tokens.iter_mut().find(|t| t.id() == token.id()) else {
+                return Ok(None);
+            };
+            *existing = token.clone();
+            self.bump(TokenListChange::Patch);
+            Ok(Some(token.clone()))
+        }
+
+        async fn delete(&self, id: &TokenId) -> Result<bool, RepositoryError> {
+            let mut tokens = self.tokens.lock().unwrap();
+            let before = tokens.len();
+            tokens.retain(|t| t.id() != id);
+            let deleted = tokens.len() != before;
+            if deleted {
+                self.bump(TokenListChange::Major);
+            }
+            Ok(deleted)
+        }
+
+        async fn import(
+            &self,
+            _network_id: &NetworkId,
+            created: &[Token],
+            updated: &[Token],
+        ) -> Result<TokenListVersion, RepositoryError> {
+            let mut tokens = self.tokens.lock().unwrap();
+            for token in updated {
+                if let Some(existing) = tokens.iter_mut().find(|t| t.id() == token.id()) {
+                    *existing = token.clone();
+                }
+            }
+            tokens.extend_from_slice(created);
+            let change = if created.is_empty() {
+                TokenListChange::Patch
+            } else {
+                TokenListChange::Minor
+            };
+            Ok(self.bump(change))
+        }
+
+        async fn find_version(&self, _network_id: &NetworkId) -> Result<Option<TokenListVersion>, RepositoryError> {
+            Ok(*self.version.lock().unwrap())
+        }
+    }
+
+    fn usdc() -> TokenMetadata {
+        TokenMetadata {
+            symbol: "USDC".to_string(),
+            name: "USD Coin".to_string(),
+            decimals: 6,
+            logo_uri: None,
+            tags: vec!["stablecoin".to_string()],
+        }
+    }
+
+    const USDC: &str = "0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48";
+
+    fn create_test_token(network_id: &NetworkId) -> Token {
+        Token::new(network_id.clone(), USDC.to_string(), usdc()).expect("valid token")
+    }
+
+    #[tokio::test]
+    async fn should_add_token_and_bump_minor_version() {
+        let network = network(1, "Ethereum Mainnet");
+        let network_repo = Arc::new(InMemoryNetworkRepository::with_networks([network.clone()]));
+        let token_repo = Arc::new(MockTokenRepository::with_tokens(vec![]));
+
+        let use_case = CreateTokenUseCase::new(network_repo, token_repo.clone());
+        let result = use_case.execute(network.id(), USDC.to_string(), usdc()).await.unwrap();
+
+        assert_eq!(result.symbol(), "USDC");
+        assert_eq!(token_repo.version.lock().unwrap().unwrap().to_string(), "1.1.0");
+    }
+
+    #[tokio::test]
+    async fn should_return_conflict_when_address_is_listed() {
+        let network = network(1, "Ethereum Mainnet");
+        let network_repo = Arc::new(InMemoryNetworkRepository::with_networks([network.clone()]));
+        let token_repo = Arc::new(MockTokenRepository::with_tokens(vec![create_test_token(network.id())]));
+
+        let use_case = CreateTokenUseCase::new(network_repo, token_repo);
+        let result = use_case.execute(network.id(), USDC.to_lowercase(), usdc()).await;
+
+        assert!(matches!(result.unwrap_err(), UseCaseError::Conflict(_)));
+    }
+
+    #[tokio::test]
+    async fn should_return_not_found_when_network_does_not_exist() {
+        let network_repo = Arc::new(InMemoryNetworkRepository::new());
+        let token_repo = Arc::new(MockTokenRepository::with_tokens(vec![]));
+
+        let use_case = CreateTokenUseCase::new(network_repo, token_repo);
+        let result = use_case.execute(&NetworkId::new(), USDC.to_string(), usdc()).await;
+
+        assert!(matches!(result.unwrap_err(), UseCaseError::NotFound { .. }));
+    }
+
+    #[tokio::test]
+    async fn should_reject_invalid_metadata() {
+        let network = network(1, "Ethereum Mainnet");
+        let network_repo = Arc::new(InMemoryNetworkRepository::with_networks([network.clone()]));
+        let token_repo = Arc::new(MockTokenRepository::with_tokens(vec![]));
+
+        let use_case = CreateTokenUseCase::new(network_repo, token_repo);
+        let metadata = TokenMetadata {
+            symbol: String::new(),
+            ..usdc()
+        };
+        let result = use_case.execute(network.id(), USDC.to_string(), metadata).await;
+
+        assert!(matches!(
+            result.unwrap_err(),
+            UseCaseError::Domain(DomainError::ValidationError(_))
+        ));
+    }
+}
@@ -0,0 +1,174 @@
+//! Delete Contract Use Case
+//!
+//! Removes a contract from the address book of a network.
+
+use std::sync::Arc;
+
+use crate::domain::gateways::ContractRepository;
+use crate::domain::models::contract::ContractId;
+use crate::domain::models::network::NetworkId;
+use crate::shared::errors::UseCaseError;
+
+/// Use case for removing a contract from a network
+pub struct DeleteContractUseCase {
+    contract_repository: Arc<dyn ContractRepository>,
+}
+
+impl DeleteContractUseCase {
+    /// Create a new DeleteContractUseCase
+    #[must_use]
+    pub fn new(contract_repository: Arc<dyn ContractRepository>) -> Self {
+        Self { contract_repository }
+    }
+
+    /// Execute the use case
+    ///
+    /// # Errors
+    ///
+    /// Returns `UseCaseError::NotFound` if the contract doesn't exist on the network.
+    /// Returns `UseCaseError::Repository` if there's a database error.
+    pub async fn execute(&self, network_id: &NetworkId, contract_id: &ContractId) -> Result<(), UseCaseError> {
+        tracing::info!(network_id = %network_id, contract_id = %contract_id, "Deleting contract");
+
+        let not_found = || {
+            tracing::warn!(network_id = %network_id, contract_id = %contract_id, "Contract not found for deletion");
+            UseCaseError::NotFound {
+                resource: "Contract".to_string(),
+                id: contract_id.to_string(),
+            }
+        };
+
+        self.contract_repository
+            .find_by_id(contract_id)
+            .await?
+            .filter(|contract| contract.network_id() == network_id)
+            .ok_or_else(not_found)?;
+
+        if !self.contract_repository.delete(contract_id).await? {
+            return Err(not_found());
+        }
+
+        tracing::info!(network_id = %network_id, contract_id = %contract_id, "Contract deleted successfully");
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::models::contract::{ContractData, NetworkContract};
+    use crate::shared::errors::RepositoryError;
+    use async_trait::async_trait;
+    use std::sync::Mutex;
+
+    struct MockContractRepository {
+        contracts: Mutex<Vec<NetworkContract>>,
+    }
+
+    impl MockContractRepository {
+        fn with_contracts(contracts: Vec<NetworkContract>) -> Self {
+            Self {
+                contracts: Mutex::new(contracts),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl ContractRepository for MockContractRepository {
+        async fn find_by_id(&self, id: &ContractId) -> Result<Option<NetworkContract>, RepositoryError> {
+            Ok(self.contracts.lock().unwrap().iter().find(|c| c.id() == id).cloned())
+        }
+
+        async fn find_by_network(&self, network_id: &NetworkId) -> Result<Vec<NetworkContract>, RepositoryError> {
+            Ok(self
+                .contracts
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|c| c.network_id() == network_id)
+                .cloned()
+                .collect())
+        }
+
+        async fn find_by_name(&self, name: &str) -> Result<Vec<NetworkContract>, RepositoryError> {
+            Ok(self
+                .contracts
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|c| c.name().eq_ignore_ascii_case(name))
+                .cloned()
+                .collect())
+        }
+
+        async fn create(&self, contract: &NetworkContract) -> Result<NetworkContract, RepositoryError> {
+            self.contracts.lock().unwrap().push(contract.clone());
+            Ok(contract.clone())
+        }
+
+        async fn update(&self, contract: &NetworkContract) -> Result<Option<NetworkContract>, RepositoryError> {
+            let mut contracts = self.contracts.lock().unwrap();
+            let Some(existing) = contracts.iter_mut().find(|c| c.id() == contract.id()) else {
+                return Ok(None);
+            };
+            *existing = contract.clone();
+            Ok(Some(contract.clone()))
+        }
+
+        async fn delete(&self, id: &ContractId) -> Result<bool, RepositoryError> {
+            let mut contracts = self.contracts.lock().unwrap();
+            let before = contracts.len();
+            contracts.retain(|c| c.id() != id);
+            Ok(contracts.len() != before)
+        }
+
+        async fn exists_by_name(
+            &self,
+            network_id: &NetworkId,
+            name: &str,
+            exclude_id: Option<&ContractId>,
+        ) -> Result<bool, RepositoryError> {
+            Ok(self.contracts.lock().unwrap().iter().any(|c| {
+                c.network_id() == network_id && c.name().eq_ignore_ascii_case(name) && Some(c.id()) != exclude_id
+            }))
+        }
+    }
+
+    fn create_test_contract(network_id: &NetworkId, name: &str) -> NetworkContract {
+        NetworkContract::new(
+            network_id.clone(),
+            ContractData {
+                name: name.to_string(),
+                address: "0xcA11bde05977b3631167028862bE2a173976CA11".to_string(),
+                deployment_block: None,
+                abi_ref: None,
+                tags: vec![],
+            },
+        )
+        .expect("valid contract")
+    }
+
+    #[tokio::test]
+    async fn should_delete_contract() {
+        let network_id = NetworkId::new();
+        let contract = create_test_contract(&network_id, "Multicall3");
+        let repo = Arc::new(MockContractRepository::with_contracts(vec![contract.clone()]));
+
+        let use_case = DeleteContractUseCase::new(repo.clone());
+        use_case.execute(&network_id, contract.id()).await.unwrap();
+
+        assert!(repo.contracts.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn should_not_delete_contract_of_other_network() {
+        let contract = create_test_contract(&NetworkId::new(), "Multicall3");
+        let repo = Arc::new(MockContractRepository::with_contracts(vec![contract.clone()]));
+
+        let use_case = DeleteContractUseCase::new(repo.clone());
+        let result = use_case.execute(&NetworkId::new(), contract.id()).await;
+
+        assert!(matches!(result.unwrap_err(), UseCaseError::NotFound { .. }));
+        assert_eq!(repo.contracts.lock().unwrap().len(), 1);
+    }
+}
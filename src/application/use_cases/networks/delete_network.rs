@@ -1,27 +1,39 @@
 //! Delete Network Use Case (Soft Delete)
 //!
-//! Soft deletes a network by setting active=false.
+//! Soft deletes a network by retiring it.
 
 use std::sync::Arc;
 
-use crate::domain::gateways::NetworkRepository;
+use crate::domain::gateways::{NetworkEventPublisher, NetworkRepository};
+use crate::domain::models::event::NetworkEvent;
+use crate::domain::models::lifecycle::NetworkStatus;
 use crate::domain::models::network::NetworkId;
 use crate::shared::errors::UseCaseError;
 
 /// Use case for soft deleting a network
 pub struct DeleteNetworkUseCase {
     network_repository: Arc<dyn NetworkRepository>,
+    event_publisher: Arc<dyn NetworkEventPublisher>,
 }
 
 impl DeleteNetworkUseCase {
     /// Create a new DeleteNetworkUseCase
     #[must_use]
-    pub fn new(network_repository: Arc<dyn NetworkRepository>) -> Self {
-        Self { network_repository }
+    pub fn new(
+        network_repository: Arc<dyn NetworkRepository>,
+        event_publisher: Arc<dyn NetworkEventPublisher>,
+    ) -> Self {
+        Self {
+            network_repository,
+            event_publisher,
+        }
     }
 
     /// Execute the use case
     ///
+    /// Deleting a retired network again succeeds without publishing an event.
+    /// A deleted network is restored by activating it again.
+    ///
     /// # Errors
     ///
     /// Returns `UseCaseError::NotFound` if the network doesn't exist.
@@ -29,15 +41,35 @@ impl DeleteNetworkUseCase {
     pub async fn execute(&self, id: &NetworkId) -> Result<(), UseCaseError> {
         tracing::info!(network_id = %id, "Soft deleting network");
 
-        let deleted = self.network_repository.soft_delete(id).await?;
+        let existing = self
+            .network_repository
+            .find_by_id_for_update(id)
+            .await?
+            .ok_or_else(|| {
+                tracing::warn!(network_id = %id, "Network not found for deletion");
+                UseCaseError::NotFound {
+                    resource: "Network".to_string(),
+                    id: id.to_string(),
+                }
+            })?;
+
+        if existing.status() == NetworkStatus::Retired {
+            tracing::info!(network_id = %id, "Network already retired");
+            return Ok(());
+        }
 
-        if !deleted {
-            tracing::warn!(network_id = %id, "Network not found for deletion");
-            return Err(UseCaseError::NotFound {
+        let retired = existing.clone().transition_to(NetworkStatus::Retired, None)?;
+        let result = self
+            .network_repository
+            .update(&retired)
+            .await?
+            .ok_or_else(|| UseCaseError::NotFound {
                 resource: "Network".to_string(),
                 id: id.to_string(),
-            });
-        }
+            })?;
+        self.event_publisher
+            .publish(NetworkEvent::changed(&existing, result))
+            .await;
 
         tracing::info!(network_id = %id, "Network soft deleted successfully");
         Ok(())
@@ -47,82 +79,73 @@ impl DeleteNetworkUseCase {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::domain::models::network::Network;
-    use crate::shared::errors::RepositoryError;
-    use async_trait::async_trait;
-    use std::sync::Mutex;
+    use crate::application::use_cases::networks::test_support::{
+        network, InMemoryNetworkRepository, RecordingEventPublisher,
+    };
+    use crate::application::use_cases::networks::PartialUpdateNetworkUseCase;
+    use crate::domain::models::event::NetworkEventKind;
+    use crate::domain::models::network::UpdateNetworkData;
 
-    struct MockNetworkRepository {
-        soft_delete_result: Mutex<Option<Result<bool, RepositoryError>>>,
-    }
+    #[tokio::test]
+    async fn should_soft_delete_network_when_found() {
+        let existing = network(1, "Ethereum Mainnet");
+        let repo = Arc::new(InMemoryNetworkRepository::with_networks([existing.clone()]));
 
-    impl MockNetworkRepository {
-        fn new() -> Self {
-            Self {
-                soft_delete_result: Mutex::new(None),
-            }
-        }
+        let use_case = DeleteNetworkUseCase::new(repo.clone(), Arc::new(RecordingEventPublisher::default()));
+        let result = use_case.execute(existing.id()).await;
 
-        fn with_soft_delete(self, result: Result<bool, RepositoryError>) -> Self {
-            *self.soft_delete_result.lock().unwrap() = Some(result);
-            self
-        }
+        assert!(result.is_ok());
+        assert_eq!(repo.get(existing.id()).unwrap().status(), NetworkStatus::Retired);
     }
 
-    #[async_trait]
-    impl NetworkRepository for MockNetworkRepository {
-        async fn find_by_id(&self, _id: &NetworkId) -> Result<Option<Network>, RepositoryError> {
-            Ok(None)
-        }
-
-        async fn find_by_chain_id(&self, _chain_id: i32) -> Result<Option<Network>, RepositoryError> {
-            Ok(None)
-        }
-
-        async fn find_all_active(&self) -> Result<Vec<Network>, RepositoryError> {
-            Ok(vec![])
-        }
-
-        async fn create(&self, network: &Network) -> Result<Network, RepositoryError> {
-            Ok(network.clone())
-        }
-
-        async fn update(&self, _network: &Network) -> Result<Option<Network>, RepositoryError> {
-            Ok(None)
-        }
-
-        async fn soft_delete(&self, _id: &NetworkId) -> Result<bool, RepositoryError> {
-            self.soft_delete_result
-                .lock()
-                .unwrap()
-                .take()
-                .unwrap_or(Ok(false))
-        }
-
-        async fn exists_by_chain_id(
-            &self,
-            _chain_id: i32,
-            _exclude_id: Option<&NetworkId>,
-        ) -> Result<bool, RepositoryError> {
-            Ok(false)
-        }
+    #[tokio::test]
+    async fn should_publish_a_single_event_when_deleted_twice() {
+        let existing = network(1, "Ethereum Mainnet");
+        let repo = Arc::new(InMemoryNetworkRepository::with_networks([existing.clone()]));
+        let publisher = Arc::new(RecordingEventPublisher::default());
+
+        let use_case = DeleteNetworkUseCase::new(repo, publisher.clone());
+        use_case.execute(existing.id()).await.unwrap();
+        use_case.execute(existing.id()).await.unwrap();
+
+        let events = publisher.events.lock().unwrap();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].kind, NetworkEventKind::Deactivated);
     }
 
     #[tokio::test]
-    async fn should_soft_delete_network_when_found() {
-        let repo = Arc::new(MockNetworkRepository::new().with_soft_delete(Ok(true)));
-
-        let use_case = DeleteNetworkUseCase::new(repo);
-        let result = use_case.execute(&NetworkId::new()).await;
-
-        assert!(result.is_ok());
+    async fn should_restore_deleted_network() {
+        let existing = network(1, "Ethereum Mainnet");
+        let repo = Arc::new(InMemoryNetworkRepository::with_networks([existing.clone()]));
+        let publisher = Arc::new(RecordingEventPublisher::default());
+
+        DeleteNetworkUseCase::new(repo.clone(), publisher.clone())
+            .execute(existing.id())
+            .await
+            .unwrap();
+        let restored = PartialUpdateNetworkUseCase::new(repo.clone(), publisher.clone(), Arc::default())
+            .execute(
+                existing.id(),
+                UpdateNetworkData {
+                    active: Some(true),
+                    ..Default::default()
+                },
+            )
+            .await
+            .expect("deleted network should be restored");
+
+        assert_eq!(restored.status(), NetworkStatus::Active);
+        assert_eq!(repo.get(existing.id()).unwrap().chain_id(), existing.chain_id());
+        let events = publisher.events.lock().unwrap();
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[1].kind, NetworkEventKind::Restored);
     }
 
     #[tokio::test]
     async fn should_return_not_found_when_network_does_not_exist() {
-        let repo = Arc::new(MockNetworkRepository::new().with_soft_delete(Ok(false)));
+        let repo = Arc::new(InMemoryNetworkRepository::new());
 
-        let use_case = DeleteNetworkUseCase::new(repo);
+        let use_case = DeleteNetworkUseCase::new(repo, Arc::new(RecordingEventPublisher::default()));
         let result = use_case.execute(&NetworkId::new()).await;
 
         assert!(result.is_err());
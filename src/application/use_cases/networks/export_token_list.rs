@@ -0,0 +1,224 @@
+//! Export Token List Use Case
+//!
+//! Assembles the versioned token list of a network for export.
+
+use std::sync::Arc;
+
+use crate::domain::gateways::{NetworkRepository, TokenRepository};
+use crate::domain::models::network::NetworkId;
+use crate::domain::models::token_list::{TokenList, TokenListVersion};
+use crate::shared::errors::UseCaseError;
+
+/// Use case for exporting the token list of a network
+pub struct ExportTokenListUseCase {
+    network_repository: Arc<dyn NetworkRepository>,
+    token_repository: Arc<dyn TokenRepository>,
+}
+
+impl ExportTokenListUseCase {
+    /// Create a new ExportTokenListUseCase
+    #[must_use]
+    pub fn new(network_repository: Arc<dyn NetworkRepository>, token_repository: Arc<dyn TokenRepository>) -> Self {
+        Self {
+            network_repository,
+            token_repository,
+        }
+    }
+
+    /// Execute the use case
+    ///
+    /// A list that never changed is at version 1.0.0, timestamped with the
+    /// creation of the network.
+    ///
+    /// # Errors
+    ///
+    /// Returns `UseCaseError::NotFound` if the network doesn't exist.
+    /// Returns `UseCaseError::Repository` if there's a database error.
+    pub async fn execute(&self, network_id: &NetworkId) -> Result<TokenList, UseCaseError> {
+        tracing::debug!(network_id = %network_id, "Exporting token list");
+
+        let network = self
+            .network_repository
+            .find_by_id(network_id)
+            .await?
+            .ok_or_else(|| UseCaseError::NotFound {
+                resource: "Network".to_string(),
+                id: network_id.to_string(),
+            })?;
+
+        let tokens = self.token_repository.find_by_network(network_id).await?;
+        let version = self
+            .token_repository
+            .find_version(network_id)
+            .await?
+            .unwrap_or_else(|| TokenListVersion::initial(network.created_at()));
+
+        tracing::debug!(network_id = %network_id, count = tokens.len(), version = %version, "Exported token list");
+        Ok(TokenList {
+            name: network.name().to_string(),
+            chain_id: network.chain_id(),
+            version,
+            tokens,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::use_cases::networks::test_support::{network, InMemoryNetworkRepository};
+    use crate::domain::models::token::{Token, TokenId, TokenMetadata};
+    use crate::domain::models::token_list::TokenListChange;
+    use crate::shared::errors::RepositoryError;
+    use async_trait::async_trait;
+    use chrono::Utc;
+    use std::sync::Mutex;
+
+    struct MockTokenRepository {
+        tokens: Mutex<Vec<Token>>,
+        version: Mutex<Option<TokenListVersion>>,
+    }
+
+    impl MockTokenRepository {
+        fn with_tokens(tokens: Vec<Token>) -> Self {
+            Self {
+                tokens: Mutex::new(tokens),
+                version: Mutex::new(None),
+            }
+        }
+
+        fn bump(&self, change: TokenListChange) -> TokenListVersion {
+            let mut version = self.version.lock().unwrap();
+            let bumped = version
+                .unwrap_or_else(|| TokenListVersion::initial(Utc::now()))
+                .bump(change, Utc::now());
+            *version = Some(bumped);
+            bumped
+        }
+    }
+
+    #[async_trait]
+    impl TokenRepository for MockTokenRepository {
+        async fn find_by_id(&self, id: &TokenId) -> Result<Option<Token>, RepositoryError> {
+            Ok(self.tokens.lock().unwrap().iter().find(|t| t.id() == id).cloned())
+        }
+
+        async fn find_by_network(&self, network_id: &NetworkId) -> Result<Vec<Token>, RepositoryError> {
+            Ok(self
+                .tokens
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|t| t.network_id() == network_id)
+                .cloned()
+                .collect())
+        }
+
+        async fn exists_by_address(&self, network_id: &NetworkId, address: &str) -> Result<bool, RepositoryError> {
+            Ok(self
+                .tokens
+                .lock()
+                .unwrap()
+                .iter()
+                .any(|t| t.network_id() == network_id && t.address().eq_ignore_ascii_case(address)))
+        }
+
+        async fn create(&self, token: &Token) -> Result<Token, RepositoryError> {
+            self.tokens.lock().unwrap().push(token.clone());
+            self.bump(TokenListChange::Minor);
+            Ok(token.clone())
+        }
+
+        async fn update(&self, token: &Token) -> Result<Option<Token>, RepositoryError> {
+            let mut tokens = self.tokens.lock().unwrap();
+            let Some(existing) = tokens.iter_mut().find(|t| t.id() == token.id()) else {
+                return Ok(None);
+            };
+            *existing = token.clone();
+            self.bump(TokenListChange::Patch);
+            Ok(Some(token.clone()))
+        }
+
+        async fn delete(&self, id: &TokenId) -> Result<bool, RepositoryError> {
+            let mut tokens = self.tokens.lock().unwrap();
+            let before = tokens.len();
+            tokens.retain(|t| t.id() != id);
+            let deleted = tokens.len() != before;
+            if deleted {
+                self.bump(TokenListChange::Major);
+            }
+            Ok(deleted)
+        }
+
+        async fn import(
+            &self,
+            _network_id: &NetworkId,
+            created: &[Token],
+            updated: &[Token],
+        ) -> Result<TokenListVersion, RepositoryError> {
+            let mut tokens = self.tokens.lock().unwrap();
+            for token in updated {
+                if let Some(existing) = tokens.iter_mut().find(|t| t.id() == token.id()) {
+                    *existing = token.clone();
+                }
+            }
+            tokens.extend_from_slice(created);
+            let change = if created.is_empty() {
+                TokenListChange::Patch
+            } else {
+                TokenListChange::Minor
+            };
+            Ok(self.bump(change))
+        }
+
+        async fn find_version(&self, _network_id: &NetworkId) -> Result<Option<TokenListVersion>, RepositoryError> {
+            Ok(*self.version.lock().unwrap())
+        }
+    }
+
+    fn usdc() -> TokenMetadata {
+        TokenMetadata {
+            symbol: "USDC".to_string(),
+            name: "USD Coin".to_string(),
+            decimals: 6,
+            logo_uri: None,
+            tags: vec!["stablecoin".to_string()],
+        }
+    }
+
+    const USDC: &str = "0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48";
+
+    fn create_test_token(network_id: &NetworkId) -> Token {
+        Token::new(network_id.clone(), USDC.to_string(), usdc()).expect("valid token")
+    }
+
+    #[tokio::test]
+    async fn should_export_tokens_with_current_version() {
+        let network = network(1, "Ethereum Mainnet");
+        let network_repo = Arc::new(InMemoryNetworkRepository::with_networks([network.clone()]));
+        let token_repo = Arc::new(MockTokenRepository::with_tokens(vec![]));
+        token_repo.create(&create_test_token(network.id())).await.unwrap();
+
+        let use_case = ExportTokenListUseCase::new(network_repo, token_repo);
+        let list = use_case.execute(network.id()).await.unwrap();
+
+        assert_eq!(list.name, "Ethereum Mainnet");
+        assert_eq!(list.chain_id, 1);
+        assert_eq!(list.version.to_string(), "1.1.0");
+        assert_eq!(list.tokens.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn should_start_at_initial_version() {
+        let network = network(1, "Ethereum Mainnet");
+        let network_repo = Arc::new(InMemoryNetworkRepository::with_networks([network.clone()]));
+        let token_repo = Arc::new(MockTokenRepository::with_tokens(vec![]));
+
+        let use_case = ExportTokenListUseCase::new(network_repo, token_repo);
+        let list = use_case.execute(network.id()).await.unwrap();
+
+        assert_eq!(list.version.to_string(), "1.0.0");
+        assert_eq!(list.version.updated_at, network.created_at());
+        assert!(list.tokens.is_empty());
+    }
+}
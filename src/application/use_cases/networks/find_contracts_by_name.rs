@@ -0,0 +1,198 @@
+//! Find Contracts By Name Use Case
+//!
+//! Looks up a contract name across every network, answering "where is
+//! Multicall3 deployed?".
+
+use std::sync::Arc;
+
+use crate::domain::gateways::{ContractRepository, NetworkRepository};
+use crate::domain::models::contract::ContractDeployment;
+use crate::shared::errors::UseCaseError;
+
+/// Use case for finding the deployments of a contract by name
+pub struct FindContractsByNameUseCase {
+    network_repository: Arc<dyn NetworkRepository>,
+    contract_repository: Arc<dyn ContractRepository>,
+}
+
+impl FindContractsByNameUseCase {
+    /// Create a new FindContractsByNameUseCase
+    #[must_use]
+    pub fn new(
+        network_repository: Arc<dyn NetworkRepository>,
+        contract_repository: Arc<dyn ContractRepository>,
+    ) -> Self {
+        Self {
+            network_repository,
+            contract_repository,
+        }
+    }
+
+    /// Execute the use case
+    ///
+    /// The name is matched case-insensitively. Deployments are sorted by chain ID.
+    ///
+    /// # Errors
+    ///
+    /// Returns `UseCaseError::Validation` if the name is blank.
+    /// Returns `UseCaseError::Repository` if there's a database error.
+    pub async fn execute(&self, name: &str) -> Result<Vec<ContractDeployment>, UseCaseError> {
+        let name = name.trim();
+        if name.is_empty() {
+            return Err(UseCaseError::Validation(vec!["name must not be empty".to_string()]));
+        }
+
+        tracing::debug!(name = %name, "Finding contracts by name");
+
+        let contracts = self.contract_repository.find_by_name(name).await?;
+
+        // Names are unique per network, so each contract lives on a different network
+        let mut deployments = Vec::with_capacity(contracts.len());
+        for contract in contracts {
+            let Some(network) = self.network_repository.find_by_id(contract.network_id()).await? else {
+                continue;
+            };
+            deployments.push(ContractDeployment {
+                chain_id: network.chain_id(),
+                network_name: network.name().to_string(),
+                contract,
+            });
+        }
+        deployments.sort_by_key(|deployment| deployment.chain_id);
+
+        tracing::debug!(name = %name, count = deployments.len(), "Found contract deployments");
+        Ok(deployments)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::use_cases::networks::test_support::{network, InMemoryNetworkRepository};
+    use crate::domain::models::contract::{ContractData, ContractId, NetworkContract};
+    use crate::domain::models::network::NetworkId;
+    use crate::shared::errors::RepositoryError;
+    use async_trait::async_trait;
+    use std::sync::Mutex;
+
+    struct MockContractRepository {
+        contracts: Mutex<Vec<NetworkContract>>,
+    }
+
+    impl MockContractRepository {
+        fn with_contracts(contracts: Vec<NetworkContract>) -> Self {
+            Self {
+                contracts: Mutex::new(contracts),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl ContractRepository for MockContractRepository {
+        async fn find_by_id(&self, id: &ContractId) -> Result<Option<NetworkContract>, RepositoryError> {
+            Ok(self.contracts.lock().unwrap().iter().find(|c| c.id() == id).cloned())
+        }
+
+        async fn find_by_network(&self, network_id: &NetworkId) -> Result<Vec<NetworkContract>, RepositoryError> {
+            Ok(self
+                .contracts
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|c| c.network_id() == network_id)
+                .cloned()
+                .collect())
+        }
+
+        async fn find_by_name(&self, name: &str) -> Result<Vec<NetworkContract>, RepositoryError> {
+            Ok(self
+                .contracts
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|c| c.name().eq_ignore_ascii_case(name))
+                .cloned()
+                .collect())
+        }
+
+        async fn create(&self, contract: &NetworkContract) -> Result<NetworkContract, RepositoryError> {
+            self.contracts.lock().unwrap().push(contract.clone());
+            Ok(contract.clone())
+        }
+
+        async fn update(&self, contract: &NetworkContract) -> Result<Option<NetworkContract>, RepositoryError> {
+            let mut contracts = self.contracts.lock().unwrap();
+            let Some(existing) = contracts.iter_mut().find(|c| c.id() == contract.id()) else {
+                return Ok(None);
+            };
+            *existing = contract.clone();
+            Ok(Some(contract.clone()))
+        }
+
+        async fn delete(&self, id: &ContractId) -> Result<bool, RepositoryError> {
+            let mut contracts = self.contracts.lock().unwrap();
+            let before = contracts.len();
+            contracts.retain(|c| c.id() != id);
+            Ok(contracts.len() != before)
+        }
+
+        async fn exists_by_name(
+            &self,
+            network_id: &NetworkId,
+            name: &str,
+            exclude_id: Option<&ContractId>,
+        ) -> Result<bool, RepositoryError> {
+            Ok(self.contracts.lock().unwrap().iter().any(|c| {
+                c.network_id() == network_id && c.name().eq_ignore_ascii_case(name) && Some(c.id()) != exclude_id
+            }))
+        }
+    }
+
+    fn create_test_contract(network_id: &NetworkId, name: &str) -> NetworkContract {
+        NetworkContract::new(
+            network_id.clone(),
+            ContractData {
+                name: name.to_string(),
+                address: "0xcA11bde05977b3631167028862bE2a173976CA11".to_string(),
+                deployment_block: None,
+                abi_ref: None,
+                tags: vec![],
+            },
+        )
+        .expect("valid contract")
+    }
+
+    #[tokio::test]
+    async fn should_find_deployments_across_networks() {
+        let polygon = network(137, "Polygon");
+        let mainnet = network(1, "Ethereum Mainnet");
+        let network_repo = Arc::new(InMemoryNetworkRepository::with_networks([
+            polygon.clone(),
+            mainnet.clone(),
+        ]));
+        let contract_repo = Arc::new(MockContractRepository::with_contracts(vec![
+            create_test_contract(polygon.id(), "Multicall3"),
+            create_test_contract(mainnet.id(), "multicall3"),
+            create_test_contract(mainnet.id(), "ENS Registry"),
+        ]));
+
+        let use_case = FindContractsByNameUseCase::new(network_repo, contract_repo);
+        let result = use_case.execute("MULTICALL3").await.unwrap();
+
+        assert_eq!(result.len(), 2);
+        assert_eq!(result[0].chain_id, 1);
+        assert_eq!(result[0].network_name, "Ethereum Mainnet");
+        assert_eq!(result[1].chain_id, 137);
+    }
+
+    #[tokio::test]
+    async fn should_reject_blank_name() {
+        let network_repo = Arc::new(InMemoryNetworkRepository::new());
+        let contract_repo = Arc::new(MockContractRepository::with_contracts(vec![]));
+
+        let use_case = FindContractsByNameUseCase::new(network_repo, contract_repo);
+        let result = use_case.execute("  ").await;
+
+        assert!(matches!(result.unwrap_err(), UseCaseError::Validation(_)));
+    }
+}
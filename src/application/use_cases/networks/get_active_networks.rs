@@ -1,10 +1,12 @@
 //! Get Active Networks Use Case
 //!
-//! Retrieves all active networks, sorted by name.
+//! Retrieves all active networks, sorted by name, optionally narrowed down
+//! to the networks carrying a set of tags.
 
 use std::sync::Arc;
 
 use crate::domain::gateways::NetworkRepository;
+use crate::domain::models::labels::validate_tags;
 use crate::domain::models::network::Network;
 use crate::shared::errors::UseCaseError;
 
@@ -20,15 +22,17 @@ impl GetActiveNetworksUseCase {
         Self { network_repository }
     }
 
-    /// Execute the use case
+    /// Execute the use case, keeping only the networks carrying every tag in `tags`
     ///
     /// # Errors
     ///
+    /// Returns `UseCaseError::Domain` if a tag is malformed.
     /// Returns `UseCaseError::Repository` if there's a database error.
-    pub async fn execute(&self) -> Result<Vec<Network>, UseCaseError> {
-        tracing::debug!("Getting all active networks");
+    pub async fn execute(&self, tags: &[String]) -> Result<Vec<Network>, UseCaseError> {
+        tracing::debug!(?tags, "Getting all active networks");
 
-        let networks = self.network_repository.find_all_active().await?;
+        validate_tags(tags)?;
+        let networks = self.network_repository.find_all_active(tags).await?;
 
         tracing::debug!(count = networks.len(), "Found active networks");
         Ok(networks)
@@ -38,89 +42,28 @@ impl GetActiveNetworksUseCase {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::domain::models::network::{CreateNetworkData, NetworkId};
-    use crate::shared::errors::RepositoryError;
-    use async_trait::async_trait;
-    use rust_decimal_macros::dec;
-    use std::sync::Mutex;
-
-    struct MockNetworkRepository {
-        find_all_active_result: Mutex<Option<Result<Vec<Network>, RepositoryError>>>,
-    }
-
-    impl MockNetworkRepository {
-        fn new() -> Self {
-            Self {
-                find_all_active_result: Mutex::new(None),
-            }
-        }
-
-        fn with_find_all_active(self, result: Result<Vec<Network>, RepositoryError>) -> Self {
-            *self.find_all_active_result.lock().unwrap() = Some(result);
-            self
-        }
-    }
+    use crate::application::use_cases::networks::test_support::{network_data, InMemoryNetworkRepository};
+    use crate::domain::models::network::CreateNetworkData;
+    use crate::domain::models::url_policy::UrlPolicy;
 
-    #[async_trait]
-    impl NetworkRepository for MockNetworkRepository {
-        async fn find_by_id(&self, _id: &NetworkId) -> Result<Option<Network>, RepositoryError> {
-            Ok(None)
-        }
-
-        async fn find_by_chain_id(&self, _chain_id: i32) -> Result<Option<Network>, RepositoryError> {
-            Ok(None)
-        }
-
-        async fn find_all_active(&self) -> Result<Vec<Network>, RepositoryError> {
-            self.find_all_active_result
-                .lock()
-                .unwrap()
-                .take()
-                .unwrap_or(Ok(vec![]))
-        }
-
-        async fn create(&self, network: &Network) -> Result<Network, RepositoryError> {
-            Ok(network.clone())
-        }
-
-        async fn update(&self, _network: &Network) -> Result<Option<Network>, RepositoryError> {
-            Ok(None)
-        }
-
-        async fn soft_delete(&self, _id: &NetworkId) -> Result<bool, RepositoryError> {
-            Ok(false)
-        }
-
-        async fn exists_by_chain_id(
-            &self,
-            _chain_id: i32,
-            _exclude_id: Option<&NetworkId>,
-        ) -> Result<bool, RepositoryError> {
-            Ok(false)
-        }
+    fn create_test_network(chain_id: i32, name: &str) -> Network {
+        create_tagged_network(chain_id, name, &[])
     }
 
-    fn create_test_network(chain_id: i32, name: &str) -> Network {
-        Network::new(CreateNetworkData {
-            chain_id,
-            name: name.to_string(),
-            rpc_url: "https://example.com".to_string(),
-            other_rpc_urls: vec![],
-            test_net: false,
-            block_explorer_url: "https://explorer.example.com".to_string(),
-            fee_multiplier: dec!(1.0),
-            gas_limit_multiplier: dec!(1.2),
-            default_signer_address: "0x742d35Cc6634C0532925a3b844Bc9e7595f1dEaD".to_string(),
-        })
-        .expect("valid test data")
+    fn create_tagged_network(chain_id: i32, name: &str, tags: &[&str]) -> Network {
+        let data = CreateNetworkData {
+            tags: tags.iter().map(ToString::to_string).collect(),
+            ..network_data(chain_id, name)
+        };
+        Network::new(data, &UrlPolicy::default()).expect("valid test data")
     }
 
     #[tokio::test]
     async fn should_return_empty_list_when_no_active_networks() {
-        let repo = Arc::new(MockNetworkRepository::new().with_find_all_active(Ok(vec![])));
+        let repo = Arc::new(InMemoryNetworkRepository::new());
 
         let use_case = GetActiveNetworksUseCase::new(repo);
-        let result = use_case.execute().await;
+        let result = use_case.execute(&[]).await;
 
         assert!(result.is_ok());
         assert!(result.unwrap().is_empty());
@@ -128,16 +71,42 @@ mod tests {
 
     #[tokio::test]
     async fn should_return_active_networks() {
-        let networks = vec![
-            create_test_network(1, "Ethereum"),
-            create_test_network(137, "Polygon"),
-        ];
-        let repo = Arc::new(MockNetworkRepository::new().with_find_all_active(Ok(networks)));
+        let networks = vec![create_test_network(1, "Ethereum"), create_test_network(137, "Polygon")];
+        let repo = Arc::new(InMemoryNetworkRepository::with_networks(networks));
 
         let use_case = GetActiveNetworksUseCase::new(repo);
-        let result = use_case.execute().await;
+        let result = use_case.execute(&[]).await;
 
         assert!(result.is_ok());
         assert_eq!(result.unwrap().len(), 2);
     }
+
+    #[tokio::test]
+    async fn should_filter_networks_by_tags() {
+        let networks = vec![
+            create_tagged_network(1, "Ethereum", &["l1", "tier:gold"]),
+            create_tagged_network(42161, "Arbitrum One", &["l2", "tier:gold"]),
+            create_tagged_network(137, "Polygon", &["l2"]),
+        ];
+        let repo = Arc::new(InMemoryNetworkRepository::with_networks(networks));
+
+        let use_case = GetActiveNetworksUseCase::new(repo);
+        let result = use_case
+            .execute(&["L2".to_string(), "tier:gold".to_string()])
+            .await
+            .unwrap();
+
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].chain_id(), 42161);
+    }
+
+    #[tokio::test]
+    async fn should_reject_malformed_tag_filter() {
+        let repo = Arc::new(InMemoryNetworkRepository::new());
+
+        let use_case = GetActiveNetworksUseCase::new(repo);
+        let result = use_case.execute(&["not a tag".to_string()]).await;
+
+        assert!(matches!(result.unwrap_err(), UseCaseError::Domain(_)));
+    }
 }
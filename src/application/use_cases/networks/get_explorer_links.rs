@@ -0,0 +1,110 @@
+//! Get Explorer Links Use Case
+//!
+//! Builds canonical block explorer URLs for transactions, addresses and blocks on a network.
+
+use std::sync::Arc;
+
+use crate::domain::gateways::NetworkRepository;
+use crate::domain::models::explorer::{ExplorerLinkQuery, ExplorerLinks};
+use crate::domain::models::network::NetworkId;
+use crate::shared::errors::UseCaseError;
+
+/// Use case for building explorer links for a network
+pub struct GetExplorerLinksUseCase {
+    network_repository: Arc<dyn NetworkRepository>,
+}
+
+impl GetExplorerLinksUseCase {
+    /// Create a new GetExplorerLinksUseCase
+    #[must_use]
+    pub fn new(network_repository: Arc<dyn NetworkRepository>) -> Self {
+        Self { network_repository }
+    }
+
+    /// Execute the use case
+    ///
+    /// # Errors
+    ///
+    /// Returns `UseCaseError::NotFound` if the network doesn't exist.
+    /// Returns `UseCaseError::Domain` if the query is malformed or the explorer has no known URL standard.
+    /// Returns `UseCaseError::Repository` if there's a database error.
+    pub async fn execute(&self, id: &NetworkId, query: &ExplorerLinkQuery) -> Result<ExplorerLinks, UseCaseError> {
+        tracing::debug!(network_id = %id, "Building explorer links");
+
+        let network = self.network_repository.find_by_id(id).await?.ok_or_else(|| {
+            tracing::warn!(network_id = %id, "Network not found for explorer links");
+            UseCaseError::NotFound {
+                resource: "Network".to_string(),
+                id: id.to_string(),
+            }
+        })?;
+
+        let links = ExplorerLinks::build(network.block_explorer_url(), network.explorer(), query)?;
+        Ok(links)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::use_cases::networks::test_support::{network_data, InMemoryNetworkRepository};
+    use crate::domain::models::explorer::{ExplorerKind, ExplorerMetadata};
+    use crate::domain::models::network::{CreateNetworkData, Network};
+    use crate::domain::models::url_policy::UrlPolicy;
+    use crate::shared::errors::DomainError;
+
+    fn create_test_network() -> Network {
+        let data = CreateNetworkData {
+            explorer: ExplorerMetadata {
+                kind: ExplorerKind::Etherscan,
+                api_url: Some("https://api.etherscan.io/api".to_string()),
+                ..Default::default()
+            },
+            ..network_data(1, "Ethereum Mainnet")
+        };
+        Network::new(data, &UrlPolicy::default()).expect("valid test data")
+    }
+
+    #[tokio::test]
+    async fn should_build_links_for_existing_network() {
+        let network = create_test_network();
+        let repo = Arc::new(InMemoryNetworkRepository::with_networks([network.clone()]));
+
+        let use_case = GetExplorerLinksUseCase::new(repo);
+        let query = ExplorerLinkQuery {
+            block: Some("1".to_string()),
+            ..Default::default()
+        };
+        let result = use_case.execute(network.id(), &query).await;
+
+        assert_eq!(result.unwrap().block.as_deref(), Some("https://etherscan.io/block/1"));
+    }
+
+    #[tokio::test]
+    async fn should_return_validation_error_for_malformed_address() {
+        let network = create_test_network();
+        let repo = Arc::new(InMemoryNetworkRepository::with_networks([network.clone()]));
+
+        let use_case = GetExplorerLinksUseCase::new(repo);
+        let query = ExplorerLinkQuery {
+            address: Some("0x123".to_string()),
+            ..Default::default()
+        };
+        let result = use_case.execute(network.id(), &query).await;
+
+        assert!(matches!(
+            result.unwrap_err(),
+            UseCaseError::Domain(DomainError::ValidationError(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn should_return_not_found_when_network_does_not_exist() {
+        let repo = Arc::new(InMemoryNetworkRepository::new());
+
+        let use_case = GetExplorerLinksUseCase::new(repo);
+        let result = use_case.execute(&NetworkId::new(), &ExplorerLinkQuery::default()).await;
+
+        assert!(matches!(result.unwrap_err(), UseCaseError::NotFound { .. }));
+    }
+}
@@ -45,83 +45,12 @@ impl GetNetworkByIdUseCase {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::domain::models::network::CreateNetworkData;
-    use crate::shared::errors::RepositoryError;
-    use async_trait::async_trait;
-    use rust_decimal_macros::dec;
-    use std::sync::Mutex;
-
-    struct MockNetworkRepository {
-        find_by_id_result: Mutex<Option<Result<Option<Network>, RepositoryError>>>,
-    }
-
-    impl MockNetworkRepository {
-        fn new() -> Self {
-            Self {
-                find_by_id_result: Mutex::new(None),
-            }
-        }
-
-        fn with_find_by_id(self, result: Result<Option<Network>, RepositoryError>) -> Self {
-            *self.find_by_id_result.lock().unwrap() = Some(result);
-            self
-        }
-    }
-
-    #[async_trait]
-    impl NetworkRepository for MockNetworkRepository {
-        async fn find_by_id(&self, _id: &NetworkId) -> Result<Option<Network>, RepositoryError> {
-            self.find_by_id_result.lock().unwrap().take().unwrap_or(Ok(None))
-        }
-
-        async fn find_by_chain_id(&self, _chain_id: i32) -> Result<Option<Network>, RepositoryError> {
-            Ok(None)
-        }
-
-        async fn find_all_active(&self) -> Result<Vec<Network>, RepositoryError> {
-            Ok(vec![])
-        }
-
-        async fn create(&self, network: &Network) -> Result<Network, RepositoryError> {
-            Ok(network.clone())
-        }
-
-        async fn update(&self, _network: &Network) -> Result<Option<Network>, RepositoryError> {
-            Ok(None)
-        }
-
-        async fn soft_delete(&self, _id: &NetworkId) -> Result<bool, RepositoryError> {
-            Ok(false)
-        }
-
-        async fn exists_by_chain_id(
-            &self,
-            _chain_id: i32,
-            _exclude_id: Option<&NetworkId>,
-        ) -> Result<bool, RepositoryError> {
-            Ok(false)
-        }
-    }
-
-    fn create_test_network() -> Network {
-        Network::new(CreateNetworkData {
-            chain_id: 1,
-            name: "Ethereum Mainnet".to_string(),
-            rpc_url: "https://mainnet.infura.io".to_string(),
-            other_rpc_urls: vec![],
-            test_net: false,
-            block_explorer_url: "https://etherscan.io".to_string(),
-            fee_multiplier: dec!(1.0),
-            gas_limit_multiplier: dec!(1.2),
-            default_signer_address: "0x742d35Cc6634C0532925a3b844Bc9e7595f1dEaD".to_string(),
-        })
-        .expect("valid test data")
-    }
+    use crate::application::use_cases::networks::test_support::{network, InMemoryNetworkRepository};
 
     #[tokio::test]
     async fn should_return_network_when_found() {
-        let network = create_test_network();
-        let repo = Arc::new(MockNetworkRepository::new().with_find_by_id(Ok(Some(network.clone()))));
+        let network = network(1, "Ethereum Mainnet");
+        let repo = Arc::new(InMemoryNetworkRepository::with_networks([network.clone()]));
 
         let use_case = GetNetworkByIdUseCase::new(repo);
         let result = use_case.execute(network.id()).await;
@@ -132,7 +61,7 @@ mod tests {
 
     #[tokio::test]
     async fn should_return_not_found_when_network_does_not_exist() {
-        let repo = Arc::new(MockNetworkRepository::new().with_find_by_id(Ok(None)));
+        let repo = Arc::new(InMemoryNetworkRepository::new());
 
         let use_case = GetNetworkByIdUseCase::new(repo);
         let result = use_case.execute(&NetworkId::new()).await;
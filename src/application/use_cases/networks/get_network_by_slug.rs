@@ -0,0 +1,78 @@
+//! Get Network By Slug Use Case
+//!
+//! Retrieves a single network by its slug or one of its aliases.
+
+use std::sync::Arc;
+
+use crate::domain::gateways::NetworkRepository;
+use crate::domain::models::network::Network;
+use crate::shared::errors::UseCaseError;
+
+/// Use case for getting a network by slug or alias
+pub struct GetNetworkBySlugUseCase {
+    network_repository: Arc<dyn NetworkRepository>,
+}
+
+impl GetNetworkBySlugUseCase {
+    /// Create a new GetNetworkBySlugUseCase
+    #[must_use]
+    pub fn new(network_repository: Arc<dyn NetworkRepository>) -> Self {
+        Self { network_repository }
+    }
+
+    /// Execute the use case
+    ///
+    /// # Errors
+    ///
+    /// Returns `UseCaseError::NotFound` if no network answers to the slug.
+    /// Returns `UseCaseError::Repository` if there's a database error.
+    pub async fn execute(&self, slug: &str) -> Result<Network, UseCaseError> {
+        tracing::debug!(slug = %slug, "Getting network by slug");
+
+        let network = self.network_repository.find_by_slug(slug).await?.ok_or_else(|| {
+            tracing::warn!(slug = %slug, "Network not found");
+            UseCaseError::NotFound {
+                resource: "Network".to_string(),
+                id: slug.to_string(),
+            }
+        })?;
+
+        tracing::debug!(network_id = %network.id(), slug = %slug, "Network found");
+        Ok(network)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::use_cases::networks::test_support::{network_data, InMemoryNetworkRepository};
+    use crate::domain::models::network::CreateNetworkData;
+    use crate::domain::models::url_policy::UrlPolicy;
+
+    fn create_test_network() -> Network {
+        let data = CreateNetworkData {
+            slug: Some("polygon".to_string()),
+            aliases: vec!["matic".to_string(), "polygon-pos".to_string()],
+            ..network_data(137, "Polygon")
+        };
+        Network::new(data, &UrlPolicy::default()).expect("valid test data")
+    }
+
+    #[tokio::test]
+    async fn should_return_network_by_slug_or_alias() {
+        let repo = Arc::new(InMemoryNetworkRepository::with_networks([create_test_network()]));
+        let use_case = GetNetworkBySlugUseCase::new(repo);
+
+        assert_eq!(use_case.execute("polygon").await.unwrap().chain_id(), 137);
+        assert_eq!(use_case.execute("MATIC").await.unwrap().chain_id(), 137);
+    }
+
+    #[tokio::test]
+    async fn should_return_not_found_for_unknown_slug() {
+        let repo = Arc::new(InMemoryNetworkRepository::with_networks([create_test_network()]));
+        let use_case = GetNetworkBySlugUseCase::new(repo);
+        let result = use_case.execute("eth-mainnet").await;
+
+        assert!(matches!(result.unwrap_err(), UseCaseError::NotFound { .. }));
+    }
+}
@@ -0,0 +1,368 @@
+//! Import Tokens Use Case
+//!
+//! Bulk adds or updates the tokens of a network, matching entries to listed
+//! tokens by address. Tokens missing from the import are kept.
+
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+use crate::domain::gateways::{NetworkRepository, TokenRepository};
+use crate::domain::models::network::NetworkId;
+use crate::domain::models::token::{ImportedToken, Token, MAX_IMPORT_TOKENS};
+use crate::domain::models::token_list::{TokenImportSummary, TokenListVersion};
+use crate::shared::errors::{DomainError, UseCaseError};
+
+/// Use case for bulk importing tokens into a network
+pub struct ImportTokensUseCase {
+    network_repository: Arc<dyn NetworkRepository>,
+    token_repository: Arc<dyn TokenRepository>,
+}
+
+impl ImportTokensUseCase {
+    /// Create a new ImportTokensUseCase
+    #[must_use]
+    pub fn new(network_repository: Arc<dyn NetworkRepository>, token_repository: Arc<dyn TokenRepository>) -> Self {
+        Self {
+            network_repository,
+            token_repository,
+        }
+    }
+
+    /// Execute the use case
+    ///
+    /// Entries declaring another `chain_id` are skipped, so a multi-chain token
+    /// list can be imported as-is. The import is all-or-nothing: any invalid
+    /// entry rejects the whole batch.
+    ///
+    /// # Errors
+    ///
+    /// Returns `UseCaseError::NotFound` if the network doesn't exist.
+    /// Returns `UseCaseError::Validation` if the batch is empty or too large, or listing
+    /// every invalid or duplicated entry.
+    /// Returns `UseCaseError::Repository` if there's a database error.
+    pub async fn execute(
+        &self,
+        network_id: &NetworkId,
+        entries: Vec<ImportedToken>,
+    ) -> Result<TokenImportSummary, UseCaseError> {
+        tracing::info!(network_id = %network_id, count = entries.len(), "Importing tokens");
+
+        if entries.is_empty() || entries.len() > MAX_IMPORT_TOKENS {
+            return Err(UseCaseError::Validation(vec![format!(
+                "tokens must have between 1 and {MAX_IMPORT_TOKENS} items"
+            )]));
+        }
+
+        let network = self
+            .network_repository
+            .find_by_id(network_id)
+            .await?
+            .ok_or_else(|| UseCaseError::NotFound {
+                resource: "Network".to_string(),
+                id: network_id.to_string(),
+            })?;
+
+        let existing: HashMap<String, Token> = self
+            .token_repository
+            .find_by_network(network_id)
+            .await?
+            .into_iter()
+            .map(|token| (token.address().to_lowercase(), token))
+            .collect();
+
+        let mut created = Vec::new();
+        let mut updated = Vec::new();
+        let mut unchanged = 0;
+        let mut skipped = 0;
+        let mut errors = Vec::new();
+        let mut seen = HashSet::new();
+
+        for (index, entry) in entries.into_iter().enumerate() {
+            if entry.chain_id.is_some_and(|chain_id| chain_id != network.chain_id()) {
+                skipped += 1;
+                continue;
+            }
+
+            if !seen.insert(entry.address.to_lowercase()) {
+                errors.push(format!("tokens[{index}]: duplicate address {}", entry.address));
+                continue;
+            }
+
+            let result = match existing.get(&entry.address.to_lowercase()) {
+                Some(token) if !token.differs_from(&entry.metadata) => {
+                    unchanged += 1;
+                    continue;
+                }
+                Some(token) => token
+                    .clone()
+                    .with_metadata(entry.metadata)
+                    .map(|token| updated.push(token)),
+                None => Token::new(network_id.clone(), entry.address, entry.metadata).map(|token| created.push(token)),
+            };
+
+            if let Err(e) = result {
+                let message = match e {
+                    DomainError::ValidationError(message) => message,
+                    other => other.to_string(),
+                };
+                errors.push(format!("tokens[{index}]: {message}"));
+            }
+        }
+
+        if !errors.is_empty() {
+            tracing::warn!(network_id = %network_id, errors = errors.len(), "Token import rejected");
+            return Err(UseCaseError::Validation(errors));
+        }
+
+        let version = if created.is_empty() && updated.is_empty() {
+            self.token_repository
+                .find_version(network_id)
+                .await?
+                .unwrap_or_else(|| TokenListVersion::initial(network.created_at()))
+        } else {
+            self.token_repository.import(network_id, &created, &updated).await?
+        };
+
+        tracing::info!(
+            network_id = %network_id,
+            created = created.len(),
+            updated = updated.len(),
+            unchanged,
+            skipped,
+            version = %version,
+            "Tokens imported successfully"
+        );
+
+        Ok(TokenImportSummary {
+            created: created.len(),
+            updated: updated.len(),
+            unchanged,
+            skipped,
+            version,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::use_cases::networks::test_support::{network, InMemoryNetworkRepository};
+    use crate::domain::models::token::{TokenId, TokenMetadata};
+    use crate::domain::models::token_list::TokenListChange;
+    use crate::shared::errors::RepositoryError;
+    use async_trait::async_trait;
+    use chrono::Utc;
+    use std::sync::Mutex;
+
+    struct MockTokenRepository {
+        tokens: Mutex<Vec<Token>>,
+        version: Mutex<Option<TokenListVersion>>,
+    }
+
+    impl MockTokenRepository {
+        fn with_tokens(tokens: Vec<Token>) -> Self {
+            Self {
+                tokens: Mutex::new(tokens),
+                version: Mutex::new(None),
+            }
+        }
+
+        fn bump(&self, change: TokenListChange) -> TokenListVersion {
+            let mut version = self.version.lock().unwrap();
+            let bumped = version
+                .unwrap_or_else(|| TokenListVersion::initial(Utc::now()))
+                .bump(change, Utc::now());
+            *version = Some(bumped);
+            bumped
+        }
+    }
+
+    #[async_trait]
+    impl TokenRepository for MockTokenRepository {
+        async fn find_by_id(&self, id: &TokenId) -> Result<Option<Token>, RepositoryError> {
+            Ok(self.tokens.lock().unwrap().iter().find(|t| t.id() == id).cloned())
+        }
+
+        async fn find_by_network(&self, network_id: &NetworkId) -> Result<Vec<Token>, RepositoryError> {
+            Ok(self
+                .tokens
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|t| t.network_id() == network_id)
+                .cloned()
+                .collect())
+        }
+
+        async fn exists_by_address(&self, network_id: &NetworkId, address: &str) -> Result<bool, RepositoryError> {
+            Ok(self
+                .tokens
+                .lock()
+                .unwrap()
+                .iter()
+                .any(|t| t.network_id() == network_id && t.address().eq_ignore_ascii_case(address)))
+        }
+
+        async fn create(&self, token: &Token) -> Result<Token, RepositoryError> {
+            self.tokens.lock().unwrap().push(token.clone());
+            self.bump(TokenListChange::Minor);
+            Ok(token.clone())
+        }
+
+        async fn update(&self, token: &Token) -> Result<Option<Token>, RepositoryError> {
+            let mut tokens = self.tokens.lock().unwrap();
+            let Some(existing) = tokens.iter_mut().find(|t| t.id() == token.id()) else {
+                return Ok(None);
+            };
+            *existing = token.clone();
+            self.bump(TokenListChange::Patch);
+            Ok(Some(token.clone()))
+        }
+
+        async fn delete(&self, id: &TokenId) -> Result<bool, RepositoryError> {
+            let mut tokens = self.tokens.lock().unwrap();
+            let before = tokens.len();
+            tokens.retain(|t| t.id() != id);
+            let deleted = tokens.len() != before;
+            if deleted {
+                self.bump(TokenListChange::Major);
+            }
+            Ok(deleted)
+        }
+
+        async fn import(
+            &self,
+            _network_id: &NetworkId,
+            created: &[Token],
+            updated: &[Token],
+        ) -> Result<TokenListVersion, RepositoryError> {
+            let mut tokens = self.tokens.lock().unwrap();
+            for token in updated {
+                if let Some(existing) = tokens.iter_mut().find(|t| t.id() == token.id()) {
+                    *existing = token.clone();
+                }
+            }
+            tokens.extend_from_slice(created);
+            let change = if created.is_empty() {
+                TokenListChange::Patch
+            } else {
+                TokenListChange::Minor
+            };
+            Ok(self.bump(change))
+        }
+
+        async fn find_version(&self, _network_id: &NetworkId) -> Result<Option<TokenListVersion>, RepositoryError> {
+            Ok(*self.version.lock().unwrap())
+        }
+    }
+
+    fn usdc() -> TokenMetadata {
+        TokenMetadata {
+            symbol: "USDC".to_string(),
+            name: "USD Coin".to_string(),
+            decimals: 6,
+            logo_uri: None,
+            tags: vec!["stablecoin".to_string()],
+        }
+    }
+
+    const USDC: &str = "0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48";
+
+    fn create_test_token(network_id: &NetworkId) -> Token {
+        Token::new(network_id.clone(), USDC.to_string(), usdc()).expect("valid token")
+    }
+
+    const DAI: &str = "0x6B175474E89094C44Da98b954EedeAC495271d0F";
+
+    fn entry(chain_id: Option<i32>, address: &str, metadata: TokenMetadata) -> ImportedToken {
+        ImportedToken {
+            chain_id,
+            address: address.to_string(),
+            metadata,
+        }
+    }
+
+    fn dai() -> TokenMetadata {
+        TokenMetadata {
+            symbol: "DAI".to_string(),
+            name: "Dai Stablecoin".to_string(),
+            decimals: 18,
+            logo_uri: None,
+            tags: vec![],
+        }
+    }
+
+    #[tokio::test]
+    async fn should_create_update_and_skip_entries() {
+        let network = network(1, "Ethereum Mainnet");
+        let network_repo = Arc::new(InMemoryNetworkRepository::with_networks([network.clone()]));
+        let token_repo = Arc::new(MockTokenRepository::with_tokens(vec![create_test_token(network.id())]));
+
+        let renamed_usdc = TokenMetadata {
+            name: "USD Coin v2".to_string(),
+            ..usdc()
+        };
+        let use_case = ImportTokensUseCase::new(network_repo, token_repo.clone());
+        let summary = use_case
+            .execute(
+                network.id(),
+                vec![
+                    entry(Some(1), &USDC.to_lowercase(), renamed_usdc),
+                    entry(None, DAI, dai()),
+                    entry(Some(137), DAI, dai()),
+                ],
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(summary.created, 1);
+        assert_eq!(summary.updated, 1);
+        assert_eq!(summary.unchanged, 0);
+        assert_eq!(summary.skipped, 1);
+        assert_eq!(summary.version.to_string(), "1.1.0");
+        assert_eq!(token_repo.tokens.lock().unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn should_keep_version_when_nothing_changes() {
+        let network = network(1, "Ethereum Mainnet");
+        let network_repo = Arc::new(InMemoryNetworkRepository::with_networks([network.clone()]));
+        let token_repo = Arc::new(MockTokenRepository::with_tokens(vec![create_test_token(network.id())]));
+
+        let use_case = ImportTokensUseCase::new(network_repo, token_repo);
+        let summary = use_case
+            .execute(network.id(), vec![entry(None, USDC, usdc())])
+            .await
+            .unwrap();
+
+        assert_eq!(summary.unchanged, 1);
+        assert_eq!(summary.version.to_string(), "1.0.0");
+    }
+
+    #[tokio::test]
+    async fn should_reject_whole_batch_with_every_error() {
+        let network = network(1, "Ethereum Mainnet");
+        let network_repo = Arc::new(InMemoryNetworkRepository::with_networks([network.clone()]));
+        let token_repo = Arc::new(MockTokenRepository::with_tokens(vec![]));
+
+        let use_case = ImportTokensUseCase::new(network_repo, token_repo.clone());
+        let result = use_case
+            .execute(
+                network.id(),
+                vec![
+                    entry(None, DAI, dai()),
+                    entry(None, &DAI.to_lowercase(), dai()),
+                    entry(None, "0x1234", dai()),
+                ],
+            )
+            .await;
+
+        let UseCaseError::Validation(errors) = result.unwrap_err() else {
+            panic!("expected validation error");
+        };
+        assert_eq!(errors.len(), 2);
+        assert!(errors[0].starts_with("tokens[1]: duplicate address"));
+        assert!(errors[1].starts_with("tokens[2]:"));
+        assert!(token_repo.tokens.lock().unwrap().is_empty());
+    }
+}
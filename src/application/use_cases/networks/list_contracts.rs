@@ -0,0 +1,176 @@
+//! List Contracts Use Case
+//!
+//! Retrieves the contract address book of a network.
+
+use std::sync::Arc;
+
+use crate::domain::gateways::{ContractRepository, NetworkRepository};
+use crate::domain::models::contract::NetworkContract;
+use crate::domain::models::network::NetworkId;
+use crate::shared::errors::UseCaseError;
+
+/// Use case for listing the contracts of a network
+pub struct ListContractsUseCase {
+    network_repository: Arc<dyn NetworkRepository>,
+    contract_repository: Arc<dyn ContractRepository>,
+}
+
+impl ListContractsUseCase {
+    /// Create a new ListContractsUseCase
+    #[must_use]
+    pub fn new(
+        network_repository: Arc<dyn NetworkRepository>,
+        contract_repository: Arc<dyn ContractRepository>,
+    ) -> Self {
+        Self {
+            network_repository,
+            contract_repository,
+        }
+    }
+
+    /// Execute the use case
+    ///
+    /// # Errors
+    ///
+    /// Returns `UseCaseError::NotFound` if the network doesn't exist.
+    /// Returns `UseCaseError::Repository` if there's a database error.
+    pub async fn execute(&self, network_id: &NetworkId) -> Result<Vec<NetworkContract>, UseCaseError> {
+        tracing::debug!(network_id = %network_id, "Listing contracts");
+
+        if self.network_repository.find_by_id(network_id).await?.is_none() {
+            return Err(UseCaseError::NotFound {
+                resource: "Network".to_string(),
+                id: network_id.to_string(),
+            });
+        }
+
+        let contracts = self.contract_repository.find_by_network(network_id).await?;
+
+        tracing::debug!(network_id = %network_id, count = contracts.len(), "Retrieved contracts");
+        Ok(contracts)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::use_cases::networks::test_support::{network, InMemoryNetworkRepository};
+    use crate::domain::models::contract::{ContractData, ContractId};
+    use crate::shared::errors::RepositoryError;
+    use async_trait::async_trait;
+    use std::sync::Mutex;
+
+    struct MockContractRepository {
+        contracts: Mutex<Vec<NetworkContract>>,
+    }
+
+    impl MockContractRepository {
+        fn with_contracts(contracts: Vec<NetworkContract>) -> Self {
+            Self {
+                contracts: Mutex::new(contracts),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl ContractRepository for MockContractRepository {
+        async fn find_by_id(&self, id: &ContractId) -> Result<Option<NetworkContract>, RepositoryError> {
+            Ok(self.contracts.lock().unwrap().iter().find(|c| c.id() == id).cloned())
+        }
+
+        async fn find_by_network(&self, network_id: &NetworkId) -> Result<Vec<NetworkContract>, RepositoryError> {
+            Ok(self
+                .contracts
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|c| c.network_id() == network_id)
+                .cloned()
+                .collect())
+        }
+
+        async fn find_by_name(&self, name: &str) -> Result<Vec<NetworkContract>, RepositoryError> {
+            Ok(self
+                .contracts
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|c| c.name().eq_ignore_ascii_case(name))
+                .cloned()
+                .collect())
+        }
+
+        async fn create(&self, contract: &NetworkContract) -> Result<NetworkContract, RepositoryError> {
+            self.contracts.lock().unwrap().push(contract.clone());
+            Ok(contract.clone())
+        }
+
+        async fn update(&self, contract: &NetworkContract) -> Result<Option<NetworkContract>, RepositoryError> {
+            let mut contracts = self.contracts.lock().unwrap();
+            let Some(existing) = contracts.iter_mut().find(|c| c.id() == contract.id()) else {
+                return Ok(None);
+            };
+            *existing = contract.clone();
+            Ok(Some(contract.clone()))
+        }
+
+        async fn delete(&self, id: &ContractId) -> Result<bool, RepositoryError> {
+            let mut contracts = self.contracts.lock().unwrap();
+            let before = contracts.len();
+            contracts.retain(|c| c.id() != id);
+            Ok(contracts.len() != before)
+        }
+
+        async fn exists_by_name(
+            &self,
+            network_id: &NetworkId,
+            name: &str,
+            exclude_id: Option<&ContractId>,
+        ) -> Result<bool, RepositoryError> {
+            Ok(self.contracts.lock().unwrap().iter().any(|c| {
+                c.network_id() == network_id && c.name().eq_ignore_ascii_case(name) && Some(c.id()) != exclude_id
+            }))
+        }
+    }
+
+    fn create_test_contract(network_id: &NetworkId, name: &str) -> NetworkContract {
+        NetworkContract::new(
+            network_id.clone(),
+            ContractData {
+                name: name.to_string(),
+                address: "0xcA11bde05977b3631167028862bE2a173976CA11".to_string(),
+                deployment_block: None,
+                abi_ref: None,
+                tags: vec![],
+            },
+        )
+        .expect("valid contract")
+    }
+
+    #[tokio::test]
+    async fn should_return_contracts_of_network() {
+        let network = network(1, "Ethereum Mainnet");
+        let network_repo = Arc::new(InMemoryNetworkRepository::with_networks([network.clone()]));
+        let contract_repo = Arc::new(MockContractRepository::with_contracts(vec![
+            create_test_contract(network.id(), "Multicall3"),
+            create_test_contract(&NetworkId::new(), "Multicall3"),
+        ]));
+
+        let use_case = ListContractsUseCase::new(network_repo, contract_repo);
+        let result = use_case.execute(network.id()).await.unwrap();
+
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].network_id(), network.id());
+    }
+
+    #[tokio::test]
+    async fn should_return_not_found_when_network_does_not_exist() {
+        let network_repo = Arc::new(InMemoryNetworkRepository::new());
+        let contract_repo = Arc::new(MockContractRepository::with_contracts(vec![]));
+
+        let use_case = ListContractsUseCase::new(network_repo, contract_repo);
+        let result = use_case.execute(&NetworkId::new()).await;
+
+        assert!(matches!(result.unwrap_err(), UseCaseError::NotFound { .. }));
+    }
+}
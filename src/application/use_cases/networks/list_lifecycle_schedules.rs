@@ -0,0 +1,133 @@
+//! List Lifecycle Schedules Use Case
+//!
+//! Retrieves the scheduled lifecycle changes of a network, including completed
+//! and cancelled ones, as an audit trail.
+
+use std::sync::Arc;
+
+use crate::domain::gateways::{NetworkRepository, ScheduleRepository};
+use crate::domain::models::network::NetworkId;
+use crate::domain::models::schedule::LifecycleSchedule;
+use crate::shared::errors::UseCaseError;
+
+/// Use case for listing the lifecycle schedules of a network
+pub struct ListLifecycleSchedulesUseCase {
+    network_repository: Arc<dyn NetworkRepository>,
+    schedule_repository: Arc<dyn ScheduleRepository>,
+}
+
+impl ListLifecycleSchedulesUseCase {
+    /// Create a new ListLifecycleSchedulesUseCase
+    #[must_use]
+    pub fn new(
+        network_repository: Arc<dyn NetworkRepository>,
+        schedule_repository: Arc<dyn ScheduleRepository>,
+    ) -> Self {
+        Self {
+            network_repository,
+            schedule_repository,
+        }
+    }
+
+    /// Execute the use case
+    ///
+    /// # Errors
+    ///
+    /// Returns `UseCaseError::NotFound` if the network doesn't exist.
+    /// Returns `UseCaseError::Repository` if there's a database error.
+    pub async fn execute(&self, network_id: &NetworkId) -> Result<Vec<LifecycleSchedule>, UseCaseError> {
+        tracing::debug!(network_id = %network_id, "Listing lifecycle schedules");
+
+        if self.network_repository.find_by_id(network_id).await?.is_none() {
+            return Err(UseCaseError::NotFound {
+                resource: "Network".to_string(),
+                id: network_id.to_string(),
+            });
+        }
+
+        let schedules = self.schedule_repository.find_by_network(network_id).await?;
+
+        tracing::debug!(network_id = %network_id, count = schedules.len(), "Retrieved lifecycle schedules");
+        Ok(schedules)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::use_cases::networks::test_support::{network, InMemoryNetworkRepository};
+    use crate::domain::models::schedule::{ScheduleId, ScheduledAction};
+    use crate::shared::errors::RepositoryError;
+    use async_trait::async_trait;
+    use chrono::{DateTime, Utc};
+
+    struct MockScheduleRepository {
+        schedules: Vec<LifecycleSchedule>,
+    }
+
+    #[async_trait]
+    impl ScheduleRepository for MockScheduleRepository {
+        async fn find_by_id(&self, _id: &ScheduleId) -> Result<Option<LifecycleSchedule>, RepositoryError> {
+            Ok(None)
+        }
+
+        async fn find_by_network(&self, network_id: &NetworkId) -> Result<Vec<LifecycleSchedule>, RepositoryError> {
+            Ok(self
+                .schedules
+                .iter()
+                .filter(|schedule| schedule.network_id() == network_id)
+                .cloned()
+                .collect())
+        }
+
+        async fn create(&self, schedule: &LifecycleSchedule) -> Result<LifecycleSchedule, RepositoryError> {
+            Ok(schedule.clone())
+        }
+
+        async fn complete(&self, schedule: &LifecycleSchedule) -> Result<Option<LifecycleSchedule>, RepositoryError> {
+            Ok(Some(schedule.clone()))
+        }
+
+        async fn claim_due(&self, _now: DateTime<Utc>, _limit: u32) -> Result<Vec<LifecycleSchedule>, RepositoryError> {
+            Ok(vec![])
+        }
+    }
+
+    fn create_test_schedule(network_id: &NetworkId) -> LifecycleSchedule {
+        LifecycleSchedule::new(
+            network_id.clone(),
+            ScheduledAction::Retire,
+            Utc::now() + chrono::Duration::days(1),
+            "user-1".to_string(),
+        )
+        .expect("valid schedule")
+    }
+
+    #[tokio::test]
+    async fn should_return_schedules_of_network() {
+        let network = network(1, "Ethereum Mainnet");
+        let schedules = vec![
+            create_test_schedule(network.id()),
+            create_test_schedule(&NetworkId::new()),
+        ];
+        let network_repo = Arc::new(InMemoryNetworkRepository::with_networks([network.clone()]));
+        let schedule_repo = Arc::new(MockScheduleRepository { schedules });
+
+        let use_case = ListLifecycleSchedulesUseCase::new(network_repo, schedule_repo);
+        let result = use_case.execute(network.id()).await.unwrap();
+
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].network_id(), network.id());
+    }
+
+    #[tokio::test]
+    async fn should_return_not_found_when_network_does_not_exist() {
+        let network_repo = Arc::new(InMemoryNetworkRepository::new());
+        let schedule_repo = Arc::new(MockScheduleRepository { schedules: vec![] });
+
+        let use_case = ListLifecycleSchedulesUseCase::new(network_repo, schedule_repo);
+        let result = use_case.execute(&NetworkId::new()).await;
+
+        assert!(matches!(result.unwrap_err(), UseCaseError::NotFound { .. }));
+    }
+}
@@ -0,0 +1,98 @@
+//! List Network Ancestors Use Case
+//!
+//! Retrieves the chain of parents above a network, up to the root network.
+
+use std::sync::Arc;
+
+use crate::domain::gateways::NetworkRepository;
+use crate::domain::models::network::{Network, NetworkId};
+use crate::shared::errors::UseCaseError;
+
+/// Use case for listing the ancestors of a network
+pub struct ListNetworkAncestorsUseCase {
+    network_repository: Arc<dyn NetworkRepository>,
+}
+
+impl ListNetworkAncestorsUseCase {
+    /// Create a new ListNetworkAncestorsUseCase
+    #[must_use]
+    pub fn new(network_repository: Arc<dyn NetworkRepository>) -> Self {
+        Self { network_repository }
+    }
+
+    /// Execute the use case
+    ///
+    /// Networks are returned nearest first.
+    ///
+    /// # Errors
+    ///
+    /// Returns `UseCaseError::NotFound` if the network doesn't exist.
+    /// Returns `UseCaseError::Repository` if there's a database error.
+    pub async fn execute(&self, id: &NetworkId) -> Result<Vec<Network>, UseCaseError> {
+        tracing::debug!(network_id = %id, "Listing network ancestors");
+
+        if self.network_repository.find_by_id(id).await?.is_none() {
+            return Err(UseCaseError::NotFound {
+                resource: "Network".to_string(),
+                id: id.to_string(),
+            });
+        }
+
+        let networks = self.network_repository.find_ancestors(id).await?;
+
+        tracing::debug!(network_id = %id, count = networks.len(), "Retrieved network ancestors");
+        Ok(networks)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::use_cases::networks::test_support::{network_data, InMemoryNetworkRepository};
+    use crate::domain::models::network::CreateNetworkData;
+    use crate::domain::models::relation::{NetworkParent, NetworkRelationKind};
+    use crate::domain::models::url_policy::UrlPolicy;
+
+    fn create_test_network(chain_id: i32, parent: Option<NetworkParent>) -> Network {
+        let data = CreateNetworkData {
+            parent,
+            ..network_data(chain_id, &format!("Chain {chain_id}"))
+        };
+        Network::new(data, &UrlPolicy::default()).expect("valid test data")
+    }
+
+    fn child_of(parent: &Network, kind: NetworkRelationKind) -> Option<NetworkParent> {
+        Some(NetworkParent {
+            network_id: parent.id().clone(),
+            kind,
+        })
+    }
+
+    #[tokio::test]
+    async fn should_list_ancestors_nearest_first() {
+        let mainnet = create_test_network(1, None);
+        let arbitrum = create_test_network(42161, child_of(&mainnet, NetworkRelationKind::Rollup));
+        let l3 = create_test_network(660_279, child_of(&arbitrum, NetworkRelationKind::Rollup));
+        let repo = Arc::new(InMemoryNetworkRepository::with_networks([
+            mainnet,
+            arbitrum,
+            l3.clone(),
+        ]));
+
+        let use_case = ListNetworkAncestorsUseCase::new(repo.clone());
+        let ancestors = use_case.execute(l3.id()).await.unwrap();
+
+        let chain_ids: Vec<i32> = ancestors.iter().map(Network::chain_id).collect();
+        assert_eq!(chain_ids, [42161, 1]);
+    }
+
+    #[tokio::test]
+    async fn should_return_not_found_when_network_does_not_exist() {
+        let repo = Arc::new(InMemoryNetworkRepository::new());
+
+        let use_case = ListNetworkAncestorsUseCase::new(repo);
+        let result = use_case.execute(&NetworkId::new()).await;
+
+        assert!(matches!(result.unwrap_err(), UseCaseError::NotFound { .. }));
+    }
+}
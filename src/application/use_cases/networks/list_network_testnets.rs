@@ -0,0 +1,103 @@
+//! List Network Testnets Use Case
+//!
+//! Retrieves the testnets paired with a mainnet, so a consumer can find
+//! where to try a deployment before shipping it.
+
+use std::sync::Arc;
+
+use crate::domain::gateways::NetworkRepository;
+use crate::domain::models::network::{Network, NetworkId};
+use crate::shared::errors::UseCaseError;
+
+/// Use case for listing the testnets mirroring a mainnet
+pub struct ListNetworkTestnetsUseCase {
+    network_repository: Arc<dyn NetworkRepository>,
+}
+
+impl ListNetworkTestnetsUseCase {
+    /// Create a new ListNetworkTestnetsUseCase
+    #[must_use]
+    pub fn new(network_repository: Arc<dyn NetworkRepository>) -> Self {
+        Self { network_repository }
+    }
+
+    /// Execute the use case
+    ///
+    /// Testnets are returned sorted by name.
+    ///
+    /// # Errors
+    ///
+    /// Returns `UseCaseError::NotFound` if the network doesn't exist.
+    /// Returns `UseCaseError::Repository` if there's a database error.
+    pub async fn execute(&self, id: &NetworkId) -> Result<Vec<Network>, UseCaseError> {
+        tracing::debug!(network_id = %id, "Listing network testnets");
+
+        if self.network_repository.find_by_id(id).await?.is_none() {
+            return Err(UseCaseError::NotFound {
+                resource: "Network".to_string(),
+                id: id.to_string(),
+            });
+        }
+
+        let networks = self.network_repository.find_testnets(id).await?;
+
+        tracing::debug!(network_id = %id, count = networks.len(), "Retrieved network testnets");
+        Ok(networks)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::use_cases::networks::test_support::{network_data, InMemoryNetworkRepository};
+    use crate::domain::models::network::CreateNetworkData;
+    use crate::domain::models::testnet::TestnetInfo;
+    use crate::domain::models::url_policy::UrlPolicy;
+
+    fn create_test_network(chain_id: i32, name: &str, mainnet: Option<&Network>) -> Network {
+        let testnet = mainnet.map_or_else(TestnetInfo::default, |mainnet| TestnetInfo {
+            faucet_urls: vec!["https://faucet.example.com".to_string()],
+            mainnet_id: Some(mainnet.id().clone()),
+            expected_deprecation_at: None,
+        });
+
+        let data = CreateNetworkData {
+            test_net: mainnet.is_some(),
+            testnet,
+            ..network_data(chain_id, name)
+        };
+        Network::new(data, &UrlPolicy::default()).expect("valid test data")
+    }
+
+    #[tokio::test]
+    async fn should_list_paired_testnets() {
+        let ethereum = create_test_network(1, "Ethereum Mainnet", None);
+        let polygon = create_test_network(137, "Polygon", None);
+        let sepolia = create_test_network(11_155_111, "Sepolia", Some(&ethereum));
+        let holesky = create_test_network(17000, "Holesky", Some(&ethereum));
+        let amoy = create_test_network(80002, "Polygon Amoy", Some(&polygon));
+        let repo = Arc::new(InMemoryNetworkRepository::with_networks([
+            ethereum.clone(),
+            polygon,
+            sepolia,
+            holesky,
+            amoy,
+        ]));
+
+        let use_case = ListNetworkTestnetsUseCase::new(repo);
+        let testnets = use_case.execute(ethereum.id()).await.unwrap();
+
+        let chain_ids: Vec<i32> = testnets.iter().map(Network::chain_id).collect();
+        assert_eq!(chain_ids, [17000, 11_155_111]);
+    }
+
+    #[tokio::test]
+    async fn should_return_not_found_when_network_does_not_exist() {
+        let repo = Arc::new(InMemoryNetworkRepository::new());
+
+        let use_case = ListNetworkTestnetsUseCase::new(repo);
+        let result = use_case.execute(&NetworkId::new()).await;
+
+        assert!(matches!(result.unwrap_err(), UseCaseError::NotFound { .. }));
+    }
+}
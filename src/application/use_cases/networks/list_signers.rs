@@ -0,0 +1,180 @@
+//! List Signers Use Case
+//!
+//! Retrieves the signers of a network.
+
+use std::sync::Arc;
+
+use crate::domain::gateways::{NetworkRepository, SignerRepository};
+use crate::domain::models::network::NetworkId;
+use crate::domain::models::signer::{normalize_role, NetworkSigner};
+use crate::shared::errors::UseCaseError;
+
+/// Use case for listing the signers of a network
+pub struct ListSignersUseCase {
+    network_repository: Arc<dyn NetworkRepository>,
+    signer_repository: Arc<dyn SignerRepository>,
+}
+
+impl ListSignersUseCase {
+    /// Create a new ListSignersUseCase
+    #[must_use]
+    pub fn new(network_repository: Arc<dyn NetworkRepository>, signer_repository: Arc<dyn SignerRepository>) -> Self {
+        Self {
+            network_repository,
+            signer_repository,
+        }
+    }
+
+    /// Execute the use case
+    ///
+    /// Signers are sorted by role then priority, optionally keeping a single role.
+    ///
+    /// # Errors
+    ///
+    /// Returns `UseCaseError::NotFound` if the network doesn't exist.
+    /// Returns `UseCaseError::Repository` if there's a database error.
+    pub async fn execute(
+        &self,
+        network_id: &NetworkId,
+        role: Option<&str>,
+    ) -> Result<Vec<NetworkSigner>, UseCaseError> {
+        tracing::debug!(network_id = %network_id, role = ?role, "Listing signers");
+
+        if self.network_repository.find_by_id(network_id).await?.is_none() {
+            return Err(UseCaseError::NotFound {
+                resource: "Network".to_string(),
+                id: network_id.to_string(),
+            });
+        }
+
+        let mut signers = self.signer_repository.find_by_network(network_id).await?;
+        if let Some(role) = role.map(normalize_role) {
+            signers.retain(|signer| signer.role() == role);
+        }
+
+        tracing::debug!(network_id = %network_id, count = signers.len(), "Retrieved signers");
+        Ok(signers)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::use_cases::networks::test_support::{network, InMemoryNetworkRepository};
+    use crate::domain::models::signer::{SignerData, SignerId};
+    use crate::shared::errors::RepositoryError;
+    use async_trait::async_trait;
+    use std::sync::Mutex;
+
+    struct MockSignerRepository {
+        signers: Mutex<Vec<NetworkSigner>>,
+    }
+
+    impl MockSignerRepository {
+        fn with_signers(signers: Vec<NetworkSigner>) -> Self {
+            Self {
+                signers: Mutex::new(signers),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl SignerRepository for MockSignerRepository {
+        async fn find_by_id(&self, id: &SignerId) -> Result<Option<NetworkSigner>, RepositoryError> {
+            Ok(self.signers.lock().unwrap().iter().find(|s| s.id() == id).cloned())
+        }
+
+        async fn find_by_network(&self, network_id: &NetworkId) -> Result<Vec<NetworkSigner>, RepositoryError> {
+            Ok(self
+                .signers
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|s| s.network_id() == network_id)
+                .cloned()
+                .collect())
+        }
+
+        async fn create(&self, signer: &NetworkSigner) -> Result<NetworkSigner, RepositoryError> {
+            self.signers.lock().unwrap().push(signer.clone());
+            Ok(signer.clone())
+        }
+
+        async fn update(&self, signer: &NetworkSigner) -> Result<Option<NetworkSigner>, RepositoryError> {
+            let mut signers = self.signers.lock().unwrap();
+            let Some(existing) = signers.iter_mut().find(|s| s.id() == signer.id()) else {
+                return Ok(None);
+            };
+            *existing = signer.clone();
+            Ok(Some(signer.clone()))
+        }
+
+        async fn delete(&self, id: &SignerId) -> Result<bool, RepositoryError> {
+            let mut signers = self.signers.lock().unwrap();
+            let before = signers.len();
+            signers.retain(|s| s.id() != id);
+            Ok(signers.len() != before)
+        }
+
+        async fn exists_by_role_and_address(
+            &self,
+            network_id: &NetworkId,
+            role: &str,
+            address: &str,
+            exclude_id: Option<&SignerId>,
+        ) -> Result<bool, RepositoryError> {
+            Ok(self.signers.lock().unwrap().iter().any(|s| {
+                s.network_id() == network_id
+                    && s.role() == role
+                    && s.address().eq_ignore_ascii_case(address)
+                    && Some(s.id()) != exclude_id
+            }))
+        }
+    }
+
+    fn create_test_signer(network_id: &NetworkId, role: &str, address: &str) -> NetworkSigner {
+        NetworkSigner::new(
+            network_id.clone(),
+            SignerData {
+                address: address.to_string(),
+                role: role.to_string(),
+                priority: 0,
+                enabled: true,
+            },
+        )
+        .expect("valid signer")
+    }
+
+    const RELAYER: &str = "0x1111111111111111111111111111111111111111";
+    const PAYOUTS: &str = "0x2222222222222222222222222222222222222222";
+
+    #[tokio::test]
+    async fn should_list_signers_filtered_by_role() {
+        let network = network(1, "Ethereum Mainnet");
+        let network_repo = Arc::new(InMemoryNetworkRepository::with_networks([network.clone()]));
+        let signer_repo = Arc::new(MockSignerRepository::with_signers(vec![
+            create_test_signer(network.id(), "relayer", RELAYER),
+            create_test_signer(network.id(), "payouts", PAYOUTS),
+        ]));
+
+        let use_case = ListSignersUseCase::new(network_repo, signer_repo);
+
+        let all = use_case.execute(network.id(), None).await.unwrap();
+        assert_eq!(all.len(), 2);
+
+        let payouts = use_case.execute(network.id(), Some(" Payouts ")).await.unwrap();
+        assert_eq!(payouts.len(), 1);
+        assert_eq!(payouts[0].address(), PAYOUTS);
+    }
+
+    #[tokio::test]
+    async fn should_return_not_found_when_network_does_not_exist() {
+        let network_repo = Arc::new(InMemoryNetworkRepository::new());
+        let signer_repo = Arc::new(MockSignerRepository::with_signers(vec![]));
+
+        let use_case = ListSignersUseCase::new(network_repo, signer_repo);
+        let result = use_case.execute(&NetworkId::new(), None).await;
+
+        assert!(matches!(result.unwrap_err(), UseCaseError::NotFound { .. }));
+    }
+}
@@ -0,0 +1,206 @@
+//! List Tokens Use Case
+//!
+//! Retrieves the tokens listed on a network.
+
+use std::sync::Arc;
+
+use crate::domain::gateways::{NetworkRepository, TokenRepository};
+use crate::domain::models::network::NetworkId;
+use crate::domain::models::token::Token;
+use crate::shared::errors::UseCaseError;
+
+/// Use case for listing the tokens of a network
+pub struct ListTokensUseCase {
+    network_repository: Arc<dyn NetworkRepository>,
+    token_repository: Arc<dyn TokenRepository>,
+}
+
+impl ListTokensUseCase {
+    /// Create a new ListTokensUseCase
+    #[must_use]
+    pub fn new(network_repository: Arc<dyn NetworkRepository>, token_repository: Arc<dyn TokenRepository>) -> Self {
+        Self {
+            network_repository,
+            token_repository,
+        }
+    }
+
+    /// Execute the use case
+    ///
+    /// # Errors
+    ///
+    /// Returns `UseCaseError::NotFound` if the network doesn't exist.
+    /// Returns `UseCaseError::Repository` if there's a database error.
+    pub async fn execute(&self, network_id: &NetworkId) -> Result<Vec<Token>, UseCaseError> {
+        tracing::debug!(network_id = %network_id, "Listing tokens");
+
+        if self.network_repository.find_by_id(network_id).await?.is_none() {
+            return Err(UseCaseError::NotFound {
+                resource: "Network".to_string(),
+                id: network_id.to_string(),
+            });
+        }
+
+        let tokens = self.token_repository.find_by_network(network_id).await?;
+
+        tracing::debug!(network_id = %network_id, count = tokens.len(), "Retrieved tokens");
+        Ok(tokens)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::use_cases::networks::test_support::{network, InMemoryNetworkRepository};
+    use crate::domain::models::token::{TokenId, TokenMetadata};
+    use crate::domain::models::token_list::{TokenListChange, TokenListVersion};
+    use crate::shared::errors::RepositoryError;
+    use async_trait::async_trait;
+    use chrono::Utc;
+    use std::sync::Mutex;
+
+    struct MockTokenRepository {
+        tokens: Mutex<Vec<Token>>,
+        version: Mutex<Option<TokenListVersion>>,
+    }
+
+    impl MockTokenRepository {
+        fn with_tokens(tokens: Vec<Token>) -> Self {
+            Self {
+                tokens: Mutex::new(tokens),
+                version: Mutex::new(None),
+            }
+        }
+
+        fn bump(&self, change: TokenListChange) -> TokenListVersion {
+            let mut version = self.version.lock().unwrap();
+            let bumped = version
+                .unwrap_or_else(|| TokenListVersion::initial(Utc::now()))
+                .bump(change, Utc::now());
+            *version = Some(bumped);
+            bumped
+        }
+    }
+
+    #[async_trait]
+    impl TokenRepository for MockTokenRepository {
+        async fn find_by_id(&self, id: &TokenId) -> Result<Option<Token>, RepositoryError> {
+            Ok(self.tokens.lock().unwrap().iter().find(|t| t.id() == id).cloned())
+        }
+
+        async fn find_by_network(&self, network_id: &NetworkId) -> Result<Vec<Token>, RepositoryError> {
+            Ok(self
+                .tokens
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|t| t.network_id() == network_id)
+                .cloned()
+                .collect())
+        }
+
+        async fn exists_by_address(&self, network_id: &NetworkId, address: &str) -> Result<bool, RepositoryError> {
+            Ok(self
+                .tokens
+                .lock()
+                .unwrap()
+                .iter()
+                .any(|t| t.network_id() == network_id && t.address().eq_ignore_ascii_case(address)))
+        }
+
+        async fn create(&self, token: &Token) -> Result<Token, RepositoryError> {
+            self.tokens.lock().unwrap().push(token.clone());
+            self.bump(TokenListChange::Minor);
+            Ok(token.clone())
+        }
+
+        async fn update(&self, token: &Token) -> Result<Option<Token>, RepositoryError> {
+            let mut tokens = self.tokens.lock().unwrap();
+            let Some(existing) = tokens.iter_mut().find(|t| t.id() == token.id()) else {
+                return Ok(None);
+            };
+            *existing = token.clone();
+            self.bump(TokenListChange::Patch);
+            Ok(Some(token.clone()))
+        }
+
+        async fn delete(&self, id: &TokenId) -> Result<bool, RepositoryError> {
+            let mut tokens = self.tokens.lock().unwrap();
+            let before = tokens.len();
+            tokens.retain(|t| t.id() != id);
+            let deleted = tokens.len() != before;
+            if deleted {
+                self.bump(TokenListChange::Major);
+            }
+            Ok(deleted)
+        }
+
+        async fn import(
+            &self,
+            _network_id: &NetworkId,
+            created: &[Token],
+            updated: &[Token],
+        ) -> Result<TokenListVersion, RepositoryError> {
+            let mut tokens = self.tokens.lock().unwrap();
+            for token in updated {
+                if let Some(existing) = tokens.iter_mut().find(|t| t.id() == token.id()) {
+                    *existing = token.clone();
+                }
+            }
+            tokens.extend_from_slice(created);
+            let change = if created.is_empty() {
+                TokenListChange::Patch
+            } else {
+                TokenListChange::Minor
+            };
+            Ok(self.bump(change))
+        }
+
+        async fn find_version(&self, _network_id: &NetworkId) -> Result<Option<TokenListVersion>, RepositoryError> {
+            Ok(*self.version.lock().unwrap())
+        }
+    }
+
+    fn usdc() -> TokenMetadata {
+        TokenMetadata {
+            symbol: "USDC".to_string(),
+            name: "USD Coin".to_string(),
+            decimals: 6,
+            logo_uri: None,
+            tags: vec!["stablecoin".to_string()],
+        }
+    }
+
+    const USDC: &str = "0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48";
+
+    fn create_test_token(network_id: &NetworkId) -> Token {
+        Token::new(network_id.clone(), USDC.to_string(), usdc()).expect("valid token")
+    }
+
+    #[tokio::test]
+    async fn should_return_tokens_of_network() {
+        let network = network(1, "Ethereum Mainnet");
+        let network_repo = Arc::new(InMemoryNetworkRepository::with_networks([network.clone()]));
+        let token_repo = Arc::new(MockTokenRepository::with_tokens(vec![
+            create_test_token(network.id()),
+            create_test_token(&NetworkId::new()),
+        ]));
+
+        let use_case = ListTokensUseCase::new(network_repo, token_repo);
+        let result = use_case.execute(network.id()).await.unwrap();
+
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].network_id(), network.id());
+    }
+
+    #[tokio::test]
+    async fn should_return_not_found_when_network_does_not_exist() {
+        let network_repo = Arc::new(InMemoryNetworkRepository::new());
+        let token_repo = Arc::new(MockTokenRepository::with_tokens(vec![]));
+
+        let use_case = ListTokensUseCase::new(network_repo, token_repo);
+        let result = use_case.execute(&NetworkId::new()).await;
+
+        assert!(matches!(result.unwrap_err(), UseCaseError::NotFound { .. }));
+    }
+}
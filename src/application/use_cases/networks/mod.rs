@@ -2,16 +2,78 @@
 //!
 //! Business logic for managing blockchain networks.
 
+mod apply_due_schedules;
+mod cancel_lifecycle_schedule;
+mod create_contract;
 mod create_network;
+mod create_signer;
+mod create_token;
+mod delete_contract;
 mod delete_network;
+mod delete_signer;
+mod delete_token;
+mod export_token_list;
+mod find_contracts_by_name;
 mod get_active_networks;
+mod get_contract;
+mod get_explorer_links;
 mod get_network_by_id;
+mod get_network_by_slug;
+mod import_tokens;
+mod list_contracts;
+mod list_lifecycle_schedules;
+mod list_network_ancestors;
+mod list_network_children;
+mod list_network_testnets;
+mod list_signers;
+mod list_tokens;
 mod partial_update_network;
+mod propagate_network_change;
+mod resolve_signer;
+mod schedule_lifecycle_change;
+mod search_networks;
+mod set_network_parent;
+#[cfg(test)]
+pub(crate) mod test_support;
+mod transition_network;
+mod update_contract;
 mod update_network;
+mod update_signer;
+mod update_token;
 
+pub use apply_due_schedules::ApplyDueSchedulesUseCase;
+pub use cancel_lifecycle_schedule::CancelLifecycleScheduleUseCase;
+pub use create_contract::CreateContractUseCase;
 pub use create_network::CreateNetworkUseCase;
+pub use create_signer::CreateSignerUseCase;
+pub use create_token::CreateTokenUseCase;
+pub use delete_contract::DeleteContractUseCase;
 pub use delete_network::DeleteNetworkUseCase;
+pub use delete_signer::DeleteSignerUseCase;
+pub use delete_token::DeleteTokenUseCase;
+pub use export_token_list::ExportTokenListUseCase;
+pub use find_contracts_by_name::FindContractsByNameUseCase;
 pub use get_active_networks::GetActiveNetworksUseCase;
+pub use get_contract::GetContractUseCase;
+pub use get_explorer_links::GetExplorerLinksUseCase;
 pub use get_network_by_id::GetNetworkByIdUseCase;
+pub use get_network_by_slug::GetNetworkBySlugUseCase;
+pub use import_tokens::ImportTokensUseCase;
+pub use list_contracts::ListContractsUseCase;
+pub use list_lifecycle_schedules::ListLifecycleSchedulesUseCase;
+pub use list_network_ancestors::ListNetworkAncestorsUseCase;
+pub use list_network_children::ListNetworkChildrenUseCase;
+pub use list_network_testnets::ListNetworkTestnetsUseCase;
+pub use list_signers::ListSignersUseCase;
+pub use list_tokens::ListTokensUseCase;
 pub use partial_update_network::PartialUpdateNetworkUseCase;
+pub use propagate_network_change::PropagateNetworkChangeUseCase;
+pub use resolve_signer::ResolveSignerUseCase;
+pub use schedule_lifecycle_change::ScheduleLifecycleChangeUseCase;
+pub use search_networks::SearchNetworksUseCase;
+pub use set_network_parent::SetNetworkParentUseCase;
+pub use transition_network::TransitionNetworkUseCase;
+pub use update_contract::UpdateContractUseCase;
 pub use update_network::UpdateNetworkUseCase;
+pub use update_signer::UpdateSignerUseCase;
+pub use update_token::UpdateTokenUseCase;
@@ -0,0 +1,94 @@
+//! Propagate Network Change Use Case
+//!
+//! Publishes on the local event bus a network change made by another
+//! registry replica, so that its subscribers see every change whichever
+//! replica handled it.
+
+use std::sync::Arc;
+
+use crate::domain::gateways::{NetworkEventPublisher, NetworkRepository};
+use crate::domain::models::event::{NetworkEvent, NetworkEventKind};
+use crate::domain::models::network::NetworkId;
+use crate::shared::errors::UseCaseError;
+
+/// Use case for publishing a change made by another replica
+pub struct PropagateNetworkChangeUseCase {
+    network_repository: Arc<dyn NetworkRepository>,
+    event_publisher: Arc<dyn NetworkEventPublisher>,
+}
+
+impl PropagateNetworkChangeUseCase {
+    /// Create a new PropagateNetworkChangeUseCase
+    #[must_use]
+    pub fn new(
+        network_repository: Arc<dyn NetworkRepository>,
+        event_publisher: Arc<dyn NetworkEventPublisher>,
+    ) -> Self {
+        Self {
+            network_repository,
+            event_publisher,
+        }
+    }
+
+    /// Execute the use case
+    ///
+    /// The event carries the network as currently stored, which may already
+    /// include later changes.
+    ///
+    /// # Errors
+    ///
+    /// Returns `UseCaseError::NotFound` if the network doesn't exist.
+    /// Returns `UseCaseError::Repository` if there's a database error.
+    pub async fn execute(&self, id: &NetworkId, kind: NetworkEventKind) -> Result<(), UseCaseError> {
+        tracing::debug!(network_id = %id, kind = %kind, "Propagating network change");
+
+        let network = self.network_repository.find_by_id(id).await?.ok_or_else(|| {
+            tracing::warn!(network_id = %id, "Changed network not found");
+            UseCaseError::NotFound {
+                resource: "Network".to_string(),
+                id: id.to_string(),
+            }
+        })?;
+
+        self.event_publisher.publish(NetworkEvent::new(kind, network)).await;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::use_cases::networks::test_support::{
+        network, InMemoryNetworkRepository, RecordingEventPublisher,
+    };
+
+    #[tokio::test]
+    async fn should_publish_stored_network() {
+        let network = network(1, "Ethereum Mainnet");
+        let repo = Arc::new(InMemoryNetworkRepository::with_networks([network.clone()]));
+        let publisher = Arc::new(RecordingEventPublisher::default());
+
+        let use_case = PropagateNetworkChangeUseCase::new(repo, publisher.clone());
+        use_case
+            .execute(network.id(), NetworkEventKind::Updated)
+            .await
+            .expect("change should be propagated");
+
+        let events = publisher.events.lock().unwrap();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].kind, NetworkEventKind::Updated);
+        assert_eq!(events[0].network.id(), network.id());
+    }
+
+    #[tokio::test]
+    async fn should_return_not_found_when_network_does_not_exist() {
+        let repo = Arc::new(InMemoryNetworkRepository::new());
+        let publisher = Arc::new(RecordingEventPublisher::default());
+
+        let use_case = PropagateNetworkChangeUseCase::new(repo, publisher.clone());
+        let result = use_case.execute(&NetworkId::new(), NetworkEventKind::Created).await;
+
+        assert!(matches!(result.unwrap_err(), UseCaseError::NotFound { .. }));
+        assert!(publisher.events.lock().unwrap().is_empty());
+    }
+}
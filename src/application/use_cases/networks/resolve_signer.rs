@@ -0,0 +1,208 @@
+//! Resolve Signer Use Case
+//!
+//! Answers "which address signs for role X on chain Y?".
+
+use std::sync::Arc;
+
+use crate::domain::gateways::{NetworkRepository, SignerRepository};
+use crate::domain::models::signer::{normalize_role, ResolvedSigner};
+use crate::shared::errors::UseCaseError;
+
+/// Use case for resolving the signer of a role on a chain
+pub struct ResolveSignerUseCase {
+    network_repository: Arc<dyn NetworkRepository>,
+    signer_repository: Arc<dyn SignerRepository>,
+}
+
+impl ResolveSignerUseCase {
+    /// Create a new ResolveSignerUseCase
+    #[must_use]
+    pub fn new(network_repository: Arc<dyn NetworkRepository>, signer_repository: Arc<dyn SignerRepository>) -> Self {
+        Self {
+            network_repository,
+            signer_repository,
+        }
+    }
+
+    /// Execute the use case
+    ///
+    /// Picks the enabled signer of the role with the lowest priority. The
+    /// default role falls back to the network's `default_signer_address`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `UseCaseError::Validation` if the role is blank.
+    /// Returns `UseCaseError::NotFound` if no network has the chain ID or no enabled signer has the role.
+    /// Returns `UseCaseError::Repository` if there's a database error.
+    pub async fn execute(&self, chain_id: i32, role: &str) -> Result<ResolvedSigner, UseCaseError> {
+        let role = normalize_role(role);
+        if role.is_empty() {
+            return Err(UseCaseError::Validation(vec!["role must not be empty".to_string()]));
+        }
+
+        tracing::debug!(chain_id = chain_id, role = %role, "Resolving signer");
+
+        let network = self
+            .network_repository
+            .find_by_chain_id(chain_id)
+            .await?
+            .ok_or_else(|| UseCaseError::NotFound {
+                resource: "Network".to_string(),
+                id: format!("chain {chain_id}"),
+            })?;
+
+        let signers = self.signer_repository.find_by_network(network.id()).await?;
+
+        let resolved = ResolvedSigner::pick(&network, &role, signers).ok_or_else(|| {
+            tracing::debug!(chain_id = chain_id, role = %role, "No enabled signer for role");
+            UseCaseError::NotFound {
+                resource: "Signer".to_string(),
+                id: format!("{role} on chain {chain_id}"),
+            }
+        })?;
+
+        tracing::debug!(chain_id = chain_id, role = %role, address = %resolved.address, "Resolved signer");
+        Ok(resolved)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::use_cases::networks::test_support::{network, InMemoryNetworkRepository};
+    use crate::domain::models::network::NetworkId;
+    use crate::domain::models::signer::{NetworkSigner, SignerData, SignerId};
+    use crate::shared::errors::RepositoryError;
+    use async_trait::async_trait;
+    use std::sync::Mutex;
+
+    struct MockSignerRepository {
+        signers: Mutex<Vec<NetworkSigner>>,
+    }
+
+    impl MockSignerRepository {
+        fn with_signers(signers: Vec<NetworkSigner>) -> Self {
+            Self {
+                signers: Mutex::new(signers),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl SignerRepository for MockSignerRepository {
+        async fn find_by_id(&self, id: &SignerId) -> Result<Option<NetworkSigner>, RepositoryError> {
+            Ok(self.signers.lock().unwrap().iter().find(|s| s.id() == id).cloned())
+        }
+
+        async fn find_by_network(&self, network_id: &NetworkId) -> Result<Vec<NetworkSigner>, RepositoryError> {
+            Ok(self
+                .signers
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|s| s.network_id() == network_id)
+                .cloned()
+                .collect())
+        }
+
+        async fn create(&self, signer: &NetworkSigner) -> Result<NetworkSigner, RepositoryError> {
+            self.signers.lock().unwrap().push(signer.clone());
+            Ok(signer.clone())
+        }
+
+        async fn update(&self, signer: &NetworkSigner) -> Result<Option<NetworkSigner>, RepositoryError> {
+            let mut signers = self.signers.lock().unwrap();
+            let Some(existing) = signers.iter_mut().find(|s| s.id() == signer.id()) else {
+                return Ok(None);
+            };
+            *existing = signer.clone();
+            Ok(Some(signer.clone()))
+        }
+
+        async fn delete(&self, id: &SignerId) -> Result<bool, RepositoryError> {
+            let mut signers = self.signers.lock().unwrap();
+            let before = signers.len();
+            signers.retain(|s| s.id() != id);
+            Ok(signers.len() != before)
+        }
+
+        async fn exists_by_role_and_address(
+            &self,
+            network_id: &NetworkId,
+            role: &str,
+            address: &str,
+            exclude_id: Option<&SignerId>,
+        ) -> Result<bool, RepositoryError> {
+            Ok(self.signers.lock().unwrap().iter().any(|s| {
+                s.network_id() == network_id
+                    && s.role() == role
+                    && s.address().eq_ignore_ascii_case(address)
+                    && Some(s.id()) != exclude_id
+            }))
+        }
+    }
+
+    fn create_test_signer(network_id: &NetworkId, role: &str, address: &str) -> NetworkSigner {
+        NetworkSigner::new(
+            network_id.clone(),
+            SignerData {
+                address: address.to_string(),
+                role: role.to_string(),
+                priority: 0,
+                enabled: true,
+            },
+        )
+        .expect("valid signer")
+    }
+
+    const RELAYER: &str = "0x1111111111111111111111111111111111111111";
+    const PAYOUTS: &str = "0x2222222222222222222222222222222222222222";
+
+    #[tokio::test]
+    async fn should_resolve_registered_signer_for_role() {
+        let network = network(1, "Ethereum Mainnet");
+        let network_repo = Arc::new(InMemoryNetworkRepository::with_networks([network.clone()]));
+        let signer_repo = Arc::new(MockSignerRepository::with_signers(vec![
+            create_test_signer(network.id(), "relayer", RELAYER),
+            create_test_signer(network.id(), "payouts", PAYOUTS),
+        ]));
+
+        let use_case = ResolveSignerUseCase::new(network_repo, signer_repo);
+        let resolved = use_case.execute(1, "Payouts").await.unwrap();
+
+        assert_eq!(resolved.address, PAYOUTS);
+        assert_eq!(resolved.role, "payouts");
+        assert!(resolved.signer.is_some());
+    }
+
+    #[tokio::test]
+    async fn should_fall_back_to_default_signer_address() {
+        let network = network(1, "Ethereum Mainnet");
+        let network_repo = Arc::new(InMemoryNetworkRepository::with_networks([network.clone()]));
+        let signer_repo = Arc::new(MockSignerRepository::with_signers(vec![]));
+
+        let use_case = ResolveSignerUseCase::new(network_repo, signer_repo);
+        let resolved = use_case.execute(1, "default").await.unwrap();
+
+        assert_eq!(resolved.address, network.default_signer_address());
+        assert!(resolved.signer.is_none());
+    }
+
+    #[tokio::test]
+    async fn should_return_not_found_when_role_has_no_signer() {
+        let network = network(1, "Ethereum Mainnet");
+        let network_repo = Arc::new(InMemoryNetworkRepository::with_networks([network.clone()]));
+        let signer_repo = Arc::new(MockSignerRepository::with_signers(vec![]));
+
+        let use_case = ResolveSignerUseCase::new(network_repo, signer_repo);
+
+        let unknown_role = use_case.execute(1, "deployer").await;
+        assert!(matches!(unknown_role.unwrap_err(), UseCaseError::NotFound { .. }));
+
+        let unknown_chain = use_case.execute(137, "default").await;
+        assert!(matches!(unknown_chain.unwrap_err(), UseCaseError::NotFound { .. }));
+
+        let blank_role = use_case.execute(1, "  ").await;
+        assert!(matches!(blank_role.unwrap_err(), UseCaseError::Validation(_)));
+    }
+}
@@ -0,0 +1,185 @@
+//! Schedule Lifecycle Change Use Case
+//!
+//! Plans the activation, deprecation or retirement of a network at a future time.
+
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+
+use crate::domain::gateways::{NetworkRepository, ScheduleRepository};
+use crate::domain::models::lifecycle::NetworkStatus;
+use crate::domain::models::network::NetworkId;
+use crate::domain::models::schedule::{LifecycleSchedule, ScheduledAction};
+use crate::shared::errors::{DomainError, UseCaseError};
+
+/// Use case for scheduling a lifecycle change of a network
+pub struct ScheduleLifecycleChangeUseCase {
+    network_repository: Arc<dyn NetworkRepository>,
+    schedule_repository: Arc<dyn ScheduleRepository>,
+}
+
+impl ScheduleLifecycleChangeUseCase {
+    /// Create a new ScheduleLifecycleChangeUseCase
+    #[must_use]
+    pub fn new(
+        network_repository: Arc<dyn NetworkRepository>,
+        schedule_repository: Arc<dyn ScheduleRepository>,
+    ) -> Self {
+        Self {
+            network_repository,
+            schedule_repository,
+        }
+    }
+
+    /// Execute the use case
+    ///
+    /// # Errors
+    ///
+    /// Returns `UseCaseError::NotFound` if the network doesn't exist.
+    /// Returns `UseCaseError::Domain` if the network is retired or `run_at` is not in the future.
+    /// Returns `UseCaseError::Repository` if there's a database error.
+    pub async fn execute(
+        &self,
+        network_id: &NetworkId,
+        action: ScheduledAction,
+        run_at: DateTime<Utc>,
+        scheduled_by: String,
+    ) -> Result<LifecycleSchedule, UseCaseError> {
+        tracing::info!(network_id = %network_id, action = %action, run_at = %run_at, scheduled_by = %scheduled_by, "Scheduling lifecycle change");
+
+        let network = self.network_repository.find_by_id(network_id).await?.ok_or_else(|| {
+            tracing::warn!(network_id = %network_id, "Network not found for scheduling");
+            UseCaseError::NotFound {
+                resource: "Network".to_string(),
+                id: network_id.to_string(),
+            }
+        })?;
+
+        // Retirement is terminal, so any change scheduled now could only fail when it runs
+        if network.status() == NetworkStatus::Retired {
+            return Err(DomainError::InvalidState("retired networks cannot be scheduled".to_string()).into());
+        }
+
+        let schedule = LifecycleSchedule::new(network_id.clone(), action, run_at, scheduled_by)?;
+        let result = self.schedule_repository.create(&schedule).await?;
+
+        tracing::info!(network_id = %network_id, schedule_id = %result.id(), "Lifecycle change scheduled successfully");
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::use_cases::networks::test_support::{network_data, InMemoryNetworkRepository};
+    use crate::domain::models::network::{CreateNetworkData, Network};
+    use crate::domain::models::schedule::{ScheduleId, ScheduleStatus};
+    use crate::domain::models::url_policy::UrlPolicy;
+    use crate::shared::errors::RepositoryError;
+    use async_trait::async_trait;
+
+    struct MockScheduleRepository;
+
+    #[async_trait]
+    impl ScheduleRepository for MockScheduleRepository {
+        async fn find_by_id(&self, _id: &ScheduleId) -> Result<Option<LifecycleSchedule>, RepositoryError> {
+            Ok(None)
+        }
+
+        async fn find_by_network(&self, _network_id: &NetworkId) -> Result<Vec<LifecycleSchedule>, RepositoryError> {
+            Ok(vec![])
+        }
+
+        async fn create(&self, schedule: &LifecycleSchedule) -> Result<LifecycleSchedule, RepositoryError> {
+            Ok(schedule.clone())
+        }
+
+        async fn complete(&self, schedule: &LifecycleSchedule) -> Result<Option<LifecycleSchedule>, RepositoryError> {
+            Ok(Some(schedule.clone()))
+        }
+
+        async fn claim_due(&self, _now: DateTime<Utc>, _limit: u32) -> Result<Vec<LifecycleSchedule>, RepositoryError> {
+            Ok(vec![])
+        }
+    }
+
+    fn create_test_network(status: NetworkStatus) -> Network {
+        let data = CreateNetworkData {
+            status,
+            ..network_data(1, "Ethereum Mainnet")
+        };
+        Network::new(data, &UrlPolicy::default()).expect("valid test data")
+    }
+
+    #[tokio::test]
+    async fn should_schedule_activation_of_draft_network() {
+        let network = create_test_network(NetworkStatus::Draft);
+        let network_repo = Arc::new(InMemoryNetworkRepository::with_networks([network.clone()]));
+
+        let use_case = ScheduleLifecycleChangeUseCase::new(network_repo, Arc::new(MockScheduleRepository));
+        let run_at = Utc::now() + chrono::Duration::hours(1);
+        let result = use_case
+            .execute(network.id(), ScheduledAction::Activate, run_at, "user-1".to_string())
+            .await;
+
+        let schedule = result.expect("draft network can be scheduled");
+        assert_eq!(schedule.network_id(), network.id());
+        assert_eq!(schedule.status(), ScheduleStatus::Pending);
+        assert_eq!(schedule.scheduled_by(), "user-1");
+        assert_eq!(schedule.run_at(), run_at);
+    }
+
+    #[tokio::test]
+    async fn should_reject_retired_network() {
+        let network = create_test_network(NetworkStatus::Active)
+            .transition_to(NetworkStatus::Retired, None)
+            .unwrap();
+        let network_repo = Arc::new(InMemoryNetworkRepository::with_networks([network.clone()]));
+
+        let use_case = ScheduleLifecycleChangeUseCase::new(network_repo, Arc::new(MockScheduleRepository));
+        let run_at = Utc::now() + chrono::Duration::hours(1);
+        let result = use_case
+            .execute(network.id(), ScheduledAction::Activate, run_at, "user-1".to_string())
+            .await;
+
+        assert!(matches!(
+            result.unwrap_err(),
+            UseCaseError::Domain(DomainError::InvalidState(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn should_reject_run_at_in_the_past() {
+        let network = create_test_network(NetworkStatus::Active);
+        let network_repo = Arc::new(InMemoryNetworkRepository::with_networks([network.clone()]));
+
+        let use_case = ScheduleLifecycleChangeUseCase::new(network_repo, Arc::new(MockScheduleRepository));
+        let run_at = Utc::now() - chrono::Duration::hours(1);
+        let result = use_case
+            .execute(network.id(), ScheduledAction::Retire, run_at, "user-1".to_string())
+            .await;
+
+        assert!(matches!(
+            result.unwrap_err(),
+            UseCaseError::Domain(DomainError::ValidationError(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn should_return_not_found_when_network_does_not_exist() {
+        let network_repo = Arc::new(InMemoryNetworkRepository::new());
+
+        let use_case = ScheduleLifecycleChangeUseCase::new(network_repo, Arc::new(MockScheduleRepository));
+        let run_at = Utc::now() + chrono::Duration::hours(1);
+        let result = use_case
+            .execute(
+                &NetworkId::new(),
+                ScheduledAction::Activate,
+                run_at,
+                "user-1".to_string(),
+            )
+            .await;
+
+        assert!(matches!(result.unwrap_err(), UseCaseError::NotFound { .. }));
+    }
+}
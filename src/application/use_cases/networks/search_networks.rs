@@ -0,0 +1,92 @@
+//! Search Networks Use Case
+//!
+//! Finds networks from free text typed by a user ("polygon amoy", "arb"),
+//! matching names and tags by prefix or by similarity.
+
+use std::sync::Arc;
+
+use crate::domain::gateways::NetworkRepository;
+use crate::domain::models::network::Network;
+use crate::domain::models::search::SearchQuery;
+use crate::shared::errors::UseCaseError;
+
+/// Use case for searching networks by name and tags
+pub struct SearchNetworksUseCase {
+    network_repository: Arc<dyn NetworkRepository>,
+}
+
+impl SearchNetworksUseCase {
+    /// Create a new SearchNetworksUseCase
+    #[must_use]
+    pub fn new(network_repository: Arc<dyn NetworkRepository>) -> Self {
+        Self { network_repository }
+    }
+
+    /// Execute the use case
+    ///
+    /// Networks in any lifecycle state are returned, best match first.
+    ///
+    /// # Errors
+    ///
+    /// Returns `UseCaseError::Domain` if the query or limit is invalid.
+    /// Returns `UseCaseError::Repository` if there's a database error.
+    pub async fn execute(&self, text: &str, limit: Option<usize>) -> Result<Vec<Network>, UseCaseError> {
+        let query = SearchQuery::new(text, limit)?;
+
+        tracing::debug!(query = %query.text(), limit = query.limit(), "Searching networks");
+
+        let networks = self.network_repository.search(&query).await?;
+
+        tracing::debug!(count = networks.len(), "Found matching networks");
+        Ok(networks)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::use_cases::networks::test_support::{network_data, InMemoryNetworkRepository};
+    use crate::domain::models::network::CreateNetworkData;
+    use crate::domain::models::url_policy::UrlPolicy;
+    use crate::shared::errors::DomainError;
+
+    fn create_test_network(chain_id: i32, name: &str, tags: &[&str]) -> Network {
+        let data = CreateNetworkData {
+            tags: tags.iter().map(ToString::to_string).collect(),
+            ..network_data(chain_id, name)
+        };
+        Network::new(data, &UrlPolicy::default()).expect("valid test data")
+    }
+
+    fn repository() -> Arc<InMemoryNetworkRepository> {
+        Arc::new(InMemoryNetworkRepository::with_networks([
+            create_test_network(1, "Ethereum Mainnet", &["l1"]),
+            create_test_network(42161, "Arbitrum One", &["l2"]),
+            create_test_network(137, "Polygon", &["l2"]),
+            create_test_network(80002, "Polygon Amoy", &["testnet"]),
+        ]))
+    }
+
+    #[tokio::test]
+    async fn should_rank_best_match_first() {
+        let use_case = SearchNetworksUseCase::new(repository());
+
+        let result = use_case.execute("polygon amoy", None).await.unwrap();
+        assert_eq!(result[0].chain_id(), 80002);
+
+        let result = use_case.execute("arb", None).await.unwrap();
+        let chain_ids: Vec<i32> = result.iter().map(Network::chain_id).collect();
+        assert_eq!(chain_ids, [42161]);
+    }
+
+    #[tokio::test]
+    async fn should_reject_blank_query() {
+        let use_case = SearchNetworksUseCase::new(repository());
+        let result = use_case.execute("   ", None).await;
+
+        assert!(matches!(
+            result.unwrap_err(),
+            UseCaseError::Domain(DomainError::ValidationError(_))
+        ));
+    }
+}
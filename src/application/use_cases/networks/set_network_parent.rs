@@ -0,0 +1,239 @@
+//! Set Network Parent Use Case
+//!
+//! Attaches a network to its parent chain (rollup, sidechain or testnet of)
+//! or detaches it.
+
+use std::sync::Arc;
+
+use crate::domain::gateways::{NetworkEventPublisher, NetworkRepository};
+use crate::domain::models::event::NetworkEvent;
+use crate::domain::models::network::{Network, NetworkId};
+use crate::domain::models::relation::NetworkParent;
+use crate::shared::errors::UseCaseError;
+
+/// Load the lineage of a prospective parent: the parent first, then its ancestors
+///
+/// # Errors
+///
+/// Returns `UseCaseError::Validation` if the parent network doesn't exist.
+/// Returns `UseCaseError::Repository` if there's a database error.
+pub(super) async fn load_lineage(
+    network_repository: &dyn NetworkRepository,
+    parent_id: &NetworkId,
+) -> Result<Vec<NetworkId>, UseCaseError> {
+    if network_repository.find_by_id(parent_id).await?.is_none() {
+        return Err(UseCaseError::Validation(vec![format!(
+            "parent network {parent_id} does not exist"
+        )]));
+    }
+
+    let ancestors = network_repository.find_ancestors(parent_id).await?;
+
+    Ok(std::iter::once(parent_id.clone())
+        .chain(ancestors.iter().map(|ancestor| ancestor.id().clone()))
+        .collect())
+}
+
+/// Use case for attaching a network to a parent or detaching it
+pub struct SetNetworkParentUseCase {
+    network_repository: Arc<dyn NetworkRepository>,
+    event_publisher: Arc<dyn NetworkEventPublisher>,
+}
+
+impl SetNetworkParentUseCase {
+    /// Create a new SetNetworkParentUseCase
+    #[must_use]
+    pub fn new(
+        network_repository: Arc<dyn NetworkRepository>,
+        event_publisher: Arc<dyn NetworkEventPublisher>,
+    ) -> Self {
+        Self {
+            network_repository,
+            event_publisher,
+        }
+    }
+
+    /// Execute the use case, detaching the network when `parent` is `None`
+    ///
+    /// # Errors
+    ///
+    /// Returns `UseCaseError::NotFound` if the network doesn't exist.
+    /// Returns `UseCaseError::Validation` if the parent network doesn't exist.
+    /// Returns `UseCaseError::Domain` if the relation would create a cycle or exceed the maximum depth.
+    /// Returns `UseCaseError::Repository` if there's a database error, or if a concurrent change
+    /// invalidated the relation before it was written.
+    pub async fn execute(&self, id: &NetworkId, parent: Option<NetworkParent>) -> Result<Network, UseCaseError> {
+        tracing::info!(
+            network_id = %id,
+            parent_id = ?parent.as_ref().map(|parent| parent.network_id.to_string()),
+            "Setting network parent"
+        );
+
+        let not_found = || UseCaseError::NotFound {
+            resource: "Network".to_string(),
+            id: id.to_string(),
+        };
+
+        let network = self
+            .network_repository
+            .find_by_id_for_update(id)
+            .await?
+            .ok_or_else(not_found)?;
+
+        // The descendants move along, so their depth counts too
+        let (lineage, subtree_height) = match &parent {
+            Some(parent) => (
+                load_lineage(self.network_repository.as_ref(), &parent.network_id).await?,
+                self.network_repository.subtree_height(id).await?,
+            ),
+            None => (vec![], 0),
+        };
+
+        let updated = network.clone().with_parent(parent, &lineage, subtree_height)?;
+        let result = self.network_repository.update(&updated).await?.ok_or_else(not_found)?;
+        self.event_publisher
+            .publish(NetworkEvent::changed(&network, result.clone()))
+            .await;
+
+        tracing::info!(network_id = %id, "Network parent set successfully");
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::use_cases::networks::test_support::{
+        network_data, InMemoryNetworkRepository, RecordingEventPublisher,
+    };
+
+    use crate::domain::models::network::CreateNetworkData;
+    use crate::domain::models::relation::{NetworkRelationKind, MAX_NETWORK_DEPTH};
+    use crate::domain::models::url_policy::UrlPolicy;
+    use crate::shared::errors::DomainError;
+
+    fn create_test_network(chain_id: i32, parent: Option<NetworkParent>) -> Network {
+        let data = CreateNetworkData {
+            parent,
+            ..network_data(chain_id, &format!("Chain {chain_id}"))
+        };
+        Network::new(data, &UrlPolicy::default()).expect("valid test data")
+    }
+
+    #[tokio::test]
+    async fn should_attach_network_to_parent() {
+        let mainnet = create_test_network(1, None);
+        let arbitrum = create_test_network(42161, None);
+        let repo = Arc::new(InMemoryNetworkRepository::with_networks([
+            mainnet.clone(),
+            arbitrum.clone(),
+        ]));
+
+        let use_case = SetNetworkParentUseCase::new(repo, Arc::new(RecordingEventPublisher::default()));
+        let result = use_case
+            .execute(arbitrum.id(), Some(rollup_of(&mainnet)))
+            .await
+            .unwrap();
+
+        assert_eq!(result.parent(), Some(&rollup_of(&mainnet)));
+    }
+
+    #[tokio::test]
+    async fn should_detach_network() {
+        let mainnet = create_test_network(1, None);
+        let arbitrum = create_test_network(42161, Some(rollup_of(&mainnet)));
+        let repo = Arc::new(InMemoryNetworkRepository::with_networks([mainnet, arbitrum.clone()]));
+
+        let use_case = SetNetworkParentUseCase::new(repo, Arc::new(RecordingEventPublisher::default()));
+        let result = use_case.execute(arbitrum.id(), None).await.unwrap();
+
+        assert!(result.parent().is_none());
+    }
+
+    #[tokio::test]
+    async fn should_reject_cycle() {
+        let mainnet = create_test_network(1, None);
+        let arbitrum = create_test_network(42161, Some(rollup_of(&mainnet)));
+        let l3 = create_test_network(660_279, Some(rollup_of(&arbitrum)));
+        let repo = Arc::new(InMemoryNetworkRepository::with_networks([
+            mainnet.clone(),
+            arbitrum,
+            l3.clone(),
+        ]));
+
+        let use_case = SetNetworkParentUseCase::new(repo.clone(), Arc::new(RecordingEventPublisher::default()));
+
+        let result = use_case.execute(mainnet.id(), Some(rollup_of(&l3))).await;
+        assert!(matches!(
+            result.unwrap_err(),
+            UseCaseError::Domain(DomainError::InvalidState(_))
+        ));
+
+        let result = use_case.execute(mainnet.id(), Some(rollup_of(&mainnet))).await;
+        assert!(matches!(
+            result.unwrap_err(),
+            UseCaseError::Domain(DomainError::InvalidState(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn should_count_descendants_towards_depth() {
+        // A chain of MAX_NETWORK_DEPTH networks below a root, and a separate pair
+        let mut chain = vec![create_test_network(1, None)];
+        for chain_id in 2..=i32::try_from(MAX_NETWORK_DEPTH).unwrap() {
+            let parent = rollup_of(chain.last().unwrap());
+            chain.push(create_test_network(chain_id, Some(parent)));
+        }
+        let moved = create_test_network(100, None);
+        let child = create_test_network(101, Some(rollup_of(&moved)));
+        let repo = Arc::new(InMemoryNetworkRepository::with_networks(
+            chain.iter().cloned().chain([moved.clone(), child]),
+        ));
+        let use_case = SetNetworkParentUseCase::new(repo.clone(), Arc::new(RecordingEventPublisher::default()));
+
+        // Below the deepest network, the child would get one ancestor too many
+        let result = use_case
+            .execute(moved.id(), Some(rollup_of(&chain[chain.len() - 1])))
+            .await;
+        assert!(matches!(
+            result.unwrap_err(),
+            UseCaseError::Domain(DomainError::InvalidState(_))
+        ));
+
+        // One level up there is room for both
+        assert!(use_case
+            .execute(moved.id(), Some(rollup_of(&chain[chain.len() - 2])))
+            .await
+            .is_ok());
+    }
+
+    #[tokio::test]
+    async fn should_reject_unknown_parent() {
+        let arbitrum = create_test_network(42161, None);
+        let repo = Arc::new(InMemoryNetworkRepository::with_networks([arbitrum.clone()]));
+
+        let use_case = SetNetworkParentUseCase::new(repo, Arc::new(RecordingEventPublisher::default()));
+        let orphan = create_test_network(1, None);
+        let result = use_case.execute(arbitrum.id(), Some(rollup_of(&orphan))).await;
+
+        assert!(matches!(result.unwrap_err(), UseCaseError::Validation(_)));
+    }
+
+    #[tokio::test]
+    async fn should_return_not_found_when_network_does_not_exist() {
+        let mainnet = create_test_network(1, None);
+        let repo = Arc::new(InMemoryNetworkRepository::with_networks([mainnet.clone()]));
+
+        let use_case = SetNetworkParentUseCase::new(repo, Arc::new(RecordingEventPublisher::default()));
+        let result = use_case.execute(&NetworkId::new(), Some(rollup_of(&mainnet))).await;
+
+        assert!(matches!(result.unwrap_err(), UseCaseError::NotFound { .. }));
+    }
+
+    fn rollup_of(parent: &Network) -> NetworkParent {
+        NetworkParent {
+            network_id: parent.id().clone(),
+            kind: NetworkRelationKind::Rollup,
+        }
+    }
+}
@@ -0,0 +1,237 @@
+//! Use Case Test Support
+//!
+//! In-memory network repository, recording event publisher and network
+//! fixtures shared by the use case tests, so that a new repository method or
+//! network field only has to be handled here.
+
+use std::sync::Mutex;
+
+use async_trait::async_trait;
+use rust_decimal_macros::dec;
+
+use crate::domain::gateways::{NetworkEventPublisher, NetworkRepository};
+use crate::domain::models::event::NetworkEvent;
+use crate::domain::models::explorer::ExplorerMetadata;
+use crate::domain::models::fee_config::FeeConfig;
+use crate::domain::models::finality::FinalityPolicy;
+use crate::domain::models::labels::{normalize_tags, NetworkMetadata};
+use crate::domain::models::lifecycle::NetworkStatus;
+use crate::domain::models::network::{CreateNetworkData, Network, NetworkId};
+use crate::domain::models::search::{self, SearchQuery};
+use crate::domain::models::slug::normalize_slug;
+use crate::domain::models::testnet::TestnetInfo;
+use crate::domain::models::url_policy::UrlPolicy;
+use crate::shared::errors::RepositoryError;
+
+/// Creation data of a valid active mainnet
+pub fn network_data(chain_id: i32, name: &str) -> CreateNetworkData {
+    CreateNetworkData {
+        chain_id,
+        name: name.to_string(),
+        rpc_url: "https://mainnet.infura.io".to_string(),
+        other_rpc_urls: vec![],
+        test_net: false,
+        block_explorer_url: "https://etherscan.io".to_string(),
+        fee_multiplier: dec!(1.0),
+        gas_limit_multiplier: dec!(1.2),
+        default_signer_address: "0x742d35Cc6634C0532925a3b844Bc9e7595f1dEaD".to_string(),
+        explorer: ExplorerMetadata::default(),
+        fee_config: FeeConfig::default(),
+        finality: FinalityPolicy::default(),
+        status: NetworkStatus::Active,
+        parent: None,
+        tags: vec![],
+        metadata: NetworkMetadata::new(),
+        slug: None,
+        aliases: vec![],
+        testnet: TestnetInfo::default(),
+    }
+}
+
+/// A valid active mainnet
+pub fn network(chain_id: i32, name: &str) -> Network {
+    Network::new(network_data(chain_id, name), &UrlPolicy::default()).expect("valid test network")
+}
+
+/// In-memory network repository, following the Postgres adapter's semantics
+#[derive(Default)]
+pub struct InMemoryNetworkRepository {
+    networks: Mutex<Vec<Network>>,
+    failing: bool,
+}
+
+impl InMemoryNetworkRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_networks(networks: impl IntoIterator<Item = Network>) -> Self {
+        Self {
+            networks: Mutex::new(networks.into_iter().collect()),
+            failing: false,
+        }
+    }
+
+    /// A repository failing every call
+    pub fn failing() -> Self {
+        Self {
+            networks: Mutex::default(),
+            failing: true,
+        }
+    }
+
+    /// Current state of a stored network
+    pub fn get(&self, id: &NetworkId) -> Option<Network> {
+        self.networks.lock().unwrap().iter().find(|n| n.id() == id).cloned()
+    }
+
+    /// All stored networks, in insertion order
+    pub fn networks(&self) -> Vec<Network> {
+        self.networks.lock().unwrap().clone()
+    }
+
+    fn read(&self) -> Result<Vec<Network>, RepositoryError> {
+        if self.failing {
+            return Err(RepositoryError::Mapping("connection lost".to_string()));
+        }
+        Ok(self.networks())
+    }
+
+    fn sorted_by_name(mut networks: Vec<Network>) -> Vec<Network> {
+        networks.sort_by(|a, b| a.name().cmp(b.name()));
+        networks
+    }
+}
+
+#[async_trait]
+impl NetworkRepository for InMemoryNetworkRepository {
+    async fn find_by_id(&self, id: &NetworkId) -> Result<Option<Network>, RepositoryError> {
+        Ok(self.read()?.into_iter().find(|n| n.id() == id))
+    }
+
+    async fn find_by_id_for_update(&self, id: &NetworkId) -> Result<Option<Network>, RepositoryError> {
+        self.find_by_id(id).await
+    }
+
+    async fn find_by_chain_id(&self, chain_id: i32) -> Result<Option<Network>, RepositoryError> {
+        Ok(self.read()?.into_iter().find(|n| n.chain_id() == chain_id))
+    }
+
+    async fn find_by_slug(&self, slug: &str) -> Result<Option<Network>, RepositoryError> {
+        let slug = normalize_slug(slug);
+        let networks = self.read()?;
+        let by_slug = networks.iter().find(|n| n.slug() == slug);
+        let by_alias = networks.iter().find(|n| n.aliases().contains(&slug));
+        Ok(by_slug.or(by_alias).cloned())
+    }
+
+    async fn find_all_active(&self, tags: &[String]) -> Result<Vec<Network>, RepositoryError> {
+        let tags = normalize_tags(tags);
+        let networks = self
+            .read()?
+            .into_iter()
+            .filter(|n| n.status().is_usable() && tags.is_subset(n.tags()))
+            .collect();
+        Ok(Self::sorted_by_name(networks))
+    }
+
+    async fn search(&self, query: &SearchQuery) -> Result<Vec<Network>, RepositoryError> {
+        Ok(search::rank(self.read()?, query))
+    }
+
+    async fn find_children(&self, parent_id: &NetworkId) -> Result<Vec<Network>, RepositoryError> {
+        let children = self
+            .read()?
+            .into_iter()
+            .filter(|n| n.parent().is_some_and(|parent| parent.network_id == *parent_id))
+            .collect();
+        Ok(Self::sorted_by_name(children))
+    }
+
+    async fn find_ancestors(&self, id: &NetworkId) -> Result<Vec<Network>, RepositoryError> {
+        let networks = self.read()?;
+        let find = |id: &NetworkId| networks.iter().find(|n| n.id() == id);
+
+        let mut ancestors = Vec::new();
+        let mut current = find(id)
+            .and_then(|n| n.parent())
+            .map(|parent| parent.network_id.clone());
+        while let Some(parent_id) = current {
+            let Some(parent) = find(&parent_id) else { break };
+            if ancestors.len() > networks.len() {
+                break;
+            }
+            ancestors.push(parent.clone());
+            current = parent.parent().map(|parent| parent.network_id.clone());
+        }
+        Ok(ancestors)
+    }
+
+    async fn subtree_height(&self, id: &NetworkId) -> Result<usize, RepositoryError> {
+        let networks = self.read()?;
+        let mut level = vec![id.clone()];
+        let mut height = 0;
+        loop {
+            level = networks
+                .iter()
+                .filter(|n| n.parent().is_some_and(|parent| level.contains(&parent.network_id)))
+                .map(|n| n.id().clone())
+                .collect();
+            if level.is_empty() || height > networks.len() {
+                return Ok(height);
+            }
+            height += 1;
+        }
+    }
+
+    async fn find_testnets(&self, mainnet_id: &NetworkId) -> Result<Vec<Network>, RepositoryError> {
+        let testnets = self
+            .read()?
+            .into_iter()
+            .filter(|n| n.testnet().mainnet_id.as_ref() == Some(mainnet_id))
+            .collect();
+        Ok(Self::sorted_by_name(testnets))
+    }
+
+    async fn create(&self, network: &Network) -> Result<Network, RepositoryError> {
+        self.read()?;
+        self.networks.lock().unwrap().push(network.clone());
+        Ok(network.clone())
+    }
+
+    async fn update(&self, network: &Network) -> Result<Option<Network>, RepositoryError> {
+        self.read()?;
+        let mut networks = self.networks.lock().unwrap();
+        Ok(networks.iter_mut().find(|n| n.id() == network.id()).map(|stored| {
+            *stored = network.clone();
+            network.clone()
+        }))
+    }
+
+    async fn exists_by_chain_id(&self, chain_id: i32, exclude_id: Option<&NetworkId>) -> Result<bool, RepositoryError> {
+        Ok(self
+            .read()?
+            .iter()
+            .any(|n| n.chain_id() == chain_id && Some(n.id()) != exclude_id))
+    }
+
+    async fn exists_by_slug(&self, slug: &str, exclude_id: Option<&NetworkId>) -> Result<bool, RepositoryError> {
+        Ok(self
+            .read()?
+            .iter()
+            .any(|n| (n.slug() == slug || n.aliases().iter().any(|alias| alias == slug)) && Some(n.id()) != exclude_id))
+    }
+}
+
+/// Event publisher recording what it is given
+#[derive(Default)]
+pub struct RecordingEventPublisher {
+    pub events: Mutex<Vec<NetworkEvent>>,
+}
+
+#[async_trait]
+impl NetworkEventPublisher for RecordingEventPublisher {
+    async fn publish(&self, event: NetworkEvent) {
+        self.events.lock().unwrap().push(event);
+    }
+}
@@ -0,0 +1,158 @@
+//! Transition Network Use Case
+//!
+//! Moves a network through its lifecycle (activate, deprecate, retire).
+
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+
+use crate::domain::gateways::{NetworkEventPublisher, NetworkRepository};
+use crate::domain::models::event::NetworkEvent;
+use crate::domain::models::lifecycle::NetworkStatus;
+use crate::domain::models::network::{Network, NetworkId};
+use crate::shared::errors::UseCaseError;
+
+/// Use case for lifecycle transitions of a network
+pub struct TransitionNetworkUseCase {
+    network_repository: Arc<dyn NetworkRepository>,
+    event_publisher: Arc<dyn NetworkEventPublisher>,
+}
+
+impl TransitionNetworkUseCase {
+    /// Create a new TransitionNetworkUseCase
+    #[must_use]
+    pub fn new(
+        network_repository: Arc<dyn NetworkRepository>,
+        event_publisher: Arc<dyn NetworkEventPublisher>,
+    ) -> Self {
+        Self {
+            network_repository,
+            event_publisher,
+        }
+    }
+
+    /// Execute the use case
+    ///
+    /// # Errors
+    ///
+    /// Returns `UseCaseError::NotFound` if the network doesn't exist.
+    /// Returns `UseCaseError::Domain` if the transition is not allowed from the current state.
+    /// Returns `UseCaseError::Repository` if there's a database error.
+    pub async fn execute(
+        &self,
+        id: &NetworkId,
+        target: NetworkStatus,
+        sunset_at: Option<DateTime<Utc>>,
+    ) -> Result<Network, UseCaseError> {
+        tracing::info!(network_id = %id, target = %target, "Transitioning network");
+
+        let existing = self
+            .network_repository
+            .find_by_id_for_update(id)
+            .await?
+            .ok_or_else(|| {
+                tracing::warn!(network_id = %id, "Network not found for transition");
+                UseCaseError::NotFound {
+                    resource: "Network".to_string(),
+                    id: id.to_string(),
+                }
+            })?;
+
+        let from = existing.status();
+        let transitioned = existing.clone().transition_to(target, sunset_at).inspect_err(|e| {
+            tracing::warn!(network_id = %id, from = %from, target = %target, error = %e, "Transition rejected");
+        })?;
+
+        let result = self
+            .network_repository
+            .update(&transitioned)
+            .await?
+            .ok_or_else(|| UseCaseError::NotFound {
+                resource: "Network".to_string(),
+                id: id.to_string(),
+            })?;
+        self.event_publisher
+            .publish(NetworkEvent::changed(&existing, result.clone()))
+            .await;
+
+        tracing::info!(network_id = %id, from = %from, target = %target, "Network transitioned successfully");
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::use_cases::networks::test_support::{
+        network_data, InMemoryNetworkRepository, RecordingEventPublisher,
+    };
+    use crate::domain::models::event::NetworkEventKind;
+    use crate::domain::models::network::CreateNetworkData;
+    use crate::domain::models::url_policy::UrlPolicy;
+    use crate::shared::errors::DomainError;
+
+    fn create_test_network(status: NetworkStatus) -> Network {
+        let data = CreateNetworkData {
+            status,
+            ..network_data(1, "Ethereum Mainnet")
+        };
+        Network::new(data, &UrlPolicy::default()).expect("valid test data")
+    }
+
+    #[tokio::test]
+    async fn should_deprecate_active_network() {
+        let network = create_test_network(NetworkStatus::Active);
+        let repo = Arc::new(InMemoryNetworkRepository::with_networks([network.clone()]));
+
+        let use_case = TransitionNetworkUseCase::new(repo, Arc::new(RecordingEventPublisher::default()));
+        let sunset_at = Utc::now() + chrono::Duration::days(90);
+        let result = use_case
+            .execute(network.id(), NetworkStatus::Deprecated, Some(sunset_at))
+            .await;
+
+        let deprecated = result.expect("active network can be deprecated");
+        assert_eq!(deprecated.status(), NetworkStatus::Deprecated);
+        assert_eq!(deprecated.sunset_at(), Some(sunset_at));
+    }
+
+    #[tokio::test]
+    async fn should_publish_restored_event_when_draft_is_activated() {
+        let network = create_test_network(NetworkStatus::Draft);
+        let repo = Arc::new(InMemoryNetworkRepository::with_networks([network.clone()]));
+        let publisher = Arc::new(RecordingEventPublisher::default());
+
+        let use_case = TransitionNetworkUseCase::new(repo, publisher.clone());
+        use_case
+            .execute(network.id(), NetworkStatus::Active, None)
+            .await
+            .expect("draft network can be activated");
+
+        let events = publisher.events.lock().unwrap();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].kind, NetworkEventKind::Restored);
+    }
+
+    #[tokio::test]
+    async fn should_reject_forbidden_transition() {
+        let network = create_test_network(NetworkStatus::Draft);
+        let repo = Arc::new(InMemoryNetworkRepository::with_networks([network.clone()]));
+
+        let use_case = TransitionNetworkUseCase::new(repo, Arc::new(RecordingEventPublisher::default()));
+        let result = use_case.execute(network.id(), NetworkStatus::Deprecated, None).await;
+
+        assert!(matches!(
+            result.unwrap_err(),
+            UseCaseError::Domain(DomainError::InvalidState(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn should_return_not_found_when_network_does_not_exist() {
+        let repo = Arc::new(InMemoryNetworkRepository::new());
+
+        let use_case = TransitionNetworkUseCase::new(repo, Arc::new(RecordingEventPublisher::default()));
+        let result = use_case.execute(&NetworkId::new(), NetworkStatus::Active, None).await;
+
+        assert!(matches!(result.unwrap_err(), UseCaseError::NotFound { .. }));
+    }
+}
@@ -0,0 +1,233 @@
+//! Update Contract Use Case
+//!
+//! Replaces the details of a contract in the address book of a network.
+
+use std::sync::Arc;
+
+use crate::domain::gateways::ContractRepository;
+use crate::domain::models::contract::{ContractData, ContractId, NetworkContract};
+use crate::domain::models::network::NetworkId;
+use crate::shared::errors::UseCaseError;
+
+/// Use case for replacing a contract of a network
+pub struct UpdateContractUseCase {
+    contract_repository: Arc<dyn ContractRepository>,
+}
+
+impl UpdateContractUseCase {
+    /// Create a new UpdateContractUseCase
+    #[must_use]
+    pub fn new(contract_repository: Arc<dyn ContractRepository>) -> Self {
+        Self { contract_repository }
+    }
+
+    /// Execute the use case
+    ///
+    /// # Errors
+    ///
+    /// Returns `UseCaseError::NotFound` if the contract doesn't exist on the network.
+    /// Returns `UseCaseError::Conflict` if another contract of the network has the same name.
+    /// Returns `UseCaseError::Domain` if the contract data is invalid.
+    /// Returns `UseCaseError::Repository` if there's a database error.
+    pub async fn execute(
+        &self,
+        network_id: &NetworkId,
+        contract_id: &ContractId,
+        data: ContractData,
+    ) -> Result<NetworkContract, UseCaseError> {
+        tracing::info!(network_id = %network_id, contract_id = %contract_id, "Updating contract");
+
+        let not_found = || UseCaseError::NotFound {
+            resource: "Contract".to_string(),
+            id: contract_id.to_string(),
+        };
+
+        let existing = self
+            .contract_repository
+            .find_by_id(contract_id)
+            .await?
+            .filter(|contract| contract.network_id() == network_id)
+            .ok_or_else(not_found)?;
+
+        let updated = existing.replace(data)?;
+
+        if self
+            .contract_repository
+            .exists_by_name(network_id, updated.name(), Some(contract_id))
+            .await?
+        {
+            tracing::warn!(network_id = %network_id, name = %updated.name(), "Contract name already registered");
+            return Err(UseCaseError::Conflict(format!(
+                "Contract '{}' already exists on this network",
+                updated.name()
+            )));
+        }
+
+        let result = self.contract_repository.update(&updated).await?.ok_or_else(not_found)?;
+
+        tracing::info!(network_id = %network_id, contract_id = %contract_id, "Contract updated successfully");
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::errors::RepositoryError;
+    use async_trait::async_trait;
+    use std::sync::Mutex;
+
+    struct MockContractRepository {
+        contracts: Mutex<Vec<NetworkContract>>,
+    }
+
+    impl MockContractRepository {
+        fn with_contracts(contracts: Vec<NetworkContract>) -> Self {
+            Self {
+                contracts: Mutex::new(contracts),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl ContractRepository for MockContractRepository {
+        async fn find_by_id(&self, id: &ContractId) -> Result<Option<NetworkContract>, RepositoryError> {
+            Ok(self.contracts.lock().unwrap().iter().find(|c| c.id() == id).cloned())
+        }
+
+        async fn find_by_network(&self, network_id: &NetworkId) -> Result<Vec<NetworkContract>, RepositoryError> {
+            Ok(self
+                .contracts
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|c| c.network_id() == network_id)
+                .cloned()
+                .collect())
+        }
+
+        async fn find_by_name(&self, name: &str) -> Result<Vec<NetworkContract>, RepositoryError> {
+            Ok(self
+                .contracts
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|c| c.name().eq_ignore_ascii_case(name))
+                .cloned()
+                .collect())
+        }
+
+        async fn create(&self, contract: &NetworkContract) -> Result<NetworkContract, RepositoryError> {
+            self.contracts.lock().unwrap().push(contract.clone());
+            Ok(contract.clone())
+        }
+
+        async fn update(&self, contract: &NetworkContract) -> Result<Option<NetworkContract>, RepositoryError> {
+            let mut contracts = self.contracts.lock().unwrap();
+            let Some(existing) = contracts.iter_mut().find(|c| c.id() == contract.id()) else {
+                return Ok(None);
+            };
+            *existing = contract.clone();
+            Ok(Some(contract.clone()))
+        }
+
+        async fn delete(&self, id: &ContractId) -> Result<bool, RepositoryError> {
+            let mut contracts = self.contracts.lock().unwrap();
+            let before = contracts.len();
+            contracts.retain(|c| c.id() != id);
+            Ok(contracts.len() != before)
+        }
+
+        async fn exists_by_name(
+            &self,
+            network_id: &NetworkId,
+            name: &str,
+            exclude_id: Option<&ContractId>,
+        ) -> Result<bool, RepositoryError> {
+            Ok(self.contracts.lock().unwrap().iter().any(|c| {
+                c.network_id() == network_id && c.name().eq_ignore_ascii_case(name) && Some(c.id()) != exclude_id
+            }))
+        }
+    }
+
+    fn create_test_contract(network_id: &NetworkId, name: &str) -> NetworkContract {
+        NetworkContract::new(
+            network_id.clone(),
+            ContractData {
+                name: name.to_string(),
+                address: "0xcA11bde05977b3631167028862bE2a173976CA11".to_string(),
+                deployment_block: None,
+                abi_ref: None,
+                tags: vec![],
+            },
+        )
+        .expect("valid contract")
+    }
+
+    fn renamed(name: &str) -> ContractData {
+        ContractData {
+            name: name.to_string(),
+            address: "0x00000000000C2E074eC69A0dFb2997BA6C7d2e1e".to_string(),
+            deployment_block: Some(9_380_380),
+            abi_ref: Some("ens/registry".to_string()),
+            tags: vec!["ens".to_string()],
+        }
+    }
+
+    #[tokio::test]
+    async fn should_replace_contract() {
+        let network_id = NetworkId::new();
+        let contract = create_test_contract(&network_id, "Registry");
+        let repo = Arc::new(MockContractRepository::with_contracts(vec![contract.clone()]));
+
+        let use_case = UpdateContractUseCase::new(repo);
+        let result = use_case
+            .execute(&network_id, contract.id(), renamed("ENS Registry"))
+            .await
+            .unwrap();
+
+        assert_eq!(result.id(), contract.id());
+        assert_eq!(result.name(), "ENS Registry");
+        assert_eq!(result.deployment_block(), Some(9_380_380));
+    }
+
+    #[tokio::test]
+    async fn should_allow_keeping_the_same_name() {
+        let network_id = NetworkId::new();
+        let contract = create_test_contract(&network_id, "Registry");
+        let repo = Arc::new(MockContractRepository::with_contracts(vec![contract.clone()]));
+
+        let use_case = UpdateContractUseCase::new(repo);
+        let result = use_case.execute(&network_id, contract.id(), renamed("registry")).await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn should_return_conflict_when_name_is_taken() {
+        let network_id = NetworkId::new();
+        let contract = create_test_contract(&network_id, "Registry");
+        let other = create_test_contract(&network_id, "Multicall3");
+        let repo = Arc::new(MockContractRepository::with_contracts(vec![contract.clone(), other]));
+
+        let use_case = UpdateContractUseCase::new(repo);
+        let result = use_case
+            .execute(&network_id, contract.id(), renamed("multicall3"))
+            .await;
+
+        assert!(matches!(result.unwrap_err(), UseCaseError::Conflict(_)));
+    }
+
+    #[tokio::test]
+    async fn should_return_not_found_for_contract_of_other_network() {
+        let contract = create_test_contract(&NetworkId::new(), "Registry");
+        let repo = Arc::new(MockContractRepository::with_contracts(vec![contract.clone()]));
+
+        let use_case = UpdateContractUseCase::new(repo);
+        let result = use_case
+            .execute(&NetworkId::new(), contract.id(), renamed("Registry"))
+            .await;
+
+        assert!(matches!(result.unwrap_err(), UseCaseError::NotFound { .. }));
+    }
+}
@@ -0,0 +1,216 @@
+//! Update Signer Use Case
+//!
+//! Replaces the role, address, priority or enable flag of a network signer.
+
+use std::sync::Arc;
+
+use crate::domain::gateways::SignerRepository;
+use crate::domain::models::network::NetworkId;
+use crate::domain::models::signer::{NetworkSigner, SignerData, SignerId};
+use crate::shared::errors::UseCaseError;
+
+/// Use case for replacing a signer of a network
+pub struct UpdateSignerUseCase {
+    signer_repository: Arc<dyn SignerRepository>,
+}
+
+impl UpdateSignerUseCase {
+    /// Create a new UpdateSignerUseCase
+    #[must_use]
+    pub fn new(signer_repository: Arc<dyn SignerRepository>) -> Self {
+        Self { signer_repository }
+    }
+
+    /// Execute the use case
+    ///
+    /// # Errors
+    ///
+    /// Returns `UseCaseError::NotFound` if the signer doesn't exist on the network.
+    /// Returns `UseCaseError::Conflict` if another signer of the network has the same role and address.
+    /// Returns `UseCaseError::Domain` if the signer data is invalid.
+    /// Returns `UseCaseError::Repository` if there's a database error.
+    pub async fn execute(
+        &self,
+        network_id: &NetworkId,
+        signer_id: &SignerId,
+        data: SignerData,
+    ) -> Result<NetworkSigner, UseCaseError> {
+        tracing::info!(network_id = %network_id, signer_id = %signer_id, "Updating signer");
+
+        let not_found = || UseCaseError::NotFound {
+            resource: "Signer".to_string(),
+            id: signer_id.to_string(),
+        };
+
+        let existing = self
+            .signer_repository
+            .find_by_id(signer_id)
+            .await?
+            .filter(|signer| signer.network_id() == network_id)
+            .ok_or_else(not_found)?;
+
+        let updated = existing.replace(data)?;
+
+        if self
+            .signer_repository
+            .exists_by_role_and_address(network_id, updated.role(), updated.address(), Some(signer_id))
+            .await?
+        {
+            tracing::warn!(network_id = %network_id, role = %updated.role(), "Signer already registered for role");
+            return Err(UseCaseError::Conflict(format!(
+                "Signer {} already has role '{}' on this network",
+                updated.address(),
+                updated.role()
+            )));
+        }
+
+        let result = self.signer_repository.update(&updated).await?.ok_or_else(not_found)?;
+
+        tracing::info!(network_id = %network_id, signer_id = %signer_id, "Signer updated successfully");
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::errors::RepositoryError;
+    use async_trait::async_trait;
+    use std::sync::Mutex;
+
+    struct MockSignerRepository {
+        signers: Mutex<Vec<NetworkSigner>>,
+    }
+
+    impl MockSignerRepository {
+        fn with_signers(signers: Vec<NetworkSigner>) -> Self {
+            Self {
+                signers: Mutex::new(signers),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl SignerRepository for MockSignerRepository {
+        async fn find_by_id(&self, id: &SignerId) -> Result<Option<NetworkSigner>, RepositoryError> {
+            Ok(self.signers.lock().unwrap().iter().find(|s| s.id() == id).cloned())
+        }
+
+        async fn find_by_network(&self, network_id: &NetworkId) -> Result<Vec<NetworkSigner>, RepositoryError> {
+            Ok(self
+                .signers
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|s| s.network_id() == network_id)
+                .cloned()
+                .collect())
+        }
+
+        async fn create(&self, signer: &NetworkSigner) -> Result<NetworkSigner, RepositoryError> {
+            self.signers.lock().unwrap().push(signer.clone());
+            Ok(signer.clone())
+        }
+
+        async fn update(&self, signer: &NetworkSigner) -> Result<Option<NetworkSigner>, RepositoryError> {
+            let mut signers = self.signers.lock().unwrap();
+            let Some(existing) = signers.iter_mut().find(|s| s.id() == signer.id()) else {
+                return Ok(None);
+            };
+            *existing = signer.clone();
+            Ok(Some(signer.clone()))
+        }
+
+        async fn delete(&self, id: &SignerId) -> Result<bool, RepositoryError> {
+            let mut signers = self.signers.lock().unwrap();
+            let before = signers.len();
+            signers.retain(|s| s.id() != id);
+            Ok(signers.len() != before)
+        }
+
+        async fn exists_by_role_and_address(
+            &self,
+            network_id: &NetworkId,
+            role: &str,
+            address: &str,
+            exclude_id: Option<&SignerId>,
+        ) -> Result<bool, RepositoryError> {
+            Ok(self.signers.lock().unwrap().iter().any(|s| {
+                s.network_id() == network_id
+                    && s.role() == role
+                    && s.address().eq_ignore_ascii_case(address)
+                    && Some(s.id()) != exclude_id
+            }))
+        }
+    }
+
+    fn create_test_signer(network_id: &NetworkId, role: &str, address: &str) -> NetworkSigner {
+        NetworkSigner::new(
+            network_id.clone(),
+            SignerData {
+                address: address.to_string(),
+                role: role.to_string(),
+                priority: 0,
+                enabled: true,
+            },
+        )
+        .expect("valid signer")
+    }
+
+    const RELAYER: &str = "0x1111111111111111111111111111111111111111";
+    const PAYOUTS: &str = "0x2222222222222222222222222222222222222222";
+
+    fn disabled_relayer(address: &str) -> SignerData {
+        SignerData {
+            address: address.to_string(),
+            role: "relayer".to_string(),
+            priority: 3,
+            enabled: false,
+        }
+    }
+
+    #[tokio::test]
+    async fn should_replace_signer() {
+        let network_id = NetworkId::new();
+        let signer = create_test_signer(&network_id, "relayer", RELAYER);
+        let repo = Arc::new(MockSignerRepository::with_signers(vec![signer.clone()]));
+
+        let use_case = UpdateSignerUseCase::new(repo);
+        let result = use_case
+            .execute(&network_id, signer.id(), disabled_relayer(RELAYER))
+            .await
+            .unwrap();
+
+        assert_eq!(result.id(), signer.id());
+        assert_eq!(result.priority(), 3);
+        assert!(!result.enabled());
+    }
+
+    #[tokio::test]
+    async fn should_return_conflict_when_role_and_address_are_taken() {
+        let network_id = NetworkId::new();
+        let signer = create_test_signer(&network_id, "relayer", RELAYER);
+        let other = create_test_signer(&network_id, "relayer", PAYOUTS);
+        let repo = Arc::new(MockSignerRepository::with_signers(vec![signer.clone(), other]));
+
+        let use_case = UpdateSignerUseCase::new(repo);
+        let result = use_case
+            .execute(&network_id, signer.id(), disabled_relayer(PAYOUTS))
+            .await;
+
+        assert!(matches!(result.unwrap_err(), UseCaseError::Conflict(_)));
+    }
+
+    #[tokio::test]
+    async fn should_return_not_found_for_signer_of_other_network() {
+        let signer = create_test_signer(&NetworkId::new(), "relayer", RELAYER);
+        let repo = Arc::new(MockSignerRepository::with_signers(vec![signer.clone()]));
+
+        let use_case = UpdateSignerUseCase::new(repo);
+        let result = use_case
+            .execute(&NetworkId::new(), signer.id(), disabled_relayer(RELAYER))
+            .await;
+
+        assert!(matches!(result.unwrap_err(), UseCaseError::NotFound { .. }));
+    }
+}
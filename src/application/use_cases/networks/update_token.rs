@@ -0,0 +1,234 @@
+//! Update Token Use Case
+//!
+//! Replaces the metadata of a token listed on a network. The address is the
+//! identity of a token and cannot change.
+
+use std::sync::Arc;
+
+use crate::domain::gateways::TokenRepository;
+use crate::domain::models::network::NetworkId;
+use crate::domain::models::token::{Token, TokenId, TokenMetadata};
+use crate::shared::errors::UseCaseError;
+
+/// Use case for replacing the metadata of a token
+pub struct UpdateTokenUseCase {
+    token_repository: Arc<dyn TokenRepository>,
+}
+
+impl UpdateTokenUseCase {
+    /// Create a new UpdateTokenUseCase
+    #[must_use]
+    pub fn new(token_repository: Arc<dyn TokenRepository>) -> Self {
+        Self { token_repository }
+    }
+
+    /// Execute the use case
+    ///
+    /// Identical metadata leaves the token, and the list version, untouched.
+    ///
+    /// # Errors
+    ///
+    /// Returns `UseCaseError::NotFound` if the token doesn't exist on the network.
+    /// Returns `UseCaseError::Domain` if the metadata is invalid.
+    /// Returns `UseCaseError::Repository` if there's a database error.
+    pub async fn execute(
+        &self,
+        network_id: &NetworkId,
+        token_id: &TokenId,
+        metadata: TokenMetadata,
+    ) -> Result<Token, UseCaseError> {
+        tracing::info!(network_id = %network_id, token_id = %token_id, "Updating token");
+
+        let not_found = || UseCaseError::NotFound {
+            resource: "Token".to_string(),
+            id: token_id.to_string(),
+        };
+
+        let existing = self
+            .token_repository
+            .find_by_id(token_id)
+            .await?
+            .filter(|token| token.network_id() == network_id)
+            .ok_or_else(not_found)?;
+
+        if !existing.differs_from(&metadata) {
+            metadata.validate()?;
+            tracing::debug!(token_id = %token_id, "Token metadata unchanged");
+            return Ok(existing);
+        }
+
+        let updated = existing.with_metadata(metadata)?;
+        let result = self.token_repository.update(&updated).await?.ok_or_else(not_found)?;
+
+        tracing::info!(network_id = %network_id, token_id = %token_id, "Token updated successfully");
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::models::token_list::{TokenListChange, TokenListVersion};
+    use crate::shared::errors::RepositoryError;
+    use async_trait::async_trait;
+    use chrono::Utc;
+    use std::sync::Mutex;
+
+    struct MockTokenRepository {
+        tokens: Mutex<Vec<Token>>,
+        version: Mutex<Option<TokenListVersion>>,
+    }
+
+    impl MockTokenRepository {
+        fn with_tokens(tokens: Vec<Token>) -> Self {
+            Self {
+                tokens: Mutex::new(tokens),
+                version: Mutex::new(None),
+            }
+        }
+
+        fn bump(&self, change: TokenListChange) -> TokenListVersion {
+            let mut version = self.version.lock().unwrap();
+            let bumped = version
+                .unwrap_or_else(|| TokenListVersion::initial(Utc::now()))
+                .bump(change, Utc::now());
+            *version = Some(bumped);
+            bumped
+        }
+    }
+
+    #[async_trait]
+    impl TokenRepository for MockTokenRepository {
+        async fn find_by_id(&self, id: &TokenId) -> Result<Option<Token>, RepositoryError> {
+            Ok(self.tokens.lock().unwrap().iter().find(|t| t.id() == id).cloned())
+        }
+
+        async fn find_by_network(&self, network_id: &NetworkId) -> Result<Vec<Token>, RepositoryError> {
+            Ok(self
+                .tokens
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|t| t.network_id() == network_id)
+                .cloned()
+                .collect())
+        }
+
+        async fn exists_by_address(&self, network_id: &NetworkId, address: &str) -> Result<bool, RepositoryError> {
+            Ok(self
+                .tokens
+                .lock()
+                .unwrap()
+                .iter()
+                .any(|t| t.network_id() == network_id && t.address().eq_ignore_ascii_case(address)))
+        }
+
+        async fn create(&self, token: &Token) -> Result<Token, RepositoryError> {
+            self.tokens.lock().unwrap().push(token.clone());
+            self.bump(TokenListChange::Minor);
+            Ok(token.clone())
+        }
+
+        async fn update(&self, token: &Token) -> Result<Option<Token>, RepositoryError> {
+            let mut tokens = self.tokens.lock().unwrap();
+            let Some(existing) = tokens.iter_mut().find(|t| t.id() == token.id()) else {
+                return Ok(None);
+            };
+            *existing = token.clone();
+            self.bump(TokenListChange::Patch);
+            Ok(Some(token.clone()))
+        }
+
+        async fn delete(&self, id: &TokenId) -> Result<bool, RepositoryError> {
+            let mut tokens = self.tokens.lock().unwrap();
+            let before = tokens.len();
+            tokens.retain(|t| t.id() != id);
+            let deleted = tokens.len() != before;
+            if deleted {
+                self.bump(TokenListChange::Major);
+            }
+            Ok(deleted)
+        }
+
+        async fn import(
+            &self,
+            _network_id: &NetworkId,
+            created: &[Token],
+            updated: &[Token],
+        ) -> Result<TokenListVersion, RepositoryError> {
+            let mut tokens = self.tokens.lock().unwrap();
+            for token in updated {
+                if let Some(existing) = tokens.iter_mut().find(|t| t.id() == token.id()) {
+                    *existing = token.clone();
+                }
+            }
+            tokens.extend_from_slice(created);
+            let change = if created.is_empty() {
+                TokenListChange::Patch
+            } else {
+                TokenListChange::Minor
+            };
+            Ok(self.bump(change))
+        }
+
+        async fn find_version(&self, _network_id: &NetworkId) -> Result<Option<TokenListVersion>, RepositoryError> {
+            Ok(*self.version.lock().unwrap())
+        }
+    }
+
+    fn usdc() -> TokenMetadata {
+        TokenMetadata {
+            symbol: "USDC".to_string(),
+            name: "USD Coin".to_string(),
+            decimals: 6,
+            logo_uri: None,
+            tags: vec!["stablecoin".to_string()],
+        }
+    }
+
+    const USDC: &str = "0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48";
+
+    fn create_test_token(network_id: &NetworkId) -> Token {
+        Token::new(network_id.clone(), USDC.to_string(), usdc()).expect("valid token")
+    }
+
+    #[tokio::test]
+    async fn should_update_metadata_and_bump_patch_version() {
+        let network_id = NetworkId::new();
+        let token = create_test_token(&network_id);
+        let repo = Arc::new(MockTokenRepository::with_tokens(vec![token.clone()]));
+
+        let use_case = UpdateTokenUseCase::new(repo.clone());
+        let metadata = TokenMetadata {
+            logo_uri: Some("https://example.com/usdc.png".to_string()),
+            ..usdc()
+        };
+        let result = use_case.execute(&network_id, token.id(), metadata).await.unwrap();
+
+        assert_eq!(result.logo_uri(), Some("https://example.com/usdc.png"));
+        assert_eq!(repo.version.lock().unwrap().unwrap().to_string(), "1.0.1");
+    }
+
+    #[tokio::test]
+    async fn should_not_bump_version_when_metadata_is_unchanged() {
+        let network_id = NetworkId::new();
+        let token = create_test_token(&network_id);
+        let repo = Arc::new(MockTokenRepository::with_tokens(vec![token.clone()]));
+
+        let use_case = UpdateTokenUseCase::new(repo.clone());
+        use_case.execute(&network_id, token.id(), usdc()).await.unwrap();
+
+        assert!(repo.version.lock().unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn should_return_not_found_for_token_of_other_network() {
+        let token = create_test_token(&NetworkId::new());
+        let repo = Arc::new(MockTokenRepository::with_tokens(vec![token.clone()]));
+
+        let use_case = UpdateTokenUseCase::new(repo);
+        let result = use_case.execute(&NetworkId::new(), token.id(), usdc()).await;
+
+        assert!(matches!(result.unwrap_err(), UseCaseError::NotFound { .. }));
+    }
+}
@@ -0,0 +1,9 @@
+//! Outbox Use Cases
+//!
+//! Business logic for relaying the transactional outbox to its sinks and purging what was published.
+
+mod purge_outbox;
+mod relay_outbox;
+
+pub use purge_outbox::PurgeOutboxUseCase;
+pub use relay_outbox::RelayOutboxUseCase;
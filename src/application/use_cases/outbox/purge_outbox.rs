@@ -0,0 +1,93 @@
+//! Purge Outbox Use Case
+//!
+//! Deletes outbox messages published longer ago than the retention period,
+//! keeping recent ones around for troubleshooting.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::Utc;
+
+use crate::domain::gateways::OutboxRepository;
+use crate::shared::errors::UseCaseError;
+
+/// Use case for purging published outbox messages
+pub struct PurgeOutboxUseCase {
+    outbox_repository: Arc<dyn OutboxRepository>,
+    retention: Duration,
+}
+
+impl PurgeOutboxUseCase {
+    /// Create a new PurgeOutboxUseCase keeping published messages for `retention`
+    #[must_use]
+    pub fn new(outbox_repository: Arc<dyn OutboxRepository>, retention: Duration) -> Self {
+        Self {
+            outbox_repository,
+            retention,
+        }
+    }
+
+    /// Execute the use case
+    ///
+    /// Returns the number of messages deleted.
+    ///
+    /// # Errors
+    ///
+    /// Returns `UseCaseError::Repository` if there's a database error.
+    pub async fn execute(&self) -> Result<u64, UseCaseError> {
+        let retention = chrono::Duration::from_std(self.retention).unwrap_or(chrono::Duration::MAX);
+        let before = Utc::now()
+            .checked_sub_signed(retention)
+            .unwrap_or(chrono::DateTime::<Utc>::MIN_UTC);
+        let purged = self.outbox_repository.purge_published(before).await?;
+
+        if purged > 0 {
+            tracing::debug!(count = purged, "Purged published outbox messages");
+        }
+
+        Ok(purged)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::models::outbox::OutboxMessage;
+    use crate::shared::errors::RepositoryError;
+    use async_trait::async_trait;
+    use chrono::DateTime;
+    use std::sync::Mutex;
+
+    #[derive(Default)]
+    struct MockOutboxRepository {
+        purged_before: Mutex<Option<DateTime<Utc>>>,
+    }
+
+    #[async_trait]
+    impl OutboxRepository for MockOutboxRepository {
+        async fn claim_due(&self, _now: DateTime<Utc>, _limit: u32) -> Result<Vec<OutboxMessage>, RepositoryError> {
+            Ok(vec![])
+        }
+
+        async fn save(&self, _message: &OutboxMessage) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn purge_published(&self, before: DateTime<Utc>) -> Result<u64, RepositoryError> {
+            *self.purged_before.lock().unwrap() = Some(before);
+            Ok(3)
+        }
+    }
+
+    #[tokio::test]
+    async fn should_purge_messages_published_before_retention() {
+        let repo = Arc::new(MockOutboxRepository::default());
+        let use_case = PurgeOutboxUseCase::new(repo.clone(), Duration::from_secs(3600));
+
+        assert_eq!(use_case.execute().await.unwrap(), 3);
+
+        let before = repo.purged_before.lock().unwrap().unwrap();
+        let age = Utc::now() - before;
+        assert!(age >= chrono::Duration::seconds(3600) && age < chrono::Duration::seconds(3660));
+    }
+}
@@ -0,0 +1,251 @@
+//! Relay Outbox Use Case
+//!
+//! Hands the due outbox messages to every sink, with the network as it was
+//! right after the change. A message is published once every sink accepted it, and
+//! retried with exponential backoff otherwise, so delivery is at-least-once.
+
+use std::sync::Arc;
+
+use chrono::Utc;
+
+use crate::domain::gateways::{OutboxRepository, OutboxSink};
+use crate::domain::models::outbox::{OutboxMessage, OutboxRetryPolicy};
+use crate::shared::errors::UseCaseError;
+
+/// Use case for relaying due outbox messages to the sinks
+pub struct RelayOutboxUseCase {
+    outbox_repository: Arc<dyn OutboxRepository>,
+    sinks: Vec<Arc<dyn OutboxSink>>,
+    retry_policy: OutboxRetryPolicy,
+}
+
+impl RelayOutboxUseCase {
+    /// Create a new RelayOutboxUseCase
+    #[must_use]
+    pub fn new(
+        outbox_repository: Arc<dyn OutboxRepository>,
+        sinks: Vec<Arc<dyn OutboxSink>>,
+        retry_policy: OutboxRetryPolicy,
+    ) -> Self {
+        Self {
+            outbox_repository,
+            sinks,
+            retry_policy,
+        }
+    }
+
+    /// Execute the use case
+    ///
+    /// Relays up to `limit` due messages and returns them as saved.
+    ///
+    /// # Errors
+    ///
+    /// Returns `UseCaseError::Repository` if the due messages cannot be
+    /// claimed. Errors relaying a message are logged and the message is
+    /// claimed again once its lease expires.
+    pub async fn execute(&self, limit: u32) -> Result<Vec<OutboxMessage>, UseCaseError> {
+        let due = self.outbox_repository.claim_due(Utc::now(), limit).await?;
+        let mut relayed = Vec::with_capacity(due.len());
+
+        for message in due {
+            let message_id = message.id().clone();
+            match self.relay(message).await {
+                Ok(message) => relayed.push(message),
+                Err(e) => tracing::error!(message_id = %message_id, error = %e, "Failed to relay outbox message"),
+            }
+        }
+
+        Ok(relayed)
+    }
+
+    /// Deliver a claimed message to every sink and save the outcome
+    async fn relay(&self, message: OutboxMessage) -> Result<OutboxMessage, UseCaseError> {
+        let event = message.to_event();
+        let mut errors = Vec::new();
+        for sink in &self.sinks {
+            if let Err(e) = sink.deliver(&event).await {
+                errors.push(format!("{}: {e}", sink.name()));
+            }
+        }
+
+        let message = if errors.is_empty() {
+            tracing::debug!(message_id = %message.id(), event = %message.kind(), "Outbox message relayed");
+            message.publish()
+        } else {
+            let message = message.record_failure(&errors.join("; "), &self.retry_policy);
+            tracing::warn!(
+                message_id = %message.id(),
+                attempts = message.attempts(),
+                next_attempt_at = %message.next_attempt_at(),
+                error = ?message.last_error(),
+                "Outbox message not accepted by every sink, retry scheduled"
+            );
+            message
+        };
+
+        self.outbox_repository.save(&message).await?;
+        Ok(message)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::use_cases::networks::test_support::network;
+    use crate::domain::models::event::NetworkEventKind;
+    use crate::domain::models::network::UpdateNetworkData;
+    use crate::domain::models::outbox::{OutboxEvent, OutboxMessageId};
+    use crate::domain::models::url_policy::UrlPolicy;
+    use crate::shared::errors::RepositoryError;
+    use async_trait::async_trait;
+    use chrono::DateTime;
+    use std::sync::Mutex;
+    use std::time::Duration;
+
+    #[derive(Default)]
+    struct MockOutboxRepository {
+        messages: Mutex<Vec<OutboxMessage>>,
+    }
+
+    #[async_trait]
+    impl OutboxRepository for MockOutboxRepository {
+        async fn claim_due(&self, now: DateTime<Utc>, limit: u32) -> Result<Vec<OutboxMessage>, RepositoryError> {
+            Ok(self
+                .messages
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|m| m.published_at().is_none() && m.next_attempt_at() <= now)
+                .take(limit as usize)
+                .cloned()
+                .collect())
+        }
+
+        async fn save(&self, message: &OutboxMessage) -> Result<(), RepositoryError> {
+            let mut messages = self.messages.lock().unwrap();
+            if let Some(existing) = messages.iter_mut().find(|m| m.id() == message.id()) {
+                *existing = message.clone();
+            }
+            Ok(())
+        }
+
+        async fn purge_published(&self, before: DateTime<Utc>) -> Result<u64, RepositoryError> {
+            let mut messages = self.messages.lock().unwrap();
+            let count = messages.len();
+            messages.retain(|m| m.published_at().map_or(true, |published_at| published_at >= before));
+            Ok((count - messages.len()) as u64)
+        }
+    }
+
+    /// Sink recording the events it receives, failing them while `failing` is set
+    #[derive(Default)]
+    struct MockOutboxSink {
+        failing: Mutex<bool>,
+        received: Mutex<Vec<OutboxEvent>>,
+    }
+
+    #[async_trait]
+    impl OutboxSink for MockOutboxSink {
+        fn name(&self) -> &'static str {
+            "mock"
+        }
+
+        async fn deliver(&self, event: &OutboxEvent) -> Result<(), String> {
+            self.received.lock().unwrap().push(event.clone());
+            if *self.failing.lock().unwrap() {
+                return Err("broker unavailable".to_string());
+            }
+            Ok(())
+        }
+    }
+
+    fn policy() -> OutboxRetryPolicy {
+        OutboxRetryPolicy {
+            base_delay: Duration::ZERO,
+            max_delay: Duration::ZERO,
+        }
+    }
+
+    fn setup(messages: Vec<OutboxMessage>) -> (Arc<MockOutboxRepository>, Arc<MockOutboxSink>, RelayOutboxUseCase) {
+        let outbox_repo = Arc::new(MockOutboxRepository {
+            messages: Mutex::new(messages),
+        });
+        let sink = Arc::new(MockOutboxSink::default());
+        let use_case = RelayOutboxUseCase::new(outbox_repo.clone(), vec![sink.clone()], policy());
+        (outbox_repo, sink, use_case)
+    }
+
+    fn received_ids(sink: &MockOutboxSink) -> Vec<OutboxMessageId> {
+        sink.received
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|event| event.id.clone())
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn should_relay_due_messages_to_sinks() {
+        let message = OutboxMessage::new(NetworkEventKind::Created, network(1, "Ethereum Mainnet"));
+        let message_id = message.id().clone();
+        let (_, sink, use_case) = setup(vec![message]);
+
+        let relayed = use_case.execute(10).await.unwrap();
+
+        assert_eq!(relayed.len(), 1);
+        assert!(relayed[0].published_at().is_some());
+        assert_eq!(received_ids(&sink), [message_id]);
+        assert!(use_case.execute(10).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn should_relay_each_change_with_its_own_snapshot() {
+        let created = network(1, "Ethereum Mainnet");
+        let rename = UpdateNetworkData {
+            name: Some("Ethereum".to_string()),
+            ..Default::default()
+        };
+        let renamed = created.clone().with_updates(rename, &UrlPolicy::default()).unwrap();
+        let (_, sink, use_case) = setup(vec![
+            OutboxMessage::new(NetworkEventKind::Created, created),
+            OutboxMessage::new(NetworkEventKind::Updated, renamed),
+        ]);
+
+        use_case.execute(10).await.unwrap();
+
+        let names: Vec<String> = sink
+            .received
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|event| event.event.network.name().to_string())
+            .collect();
+        assert_eq!(names, ["Ethereum Mainnet", "Ethereum"]);
+    }
+
+    #[tokio::test]
+    async fn should_retry_with_the_same_id_until_sinks_accept() {
+        let message = OutboxMessage::new(NetworkEventKind::Updated, network(1, "Ethereum Mainnet"));
+        let message_id = message.id().clone();
+        let (outbox_repo, sink, use_case) = setup(vec![message]);
+        *sink.failing.lock().unwrap() = true;
+
+        let relayed = use_case.execute(10).await.unwrap();
+        assert!(relayed[0].published_at().is_none());
+        assert_eq!(relayed[0].attempts(), 1);
+        assert_eq!(relayed[0].last_error(), Some("mock: broker unavailable"));
+
+        *sink.failing.lock().unwrap() = false;
+        let relayed = use_case.execute(10).await.unwrap();
+        assert!(relayed[0].published_at().is_some());
+
+        assert_eq!(received_ids(&sink), [message_id.clone(), message_id]);
+        assert_eq!(
+            outbox_repo
+                .purge_published(Utc::now() + chrono::Duration::seconds(1))
+                .await
+                .unwrap(),
+            1
+        );
+    }
+}
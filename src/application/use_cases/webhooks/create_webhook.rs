@@ -0,0 +1,151 @@
+//! Create Webhook Use Case
+//!
+//! Subscribes an HTTP endpoint to network changes.
+
+use std::sync::Arc;
+
+use crate::domain::gateways::WebhookRepository;
+use crate::domain::models::url_policy::UrlPolicy;
+use crate::domain::models::webhook::{Webhook, WebhookData};
+use crate::shared::errors::UseCaseError;
+
+/// Use case for creating a webhook subscription
+pub struct CreateWebhookUseCase {
+    webhook_repository: Arc<dyn WebhookRepository>,
+    url_policy: Arc<UrlPolicy>,
+}
+
+impl CreateWebhookUseCase {
+    /// Create a new CreateWebhookUseCase
+    #[must_use]
+    pub fn new(webhook_repository: Arc<dyn WebhookRepository>, url_policy: Arc<UrlPolicy>) -> Self {
+        Self {
+            webhook_repository,
+            url_policy,
+        }
+    }
+
+    /// Execute the use case
+    ///
+    /// A secret is generated when none is given; the returned webhook carries it.
+    ///
+    /// # Errors
+    ///
+    /// Returns `UseCaseError::Domain` if the webhook data is invalid.
+    /// Returns `UseCaseError::Repository` if there's a database error.
+    pub async fn execute(&self, data: WebhookData, created_by: &str) -> Result<Webhook, UseCaseError> {
+        tracing::info!(url = %data.url, created_by, "Creating webhook");
+
+        let webhook = Webhook::new(data, created_by.to_string(), &self.url_policy)?;
+        let created = self.webhook_repository.create(&webhook).await?;
+
+        tracing::info!(webhook_id = %created.id(), "Webhook created successfully");
+        Ok(created)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::models::webhook::WebhookId;
+    use crate::shared::errors::{DomainError, RepositoryError};
+    use async_trait::async_trait;
+    use std::sync::Mutex;
+
+    struct MockWebhookRepository {
+        webhooks: Mutex<Vec<Webhook>>,
+    }
+
+    impl MockWebhookRepository {
+        fn with_webhooks(webhooks: Vec<Webhook>) -> Self {
+            Self {
+                webhooks: Mutex::new(webhooks),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl WebhookRepository for MockWebhookRepository {
+        async fn find_by_id(&self, id: &WebhookId) -> Result<Option<Webhook>, RepositoryError> {
+            Ok(self.webhooks.lock().unwrap().iter().find(|w| w.id() == id).cloned())
+        }
+
+        async fn find_all(&self) -> Result<Vec<Webhook>, RepositoryError> {
+            Ok(self.webhooks.lock().unwrap().clone())
+        }
+
+        async fn find_enabled(&self) -> Result<Vec<Webhook>, RepositoryError> {
+            Ok(self
+                .webhooks
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|w| w.enabled())
+                .cloned()
+                .collect())
+        }
+
+        async fn create(&self, webhook: &Webhook) -> Result<Webhook, RepositoryError> {
+            self.webhooks.lock().unwrap().push(webhook.clone());
+            Ok(webhook.clone())
+        }
+
+        async fn update(&self, webhook: &Webhook) -> Result<Option<Webhook>, RepositoryError> {
+            let mut webhooks = self.webhooks.lock().unwrap();
+            let Some(existing) = webhooks.iter_mut().find(|w| w.id() == webhook.id()) else {
+                return Ok(None);
+            };
+            *existing = webhook.clone();
+            Ok(Some(webhook.clone()))
+        }
+
+        async fn delete(&self, id: &WebhookId) -> Result<bool, RepositoryError> {
+            let mut webhooks = self.webhooks.lock().unwrap();
+            let before = webhooks.len();
+            webhooks.retain(|w| w.id() != id);
+            Ok(webhooks.len() != before)
+        }
+    }
+    fn webhook_data() -> WebhookData {
+        WebhookData {
+            url: "https://hooks.example.com/networks".to_string(),
+            events: vec![],
+            network_ids: vec![],
+            secret: None,
+            enabled: true,
+        }
+    }
+    #[tokio::test]
+    async fn should_create_webhook_with_generated_secret() {
+        let repo = Arc::new(MockWebhookRepository::with_webhooks(vec![]));
+
+        let use_case = CreateWebhookUseCase::new(repo.clone(), Arc::default());
+        let webhook = use_case.execute(webhook_data(), "user-1").await.unwrap();
+
+        assert!(!webhook.secret().is_empty());
+        assert_eq!(webhook.created_by(), "user-1");
+        assert_eq!(repo.webhooks.lock().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn should_reject_internal_url() {
+        let repo = Arc::new(MockWebhookRepository::with_webhooks(vec![]));
+
+        let use_case = CreateWebhookUseCase::new(repo.clone(), Arc::default());
+        let result = use_case
+            .execute(
+                WebhookData {
+                    url: "http://169.254.169.254/latest/meta-data".to_string(),
+                    ..webhook_data()
+                },
+                "user-1",
+            )
+            .await;
+
+        assert!(matches!(
+            result.unwrap_err(),
+            UseCaseError::Domain(DomainError::ValidationError(_))
+        ));
+        assert!(repo.webhooks.lock().unwrap().is_empty());
+    }
+}
@@ -0,0 +1,385 @@
+//! Deliver Due Webhooks Use Case
+//!
+//! Sends the webhook deliveries whose next attempt is due, recording each
+//! attempt and scheduling retries with exponential backoff.
+
+use std::sync::Arc;
+
+use chrono::Utc;
+
+use crate::domain::gateways::{WebhookDeliveryRepository, WebhookRepository, WebhookSender};
+use crate::domain::models::webhook::{DeliveryStatus, WebhookDelivery, WebhookRetryPolicy};
+use crate::shared::errors::UseCaseError;
+
+/// Use case for sending due webhook deliveries
+pub struct DeliverDueWebhooksUseCase {
+    webhook_repository: Arc<dyn WebhookRepository>,
+    delivery_repository: Arc<dyn WebhookDeliveryRepository>,
+    webhook_sender: Arc<dyn WebhookSender>,
+    retry_policy: WebhookRetryPolicy,
+}
+
+impl DeliverDueWebhooksUseCase {
+    /// Create a new DeliverDueWebhooksUseCase
+    #[must_use]
+    pub fn new(
+        webhook_repository: Arc<dyn WebhookRepository>,
+        delivery_repository: Arc<dyn WebhookDeliveryRepository>,
+        webhook_sender: Arc<dyn WebhookSender>,
+        retry_policy: WebhookRetryPolicy,
+    ) -> Self {
+        Self {
+            webhook_repository,
+            delivery_repository,
+            webhook_sender,
+            retry_policy,
+        }
+    }
+
+    /// Execute the use case
+    ///
+    /// Sends up to `limit` due deliveries and returns them as recorded.
+    /// Deliveries of a disabled webhook fail without being sent.
+    ///
+    /// # Errors
+    ///
+    /// Returns `UseCaseError::Repository` if the due deliveries cannot be
+    /// claimed. Errors recording an attempt are logged and the delivery is
+    /// claimed again once its lease expires.
+    pub async fn execute(&self, limit: u32) -> Result<Vec<WebhookDelivery>, UseCaseError> {
+        let due = self.delivery_repository.claim_due(Utc::now(), limit).await?;
+        let mut recorded = Vec::with_capacity(due.len());
+
+        for delivery in due {
+            let delivery_id = delivery.id().clone();
+            match self.deliver(delivery).await {
+                Ok(delivery) => recorded.push(delivery),
+                Err(e) => {
+                    tracing::error!(delivery_id = %delivery_id, error = %e, "Failed to record webhook delivery attempt");
+                }
+            }
+        }
+
+        Ok(recorded)
+    }
+
+    /// Send a claimed delivery and record the attempt
+    async fn deliver(&self, delivery: WebhookDelivery) -> Result<WebhookDelivery, UseCaseError> {
+        let webhook = self
+            .webhook_repository
+            .find_by_id(delivery.webhook_id())
+            .await?
+            .filter(|webhook| webhook.enabled());
+
+        let (delivery, attempt) = match webhook {
+            Some(webhook) => {
+                let outcome = self.webhook_sender.send(&webhook, &delivery).await;
+                delivery.record_attempt(outcome, &self.retry_policy)
+            }
+            None => delivery.abandon("webhook is disabled"),
+        };
+
+        match delivery.status() {
+            DeliveryStatus::Succeeded => {
+                tracing::debug!(delivery_id = %delivery.id(), "Webhook delivered");
+            }
+            DeliveryStatus::Pending => tracing::info!(
+                delivery_id = %delivery.id(),
+                attempts = delivery.attempts(),
+                next_attempt_at = %delivery.next_attempt_at(),
+                error = ?attempt.error,
+                status_code = ?attempt.status_code,
+                "Webhook delivery failed, retry scheduled"
+            ),
+            DeliveryStatus::Failed => tracing::warn!(
+                delivery_id = %delivery.id(),
+                attempts = delivery.attempts(),
+                error = ?attempt.error,
+                status_code = ?attempt.status_code,
+                "Webhook delivery failed for good"
+            ),
+        }
+
+        let recorded = self.delivery_repository.record_attempt(&delivery, &attempt).await?;
+        Ok(recorded.unwrap_or(delivery))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::models::event::NetworkEventKind;
+    use crate::domain::models::network::NetworkId;
+    use crate::domain::models::url_policy::UrlPolicy;
+    use crate::domain::models::webhook::{
+        DeliveryAttempt, DeliveryId, DeliveryOutcome, Webhook, WebhookData, WebhookId,
+    };
+    use crate::shared::errors::RepositoryError;
+    use async_trait::async_trait;
+    use chrono::DateTime;
+    use std::sync::Mutex;
+    use std::time::Duration;
+
+    struct MockWebhookRepository {
+        webhooks: Mutex<Vec<Webhook>>,
+    }
+
+    impl MockWebhookRepository {
+        fn with_webhooks(webhooks: Vec<Webhook>) -> Self {
+            Self {
+                webhooks: Mutex::new(webhooks),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl WebhookRepository for MockWebhookRepository {
+        async fn find_by_id(&self, id: &WebhookId) -> Result<Option<Webhook>, RepositoryError> {
+            Ok(self.webhooks.lock().unwrap().iter().find(|w| w.id() == id).cloned())
+        }
+
+        async fn find_all(&self) -> Result<Vec<Webhook>, RepositoryError> {
+            Ok(self.webhooks.lock().unwrap().clone())
+        }
+
+        async fn find_enabled(&self) -> Result<Vec<Webhook>, RepositoryError> {
+            Ok(self
+                .webhooks
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|w| w.enabled())
+                .cloned()
+                .collect())
+        }
+
+        async fn create(&self, webhook: &Webhook) -> Result<Webhook, RepositoryError> {
+            self.webhooks.lock().unwrap().push(webhook.clone());
+            Ok(webhook.clone())
+        }
+
+        async fn update(&self, webhook: &Webhook) -> Result<Option<Webhook>, RepositoryError> {
+            let mut webhooks = self.webhooks.lock().unwrap();
+            let Some(existing) = webhooks.iter_mut().find(|w| w.id() == webhook.id()) else {
+                return Ok(None);
+            };
+            *existing = webhook.clone();
+            Ok(Some(webhook.clone()))
+        }
+
+        async fn delete(&self, id: &WebhookId) -> Result<bool, RepositoryError> {
+            let mut webhooks = self.webhooks.lock().unwrap();
+            let before = webhooks.len();
+            webhooks.retain(|w| w.id() != id);
+            Ok(webhooks.len() != before)
+        }
+    }
+    #[derive(Default)]
+    struct MockWebhookDeliveryRepository {
+        deliveries: Mutex<Vec<WebhookDelivery>>,
+        attempts: Mutex<Vec<DeliveryAttempt>>,
+    }
+
+    impl MockWebhookDeliveryRepository {
+        fn with_deliveries(deliveries: Vec<WebhookDelivery>) -> Self {
+            Self {
+                deliveries: Mutex::new(deliveries),
+                attempts: Mutex::default(),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl WebhookDeliveryRepository for MockWebhookDeliveryRepository {
+        async fn find_by_id(&self, id: &DeliveryId) -> Result<Option<WebhookDelivery>, RepositoryError> {
+            Ok(self.deliveries.lock().unwrap().iter().find(|d| d.id() == id).cloned())
+        }
+
+        async fn find_by_webhook(
+            &self,
+            webhook_id: &WebhookId,
+            limit: u32,
+        ) -> Result<Vec<WebhookDelivery>, RepositoryError> {
+            Ok(self
+                .deliveries
+                .lock()
+                .unwrap()
+                .iter()
+                .rev()
+                .filter(|d| d.webhook_id() == webhook_id)
+                .take(limit as usize)
+                .cloned()
+                .collect())
+        }
+
+        async fn find_attempts(&self, id: &DeliveryId) -> Result<Vec<DeliveryAttempt>, RepositoryError> {
+            Ok(self
+                .attempts
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|a| &a.delivery_id == id)
+                .cloned()
+                .collect())
+        }
+
+        async fn enqueue(&self, deliveries: &[WebhookDelivery]) -> Result<(), RepositoryError> {
+            self.deliveries.lock().unwrap().extend_from_slice(deliveries);
+            Ok(())
+        }
+
+        async fn claim_due(&self, now: DateTime<Utc>, limit: u32) -> Result<Vec<WebhookDelivery>, RepositoryError> {
+            Ok(self
+                .deliveries
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|d| d.status() == DeliveryStatus::Pending && d.next_attempt_at() <= now)
+                .take(limit as usize)
+                .cloned()
+                .collect())
+        }
+
+        async fn record_attempt(
+            &self,
+            delivery: &WebhookDelivery,
+            attempt: &DeliveryAttempt,
+        ) -> Result<Option<WebhookDelivery>, RepositoryError> {
+            self.attempts.lock().unwrap().push(attempt.clone());
+            let mut deliveries = self.deliveries.lock().unwrap();
+            let Some(existing) = deliveries.iter_mut().find(|d| d.id() == delivery.id()) else {
+                return Ok(None);
+            };
+            *existing = delivery.clone();
+            Ok(Some(delivery.clone()))
+        }
+
+        async fn requeue(&self, delivery: &WebhookDelivery) -> Result<Option<WebhookDelivery>, RepositoryError> {
+            let mut deliveries = self.deliveries.lock().unwrap();
+            let Some(existing) = deliveries
+                .iter_mut()
+                .find(|d| d.id() == delivery.id() && d.status() != DeliveryStatus::Pending)
+            else {
+                return Ok(None);
+            };
+            *existing = delivery.clone();
+            Ok(Some(delivery.clone()))
+        }
+    }
+    /// Sender answering every delivery with the given status, or failing to connect
+    struct MockWebhookSender {
+        status_code: Option<u16>,
+        sent: Mutex<Vec<DeliveryId>>,
+    }
+
+    impl MockWebhookSender {
+        fn responding(status_code: Option<u16>) -> Self {
+            Self {
+                status_code,
+                sent: Mutex::default(),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl WebhookSender for MockWebhookSender {
+        async fn send(&self, _webhook: &Webhook, delivery: &WebhookDelivery) -> DeliveryOutcome {
+            self.sent.lock().unwrap().push(delivery.id().clone());
+            DeliveryOutcome {
+                status_code: self.status_code,
+                response_body: None,
+                error: self.status_code.is_none().then(|| "connection refused".to_string()),
+                duration: Duration::from_millis(5),
+            }
+        }
+    }
+
+    fn webhook_data() -> WebhookData {
+        WebhookData {
+            url: "https://hooks.example.com/networks".to_string(),
+            events: vec![],
+            network_ids: vec![],
+            secret: None,
+            enabled: true,
+        }
+    }
+    fn policy() -> WebhookRetryPolicy {
+        WebhookRetryPolicy {
+            max_attempts: 2,
+            base_delay: Duration::ZERO,
+            max_delay: Duration::ZERO,
+        }
+    }
+
+    fn setup(
+        webhook: &Webhook,
+        status_code: Option<u16>,
+    ) -> (
+        Arc<MockWebhookDeliveryRepository>,
+        Arc<MockWebhookSender>,
+        DeliverDueWebhooksUseCase,
+    ) {
+        let delivery = WebhookDelivery::new(
+            webhook.id().clone(),
+            NetworkEventKind::Updated,
+            NetworkId::new(),
+            "{}".to_string(),
+        );
+        let webhook_repo = Arc::new(MockWebhookRepository::with_webhooks(vec![webhook.clone()]));
+        let delivery_repo = Arc::new(MockWebhookDeliveryRepository::with_deliveries(vec![delivery]));
+        let sender = Arc::new(MockWebhookSender::responding(status_code));
+        let use_case = DeliverDueWebhooksUseCase::new(webhook_repo, delivery_repo.clone(), sender.clone(), policy());
+        (delivery_repo, sender, use_case)
+    }
+
+    #[tokio::test]
+    async fn should_deliver_due_deliveries() {
+        let webhook = Webhook::new(webhook_data(), "user-1".to_string(), &UrlPolicy::default()).unwrap();
+        let (delivery_repo, sender, use_case) = setup(&webhook, Some(200));
+
+        let recorded = use_case.execute(10).await.unwrap();
+
+        assert_eq!(recorded.len(), 1);
+        assert_eq!(recorded[0].status(), DeliveryStatus::Succeeded);
+        assert_eq!(sender.sent.lock().unwrap().len(), 1);
+        assert_eq!(delivery_repo.attempts.lock().unwrap().len(), 1);
+        assert!(use_case.execute(10).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn should_retry_until_attempts_run_out() {
+        let webhook = Webhook::new(webhook_data(), "user-1".to_string(), &UrlPolicy::default()).unwrap();
+        let (delivery_repo, sender, use_case) = setup(&webhook, None);
+
+        let first = use_case.execute(10).await.unwrap();
+        assert_eq!(first[0].status(), DeliveryStatus::Pending);
+
+        let second = use_case.execute(10).await.unwrap();
+        assert_eq!(second[0].status(), DeliveryStatus::Failed);
+        assert_eq!(second[0].attempts(), 2);
+
+        assert!(use_case.execute(10).await.unwrap().is_empty());
+        assert_eq!(sender.sent.lock().unwrap().len(), 2);
+        let attempts = delivery_repo.attempts.lock().unwrap();
+        assert_eq!(attempts[1].error.as_deref(), Some("connection refused"));
+    }
+
+    #[tokio::test]
+    async fn should_fail_deliveries_of_disabled_webhook_without_sending() {
+        let webhook = Webhook::new(
+            WebhookData {
+                enabled: false,
+                ..webhook_data()
+            },
+            "user-1".to_string(),
+            &UrlPolicy::default(),
+        )
+        .unwrap();
+        let (_, sender, use_case) = setup(&webhook, Some(200));
+
+        let recorded = use_case.execute(10).await.unwrap();
+
+        assert_eq!(recorded[0].status(), DeliveryStatus::Failed);
+        assert!(sender.sent.lock().unwrap().is_empty());
+    }
+}
@@ -0,0 +1,337 @@
+//! Enqueue Webhook Deliveries Use Case
+//!
+//! Queues a network change for delivery to every webhook subscribed to it.
+//! Deliveries are persisted, so they are sent even if the service restarts
+//! before the dispatcher picks them up. Their IDs derive from the outbox
+//! event, so an event relayed more than once is only queued once.
+
+use std::sync::Arc;
+
+use crate::domain::gateways::{WebhookDeliveryRepository, WebhookRepository};
+use crate::domain::models::event::NetworkEventKind;
+use crate::domain::models::network::NetworkId;
+use crate::domain::models::outbox::OutboxMessageId;
+use crate::domain::models::webhook::WebhookDelivery;
+use crate::shared::errors::UseCaseError;
+
+/// Use case for queueing deliveries of a network change
+pub struct EnqueueWebhookDeliveriesUseCase {
+    webhook_repository: Arc<dyn WebhookRepository>,
+    delivery_repository: Arc<dyn WebhookDeliveryRepository>,
+}
+
+impl EnqueueWebhookDeliveriesUseCase {
+    /// Create a new EnqueueWebhookDeliveriesUseCase
+    #[must_use]
+    pub fn new(
+        webhook_repository: Arc<dyn WebhookRepository>,
+        delivery_repository: Arc<dyn WebhookDeliveryRepository>,
+    ) -> Self {
+        Self {
+            webhook_repository,
+            delivery_repository,
+        }
+    }
+
+    /// Execute the use case
+    ///
+    /// `payload` is the JSON body sent to the receivers. Returns the number
+    /// of webhooks the event is delivered to.
+    ///
+    /// # Errors
+    ///
+    /// Returns `UseCaseError::Repository` if there's a database error.
+    pub async fn execute(
+        &self,
+        event_id: &OutboxMessageId,
+        event: NetworkEventKind,
+        network_id: &NetworkId,
+        payload: &str,
+    ) -> Result<usize, UseCaseError> {
+        let deliveries: Vec<WebhookDelivery> = self
+            .webhook_repository
+            .find_enabled()
+            .await?
+            .into_iter()
+            .filter(|webhook| webhook.matches(event, network_id))
+            .map(|webhook| {
+                WebhookDelivery::for_event(
+                    event_id,
+                    webhook.id().clone(),
+                    event,
+                    network_id.clone(),
+                    payload.to_string(),
+                )
+            })
+            .collect();
+
+        if !deliveries.is_empty() {
+            self.delivery_repository.enqueue(&deliveries).await?;
+            tracing::debug!(network_id = %network_id, event = %event, count = deliveries.len(), "Queued webhook deliveries");
+        }
+
+        Ok(deliveries.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::models::url_policy::UrlPolicy;
+    use crate::domain::models::webhook::{
+        DeliveryAttempt, DeliveryId, DeliveryStatus, Webhook, WebhookData, WebhookId,
+    };
+    use crate::shared::errors::RepositoryError;
+    use async_trait::async_trait;
+    use chrono::{DateTime, Utc};
+    use std::sync::Mutex;
+
+    struct MockWebhookRepository {
+        webhooks: Mutex<Vec<Webhook>>,
+    }
+
+    impl MockWebhookRepository {
+        fn with_webhooks(webhooks: Vec<Webhook>) -> Self {
+            Self {
+                webhooks: Mutex::new(webhooks),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl WebhookRepository for MockWebhookRepository {
+        async fn find_by_id(&self, id: &WebhookId) -> Result<Option<Webhook>, RepositoryError> {
+            Ok(self.webhooks.lock().unwrap().iter().find(|w| w.id() == id).cloned())
+        }
+
+        async fn find_all(&self) -> Result<Vec<Webhook>, RepositoryError> {
+            Ok(self.webhooks.lock().unwrap().clone())
+        }
+
+        async fn find_enabled(&self) -> Result<Vec<Webhook>, RepositoryError> {
+            Ok(self
+                .webhooks
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|w| w.enabled())
+                .cloned()
+                .collect())
+        }
+
+        async fn create(&self, webhook: &Webhook) -> Result<Webhook, RepositoryError> {
+            self.webhooks.lock().unwrap().push(webhook.clone());
+            Ok(webhook.clone())
+        }
+
+        async fn update(&self, webhook: &Webhook) -> Result<Option<Webhook>, RepositoryError> {
+            let mut webhooks = self.webhooks.lock().unwrap();
+            let Some(existing) = webhooks.iter_mut().find(|w| w.id() == webhook.id()) else {
+                return Ok(None);
+            };
+            *existing = webhook.clone();
+            Ok(Some(webhook.clone()))
+        }
+
+        async fn delete(&self, id: &WebhookId) -> Result<bool, RepositoryError> {
+            let mut webhooks = self.webhooks.lock().unwrap();
+            let before = webhooks.len();
+            webhooks.retain(|w| w.id() != id);
+            Ok(webhooks.len() != before)
+        }
+    }
+    #[derive(Default)]
+    struct MockWebhookDeliveryRepository {
+        deliveries: Mutex<Vec<WebhookDelivery>>,
+        attempts: Mutex<Vec<DeliveryAttempt>>,
+    }
+
+    #[async_trait]
+    impl WebhookDeliveryRepository for MockWebhookDeliveryRepository {
+        async fn find_by_id(&self, id: &DeliveryId) -> Result<Option<WebhookDelivery>, RepositoryError> {
+            Ok(self.deliveries.lock().unwrap().iter().find(|d| d.id() == id).cloned())
+        }
+
+        async fn find_by_webhook(
+            &self,
+            webhook_id: &WebhookId,
+            limit: u32,
+        ) -> Result<Vec<WebhookDelivery>, RepositoryError> {
+            Ok(self
+                .deliveries
+                .lock()
+                .unwrap()
+                .iter()
+                .rev()
+                .filter(|d| d.webhook_id() == webhook_id)
+                .take(limit as usize)
+                .cloned()
+                .collect())
+        }
+
+        async fn find_attempts(&self, id: &DeliveryId) -> Result<Vec<DeliveryAttempt>, RepositoryError> {
+            Ok(self
+                .attempts
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|a| &a.delivery_id == id)
+                .cloned()
+                .collect())
+        }
+
+        async fn enqueue(&self, deliveries: &[WebhookDelivery]) -> Result<(), RepositoryError> {
+            let mut queued = self.deliveries.lock().unwrap();
+            for delivery in deliveries {
+                if !queued.iter().any(|d| d.id() == delivery.id()) {
+                    queued.push(delivery.clone());
+                }
+            }
+            Ok(())
+        }
+
+        async fn claim_due(&self, now: DateTime<Utc>, limit: u32) -> Result<Vec<WebhookDelivery>, RepositoryError> {
+            Ok(self
+                .deliveries
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|d| d.status() == DeliveryStatus::Pending && d.next_attempt_at() <= now)
+                .take(limit as usize)
+                .cloned()
+                .collect())
+        }
+
+        async fn record_attempt(
+            &self,
+            delivery: &WebhookDelivery,
+            attempt: &DeliveryAttempt,
+        ) -> Result<Option<WebhookDelivery>, RepositoryError> {
+            self.attempts.lock().unwrap().push(attempt.clone());
+            let mut deliveries = self.deliveries.lock().unwrap();
+            let Some(existing) = deliveries.iter_mut().find(|d| d.id() == delivery.id()) else {
+                return Ok(None);
+            };
+            *existing = delivery.clone();
+            Ok(Some(delivery.clone()))
+        }
+
+        async fn requeue(&self, delivery: &WebhookDelivery) -> Result<Option<WebhookDelivery>, RepositoryError> {
+            let mut deliveries = self.deliveries.lock().unwrap();
+            let Some(existing) = deliveries
+                .iter_mut()
+                .find(|d| d.id() == delivery.id() && d.status() != DeliveryStatus::Pending)
+            else {
+                return Ok(None);
+            };
+            *existing = delivery.clone();
+            Ok(Some(delivery.clone()))
+        }
+    }
+    fn webhook_data() -> WebhookData {
+        WebhookData {
+            url: "https://hooks.example.com/networks".to_string(),
+            events: vec![],
+            network_ids: vec![],
+            secret: None,
+            enabled: true,
+        }
+    }
+    #[tokio::test]
+    async fn should_queue_deliveries_for_matching_webhooks() {
+        let network_id = NetworkId::new();
+        let all = Webhook::new(webhook_data(), "user-1".to_string(), &UrlPolicy::default()).unwrap();
+        let updates_only = Webhook::new(
+            WebhookData {
+                events: vec![NetworkEventKind::Updated],
+                ..webhook_data()
+            },
+            "user-1".to_string(),
+            &UrlPolicy::default(),
+        )
+        .unwrap();
+        let other_network = Webhook::new(
+            WebhookData {
+                network_ids: vec![NetworkId::new()],
+                ..webhook_data()
+            },
+            "user-1".to_string(),
+            &UrlPolicy::default(),
+        )
+        .unwrap();
+        let disabled = Webhook::new(
+            WebhookData {
+                enabled: false,
+                ..webhook_data()
+            },
+            "user-1".to_string(),
+            &UrlPolicy::default(),
+        )
+        .unwrap();
+        let webhook_repo = Arc::new(MockWebhookRepository::with_webhooks(vec![
+            all.clone(),
+            updates_only,
+            other_network,
+            disabled,
+        ]));
+        let delivery_repo = Arc::new(MockWebhookDeliveryRepository::default());
+
+        let use_case = EnqueueWebhookDeliveriesUseCase::new(webhook_repo, delivery_repo.clone());
+        let queued = use_case
+            .execute(
+                &OutboxMessageId::new(),
+                NetworkEventKind::Created,
+                &network_id,
+                r#"{"type":"created"}"#,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(queued, 1);
+        let deliveries = delivery_repo.deliveries.lock().unwrap();
+        assert_eq!(deliveries[0].webhook_id(), all.id());
+        assert_eq!(deliveries[0].payload(), r#"{"type":"created"}"#);
+        assert_eq!(deliveries[0].status(), DeliveryStatus::Pending);
+    }
+
+    #[tokio::test]
+    async fn should_queue_nothing_without_webhooks() {
+        let webhook_repo = Arc::new(MockWebhookRepository::with_webhooks(vec![]));
+        let delivery_repo = Arc::new(MockWebhookDeliveryRepository::default());
+
+        let use_case = EnqueueWebhookDeliveriesUseCase::new(webhook_repo, delivery_repo.clone());
+        let queued = use_case
+            .execute(
+                &OutboxMessageId::new(),
+                NetworkEventKind::Updated,
+                &NetworkId::new(),
+                "{}",
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(queued, 0);
+        assert!(delivery_repo.deliveries.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn should_queue_a_relayed_event_once() {
+        let webhook = Webhook::new(webhook_data(), "user-1".to_string(), &UrlPolicy::default()).unwrap();
+        let webhook_repo = Arc::new(MockWebhookRepository::with_webhooks(vec![webhook.clone()]));
+        let delivery_repo = Arc::new(MockWebhookDeliveryRepository::default());
+        let use_case = EnqueueWebhookDeliveriesUseCase::new(webhook_repo, delivery_repo.clone());
+
+        let event_id = OutboxMessageId::new();
+        let network_id = NetworkId::new();
+        for _ in 0..2 {
+            use_case
+                .execute(&event_id, NetworkEventKind::Updated, &network_id, "{}")
+                .await
+                .unwrap();
+        }
+
+        let deliveries = delivery_repo.deliveries.lock().unwrap();
+        assert_eq!(deliveries.len(), 1);
+        assert_eq!(deliveries[0].id(), &DeliveryId::for_event(&event_id, webhook.id()));
+    }
+}
@@ -0,0 +1,134 @@
+//! Get Webhook Use Case
+//!
+//! Retrieves a webhook subscription by its ID.
+
+use std::sync::Arc;
+
+use crate::domain::gateways::WebhookRepository;
+use crate::domain::models::webhook::{Webhook, WebhookId};
+use crate::shared::errors::UseCaseError;
+
+/// Use case for retrieving a webhook subscription
+pub struct GetWebhookUseCase {
+    webhook_repository: Arc<dyn WebhookRepository>,
+}
+
+impl GetWebhookUseCase {
+    /// Create a new GetWebhookUseCase
+    #[must_use]
+    pub fn new(webhook_repository: Arc<dyn WebhookRepository>) -> Self {
+        Self { webhook_repository }
+    }
+
+    /// Execute the use case
+    ///
+    /// # Errors
+    ///
+    /// Returns `UseCaseError::NotFound` if the webhook doesn't exist.
+    /// Returns `UseCaseError::Repository` if there's a database error.
+    pub async fn execute(&self, id: &WebhookId) -> Result<Webhook, UseCaseError> {
+        tracing::debug!(webhook_id = %id, "Getting webhook");
+
+        self.webhook_repository
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| UseCaseError::NotFound {
+                resource: "Webhook".to_string(),
+                id: id.to_string(),
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::models::url_policy::UrlPolicy;
+    use crate::domain::models::webhook::WebhookData;
+    use crate::shared::errors::RepositoryError;
+    use async_trait::async_trait;
+    use std::sync::Mutex;
+
+    struct MockWebhookRepository {
+        webhooks: Mutex<Vec<Webhook>>,
+    }
+
+    impl MockWebhookRepository {
+        fn with_webhooks(webhooks: Vec<Webhook>) -> Self {
+            Self {
+                webhooks: Mutex::new(webhooks),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl WebhookRepository for MockWebhookRepository {
+        async fn find_by_id(&self, id: &WebhookId) -> Result<Option<Webhook>, RepositoryError> {
+            Ok(self.webhooks.lock().unwrap().iter().find(|w| w.id() == id).cloned())
+        }
+
+        async fn find_all(&self) -> Result<Vec<Webhook>, RepositoryError> {
+            Ok(self.webhooks.lock().unwrap().clone())
+        }
+
+        async fn find_enabled(&self) -> Result<Vec<Webhook>, RepositoryError> {
+            Ok(self
+                .webhooks
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|w| w.enabled())
+                .cloned()
+                .collect())
+        }
+
+        async fn create(&self, webhook: &Webhook) -> Result<Webhook, RepositoryError> {
+            self.webhooks.lock().unwrap().push(webhook.clone());
+            Ok(webhook.clone())
+        }
+
+        async fn update(&self, webhook: &Webhook) -> Result<Option<Webhook>, RepositoryError> {
+            let mut webhooks = self.webhooks.lock().unwrap();
+            let Some(existing) = webhooks.iter_mut().find(|w| w.id() == webhook.id()) else {
+                return Ok(None);
+            };
+            *existing = webhook.clone();
+            Ok(Some(webhook.clone()))
+        }
+
+        async fn delete(&self, id: &WebhookId) -> Result<bool, RepositoryError> {
+            let mut webhooks = self.webhooks.lock().unwrap();
+            let before = webhooks.len();
+            webhooks.retain(|w| w.id() != id);
+            Ok(webhooks.len() != before)
+        }
+    }
+    fn webhook_data() -> WebhookData {
+        WebhookData {
+            url: "https://hooks.example.com/networks".to_string(),
+            events: vec![],
+            network_ids: vec![],
+            secret: None,
+            enabled: true,
+        }
+    }
+    #[tokio::test]
+    async fn should_get_webhook() {
+        let webhook = Webhook::new(webhook_data(), "user-1".to_string(), &UrlPolicy::default()).unwrap();
+        let repo = Arc::new(MockWebhookRepository::with_webhooks(vec![webhook.clone()]));
+
+        let use_case = GetWebhookUseCase::new(repo);
+        let found = use_case.execute(webhook.id()).await.unwrap();
+
+        assert_eq!(found.id(), webhook.id());
+    }
+
+    #[tokio::test]
+    async fn should_return_not_found_when_webhook_does_not_exist() {
+        let repo = Arc::new(MockWebhookRepository::with_webhooks(vec![]));
+
+        let use_case = GetWebhookUseCase::new(repo);
+        let result = use_case.execute(&WebhookId::new()).await;
+
+        assert!(matches!(result.unwrap_err(), UseCaseError::NotFound { .. }));
+    }
+}
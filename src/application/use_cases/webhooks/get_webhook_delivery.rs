@@ -0,0 +1,204 @@
+//! Get Webhook Delivery Use Case
+//!
+//! Retrieves a delivery of a webhook along with the log of its attempts.
+
+use std::sync::Arc;
+
+use crate::domain::gateways::WebhookDeliveryRepository;
+use crate::domain::models::webhook::{DeliveryAttempt, DeliveryId, WebhookDelivery, WebhookId};
+use crate::shared::errors::UseCaseError;
+
+/// Use case for retrieving a webhook delivery and its attempts
+pub struct GetWebhookDeliveryUseCase {
+    delivery_repository: Arc<dyn WebhookDeliveryRepository>,
+}
+
+impl GetWebhookDeliveryUseCase {
+    /// Create a new GetWebhookDeliveryUseCase
+    #[must_use]
+    pub fn new(delivery_repository: Arc<dyn WebhookDeliveryRepository>) -> Self {
+        Self { delivery_repository }
+    }
+
+    /// Execute the use case
+    ///
+    /// Attempts are returned oldest first.
+    ///
+    /// # Errors
+    ///
+    /// Returns `UseCaseError::NotFound` if the delivery doesn't exist or belongs to another webhook.
+    /// Returns `UseCaseError::Repository` if there's a database error.
+    pub async fn execute(
+        &self,
+        webhook_id: &WebhookId,
+        id: &DeliveryId,
+    ) -> Result<(WebhookDelivery, Vec<DeliveryAttempt>), UseCaseError> {
+        tracing::debug!(webhook_id = %webhook_id, delivery_id = %id, "Getting webhook delivery");
+
+        let delivery = self
+            .delivery_repository
+            .find_by_id(id)
+            .await?
+            .filter(|delivery| delivery.webhook_id() == webhook_id)
+            .ok_or_else(|| UseCaseError::NotFound {
+                resource: "Delivery".to_string(),
+                id: id.to_string(),
+            })?;
+        let attempts = self.delivery_repository.find_attempts(id).await?;
+
+        Ok((delivery, attempts))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::models::event::NetworkEventKind;
+    use crate::domain::models::network::NetworkId;
+    use crate::domain::models::webhook::{DeliveryOutcome, DeliveryStatus, WebhookRetryPolicy};
+    use crate::shared::errors::RepositoryError;
+    use async_trait::async_trait;
+    use chrono::{DateTime, Utc};
+    use std::sync::Mutex;
+    use std::time::Duration;
+
+    #[derive(Default)]
+    struct MockWebhookDeliveryRepository {
+        deliveries: Mutex<Vec<WebhookDelivery>>,
+        attempts: Mutex<Vec<DeliveryAttempt>>,
+    }
+
+    impl MockWebhookDeliveryRepository {
+        fn with_deliveries(deliveries: Vec<WebhookDelivery>) -> Self {
+            Self {
+                deliveries: Mutex::new(deliveries),
+                attempts: Mutex::default(),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl WebhookDeliveryRepository for MockWebhookDeliveryRepository {
+        async fn find_by_id(&self, id: &DeliveryId) -> Result<Option<WebhookDelivery>, RepositoryError> {
+            Ok(self.deliveries.lock().unwrap().iter().find(|d| d.id() == id).cloned())
+        }
+
+        async fn find_by_webhook(
+            &self,
+            webhook_id: &WebhookId,
+            limit: u32,
+        ) -> Result<Vec<WebhookDelivery>, RepositoryError> {
+            Ok(self
+                .deliveries
+                .lock()
+                .unwrap()
+                .iter()
+                .rev()
+                .filter(|d| d.webhook_id() == webhook_id)
+                .take(limit as usize)
+                .cloned()
+                .collect())
+        }
+
+        async fn find_attempts(&self, id: &DeliveryId) -> Result<Vec<DeliveryAttempt>, RepositoryError> {
+            Ok(self
+                .attempts
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|a| &a.delivery_id == id)
+                .cloned()
+                .collect())
+        }
+
+        async fn enqueue(&self, deliveries: &[WebhookDelivery]) -> Result<(), RepositoryError> {
+            self.deliveries.lock().unwrap().extend_from_slice(deliveries);
+            Ok(())
+        }
+
+        async fn claim_due(&self, now: DateTime<Utc>, limit: u32) -> Result<Vec<WebhookDelivery>, RepositoryError> {
+            Ok(self
+                .deliveries
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|d| d.status() == DeliveryStatus::Pending && d.next_attempt_at() <= now)
+                .take(limit as usize)
+                .cloned()
+                .collect())
+        }
+
+        async fn record_attempt(
+            &self,
+            delivery: &WebhookDelivery,
+            attempt: &DeliveryAttempt,
+        ) -> Result<Option<WebhookDelivery>, RepositoryError> {
+            self.attempts.lock().unwrap().push(attempt.clone());
+            let mut deliveries = self.deliveries.lock().unwrap();
+            let Some(existing) = deliveries.iter_mut().find(|d| d.id() == delivery.id()) else {
+                return Ok(None);
+            };
+            *existing = delivery.clone();
+            Ok(Some(delivery.clone()))
+        }
+
+        async fn requeue(&self, delivery: &WebhookDelivery) -> Result<Option<WebhookDelivery>, RepositoryError> {
+            let mut deliveries = self.deliveries.lock().unwrap();
+            let Some(existing) = deliveries
+                .iter_mut()
+                .find(|d| d.id() == delivery.id() && d.status() != DeliveryStatus::Pending)
+            else {
+                return Ok(None);
+            };
+            *existing = delivery.clone();
+            Ok(Some(delivery.clone()))
+        }
+    }
+    #[tokio::test]
+    async fn should_get_delivery_with_attempts() {
+        let webhook_id = WebhookId::new();
+        let delivery = WebhookDelivery::new(
+            webhook_id.clone(),
+            NetworkEventKind::Created,
+            NetworkId::new(),
+            "{}".to_string(),
+        );
+        let policy = WebhookRetryPolicy {
+            max_attempts: 5,
+            base_delay: Duration::from_secs(10),
+            max_delay: Duration::from_secs(60),
+        };
+        let outcome = DeliveryOutcome {
+            status_code: Some(503),
+            response_body: Some("unavailable".to_string()),
+            error: None,
+            duration: Duration::from_millis(15),
+        };
+        let (delivery, attempt) = delivery.record_attempt(outcome, &policy);
+        let repo = Arc::new(MockWebhookDeliveryRepository::with_deliveries(vec![delivery.clone()]));
+        repo.attempts.lock().unwrap().push(attempt);
+
+        let use_case = GetWebhookDeliveryUseCase::new(repo);
+        let (found, attempts) = use_case.execute(&webhook_id, delivery.id()).await.unwrap();
+
+        assert_eq!(found.attempts(), 1);
+        assert_eq!(attempts.len(), 1);
+        assert_eq!(attempts[0].status_code, Some(503));
+    }
+
+    #[tokio::test]
+    async fn should_return_not_found_for_delivery_of_another_webhook() {
+        let delivery = WebhookDelivery::new(
+            WebhookId::new(),
+            NetworkEventKind::Created,
+            NetworkId::new(),
+            "{}".to_string(),
+        );
+        let repo = Arc::new(MockWebhookDeliveryRepository::with_deliveries(vec![delivery.clone()]));
+
+        let use_case = GetWebhookDeliveryUseCase::new(repo);
+        let result = use_case.execute(&WebhookId::new(), delivery.id()).await;
+
+        assert!(matches!(result.unwrap_err(), UseCaseError::NotFound { .. }));
+    }
+}
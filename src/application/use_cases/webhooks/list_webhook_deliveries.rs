@@ -0,0 +1,248 @@
+//! List Webhook Deliveries Use Case
+//!
+//! Retrieves the most recent deliveries of a webhook, newest first.
+
+use std::sync::Arc;
+
+use crate::domain::gateways::{WebhookDeliveryRepository, WebhookRepository};
+use crate::domain::models::webhook::{WebhookDelivery, WebhookId};
+use crate::shared::errors::UseCaseError;
+
+/// Use case for listing the deliveries of a webhook
+pub struct ListWebhookDeliveriesUseCase {
+    webhook_repository: Arc<dyn WebhookRepository>,
+    delivery_repository: Arc<dyn WebhookDeliveryRepository>,
+}
+
+impl ListWebhookDeliveriesUseCase {
+    /// Create a new ListWebhookDeliveriesUseCase
+    #[must_use]
+    pub fn new(
+        webhook_repository: Arc<dyn WebhookRepository>,
+        delivery_repository: Arc<dyn WebhookDeliveryRepository>,
+    ) -> Self {
+        Self {
+            webhook_repository,
+            delivery_repository,
+        }
+    }
+
+    /// Execute the use case
+    ///
+    /// # Errors
+    ///
+    /// Returns `UseCaseError::NotFound` if the webhook doesn't exist.
+    /// Returns `UseCaseError::Repository` if there's a database error.
+    pub async fn execute(&self, webhook_id: &WebhookId, limit: u32) -> Result<Vec<WebhookDelivery>, UseCaseError> {
+        tracing::debug!(webhook_id = %webhook_id, limit, "Listing webhook deliveries");
+
+        if self.webhook_repository.find_by_id(webhook_id).await?.is_none() {
+            return Err(UseCaseError::NotFound {
+                resource: "Webhook".to_string(),
+                id: webhook_id.to_string(),
+            });
+        }
+
+        let deliveries = self.delivery_repository.find_by_webhook(webhook_id, limit).await?;
+
+        tracing::debug!(webhook_id = %webhook_id, count = deliveries.len(), "Retrieved webhook deliveries");
+        Ok(deliveries)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::models::event::NetworkEventKind;
+    use crate::domain::models::network::NetworkId;
+    use crate::domain::models::url_policy::UrlPolicy;
+    use crate::domain::models::webhook::{DeliveryAttempt, DeliveryId, DeliveryStatus, Webhook, WebhookData};
+    use crate::shared::errors::RepositoryError;
+    use async_trait::async_trait;
+    use chrono::{DateTime, Utc};
+    use std::sync::Mutex;
+
+    struct MockWebhookRepository {
+        webhooks: Mutex<Vec<Webhook>>,
+    }
+
+    impl MockWebhookRepository {
+        fn with_webhooks(webhooks: Vec<Webhook>) -> Self {
+            Self {
+                webhooks: Mutex::new(webhooks),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl WebhookRepository for MockWebhookRepository {
+        async fn find_by_id(&self, id: &WebhookId) -> Result<Option<Webhook>, RepositoryError> {
+            Ok(self.webhooks.lock().unwrap().iter().find(|w| w.id() == id).cloned())
+        }
+
+        async fn find_all(&self) -> Result<Vec<Webhook>, RepositoryError> {
+            Ok(self.webhooks.lock().unwrap().clone())
+        }
+
+        async fn find_enabled(&self) -> Result<Vec<Webhook>, RepositoryError> {
+            Ok(self
+                .webhooks
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|w| w.enabled())
+                .cloned()
+                .collect())
+        }
+
+        async fn create(&self, webhook: &Webhook) -> Result<Webhook, RepositoryError> {
+            self.webhooks.lock().unwrap().push(webhook.clone());
+            Ok(webhook.clone())
+        }
+
+        async fn update(&self, webhook: &Webhook) -> Result<Option<Webhook>, RepositoryError> {
+            let mut webhooks = self.webhooks.lock().unwrap();
+            let Some(existing) = webhooks.iter_mut().find(|w| w.id() == webhook.id()) else {
+                return Ok(None);
+            };
+            *existing = webhook.clone();
+            Ok(Some(webhook.clone()))
+        }
+
+        async fn delete(&self, id: &WebhookId) -> Result<bool, RepositoryError> {
+            let mut webhooks = self.webhooks.lock().unwrap();
+            let before = webhooks.len();
+            webhooks.retain(|w| w.id() != id);
+            Ok(webhooks.len() != before)
+        }
+    }
+    #[derive(Default)]
+    struct MockWebhookDeliveryRepository {
+        deliveries: Mutex<Vec<WebhookDelivery>>,
+        attempts: Mutex<Vec<DeliveryAttempt>>,
+    }
+
+    impl MockWebhookDeliveryRepository {
+        fn with_deliveries(deliveries: Vec<WebhookDelivery>) -> Self {
+            Self {
+                deliveries: Mutex::new(deliveries),
+                attempts: Mutex::default(),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl WebhookDeliveryRepository for MockWebhookDeliveryRepository {
+        async fn find_by_id(&self, id: &DeliveryId) -> Result<Option<WebhookDelivery>, RepositoryError> {
+            Ok(self.deliveries.lock().unwrap().iter().find(|d| d.id() == id).cloned())
+        }
+
+        async fn find_by_webhook(
+            &self,
+            webhook_id: &WebhookId,
+            limit: u32,
+        ) -> Result<Vec<WebhookDelivery>, RepositoryError> {
+            Ok(self
+                .deliveries
+                .lock()
+                .unwrap()
+                .iter()
+                .rev()
+                .filter(|d| d.webhook_id() == webhook_id)
+                .take(limit as usize)
+                .cloned()
+                .collect())
+        }
+
+        async fn find_attempts(&self, id: &DeliveryId) -> Result<Vec<DeliveryAttempt>, RepositoryError> {
+            Ok(self
+                .attempts
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|a| &a.delivery_id == id)
+                .cloned()
+                .collect())
+        }
+
+        async fn enqueue(&self, deliveries: &[WebhookDelivery]) -> Result<(), RepositoryError> {
+            self.deliveries.lock().unwrap().extend_from_slice(deliveries);
+            Ok(())
+        }
+
+        async fn claim_due(&self, now: DateTime<Utc>, limit: u32) -> Result<Vec<WebhookDelivery>, RepositoryError> {
+            Ok(self
+                .deliveries
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|d| d.status() == DeliveryStatus::Pending && d.next_attempt_at() <= now)
+                .take(limit as usize)
+                .cloned()
+                .collect())
+        }
+
+        async fn record_attempt(
+            &self,
+            delivery: &WebhookDelivery,
+            attempt: &DeliveryAttempt,
+        ) -> Result<Option<WebhookDelivery>, RepositoryError> {
+            self.attempts.lock().unwrap().push(attempt.clone());
+            let mut deliveries = self.deliveries.lock().unwrap();
+            let Some(existing) = deliveries.iter_mut().find(|d| d.id() == delivery.id()) else {
+                return Ok(None);
+            };
+            *existing = delivery.clone();
+            Ok(Some(delivery.clone()))
+        }
+
+        async fn requeue(&self, delivery: &WebhookDelivery) -> Result<Option<WebhookDelivery>, RepositoryError> {
+            let mut deliveries = self.deliveries.lock().unwrap();
+            let Some(existing) = deliveries
+                .iter_mut()
+                .find(|d| d.id() == delivery.id() && d.status() != DeliveryStatus::Pending)
+            else {
+                return Ok(None);
+            };
+            *existing = delivery.clone();
+            Ok(Some(delivery.clone()))
+        }
+    }
+    fn webhook_data() -> WebhookData {
+        WebhookData {
+            url: "https://hooks.example.com/networks".to_string(),
+            events: vec![],
+            network_ids: vec![],
+            secret: None,
+            enabled: true,
+        }
+    }
+    #[tokio::test]
+    async fn should_list_latest_deliveries_of_webhook() {
+        let webhook = Webhook::new(webhook_data(), "user-1".to_string(), &UrlPolicy::default()).unwrap();
+        let other = Webhook::new(webhook_data(), "user-1".to_string(), &UrlPolicy::default()).unwrap();
+        let deliveries: Vec<WebhookDelivery> = [&webhook, &webhook, &webhook, &other]
+            .into_iter()
+            .map(|w| WebhookDelivery::new(w.id().clone(), NetworkEventKind::Updated, NetworkId::new(), "{}".into()))
+            .collect();
+        let webhook_repo = Arc::new(MockWebhookRepository::with_webhooks(vec![webhook.clone(), other]));
+        let delivery_repo = Arc::new(MockWebhookDeliveryRepository::with_deliveries(deliveries.clone()));
+
+        let use_case = ListWebhookDeliveriesUseCase::new(webhook_repo, delivery_repo);
+        let listed = use_case.execute(webhook.id(), 2).await.unwrap();
+
+        let ids: Vec<&DeliveryId> = listed.iter().map(WebhookDelivery::id).collect();
+        assert_eq!(ids, [deliveries[2].id(), deliveries[1].id()]);
+    }
+
+    #[tokio::test]
+    async fn should_return_not_found_when_webhook_does_not_exist() {
+        let webhook_repo = Arc::new(MockWebhookRepository::with_webhooks(vec![]));
+        let delivery_repo = Arc::new(MockWebhookDeliveryRepository::default());
+
+        let use_case = ListWebhookDeliveriesUseCase::new(webhook_repo, delivery_repo);
+        let result = use_case.execute(&WebhookId::new(), 50).await;
+
+        assert!(matches!(result.unwrap_err(), UseCaseError::NotFound { .. }));
+    }
+}
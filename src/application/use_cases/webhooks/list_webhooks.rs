@@ -0,0 +1,123 @@
+//! List Webhooks Use Case
+//!
+//! Retrieves all webhook subscriptions.
+
+use std::sync::Arc;
+
+use crate::domain::gateways::WebhookRepository;
+use crate::domain::models::webhook::Webhook;
+use crate::shared::errors::UseCaseError;
+
+/// Use case for listing webhook subscriptions
+pub struct ListWebhooksUseCase {
+    webhook_repository: Arc<dyn WebhookRepository>,
+}
+
+impl ListWebhooksUseCase {
+    /// Create a new ListWebhooksUseCase
+    #[must_use]
+    pub fn new(webhook_repository: Arc<dyn WebhookRepository>) -> Self {
+        Self { webhook_repository }
+    }
+
+    /// Execute the use case
+    ///
+    /// # Errors
+    ///
+    /// Returns `UseCaseError::Repository` if there's a database error.
+    pub async fn execute(&self) -> Result<Vec<Webhook>, UseCaseError> {
+        tracing::debug!("Listing webhooks");
+
+        let webhooks = self.webhook_repository.find_all().await?;
+
+        tracing::debug!(count = webhooks.len(), "Retrieved webhooks");
+        Ok(webhooks)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::models::url_policy::UrlPolicy;
+    use crate::domain::models::webhook::{WebhookData, WebhookId};
+    use crate::shared::errors::RepositoryError;
+    use async_trait::async_trait;
+    use std::sync::Mutex;
+
+    struct MockWebhookRepository {
+        webhooks: Mutex<Vec<Webhook>>,
+    }
+
+    impl MockWebhookRepository {
+        fn with_webhooks(webhooks: Vec<Webhook>) -> Self {
+            Self {
+                webhooks: Mutex::new(webhooks),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl WebhookRepository for MockWebhookRepository {
+        async fn find_by_id(&self, id: &WebhookId) -> Result<Option<Webhook>, RepositoryError> {
+            Ok(self.webhooks.lock().unwrap().iter().find(|w| w.id() == id).cloned())
+        }
+
+        async fn find_all(&self) -> Result<Vec<Webhook>, RepositoryError> {
+            Ok(self.webhooks.lock().unwrap().clone())
+        }
+
+        async fn find_enabled(&self) -> Result<Vec<Webhook>, RepositoryError> {
+            Ok(self
+                .webhooks
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|w| w.enabled())
+                .cloned()
+                .collect())
+        }
+
+        async fn create(&self, webhook: &Webhook) -> Result<Webhook, RepositoryError> {
+            self.webhooks.lock().unwrap().push(webhook.clone());
+            Ok(webhook.clone())
+        }
+
+        async fn update(&self, webhook: &Webhook) -> Result<Option<Webhook>, RepositoryError> {
+            let mut webhooks = self.webhooks.lock().unwrap();
+            let Some(existing) = webhooks.iter_mut().find(|w| w.id() == webhook.id()) else {
+                return Ok(None);
+            };
+            *existing = webhook.clone();
+            Ok(Some(webhook.clone()))
+        }
+
+        async fn delete(&self, id: &WebhookId) -> Result<bool, RepositoryError> {
+            let mut webhooks = self.webhooks.lock().unwrap();
+            let before = webhooks.len();
+            webhooks.retain(|w| w.id() != id);
+            Ok(webhooks.len() != before)
+        }
+    }
+    fn webhook_data() -> WebhookData {
+        WebhookData {
+            url: "https://hooks.example.com/networks".to_string(),
+            events: vec![],
+            network_ids: vec![],
+            secret: None,
+            enabled: true,
+        }
+    }
+    #[tokio::test]
+    async fn should_list_webhooks() {
+        let webhooks = vec![
+            Webhook::new(webhook_data(), "user-1".to_string(), &UrlPolicy::default()).unwrap(),
+            Webhook::new(webhook_data(), "user-2".to_string(), &UrlPolicy::default()).unwrap(),
+        ];
+        let repo = Arc::new(MockWebhookRepository::with_webhooks(webhooks));
+
+        let use_case = ListWebhooksUseCase::new(repo);
+        let webhooks = use_case.execute().await.unwrap();
+
+        assert_eq!(webhooks.len(), 2);
+    }
+}
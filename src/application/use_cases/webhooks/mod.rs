@@ -0,0 +1,25 @@
+//! Webhook Use Cases
+//!
+//! Business logic for managing webhook subscriptions and delivering network events to them.
+
+mod create_webhook;
+mod delete_webhook;
+mod deliver_due_webhooks;
+mod enqueue_webhook_deliveries;
+mod get_webhook;
+mod get_webhook_delivery;
+mod list_webhook_deliveries;
+mod list_webhooks;
+mod redeliver_webhook;
+mod update_webhook;
+
+pub use create_webhook::CreateWebhookUseCase;
+pub use delete_webhook::DeleteWebhookUseCase;
+pub use deliver_due_webhooks::DeliverDueWebhooksUseCase;
+pub use enqueue_webhook_deliveries::EnqueueWebhookDeliveriesUseCase;
+pub use get_webhook::GetWebhookUseCase;
+pub use get_webhook_delivery::GetWebhookDeliveryUseCase;
+pub use list_webhook_deliveries::ListWebhookDeliveriesUseCase;
+pub use list_webhooks::ListWebhooksUseCase;
+pub use redeliver_webhook::RedeliverWebhookUseCase;
+pub use update_webhook::UpdateWebhookUseCase;
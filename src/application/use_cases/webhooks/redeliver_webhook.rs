@@ -0,0 +1,202 @@
+//! Redeliver Webhook Use Case
+//!
+//! Queues a completed webhook delivery again, e.g. once a receiver that
+//! was down for longer than the retries is back.
+
+use std::sync::Arc;
+
+use crate::domain::gateways::WebhookDeliveryRepository;
+use crate::domain::models::webhook::{DeliveryId, WebhookDelivery, WebhookId};
+use crate::shared::errors::UseCaseError;
+
+/// Use case for redelivering a webhook delivery
+pub struct RedeliverWebhookUseCase {
+    delivery_repository: Arc<dyn WebhookDeliveryRepository>,
+}
+
+impl RedeliverWebhookUseCase {
+    /// Create a new RedeliverWebhookUseCase
+    #[must_use]
+    pub fn new(delivery_repository: Arc<dyn WebhookDeliveryRepository>) -> Self {
+        Self { delivery_repository }
+    }
+
+    /// Execute the use case
+    ///
+    /// The delivery keeps its ID and payload, so receivers can deduplicate it.
+    ///
+    /// # Errors
+    ///
+    /// Returns `UseCaseError::NotFound` if the delivery doesn't exist or belongs to another webhook.
+    /// Returns `UseCaseError::Conflict` if the delivery is still pending.
+    /// Returns `UseCaseError::Repository` if there's a database error.
+    pub async fn execute(&self, webhook_id: &WebhookId, id: &DeliveryId) -> Result<WebhookDelivery, UseCaseError> {
+        tracing::info!(webhook_id = %webhook_id, delivery_id = %id, "Redelivering webhook delivery");
+
+        let delivery = self
+            .delivery_repository
+            .find_by_id(id)
+            .await?
+            .filter(|delivery| delivery.webhook_id() == webhook_id)
+            .ok_or_else(|| UseCaseError::NotFound {
+                resource: "Delivery".to_string(),
+                id: id.to_string(),
+            })?;
+
+        let pending = || UseCaseError::Conflict(format!("Delivery {id} is already pending"));
+        let requeued = delivery.redeliver().map_err(|_| pending())?;
+        let result = self.delivery_repository.requeue(&requeued).await?.ok_or_else(pending)?;
+
+        tracing::info!(delivery_id = %id, "Webhook delivery queued again");
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::models::event::NetworkEventKind;
+    use crate::domain::models::network::NetworkId;
+    use crate::domain::models::webhook::{DeliveryAttempt, DeliveryStatus};
+    use crate::shared::errors::RepositoryError;
+    use async_trait::async_trait;
+    use chrono::{DateTime, Utc};
+    use std::sync::Mutex;
+
+    #[derive(Default)]
+    struct MockWebhookDeliveryRepository {
+        deliveries: Mutex<Vec<WebhookDelivery>>,
+        attempts: Mutex<Vec<DeliveryAttempt>>,
+    }
+
+    impl MockWebhookDeliveryRepository {
+        fn with_deliveries(deliveries: Vec<WebhookDelivery>) -> Self {
+            Self {
+                deliveries: Mutex::new(deliveries),
+                attempts: Mutex::default(),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl WebhookDeliveryRepository for MockWebhookDeliveryRepository {
+        async fn find_by_id(&self, id: &DeliveryId) -> Result<Option<WebhookDelivery>, RepositoryError> {
+            Ok(self.deliveries.lock().unwrap().iter().find(|d| d.id() == id).cloned())
+        }
+
+        async fn find_by_webhook(
+            &self,
+            webhook_id: &WebhookId,
+            limit: u32,
+        ) -> Result<Vec<WebhookDelivery>, RepositoryError> {
+            Ok(self
+                .deliveries
+                .lock()
+                .unwrap()
+                .iter()
+                .rev()
+                .filter(|d| d.webhook_id() == webhook_id)
+                .take(limit as usize)
+                .cloned()
+                .collect())
+        }
+
+        async fn find_attempts(&self, id: &DeliveryId) -> Result<Vec<DeliveryAttempt>, RepositoryError> {
+            Ok(self
+                .attempts
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|a| &a.delivery_id == id)
+                .cloned()
+                .collect())
+        }
+
+        async fn enqueue(&self, deliveries: &[WebhookDelivery]) -> Result<(), RepositoryError> {
+            self.deliveries.lock().unwrap().extend_from_slice(deliveries);
+            Ok(())
+        }
+
+        async fn claim_due(&self, now: DateTime<Utc>, limit: u32) -> Result<Vec<WebhookDelivery>, RepositoryError> {
+            Ok(self
+                .deliveries
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|d| d.status() == DeliveryStatus::Pending && d.next_attempt_at() <= now)
+                .take(limit as usize)
+                .cloned()
+                .collect())
+        }
+
+        async fn record_attempt(
+            &self,
+            delivery: &WebhookDelivery,
+            attempt: &DeliveryAttempt,
+        ) -> Result<Option<WebhookDelivery>, RepositoryError> {
+            self.attempts.lock().unwrap().push(attempt.clone());
+            let mut deliveries = self.deliveries.lock().unwrap();
+            let Some(existing) = deliveries.iter_mut().find(|d| d.id() == delivery.id()) else {
+                return Ok(None);
+            };
+            *existing = delivery.clone();
+            Ok(Some(delivery.clone()))
+        }
+
+        async fn requeue(&self, delivery: &WebhookDelivery) -> Result<Option<WebhookDelivery>, RepositoryError> {
+            let mut deliveries = self.deliveries.lock().unwrap();
+            let Some(existing) = deliveries
+                .iter_mut()
+                .find(|d| d.id() == delivery.id() && d.status() != DeliveryStatus::Pending)
+            else {
+                return Ok(None);
+            };
+            *existing = delivery.clone();
+            Ok(Some(delivery.clone()))
+        }
+    }
+    fn delivery(webhook_id: &WebhookId) -> WebhookDelivery {
+        WebhookDelivery::new(
+            webhook_id.clone(),
+            NetworkEventKind::Updated,
+            NetworkId::new(),
+            "{}".to_string(),
+        )
+    }
+
+    #[tokio::test]
+    async fn should_requeue_failed_delivery() {
+        let webhook_id = WebhookId::new();
+        let (failed, _) = delivery(&webhook_id).abandon("webhook disabled");
+        let repo = Arc::new(MockWebhookDeliveryRepository::with_deliveries(vec![failed.clone()]));
+
+        let use_case = RedeliverWebhookUseCase::new(repo.clone());
+        let requeued = use_case.execute(&webhook_id, failed.id()).await.unwrap();
+
+        assert_eq!(requeued.id(), failed.id());
+        assert_eq!(requeued.status(), DeliveryStatus::Pending);
+        assert_eq!(repo.deliveries.lock().unwrap()[0].status(), DeliveryStatus::Pending);
+    }
+
+    #[tokio::test]
+    async fn should_reject_pending_delivery() {
+        let webhook_id = WebhookId::new();
+        let pending = delivery(&webhook_id);
+        let repo = Arc::new(MockWebhookDeliveryRepository::with_deliveries(vec![pending.clone()]));
+
+        let use_case = RedeliverWebhookUseCase::new(repo);
+        let result = use_case.execute(&webhook_id, pending.id()).await;
+
+        assert!(matches!(result.unwrap_err(), UseCaseError::Conflict(_)));
+    }
+
+    #[tokio::test]
+    async fn should_return_not_found_when_delivery_does_not_exist() {
+        let repo = Arc::new(MockWebhookDeliveryRepository::default());
+
+        let use_case = RedeliverWebhookUseCase::new(repo);
+        let result = use_case.execute(&WebhookId::new(), &DeliveryId::new()).await;
+
+        assert!(matches!(result.unwrap_err(), UseCaseError::NotFound { .. }));
+    }
+}
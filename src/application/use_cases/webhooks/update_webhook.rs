@@ -0,0 +1,157 @@
+//! Update Webhook Use Case
+//!
+//! Replaces the settings of a webhook subscription, optionally rotating its secret.
+
+use std::sync::Arc;
+
+use crate::domain::gateways::WebhookRepository;
+use crate::domain::models::url_policy::UrlPolicy;
+use crate::domain::models::webhook::{Webhook, WebhookData, WebhookId};
+use crate::shared::errors::UseCaseError;
+
+/// Use case for updating a webhook subscription
+pub struct UpdateWebhookUseCase {
+    webhook_repository: Arc<dyn WebhookRepository>,
+    url_policy: Arc<UrlPolicy>,
+}
+
+impl UpdateWebhookUseCase {
+    /// Create a new UpdateWebhookUseCase
+    #[must_use]
+    pub fn new(webhook_repository: Arc<dyn WebhookRepository>, url_policy: Arc<UrlPolicy>) -> Self {
+        Self {
+            webhook_repository,
+            url_policy,
+        }
+    }
+
+    /// Execute the use case
+    ///
+    /// The secret is kept when none is given.
+    ///
+    /// # Errors
+    ///
+    /// Returns `UseCaseError::NotFound` if the webhook doesn't exist.
+    /// Returns `UseCaseError::Domain` if the webhook data is invalid.
+    /// Returns `UseCaseError::Repository` if there's a database error.
+    pub async fn execute(&self, id: &WebhookId, data: WebhookData) -> Result<Webhook, UseCaseError> {
+        tracing::info!(webhook_id = %id, "Updating webhook");
+
+        let not_found = || UseCaseError::NotFound {
+            resource: "Webhook".to_string(),
+            id: id.to_string(),
+        };
+
+        let existing = self.webhook_repository.find_by_id(id).await?.ok_or_else(not_found)?;
+        let updated = existing.with_updates(data, &self.url_policy)?;
+        let result = self.webhook_repository.update(&updated).await?.ok_or_else(not_found)?;
+
+        tracing::info!(webhook_id = %id, "Webhook updated successfully");
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::models::event::NetworkEventKind;
+    use crate::shared::errors::RepositoryError;
+    use async_trait::async_trait;
+    use std::sync::Mutex;
+
+    struct MockWebhookRepository {
+        webhooks: Mutex<Vec<Webhook>>,
+    }
+
+    impl MockWebhookRepository {
+        fn with_webhooks(webhooks: Vec<Webhook>) -> Self {
+            Self {
+                webhooks: Mutex::new(webhooks),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl WebhookRepository for MockWebhookRepository {
+        async fn find_by_id(&self, id: &WebhookId) -> Result<Option<Webhook>, RepositoryError> {
+            Ok(self.webhooks.lock().unwrap().iter().find(|w| w.id() == id).cloned())
+        }
+
+        async fn find_all(&self) -> Result<Vec<Webhook>, RepositoryError> {
+            Ok(self.webhooks.lock().unwrap().clone())
+        }
+
+        async fn find_enabled(&self) -> Result<Vec<Webhook>, RepositoryError> {
+            Ok(self
+                .webhooks
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|w| w.enabled())
+                .cloned()
+                .collect())
+        }
+
+        async fn create(&self, webhook: &Webhook) -> Result<Webhook, RepositoryError> {
+            self.webhooks.lock().unwrap().push(webhook.clone());
+            Ok(webhook.clone())
+        }
+
+        async fn update(&self, webhook: &Webhook) -> Result<Option<Webhook>, RepositoryError> {
+            let mut webhooks = self.webhooks.lock().unwrap();
+            let Some(existing) = webhooks.iter_mut().find(|w| w.id() == webhook.id()) else {
+                return Ok(None);
+            };
+            *existing = webhook.clone();
+            Ok(Some(webhook.clone()))
+        }
+
+        async fn delete(&self, id: &WebhookId) -> Result<bool, RepositoryError> {
+            let mut webhooks = self.webhooks.lock().unwrap();
+            let before = webhooks.len();
+            webhooks.retain(|w| w.id() != id);
+            Ok(webhooks.len() != before)
+        }
+    }
+    fn webhook_data() -> WebhookData {
+        WebhookData {
+            url: "https://hooks.example.com/networks".to_string(),
+            events: vec![],
+            network_ids: vec![],
+            secret: None,
+            enabled: true,
+        }
+    }
+    #[tokio::test]
+    async fn should_update_webhook_and_rotate_secret() {
+        let webhook = Webhook::new(webhook_data(), "user-1".to_string(), &UrlPolicy::default()).unwrap();
+        let repo = Arc::new(MockWebhookRepository::with_webhooks(vec![webhook.clone()]));
+
+        let use_case = UpdateWebhookUseCase::new(repo.clone(), Arc::default());
+        let updated = use_case
+            .execute(
+                webhook.id(),
+                WebhookData {
+                    events: vec![NetworkEventKind::Updated],
+                    secret: Some("rotated-secret-value".to_string()),
+                    ..webhook_data()
+                },
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(updated.events(), [NetworkEventKind::Updated]);
+        assert_eq!(updated.secret(), "rotated-secret-value");
+        assert_eq!(updated.created_by(), "user-1");
+    }
+
+    #[tokio::test]
+    async fn should_return_not_found_when_webhook_does_not_exist() {
+        let repo = Arc::new(MockWebhookRepository::with_webhooks(vec![]));
+
+        let use_case = UpdateWebhookUseCase::new(repo, Arc::default());
+        let result = use_case.execute(&WebhookId::new(), webhook_data()).await;
+
+        assert!(matches!(result.unwrap_err(), UseCaseError::NotFound { .. }));
+    }
+}
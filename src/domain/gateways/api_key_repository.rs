@@ -0,0 +1,31 @@
+//! API Key Repository Gateway
+//!
+//! Abstract trait defining the contract for API key persistence.
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+use crate::domain::models::api_key::{ApiKey, ApiKeyId};
+use crate::shared::errors::RepositoryError;
+
+/// Repository trait for API key persistence
+#[async_trait]
+pub trait ApiKeyRepository: Send + Sync {
+    /// Find an API key by its ID
+    async fn find_by_id(&self, id: &ApiKeyId) -> Result<Option<ApiKey>, RepositoryError>;
+
+    /// Find an API key by the hash of the key
+    async fn find_by_hash(&self, key_hash: &str) -> Result<Option<ApiKey>, RepositoryError>;
+
+    /// Find all API keys, revoked ones included, sorted by creation time ascending
+    async fn find_all(&self) -> Result<Vec<ApiKey>, RepositoryError>;
+
+    /// Create a new API key
+    async fn create(&self, api_key: &ApiKey) -> Result<ApiKey, RepositoryError>;
+
+    /// Revoke an API key, keeping the original revocation time if already revoked
+    async fn revoke(&self, id: &ApiKeyId, revoked_at: DateTime<Utc>) -> Result<Option<ApiKey>, RepositoryError>;
+
+    /// Record that an API key was used
+    async fn record_use(&self, id: &ApiKeyId, used_at: DateTime<Utc>) -> Result<(), RepositoryError>;
+}
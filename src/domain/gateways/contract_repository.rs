@@ -0,0 +1,39 @@
+//! Contract Repository Gateway
+//!
+//! Abstract trait defining the contract for network contract persistence.
+
+use async_trait::async_trait;
+
+use crate::domain::models::contract::{ContractId, NetworkContract};
+use crate::domain::models::network::NetworkId;
+use crate::shared::errors::RepositoryError;
+
+/// Repository trait for network contract persistence
+#[async_trait]
+pub trait ContractRepository: Send + Sync {
+    /// Find a contract by its ID
+    async fn find_by_id(&self, id: &ContractId) -> Result<Option<NetworkContract>, RepositoryError>;
+
+    /// Find all contracts of a network, sorted by name ascending
+    async fn find_by_network(&self, network_id: &NetworkId) -> Result<Vec<NetworkContract>, RepositoryError>;
+
+    /// Find contracts with the given name (case-insensitive) on any network
+    async fn find_by_name(&self, name: &str) -> Result<Vec<NetworkContract>, RepositoryError>;
+
+    /// Create a new contract
+    async fn create(&self, contract: &NetworkContract) -> Result<NetworkContract, RepositoryError>;
+
+    /// Update an existing contract
+    async fn update(&self, contract: &NetworkContract) -> Result<Option<NetworkContract>, RepositoryError>;
+
+    /// Delete a contract
+    async fn delete(&self, id: &ContractId) -> Result<bool, RepositoryError>;
+
+    /// Check if a contract name (case-insensitive) is taken on a network, optionally excluding a contract
+    async fn exists_by_name(
+        &self,
+        network_id: &NetworkId,
+        name: &str,
+        exclude_id: Option<&ContractId>,
+    ) -> Result<bool, RepositoryError>;
+}
@@ -0,0 +1,34 @@
+//! JWT Revocation Repository Gateway
+//!
+//! Abstract trait defining the contract for JWT revocation persistence.
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+use crate::domain::models::jwt_revocation::{JwtRevocation, SubjectRevocation};
+use crate::shared::errors::RepositoryError;
+
+/// Repository trait for JWT revocation persistence
+#[async_trait]
+pub trait JwtRevocationRepository: Send + Sync {
+    /// Check whether the token with this ID has been revoked
+    async fn is_jwt_revoked(&self, jti: &str) -> Result<bool, RepositoryError>;
+
+    /// Get the time up to which the tokens of a subject are revoked, if any
+    async fn subject_revoked_before(&self, subject: &str) -> Result<Option<DateTime<Utc>>, RepositoryError>;
+
+    /// Find the JWT revocations expiring after `now`, sorted by revocation time ascending
+    async fn find_jwt_revocations(&self, now: DateTime<Utc>) -> Result<Vec<JwtRevocation>, RepositoryError>;
+
+    /// Find all subject revocations, sorted by revocation time ascending
+    async fn find_subject_revocations(&self) -> Result<Vec<SubjectRevocation>, RepositoryError>;
+
+    /// Revoke a token, keeping the original revocation if already revoked
+    async fn revoke_jwt(&self, revocation: &JwtRevocation) -> Result<JwtRevocation, RepositoryError>;
+
+    /// Revoke the tokens of a subject, keeping the latest revocation point
+    async fn revoke_subject(&self, revocation: &SubjectRevocation) -> Result<SubjectRevocation, RepositoryError>;
+
+    /// Delete the JWT revocations that expired before `now`, returning how many were deleted
+    async fn purge_expired_jwts(&self, now: DateTime<Utc>) -> Result<u64, RepositoryError>;
+}
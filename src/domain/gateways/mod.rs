@@ -3,6 +3,30 @@
 //! Abstract interfaces defining contracts for external dependencies.
 //! These are implemented by driven adapters in the infrastructure layer.
 
+pub mod api_key_repository;
+pub mod contract_repository;
+pub mod jwt_revocation_repository;
+pub mod network_events;
 pub mod network_repository;
+pub mod outbox;
+pub mod schedule_repository;
+pub mod secrets;
+pub mod signer_repository;
+pub mod token_issuer;
+pub mod token_repository;
+pub mod webhook_repository;
+pub mod webhook_sender;
 
-pub use network_repository::NetworkRepository;
+pub use api_key_repository::ApiKeyRepository;
+pub use contract_repository::ContractRepository;
+pub use jwt_revocation_repository::JwtRevocationRepository;
+pub use network_events::{NetworkEventPublisher, NetworkEventStream};
+pub use network_repository::{NetworkCacheInvalidator, NetworkRepository};
+pub use outbox::{OutboxRepository, OutboxSink};
+pub use schedule_repository::ScheduleRepository;
+pub use secrets::{SecretCipher, SecretResolver};
+pub use signer_repository::SignerRepository;
+pub use token_issuer::TokenIssuer;
+pub use token_repository::TokenRepository;
+pub use webhook_repository::{WebhookDeliveryRepository, WebhookRepository};
+pub use webhook_sender::WebhookSender;
@@ -0,0 +1,39 @@
+//! Network Event Gateways
+//!
+//! Abstract traits for publishing network change events and subscribing to them.
+
+use std::pin::Pin;
+
+use async_trait::async_trait;
+use tokio_stream::Stream;
+
+use crate::domain::models::event::{NetworkEvent, SequencedEvent};
+
+/// Events delivered to a subscriber, in stream order
+pub type EventStream = Pin<Box<dyn Stream<Item = SequencedEvent> + Send>>;
+
+/// A subscription to network events
+pub struct EventSubscription {
+    /// Events after the requested position are no longer retained; the
+    /// subscriber must reload the networks instead of relying on the replay
+    pub missed: bool,
+    /// Replayed events followed by live ones. Ends when the subscriber falls
+    /// too far behind, so it can resume from the last event it saw
+    pub events: EventStream,
+}
+
+/// Publishes network change events
+#[async_trait]
+pub trait NetworkEventPublisher: Send + Sync {
+    /// Publish an event to current and future subscribers
+    ///
+    /// Delivery is best effort: a publishing problem never fails the change
+    /// that caused the event.
+    async fn publish(&self, event: NetworkEvent);
+}
+
+/// Source of network change events
+pub trait NetworkEventStream: Send + Sync {
+    /// Subscribe to events after the given position, or to new events only when `None`
+    fn subscribe(&self, after: Option<u64>) -> EventSubscription;
+}
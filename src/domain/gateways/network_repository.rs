@@ -5,6 +5,7 @@
 use async_trait::async_trait;
 
 use crate::domain::models::network::{Network, NetworkId};
+use crate::domain::models::search::SearchQuery;
 use crate::shared::errors::RepositoryError;
 
 /// Repository trait for Network persistence operations
@@ -13,11 +14,32 @@ pub trait NetworkRepository: Send + Sync {
     /// Find a network by its ID
     async fn find_by_id(&self, id: &NetworkId) -> Result<Option<Network>, RepositoryError>;
 
+    /// Find a network by its ID as currently stored, bypassing any cache, to base a change on
+    async fn find_by_id_for_update(&self, id: &NetworkId) -> Result<Option<Network>, RepositoryError>;
+
     /// Find a network by its chain ID
     async fn find_by_chain_id(&self, chain_id: i32) -> Result<Option<Network>, RepositoryError>;
 
-    /// Find all active networks, sorted by name ascending
-    async fn find_all_active(&self) -> Result<Vec<Network>, RepositoryError>;
+    /// Find a network by its slug or one of its aliases
+    async fn find_by_slug(&self, slug: &str) -> Result<Option<Network>, RepositoryError>;
+
+    /// Find all usable (active or deprecated) networks carrying every given tag, sorted by name ascending
+    async fn find_all_active(&self, tags: &[String]) -> Result<Vec<Network>, RepositoryError>;
+
+    /// Search networks in any lifecycle state by name, slug, aliases and tags, best match first
+    async fn search(&self, query: &SearchQuery) -> Result<Vec<Network>, RepositoryError>;
+
+    /// Find the networks whose parent is the given network, sorted by name ascending
+    async fn find_children(&self, parent_id: &NetworkId) -> Result<Vec<Network>, RepositoryError>;
+
+    /// Find the ancestors of a network, nearest first, up to the root
+    async fn find_ancestors(&self, id: &NetworkId) -> Result<Vec<Network>, RepositoryError>;
+
+    /// Count the levels of descendants below a network, 0 for a leaf
+    async fn subtree_height(&self, id: &NetworkId) -> Result<usize, RepositoryError>;
+
+    /// Find the testnets mirroring the given mainnet, sorted by name ascending
+    async fn find_testnets(&self, mainnet_id: &NetworkId) -> Result<Vec<Network>, RepositoryError>;
 
     /// Create a new network
     async fn create(&self, network: &Network) -> Result<Network, RepositoryError>;
@@ -25,13 +47,19 @@ pub trait NetworkRepository: Send + Sync {
     /// Update an existing network
     async fn update(&self, network: &Network) -> Result<Option<Network>, RepositoryError>;
 
-    /// Soft delete a network (sets active=false)
-    async fn soft_delete(&self, id: &NetworkId) -> Result<bool, RepositoryError>;
-
     /// Check if a chain ID exists, optionally excluding a specific network ID
     async fn exists_by_chain_id(
         &self,
         chain_id: i32,
         exclude_id: Option<&NetworkId>,
     ) -> Result<bool, RepositoryError>;
+
+    /// Check if a slug or alias is taken, optionally excluding a specific network ID
+    async fn exists_by_slug(&self, slug: &str, exclude_id: Option<&NetworkId>) -> Result<bool, RepositoryError>;
+}
+
+/// Hook dropping cached copies of a network changed elsewhere, e.g. by another replica
+pub trait NetworkCacheInvalidator: Send + Sync {
+    /// Forget everything cached about the network
+    fn invalidate(&self, id: &NetworkId);
 }
@@ -0,0 +1,45 @@
+//! Outbox Gateways
+//!
+//! Abstract traits for relaying network change events recorded in the outbox.
+//! Messages are written by the network repository, in the same transaction
+//! as the change they describe.
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+use crate::domain::models::outbox::{OutboxEvent, OutboxMessage};
+use crate::shared::errors::RepositoryError;
+
+/// Repository trait for relaying outbox messages
+#[async_trait]
+pub trait OutboxRepository: Send + Sync {
+    /// Claim unpublished messages due at `now`, oldest first
+    ///
+    /// Claimed messages are hidden from other relays for a while, so that
+    /// several instances can relay concurrently.
+    async fn claim_due(&self, now: DateTime<Utc>, limit: u32) -> Result<Vec<OutboxMessage>, RepositoryError>;
+
+    /// Save the outcome of a relay attempt and release the claim
+    async fn save(&self, message: &OutboxMessage) -> Result<(), RepositoryError>;
+
+    /// Delete messages published before `before`, returning how many were deleted
+    async fn purge_published(&self, before: DateTime<Utc>) -> Result<u64, RepositoryError>;
+}
+
+/// A destination of relayed events (log, webhooks, a message broker, ...)
+///
+/// Delivery is at-least-once: an event is handed to every sink again when
+/// any of them fails, so sinks must tolerate duplicates, using the event ID
+/// to deduplicate (e.g., as a broker message ID or idempotency key).
+#[async_trait]
+pub trait OutboxSink: Send + Sync {
+    /// Name of the sink, used in logs and relay errors
+    fn name(&self) -> &'static str;
+
+    /// Deliver an event to the sink
+    ///
+    /// # Errors
+    ///
+    /// Returns a human-readable reason if the sink did not accept the event
+    async fn deliver(&self, event: &OutboxEvent) -> Result<(), String>;
+}
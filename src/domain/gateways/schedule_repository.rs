@@ -0,0 +1,35 @@
+//! Schedule Repository Gateway
+//!
+//! Abstract trait defining the contract for lifecycle schedule persistence.
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+use crate::domain::models::network::NetworkId;
+use crate::domain::models::schedule::{LifecycleSchedule, ScheduleId};
+use crate::shared::errors::RepositoryError;
+
+/// Repository trait for lifecycle schedule persistence
+#[async_trait]
+pub trait ScheduleRepository: Send + Sync {
+    /// Find a schedule by its ID
+    async fn find_by_id(&self, id: &ScheduleId) -> Result<Option<LifecycleSchedule>, RepositoryError>;
+
+    /// Find all schedules of a network, sorted by run time ascending
+    async fn find_by_network(&self, network_id: &NetworkId) -> Result<Vec<LifecycleSchedule>, RepositoryError>;
+
+    /// Create a new schedule
+    async fn create(&self, schedule: &LifecycleSchedule) -> Result<LifecycleSchedule, RepositoryError>;
+
+    /// Persist the completion (applied, failed or cancelled) of a pending schedule
+    ///
+    /// Returns `None` if the schedule is no longer pending, e.g. because it was
+    /// completed concurrently.
+    async fn complete(&self, schedule: &LifecycleSchedule) -> Result<Option<LifecycleSchedule>, RepositoryError>;
+
+    /// Claim up to `limit` pending schedules due at `now`, oldest first
+    ///
+    /// Claimed schedules are leased so that other scheduler instances skip them;
+    /// a schedule whose lease expires without being completed is claimed again.
+    async fn claim_due(&self, now: DateTime<Utc>, limit: u32) -> Result<Vec<LifecycleSchedule>, RepositoryError>;
+}
@@ -0,0 +1,34 @@
+//! Secret Gateways
+//!
+//! Abstract traits for protecting secrets at rest and resolving secret placeholders.
+
+use crate::shared::errors::SecretError;
+
+/// Encrypts and decrypts secret-bearing values before they are persisted
+pub trait SecretCipher: Send + Sync {
+    /// Encrypt a plaintext value into a self-describing sealed string
+    ///
+    /// # Errors
+    ///
+    /// Returns `SecretError::Encryption` if the value cannot be sealed
+    fn encrypt(&self, plaintext: &str) -> Result<String, SecretError>;
+
+    /// Decrypt a value previously produced by [`SecretCipher::encrypt`]
+    ///
+    /// # Errors
+    ///
+    /// Returns `SecretError::Decryption` if the value is malformed or was tampered with
+    fn decrypt(&self, sealed: &str) -> Result<String, SecretError>;
+
+    /// Check whether a stored value is sealed by this cipher
+    fn is_encrypted(&self, value: &str) -> bool;
+}
+
+/// Resolves `${NAME}` placeholders to secret values
+pub trait SecretResolver: Send + Sync {
+    /// Look up the value of a named secret
+    fn resolve(&self, name: &str) -> Option<String>;
+
+    /// Identify the current secret values, changing whenever one of them does
+    fn version(&self) -> String;
+}
@@ -0,0 +1,37 @@
+//! Signer Repository Gateway
+//!
+//! Abstract trait defining the contract for network signer persistence.
+
+use async_trait::async_trait;
+
+use crate::domain::models::network::NetworkId;
+use crate::domain::models::signer::{NetworkSigner, SignerId};
+use crate::shared::errors::RepositoryError;
+
+/// Repository trait for network signer persistence
+#[async_trait]
+pub trait SignerRepository: Send + Sync {
+    /// Find a signer by its ID
+    async fn find_by_id(&self, id: &SignerId) -> Result<Option<NetworkSigner>, RepositoryError>;
+
+    /// Find all signers of a network, sorted by role then priority ascending
+    async fn find_by_network(&self, network_id: &NetworkId) -> Result<Vec<NetworkSigner>, RepositoryError>;
+
+    /// Create a new signer
+    async fn create(&self, signer: &NetworkSigner) -> Result<NetworkSigner, RepositoryError>;
+
+    /// Update an existing signer
+    async fn update(&self, signer: &NetworkSigner) -> Result<Option<NetworkSigner>, RepositoryError>;
+
+    /// Delete a signer
+    async fn delete(&self, id: &SignerId) -> Result<bool, RepositoryError>;
+
+    /// Check if an address (case-insensitive) already has a role on a network, optionally excluding a signer
+    async fn exists_by_role_and_address(
+        &self,
+        network_id: &NetworkId,
+        role: &str,
+        address: &str,
+        exclude_id: Option<&SignerId>,
+    ) -> Result<bool, RepositoryError>;
+}
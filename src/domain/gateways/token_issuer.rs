@@ -0,0 +1,16 @@
+//! Token Issuer Gateway
+//!
+//! Abstract trait for minting the JWTs accepted by the API.
+
+use crate::domain::models::service_account::IssuedToken;
+use crate::shared::errors::SecretError;
+
+/// Mints signed JWTs
+pub trait TokenIssuer: Send + Sync {
+    /// Issue a token carrying `sub`, `email` and `role` claims
+    ///
+    /// # Errors
+    ///
+    /// Returns `SecretError::Signing` if the token cannot be signed
+    fn issue(&self, subject: &str, email: &str, role: &str) -> Result<IssuedToken, SecretError>;
+}
@@ -0,0 +1,48 @@
+//! Token Repository Gateway
+//!
+//! Abstract trait defining the contract for token persistence.
+//!
+//! Every write also bumps the version of the network token list, atomically
+//! with the change: adding tokens is a minor bump, changing metadata a patch
+//! bump and removing tokens a major bump.
+
+use async_trait::async_trait;
+
+use crate::domain::models::network::NetworkId;
+use crate::domain::models::token::{Token, TokenId};
+use crate::domain::models::token_list::TokenListVersion;
+use crate::shared::errors::RepositoryError;
+
+/// Repository trait for token persistence
+#[async_trait]
+pub trait TokenRepository: Send + Sync {
+    /// Find a token by its ID
+    async fn find_by_id(&self, id: &TokenId) -> Result<Option<Token>, RepositoryError>;
+
+    /// Find all tokens of a network, sorted by symbol
+    async fn find_by_network(&self, network_id: &NetworkId) -> Result<Vec<Token>, RepositoryError>;
+
+    /// Check if a token address (case-insensitive) is registered on a network
+    async fn exists_by_address(&self, network_id: &NetworkId, address: &str) -> Result<bool, RepositoryError>;
+
+    /// Create a new token (minor version bump)
+    async fn create(&self, token: &Token) -> Result<Token, RepositoryError>;
+
+    /// Update the metadata of an existing token (patch version bump)
+    async fn update(&self, token: &Token) -> Result<Option<Token>, RepositoryError>;
+
+    /// Delete a token (major version bump)
+    async fn delete(&self, id: &TokenId) -> Result<bool, RepositoryError>;
+
+    /// Create and update tokens of a network in one transaction, with a single
+    /// version bump for the most significant change
+    async fn import(
+        &self,
+        network_id: &NetworkId,
+        created: &[Token],
+        updated: &[Token],
+    ) -> Result<TokenListVersion, RepositoryError>;
+
+    /// Get the current token list version of a network, if it ever changed
+    async fn find_version(&self, network_id: &NetworkId) -> Result<Option<TokenListVersion>, RepositoryError>;
+}
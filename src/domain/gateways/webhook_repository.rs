@@ -0,0 +1,72 @@
+//! Webhook Repository Gateways
+//!
+//! Abstract traits defining the contracts for webhook subscription and
+//! delivery persistence.
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+use crate::domain::models::webhook::{DeliveryAttempt, DeliveryId, Webhook, WebhookDelivery, WebhookId};
+use crate::shared::errors::RepositoryError;
+
+/// Repository trait for webhook subscription persistence
+#[async_trait]
+pub trait WebhookRepository: Send + Sync {
+    /// Find a webhook by its ID
+    async fn find_by_id(&self, id: &WebhookId) -> Result<Option<Webhook>, RepositoryError>;
+
+    /// Find all webhooks, sorted by creation time ascending
+    async fn find_all(&self) -> Result<Vec<Webhook>, RepositoryError>;
+
+    /// Find all enabled webhooks
+    async fn find_enabled(&self) -> Result<Vec<Webhook>, RepositoryError>;
+
+    /// Create a new webhook
+    async fn create(&self, webhook: &Webhook) -> Result<Webhook, RepositoryError>;
+
+    /// Update an existing webhook
+    async fn update(&self, webhook: &Webhook) -> Result<Option<Webhook>, RepositoryError>;
+
+    /// Delete a webhook along with its deliveries
+    async fn delete(&self, id: &WebhookId) -> Result<bool, RepositoryError>;
+}
+
+/// Repository trait for webhook delivery persistence
+#[async_trait]
+pub trait WebhookDeliveryRepository: Send + Sync {
+    /// Find a delivery by its ID
+    async fn find_by_id(&self, id: &DeliveryId) -> Result<Option<WebhookDelivery>, RepositoryError>;
+
+    /// Find the most recent deliveries of a webhook, newest first
+    async fn find_by_webhook(
+        &self,
+        webhook_id: &WebhookId,
+        limit: u32,
+    ) -> Result<Vec<WebhookDelivery>, RepositoryError>;
+
+    /// Find the attempts made for a delivery, oldest first
+    async fn find_attempts(&self, id: &DeliveryId) -> Result<Vec<DeliveryAttempt>, RepositoryError>;
+
+    /// Queue new deliveries, skipping any already queued under the same ID
+    async fn enqueue(&self, deliveries: &[WebhookDelivery]) -> Result<(), RepositoryError>;
+
+    /// Claim up to `limit` pending deliveries due at `now`, oldest first
+    ///
+    /// Claimed deliveries are leased so that other dispatcher instances skip
+    /// them; a delivery whose lease expires without an attempt being recorded
+    /// is claimed again.
+    async fn claim_due(&self, now: DateTime<Utc>, limit: u32) -> Result<Vec<WebhookDelivery>, RepositoryError>;
+
+    /// Persist an attempt along with the resulting delivery state, releasing the lease
+    async fn record_attempt(
+        &self,
+        delivery: &WebhookDelivery,
+        attempt: &DeliveryAttempt,
+    ) -> Result<Option<WebhookDelivery>, RepositoryError>;
+
+    /// Queue a completed delivery again
+    ///
+    /// Returns `None` if the delivery is pending, e.g. because it was
+    /// requeued concurrently.
+    async fn requeue(&self, delivery: &WebhookDelivery) -> Result<Option<WebhookDelivery>, RepositoryError>;
+}
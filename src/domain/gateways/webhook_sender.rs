@@ -0,0 +1,17 @@
+//! Webhook Sender Gateway
+//!
+//! Abstract trait for sending webhook deliveries to their receivers.
+
+use async_trait::async_trait;
+
+use crate::domain::models::webhook::{DeliveryOutcome, Webhook, WebhookDelivery};
+
+/// Sends webhook deliveries
+#[async_trait]
+pub trait WebhookSender: Send + Sync {
+    /// Send a delivery to its webhook, signed with the webhook secret
+    ///
+    /// Failures are reported in the outcome rather than as errors, so that
+    /// they are recorded in the delivery log.
+    async fn send(&self, webhook: &Webhook, delivery: &WebhookDelivery) -> DeliveryOutcome;
+}
@@ -0,0 +1,398 @@
+//! API Key Model
+//!
+//! Long-lived credentials for service-to-service clients, as an alternative
+//! to JWTs. Each key carries the scopes (permissions) it grants, an owner
+//! and an optional expiry.
+//!
+//! Only a SHA-256 hash of each key is stored, which is enough for keys made
+//! of random bytes: the key itself is only returned when it is created.
+
+use std::fmt;
+
+use chrono::{DateTime, Duration, Utc};
+use sha2::{Digest, Sha256};
+use uuid::Uuid;
+
+use crate::shared::errors::DomainError;
+
+/// Prefix of every API key, making leaked keys easy to recognize
+pub const API_KEY_PREFIX: &str = "nrk_";
+/// Length of the key prefix kept in clear to tell keys apart
+pub const DISPLAY_PREFIX_LENGTH: usize = 12;
+/// Maximum length of an API key name
+pub const MAX_API_KEY_NAME_LENGTH: usize = 100;
+/// Maximum length of an API key owner
+pub const MAX_API_KEY_OWNER_LENGTH: usize = 255;
+/// Maximum number of scopes of an API key
+pub const MAX_API_KEY_SCOPES: usize = 20;
+/// Maximum length of a scope
+pub const MAX_SCOPE_LENGTH: usize = 64;
+
+/// Minimum time between two recorded uses of a key, sparing a write per request
+const LAST_USED_RESOLUTION: Duration = Duration::minutes(1);
+
+/// Unique identifier for an API key
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ApiKeyId(Uuid);
+
+impl ApiKeyId {
+    /// Create a new random ApiKeyId
+    #[must_use]
+    pub fn new() -> Self {
+        Self(Uuid::new_v4())
+    }
+
+    /// Create an ApiKeyId from an existing UUID
+    #[must_use]
+    pub fn from_uuid(uuid: Uuid) -> Self {
+        Self(uuid)
+    }
+
+    /// Get the underlying UUID
+    #[must_use]
+    pub fn as_uuid(&self) -> &Uuid {
+        &self.0
+    }
+}
+
+impl Default for ApiKeyId {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Display for ApiKeyId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// Hash an API key for storage and lookup
+#[must_use]
+pub fn hash_key(key: &str) -> String {
+    hex::encode(Sha256::digest(key.as_bytes()))
+}
+
+/// Generate a random API key
+fn generate_key() -> String {
+    format!("{API_KEY_PREFIX}{}{}", Uuid::new_v4().simple(), Uuid::new_v4().simple())
+}
+
+/// Data required to create an API key
+#[derive(Debug, Clone)]
+pub struct ApiKeyData {
+    pub name: String,
+    /// Team or service the key is issued to
+    pub owner: String,
+    /// Permissions granted to the key
+    pub scopes: Vec<String>,
+    pub expires_at: Option<DateTime<Utc>>,
+}
+
+impl ApiKeyData {
+    /// Validate the API key data
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DomainError::ValidationError` if any field is invalid
+    pub fn validate(&self) -> Result<(), DomainError> {
+        if self.name.trim().is_empty() || self.name.len() > MAX_API_KEY_NAME_LENGTH {
+            return Err(DomainError::ValidationError(format!(
+                "name must be between 1 and {MAX_API_KEY_NAME_LENGTH} characters"
+            )));
+        }
+
+        if self.owner.trim().is_empty() || self.owner.len() > MAX_API_KEY_OWNER_LENGTH {
+            return Err(DomainError::ValidationError(format!(
+                "owner must be between 1 and {MAX_API_KEY_OWNER_LENGTH} characters"
+            )));
+        }
+
+        if self.scopes.len() > MAX_API_KEY_SCOPES {
+            return Err(DomainError::ValidationError(format!(
+                "scopes can have at most {MAX_API_KEY_SCOPES} items"
+            )));
+        }
+        for scope in &self.scopes {
+            let well_formed = !scope.is_empty()
+                && scope.len() <= MAX_SCOPE_LENGTH
+                && scope
+                    .chars()
+                    .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || matches!(c, ':' | '_' | '-' | '.'));
+            if !well_formed {
+                return Err(DomainError::ValidationError(format!("scope '{scope}' is malformed")));
+            }
+        }
+
+        if self.expires_at.is_some_and(|expires_at| expires_at <= Utc::now()) {
+            return Err(DomainError::ValidationError(
+                "expires_at must be in the future".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+/// A credential granting scopes to a service-to-service client
+#[derive(Debug, Clone)]
+pub struct ApiKey {
+    id: ApiKeyId,
+    name: String,
+    owner: String,
+    prefix: String,
+    key_hash: String,
+    scopes: Vec<String>,
+    expires_at: Option<DateTime<Utc>>,
+    last_used_at: Option<DateTime<Utc>>,
+    revoked_at: Option<DateTime<Utc>>,
+    created_by: String,
+    created_at: DateTime<Utc>,
+}
+
+impl ApiKey {
+    /// Create a new API key, returned along with the key itself
+    ///
+    /// The key cannot be recovered afterwards.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DomainError::ValidationError` if the data is invalid
+    pub fn generate(data: ApiKeyData, created_by: String) -> Result<(Self, String), DomainError> {
+        data.validate()?;
+
+        let key = generate_key();
+        let mut scopes = data.scopes;
+        scopes.sort();
+        scopes.dedup();
+
+        let api_key = Self {
+            id: ApiKeyId::new(),
+            name: data.name.trim().to_string(),
+            owner: data.owner.trim().to_string(),
+            prefix: key[..DISPLAY_PREFIX_LENGTH].to_string(),
+            key_hash: hash_key(&key),
+            scopes,
+            expires_at: data.expires_at,
+            last_used_at: None,
+            revoked_at: None,
+            created_by,
+            created_at: Utc::now(),
+        };
+        Ok((api_key, key))
+    }
+
+    /// Restore an API key from persistence (no validation)
+    #[must_use]
+    #[allow(clippy::too_many_arguments)]
+    pub fn restore(
+        id: ApiKeyId,
+        name: String,
+        owner: String,
+        prefix: String,
+        key_hash: String,
+        scopes: Vec<String>,
+        expires_at: Option<DateTime<Utc>>,
+        last_used_at: Option<DateTime<Utc>>,
+        revoked_at: Option<DateTime<Utc>>,
+        created_by: String,
+        created_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id,
+            name,
+            owner,
+            prefix,
+            key_hash,
+            scopes,
+            expires_at,
+            last_used_at,
+            revoked_at,
+            created_by,
+            created_at,
+        }
+    }
+
+    /// Check whether the key authenticates requests at `now`
+    #[must_use]
+    pub fn is_valid_at(&self, now: DateTime<Utc>) -> bool {
+        self.revoked_at.is_none() && self.expires_at.is_none_or(|expires_at| expires_at > now)
+    }
+
+    /// Check whether a use at `now` should be recorded
+    #[must_use]
+    pub fn should_record_use(&self, now: DateTime<Utc>) -> bool {
+        self.last_used_at
+            .is_none_or(|last_used_at| now - last_used_at >= LAST_USED_RESOLUTION)
+    }
+
+    // Getters
+    #[must_use]
+    pub fn id(&self) -> &ApiKeyId {
+        &self.id
+    }
+
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    #[must_use]
+    pub fn owner(&self) -> &str {
+        &self.owner
+    }
+
+    /// First characters of the key, enough to recognize it
+    #[must_use]
+    pub fn prefix(&self) -> &str {
+        &self.prefix
+    }
+
+    #[must_use]
+    pub fn key_hash(&self) -> &str {
+        &self.key_hash
+    }
+
+    #[must_use]
+    pub fn scopes(&self) -> &[String] {
+        &self.scopes
+    }
+
+    #[must_use]
+    pub fn expires_at(&self) -> Option<DateTime<Utc>> {
+        self.expires_at
+    }
+
+    #[must_use]
+    pub fn last_used_at(&self) -> Option<DateTime<Utc>> {
+        self.last_used_at
+    }
+
+    #[must_use]
+    pub fn revoked_at(&self) -> Option<DateTime<Utc>> {
+        self.revoked_at
+    }
+
+    #[must_use]
+    pub fn created_by(&self) -> &str {
+        &self.created_by
+    }
+
+    #[must_use]
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn api_key_data() -> ApiKeyData {
+        ApiKeyData {
+            name: "Nightly sync".to_string(),
+            owner: "data-platform".to_string(),
+            scopes: vec!["secrets:read".to_string(), "secrets:read".to_string()],
+            expires_at: None,
+        }
+    }
+
+    #[test]
+    fn test_generate_api_key() {
+        let (api_key, key) = ApiKey::generate(api_key_data(), "admin".to_string()).unwrap();
+
+        assert!(key.starts_with(API_KEY_PREFIX));
+        assert!(key.starts_with(api_key.prefix()));
+        assert_eq!(api_key.key_hash(), hash_key(&key));
+        assert_ne!(api_key.key_hash(), key);
+        assert_eq!(api_key.scopes(), ["secrets:read"]);
+        assert!(api_key.is_valid_at(Utc::now()));
+
+        let (other, other_key) = ApiKey::generate(api_key_data(), "admin".to_string()).unwrap();
+        assert_ne!(key, other_key);
+        assert_ne!(api_key.key_hash(), other.key_hash());
+    }
+
+    #[test]
+    fn test_validate_api_key_data() {
+        let invalid = [
+            ApiKeyData {
+                name: " ".to_string(),
+                ..api_key_data()
+            },
+            ApiKeyData {
+                owner: String::new(),
+                ..api_key_data()
+            },
+            ApiKeyData {
+                scopes: vec!["Secrets Read".to_string()],
+                ..api_key_data()
+            },
+            ApiKeyData {
+                scopes: (0..=MAX_API_KEY_SCOPES).map(|i| format!("scope:{i}")).collect(),
+                ..api_key_data()
+            },
+            ApiKeyData {
+                expires_at: Some(Utc::now() - Duration::hours(1)),
+                ..api_key_data()
+            },
+        ];
+
+        for data in invalid {
+            assert!(data.validate().is_err(), "{data:?}");
+        }
+    }
+
+    #[test]
+    fn test_expired_and_revoked_keys_are_invalid() {
+        let now = Utc::now();
+        let (api_key, _) = ApiKey::generate(
+            ApiKeyData {
+                expires_at: Some(now + Duration::hours(1)),
+                ..api_key_data()
+            },
+            "admin".to_string(),
+        )
+        .unwrap();
+        assert!(api_key.is_valid_at(now));
+        assert!(!api_key.is_valid_at(now + Duration::hours(2)));
+
+        let revoked = ApiKey::restore(
+            api_key.id().clone(),
+            api_key.name().to_string(),
+            api_key.owner().to_string(),
+            api_key.prefix().to_string(),
+            api_key.key_hash().to_string(),
+            api_key.scopes().to_vec(),
+            None,
+            None,
+            Some(now),
+            api_key.created_by().to_string(),
+            api_key.created_at(),
+        );
+        assert!(!revoked.is_valid_at(now));
+    }
+
+    #[test]
+    fn test_uses_are_recorded_once_a_minute() {
+        let now = Utc::now();
+        let (api_key, _) = ApiKey::generate(api_key_data(), "admin".to_string()).unwrap();
+        assert!(api_key.should_record_use(now));
+
+        let used = ApiKey::restore(
+            api_key.id().clone(),
+            api_key.name().to_string(),
+            api_key.owner().to_string(),
+            api_key.prefix().to_string(),
+            api_key.key_hash().to_string(),
+            api_key.scopes().to_vec(),
+            None,
+            Some(now),
+            None,
+            api_key.created_by().to_string(),
+            api_key.created_at(),
+        );
+        assert!(!used.should_record_use(now + Duration::seconds(30)));
+        assert!(used.should_record_use(now + Duration::seconds(60)));
+    }
+}
@@ -0,0 +1,343 @@
+//! Network Contract Model
+//!
+//! Well-known contract addresses deployed on a network (Multicall3, ENS
+//! registry, in-house deployments), forming a per-network address book.
+
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::domain::models::network::{is_ethereum_address, NetworkId, ETHEREUM_ADDRESS_LENGTH};
+use crate::shared::errors::DomainError;
+
+/// Maximum length for contract name
+pub const MAX_CONTRACT_NAME_LENGTH: usize = 100;
+/// Maximum length for the ABI reference
+pub const MAX_ABI_REF_LENGTH: usize = 500;
+/// Maximum number of tags on a contract
+pub const MAX_TAGS: usize = 20;
+/// Maximum length of a single tag
+pub const MAX_TAG_LENGTH: usize = 50;
+
+/// Unique identifier for a network contract
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ContractId(Uuid);
+
+impl ContractId {
+    /// Create a new random ContractId
+    #[must_use]
+    pub fn new() -> Self {
+        Self(Uuid::new_v4())
+    }
+
+    /// Create a ContractId from an existing UUID
+    #[must_use]
+    pub fn from_uuid(uuid: Uuid) -> Self {
+        Self(uuid)
+    }
+
+    /// Get the underlying UUID
+    #[must_use]
+    pub fn as_uuid(&self) -> &Uuid {
+        &self.0
+    }
+}
+
+impl Default for ContractId {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl std::fmt::Display for ContractId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// Data required to register a contract, also used to replace one
+#[derive(Debug, Clone)]
+pub struct ContractData {
+    pub name: String,
+    pub address: String,
+    /// Block the contract was deployed at, used as a starting point by indexers
+    pub deployment_block: Option<i64>,
+    /// Where to find the ABI (artifact name, URL, IPFS CID, ...)
+    pub abi_ref: Option<String>,
+    pub tags: Vec<String>,
+}
+
+impl ContractData {
+    /// Validate the contract data
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DomainError::ValidationError` if any field is invalid
+    pub fn validate(&self) -> Result<(), DomainError> {
+        if self.name.trim().is_empty() || self.name.len() > MAX_CONTRACT_NAME_LENGTH {
+            return Err(DomainError::ValidationError(format!(
+                "name must be between 1 and {MAX_CONTRACT_NAME_LENGTH} characters"
+            )));
+        }
+
+        if !is_ethereum_address(&self.address) {
+            return Err(DomainError::ValidationError(format!(
+                "address must be 0x followed by {} hex characters",
+                ETHEREUM_ADDRESS_LENGTH - 2
+            )));
+        }
+
+        if self.deployment_block.is_some_and(|block| block < 0) {
+            return Err(DomainError::ValidationError(
+                "deployment_block must be at least 0".to_string(),
+            ));
+        }
+
+        if let Some(abi_ref) = &self.abi_ref {
+            if abi_ref.trim().is_empty() || abi_ref.len() > MAX_ABI_REF_LENGTH {
+                return Err(DomainError::ValidationError(format!(
+                    "abi_ref must be between 1 and {MAX_ABI_REF_LENGTH} characters"
+                )));
+            }
+        }
+
+        if self.tags.len() > MAX_TAGS {
+            return Err(DomainError::ValidationError(format!(
+                "tags can have at most {MAX_TAGS} items"
+            )));
+        }
+
+        for tag in &self.tags {
+            if tag.trim().is_empty() || tag.len() > MAX_TAG_LENGTH {
+                return Err(DomainError::ValidationError(format!(
+                    "each tag must be between 1 and {MAX_TAG_LENGTH} characters"
+                )));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Normalize tags: trim, lowercase, drop duplicates while keeping order
+fn normalize_tags(tags: Vec<String>) -> Vec<String> {
+    let mut normalized: Vec<String> = Vec::with_capacity(tags.len());
+    for tag in tags {
+        let tag = tag.trim().to_lowercase();
+        if !normalized.contains(&tag) {
+            normalized.push(tag);
+        }
+    }
+    normalized
+}
+
+/// A contract deployed on a network
+#[derive(Debug, Clone)]
+pub struct NetworkContract {
+    id: ContractId,
+    network_id: NetworkId,
+    name: String,
+    address: String,
+    deployment_block: Option<i64>,
+    abi_ref: Option<String>,
+    tags: Vec<String>,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl NetworkContract {
+    /// Register a new contract on a network
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DomainError::ValidationError` if the data is invalid
+    pub fn new(network_id: NetworkId, data: ContractData) -> Result<Self, DomainError> {
+        data.validate()?;
+
+        let now = Utc::now();
+        Ok(Self {
+            id: ContractId::new(),
+            network_id,
+            name: data.name.trim().to_string(),
+            address: data.address,
+            deployment_block: data.deployment_block,
+            abi_ref: data.abi_ref,
+            tags: normalize_tags(data.tags),
+            created_at: now,
+            updated_at: now,
+        })
+    }
+
+    /// Restore a contract from persistence (no validation)
+    #[must_use]
+    #[allow(clippy::too_many_arguments)]
+    pub fn restore(
+        id: ContractId,
+        network_id: NetworkId,
+        name: String,
+        address: String,
+        deployment_block: Option<i64>,
+        abi_ref: Option<String>,
+        tags: Vec<String>,
+        created_at: DateTime<Utc>,
+        updated_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id,
+            network_id,
+            name,
+            address,
+            deployment_block,
+            abi_ref,
+            tags,
+            created_at,
+            updated_at,
+        }
+    }
+
+    /// Replace the contract details, keeping its identity
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DomainError::ValidationError` if the data is invalid
+    pub fn replace(self, data: ContractData) -> Result<Self, DomainError> {
+        data.validate()?;
+
+        Ok(Self {
+            name: data.name.trim().to_string(),
+            address: data.address,
+            deployment_block: data.deployment_block,
+            abi_ref: data.abi_ref,
+            tags: normalize_tags(data.tags),
+            updated_at: Utc::now(),
+            ..self
+        })
+    }
+
+    // Getters
+
+    #[must_use]
+    pub fn id(&self) -> &ContractId {
+        &self.id
+    }
+
+    #[must_use]
+    pub fn network_id(&self) -> &NetworkId {
+        &self.network_id
+    }
+
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    #[must_use]
+    pub fn address(&self) -> &str {
+        &self.address
+    }
+
+    #[must_use]
+    pub fn deployment_block(&self) -> Option<i64> {
+        self.deployment_block
+    }
+
+    #[must_use]
+    pub fn abi_ref(&self) -> Option<&str> {
+        self.abi_ref.as_deref()
+    }
+
+    #[must_use]
+    pub fn tags(&self) -> &[String] {
+        &self.tags
+    }
+
+    #[must_use]
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    #[must_use]
+    pub fn updated_at(&self) -> DateTime<Utc> {
+        self.updated_at
+    }
+}
+
+/// A contract found by name, with the chain it is deployed on
+#[derive(Debug, Clone)]
+pub struct ContractDeployment {
+    pub chain_id: i32,
+    pub network_name: String,
+    pub contract: NetworkContract,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn multicall3() -> ContractData {
+        ContractData {
+            name: "Multicall3".to_string(),
+            address: "0xcA11bde05977b3631167028862bE2a173976CA11".to_string(),
+            deployment_block: Some(14_353_601),
+            abi_ref: Some("https://github.com/mds1/multicall".to_string()),
+            tags: vec!["Utility".to_string(), "utility".to_string(), " batching ".to_string()],
+        }
+    }
+
+    #[test]
+    fn test_new_contract_normalizes_tags() {
+        let contract = NetworkContract::new(NetworkId::new(), multicall3()).unwrap();
+        assert_eq!(contract.name(), "Multicall3");
+        assert_eq!(contract.tags(), ["utility", "batching"]);
+    }
+
+    #[test]
+    fn test_rejects_invalid_address() {
+        for address in [
+            "0x123",
+            "cA11bde05977b3631167028862bE2a173976CA11ab",
+            "0xZZ11bde05977b3631167028862bE2a173976CA11",
+        ] {
+            let data = ContractData {
+                address: address.to_string(),
+                ..multicall3()
+            };
+            assert!(data.validate().is_err(), "{address} should be rejected");
+        }
+    }
+
+    #[test]
+    fn test_rejects_invalid_fields() {
+        let negative_block = ContractData {
+            deployment_block: Some(-1),
+            ..multicall3()
+        };
+        assert!(negative_block.validate().is_err());
+
+        let blank_name = ContractData {
+            name: "  ".to_string(),
+            ..multicall3()
+        };
+        assert!(blank_name.validate().is_err());
+
+        let too_many_tags = ContractData {
+            tags: (0..=MAX_TAGS).map(|i| format!("tag-{i}")).collect(),
+            ..multicall3()
+        };
+        assert!(too_many_tags.validate().is_err());
+    }
+
+    #[test]
+    fn test_replace_keeps_identity() {
+        let contract = NetworkContract::new(NetworkId::new(), multicall3()).unwrap();
+        let replaced = contract
+            .clone()
+            .replace(ContractData {
+                deployment_block: None,
+                ..multicall3()
+            })
+            .unwrap();
+        assert_eq!(replaced.id(), contract.id());
+        assert_eq!(replaced.network_id(), contract.network_id());
+        assert_eq!(replaced.deployment_block(), None);
+    }
+}
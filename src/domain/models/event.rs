@@ -0,0 +1,208 @@
+//! Network Event Model
+//!
+//! Change events emitted by the network use cases, so consumers can
+//! hot-reload network configuration instead of polling. Events carry the
+//! network as it is after the change.
+
+use std::fmt;
+use std::str::FromStr;
+
+use chrono::{DateTime, Utc};
+
+use crate::domain::models::lifecycle::NetworkStatus;
+use crate::domain::models::network::Network;
+use crate::shared::errors::DomainError;
+
+/// What happened to a network
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NetworkEventKind {
+    /// The network was registered
+    Created,
+    /// The network changed without changing whether it is usable
+    Updated,
+    /// The network stopped being usable (retired)
+    Deactivated,
+    /// The network became usable again (a draft activated or a retired network restored)
+    Restored,
+}
+
+impl NetworkEventKind {
+    /// Get the wire representation
+    #[must_use]
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Created => "created",
+            Self::Updated => "updated",
+            Self::Deactivated => "deactivated",
+            Self::Restored => "restored",
+        }
+    }
+
+    /// Classify a change from `before` to `after` by its effect on usability
+    #[must_use]
+    pub fn for_change(before: &Network, after: &Network) -> Self {
+        Self::for_status_change(before.status(), after.status())
+    }
+
+    /// Classify a lifecycle change from `before` to `after` by its effect on usability
+    #[must_use]
+    pub fn for_status_change(before: NetworkStatus, after: NetworkStatus) -> Self {
+        match (before.is_usable(), after.is_usable()) {
+            (true, false) => Self::Deactivated,
+            (false, true) => Self::Restored,
+            _ => Self::Updated,
+        }
+    }
+}
+
+impl fmt::Display for NetworkEventKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for NetworkEventKind {
+    type Err = DomainError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "created" => Ok(Self::Created),
+            "updated" => Ok(Self::Updated),
+            "deactivated" => Ok(Self::Deactivated),
+            "restored" => Ok(Self::Restored),
+            _ => Err(DomainError::ValidationError(format!("unknown network event '{value}'"))),
+        }
+    }
+}
+
+/// A change to a network
+#[derive(Debug, Clone)]
+pub struct NetworkEvent {
+    pub kind: NetworkEventKind,
+    /// The network after the change
+    pub network: Network,
+    pub occurred_at: DateTime<Utc>,
+}
+
+impl NetworkEvent {
+    /// Create an event of the given kind, occurring now
+    #[must_use]
+    pub fn new(kind: NetworkEventKind, network: Network) -> Self {
+        Self {
+            kind,
+            network,
+            occurred_at: Utc::now(),
+        }
+    }
+
+    /// Event for a newly registered network
+    #[must_use]
+    pub fn created(network: Network) -> Self {
+        Self::new(NetworkEventKind::Created, network)
+    }
+
+    /// Event for a change from `before` to `after`
+    #[must_use]
+    pub fn changed(before: &Network, after: Network) -> Self {
+        Self::new(NetworkEventKind::for_change(before, &after), after)
+    }
+}
+
+/// An event and its position in the stream
+///
+/// Positions increase monotonically, so a consumer can resume after the
+/// last one it saw.
+#[derive(Debug, Clone)]
+pub struct SequencedEvent {
+    pub id: u64,
+    pub event: NetworkEvent,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::models::explorer::ExplorerMetadata;
+    use crate::domain::models::fee_config::FeeConfig;
+    use crate::domain::models::finality::FinalityPolicy;
+    use crate::domain::models::labels::NetworkMetadata;
+    use crate::domain::models::network::{CreateNetworkData, UpdateNetworkData};
+    use crate::domain::models::testnet::TestnetInfo;
+    use crate::domain::models::url_policy::UrlPolicy;
+    use rust_decimal_macros::dec;
+
+    fn create_test_network(status: NetworkStatus) -> Network {
+        let data = CreateNetworkData {
+            chain_id: 1,
+            name: "Ethereum Mainnet".to_string(),
+            rpc_url: "https://rpc.example.com".to_string(),
+            other_rpc_urls: vec![],
+            test_net: false,
+            block_explorer_url: "https://explorer.example.com".to_string(),
+            fee_multiplier: dec!(1.0),
+            gas_limit_multiplier: dec!(1.2),
+            default_signer_address: "0x742d35Cc6634C0532925a3b844Bc9e7595f1dEaD".to_string(),
+            explorer: ExplorerMetadata::default(),
+            fee_config: FeeConfig::default(),
+            finality: FinalityPolicy::default(),
+            status,
+            parent: None,
+            tags: vec![],
+            metadata: NetworkMetadata::new(),
+            slug: None,
+            aliases: vec![],
+            testnet: TestnetInfo::default(),
+        };
+        Network::new(data, &UrlPolicy::default()).expect("valid test data")
+    }
+
+    #[test]
+    fn test_event_kind_for_change() {
+        let active = create_test_network(NetworkStatus::Active);
+        let renamed = active
+            .clone()
+            .with_updates(
+                UpdateNetworkData {
+                    name: Some("Ethereum".to_string()),
+                    ..Default::default()
+                },
+                &UrlPolicy::default(),
+            )
+            .unwrap();
+        assert_eq!(
+            NetworkEventKind::for_change(&active, &renamed),
+            NetworkEventKind::Updated
+        );
+
+        let deprecated = active.clone().transition_to(NetworkStatus::Deprecated, None).unwrap();
+        assert_eq!(
+            NetworkEventKind::for_change(&active, &deprecated),
+            NetworkEventKind::Updated
+        );
+
+        let retired = active.clone().transition_to(NetworkStatus::Retired, None).unwrap();
+        assert_eq!(
+            NetworkEventKind::for_change(&active, &retired),
+            NetworkEventKind::Deactivated
+        );
+
+        let draft = create_test_network(NetworkStatus::Draft);
+        let activated = draft.clone().transition_to(NetworkStatus::Active, None).unwrap();
+        assert_eq!(
+            NetworkEventKind::for_change(&draft, &activated),
+            NetworkEventKind::Restored
+        );
+    }
+
+    #[test]
+    fn test_event_kind_roundtrip() {
+        for kind in [
+            NetworkEventKind::Created,
+            NetworkEventKind::Updated,
+            NetworkEventKind::Deactivated,
+            NetworkEventKind::Restored,
+        ] {
+            assert_eq!(kind.as_str().parse::<NetworkEventKind>().unwrap(), kind);
+        }
+        assert!("deleted".parse::<NetworkEventKind>().is_err());
+    }
+}
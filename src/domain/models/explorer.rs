@@ -0,0 +1,293 @@
+//! Block Explorer Model
+//!
+//! Explorer metadata attached to a network and the canonical link builder
+//! for transactions, addresses and blocks.
+
+use std::fmt;
+use std::str::FromStr;
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::shared::errors::DomainError;
+
+/// Regex for transaction and block hashes (0x followed by 64 hex characters)
+static HASH_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^0x[a-fA-F0-9]{64}$").unwrap_or_else(|e| panic!("Invalid hash regex: {e}")));
+
+/// Regex for Ethereum addresses (0x followed by 40 hex characters)
+static ADDRESS_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^0x[a-fA-F0-9]{40}$").unwrap_or_else(|e| panic!("Invalid address regex: {e}")));
+
+/// Explorer software family, which determines the URL templates
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ExplorerKind {
+    Etherscan,
+    Blockscout,
+    #[default]
+    Other,
+}
+
+impl ExplorerKind {
+    /// Get the persisted representation
+    #[must_use]
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Etherscan => "etherscan",
+            Self::Blockscout => "blockscout",
+            Self::Other => "other",
+        }
+    }
+}
+
+impl fmt::Display for ExplorerKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for ExplorerKind {
+    type Err = DomainError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "etherscan" => Ok(Self::Etherscan),
+            "blockscout" => Ok(Self::Blockscout),
+            "other" => Ok(Self::Other),
+            _ => Err(DomainError::ValidationError(format!("unknown explorer kind '{value}'"))),
+        }
+    }
+}
+
+/// URL standard implemented by the explorer
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ExplorerStandard {
+    /// EIP-3091 paths (`/tx/{hash}`, `/address/{address}`, `/block/{block}`)
+    #[default]
+    Eip3091,
+    /// No known URL standard
+    None,
+}
+
+impl ExplorerStandard {
+    /// Get the persisted representation
+    #[must_use]
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Eip3091 => "EIP3091",
+            Self::None => "none",
+        }
+    }
+}
+
+impl fmt::Display for ExplorerStandard {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for ExplorerStandard {
+    type Err = DomainError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "EIP3091" => Ok(Self::Eip3091),
+            "none" => Ok(Self::None),
+            _ => Err(DomainError::ValidationError(format!(
+                "unknown explorer standard '{value}'"
+            ))),
+        }
+    }
+}
+
+/// Explorer metadata attached to a network
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct ExplorerMetadata {
+    pub kind: ExplorerKind,
+    /// Base URL of the explorer API (e.g., `https://api.etherscan.io/api`)
+    pub api_url: Option<String>,
+    pub standard: ExplorerStandard,
+}
+
+/// URL path templates for an explorer
+struct LinkTemplates {
+    tx: &'static str,
+    address: &'static str,
+    block: &'static str,
+}
+
+/// Templates for Etherscan-family explorers
+const ETHERSCAN_TEMPLATES: LinkTemplates = LinkTemplates {
+    tx: "/tx/{value}",
+    address: "/address/{value}",
+    block: "/block/{value}",
+};
+
+/// Templates for Blockscout explorers
+const BLOCKSCOUT_TEMPLATES: LinkTemplates = LinkTemplates {
+    tx: "/tx/{value}",
+    address: "/address/{value}",
+    block: "/block/{value}",
+};
+
+/// Templates mandated by EIP-3091
+const EIP3091_TEMPLATES: LinkTemplates = LinkTemplates {
+    tx: "/tx/{value}",
+    address: "/address/{value}",
+    block: "/block/{value}",
+};
+
+impl ExplorerMetadata {
+    /// Get the link templates for this explorer, if its URL patterns are known
+    fn templates(&self) -> Option<&'static LinkTemplates> {
+        match (self.kind, self.standard) {
+            (ExplorerKind::Etherscan, _) => Some(&ETHERSCAN_TEMPLATES),
+            (ExplorerKind::Blockscout, _) => Some(&BLOCKSCOUT_TEMPLATES),
+            (ExplorerKind::Other, ExplorerStandard::Eip3091) => Some(&EIP3091_TEMPLATES),
+            (ExplorerKind::Other, ExplorerStandard::None) => None,
+        }
+    }
+}
+
+/// Items to build explorer links for
+#[derive(Debug, Clone, Default)]
+pub struct ExplorerLinkQuery {
+    pub tx: Option<String>,
+    pub address: Option<String>,
+    pub block: Option<String>,
+}
+
+/// Canonical explorer URLs for the requested items
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct ExplorerLinks {
+    pub tx: Option<String>,
+    pub address: Option<String>,
+    pub block: Option<String>,
+}
+
+impl ExplorerLinks {
+    /// Build canonical explorer URLs for a network's explorer
+    ///
+    /// # Errors
+    ///
+    /// Returns `DomainError::ValidationError` if no item was requested or an item is malformed.
+    /// Returns `DomainError::InvalidState` if the explorer's URL patterns are unknown.
+    pub fn build(base_url: &str, metadata: &ExplorerMetadata, query: &ExplorerLinkQuery) -> Result<Self, DomainError> {
+        if query.tx.is_none() && query.address.is_none() && query.block.is_none() {
+            return Err(DomainError::ValidationError(
+                "at least one of tx, address or block is required".to_string(),
+            ));
+        }
+
+        if let Some(tx) = &query.tx {
+            if !HASH_REGEX.is_match(tx) {
+                return Err(DomainError::ValidationError(
+                    "tx must be 0x followed by 64 hex characters".to_string(),
+                ));
+            }
+        }
+
+        if let Some(address) = &query.address {
+            if !ADDRESS_REGEX.is_match(address) {
+                return Err(DomainError::ValidationError(
+                    "address must be 0x followed by 40 hex characters".to_string(),
+                ));
+            }
+        }
+
+        if let Some(block) = &query.block {
+            if block.parse::<u64>().is_err() && !HASH_REGEX.is_match(block) {
+                return Err(DomainError::ValidationError(
+                    "block must be a block number or 0x followed by 64 hex characters".to_string(),
+                ));
+            }
+        }
+
+        let templates = metadata.templates().ok_or_else(|| {
+            DomainError::InvalidState(format!(
+                "explorer of kind '{}' does not follow a known URL standard",
+                metadata.kind
+            ))
+        })?;
+
+        let base_url = base_url.trim_end_matches('/');
+        let render = |template: &str, value: &str| format!("{base_url}{}", template.replace("{value}", value));
+
+        Ok(Self {
+            tx: query.tx.as_deref().map(|tx| render(templates.tx, tx)),
+            address: query
+                .address
+                .as_deref()
+                .map(|address| render(templates.address, address)),
+            block: query.block.as_deref().map(|block| render(templates.block, block)),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TX: &str = "0x5c504ed432cb51138bcf09aa5e8a410dd4a1e204ef84bfed1be16dfba1b22060";
+    const ADDRESS: &str = "0x742d35Cc6634C0532925a3b844Bc9e7595f1dEaD";
+
+    #[test]
+    fn test_build_etherscan_links() {
+        let metadata = ExplorerMetadata {
+            kind: ExplorerKind::Etherscan,
+            ..Default::default()
+        };
+        let query = ExplorerLinkQuery {
+            tx: Some(TX.to_string()),
+            address: Some(ADDRESS.to_string()),
+            block: Some("17000000".to_string()),
+        };
+
+        let links = ExplorerLinks::build("https://etherscan.io/", &metadata, &query).unwrap();
+        assert_eq!(links.tx, Some(format!("https://etherscan.io/tx/{TX}")));
+        assert_eq!(links.address, Some(format!("https://etherscan.io/address/{ADDRESS}")));
+        assert_eq!(links.block, Some("https://etherscan.io/block/17000000".to_string()));
+    }
+
+    #[test]
+    fn test_build_rejects_malformed_items() {
+        let metadata = ExplorerMetadata::default();
+        let bad_tx = ExplorerLinkQuery {
+            tx: Some("0x1234".to_string()),
+            ..Default::default()
+        };
+        let bad_block = ExplorerLinkQuery {
+            block: Some("latest".to_string()),
+            ..Default::default()
+        };
+
+        assert!(ExplorerLinks::build("https://etherscan.io", &metadata, &bad_tx).is_err());
+        assert!(ExplorerLinks::build("https://etherscan.io", &metadata, &bad_block).is_err());
+        assert!(ExplorerLinks::build("https://etherscan.io", &metadata, &ExplorerLinkQuery::default()).is_err());
+    }
+
+    #[test]
+    fn test_build_without_standard_is_invalid_state() {
+        let metadata = ExplorerMetadata {
+            standard: ExplorerStandard::None,
+            ..Default::default()
+        };
+        let query = ExplorerLinkQuery {
+            address: Some(ADDRESS.to_string()),
+            ..Default::default()
+        };
+
+        let result = ExplorerLinks::build("https://explorer.example.com", &metadata, &query);
+        assert!(matches!(result, Err(DomainError::InvalidState(_))));
+    }
+
+    #[test]
+    fn test_kind_and_standard_roundtrip() {
+        for kind in [ExplorerKind::Etherscan, ExplorerKind::Blockscout, ExplorerKind::Other] {
+            assert_eq!(kind.as_str().parse::<ExplorerKind>().unwrap(), kind);
+        }
+        for standard in [ExplorerStandard::Eip3091, ExplorerStandard::None] {
+            assert_eq!(standard.as_str().parse::<ExplorerStandard>().unwrap(), standard);
+        }
+    }
+}
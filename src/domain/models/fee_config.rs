@@ -0,0 +1,295 @@
+//! Fee Configuration Model
+//!
+//! Per-network transaction fee settings used by the transaction sender.
+//!
+//! All fee amounts are held in wei as integral decimals. Callers may express
+//! them in gwei through [`FeeUnit`], which converts at the boundary.
+
+use std::fmt;
+use std::str::FromStr;
+
+use rust_decimal::Decimal;
+
+use crate::shared::errors::DomainError;
+
+/// Number of wei in one gwei
+pub const WEI_PER_GWEI: Decimal = Decimal::from_parts(1_000_000_000, 0, 0, false, 0);
+/// Largest fee amount accepted, in wei (one billion ether)
+pub const MAX_FEE_WEI: Decimal = Decimal::from_parts(3_892_314_112, 2_681_241_660, 54_210_108, false, 0);
+/// Minimum gas limit cap (intrinsic gas of a plain transfer)
+pub const MIN_GAS_LIMIT_CAP: i64 = 21_000;
+/// Lowest accepted base-fee multiplier
+pub const MIN_BASE_FEE_MULTIPLIER: Decimal = Decimal::ONE;
+/// Highest accepted base-fee multiplier
+pub const MAX_BASE_FEE_MULTIPLIER: Decimal = Decimal::TEN;
+
+/// Transaction fee model
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FeeModel {
+    /// Pre-London pricing with a single `gasPrice`
+    #[default]
+    Legacy,
+    /// EIP-1559 pricing with `maxFeePerGas` and `maxPriorityFeePerGas`
+    Eip1559,
+}
+
+impl FeeModel {
+    /// Get the persisted representation
+    #[must_use]
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Legacy => "legacy",
+            Self::Eip1559 => "eip1559",
+        }
+    }
+}
+
+impl fmt::Display for FeeModel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for FeeModel {
+    type Err = DomainError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "legacy" => Ok(Self::Legacy),
+            "eip1559" => Ok(Self::Eip1559),
+            _ => Err(DomainError::ValidationError(format!("unknown fee model '{value}'"))),
+        }
+    }
+}
+
+/// Unit a fee amount is expressed in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FeeUnit {
+    Wei,
+    #[default]
+    Gwei,
+}
+
+impl FeeUnit {
+    /// Convert an amount in this unit to wei, returning `None` on overflow
+    #[must_use]
+    pub fn to_wei(self, amount: Decimal) -> Option<Decimal> {
+        match self {
+            Self::Wei => Some(amount),
+            Self::Gwei => amount.checked_mul(WEI_PER_GWEI),
+        }
+    }
+}
+
+/// Convert a wei amount to gwei
+#[must_use]
+pub fn wei_to_gwei(wei: Decimal) -> Decimal {
+    (wei / WEI_PER_GWEI).normalize()
+}
+
+/// Lower and upper bound for a fee, in wei
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct FeeBounds {
+    pub floor: Option<Decimal>,
+    pub cap: Option<Decimal>,
+}
+
+impl FeeBounds {
+    /// Check whether neither bound is set
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.floor.is_none() && self.cap.is_none()
+    }
+
+    fn validate(&self, field: &str) -> Result<(), DomainError> {
+        for (bound, value) in [("floor", self.floor), ("cap", self.cap)] {
+            if let Some(value) = value {
+                validate_wei(&format!("{field}.{bound}"), value)?;
+            }
+        }
+
+        if let (Some(floor), Some(cap)) = (self.floor, self.cap) {
+            if floor > cap {
+                return Err(DomainError::ValidationError(format!(
+                    "{field}.floor must not exceed {field}.cap"
+                )));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Validate that an amount is a whole, non-negative number of wei within range
+fn validate_wei(field: &str, value: Decimal) -> Result<(), DomainError> {
+    if value.is_sign_negative() || !value.fract().is_zero() {
+        return Err(DomainError::ValidationError(format!(
+            "{field} must be a non-negative whole number of wei"
+        )));
+    }
+
+    if value > MAX_FEE_WEI {
+        return Err(DomainError::ValidationError(format!(
+            "{field} must be at most {MAX_FEE_WEI} wei"
+        )));
+    }
+
+    Ok(())
+}
+
+/// Fee configuration attached to a network
+///
+/// For the legacy model, `max_fee_per_gas` bounds the `gasPrice`; priority fee
+/// bounds and the base-fee multiplier only apply to EIP-1559.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct FeeConfig {
+    pub model: FeeModel,
+    pub max_fee_per_gas: FeeBounds,
+    pub max_priority_fee_per_gas: FeeBounds,
+    /// Multiplier applied to the latest base fee when computing `maxFeePerGas`
+    pub base_fee_multiplier: Option<Decimal>,
+    /// Upper bound for estimated gas limits
+    pub gas_limit_cap: Option<i64>,
+}
+
+impl FeeConfig {
+    /// Validate that the configuration is internally consistent
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DomainError::ValidationError` if an amount is out of range,
+    /// a floor exceeds its cap, or an EIP-1559 setting is used with the legacy model
+    pub fn validate(&self) -> Result<(), DomainError> {
+        self.max_fee_per_gas.validate("fee_config.max_fee_per_gas")?;
+        self.max_priority_fee_per_gas
+            .validate("fee_config.max_priority_fee_per_gas")?;
+
+        if self.model == FeeModel::Legacy {
+            if !self.max_priority_fee_per_gas.is_empty() {
+                return Err(DomainError::ValidationError(
+                    "fee_config.max_priority_fee_per_gas requires the eip1559 fee model".to_string(),
+                ));
+            }
+            if self.base_fee_multiplier.is_some() {
+                return Err(DomainError::ValidationError(
+                    "fee_config.base_fee_multiplier requires the eip1559 fee model".to_string(),
+                ));
+            }
+        }
+
+        // The tip is part of the max fee, so it can never be allowed to exceed it
+        if let Some(max_fee_cap) = self.max_fee_per_gas.cap {
+            let priority = &self.max_priority_fee_per_gas;
+            if priority.cap.or(priority.floor).is_some_and(|tip| tip > max_fee_cap) {
+                return Err(DomainError::ValidationError(
+                    "fee_config.max_priority_fee_per_gas must not exceed fee_config.max_fee_per_gas.cap".to_string(),
+                ));
+            }
+        }
+
+        if let Some(multiplier) = self.base_fee_multiplier {
+            if multiplier < MIN_BASE_FEE_MULTIPLIER || multiplier > MAX_BASE_FEE_MULTIPLIER {
+                return Err(DomainError::ValidationError(format!(
+                    "fee_config.base_fee_multiplier must be between {MIN_BASE_FEE_MULTIPLIER} and {MAX_BASE_FEE_MULTIPLIER}"
+                )));
+            }
+        }
+
+        if let Some(gas_limit_cap) = self.gas_limit_cap {
+            if gas_limit_cap < MIN_GAS_LIMIT_CAP {
+                return Err(DomainError::ValidationError(format!(
+                    "fee_config.gas_limit_cap must be at least {MIN_GAS_LIMIT_CAP}"
+                )));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    fn eip1559_config() -> FeeConfig {
+        FeeConfig {
+            model: FeeModel::Eip1559,
+            max_fee_per_gas: FeeBounds {
+                floor: Some(dec!(1_000_000_000)),
+                cap: Some(dec!(200_000_000_000)),
+            },
+            max_priority_fee_per_gas: FeeBounds {
+                floor: Some(dec!(100_000_000)),
+                cap: Some(dec!(5_000_000_000)),
+            },
+            base_fee_multiplier: Some(dec!(2)),
+            gas_limit_cap: Some(10_000_000),
+        }
+    }
+
+    #[test]
+    fn test_constants() {
+        assert_eq!(WEI_PER_GWEI, dec!(1_000_000_000));
+        assert_eq!(MAX_FEE_WEI, dec!(1_000_000_000_000_000_000_000_000_000));
+    }
+
+    #[test]
+    fn test_fee_unit_conversion() {
+        assert_eq!(FeeUnit::Gwei.to_wei(dec!(1.5)), Some(dec!(1_500_000_000)));
+        assert_eq!(FeeUnit::Wei.to_wei(dec!(42)), Some(dec!(42)));
+        assert_eq!(FeeUnit::Gwei.to_wei(Decimal::MAX), None);
+        assert_eq!(wei_to_gwei(dec!(1_500_000_000)), dec!(1.5));
+    }
+
+    #[test]
+    fn test_valid_configs() {
+        assert!(FeeConfig::default().validate().is_ok());
+        assert!(eip1559_config().validate().is_ok());
+    }
+
+    #[test]
+    fn test_rejects_floor_above_cap() {
+        let mut config = eip1559_config();
+        config.max_fee_per_gas.floor = Some(dec!(300_000_000_000));
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_rejects_priority_fee_above_max_fee_cap() {
+        let mut config = eip1559_config();
+        config.max_priority_fee_per_gas.cap = Some(dec!(300_000_000_000));
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_rejects_eip1559_settings_on_legacy() {
+        let config = FeeConfig {
+            model: FeeModel::Legacy,
+            ..eip1559_config()
+        };
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_rejects_out_of_range_values() {
+        let mut config = eip1559_config();
+        config.max_fee_per_gas.cap = Some(dec!(1.5));
+        assert!(config.validate().is_err());
+
+        let mut config = eip1559_config();
+        config.base_fee_multiplier = Some(dec!(0.5));
+        assert!(config.validate().is_err());
+
+        let mut config = eip1559_config();
+        config.gas_limit_cap = Some(20_999);
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_fee_model_roundtrip() {
+        for model in [FeeModel::Legacy, FeeModel::Eip1559] {
+            assert_eq!(model.as_str().parse::<FeeModel>().unwrap(), model);
+        }
+    }
+}
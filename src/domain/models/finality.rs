@@ -0,0 +1,173 @@
+//! Finality Policy Model
+//!
+//! Per-network block time, confirmation and reorg settings used by indexers
+//! to decide when a block can be treated as final.
+
+use crate::shared::errors::DomainError;
+
+/// Default average block time in milliseconds (Ethereum mainnet)
+pub const DEFAULT_BLOCK_TIME_MS: i32 = 12_000;
+/// Default number of confirmations
+pub const DEFAULT_REQUIRED_CONFIRMATIONS: i32 = 12;
+/// Longest accepted average block time in milliseconds (one hour)
+pub const MAX_BLOCK_TIME_MS: i32 = 3_600_000;
+/// Largest accepted confirmation count or reorg depth
+pub const MAX_CONFIRMATIONS: i32 = 10_000;
+
+/// Block tag a client should read from to only observe final data
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BlockTag {
+    Latest,
+    Safe,
+    Finalized,
+}
+
+impl BlockTag {
+    /// Get the JSON-RPC tag name
+    #[must_use]
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Latest => "latest",
+            Self::Safe => "safe",
+            Self::Finalized => "finalized",
+        }
+    }
+}
+
+/// Finality and confirmation policy attached to a network
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FinalityPolicy {
+    pub average_block_time_ms: i32,
+    pub required_confirmations: i32,
+    /// Whether the node exposes the `safe` block tag
+    pub supports_safe_tag: bool,
+    /// Whether the node exposes the `finalized` block tag
+    pub supports_finalized_tag: bool,
+    /// Deepest reorg observed or tolerated on the chain, if known
+    pub max_reorg_depth: Option<i32>,
+}
+
+impl Default for FinalityPolicy {
+    fn default() -> Self {
+        Self {
+            average_block_time_ms: DEFAULT_BLOCK_TIME_MS,
+            required_confirmations: DEFAULT_REQUIRED_CONFIRMATIONS,
+            supports_safe_tag: false,
+            supports_finalized_tag: false,
+            max_reorg_depth: None,
+        }
+    }
+}
+
+impl FinalityPolicy {
+    /// Validate the policy
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DomainError::ValidationError` if a value is out of range, or if
+    /// confirmations are shallower than the max reorg depth on a chain without
+    /// a `finalized` tag to fall back on
+    pub fn validate(&self) -> Result<(), DomainError> {
+        if !(1..=MAX_BLOCK_TIME_MS).contains(&self.average_block_time_ms) {
+            return Err(DomainError::ValidationError(format!(
+                "finality.average_block_time_ms must be between 1 and {MAX_BLOCK_TIME_MS}"
+            )));
+        }
+
+        if !(0..=MAX_CONFIRMATIONS).contains(&self.required_confirmations) {
+            return Err(DomainError::ValidationError(format!(
+                "finality.required_confirmations must be between 0 and {MAX_CONFIRMATIONS}"
+            )));
+        }
+
+        if let Some(max_reorg_depth) = self.max_reorg_depth {
+            if !(0..=MAX_CONFIRMATIONS).contains(&max_reorg_depth) {
+                return Err(DomainError::ValidationError(format!(
+                    "finality.max_reorg_depth must be between 0 and {MAX_CONFIRMATIONS}"
+                )));
+            }
+
+            if !self.supports_finalized_tag && self.required_confirmations < max_reorg_depth {
+                return Err(DomainError::ValidationError(
+                    "finality.required_confirmations must be at least finality.max_reorg_depth".to_string(),
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Number of blocks to wait before treating a block as final
+    #[must_use]
+    pub fn recommended_confirmations(&self) -> i32 {
+        self.required_confirmations.max(self.max_reorg_depth.unwrap_or(0))
+    }
+
+    /// Recommended wait in milliseconds before treating a block as final
+    #[must_use]
+    pub fn recommended_wait_ms(&self) -> i64 {
+        i64::from(self.recommended_confirmations()) * i64::from(self.average_block_time_ms)
+    }
+
+    /// Strongest block tag supported by the network
+    #[must_use]
+    pub fn recommended_block_tag(&self) -> BlockTag {
+        if self.supports_finalized_tag {
+            BlockTag::Finalized
+        } else if self.supports_safe_tag {
+            BlockTag::Safe
+        } else {
+            BlockTag::Latest
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_policy_is_valid() {
+        let policy = FinalityPolicy::default();
+        assert!(policy.validate().is_ok());
+        assert_eq!(policy.recommended_wait_ms(), 144_000);
+        assert_eq!(policy.recommended_block_tag(), BlockTag::Latest);
+    }
+
+    #[test]
+    fn test_recommended_wait_covers_reorg_depth() {
+        let policy = FinalityPolicy {
+            average_block_time_ms: 2_000,
+            required_confirmations: 5,
+            supports_safe_tag: true,
+            supports_finalized_tag: true,
+            max_reorg_depth: Some(30),
+        };
+        assert!(policy.validate().is_ok());
+        assert_eq!(policy.recommended_confirmations(), 30);
+        assert_eq!(policy.recommended_wait_ms(), 60_000);
+        assert_eq!(policy.recommended_block_tag(), BlockTag::Finalized);
+    }
+
+    #[test]
+    fn test_rejects_invalid_policies() {
+        let zero_block_time = FinalityPolicy {
+            average_block_time_ms: 0,
+            ..Default::default()
+        };
+        assert!(zero_block_time.validate().is_err());
+
+        let negative_confirmations = FinalityPolicy {
+            required_confirmations: -1,
+            ..Default::default()
+        };
+        assert!(negative_confirmations.validate().is_err());
+
+        let shallow_confirmations = FinalityPolicy {
+            required_confirmations: 5,
+            max_reorg_depth: Some(30),
+            ..Default::default()
+        };
+        assert!(shallow_confirmations.validate().is_err());
+    }
+}
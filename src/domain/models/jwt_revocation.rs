@@ -0,0 +1,353 @@
+//! JWT Revocation Model
+//!
+//! Revocations rejecting JWTs before they expire, either a single token by
+//! its `jti` claim or every token of a subject issued up to a point in time.
+//!
+//! A JWT revocation is only needed until the token expires, after which it
+//! can be purged. A subject revocation is kept: later tokens of the subject
+//! are not affected, and revoking the subject again moves the point forward.
+
+use chrono::{DateTime, Utc};
+
+use crate::shared::errors::DomainError;
+
+/// Maximum length of a token ID (`jti` claim)
+pub const MAX_JTI_LENGTH: usize = 128;
+/// Maximum length of a subject (`sub` claim)
+pub const MAX_SUBJECT_LENGTH: usize = 255;
+/// Maximum length of a revocation reason
+pub const MAX_REASON_LENGTH: usize = 500;
+
+fn validate_reason(reason: Option<&str>) -> Result<(), DomainError> {
+    if reason.is_some_and(|reason| reason.len() > MAX_REASON_LENGTH) {
+        return Err(DomainError::ValidationError(format!(
+            "reason must be at most {MAX_REASON_LENGTH} characters"
+        )));
+    }
+    Ok(())
+}
+
+fn validate_subject(subject: &str) -> Result<(), DomainError> {
+    if subject.trim().is_empty() || subject.len() > MAX_SUBJECT_LENGTH {
+        return Err(DomainError::ValidationError(format!(
+            "subject must be between 1 and {MAX_SUBJECT_LENGTH} characters"
+        )));
+    }
+    Ok(())
+}
+
+/// Data required to revoke a single token
+#[derive(Debug, Clone)]
+pub struct JwtRevocationData {
+    /// ID of the token (`jti` claim)
+    pub jti: String,
+    /// Subject of the token, for reference only
+    pub subject: Option<String>,
+    pub reason: Option<String>,
+    /// Expiry of the token; the maximum token lifetime from now when unknown
+    pub expires_at: Option<DateTime<Utc>>,
+}
+
+impl JwtRevocationData {
+    /// Validate the JWT revocation data
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DomainError::ValidationError` if any field is invalid
+    pub fn validate(&self) -> Result<(), DomainError> {
+        if self.jti.trim().is_empty() || self.jti.len() > MAX_JTI_LENGTH {
+            return Err(DomainError::ValidationError(format!(
+                "jti must be between 1 and {MAX_JTI_LENGTH} characters"
+            )));
+        }
+
+        if let Some(subject) = &self.subject {
+            validate_subject(subject)?;
+        }
+
+        validate_reason(self.reason.as_deref())
+    }
+}
+
+/// A single revoked token
+#[derive(Debug, Clone)]
+pub struct JwtRevocation {
+    jti: String,
+    subject: Option<String>,
+    reason: Option<String>,
+    expires_at: DateTime<Utc>,
+    revoked_by: String,
+    revoked_at: DateTime<Utc>,
+}
+
+impl JwtRevocation {
+    /// Revoke a token, kept until `default_expires_at` unless its expiry is given
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DomainError::ValidationError` if the data is invalid
+    pub fn new(
+        data: JwtRevocationData,
+        revoked_by: String,
+        default_expires_at: DateTime<Utc>,
+    ) -> Result<Self, DomainError> {
+        data.validate()?;
+
+        Ok(Self {
+            jti: data.jti,
+            subject: data.subject.map(|subject| subject.trim().to_string()),
+            reason: data.reason,
+            expires_at: data.expires_at.unwrap_or(default_expires_at),
+            revoked_by,
+            revoked_at: Utc::now(),
+        })
+    }
+
+    /// Restore a JWT revocation from persistence (no validation)
+    #[must_use]
+    pub fn restore(
+        jti: String,
+        subject: Option<String>,
+        reason: Option<String>,
+        expires_at: DateTime<Utc>,
+        revoked_by: String,
+        revoked_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            jti,
+            subject,
+            reason,
+            expires_at,
+            revoked_by,
+            revoked_at,
+        }
+    }
+
+    // Getters
+    #[must_use]
+    pub fn jti(&self) -> &str {
+        &self.jti
+    }
+
+    #[must_use]
+    pub fn subject(&self) -> Option<&str> {
+        self.subject.as_deref()
+    }
+
+    #[must_use]
+    pub fn reason(&self) -> Option<&str> {
+        self.reason.as_deref()
+    }
+
+    /// Time after which the token is rejected anyway and the revocation can be purged
+    #[must_use]
+    pub fn expires_at(&self) -> DateTime<Utc> {
+        self.expires_at
+    }
+
+    #[must_use]
+    pub fn revoked_by(&self) -> &str {
+        &self.revoked_by
+    }
+
+    #[must_use]
+    pub fn revoked_at(&self) -> DateTime<Utc> {
+        self.revoked_at
+    }
+}
+
+/// Data required to revoke the tokens of a subject
+#[derive(Debug, Clone)]
+pub struct SubjectRevocationData {
+    /// Subject of the tokens (`sub` claim)
+    pub subject: String,
+    /// Tokens issued at or before this time are revoked; now when not given
+    pub revoked_before: Option<DateTime<Utc>>,
+    pub reason: Option<String>,
+}
+
+impl SubjectRevocationData {
+    /// Validate the subject revocation data
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DomainError::ValidationError` if any field is invalid
+    pub fn validate(&self) -> Result<(), DomainError> {
+        validate_subject(&self.subject)?;
+
+        if self
+            .revoked_before
+            .is_some_and(|revoked_before| revoked_before > Utc::now())
+        {
+            return Err(DomainError::ValidationError(
+                "revoked_before cannot be in the future".to_string(),
+            ));
+        }
+
+        validate_reason(self.reason.as_deref())
+    }
+}
+
+/// Revocation of every token of a subject issued up to a point in time
+#[derive(Debug, Clone)]
+pub struct SubjectRevocation {
+    subject: String,
+    revoked_before: DateTime<Utc>,
+    reason: Option<String>,
+    revoked_by: String,
+    revoked_at: DateTime<Utc>,
+}
+
+impl SubjectRevocation {
+    /// Revoke the tokens of a subject
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DomainError::ValidationError` if the data is invalid
+    pub fn new(data: SubjectRevocationData, revoked_by: String) -> Result<Self, DomainError> {
+        data.validate()?;
+
+        let revoked_at = Utc::now();
+        Ok(Self {
+            subject: data.subject.trim().to_string(),
+            revoked_before: data.revoked_before.unwrap_or(revoked_at),
+            reason: data.reason,
+            revoked_by,
+            revoked_at,
+        })
+    }
+
+    /// Restore a subject revocation from persistence (no validation)
+    #[must_use]
+    pub fn restore(
+        subject: String,
+        revoked_before: DateTime<Utc>,
+        reason: Option<String>,
+        revoked_by: String,
+        revoked_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            subject,
+            revoked_before,
+            reason,
+            revoked_by,
+            revoked_at,
+        }
+    }
+
+    /// Check whether a token of the subject issued at `issued_at` is revoked
+    ///
+    /// Token issue times have a one second resolution, so a token issued
+    /// during the second of `revoked_before` is revoked too.
+    #[must_use]
+    pub fn revokes(&self, issued_at: DateTime<Utc>) -> bool {
+        is_revoked_before(self.revoked_before, issued_at)
+    }
+
+    // Getters
+    #[must_use]
+    pub fn subject(&self) -> &str {
+        &self.subject
+    }
+
+    #[must_use]
+    pub fn revoked_before(&self) -> DateTime<Utc> {
+        self.revoked_before
+    }
+
+    #[must_use]
+    pub fn reason(&self) -> Option<&str> {
+        self.reason.as_deref()
+    }
+
+    #[must_use]
+    pub fn revoked_by(&self) -> &str {
+        &self.revoked_by
+    }
+
+    #[must_use]
+    pub fn revoked_at(&self) -> DateTime<Utc> {
+        self.revoked_at
+    }
+}
+
+/// Check whether a token issued at `issued_at` is covered by a subject
+/// revocation up to `revoked_before`
+#[must_use]
+pub fn is_revoked_before(revoked_before: DateTime<Utc>, issued_at: DateTime<Utc>) -> bool {
+    issued_at.timestamp() <= revoked_before.timestamp()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    #[test]
+    fn test_jwt_revocation_defaults_expiry() {
+        let default_expires_at = Utc::now() + Duration::hours(1);
+        let data = JwtRevocationData {
+            jti: "3f1c".to_string(),
+            subject: Some(" user-1 ".to_string()),
+            reason: None,
+            expires_at: None,
+        };
+
+        let revocation = JwtRevocation::new(data.clone(), "admin".to_string(), default_expires_at).unwrap();
+        assert_eq!(revocation.expires_at(), default_expires_at);
+        assert_eq!(revocation.subject(), Some("user-1"));
+
+        let expires_at = Utc::now() + Duration::minutes(5);
+        let revocation = JwtRevocation::new(
+            JwtRevocationData {
+                expires_at: Some(expires_at),
+                ..data.clone()
+            },
+            "admin".to_string(),
+            default_expires_at,
+        )
+        .unwrap();
+        assert_eq!(revocation.expires_at(), expires_at);
+
+        let invalid = JwtRevocationData {
+            jti: " ".to_string(),
+            ..data
+        };
+        assert!(invalid.validate().is_err());
+    }
+
+    #[test]
+    fn test_subject_revocation_covers_earlier_tokens() {
+        let revocation = SubjectRevocation::new(
+            SubjectRevocationData {
+                subject: "user-1".to_string(),
+                revoked_before: None,
+                reason: Some("Laptop stolen".to_string()),
+            },
+            "admin".to_string(),
+        )
+        .unwrap();
+        let revoked_before = revocation.revoked_before();
+
+        assert!(revocation.revokes(revoked_before - Duration::hours(1)));
+        assert!(revocation.revokes(revoked_before));
+        assert!(!revocation.revokes(revoked_before + Duration::seconds(1)));
+    }
+
+    #[test]
+    fn test_subject_revocation_rejects_future_point() {
+        let data = SubjectRevocationData {
+            subject: "user-1".to_string(),
+            revoked_before: Some(Utc::now() + Duration::hours(1)),
+            reason: None,
+        };
+        assert!(data.validate().is_err());
+
+        let data = SubjectRevocationData {
+            subject: String::new(),
+            revoked_before: None,
+            reason: None,
+        };
+        assert!(data.validate().is_err());
+    }
+}
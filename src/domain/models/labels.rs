@@ -0,0 +1,199 @@
+//! Network Labels Model
+//!
+//! Free-form grouping of networks: a set of tags (`tier:gold`,
+//! `team:payments`, `l2`) and a bounded JSON metadata map for anything the
+//! fixed field set does not cover.
+
+use std::collections::BTreeSet;
+
+use serde_json::{Map, Value};
+
+use crate::shared::errors::DomainError;
+
+/// Maximum number of tags on a network
+pub const MAX_TAGS: usize = 32;
+/// Maximum length for a tag
+pub const MAX_TAG_LENGTH: usize = 50;
+/// Maximum number of top-level metadata keys
+pub const MAX_METADATA_KEYS: usize = 32;
+/// Maximum length for a metadata key
+pub const MAX_METADATA_KEY_LENGTH: usize = 64;
+/// Maximum size of the serialized metadata, in bytes
+pub const MAX_METADATA_BYTES: usize = 8192;
+
+/// Custom metadata attached to a network
+pub type NetworkMetadata = Map<String, Value>;
+
+/// Normalize a tag: trim and lowercase
+#[must_use]
+pub fn normalize_tag(tag: &str) -> String {
+    tag.trim().to_lowercase()
+}
+
+/// Normalize a list of tags into a deduplicated, sorted set
+#[must_use]
+pub fn normalize_tags(tags: &[String]) -> BTreeSet<String> {
+    tags.iter().map(|tag| normalize_tag(tag)).collect()
+}
+
+/// Validate a list of tags
+///
+/// Tags are compared after normalization, so `L2` and `l2` count once.
+///
+/// # Errors
+///
+/// Returns a `DomainError::ValidationError` if a tag is malformed or there are too many tags
+pub fn validate_tags(tags: &[String]) -> Result<(), DomainError> {
+    let tags = normalize_tags(tags);
+    if tags.len() > MAX_TAGS {
+        return Err(DomainError::ValidationError(format!(
+            "a network can have at most {MAX_TAGS} tags"
+        )));
+    }
+
+    for tag in &tags {
+        let valid = tag.len() <= MAX_TAG_LENGTH
+            && tag.starts_with(|c: char| c.is_ascii_alphanumeric())
+            && tag
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, ':' | '.' | '_' | '-'));
+        if !valid {
+            return Err(DomainError::ValidationError(format!(
+                "tag '{tag}' must be 1 to {MAX_TAG_LENGTH} letters, digits, ':', '.', '_' or '-' \
+                 starting with a letter or digit"
+            )));
+        }
+    }
+
+    Ok(())
+}
+
+/// Validate custom metadata
+///
+/// # Errors
+///
+/// Returns a `DomainError::ValidationError` if a key is malformed, there are
+/// too many keys or the serialized map is too large
+pub fn validate_metadata(metadata: &NetworkMetadata) -> Result<(), DomainError> {
+    if metadata.len() > MAX_METADATA_KEYS {
+        return Err(DomainError::ValidationError(format!(
+            "metadata can have at most {MAX_METADATA_KEYS} keys"
+        )));
+    }
+
+    if let Some(key) = metadata
+        .keys()
+        .find(|key| key.is_empty() || key.len() > MAX_METADATA_KEY_LENGTH)
+    {
+        return Err(DomainError::ValidationError(format!(
+            "metadata key '{key}' must be between 1 and {MAX_METADATA_KEY_LENGTH} characters"
+        )));
+    }
+
+    let size = serde_json::to_vec(metadata).map_or(usize::MAX, |bytes| bytes.len());
+    if size > MAX_METADATA_BYTES {
+        return Err(DomainError::ValidationError(format!(
+            "metadata must be at most {MAX_METADATA_BYTES} bytes once serialized"
+        )));
+    }
+
+    Ok(())
+}
+
+/// Change to the custom metadata of a network
+#[derive(Debug, Clone, PartialEq)]
+pub enum MetadataUpdate {
+    /// Replace the whole map
+    Replace(NetworkMetadata),
+    /// Apply a JSON merge patch (RFC 7396): `null` removes a key, objects merge recursively
+    Merge(NetworkMetadata),
+}
+
+impl MetadataUpdate {
+    /// Apply the change to the current metadata
+    #[must_use]
+    pub fn apply(self, current: NetworkMetadata) -> NetworkMetadata {
+        match self {
+            Self::Replace(metadata) => metadata,
+            Self::Merge(patch) => merge_patch(current, patch),
+        }
+    }
+}
+
+/// Apply a JSON merge patch to an object
+fn merge_patch(mut target: NetworkMetadata, patch: NetworkMetadata) -> NetworkMetadata {
+    for (key, value) in patch {
+        match value {
+            Value::Null => {
+                target.remove(&key);
+            }
+            Value::Object(patch) => {
+                let current = match target.remove(&key) {
+                    Some(Value::Object(current)) => current,
+                    _ => Map::new(),
+                };
+                target.insert(key, Value::Object(merge_patch(current, patch)));
+            }
+            value => {
+                target.insert(key, value);
+            }
+        }
+    }
+    target
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn metadata(value: Value) -> NetworkMetadata {
+        match value {
+            Value::Object(map) => map,
+            _ => panic!("metadata must be an object"),
+        }
+    }
+
+    #[test]
+    fn test_normalize_tags() {
+        let tags = normalize_tags(&[" L2 ".to_string(), "l2".to_string(), "team:payments".to_string()]);
+        assert_eq!(tags.into_iter().collect::<Vec<_>>(), ["l2", "team:payments"]);
+    }
+
+    #[test]
+    fn test_validate_tags() {
+        assert!(validate_tags(&["tier:gold".to_string(), "Team.Payments".to_string()]).is_ok());
+        assert!(validate_tags(&[String::new()]).is_err());
+        assert!(validate_tags(&["-leading".to_string()]).is_err());
+        assert!(validate_tags(&["with space".to_string()]).is_err());
+        assert!(validate_tags(&["x".repeat(MAX_TAG_LENGTH + 1)]).is_err());
+
+        let too_many: Vec<String> = (0..=MAX_TAGS).map(|i| format!("tag-{i}")).collect();
+        assert!(validate_tags(&too_many).is_err());
+    }
+
+    #[test]
+    fn test_validate_metadata() {
+        assert!(validate_metadata(&metadata(json!({ "product": "payments", "sla": { "tier": 1 } }))).is_ok());
+        assert!(validate_metadata(&metadata(json!({ "": 1 }))).is_err());
+        assert!(validate_metadata(&metadata(json!({ "blob": "x".repeat(MAX_METADATA_BYTES) }))).is_err());
+
+        let too_many: NetworkMetadata = (0..=MAX_METADATA_KEYS).map(|i| (format!("k{i}"), json!(i))).collect();
+        assert!(validate_metadata(&too_many).is_err());
+    }
+
+    #[test]
+    fn test_metadata_merge_patch() {
+        let current = metadata(json!({ "product": "payments", "owner": "team-a", "sla": { "tier": 1, "hours": 24 } }));
+        let patch = metadata(json!({ "owner": null, "sla": { "hours": 8 }, "region": "eu" }));
+
+        let merged = MetadataUpdate::Merge(patch).apply(current.clone());
+        assert_eq!(
+            Value::Object(merged),
+            json!({ "product": "payments", "sla": { "tier": 1, "hours": 8 }, "region": "eu" })
+        );
+
+        let replaced = MetadataUpdate::Replace(metadata(json!({ "region": "us" }))).apply(current);
+        assert_eq!(Value::Object(replaced), json!({ "region": "us" }));
+    }
+}
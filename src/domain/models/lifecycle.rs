@@ -0,0 +1,122 @@
+//! Network Lifecycle Model
+//!
+//! Lifecycle states of a network and the transitions allowed between them:
+//!
+//! ```text
+//! draft ──► active ◄──► deprecated
+//!   │        ▲ │            │
+//!   │        │ ▼            │
+//!   └─────► retired ◄───────┘
+//! ```
+//!
+//! Retired networks can only be restored to `active`.
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::shared::errors::DomainError;
+
+/// Lifecycle state of a network
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum NetworkStatus {
+    /// Being onboarded, not yet served to clients
+    Draft,
+    /// Live
+    #[default]
+    Active,
+    /// Still usable but scheduled for removal
+    Deprecated,
+    /// Sunset, no longer usable
+    Retired,
+}
+
+impl NetworkStatus {
+    /// Get the persisted representation
+    #[must_use]
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Draft => "draft",
+            Self::Active => "active",
+            Self::Deprecated => "deprecated",
+            Self::Retired => "retired",
+        }
+    }
+
+    /// States reachable from this state
+    #[must_use]
+    pub fn allowed_transitions(&self) -> &'static [NetworkStatus] {
+        match self {
+            Self::Draft | Self::Deprecated => &[Self::Active, Self::Retired],
+            Self::Active => &[Self::Deprecated, Self::Retired],
+            Self::Retired => &[Self::Active],
+        }
+    }
+
+    /// Check whether the state machine allows moving to `target`
+    #[must_use]
+    pub fn can_transition_to(&self, target: NetworkStatus) -> bool {
+        self.allowed_transitions().contains(&target)
+    }
+
+    /// Whether clients may use networks in this state
+    #[must_use]
+    pub fn is_usable(&self) -> bool {
+        matches!(self, Self::Active | Self::Deprecated)
+    }
+}
+
+impl fmt::Display for NetworkStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for NetworkStatus {
+    type Err = DomainError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "draft" => Ok(Self::Draft),
+            "active" => Ok(Self::Active),
+            "deprecated" => Ok(Self::Deprecated),
+            "retired" => Ok(Self::Retired),
+            _ => Err(DomainError::ValidationError(format!(
+                "unknown network status '{value}'"
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_allowed_transitions() {
+        assert!(NetworkStatus::Draft.can_transition_to(NetworkStatus::Active));
+        assert!(NetworkStatus::Active.can_transition_to(NetworkStatus::Deprecated));
+        assert!(NetworkStatus::Deprecated.can_transition_to(NetworkStatus::Active));
+        assert!(NetworkStatus::Deprecated.can_transition_to(NetworkStatus::Retired));
+        assert!(NetworkStatus::Retired.can_transition_to(NetworkStatus::Active));
+    }
+
+    #[test]
+    fn test_forbidden_transitions() {
+        assert!(!NetworkStatus::Active.can_transition_to(NetworkStatus::Draft));
+        assert!(!NetworkStatus::Draft.can_transition_to(NetworkStatus::Deprecated));
+        assert!(!NetworkStatus::Active.can_transition_to(NetworkStatus::Active));
+        assert_eq!(NetworkStatus::Retired.allowed_transitions(), &[NetworkStatus::Active]);
+    }
+
+    #[test]
+    fn test_status_roundtrip() {
+        for status in [
+            NetworkStatus::Draft,
+            NetworkStatus::Active,
+            NetworkStatus::Deprecated,
+            NetworkStatus::Retired,
+        ] {
+            assert_eq!(status.as_str().parse::<NetworkStatus>().unwrap(), status);
+        }
+    }
+}
@@ -2,6 +2,28 @@
 //!
 //! Pure domain entities and value objects representing business concepts.
 
+pub mod api_key;
+pub mod contract;
+pub mod event;
+pub mod explorer;
+pub mod fee_config;
+pub mod finality;
+pub mod jwt_revocation;
+pub mod labels;
+pub mod lifecycle;
 pub mod network;
+pub mod outbox;
+pub mod relation;
+pub mod rpc_url;
+pub mod schedule;
+pub mod search;
+pub mod service_account;
+pub mod signer;
+pub mod slug;
+pub mod testnet;
+pub mod token;
+pub mod token_list;
+pub mod url_policy;
+pub mod webhook;
 
 pub use network::{CreateNetworkData, Network, NetworkId, UpdateNetworkData};
@@ -2,10 +2,24 @@
 //!
 //! Represents a blockchain network in the registry.
 
+use std::collections::BTreeSet;
+
 use chrono::{DateTime, Utc};
 use rust_decimal::Decimal;
 use uuid::Uuid;
 
+use crate::domain::models::explorer::ExplorerMetadata;
+use crate::domain::models::fee_config::FeeConfig;
+use crate::domain::models::finality::FinalityPolicy;
+use crate::domain::models::labels::{
+    normalize_tags, validate_metadata, validate_tags, MetadataUpdate, NetworkMetadata,
+};
+use crate::domain::models::lifecycle::NetworkStatus;
+use crate::domain::models::relation::{check_lineage, NetworkParent};
+use crate::domain::models::rpc_url;
+use crate::domain::models::slug::{normalize_aliases, normalize_slug, slugify, validate_aliases, validate_slug};
+use crate::domain::models::testnet::TestnetInfo;
+use crate::domain::models::url_policy::UrlPolicy;
 use crate::shared::errors::DomainError;
 
 /// Maximum length for network name
@@ -93,73 +107,209 @@ pub struct CreateNetworkData {
     pub fee_multiplier: Decimal,
     pub gas_limit_multiplier: Decimal,
     pub default_signer_address: String,
+    pub explorer: ExplorerMetadata,
+    pub fee_config: FeeConfig,
+    pub finality: FinalityPolicy,
+    /// Initial lifecycle state, either `Draft` or `Active`
+    pub status: NetworkStatus,
+    pub parent: Option<NetworkParent>,
+    pub tags: Vec<String>,
+    pub metadata: NetworkMetadata,
+    /// Canonical slug, derived from the name when absent
+    pub slug: Option<String>,
+    pub aliases: Vec<String>,
+    pub testnet: TestnetInfo,
 }
 
 impl CreateNetworkData {
-    /// Validate all fields in the creation data
+    /// Validate all fields in the creation data, checking URLs against the URL policy
     ///
     /// # Errors
     ///
     /// Returns a `DomainError::ValidationError` if any field is invalid
-    pub fn validate(&self) -> Result<(), DomainError> {
-        if self.chain_id < 1 {
-            return Err(DomainError::ValidationError("chain_id must be at least 1".to_string()));
+    pub fn validate(&self, url_policy: &UrlPolicy) -> Result<(), DomainError> {
+        validate_chain_and_name(self.chain_id, &self.name)?;
+        validate_urls(
+            &self.rpc_url,
+            &self.other_rpc_urls,
+            &self.block_explorer_url,
+            url_policy,
+        )?;
+        validate_signing_defaults(
+            self.fee_multiplier,
+            self.gas_limit_multiplier,
+            &self.default_signer_address,
+        )?;
+        validate_explorer(&self.explorer, url_policy)?;
+        self.fee_config.validate()?;
+        self.finality.validate()?;
+        validate_tags(&self.tags)?;
+        validate_metadata(&self.metadata)?;
+        if let Some(slug) = &self.slug {
+            validate_slug(slug)?;
         }
+        validate_aliases(&self.aliases)?;
+        self.testnet.validate(self.test_net, url_policy)?;
 
-        if self.name.is_empty() || self.name.len() > MAX_NAME_LENGTH {
-            return Err(DomainError::ValidationError(format!(
-                "name must be between 1 and {} characters",
-                MAX_NAME_LENGTH
-            )));
+        if !matches!(self.status, NetworkStatus::Draft | NetworkStatus::Active) {
+            return Err(DomainError::ValidationError(
+                "status of a new network must be draft or active".to_string(),
+            ));
         }
 
-        if self.rpc_url.len() > MAX_URL_LENGTH {
-            return Err(DomainError::ValidationError(format!(
-                "rpc_url must be at most {} characters",
-                MAX_URL_LENGTH
-            )));
-        }
+        Ok(())
+    }
+}
 
-        if self.other_rpc_urls.len() > MAX_OTHER_RPC_URLS {
+/// Check that a value is a `0x`-prefixed 20-byte hex address
+#[must_use]
+pub fn is_ethereum_address(value: &str) -> bool {
+    value.len() == ETHEREUM_ADDRESS_LENGTH
+        && value.starts_with("0x")
+        && value[2..].chars().all(|c| c.is_ascii_hexdigit())
+}
+
+/// Validate the chain ID and name of a network
+///
+/// # Errors
+///
+/// Returns a `DomainError::ValidationError` if the chain ID is not positive or the name is empty or too long
+fn validate_chain_and_name(chain_id: i32, name: &str) -> Result<(), DomainError> {
+    if chain_id < 1 {
+        return Err(DomainError::ValidationError("chain_id must be at least 1".to_string()));
+    }
+
+    if name.is_empty() || name.len() > MAX_NAME_LENGTH {
+        return Err(DomainError::ValidationError(format!(
+            "name must be between 1 and {} characters",
+            MAX_NAME_LENGTH
+        )));
+    }
+
+    Ok(())
+}
+
+/// Validate the multipliers and default signer of a network
+///
+/// # Errors
+///
+/// Returns a `DomainError::ValidationError` if a multiplier is negative or the signer address has the wrong length
+fn validate_signing_defaults(
+    fee_multiplier: Decimal,
+    gas_limit_multiplier: Decimal,
+    default_signer_address: &str,
+) -> Result<(), DomainError> {
+    if fee_multiplier < Decimal::ZERO {
+        return Err(DomainError::ValidationError(
+            "fee_multiplier must be at least 0".to_string(),
+        ));
+    }
+
+    if gas_limit_multiplier < Decimal::ZERO {
+        return Err(DomainError::ValidationError(
+            "gas_limit_multiplier must be at least 0".to_string(),
+        ));
+    }
+
+    if default_signer_address.len() != ETHEREUM_ADDRESS_LENGTH {
+        return Err(DomainError::ValidationError(format!(
+            "default_signer_address must be {} characters",
+            ETHEREUM_ADDRESS_LENGTH
+        )));
+    }
+
+    Ok(())
+}
+
+/// Validate the RPC and block explorer URLs of a network
+///
+/// # Errors
+///
+/// Returns a `DomainError::ValidationError` if a URL is too long or rejected by the URL policy
+fn validate_urls(
+    rpc_url: &str,
+    other_rpc_urls: &[String],
+    block_explorer_url: &str,
+    url_policy: &UrlPolicy,
+) -> Result<(), DomainError> {
+    if rpc_url.len() > MAX_URL_LENGTH {
+        return Err(DomainError::ValidationError(format!(
+            "rpc_url must be at most {} characters",
+            MAX_URL_LENGTH
+        )));
+    }
+
+    url_policy
+        .check(rpc_url)
+        .map_err(|reason| DomainError::ValidationError(format!("rpc_url {reason}")))?;
+
+    if rpc_url::is_redacted(rpc_url) {
+        return Err(DomainError::ValidationError(format!(
+            "rpc_url must not contain the redaction marker '{}'",
+            rpc_url::REDACTED
+        )));
+    }
+
+    if other_rpc_urls.len() > MAX_OTHER_RPC_URLS {
+        return Err(DomainError::ValidationError(format!(
+            "other_rpc_urls can have at most {} items",
+            MAX_OTHER_RPC_URLS
+        )));
+    }
+
+    for url in other_rpc_urls {
+        if url.len() > MAX_URL_LENGTH {
             return Err(DomainError::ValidationError(format!(
-                "other_rpc_urls can have at most {} items",
-                MAX_OTHER_RPC_URLS
+                "each URL in other_rpc_urls must be at most {} characters",
+                MAX_URL_LENGTH
             )));
         }
 
-        for url in &self.other_rpc_urls {
-            if url.len() > MAX_URL_LENGTH {
-                return Err(DomainError::ValidationError(format!(
-                    "each URL in other_rpc_urls must be at most {} characters",
-                    MAX_URL_LENGTH
-                )));
-            }
-        }
+        url_policy
+            .check(url)
+            .map_err(|reason| DomainError::ValidationError(format!("each URL in other_rpc_urls {reason}")))?;
 
-        if self.block_explorer_url.len() > MAX_URL_LENGTH {
+        if rpc_url::is_redacted(url) {
             return Err(DomainError::ValidationError(format!(
-                "block_explorer_url must be at most {} characters",
-                MAX_URL_LENGTH
+                "each URL in other_rpc_urls must not contain the redaction marker '{}'",
+                rpc_url::REDACTED
             )));
         }
+    }
 
-        if self.fee_multiplier < Decimal::ZERO {
-            return Err(DomainError::ValidationError("fee_multiplier must be at least 0".to_string()));
-        }
+    if block_explorer_url.len() > MAX_URL_LENGTH {
+        return Err(DomainError::ValidationError(format!(
+            "block_explorer_url must be at most {} characters",
+            MAX_URL_LENGTH
+        )));
+    }
 
-        if self.gas_limit_multiplier < Decimal::ZERO {
-            return Err(DomainError::ValidationError("gas_limit_multiplier must be at least 0".to_string()));
-        }
+    url_policy
+        .check(block_explorer_url)
+        .map_err(|reason| DomainError::ValidationError(format!("block_explorer_url {reason}")))?;
 
-        if self.default_signer_address.len() != ETHEREUM_ADDRESS_LENGTH {
+    Ok(())
+}
+
+/// Validate explorer metadata
+///
+/// # Errors
+///
+/// Returns a `DomainError::ValidationError` if the explorer API URL is invalid
+pub fn validate_explorer(explorer: &ExplorerMetadata, url_policy: &UrlPolicy) -> Result<(), DomainError> {
+    if let Some(api_url) = &explorer.api_url {
+        if api_url.len() > MAX_URL_LENGTH {
             return Err(DomainError::ValidationError(format!(
-                "default_signer_address must be {} characters",
-                ETHEREUM_ADDRESS_LENGTH
+                "explorer.api_url must be at most {MAX_URL_LENGTH} characters"
             )));
         }
 
-        Ok(())
+        url_policy
+            .check(api_url)
+            .map_err(|reason| DomainError::ValidationError(format!("explorer.api_url {reason}")))?;
     }
+
+    Ok(())
 }
 
 /// Data for updating an existing Network (all fields optional for partial updates)
@@ -174,7 +324,18 @@ pub struct UpdateNetworkData {
     pub fee_multiplier: Option<Decimal>,
     pub gas_limit_multiplier: Option<Decimal>,
     pub default_signer_address: Option<String>,
+    /// Legacy lifecycle flag: `true` activates the network, `false` retires it.
+    /// Applied through [`Network::apply_active_flag`], not by `with_updates`.
     pub active: Option<bool>,
+    pub explorer: Option<ExplorerMetadata>,
+    pub fee_config: Option<FeeConfig>,
+    pub finality: Option<FinalityPolicy>,
+    pub tags: Option<Vec<String>>,
+    pub metadata: Option<MetadataUpdate>,
+    pub slug: Option<String>,
+    /// Replaces the aliases; the slug is never kept as its own alias
+    pub aliases: Option<Vec<String>>,
+    pub testnet: Option<TestnetInfo>,
 }
 
 /// Network domain entity representing a blockchain network
@@ -189,8 +350,19 @@ pub struct Network {
     block_explorer_url: String,
     fee_multiplier: Decimal,
     gas_limit_multiplier: Decimal,
-    active: bool,
+    status: NetworkStatus,
+    deprecated_at: Option<DateTime<Utc>>,
+    sunset_at: Option<DateTime<Utc>>,
     default_signer_address: String,
+    explorer: ExplorerMetadata,
+    fee_config: FeeConfig,
+    finality: FinalityPolicy,
+    parent: Option<NetworkParent>,
+    tags: BTreeSet<String>,
+    metadata: NetworkMetadata,
+    slug: String,
+    aliases: BTreeSet<String>,
+    testnet: TestnetInfo,
     created_at: DateTime<Utc>,
     updated_at: DateTime<Utc>,
 }
@@ -201,9 +373,13 @@ impl Network {
     /// # Errors
     ///
     /// Returns a `DomainError::ValidationError` if the data is invalid
-    pub fn new(data: CreateNetworkData) -> Result<Self, DomainError> {
+    /// or a URL is rejected by the URL policy
+    pub fn new(data: CreateNetworkData, url_policy: &UrlPolicy) -> Result<Self, DomainError> {
         // Validate data at the domain level
-        data.validate()?;
+        data.validate(url_policy)?;
+
+        let slug = data.slug.as_deref().map_or_else(|| slugify(&data.name), normalize_slug);
+        let aliases = normalize_aliases(&slug, &data.aliases);
 
         let now = Utc::now();
         Ok(Self {
@@ -216,8 +392,19 @@ impl Network {
             block_explorer_url: data.block_explorer_url,
             fee_multiplier: data.fee_multiplier,
             gas_limit_multiplier: data.gas_limit_multiplier,
-            active: true,
+            status: data.status,
+            deprecated_at: None,
+            sunset_at: None,
             default_signer_address: data.default_signer_address,
+            explorer: data.explorer,
+            fee_config: data.fee_config,
+            finality: data.finality,
+            parent: data.parent,
+            tags: normalize_tags(&data.tags),
+            metadata: data.metadata,
+            slug,
+            aliases,
+            testnet: data.testnet,
             created_at: now,
             updated_at: now,
         })
@@ -236,8 +423,19 @@ impl Network {
         block_explorer_url: String,
         fee_multiplier: Decimal,
         gas_limit_multiplier: Decimal,
-        active: bool,
+        status: NetworkStatus,
+        deprecated_at: Option<DateTime<Utc>>,
+        sunset_at: Option<DateTime<Utc>>,
         default_signer_address: String,
+        explorer: ExplorerMetadata,
+        fee_config: FeeConfig,
+        finality: FinalityPolicy,
+        parent: Option<NetworkParent>,
+        tags: BTreeSet<String>,
+        metadata: NetworkMetadata,
+        slug: String,
+        aliases: BTreeSet<String>,
+        testnet: TestnetInfo,
         created_at: DateTime<Utc>,
         updated_at: DateTime<Utc>,
     ) -> Self {
@@ -251,40 +449,192 @@ impl Network {
             block_explorer_url,
             fee_multiplier,
             gas_limit_multiplier,
-            active,
+            status,
+            deprecated_at,
+            sunset_at,
             default_signer_address,
+            explorer,
+            fee_config,
+            finality,
+            parent,
+            tags,
+            metadata,
+            slug,
+            aliases,
+            testnet,
             created_at,
             updated_at,
         }
     }
 
     /// Apply updates to the network, returning a new instance
-    #[must_use]
-    pub fn with_updates(self, data: UpdateNetworkData) -> Self {
-        Self {
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DomainError::ValidationError` if the updated network is invalid
+    /// or one of its URLs is rejected by the URL policy
+    pub fn with_updates(self, data: UpdateNetworkData, url_policy: &UrlPolicy) -> Result<Self, DomainError> {
+        // Redacted RPC URLs sent back unchanged keep their stored secrets, preferring the URL
+        // at the same position in [rpc_url, other_rpc_urls..]
+        let stored_urls: Vec<&str> = std::iter::once(self.rpc_url.as_str())
+            .chain(self.other_rpc_urls.iter().map(String::as_str))
+            .collect();
+        let new_rpc_url = data.rpc_url.map(|url| rpc_url::restore_redacted(url, &stored_urls, 0));
+        let new_other_rpc_urls = data.other_rpc_urls.map(|urls| {
+            urls.into_iter()
+                .enumerate()
+                .map(|(index, url)| rpc_url::restore_redacted(url, &stored_urls, index + 1))
+                .collect::<Vec<_>>()
+        });
+
+        let slug = data.slug.map_or(self.slug, |slug| normalize_slug(&slug));
+        let aliases = match data.aliases {
+            Some(aliases) => normalize_aliases(&slug, &aliases),
+            None => self.aliases.into_iter().filter(|alias| *alias != slug).collect(),
+        };
+
+        let updated = Self {
             id: self.id,
             chain_id: data.chain_id.unwrap_or(self.chain_id),
             name: data.name.unwrap_or(self.name),
-            rpc_url: data.rpc_url.unwrap_or(self.rpc_url),
-            other_rpc_urls: data.other_rpc_urls.unwrap_or(self.other_rpc_urls),
+            rpc_url: new_rpc_url.unwrap_or(self.rpc_url),
+            other_rpc_urls: new_other_rpc_urls.unwrap_or(self.other_rpc_urls),
             test_net: data.test_net.unwrap_or(self.test_net),
             block_explorer_url: data.block_explorer_url.unwrap_or(self.block_explorer_url),
             fee_multiplier: data.fee_multiplier.unwrap_or(self.fee_multiplier),
             gas_limit_multiplier: data.gas_limit_multiplier.unwrap_or(self.gas_limit_multiplier),
-            active: data.active.unwrap_or(self.active),
+            status: self.status,
+            deprecated_at: self.deprecated_at,
+            sunset_at: self.sunset_at,
             default_signer_address: data.default_signer_address.unwrap_or(self.default_signer_address),
+            explorer: data.explorer.unwrap_or(self.explorer),
+            fee_config: data.fee_config.unwrap_or(self.fee_config),
+            finality: data.finality.unwrap_or(self.finality),
+            parent: self.parent,
+            tags: data.tags.map_or(self.tags, |tags| normalize_tags(&tags)),
+            metadata: match data.metadata {
+                Some(update) => update.apply(self.metadata),
+                None => self.metadata,
+            },
+            slug,
+            aliases,
+            testnet: data.testnet.unwrap_or(self.testnet),
             created_at: self.created_at,
             updated_at: Utc::now(),
-        }
+        };
+
+        updated.validate(url_policy)?;
+        Ok(updated)
     }
 
-    /// Mark the network as inactive (soft delete)
-    #[must_use]
-    pub fn deactivate(self) -> Self {
-        Self {
-            active: false,
+    /// Validate all fields of the network, as `CreateNetworkData::validate` does on creation
+    fn validate(&self, url_policy: &UrlPolicy) -> Result<(), DomainError> {
+        validate_chain_and_name(self.chain_id, &self.name)?;
+        validate_urls(
+            &self.rpc_url,
+            &self.other_rpc_urls,
+            &self.block_explorer_url,
+            url_policy,
+        )?;
+        validate_signing_defaults(
+            self.fee_multiplier,
+            self.gas_limit_multiplier,
+            &self.default_signer_address,
+        )?;
+        validate_explorer(&self.explorer, url_policy)?;
+        self.fee_config.validate()?;
+        self.finality.validate()?;
+        validate_tags(&self.tags.iter().cloned().collect::<Vec<_>>())?;
+        validate_metadata(&self.metadata)?;
+        validate_slug(&self.slug)?;
+        validate_aliases(&self.aliases.iter().cloned().collect::<Vec<_>>())?;
+        self.testnet.validate(self.test_net, url_policy)
+    }
+
+    /// Attach the network to a parent, or detach it with `None`
+    ///
+    /// `lineage` lists the parent first, then its ancestors up to the root.
+    /// `subtree_height` is the number of levels of descendants below the network.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DomainError::InvalidState` if the relation would create a cycle or exceed the maximum depth.
+    pub fn with_parent(
+        self,
+        parent: Option<NetworkParent>,
+        lineage: &[NetworkId],
+        subtree_height: usize,
+    ) -> Result<Self, DomainError> {
+        if parent.is_some() {
+            check_lineage(&self.id, lineage, subtree_height)?;
+        }
+
+        Ok(Self {
+            parent,
             updated_at: Utc::now(),
             ..self
+        })
+    }
+
+    /// Move the network to another lifecycle state
+    ///
+    /// `sunset_at` is the planned removal date and only applies when deprecating.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DomainError::InvalidState` if the state machine forbids the transition.
+    /// Returns `DomainError::ValidationError` if `sunset_at` is in the past or given for another target.
+    pub fn transition_to(self, target: NetworkStatus, sunset_at: Option<DateTime<Utc>>) -> Result<Self, DomainError> {
+        if !self.status.can_transition_to(target) {
+            return Err(DomainError::InvalidState(format!(
+                "cannot transition network from {} to {target}",
+                self.status
+            )));
+        }
+
+        let now = Utc::now();
+        if let Some(sunset_at) = sunset_at {
+            if target != NetworkStatus::Deprecated {
+                return Err(DomainError::ValidationError(
+                    "sunset_at only applies when deprecating".to_string(),
+                ));
+            }
+            if sunset_at <= now {
+                return Err(DomainError::ValidationError(
+                    "sunset_at must be in the future".to_string(),
+                ));
+            }
+        }
+
+        let (deprecated_at, sunset_at) = match target {
+            NetworkStatus::Deprecated => (Some(now), sunset_at),
+            NetworkStatus::Retired => (self.deprecated_at, Some(now)),
+            NetworkStatus::Draft | NetworkStatus::Active => (None, None),
+        };
+
+        Ok(Self {
+            status: target,
+            deprecated_at,
+            sunset_at,
+            updated_at: now,
+            ..self
+        })
+    }
+
+    /// Apply the legacy `active` flag on top of the lifecycle
+    ///
+    /// `true` activates a draft network or restores a retired one, `false`
+    /// retires a usable one. Flags matching the current usability are a no-op.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DomainError::InvalidState` if the state machine forbids the transition.
+    pub fn apply_active_flag(self, active: bool) -> Result<Self, DomainError> {
+        match (active, self.status) {
+            (true, NetworkStatus::Active | NetworkStatus::Deprecated)
+            | (false, NetworkStatus::Draft | NetworkStatus::Retired) => Ok(self),
+            (true, _) => self.transition_to(NetworkStatus::Active, None),
+            (false, _) => self.transition_to(NetworkStatus::Retired, None),
         }
     }
 
@@ -335,9 +685,25 @@ impl Network {
         self.gas_limit_multiplier
     }
 
+    #[must_use]
+    pub fn status(&self) -> NetworkStatus {
+        self.status
+    }
+
+    /// Whether the network is usable (active or deprecated)
     #[must_use]
     pub fn active(&self) -> bool {
-        self.active
+        self.status.is_usable()
+    }
+
+    #[must_use]
+    pub fn deprecated_at(&self) -> Option<DateTime<Utc>> {
+        self.deprecated_at
+    }
+
+    #[must_use]
+    pub fn sunset_at(&self) -> Option<DateTime<Utc>> {
+        self.sunset_at
     }
 
     #[must_use]
@@ -345,6 +711,67 @@ impl Network {
         &self.default_signer_address
     }
 
+    #[must_use]
+    pub fn explorer(&self) -> &ExplorerMetadata {
+        &self.explorer
+    }
+
+    #[must_use]
+    pub fn fee_config(&self) -> &FeeConfig {
+        &self.fee_config
+    }
+
+    #[must_use]
+    pub fn finality(&self) -> &FinalityPolicy {
+        &self.finality
+    }
+
+    #[must_use]
+    pub fn parent(&self) -> Option<&NetworkParent> {
+        self.parent.as_ref()
+    }
+
+    /// Normalized tags, sorted
+    #[must_use]
+    pub fn tags(&self) -> &BTreeSet<String> {
+        &self.tags
+    }
+
+    #[must_use]
+    pub fn metadata(&self) -> &NetworkMetadata {
+        &self.metadata
+    }
+
+    /// Whether the network carries every given tag, compared after normalization
+    #[must_use]
+    pub fn has_tags(&self, tags: &[String]) -> bool {
+        normalize_tags(tags).is_subset(&self.tags)
+    }
+
+    #[must_use]
+    pub fn slug(&self) -> &str {
+        &self.slug
+    }
+
+    /// Normalized aliases, sorted
+    #[must_use]
+    pub fn aliases(&self) -> &BTreeSet<String> {
+        &self.aliases
+    }
+
+    /// Whether the network answers to the given slug or alias, compared after normalization
+    #[must_use]
+    pub fn answers_to(&self, identifier: &str) -> bool {
+        let identifier = normalize_slug(identifier);
+        self.slug == identifier || self.aliases.contains(&identifier)
+    }
+
+    /// Testnet faucets, mirrored mainnet and expected deprecation, empty for mainnets
+    #[must_use]
+    pub fn testnet(&self) -> &TestnetInfo {
+        &self.testnet
+    }
+
     #[must_use]
     pub fn created_at(&self) -> DateTime<Utc> {
         self.created_at
@@ -359,6 +786,7 @@ impl Network {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::domain::models::fee_config::FeeBounds;
     use rust_decimal_macros::dec;
 
     fn create_test_network_data() -> CreateNetworkData {
@@ -372,6 +800,16 @@ mod tests {
             fee_multiplier: dec!(1.0),
             gas_limit_multiplier: dec!(1.2),
             default_signer_address: "0x742d35Cc6634C0532925a3b844Bc9e7595f1dEaD".to_string(),
+            explorer: ExplorerMetadata::default(),
+            fee_config: FeeConfig::default(),
+            finality: FinalityPolicy::default(),
+            status: NetworkStatus::Active,
+            parent: None,
+            tags: vec![],
+            metadata: NetworkMetadata::new(),
+            slug: None,
+            aliases: vec![],
+            testnet: TestnetInfo::default(),
         }
     }
 
@@ -399,7 +837,7 @@ mod tests {
     #[test]
     fn test_network_new() {
         let data = create_test_network_data();
-        let network = Network::new(data.clone()).expect("valid data should create network");
+        let network = Network::new(data.clone(), &UrlPolicy::default()).expect("valid data should create network");
 
         assert_eq!(network.chain_id(), data.chain_id);
         assert_eq!(network.name(), data.name);
@@ -417,7 +855,7 @@ mod tests {
     fn test_network_new_validates_chain_id() {
         let mut data = create_test_network_data();
         data.chain_id = 0;
-        let result = Network::new(data);
+        let result = Network::new(data, &UrlPolicy::default());
         assert!(result.is_err());
     }
 
@@ -425,7 +863,7 @@ mod tests {
     fn test_network_new_validates_name_length() {
         let mut data = create_test_network_data();
         data.name = "x".repeat(MAX_NAME_LENGTH + 1);
-        let result = Network::new(data);
+        let result = Network::new(data, &UrlPolicy::default());
         assert!(result.is_err());
     }
 
@@ -433,7 +871,7 @@ mod tests {
     fn test_network_new_validates_empty_name() {
         let mut data = create_test_network_data();
         data.name = String::new();
-        let result = Network::new(data);
+        let result = Network::new(data, &UrlPolicy::default());
         assert!(result.is_err());
     }
 
@@ -441,14 +879,39 @@ mod tests {
     fn test_network_new_validates_signer_address_length() {
         let mut data = create_test_network_data();
         data.default_signer_address = "0x123".to_string(); // too short
-        let result = Network::new(data);
+        let result = Network::new(data, &UrlPolicy::default());
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_network_new_rejects_internal_rpc_url() {
+        let mut data = create_test_network_data();
+        data.rpc_url = "http://169.254.169.254/latest/meta-data".to_string();
+        assert!(Network::new(data, &UrlPolicy::default()).is_err());
+
+        let mut data = create_test_network_data();
+        data.other_rpc_urls = vec!["http://localhost:8545".to_string()];
+        assert!(Network::new(data, &UrlPolicy::default()).is_err());
+
+        let mut data = create_test_network_data();
+        data.explorer.api_url = Some("http://169.254.169.254/latest".to_string());
+        assert!(Network::new(data, &UrlPolicy::default()).is_err());
+    }
+
+    #[test]
+    fn test_network_new_validates_fee_config() {
+        let mut data = create_test_network_data();
+        data.fee_config.max_fee_per_gas = FeeBounds {
+            floor: Some(dec!(2_000_000_000)),
+            cap: Some(dec!(1_000_000_000)),
+        };
+        assert!(Network::new(data, &UrlPolicy::default()).is_err());
+    }
+
     #[test]
     fn test_network_with_updates() {
         let data = create_test_network_data();
-        let network = Network::new(data).expect("valid data");
+        let network = Network::new(data, &UrlPolicy::default()).expect("valid data");
 
         let updates = UpdateNetworkData {
             name: Some("Updated Network".to_string()),
@@ -456,18 +919,195 @@ mod tests {
             ..Default::default()
         };
 
-        let updated_network = network.with_updates(updates);
+        let updated_network = network.with_updates(updates, &UrlPolicy::default()).unwrap();
         assert_eq!(updated_network.name(), "Updated Network");
         assert_eq!(updated_network.chain_id(), 2);
     }
 
     #[test]
-    fn test_network_deactivate() {
-        let data = create_test_network_data();
-        let network = Network::new(data).expect("valid data");
-        assert!(network.active());
+    fn test_network_with_updates_validates_updated_network() {
+        let network = Network::new(create_test_network_data(), &UrlPolicy::default()).expect("valid data");
+
+        let invalid_updates = [
+            UpdateNetworkData {
+                chain_id: Some(0),
+                ..Default::default()
+            },
+            UpdateNetworkData {
+                name: Some("x".repeat(MAX_NAME_LENGTH + 1)),
+                ..Default::default()
+            },
+            UpdateNetworkData {
+                fee_multiplier: Some(dec!(-1)),
+                ..Default::default()
+            },
+            UpdateNetworkData {
+                default_signer_address: Some("0x123".to_string()),
+                ..Default::default()
+            },
+            UpdateNetworkData {
+                tags: Some(vec!["not a tag".to_string()]),
+                ..Default::default()
+            },
+            UpdateNetworkData {
+                slug: Some("-invalid-".to_string()),
+                ..Default::default()
+            },
+            UpdateNetworkData {
+                explorer: Some(ExplorerMetadata {
+                    api_url: Some("http://169.254.169.254/latest".to_string()),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            },
+        ];
+        for updates in invalid_updates {
+            assert!(
+                network
+                    .clone()
+                    .with_updates(updates.clone(), &UrlPolicy::default())
+                    .is_err(),
+                "{updates:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_network_tags_and_metadata() {
+        let mut data = create_test_network_data();
+        data.tags = vec!["Tier:Gold".to_string(), "l2".to_string(), "tier:gold".to_string()];
+        data.metadata = serde_json::json!({ "product": "payments", "owner": "team-a" })
+            .as_object()
+            .cloned()
+            .unwrap_or_default();
+        let network = Network::new(data, &UrlPolicy::default()).expect("valid data");
+
+        assert_eq!(network.tags().iter().collect::<Vec<_>>(), ["l2", "tier:gold"]);
+        assert!(network.has_tags(&["TIER:GOLD".to_string()]));
+        assert!(!network.has_tags(&["l2".to_string(), "team:payments".to_string()]));
+
+        let patch = serde_json::json!({ "owner": null, "region": "eu" })
+            .as_object()
+            .cloned()
+            .unwrap_or_default();
+        let updated = network
+            .with_updates(
+                UpdateNetworkData {
+                    tags: Some(vec!["team:payments".to_string()]),
+                    metadata: Some(MetadataUpdate::Merge(patch)),
+                    ..Default::default()
+                },
+                &UrlPolicy::default(),
+            )
+            .unwrap();
+        assert_eq!(updated.tags().iter().collect::<Vec<_>>(), ["team:payments"]);
+        assert_eq!(
+            serde_json::Value::Object(updated.metadata().clone()),
+            serde_json::json!({ "product": "payments", "region": "eu" })
+        );
+    }
+
+    #[test]
+    fn test_network_new_validates_tags() {
+        let mut data = create_test_network_data();
+        data.tags = vec!["not a tag".to_string()];
+        assert!(Network::new(data, &UrlPolicy::default()).is_err());
+    }
+
+    #[test]
+    fn test_network_slug_and_aliases() {
+        let network = Network::new(create_test_network_data(), &UrlPolicy::default()).expect("valid data");
+        assert_eq!(network.slug(), "ethereum-mainnet");
+        assert!(network.aliases().is_empty());
+
+        let mut data = create_test_network_data();
+        data.slug = Some("ETH-Mainnet".to_string());
+        data.aliases = vec!["Ethereum".to_string(), "eth-mainnet".to_string()];
+        let network = Network::new(data, &UrlPolicy::default()).expect("valid data");
+        assert_eq!(network.slug(), "eth-mainnet");
+        assert_eq!(network.aliases().iter().collect::<Vec<_>>(), ["ethereum"]);
+        assert!(network.answers_to("ETHEREUM"));
+        assert!(!network.answers_to("mainnet"));
+
+        // Renaming the slug to an alias drops the alias
+        let updated = network
+            .with_updates(
+                UpdateNetworkData {
+                    slug: Some("ethereum".to_string()),
+                    ..Default::default()
+                },
+                &UrlPolicy::default(),
+            )
+            .unwrap();
+        assert_eq!(updated.slug(), "ethereum");
+        assert!(updated.aliases().is_empty());
+
+        let mut data = create_test_network_data();
+        data.slug = Some("eth mainnet".to_string());
+        assert!(Network::new(data, &UrlPolicy::default()).is_err());
+    }
+
+    #[test]
+    fn test_network_new_rejects_non_initial_status() {
+        let mut data = create_test_network_data();
+        data.status = NetworkStatus::Deprecated;
+        assert!(Network::new(data, &UrlPolicy::default()).is_err());
+    }
+
+    #[test]
+    fn test_network_lifecycle_transitions() {
+        let mut data = create_test_network_data();
+        data.status = NetworkStatus::Draft;
+        let network = Network::new(data, &UrlPolicy::default()).expect("valid data");
+        assert!(!network.active());
+
+        let active = network
+            .transition_to(NetworkStatus::Active, None)
+            .expect("draft can be activated");
+        assert!(active.active());
+
+        let sunset_at = Utc::now() + chrono::Duration::days(30);
+        let deprecated = active
+            .transition_to(NetworkStatus::Deprecated, Some(sunset_at))
+            .expect("active can be deprecated");
+        assert!(deprecated.active());
+        assert!(deprecated.deprecated_at().is_some());
+        assert_eq!(deprecated.sunset_at(), Some(sunset_at));
+
+        let retired = deprecated
+            .transition_to(NetworkStatus::Retired, None)
+            .expect("deprecated can be retired");
+        assert!(!retired.active());
+
+        let result = retired.clone().transition_to(NetworkStatus::Deprecated, None);
+        assert!(matches!(result, Err(DomainError::InvalidState(_))));
+
+        let restored = retired
+            .transition_to(NetworkStatus::Active, None)
+            .expect("retired can be restored");
+        assert!(restored.active());
+        assert!(restored.deprecated_at().is_none());
+        assert!(restored.sunset_at().is_none());
+    }
+
+    #[test]
+    fn test_network_transition_rejects_past_sunset() {
+        let network = Network::new(create_test_network_data(), &UrlPolicy::default()).expect("valid data");
+        let result = network.transition_to(NetworkStatus::Deprecated, Some(Utc::now() - chrono::Duration::days(1)));
+        assert!(matches!(result, Err(DomainError::ValidationError(_))));
+    }
+
+    #[test]
+    fn test_network_apply_active_flag() {
+        let network = Network::new(create_test_network_data(), &UrlPolicy::default()).expect("valid data");
+
+        let unchanged = network.apply_active_flag(true).expect("no-op");
+        assert_eq!(unchanged.status(), NetworkStatus::Active);
+
+        let retired = unchanged.apply_active_flag(false).expect("active can be retired");
+        assert_eq!(retired.status(), NetworkStatus::Retired);
 
-        let deactivated = network.deactivate();
-        assert!(!deactivated.active());
+        let restored = retired.apply_active_flag(true).expect("retired can be restored");
+        assert_eq!(restored.status(), NetworkStatus::Active);
     }
 }
@@ -0,0 +1,293 @@
+//! Outbox Model
+//!
+//! Network change events recorded in the same transaction as the change
+//! itself, then relayed to the configured sinks. Each message carries a
+//! snapshot of the network right after the change, so sinks see every
+//! intermediate state even when later changes follow before relaying.
+//!
+//! Relaying is at-least-once: a message is retried until every sink accepts
+//! it, so a sink may see it more than once. Each message carries a stable ID
+//! for sinks and their consumers to deduplicate on.
+
+use std::fmt;
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::domain::models::event::{NetworkEvent, NetworkEventKind};
+use crate::domain::models::network::{Network, NetworkId};
+
+/// Maximum length of the last relay error kept on a message
+pub const MAX_OUTBOX_ERROR_LENGTH: usize = 1024;
+
+/// Unique identifier for an outbox message, used for deduplication
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct OutboxMessageId(Uuid);
+
+impl OutboxMessageId {
+    /// Create a new random OutboxMessageId
+    #[must_use]
+    pub fn new() -> Self {
+        Self(Uuid::new_v4())
+    }
+
+    /// Create an OutboxMessageId from an existing UUID
+    #[must_use]
+    pub fn from_uuid(uuid: Uuid) -> Self {
+        Self(uuid)
+    }
+
+    /// Get the underlying UUID
+    #[must_use]
+    pub fn as_uuid(&self) -> &Uuid {
+        &self.0
+    }
+}
+
+impl Default for OutboxMessageId {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Display for OutboxMessageId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// How messages that could not be relayed are retried
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OutboxRetryPolicy {
+    /// Delay before the first retry, doubled for each further one
+    pub base_delay: Duration,
+    /// Upper bound for the delay between attempts
+    pub max_delay: Duration,
+}
+
+impl OutboxRetryPolicy {
+    /// Delay before the attempt following the given number of failed attempts
+    #[must_use]
+    pub fn delay_after(&self, attempts: u32) -> Duration {
+        let exponent = attempts.saturating_sub(1).min(31);
+        self.base_delay
+            .checked_mul(1 << exponent)
+            .map_or(self.max_delay, |delay| delay.min(self.max_delay))
+    }
+}
+
+/// A network change waiting to be relayed
+#[derive(Debug, Clone)]
+pub struct OutboxMessage {
+    id: OutboxMessageId,
+    /// The network right after the change
+    network: Network,
+    kind: NetworkEventKind,
+    occurred_at: DateTime<Utc>,
+    /// Failed relay attempts so far
+    attempts: u32,
+    next_attempt_at: DateTime<Utc>,
+    last_error: Option<String>,
+    published_at: Option<DateTime<Utc>>,
+}
+
+impl OutboxMessage {
+    /// Record a change to a network, due for relaying immediately
+    #[must_use]
+    pub fn new(kind: NetworkEventKind, network: Network) -> Self {
+        let now = Utc::now();
+        Self {
+            id: OutboxMessageId::new(),
+            network,
+            kind,
+            occurred_at: now,
+            attempts: 0,
+            next_attempt_at: now,
+            last_error: None,
+            published_at: None,
+        }
+    }
+
+    /// Restore a message from persistence (no validation)
+    #[must_use]
+    #[allow(clippy::too_many_arguments)]
+    pub fn restore(
+        id: OutboxMessageId,
+        network: Network,
+        kind: NetworkEventKind,
+        occurred_at: DateTime<Utc>,
+        attempts: u32,
+        next_attempt_at: DateTime<Utc>,
+        last_error: Option<String>,
+        published_at: Option<DateTime<Utc>>,
+    ) -> Self {
+        Self {
+            id,
+            network,
+            kind,
+            occurred_at,
+            attempts,
+            next_attempt_at,
+            last_error,
+            published_at,
+        }
+    }
+
+    /// Mark the message as accepted by every sink
+    #[must_use]
+    pub fn publish(mut self) -> Self {
+        self.published_at = Some(Utc::now());
+        self.last_error = None;
+        self
+    }
+
+    /// Record a failed relay attempt and schedule the next one
+    #[must_use]
+    pub fn record_failure(mut self, error: &str, policy: &OutboxRetryPolicy) -> Self {
+        let now = Utc::now();
+        self.attempts = self.attempts.saturating_add(1);
+        self.next_attempt_at = now + policy.delay_after(self.attempts);
+        self.last_error = Some(error.chars().take(MAX_OUTBOX_ERROR_LENGTH).collect());
+        self
+    }
+
+    /// Build the event handed to the sinks, with the network as of the change
+    #[must_use]
+    pub fn to_event(&self) -> OutboxEvent {
+        OutboxEvent {
+            id: self.id.clone(),
+            event: NetworkEvent {
+                kind: self.kind,
+                network: self.network.clone(),
+                occurred_at: self.occurred_at,
+            },
+        }
+    }
+
+    #[must_use]
+    pub fn id(&self) -> &OutboxMessageId {
+        &self.id
+    }
+
+    #[must_use]
+    pub fn network_id(&self) -> &NetworkId {
+        self.network.id()
+    }
+
+    #[must_use]
+    pub fn network(&self) -> &Network {
+        &self.network
+    }
+
+    #[must_use]
+    pub fn kind(&self) -> NetworkEventKind {
+        self.kind
+    }
+
+    #[must_use]
+    pub fn occurred_at(&self) -> DateTime<Utc> {
+        self.occurred_at
+    }
+
+    #[must_use]
+    pub fn attempts(&self) -> u32 {
+        self.attempts
+    }
+
+    #[must_use]
+    pub fn next_attempt_at(&self) -> DateTime<Utc> {
+        self.next_attempt_at
+    }
+
+    #[must_use]
+    pub fn last_error(&self) -> Option<&str> {
+        self.last_error.as_deref()
+    }
+
+    #[must_use]
+    pub fn published_at(&self) -> Option<DateTime<Utc>> {
+        self.published_at
+    }
+}
+
+/// An event relayed from the outbox, identified for deduplication
+#[derive(Debug, Clone)]
+pub struct OutboxEvent {
+    /// Stable across redeliveries of the same change
+    pub id: OutboxMessageId,
+    pub event: NetworkEvent,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::models::explorer::ExplorerMetadata;
+    use crate::domain::models::fee_config::FeeConfig;
+    use crate::domain::models::finality::FinalityPolicy;
+    use crate::domain::models::labels::NetworkMetadata;
+    use crate::domain::models::lifecycle::NetworkStatus;
+    use crate::domain::models::network::CreateNetworkData;
+    use crate::domain::models::testnet::TestnetInfo;
+    use crate::domain::models::url_policy::UrlPolicy;
+    use rust_decimal_macros::dec;
+
+    fn create_test_network() -> Network {
+        let data = CreateNetworkData {
+            chain_id: 1,
+            name: "Ethereum Mainnet".to_string(),
+            rpc_url: "https://rpc.example.com".to_string(),
+            other_rpc_urls: vec![],
+            test_net: false,
+            block_explorer_url: "https://explorer.example.com".to_string(),
+            fee_multiplier: dec!(1.0),
+            gas_limit_multiplier: dec!(1.2),
+            default_signer_address: "0x742d35Cc6634C0532925a3b844Bc9e7595f1dEaD".to_string(),
+            explorer: ExplorerMetadata::default(),
+            fee_config: FeeConfig::default(),
+            finality: FinalityPolicy::default(),
+            status: NetworkStatus::Active,
+            parent: None,
+            tags: vec![],
+            metadata: NetworkMetadata::new(),
+            slug: None,
+            aliases: vec![],
+            testnet: TestnetInfo::default(),
+        };
+        Network::new(data, &UrlPolicy::default()).expect("valid test data")
+    }
+
+    fn policy() -> OutboxRetryPolicy {
+        OutboxRetryPolicy {
+            base_delay: Duration::from_secs(5),
+            max_delay: Duration::from_secs(30),
+        }
+    }
+
+    #[test]
+    fn test_retry_delay_doubles_up_to_max() {
+        let policy = policy();
+        assert_eq!(policy.delay_after(1), Duration::from_secs(5));
+        assert_eq!(policy.delay_after(2), Duration::from_secs(10));
+        assert_eq!(policy.delay_after(3), Duration::from_secs(20));
+        assert_eq!(policy.delay_after(4), Duration::from_secs(30));
+        assert_eq!(policy.delay_after(u32::MAX), Duration::from_secs(30));
+    }
+
+    #[test]
+    fn test_record_failure_then_publish() {
+        let message = OutboxMessage::new(NetworkEventKind::Created, create_test_network());
+        let id = message.id().clone();
+
+        let failed = message.record_failure(&"x".repeat(MAX_OUTBOX_ERROR_LENGTH + 10), &policy());
+        assert_eq!(failed.attempts(), 1);
+        assert!(failed.next_attempt_at() > failed.occurred_at());
+        assert_eq!(failed.last_error().unwrap().len(), MAX_OUTBOX_ERROR_LENGTH);
+        assert!(failed.published_at().is_none());
+
+        let published = failed.publish();
+        assert_eq!(published.id(), &id);
+        assert!(published.published_at().is_some());
+        assert!(published.last_error().is_none());
+    }
+}
@@ -0,0 +1,139 @@
+//! Network Relation Model
+//!
+//! Parent-chain relationships between networks: a rollup settling on its
+//! parent, a sidechain bridged to it, or a testnet standing in for it.
+//! Relationships form a forest; cycles are rejected.
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::domain::models::network::NetworkId;
+use crate::shared::errors::DomainError;
+
+/// Maximum number of ancestors above a network
+pub const MAX_NETWORK_DEPTH: usize = 8;
+
+/// How a network relates to its parent
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NetworkRelationKind {
+    /// Settles on the parent (Arbitrum One on Ethereum)
+    Rollup,
+    /// Independent consensus bridged to the parent (Gnosis Chain, Ronin)
+    Sidechain,
+    /// Test counterpart of the parent (Sepolia of Ethereum)
+    TestnetOf,
+}
+
+impl NetworkRelationKind {
+    /// Get the persisted representation
+    #[must_use]
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Rollup => "rollup",
+            Self::Sidechain => "sidechain",
+            Self::TestnetOf => "testnet-of",
+        }
+    }
+}
+
+impl fmt::Display for NetworkRelationKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for NetworkRelationKind {
+    type Err = DomainError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "rollup" => Ok(Self::Rollup),
+            "sidechain" => Ok(Self::Sidechain),
+            "testnet-of" => Ok(Self::TestnetOf),
+            _ => Err(DomainError::ValidationError(format!(
+                "unknown network relation '{value}'"
+            ))),
+        }
+    }
+}
+
+/// The parent of a network and the kind of relation to it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NetworkParent {
+    pub network_id: NetworkId,
+    pub kind: NetworkRelationKind,
+}
+
+/// Check that a network can be attached below a lineage
+///
+/// `lineage` lists the prospective parent first, then its ancestors up to the root.
+/// `subtree_height` is the number of levels of descendants below the network
+/// (0 for a leaf), which move along with it.
+///
+/// # Errors
+///
+/// Returns `DomainError::InvalidState` if the network appears in the lineage
+/// (the relation would create a cycle) or if the network or one of its
+/// descendants would have more than [`MAX_NETWORK_DEPTH`] ancestors.
+pub fn check_lineage(network_id: &NetworkId, lineage: &[NetworkId], subtree_height: usize) -> Result<(), DomainError> {
+    if lineage.contains(network_id) {
+        return Err(DomainError::InvalidState(
+            "parent network would create a cycle".to_string(),
+        ));
+    }
+
+    if lineage.len() + subtree_height > MAX_NETWORK_DEPTH {
+        return Err(DomainError::InvalidState(format!(
+            "a network can have at most {MAX_NETWORK_DEPTH} ancestors"
+        )));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_relation_roundtrip() {
+        for kind in [
+            NetworkRelationKind::Rollup,
+            NetworkRelationKind::Sidechain,
+            NetworkRelationKind::TestnetOf,
+        ] {
+            assert_eq!(kind.as_str().parse::<NetworkRelationKind>().unwrap(), kind);
+        }
+        assert!("l2".parse::<NetworkRelationKind>().is_err());
+    }
+
+    #[test]
+    fn test_check_lineage_rejects_cycles() {
+        let mainnet = NetworkId::new();
+        let rollup = NetworkId::new();
+        let l3 = NetworkId::new();
+
+        // Attaching the L3 below the rollup is fine
+        assert!(check_lineage(&l3, &[rollup.clone(), mainnet.clone()], 0).is_ok());
+
+        // Attaching mainnet below the L3 closes a loop
+        assert!(matches!(
+            check_lineage(&mainnet, &[l3.clone(), rollup, mainnet.clone()], 0),
+            Err(DomainError::InvalidState(_))
+        ));
+
+        // A network cannot be its own parent
+        assert!(check_lineage(&l3, &[l3.clone()], 0).is_err());
+    }
+
+    #[test]
+    fn test_check_lineage_limits_depth() {
+        let lineage: Vec<NetworkId> = (0..=MAX_NETWORK_DEPTH).map(|_| NetworkId::new()).collect();
+        assert!(check_lineage(&NetworkId::new(), &lineage[..MAX_NETWORK_DEPTH], 0).is_ok());
+        assert!(check_lineage(&NetworkId::new(), &lineage, 0).is_err());
+
+        // The descendants moving along count towards the depth
+        assert!(check_lineage(&NetworkId::new(), &lineage[..MAX_NETWORK_DEPTH - 2], 2).is_ok());
+        assert!(check_lineage(&NetworkId::new(), &lineage[..MAX_NETWORK_DEPTH - 2], 3).is_err());
+    }
+}
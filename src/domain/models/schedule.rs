@@ -0,0 +1,460 @@
+//! Lifecycle Schedule Model
+//!
+//! A lifecycle transition (activation, deprecation or retirement) planned for a
+//! future time.
+//!
+//! Schedules are never deleted: once applied, failed or cancelled they remain as
+//! an audit record of who planned which change and how it turned out.
+
+use std::fmt;
+use std::str::FromStr;
+
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::domain::models::lifecycle::NetworkStatus;
+use crate::domain::models::network::NetworkId;
+use crate::shared::errors::DomainError;
+
+/// Maximum length of the recorded actor identifiers
+pub const MAX_ACTOR_LENGTH: usize = 255;
+
+/// Unique identifier for a lifecycle schedule
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ScheduleId(Uuid);
+
+impl ScheduleId {
+    /// Create a new random ScheduleId
+    #[must_use]
+    pub fn new() -> Self {
+        Self(Uuid::new_v4())
+    }
+
+    /// Create a ScheduleId from an existing UUID
+    #[must_use]
+    pub fn from_uuid(uuid: Uuid) -> Self {
+        Self(uuid)
+    }
+
+    /// Get the underlying UUID
+    #[must_use]
+    pub fn as_uuid(&self) -> &Uuid {
+        &self.0
+    }
+}
+
+impl Default for ScheduleId {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Display for ScheduleId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// Lifecycle transition to apply
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScheduledAction {
+    /// Make the network usable (same as `POST /networks/:id/activate`)
+    Activate,
+    /// Deprecate the network, which stays usable (same as `POST /networks/:id/deprecate`)
+    Deprecate,
+    /// Take the network out of service for good (same as `POST /networks/:id/retire`)
+    Retire,
+}
+
+impl ScheduledAction {
+    /// Get the persisted representation
+    #[must_use]
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Activate => "activate",
+            Self::Deprecate => "deprecate",
+            Self::Retire => "retire",
+        }
+    }
+
+    /// Lifecycle state this action moves the network to
+    #[must_use]
+    pub fn target_status(&self) -> NetworkStatus {
+        match self {
+            Self::Activate => NetworkStatus::Active,
+            Self::Deprecate => NetworkStatus::Deprecated,
+            Self::Retire => NetworkStatus::Retired,
+        }
+    }
+}
+
+impl fmt::Display for ScheduledAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for ScheduledAction {
+    type Err = DomainError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "activate" => Ok(Self::Activate),
+            "deprecate" => Ok(Self::Deprecate),
+            "retire" => Ok(Self::Retire),
+            _ => Err(DomainError::ValidationError(format!(
+                "unknown scheduled action '{value}'"
+            ))),
+        }
+    }
+}
+
+/// State of a lifecycle schedule
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScheduleStatus {
+    /// Waiting for its run time
+    Pending,
+    /// Change applied to the network
+    Applied,
+    /// Change could not be applied; see the outcome
+    Failed,
+    /// Withdrawn before it ran
+    Cancelled,
+}
+
+impl ScheduleStatus {
+    /// Get the persisted representation
+    #[must_use]
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Pending => "pending",
+            Self::Applied => "applied",
+            Self::Failed => "failed",
+            Self::Cancelled => "cancelled",
+        }
+    }
+}
+
+impl fmt::Display for ScheduleStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for ScheduleStatus {
+    type Err = DomainError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "pending" => Ok(Self::Pending),
+            "applied" => Ok(Self::Applied),
+            "failed" => Ok(Self::Failed),
+            "cancelled" => Ok(Self::Cancelled),
+            _ => Err(DomainError::ValidationError(format!(
+                "unknown schedule status '{value}'"
+            ))),
+        }
+    }
+}
+
+/// A planned lifecycle change of a network
+#[derive(Debug, Clone)]
+pub struct LifecycleSchedule {
+    id: ScheduleId,
+    network_id: NetworkId,
+    action: ScheduledAction,
+    run_at: DateTime<Utc>,
+    status: ScheduleStatus,
+    scheduled_by: String,
+    cancelled_by: Option<String>,
+    outcome: Option<String>,
+    completed_at: Option<DateTime<Utc>>,
+    created_at: DateTime<Utc>,
+}
+
+impl LifecycleSchedule {
+    /// Plan a lifecycle change
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DomainError::ValidationError` if `run_at` is not in the future
+    /// or the actor is missing or too long
+    pub fn new(
+        network_id: NetworkId,
+        action: ScheduledAction,
+        run_at: DateTime<Utc>,
+        scheduled_by: String,
+    ) -> Result<Self, DomainError> {
+        let now = Utc::now();
+        if run_at <= now {
+            return Err(DomainError::ValidationError("run_at must be in the future".to_string()));
+        }
+        validate_actor("scheduled_by", &scheduled_by)?;
+
+        Ok(Self {
+            id: ScheduleId::new(),
+            network_id,
+            action,
+            run_at,
+            status: ScheduleStatus::Pending,
+            scheduled_by,
+            cancelled_by: None,
+            outcome: None,
+            completed_at: None,
+            created_at: now,
+        })
+    }
+
+    /// Restore a schedule from persistence (no validation)
+    #[must_use]
+    #[allow(clippy::too_many_arguments)]
+    pub fn restore(
+        id: ScheduleId,
+        network_id: NetworkId,
+        action: ScheduledAction,
+        run_at: DateTime<Utc>,
+        status: ScheduleStatus,
+        scheduled_by: String,
+        cancelled_by: Option<String>,
+        outcome: Option<String>,
+        completed_at: Option<DateTime<Utc>>,
+        created_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id,
+            network_id,
+            action,
+            run_at,
+            status,
+            scheduled_by,
+            cancelled_by,
+            outcome,
+            completed_at,
+            created_at,
+        }
+    }
+
+    /// Withdraw a pending schedule
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DomainError::InvalidState` if the schedule already ran or was cancelled,
+    /// or a `DomainError::ValidationError` if the actor is missing or too long
+    pub fn cancel(self, cancelled_by: String) -> Result<Self, DomainError> {
+        self.ensure_pending()?;
+        validate_actor("cancelled_by", &cancelled_by)?;
+
+        Ok(Self {
+            status: ScheduleStatus::Cancelled,
+            cancelled_by: Some(cancelled_by),
+            completed_at: Some(Utc::now()),
+            ..self
+        })
+    }
+
+    /// Record that the change was applied
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DomainError::InvalidState` if the schedule is not pending
+    pub fn mark_applied(self) -> Result<Self, DomainError> {
+        self.ensure_pending()?;
+
+        Ok(Self {
+            status: ScheduleStatus::Applied,
+            completed_at: Some(Utc::now()),
+            ..self
+        })
+    }
+
+    /// Record that the change could not be applied
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DomainError::InvalidState` if the schedule is not pending
+    pub fn mark_failed(self, reason: String) -> Result<Self, DomainError> {
+        self.ensure_pending()?;
+
+        Ok(Self {
+            status: ScheduleStatus::Failed,
+            outcome: Some(reason),
+            completed_at: Some(Utc::now()),
+            ..self
+        })
+    }
+
+    /// Check whether the schedule is pending and its run time has passed
+    #[must_use]
+    pub fn is_due(&self, now: DateTime<Utc>) -> bool {
+        self.status == ScheduleStatus::Pending && self.run_at <= now
+    }
+
+    fn ensure_pending(&self) -> Result<(), DomainError> {
+        if self.status == ScheduleStatus::Pending {
+            Ok(())
+        } else {
+            Err(DomainError::InvalidState(format!(
+                "schedule is already {}",
+                self.status
+            )))
+        }
+    }
+
+    // Getters
+
+    #[must_use]
+    pub fn id(&self) -> &ScheduleId {
+        &self.id
+    }
+
+    #[must_use]
+    pub fn network_id(&self) -> &NetworkId {
+        &self.network_id
+    }
+
+    #[must_use]
+    pub fn action(&self) -> ScheduledAction {
+        self.action
+    }
+
+    #[must_use]
+    pub fn run_at(&self) -> DateTime<Utc> {
+        self.run_at
+    }
+
+    #[must_use]
+    pub fn status(&self) -> ScheduleStatus {
+        self.status
+    }
+
+    #[must_use]
+    pub fn scheduled_by(&self) -> &str {
+        &self.scheduled_by
+    }
+
+    #[must_use]
+    pub fn cancelled_by(&self) -> Option<&str> {
+        self.cancelled_by.as_deref()
+    }
+
+    /// Failure reason, if the change could not be applied
+    #[must_use]
+    pub fn outcome(&self) -> Option<&str> {
+        self.outcome.as_deref()
+    }
+
+    #[must_use]
+    pub fn completed_at(&self) -> Option<DateTime<Utc>> {
+        self.completed_at
+    }
+
+    #[must_use]
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+}
+
+/// Validate a recorded actor identifier
+fn validate_actor(field: &str, actor: &str) -> Result<(), DomainError> {
+    if actor.trim().is_empty() || actor.len() > MAX_ACTOR_LENGTH {
+        return Err(DomainError::ValidationError(format!(
+            "{field} must be between 1 and {MAX_ACTOR_LENGTH} characters"
+        )));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    fn pending_schedule() -> LifecycleSchedule {
+        LifecycleSchedule::new(
+            NetworkId::new(),
+            ScheduledAction::Activate,
+            Utc::now() + Duration::hours(1),
+            "user-1".to_string(),
+        )
+        .expect("valid schedule")
+    }
+
+    #[test]
+    fn test_new_schedule_is_pending() {
+        let schedule = pending_schedule();
+        assert_eq!(schedule.status(), ScheduleStatus::Pending);
+        assert_eq!(schedule.scheduled_by(), "user-1");
+        assert!(!schedule.is_due(Utc::now()));
+        assert!(schedule.is_due(Utc::now() + Duration::hours(2)));
+    }
+
+    #[test]
+    fn test_rejects_past_run_at_and_missing_actor() {
+        let past = LifecycleSchedule::new(
+            NetworkId::new(),
+            ScheduledAction::Retire,
+            Utc::now() - Duration::minutes(1),
+            "user-1".to_string(),
+        );
+        assert!(matches!(past, Err(DomainError::ValidationError(_))));
+
+        let anonymous = LifecycleSchedule::new(
+            NetworkId::new(),
+            ScheduledAction::Retire,
+            Utc::now() + Duration::minutes(1),
+            " ".to_string(),
+        );
+        assert!(matches!(anonymous, Err(DomainError::ValidationError(_))));
+    }
+
+    #[test]
+    fn test_cancel_records_actor() {
+        let cancelled = pending_schedule().cancel("user-2".to_string()).unwrap();
+        assert_eq!(cancelled.status(), ScheduleStatus::Cancelled);
+        assert_eq!(cancelled.cancelled_by(), Some("user-2"));
+        assert!(cancelled.completed_at().is_some());
+    }
+
+    #[test]
+    fn test_only_pending_schedules_complete() {
+        let applied = pending_schedule().mark_applied().unwrap();
+        assert!(matches!(
+            applied.clone().cancel("user-2".to_string()),
+            Err(DomainError::InvalidState(_))
+        ));
+        assert!(applied.mark_failed("too late".to_string()).is_err());
+
+        let failed = pending_schedule()
+            .mark_failed("network is retired".to_string())
+            .unwrap();
+        assert_eq!(failed.outcome(), Some("network is retired"));
+    }
+
+    #[test]
+    fn test_action_target_status() {
+        assert_eq!(ScheduledAction::Activate.target_status(), NetworkStatus::Active);
+        assert_eq!(ScheduledAction::Deprecate.target_status(), NetworkStatus::Deprecated);
+        assert_eq!(ScheduledAction::Retire.target_status(), NetworkStatus::Retired);
+    }
+
+    #[test]
+    fn test_action_and_status_roundtrip() {
+        for action in [
+            ScheduledAction::Activate,
+            ScheduledAction::Deprecate,
+            ScheduledAction::Retire,
+        ] {
+            assert_eq!(action.as_str().parse::<ScheduledAction>().unwrap(), action);
+        }
+        assert!("deactivate".parse::<ScheduledAction>().is_err());
+        for status in [
+            ScheduleStatus::Pending,
+            ScheduleStatus::Applied,
+            ScheduleStatus::Failed,
+            ScheduleStatus::Cancelled,
+        ] {
+            assert_eq!(status.as_str().parse::<ScheduleStatus>().unwrap(), status);
+        }
+    }
+}
@@ -0,0 +1,289 @@
+//! Network Search Model
+//!
+//! Free-text lookup of networks by name, slug, aliases and tags, as typed
+//! into an admin UI ("polygon amoy", "arb"). The Postgres adapter combines
+//! full-text search with trigram similarity; [`rank`] mirrors that ranking in process for
+//! adapters without a search engine.
+
+use std::collections::HashSet;
+
+use crate::domain::models::network::Network;
+use crate::shared::errors::DomainError;
+
+/// Maximum length for a search query
+pub const MAX_QUERY_LENGTH: usize = 100;
+/// Default number of results
+pub const DEFAULT_SEARCH_LIMIT: usize = 20;
+/// Maximum number of results
+pub const MAX_SEARCH_LIMIT: usize = 50;
+/// Minimum trigram similarity for a fuzzy match, as `pg_trgm.word_similarity_threshold`
+pub const SIMILARITY_THRESHOLD: f64 = 0.3;
+
+/// A validated search query
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SearchQuery {
+    text: String,
+    terms: Vec<String>,
+    limit: usize,
+}
+
+impl SearchQuery {
+    /// Parse a query typed by a user
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DomainError::ValidationError` if the query has no searchable
+    /// term, is too long or the limit is out of range
+    pub fn new(text: &str, limit: Option<usize>) -> Result<Self, DomainError> {
+        let text = text.trim().to_lowercase();
+        if text.len() > MAX_QUERY_LENGTH {
+            return Err(DomainError::ValidationError(format!(
+                "q must be at most {MAX_QUERY_LENGTH} characters"
+            )));
+        }
+
+        let terms = terms(&text);
+        if terms.is_empty() {
+            return Err(DomainError::ValidationError(
+                "q must contain at least one letter or digit".to_string(),
+            ));
+        }
+
+        let limit = limit.unwrap_or(DEFAULT_SEARCH_LIMIT);
+        if !(1..=MAX_SEARCH_LIMIT).contains(&limit) {
+            return Err(DomainError::ValidationError(format!(
+                "limit must be between 1 and {MAX_SEARCH_LIMIT}"
+            )));
+        }
+
+        Ok(Self { text, terms, limit })
+    }
+
+    /// The normalized query text
+    #[must_use]
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Lowercased alphanumeric terms of the query
+    #[must_use]
+    pub fn terms(&self) -> &[String] {
+        &self.terms
+    }
+
+    #[must_use]
+    pub fn limit(&self) -> usize {
+        self.limit
+    }
+
+    /// Full-text query matching every term as a prefix (`polygon:* & amoy:*`)
+    #[must_use]
+    pub fn prefix_tsquery(&self) -> String {
+        self.terms
+            .iter()
+            .map(|term| format!("{term}:*"))
+            .collect::<Vec<_>>()
+            .join(" & ")
+    }
+
+    /// Score a network against the query, `None` when it does not match
+    ///
+    /// A network matches when every term prefixes one of its words, or when
+    /// the query is similar enough to one of them. Prefix matches outrank
+    /// fuzzy ones; similarity breaks ties.
+    #[must_use]
+    pub fn score(&self, network: &Network) -> Option<f64> {
+        let words = searchable_words(network);
+
+        let full_text = self
+            .terms
+            .iter()
+            .all(|term| words.iter().any(|word| word.starts_with(term.as_str())));
+        let similarity = words
+            .iter()
+            .map(|word| similarity(&self.text, word))
+            .fold(0.0, f64::max);
+
+        if full_text {
+            Some(1.0 + similarity)
+        } else if similarity >= SIMILARITY_THRESHOLD {
+            Some(similarity)
+        } else {
+            None
+        }
+    }
+}
+
+/// Rank networks against a query, best match first, ties broken by name
+#[must_use]
+pub fn rank(networks: impl IntoIterator<Item = Network>, query: &SearchQuery) -> Vec<Network> {
+    let mut scored: Vec<(f64, Network)> = networks
+        .into_iter()
+        .filter_map(|network| query.score(&network).map(|score| (score, network)))
+        .collect();
+
+    scored.sort_by(|(a_score, a), (b_score, b)| b_score.total_cmp(a_score).then_with(|| a.name().cmp(b.name())));
+    scored
+        .into_iter()
+        .take(query.limit)
+        .map(|(_, network)| network)
+        .collect()
+}
+
+/// Split text into lowercased alphanumeric terms
+fn terms(text: &str) -> Vec<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|term| !term.is_empty())
+        .map(str::to_lowercase)
+        .collect()
+}
+
+/// Words a network can be found by: its name, slug, aliases and tags
+fn searchable_words(network: &Network) -> Vec<String> {
+    [network.name(), network.slug()]
+        .into_iter()
+        .chain(network.aliases().iter().map(String::as_str))
+        .chain(network.tags().iter().map(String::as_str))
+        .flat_map(terms)
+        .collect()
+}
+
+/// Trigram similarity of two strings, as computed by `pg_trgm`
+fn similarity(a: &str, b: &str) -> f64 {
+    let a = trigrams(a);
+    let b = trigrams(b);
+    if a.is_empty() || b.is_empty() {
+        return 0.0;
+    }
+
+    let shared = a.intersection(&b).count();
+    #[allow(clippy::cast_precision_loss)]
+    let ratio = shared as f64 / (a.len() + b.len() - shared) as f64;
+    ratio
+}
+
+/// Trigrams of each word, padded with two leading spaces and one trailing space
+fn trigrams(text: &str) -> HashSet<[char; 3]> {
+    terms(text)
+        .iter()
+        .flat_map(|word| {
+            let padded: Vec<char> = "  ".chars().chain(word.chars()).chain(" ".chars()).collect();
+            padded.windows(3).map(|w| [w[0], w[1], w[2]]).collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::models::explorer::ExplorerMetadata;
+    use crate::domain::models::fee_config::FeeConfig;
+    use crate::domain::models::finality::FinalityPolicy;
+    use crate::domain::models::labels::NetworkMetadata;
+    use crate::domain::models::lifecycle::NetworkStatus;
+    use crate::domain::models::network::{CreateNetworkData, UpdateNetworkData};
+    use crate::domain::models::testnet::TestnetInfo;
+    use crate::domain::models::url_policy::UrlPolicy;
+    use rust_decimal_macros::dec;
+
+    fn create_test_network(chain_id: i32, name: &str, tags: &[&str]) -> Network {
+        let data = CreateNetworkData {
+            chain_id,
+            name: name.to_string(),
+            rpc_url: "https://rpc.example.com".to_string(),
+            other_rpc_urls: vec![],
+            test_net: false,
+            block_explorer_url: "https://explorer.example.com".to_string(),
+            fee_multiplier: dec!(1.0),
+            gas_limit_multiplier: dec!(1.2),
+            default_signer_address: "0x742d35Cc6634C0532925a3b844Bc9e7595f1dEaD".to_string(),
+            explorer: ExplorerMetadata::default(),
+            fee_config: FeeConfig::default(),
+            finality: FinalityPolicy::default(),
+            status: NetworkStatus::Active,
+            parent: None,
+            tags: tags.iter().map(ToString::to_string).collect(),
+            metadata: NetworkMetadata::new(),
+            slug: None,
+            aliases: vec![],
+            testnet: TestnetInfo::default(),
+        };
+        Network::new(data, &UrlPolicy::default()).expect("valid test data")
+    }
+
+    fn networks() -> Vec<Network> {
+        vec![
+            create_test_network(1, "Ethereum Mainnet", &["l1"]),
+            create_test_network(42161, "Arbitrum One", &["l2", "team:payments"]),
+            create_test_network(137, "Polygon", &["l2"]),
+            create_test_network(80002, "Polygon Amoy", &["testnet"]),
+        ]
+    }
+
+    fn names(networks: &[Network]) -> Vec<&str> {
+        networks.iter().map(Network::name).collect()
+    }
+
+    #[test]
+    fn test_search_query_validation() {
+        assert!(SearchQuery::new("  ", None).is_err());
+        assert!(SearchQuery::new("--", None).is_err());
+        assert!(SearchQuery::new(&"x".repeat(MAX_QUERY_LENGTH + 1), None).is_err());
+        assert!(SearchQuery::new("arb", Some(0)).is_err());
+        assert!(SearchQuery::new("arb", Some(MAX_SEARCH_LIMIT + 1)).is_err());
+
+        let query = SearchQuery::new(" Polygon  AMOY ", None).unwrap();
+        assert_eq!(query.terms(), ["polygon", "amoy"]);
+        assert_eq!(query.prefix_tsquery(), "polygon:* & amoy:*");
+        assert_eq!(query.limit(), DEFAULT_SEARCH_LIMIT);
+    }
+
+    #[test]
+    fn test_rank_matches_prefixes() {
+        let query = SearchQuery::new("arb", None).unwrap();
+        assert_eq!(names(&rank(networks(), &query)), ["Arbitrum One"]);
+
+        let query = SearchQuery::new("polygon amoy", None).unwrap();
+        assert_eq!(names(&rank(networks(), &query))[0], "Polygon Amoy");
+    }
+
+    #[test]
+    fn test_rank_matches_tags() {
+        let query = SearchQuery::new("payments", None).unwrap();
+        assert_eq!(names(&rank(networks(), &query)), ["Arbitrum One"]);
+    }
+
+    #[test]
+    fn test_rank_matches_aliases() {
+        let mut networks = networks();
+        networks[2] = networks[2]
+            .clone()
+            .with_updates(
+                UpdateNetworkData {
+                    aliases: Some(vec!["matic".to_string()]),
+                    ..Default::default()
+                },
+                &UrlPolicy::default(),
+            )
+            .unwrap();
+
+        let query = SearchQuery::new("matic", None).unwrap();
+        assert_eq!(names(&rank(networks, &query)), ["Polygon"]);
+    }
+
+    #[test]
+    fn test_rank_tolerates_typos() {
+        let query = SearchQuery::new("polygn", None).unwrap();
+        let ranked = rank(networks(), &query);
+        assert_eq!(names(&ranked), ["Polygon", "Polygon Amoy"]);
+
+        let query = SearchQuery::new("solana", None).unwrap();
+        assert!(rank(networks(), &query).is_empty());
+    }
+
+    #[test]
+    fn test_rank_applies_limit() {
+        let query = SearchQuery::new("polygon", Some(1)).unwrap();
+        assert_eq!(rank(networks(), &query).len(), 1);
+    }
+}
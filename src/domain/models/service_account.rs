@@ -0,0 +1,96 @@
+//! Service Account Model
+//!
+//! Clients allowed to obtain JWTs with the client credentials grant. Service
+//! accounts are declared in configuration rather than stored: each one has a
+//! client ID, the SHA-256 hash of its secret and the role its tokens carry.
+//!
+//! A single unsalted SHA-256 is fast to brute-force, so it only protects
+//! high-entropy secrets: generate them randomly (e.g. 32 random bytes) and
+//! never reuse a human-chosen password as a client secret.
+
+use chrono::{DateTime, Utc};
+use sha2::{Digest, Sha256};
+use subtle::ConstantTimeEq;
+
+/// Hash a client secret, as configured for service accounts
+#[must_use]
+pub fn hash_secret(secret: &str) -> String {
+    hex::encode(Sha256::digest(secret.as_bytes()))
+}
+
+/// A client allowed to obtain tokens for a role
+#[derive(Debug, Clone)]
+pub struct ServiceAccount {
+    client_id: String,
+    secret_hash: String,
+    role: String,
+    email: String,
+}
+
+impl ServiceAccount {
+    /// Create a service account from its configuration
+    ///
+    /// Without an email, tokens carry the client ID instead.
+    #[must_use]
+    pub fn new(client_id: String, mut secret_hash: String, role: String, email: Option<String>) -> Self {
+        secret_hash.make_ascii_lowercase();
+        let email = email.unwrap_or_else(|| client_id.clone());
+        Self {
+            client_id,
+            secret_hash,
+            role,
+            email,
+        }
+    }
+
+    /// Check the hash of a client secret, from `hash_secret`, against the configured one in constant time
+    #[must_use]
+    pub fn verify_secret_hash(&self, secret_hash: &str) -> bool {
+        self.secret_hash.as_bytes().ct_eq(secret_hash.as_bytes()).into()
+    }
+
+    // Getters
+    #[must_use]
+    pub fn client_id(&self) -> &str {
+        &self.client_id
+    }
+
+    #[must_use]
+    pub fn role(&self) -> &str {
+        &self.role
+    }
+
+    #[must_use]
+    pub fn email(&self) -> &str {
+        &self.email
+    }
+}
+
+/// A signed JWT along with its ID and expiry
+#[derive(Debug, Clone)]
+pub struct IssuedToken {
+    pub token: String,
+    pub jti: String,
+    pub expires_at: DateTime<Utc>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_verify_secret() {
+        let account = ServiceAccount::new(
+            "indexer".to_string(),
+            hash_secret("s3cret").to_ascii_uppercase(),
+            "service".to_string(),
+            None,
+        );
+
+        assert!(account.verify_secret_hash(&hash_secret("s3cret")));
+        assert!(!account.verify_secret_hash(&hash_secret("s3cret ")));
+        assert!(!account.verify_secret_hash(&hash_secret("")));
+        assert!(!account.verify_secret_hash(""));
+        assert_eq!(account.email(), "indexer");
+    }
+}
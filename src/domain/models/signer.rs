@@ -0,0 +1,410 @@
+//! Network Signer Model
+//!
+//! Signer addresses of a network, labelled with the role they play
+//! (deployments, relaying, payouts, ...). The network's
+//! `default_signer_address` remains the signer of the default role.
+
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::domain::models::network::{is_ethereum_address, Network, NetworkId, ETHEREUM_ADDRESS_LENGTH};
+use crate::shared::errors::DomainError;
+
+/// Role served by the network's `default_signer_address`
+pub const DEFAULT_SIGNER_ROLE: &str = "default";
+/// Maximum length for a signer role
+pub const MAX_SIGNER_ROLE_LENGTH: usize = 50;
+/// Maximum signer priority
+pub const MAX_SIGNER_PRIORITY: i32 = 1000;
+
+/// Unique identifier for a network signer
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SignerId(Uuid);
+
+impl SignerId {
+    /// Create a new random SignerId
+    #[must_use]
+    pub fn new() -> Self {
+        Self(Uuid::new_v4())
+    }
+
+    /// Create a SignerId from an existing UUID
+    #[must_use]
+    pub fn from_uuid(uuid: Uuid) -> Self {
+        Self(uuid)
+    }
+
+    /// Get the underlying UUID
+    #[must_use]
+    pub fn as_uuid(&self) -> &Uuid {
+        &self.0
+    }
+}
+
+impl Default for SignerId {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl std::fmt::Display for SignerId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// Normalize a role label: trim and lowercase
+#[must_use]
+pub fn normalize_role(role: &str) -> String {
+    role.trim().to_lowercase()
+}
+
+/// Data required to register a signer, also used to replace one
+#[derive(Debug, Clone)]
+pub struct SignerData {
+    pub address: String,
+    pub role: String,
+    /// Lower values are preferred when several signers share a role
+    pub priority: i32,
+    pub enabled: bool,
+}
+
+impl SignerData {
+    /// Validate the signer data
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DomainError::ValidationError` if any field is invalid
+    pub fn validate(&self) -> Result<(), DomainError> {
+        if !is_ethereum_address(&self.address) {
+            return Err(DomainError::ValidationError(format!(
+                "address must be 0x followed by {} hex characters",
+                ETHEREUM_ADDRESS_LENGTH - 2
+            )));
+        }
+
+        let role = normalize_role(&self.role);
+        if role.is_empty()
+            || role.len() > MAX_SIGNER_ROLE_LENGTH
+            || !role.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+        {
+            return Err(DomainError::ValidationError(format!(
+                "role must be between 1 and {MAX_SIGNER_ROLE_LENGTH} letters, digits, underscores or hyphens"
+            )));
+        }
+
+        if !(0..=MAX_SIGNER_PRIORITY).contains(&self.priority) {
+            return Err(DomainError::ValidationError(format!(
+                "priority must be between 0 and {MAX_SIGNER_PRIORITY}"
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+/// A signer address of a network
+#[derive(Debug, Clone)]
+pub struct NetworkSigner {
+    id: SignerId,
+    network_id: NetworkId,
+    address: String,
+    role: String,
+    priority: i32,
+    enabled: bool,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl NetworkSigner {
+    /// Register a new signer on a network
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DomainError::ValidationError` if the data is invalid
+    pub fn new(network_id: NetworkId, data: SignerData) -> Result<Self, DomainError> {
+        data.validate()?;
+
+        let now = Utc::now();
+        Ok(Self {
+            id: SignerId::new(),
+            network_id,
+            address: data.address,
+            role: normalize_role(&data.role),
+            priority: data.priority,
+            enabled: data.enabled,
+            created_at: now,
+            updated_at: now,
+        })
+    }
+
+    /// Restore a signer from persistence (no validation)
+    #[must_use]
+    #[allow(clippy::too_many_arguments)]
+    pub fn restore(
+        id: SignerId,
+        network_id: NetworkId,
+        address: String,
+        role: String,
+        priority: i32,
+        enabled: bool,
+        created_at: DateTime<Utc>,
+        updated_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id,
+            network_id,
+            address,
+            role,
+            priority,
+            enabled,
+            created_at,
+            updated_at,
+        }
+    }
+
+    /// Replace the signer details, keeping its identity
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DomainError::ValidationError` if the data is invalid
+    pub fn replace(self, data: SignerData) -> Result<Self, DomainError> {
+        data.validate()?;
+
+        Ok(Self {
+            address: data.address,
+            role: normalize_role(&data.role),
+            priority: data.priority,
+            enabled: data.enabled,
+            updated_at: Utc::now(),
+            ..self
+        })
+    }
+
+    // Getters
+
+    #[must_use]
+    pub fn id(&self) -> &SignerId {
+        &self.id
+    }
+
+    #[must_use]
+    pub fn network_id(&self) -> &NetworkId {
+        &self.network_id
+    }
+
+    #[must_use]
+    pub fn address(&self) -> &str {
+        &self.address
+    }
+
+    #[must_use]
+    pub fn role(&self) -> &str {
+        &self.role
+    }
+
+    #[must_use]
+    pub fn priority(&self) -> i32 {
+        self.priority
+    }
+
+    #[must_use]
+    pub fn enabled(&self) -> bool {
+        self.enabled
+    }
+
+    #[must_use]
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    #[must_use]
+    pub fn updated_at(&self) -> DateTime<Utc> {
+        self.updated_at
+    }
+}
+
+/// The signer chosen for a role on a chain
+#[derive(Debug, Clone)]
+pub struct ResolvedSigner {
+    pub chain_id: i32,
+    pub network_id: NetworkId,
+    pub role: String,
+    pub address: String,
+    /// The registered signer, or `None` when falling back to the network's default signer address
+    pub signer: Option<NetworkSigner>,
+}
+
+impl ResolvedSigner {
+    /// Pick the signer for a role among the signers of a network
+    ///
+    /// The enabled signer with the lowest priority wins, the oldest one on ties.
+    /// The default role falls back to the network's `default_signer_address`.
+    #[must_use]
+    pub fn pick(network: &Network, role: &str, signers: Vec<NetworkSigner>) -> Option<Self> {
+        let role = normalize_role(role);
+        let signer = signers
+            .into_iter()
+            .filter(|signer| signer.enabled && signer.role == role && signer.network_id == *network.id())
+            .min_by_key(|signer| (signer.priority, signer.created_at));
+
+        let address = match &signer {
+            Some(signer) => signer.address.clone(),
+            None if role == DEFAULT_SIGNER_ROLE => network.default_signer_address().to_string(),
+            None => return None,
+        };
+
+        Some(Self {
+            chain_id: network.chain_id(),
+            network_id: network.id().clone(),
+            role,
+            address,
+            signer,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::models::explorer::ExplorerMetadata;
+    use crate::domain::models::fee_config::FeeConfig;
+    use crate::domain::models::finality::FinalityPolicy;
+    use crate::domain::models::labels::NetworkMetadata;
+    use crate::domain::models::lifecycle::NetworkStatus;
+    use crate::domain::models::network::CreateNetworkData;
+    use crate::domain::models::testnet::TestnetInfo;
+    use crate::domain::models::url_policy::UrlPolicy;
+    use rust_decimal_macros::dec;
+
+    const DEFAULT_SIGNER: &str = "0x742d35Cc6634C0532925a3b844Bc9e7595f1dEaD";
+
+    fn create_test_network() -> Network {
+        let data = CreateNetworkData {
+            chain_id: 1,
+            name: "Ethereum Mainnet".to_string(),
+            rpc_url: "https://mainnet.infura.io".to_string(),
+            other_rpc_urls: vec![],
+            test_net: false,
+            block_explorer_url: "https://etherscan.io".to_string(),
+            fee_multiplier: dec!(1.0),
+            gas_limit_multiplier: dec!(1.2),
+            default_signer_address: DEFAULT_SIGNER.to_string(),
+            explorer: ExplorerMetadata::default(),
+            fee_config: FeeConfig::default(),
+            finality: FinalityPolicy::default(),
+            status: NetworkStatus::Active,
+            parent: None,
+            tags: vec![],
+            metadata: NetworkMetadata::new(),
+            slug: None,
+            aliases: vec![],
+            testnet: TestnetInfo::default(),
+        };
+        Network::new(data, &UrlPolicy::default()).expect("valid test data")
+    }
+
+    fn signer(network: &Network, role: &str, address: &str, priority: i32, enabled: bool) -> NetworkSigner {
+        NetworkSigner::new(
+            network.id().clone(),
+            SignerData {
+                address: address.to_string(),
+                role: role.to_string(),
+                priority,
+                enabled,
+            },
+        )
+        .expect("valid signer")
+    }
+
+    #[test]
+    fn test_new_signer_normalizes_role() {
+        let network = create_test_network();
+        let signer = signer(&network, " Relayer ", DEFAULT_SIGNER, 0, true);
+        assert_eq!(signer.role(), "relayer");
+    }
+
+    #[test]
+    fn test_rejects_invalid_fields() {
+        let valid = SignerData {
+            address: DEFAULT_SIGNER.to_string(),
+            role: "payouts".to_string(),
+            priority: 0,
+            enabled: true,
+        };
+        assert!(valid.validate().is_ok());
+
+        let cases = [
+            SignerData {
+                address: "0x1234".to_string(),
+                ..valid.clone()
+            },
+            SignerData {
+                role: "hot wallet".to_string(),
+                ..valid.clone()
+            },
+            SignerData {
+                priority: -1,
+                ..valid.clone()
+            },
+            SignerData {
+                priority: MAX_SIGNER_PRIORITY + 1,
+                ..valid
+            },
+        ];
+        for data in cases {
+            assert!(data.validate().is_err(), "{data:?} should be rejected");
+        }
+    }
+
+    #[test]
+    fn test_pick_prefers_enabled_signer_with_lowest_priority() {
+        let network = create_test_network();
+        let signers = vec![
+            signer(
+                &network,
+                "relayer",
+                "0x1111111111111111111111111111111111111111",
+                0,
+                false,
+            ),
+            signer(
+                &network,
+                "relayer",
+                "0x2222222222222222222222222222222222222222",
+                5,
+                true,
+            ),
+            signer(
+                &network,
+                "relayer",
+                "0x3333333333333333333333333333333333333333",
+                1,
+                true,
+            ),
+            signer(
+                &network,
+                "payouts",
+                "0x4444444444444444444444444444444444444444",
+                0,
+                true,
+            ),
+        ];
+
+        let resolved = ResolvedSigner::pick(&network, "Relayer", signers).unwrap();
+        assert_eq!(resolved.address, "0x3333333333333333333333333333333333333333");
+        assert_eq!(resolved.chain_id, 1);
+        assert!(resolved.signer.is_some());
+    }
+
+    #[test]
+    fn test_pick_falls_back_to_default_signer_address() {
+        let network = create_test_network();
+
+        let resolved = ResolvedSigner::pick(&network, DEFAULT_SIGNER_ROLE, vec![]).unwrap();
+        assert_eq!(resolved.address, DEFAULT_SIGNER);
+        assert!(resolved.signer.is_none());
+
+        assert!(ResolvedSigner::pick(&network, "deployer", vec![]).is_none());
+    }
+}
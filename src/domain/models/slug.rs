@@ -0,0 +1,157 @@
+//! Network Slug Model
+//!
+//! Human-friendly identifiers for networks. Every network has one canonical
+//! slug (`eth-mainnet`) and may answer to a set of aliases (`ethereum`,
+//! `matic`). Slugs and aliases share a single namespace across networks.
+
+use std::collections::BTreeSet;
+
+use uuid::Uuid;
+
+use crate::shared::errors::DomainError;
+
+/// Maximum length for a slug or alias (a DNS label)
+pub const MAX_SLUG_LENGTH: usize = 63;
+/// Maximum number of aliases on a network
+pub const MAX_ALIASES: usize = 16;
+/// Maximum length of a slug derived from a name, leaving room for a `-<chain_id>` suffix
+const MAX_DERIVED_SLUG_LENGTH: usize = 50;
+/// Static route segments under `/networks`, which would shadow a network with that slug
+pub const RESERVED_SLUGS: &[&str] = &["contracts", "events", "search", "signers"];
+
+/// Normalize a slug or alias: trim and lowercase
+#[must_use]
+pub fn normalize_slug(slug: &str) -> String {
+    slug.trim().to_lowercase()
+}
+
+/// Normalize aliases into a deduplicated, sorted set, dropping the slug itself
+#[must_use]
+pub fn normalize_aliases(slug: &str, aliases: &[String]) -> BTreeSet<String> {
+    aliases
+        .iter()
+        .map(|alias| normalize_slug(alias))
+        .filter(|alias| alias != slug)
+        .collect()
+}
+
+/// Check whether a slug is taken by a static route under `/networks`
+#[must_use]
+pub fn is_reserved_slug(slug: &str) -> bool {
+    RESERVED_SLUGS.contains(&slug)
+}
+
+/// Derive a slug from a network name (`Polygon zkEVM` -> `polygon-zkevm`)
+///
+/// The result may be reserved; callers skip reserved slugs like taken ones.
+#[must_use]
+pub fn slugify(name: &str) -> String {
+    let slug = name
+        .to_lowercase()
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .collect::<Vec<_>>()
+        .join("-");
+
+    let slug = slug[..slug.len().min(MAX_DERIVED_SLUG_LENGTH)].trim_end_matches('-');
+    if slug.is_empty() {
+        "network".to_string()
+    } else {
+        slug.to_string()
+    }
+}
+
+/// Validate a slug or alias
+///
+/// # Errors
+///
+/// Returns a `DomainError::ValidationError` if the value is not a lowercase
+/// URL-safe label, could be mistaken for a network ID or is a reserved route segment
+pub fn validate_slug(slug: &str) -> Result<(), DomainError> {
+    let slug = normalize_slug(slug);
+    let valid = !slug.is_empty()
+        && slug.len() <= MAX_SLUG_LENGTH
+        && !slug.starts_with('-')
+        && !slug.ends_with('-')
+        && slug.chars().all(|c| c.is_ascii_alphanumeric() || c == '-');
+    if !valid {
+        return Err(DomainError::ValidationError(format!(
+            "slug '{slug}' must be 1 to {MAX_SLUG_LENGTH} letters, digits or '-' \
+             starting and ending with a letter or digit"
+        )));
+    }
+
+    if Uuid::parse_str(&slug).is_ok() {
+        return Err(DomainError::ValidationError(format!(
+            "slug '{slug}' must not be a UUID"
+        )));
+    }
+
+    if is_reserved_slug(&slug) {
+        return Err(DomainError::ValidationError(format!(
+            "slug '{slug}' is reserved for the /networks/{slug} route"
+        )));
+    }
+
+    Ok(())
+}
+
+/// Validate a list of aliases
+///
+/// # Errors
+///
+/// Returns a `DomainError::ValidationError` if an alias is malformed or there are too many aliases
+pub fn validate_aliases(aliases: &[String]) -> Result<(), DomainError> {
+    let aliases: BTreeSet<String> = aliases.iter().map(|alias| normalize_slug(alias)).collect();
+    if aliases.len() > MAX_ALIASES {
+        return Err(DomainError::ValidationError(format!(
+            "a network can have at most {MAX_ALIASES} aliases"
+        )));
+    }
+
+    aliases.iter().try_for_each(|alias| validate_slug(alias))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_slugify() {
+        assert_eq!(slugify("Ethereum Mainnet"), "ethereum-mainnet");
+        assert_eq!(slugify("  Polygon zkEVM (Cardona) "), "polygon-zkevm-cardona");
+        assert_eq!(slugify("以太坊"), "network");
+        assert!(slugify(&"word ".repeat(40)).len() <= MAX_DERIVED_SLUG_LENGTH);
+        assert!(validate_slug(&slugify(&"word ".repeat(40))).is_ok());
+    }
+
+    #[test]
+    fn test_validate_slug() {
+        assert!(validate_slug("eth-mainnet").is_ok());
+        assert!(validate_slug("Matic").is_ok());
+        assert!(validate_slug("").is_err());
+        assert!(validate_slug("-eth").is_err());
+        assert!(validate_slug("eth_mainnet").is_err());
+        assert!(validate_slug(&"x".repeat(MAX_SLUG_LENGTH + 1)).is_err());
+        assert!(validate_slug("550e8400-e29b-41d4-a716-446655440000").is_err());
+        assert!(validate_slug("search").is_err());
+        assert!(validate_slug("Events").is_err());
+        assert!(validate_slug("search-1").is_ok());
+    }
+
+    #[test]
+    fn test_aliases() {
+        let aliases = normalize_aliases(
+            "polygon",
+            &["Matic".to_string(), "matic".to_string(), "polygon".to_string()],
+        );
+        assert_eq!(aliases.into_iter().collect::<Vec<_>>(), ["matic"]);
+
+        assert!(validate_aliases(&["matic".to_string(), "pos".to_string()]).is_ok());
+        assert!(validate_aliases(&["not an alias".to_string()]).is_err());
+        assert!(validate_aliases(&["contracts".to_string()]).is_err());
+
+        let too_many: Vec<String> = (0..=MAX_ALIASES).map(|i| format!("alias-{i}")).collect();
+        assert!(validate_aliases(&too_many).is_err());
+    }
+}
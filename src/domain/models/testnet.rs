@@ -0,0 +1,99 @@
+//! Testnet Model
+//!
+//! What a consumer needs to know about a testnet beyond `test_net`: where to
+//! get funds, which mainnet it stands in for, and when it is expected to be
+//! shut down.
+
+use chrono::{DateTime, Utc};
+
+use crate::domain::models::network::{NetworkId, MAX_URL_LENGTH};
+use crate::domain::models::url_policy::UrlPolicy;
+use crate::shared::errors::DomainError;
+
+/// Maximum number of faucet URLs on a testnet
+pub const MAX_FAUCET_URLS: usize = 10;
+
+/// Testnet metadata attached to a network, empty for mainnets
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct TestnetInfo {
+    pub faucet_urls: Vec<String>,
+    /// Mainnet this testnet mirrors (Sepolia mirrors Ethereum Mainnet)
+    pub mainnet_id: Option<NetworkId>,
+    /// When the testnet is expected to be deprecated upstream
+    pub expected_deprecation_at: Option<DateTime<Utc>>,
+}
+
+impl TestnetInfo {
+    /// Whether no testnet metadata is set
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.faucet_urls.is_empty() && self.mainnet_id.is_none() && self.expected_deprecation_at.is_none()
+    }
+
+    /// Validate the metadata for a network
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DomainError::ValidationError` if metadata is set on a network
+    /// that is not a testnet, or a faucet URL is invalid
+    pub fn validate(&self, test_net: bool, url_policy: &UrlPolicy) -> Result<(), DomainError> {
+        if !test_net && !self.is_empty() {
+            return Err(DomainError::ValidationError(
+                "testnet metadata only applies to networks with test_net set".to_string(),
+            ));
+        }
+
+        if self.faucet_urls.len() > MAX_FAUCET_URLS {
+            return Err(DomainError::ValidationError(format!(
+                "testnet.faucet_urls can have at most {MAX_FAUCET_URLS} items"
+            )));
+        }
+
+        for url in &self.faucet_urls {
+            if url.len() > MAX_URL_LENGTH {
+                return Err(DomainError::ValidationError(format!(
+                    "each URL in testnet.faucet_urls must be at most {MAX_URL_LENGTH} characters"
+                )));
+            }
+
+            url_policy
+                .check(url)
+                .map_err(|reason| DomainError::ValidationError(format!("each URL in testnet.faucet_urls {reason}")))?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sepolia() -> TestnetInfo {
+        TestnetInfo {
+            faucet_urls: vec!["https://sepoliafaucet.com".to_string()],
+            mainnet_id: Some(NetworkId::new()),
+            expected_deprecation_at: None,
+        }
+    }
+
+    #[test]
+    fn test_testnet_info_only_on_testnets() {
+        assert!(sepolia().validate(true, &UrlPolicy::default()).is_ok());
+        assert!(sepolia().validate(false, &UrlPolicy::default()).is_err());
+        assert!(TestnetInfo::default().validate(false, &UrlPolicy::default()).is_ok());
+    }
+
+    #[test]
+    fn test_testnet_info_validates_faucet_urls() {
+        let mut info = sepolia();
+        info.faucet_urls.push("http://localhost:3000/drip".to_string());
+        assert!(info.validate(true, &UrlPolicy::default()).is_err());
+
+        let mut info = sepolia();
+        info.faucet_urls = (0..=MAX_FAUCET_URLS)
+            .map(|i| format!("https://faucet-{i}.example.com"))
+            .collect();
+        assert!(info.validate(true, &UrlPolicy::default()).is_err());
+    }
+}
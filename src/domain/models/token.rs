@@ -0,0 +1,372 @@
+//! Token Model
+//!
+//! ERC-20 token metadata kept per network. Field limits follow the Uniswap
+//! Token Lists schema so every stored token can be exported as-is.
+
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::domain::models::network::{is_ethereum_address, NetworkId, ETHEREUM_ADDRESS_LENGTH};
+use crate::shared::errors::DomainError;
+
+/// Maximum length for token symbol
+pub const MAX_SYMBOL_LENGTH: usize = 20;
+/// Maximum length for token name
+pub const MAX_TOKEN_NAME_LENGTH: usize = 40;
+/// Maximum length for the logo URI
+pub const MAX_LOGO_URI_LENGTH: usize = 500;
+/// Maximum number of tags on a token
+pub const MAX_TOKEN_TAGS: usize = 10;
+/// Maximum length of a single token tag
+pub const MAX_TOKEN_TAG_LENGTH: usize = 10;
+/// Maximum number of tokens in a single import
+pub const MAX_IMPORT_TOKENS: usize = 1000;
+
+/// URI schemes accepted for token logos
+const LOGO_URI_SCHEMES: [&str; 4] = ["https", "http", "ipfs", "ipns"];
+
+/// Unique identifier for a token
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TokenId(Uuid);
+
+impl TokenId {
+    /// Create a new random TokenId
+    #[must_use]
+    pub fn new() -> Self {
+        Self(Uuid::new_v4())
+    }
+
+    /// Create a TokenId from an existing UUID
+    #[must_use]
+    pub fn from_uuid(uuid: Uuid) -> Self {
+        Self(uuid)
+    }
+
+    /// Get the underlying UUID
+    #[must_use]
+    pub fn as_uuid(&self) -> &Uuid {
+        &self.0
+    }
+}
+
+impl Default for TokenId {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl std::fmt::Display for TokenId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// Descriptive token fields, everything but the address
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TokenMetadata {
+    pub symbol: String,
+    pub name: String,
+    pub decimals: u8,
+    pub logo_uri: Option<String>,
+    pub tags: Vec<String>,
+}
+
+impl TokenMetadata {
+    /// Validate the token metadata
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DomainError::ValidationError` if any field is invalid
+    pub fn validate(&self) -> Result<(), DomainError> {
+        if self.symbol.is_empty()
+            || self.symbol.len() > MAX_SYMBOL_LENGTH
+            || self.symbol.chars().any(char::is_whitespace)
+        {
+            return Err(DomainError::ValidationError(format!(
+                "symbol must be between 1 and {MAX_SYMBOL_LENGTH} characters without whitespace"
+            )));
+        }
+
+        if self.name.trim().is_empty()
+            || self.name.chars().count() > MAX_TOKEN_NAME_LENGTH
+            || !self.name.chars().all(is_token_name_char)
+        {
+            return Err(DomainError::ValidationError(format!(
+                "name must be between 1 and {MAX_TOKEN_NAME_LENGTH} characters of letters, digits, spaces or .'+-%/:&[]()"
+            )));
+        }
+
+        if let Some(logo_uri) = &self.logo_uri {
+            let scheme_allowed = url::Url::parse(logo_uri).is_ok_and(|uri| LOGO_URI_SCHEMES.contains(&uri.scheme()));
+            if logo_uri.len() > MAX_LOGO_URI_LENGTH || !scheme_allowed {
+                return Err(DomainError::ValidationError(format!(
+                    "logo_uri must be an http(s), ipfs or ipns URI of at most {MAX_LOGO_URI_LENGTH} characters"
+                )));
+            }
+        }
+
+        if self.tags.len() > MAX_TOKEN_TAGS {
+            return Err(DomainError::ValidationError(format!(
+                "tags can have at most {MAX_TOKEN_TAGS} items"
+            )));
+        }
+
+        for tag in &self.tags {
+            let tag = tag.trim();
+            if tag.is_empty()
+                || tag.len() > MAX_TOKEN_TAG_LENGTH
+                || !tag.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+            {
+                return Err(DomainError::ValidationError(format!(
+                    "each tag must be between 1 and {MAX_TOKEN_TAG_LENGTH} letters, digits or underscores"
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Normalize the metadata: trim the name, lowercase and deduplicate tags
+    fn normalized(self) -> Self {
+        let mut tags: Vec<String> = Vec::with_capacity(self.tags.len());
+        for tag in self.tags {
+            let tag = tag.trim().to_lowercase();
+            if !tags.contains(&tag) {
+                tags.push(tag);
+            }
+        }
+
+        Self {
+            name: self.name.trim().to_string(),
+            tags,
+            ..self
+        }
+    }
+}
+
+/// A token entry of a bulk import
+#[derive(Debug, Clone)]
+pub struct ImportedToken {
+    /// Chain the entry belongs to, when imported from a multi-chain list
+    pub chain_id: Option<i32>,
+    pub address: String,
+    pub metadata: TokenMetadata,
+}
+
+/// Characters allowed in a token name by the Token Lists schema
+fn is_token_name_char(c: char) -> bool {
+    c.is_ascii_alphanumeric()
+        || " _.'+-%/:&[]()".contains(c)
+        || (('\u{C0}'..='\u{FF}').contains(&c) && c != '\u{D7}' && c != '\u{F7}')
+}
+
+/// An ERC-20 token deployed on a network
+#[derive(Debug, Clone)]
+pub struct Token {
+    id: TokenId,
+    network_id: NetworkId,
+    address: String,
+    metadata: TokenMetadata,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl Token {
+    /// Register a new token on a network
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DomainError::ValidationError` if the address or metadata is invalid
+    pub fn new(network_id: NetworkId, address: String, metadata: TokenMetadata) -> Result<Self, DomainError> {
+        if !is_ethereum_address(&address) {
+            return Err(DomainError::ValidationError(format!(
+                "address must be 0x followed by {} hex characters",
+                ETHEREUM_ADDRESS_LENGTH - 2
+            )));
+        }
+        metadata.validate()?;
+
+        let now = Utc::now();
+        Ok(Self {
+            id: TokenId::new(),
+            network_id,
+            address,
+            metadata: metadata.normalized(),
+            created_at: now,
+            updated_at: now,
+        })
+    }
+
+    /// Restore a token from persistence (no validation)
+    #[must_use]
+    pub fn restore(
+        id: TokenId,
+        network_id: NetworkId,
+        address: String,
+        metadata: TokenMetadata,
+        created_at: DateTime<Utc>,
+        updated_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id,
+            network_id,
+            address,
+            metadata,
+            created_at,
+            updated_at,
+        }
+    }
+
+    /// Replace the token metadata, keeping its identity and address
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DomainError::ValidationError` if the metadata is invalid
+    pub fn with_metadata(self, metadata: TokenMetadata) -> Result<Self, DomainError> {
+        metadata.validate()?;
+
+        Ok(Self {
+            metadata: metadata.normalized(),
+            updated_at: Utc::now(),
+            ..self
+        })
+    }
+
+    /// Whether applying the metadata would change the token
+    #[must_use]
+    pub fn differs_from(&self, metadata: &TokenMetadata) -> bool {
+        self.metadata != metadata.clone().normalized()
+    }
+
+    // Getters
+
+    #[must_use]
+    pub fn id(&self) -> &TokenId {
+        &self.id
+    }
+
+    #[must_use]
+    pub fn network_id(&self) -> &NetworkId {
+        &self.network_id
+    }
+
+    #[must_use]
+    pub fn address(&self) -> &str {
+        &self.address
+    }
+
+    #[must_use]
+    pub fn metadata(&self) -> &TokenMetadata {
+        &self.metadata
+    }
+
+    #[must_use]
+    pub fn symbol(&self) -> &str {
+        &self.metadata.symbol
+    }
+
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.metadata.name
+    }
+
+    #[must_use]
+    pub fn decimals(&self) -> u8 {
+        self.metadata.decimals
+    }
+
+    #[must_use]
+    pub fn logo_uri(&self) -> Option<&str> {
+        self.metadata.logo_uri.as_deref()
+    }
+
+    #[must_use]
+    pub fn tags(&self) -> &[String] {
+        &self.metadata.tags
+    }
+
+    #[must_use]
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    #[must_use]
+    pub fn updated_at(&self) -> DateTime<Utc> {
+        self.updated_at
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const USDC: &str = "0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48";
+
+    fn usdc() -> TokenMetadata {
+        TokenMetadata {
+            symbol: "USDC".to_string(),
+            name: "USD Coin".to_string(),
+            decimals: 6,
+            logo_uri: Some("ipfs://QmXfzKRvjZz3u5JRgC4v5mGVbm9ahrUiB4DgzHBsnWbTMM".to_string()),
+            tags: vec!["Stablecoin".to_string(), "stablecoin".to_string()],
+        }
+    }
+
+    #[test]
+    fn test_new_token_normalizes_tags() {
+        let token = Token::new(NetworkId::new(), USDC.to_string(), usdc()).unwrap();
+        assert_eq!(token.symbol(), "USDC");
+        assert_eq!(token.tags(), ["stablecoin"]);
+        assert!(!token.differs_from(&usdc()));
+    }
+
+    #[test]
+    fn test_rejects_invalid_address() {
+        assert!(Token::new(NetworkId::new(), "0x1234".to_string(), usdc()).is_err());
+    }
+
+    #[test]
+    fn test_rejects_metadata_outside_token_list_schema() {
+        let cases = [
+            TokenMetadata {
+                symbol: "US DC".to_string(),
+                ..usdc()
+            },
+            TokenMetadata {
+                name: "USD Coin ✓".to_string(),
+                ..usdc()
+            },
+            TokenMetadata {
+                logo_uri: Some("ftp://example.com/usdc.png".to_string()),
+                ..usdc()
+            },
+            TokenMetadata {
+                tags: vec!["stable-coin".to_string()],
+                ..usdc()
+            },
+            TokenMetadata {
+                tags: (0..=MAX_TOKEN_TAGS).map(|i| format!("tag{i}")).collect(),
+                ..usdc()
+            },
+        ];
+
+        for metadata in cases {
+            assert!(metadata.validate().is_err(), "{metadata:?} should be rejected");
+        }
+    }
+
+    #[test]
+    fn test_with_metadata_keeps_identity() {
+        let token = Token::new(NetworkId::new(), USDC.to_string(), usdc()).unwrap();
+        let renamed = TokenMetadata {
+            name: "USD Coin (Bridged)".to_string(),
+            ..usdc()
+        };
+        assert!(token.differs_from(&renamed));
+
+        let updated = token.clone().with_metadata(renamed).unwrap();
+        assert_eq!(updated.id(), token.id());
+        assert_eq!(updated.address(), USDC);
+        assert_eq!(updated.name(), "USD Coin (Bridged)");
+    }
+}
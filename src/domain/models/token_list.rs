@@ -0,0 +1,127 @@
+//! Token List Model
+//!
+//! Versioning of the per-network token list, following the Uniswap Token
+//! Lists semantic versioning rules.
+
+use chrono::{DateTime, Utc};
+
+use crate::domain::models::token::Token;
+
+/// Kind of change made to a token list, deciding which version part is bumped
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum TokenListChange {
+    /// Token metadata changed (symbol, name, decimals, logo, tags)
+    Patch,
+    /// Tokens were added
+    Minor,
+    /// Tokens were removed
+    Major,
+}
+
+impl TokenListChange {
+    /// Get the string representation of the change
+    #[must_use]
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Patch => "patch",
+            Self::Minor => "minor",
+            Self::Major => "major",
+        }
+    }
+}
+
+impl std::fmt::Display for TokenListChange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Semantic version of a token list, with the time of its last change
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TokenListVersion {
+    pub major: u32,
+    pub minor: u32,
+    pub patch: u32,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl TokenListVersion {
+    /// Version of a list that has never changed
+    #[must_use]
+    pub fn initial(at: DateTime<Utc>) -> Self {
+        Self {
+            major: 1,
+            minor: 0,
+            patch: 0,
+            updated_at: at,
+        }
+    }
+
+    /// Bump the version for a change made at the given time
+    #[must_use]
+    pub fn bump(self, change: TokenListChange, at: DateTime<Utc>) -> Self {
+        let (major, minor, patch) = match change {
+            TokenListChange::Major => (self.major + 1, 0, 0),
+            TokenListChange::Minor => (self.major, self.minor + 1, 0),
+            TokenListChange::Patch => (self.major, self.minor, self.patch + 1),
+        };
+
+        Self {
+            major,
+            minor,
+            patch,
+            updated_at: at,
+        }
+    }
+}
+
+impl std::fmt::Display for TokenListVersion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
+/// The token list of a network, ready to be exported
+#[derive(Debug, Clone)]
+pub struct TokenList {
+    pub name: String,
+    pub chain_id: i32,
+    pub version: TokenListVersion,
+    pub tokens: Vec<Token>,
+}
+
+/// Outcome of a bulk token import
+#[derive(Debug, Clone)]
+pub struct TokenImportSummary {
+    pub created: usize,
+    pub updated: usize,
+    pub unchanged: usize,
+    /// Tokens declared for another chain, ignored by the import
+    pub skipped: usize,
+    pub version: TokenListVersion,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bump_resets_lower_parts() {
+        let now = Utc::now();
+        let version = TokenListVersion::initial(now)
+            .bump(TokenListChange::Patch, now)
+            .bump(TokenListChange::Minor, now);
+        assert_eq!(version.to_string(), "1.1.0");
+
+        let version = version
+            .bump(TokenListChange::Patch, now)
+            .bump(TokenListChange::Major, now);
+        assert_eq!(version.to_string(), "2.0.0");
+    }
+
+    #[test]
+    fn test_change_ordering_picks_most_significant() {
+        let changes = [TokenListChange::Patch, TokenListChange::Minor, TokenListChange::Patch];
+        assert_eq!(changes.into_iter().max(), Some(TokenListChange::Minor));
+    }
+}
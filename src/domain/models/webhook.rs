@@ -0,0 +1,819 @@
+//! Webhook Model
+//!
+//! Subscriptions notifying external HTTP endpoints of network changes, and
+//! the deliveries made to them.
+//!
+//! Each delivery is signed with the subscription secret and retried with
+//! exponential backoff until it succeeds or runs out of attempts. Deliveries
+//! and their attempts are kept as a log, and can be redelivered by hand.
+
+use std::fmt;
+use std::str::FromStr;
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::domain::models::event::NetworkEventKind;
+use crate::domain::models::network::{NetworkId, MAX_URL_LENGTH};
+use crate::domain::models::outbox::OutboxMessageId;
+use crate::domain::models::url_policy::UrlPolicy;
+use crate::shared::errors::DomainError;
+
+/// Maximum number of networks a webhook can be limited to
+pub const MAX_WEBHOOK_NETWORKS: usize = 100;
+/// Minimum length of a webhook secret
+pub const MIN_WEBHOOK_SECRET_LENGTH: usize = 16;
+/// Maximum length of a webhook secret
+pub const MAX_WEBHOOK_SECRET_LENGTH: usize = 128;
+/// Maximum length of a receiver response body kept in the delivery log
+pub const MAX_RESPONSE_BODY_LENGTH: usize = 1024;
+/// Prefix of generated webhook secrets
+const GENERATED_SECRET_PREFIX: &str = "whsec_";
+
+/// Unique identifier for a webhook
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct WebhookId(Uuid);
+
+impl WebhookId {
+    /// Create a new random WebhookId
+    #[must_use]
+    pub fn new() -> Self {
+        Self(Uuid::new_v4())
+    }
+
+    /// Create a WebhookId from an existing UUID
+    #[must_use]
+    pub fn from_uuid(uuid: Uuid) -> Self {
+        Self(uuid)
+    }
+
+    /// Get the underlying UUID
+    #[must_use]
+    pub fn as_uuid(&self) -> &Uuid {
+        &self.0
+    }
+}
+
+impl Default for WebhookId {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Display for WebhookId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// Unique identifier for a webhook delivery
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DeliveryId(Uuid);
+
+impl DeliveryId {
+    /// Create a new random DeliveryId
+    #[must_use]
+    pub fn new() -> Self {
+        Self(Uuid::new_v4())
+    }
+
+    /// Derive the ID of the delivery of an outbox event to a webhook
+    ///
+    /// The same event relayed twice yields the same delivery, so it is only queued once.
+    #[must_use]
+    pub fn for_event(event_id: &OutboxMessageId, webhook_id: &WebhookId) -> Self {
+        Self(Uuid::new_v5(event_id.as_uuid(), webhook_id.as_uuid().as_bytes()))
+    }
+
+    /// Create a DeliveryId from an existing UUID
+    #[must_use]
+    pub fn from_uuid(uuid: Uuid) -> Self {
+        Self(uuid)
+    }
+
+    /// Get the underlying UUID
+    #[must_use]
+    pub fn as_uuid(&self) -> &Uuid {
+        &self.0
+    }
+}
+
+impl Default for DeliveryId {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Display for DeliveryId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// Generate a random webhook secret
+#[must_use]
+pub fn generate_secret() -> String {
+    format!(
+        "{GENERATED_SECRET_PREFIX}{}{}",
+        Uuid::new_v4().simple(),
+        Uuid::new_v4().simple()
+    )
+}
+
+/// Data required to create a webhook, also used to replace one
+#[derive(Debug, Clone)]
+pub struct WebhookData {
+    pub url: String,
+    /// Event kinds to deliver; empty means all
+    pub events: Vec<NetworkEventKind>,
+    /// Networks to deliver events for; empty means all
+    pub network_ids: Vec<NetworkId>,
+    /// Signing secret; generated on creation and kept on update when omitted
+    pub secret: Option<String>,
+    pub enabled: bool,
+}
+
+impl WebhookData {
+    /// Validate the webhook data
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DomainError::ValidationError` if any field is invalid
+    pub fn validate(&self, url_policy: &UrlPolicy) -> Result<(), DomainError> {
+        if self.url.len() > MAX_URL_LENGTH {
+            return Err(DomainError::ValidationError(format!(
+                "url must be at most {MAX_URL_LENGTH} characters"
+            )));
+        }
+        url_policy
+            .check(&self.url)
+            .map_err(|reason| DomainError::ValidationError(format!("url {reason}")))?;
+
+        if self.network_ids.len() > MAX_WEBHOOK_NETWORKS {
+            return Err(DomainError::ValidationError(format!(
+                "network_ids can have at most {MAX_WEBHOOK_NETWORKS} items"
+            )));
+        }
+
+        if let Some(secret) = &self.secret {
+            if !(MIN_WEBHOOK_SECRET_LENGTH..=MAX_WEBHOOK_SECRET_LENGTH).contains(&secret.len()) {
+                return Err(DomainError::ValidationError(format!(
+                    "secret must be between {MIN_WEBHOOK_SECRET_LENGTH} and {MAX_WEBHOOK_SECRET_LENGTH} characters"
+                )));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Keep the first occurrence of each item, preserving order
+fn dedup<T: PartialEq>(items: Vec<T>) -> Vec<T> {
+    let mut unique = Vec::with_capacity(items.len());
+    for item in items {
+        if !unique.contains(&item) {
+            unique.push(item);
+        }
+    }
+    unique
+}
+
+/// A subscription of an HTTP endpoint to network changes
+#[derive(Debug, Clone)]
+pub struct Webhook {
+    id: WebhookId,
+    url: String,
+    events: Vec<NetworkEventKind>,
+    network_ids: Vec<NetworkId>,
+    secret: String,
+    enabled: bool,
+    created_by: String,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl Webhook {
+    /// Create a new webhook, generating a secret if none is given
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DomainError::ValidationError` if the data is invalid
+    /// or the URL is rejected by the URL policy
+    pub fn new(data: WebhookData, created_by: String, url_policy: &UrlPolicy) -> Result<Self, DomainError> {
+        data.validate(url_policy)?;
+
+        let now = Utc::now();
+        Ok(Self {
+            id: WebhookId::new(),
+            url: data.url,
+            events: dedup(data.events),
+            network_ids: dedup(data.network_ids),
+            secret: data.secret.unwrap_or_else(generate_secret),
+            enabled: data.enabled,
+            created_by,
+            created_at: now,
+            updated_at: now,
+        })
+    }
+
+    /// Restore a webhook from persistence (no validation)
+    #[must_use]
+    #[allow(clippy::too_many_arguments)]
+    pub fn restore(
+        id: WebhookId,
+        url: String,
+        events: Vec<NetworkEventKind>,
+        network_ids: Vec<NetworkId>,
+        secret: String,
+        enabled: bool,
+        created_by: String,
+        created_at: DateTime<Utc>,
+        updated_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id,
+            url,
+            events,
+            network_ids,
+            secret,
+            enabled,
+            created_by,
+            created_at,
+            updated_at,
+        }
+    }
+
+    /// Replace the webhook settings, keeping the secret when none is given
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DomainError::ValidationError` if the data is invalid
+    /// or the URL is rejected by the URL policy
+    pub fn with_updates(mut self, data: WebhookData, url_policy: &UrlPolicy) -> Result<Self, DomainError> {
+        data.validate(url_policy)?;
+
+        self.url = data.url;
+        self.events = dedup(data.events);
+        self.network_ids = dedup(data.network_ids);
+        if let Some(secret) = data.secret {
+            self.secret = secret;
+        }
+        self.enabled = data.enabled;
+        self.updated_at = Utc::now();
+        Ok(self)
+    }
+
+    /// Check whether an event about a network should be delivered to this webhook
+    #[must_use]
+    pub fn matches(&self, kind: NetworkEventKind, network_id: &NetworkId) -> bool {
+        self.enabled
+            && (self.events.is_empty() || self.events.contains(&kind))
+            && (self.network_ids.is_empty() || self.network_ids.contains(network_id))
+    }
+
+    // Getters
+    #[must_use]
+    pub fn id(&self) -> &WebhookId {
+        &self.id
+    }
+
+    #[must_use]
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    #[must_use]
+    pub fn events(&self) -> &[NetworkEventKind] {
+        &self.events
+    }
+
+    #[must_use]
+    pub fn network_ids(&self) -> &[NetworkId] {
+        &self.network_ids
+    }
+
+    #[must_use]
+    pub fn secret(&self) -> &str {
+        &self.secret
+    }
+
+    #[must_use]
+    pub fn enabled(&self) -> bool {
+        self.enabled
+    }
+
+    #[must_use]
+    pub fn created_by(&self) -> &str {
+        &self.created_by
+    }
+
+    #[must_use]
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    #[must_use]
+    pub fn updated_at(&self) -> DateTime<Utc> {
+        self.updated_at
+    }
+}
+
+/// State of a webhook delivery
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeliveryStatus {
+    /// Waiting for its next attempt
+    Pending,
+    /// Accepted by the receiver
+    Succeeded,
+    /// Gave up after the last attempt
+    Failed,
+}
+
+impl DeliveryStatus {
+    /// Get the persisted representation
+    #[must_use]
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Pending => "pending",
+            Self::Succeeded => "succeeded",
+            Self::Failed => "failed",
+        }
+    }
+}
+
+impl fmt::Display for DeliveryStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for DeliveryStatus {
+    type Err = DomainError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "pending" => Ok(Self::Pending),
+            "succeeded" => Ok(Self::Succeeded),
+            "failed" => Ok(Self::Failed),
+            _ => Err(DomainError::ValidationError(format!(
+                "unknown delivery status '{value}'"
+            ))),
+        }
+    }
+}
+
+/// How failed deliveries are retried
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WebhookRetryPolicy {
+    /// Attempts made before a delivery fails for good
+    pub max_attempts: u32,
+    /// Delay before the first retry, doubled for each further one
+    pub base_delay: Duration,
+    /// Upper bound for the delay between attempts
+    pub max_delay: Duration,
+}
+
+impl WebhookRetryPolicy {
+    /// Delay before the attempt following the given number of failed attempts
+    #[must_use]
+    pub fn delay_after(&self, attempts: u32) -> Duration {
+        let exponent = attempts.saturating_sub(1).min(31);
+        self.base_delay
+            .checked_mul(1 << exponent)
+            .map_or(self.max_delay, |delay| delay.min(self.max_delay))
+    }
+}
+
+/// Result of sending a delivery to its receiver
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeliveryOutcome {
+    /// HTTP status of the response, if one was received
+    pub status_code: Option<u16>,
+    /// Beginning of the response body
+    pub response_body: Option<String>,
+    /// Why the request failed, if it did
+    pub error: Option<String>,
+    pub duration: Duration,
+}
+
+impl DeliveryOutcome {
+    /// Check whether the receiver accepted the delivery (2xx response)
+    #[must_use]
+    pub fn succeeded(&self) -> bool {
+        self.error.is_none() && self.status_code.is_some_and(|status| (200..300).contains(&status))
+    }
+}
+
+/// One attempt at sending a delivery, kept in the delivery log
+#[derive(Debug, Clone)]
+pub struct DeliveryAttempt {
+    pub delivery_id: DeliveryId,
+    pub attempted_at: DateTime<Utc>,
+    pub status_code: Option<u16>,
+    pub response_body: Option<String>,
+    pub error: Option<String>,
+    pub duration: Duration,
+}
+
+/// An event to deliver to a webhook
+#[derive(Debug, Clone)]
+pub struct WebhookDelivery {
+    id: DeliveryId,
+    webhook_id: WebhookId,
+    event: NetworkEventKind,
+    network_id: NetworkId,
+    /// JSON body, fixed at creation so that every attempt sends the same signed content
+    payload: String,
+    status: DeliveryStatus,
+    /// Attempts made since the delivery was (re)queued
+    attempts: u32,
+    next_attempt_at: DateTime<Utc>,
+    last_attempt_at: Option<DateTime<Utc>>,
+    delivered_at: Option<DateTime<Utc>>,
+    created_at: DateTime<Utc>,
+}
+
+impl WebhookDelivery {
+    /// Queue an event for immediate delivery to a webhook
+    #[must_use]
+    pub fn new(webhook_id: WebhookId, event: NetworkEventKind, network_id: NetworkId, payload: String) -> Self {
+        Self::with_id(DeliveryId::new(), webhook_id, event, network_id, payload)
+    }
+
+    /// Queue an outbox event for immediate delivery to a webhook, under an ID derived from the event
+    #[must_use]
+    pub fn for_event(
+        event_id: &OutboxMessageId,
+        webhook_id: WebhookId,
+        event: NetworkEventKind,
+        network_id: NetworkId,
+        payload: String,
+    ) -> Self {
+        Self::with_id(
+            DeliveryId::for_event(event_id, &webhook_id),
+            webhook_id,
+            event,
+            network_id,
+            payload,
+        )
+    }
+
+    fn with_id(
+        id: DeliveryId,
+        webhook_id: WebhookId,
+        event: NetworkEventKind,
+        network_id: NetworkId,
+        payload: String,
+    ) -> Self {
+        let now = Utc::now();
+        Self {
+            id,
+            webhook_id,
+            event,
+            network_id,
+            payload,
+            status: DeliveryStatus::Pending,
+            attempts: 0,
+            next_attempt_at: now,
+            last_attempt_at: None,
+            delivered_at: None,
+            created_at: now,
+        }
+    }
+
+    /// Restore a delivery from persistence (no validation)
+    #[must_use]
+    #[allow(clippy::too_many_arguments)]
+    pub fn restore(
+        id: DeliveryId,
+        webhook_id: WebhookId,
+        event: NetworkEventKind,
+        network_id: NetworkId,
+        payload: String,
+        status: DeliveryStatus,
+        attempts: u32,
+        next_attempt_at: DateTime<Utc>,
+        last_attempt_at: Option<DateTime<Utc>>,
+        delivered_at: Option<DateTime<Utc>>,
+        created_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id,
+            webhook_id,
+            event,
+            network_id,
+            payload,
+            status,
+            attempts,
+            next_attempt_at,
+            last_attempt_at,
+            delivered_at,
+            created_at,
+        }
+    }
+
+    /// Record an attempt, scheduling a retry unless it succeeded or was the last one
+    #[must_use]
+    pub fn record_attempt(mut self, outcome: DeliveryOutcome, policy: &WebhookRetryPolicy) -> (Self, DeliveryAttempt) {
+        let now = Utc::now();
+        self.attempts += 1;
+        self.last_attempt_at = Some(now);
+
+        if outcome.succeeded() {
+            self.status = DeliveryStatus::Succeeded;
+            self.delivered_at = Some(now);
+        } else if self.attempts >= policy.max_attempts {
+            self.status = DeliveryStatus::Failed;
+        } else {
+            let delay = chrono::Duration::from_std(policy.delay_after(self.attempts)).unwrap_or(chrono::Duration::MAX);
+            self.next_attempt_at = now.checked_add_signed(delay).unwrap_or(DateTime::<Utc>::MAX_UTC);
+        }
+
+        let attempt = DeliveryAttempt {
+            delivery_id: self.id.clone(),
+            attempted_at: now,
+            status_code: outcome.status_code,
+            response_body: outcome
+                .response_body
+                .map(|body| body.chars().take(MAX_RESPONSE_BODY_LENGTH).collect()),
+            error: outcome.error,
+            duration: outcome.duration,
+        };
+        (self, attempt)
+    }
+
+    /// Give up on the delivery without sending it, e.g. because its webhook was disabled
+    #[must_use]
+    pub fn abandon(mut self, reason: &str) -> (Self, DeliveryAttempt) {
+        let now = Utc::now();
+        self.status = DeliveryStatus::Failed;
+        self.last_attempt_at = Some(now);
+
+        let attempt = DeliveryAttempt {
+            delivery_id: self.id.clone(),
+            attempted_at: now,
+            status_code: None,
+            response_body: None,
+            error: Some(reason.to_string()),
+            duration: Duration::ZERO,
+        };
+        (self, attempt)
+    }
+
+    /// Queue a completed delivery again, with a fresh set of attempts
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DomainError::InvalidState` if the delivery is still pending
+    pub fn redeliver(mut self) -> Result<Self, DomainError> {
+        if self.status == DeliveryStatus::Pending {
+            return Err(DomainError::InvalidState("delivery is already pending".to_string()));
+        }
+
+        self.status = DeliveryStatus::Pending;
+        self.attempts = 0;
+        self.next_attempt_at = Utc::now();
+        self.delivered_at = None;
+        Ok(self)
+    }
+
+    // Getters
+    #[must_use]
+    pub fn id(&self) -> &DeliveryId {
+        &self.id
+    }
+
+    #[must_use]
+    pub fn webhook_id(&self) -> &WebhookId {
+        &self.webhook_id
+    }
+
+    #[must_use]
+    pub fn event(&self) -> NetworkEventKind {
+        self.event
+    }
+
+    #[must_use]
+    pub fn network_id(&self) -> &NetworkId {
+        &self.network_id
+    }
+
+    #[must_use]
+    pub fn payload(&self) -> &str {
+        &self.payload
+    }
+
+    #[must_use]
+    pub fn status(&self) -> DeliveryStatus {
+        self.status
+    }
+
+    #[must_use]
+    pub fn attempts(&self) -> u32 {
+        self.attempts
+    }
+
+    #[must_use]
+    pub fn next_attempt_at(&self) -> DateTime<Utc> {
+        self.next_attempt_at
+    }
+
+    #[must_use]
+    pub fn last_attempt_at(&self) -> Option<DateTime<Utc>> {
+        self.last_attempt_at
+    }
+
+    #[must_use]
+    pub fn delivered_at(&self) -> Option<DateTime<Utc>> {
+        self.delivered_at
+    }
+
+    #[must_use]
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn webhook_data() -> WebhookData {
+        WebhookData {
+            url: "https://hooks.example.com/networks".to_string(),
+            events: vec![],
+            network_ids: vec![],
+            secret: None,
+            enabled: true,
+        }
+    }
+
+    fn policy() -> WebhookRetryPolicy {
+        WebhookRetryPolicy {
+            max_attempts: 3,
+            base_delay: Duration::from_secs(10),
+            max_delay: Duration::from_secs(25),
+        }
+    }
+
+    fn outcome(status_code: Option<u16>) -> DeliveryOutcome {
+        DeliveryOutcome {
+            status_code,
+            response_body: None,
+            error: status_code.is_none().then(|| "connection refused".to_string()),
+            duration: Duration::from_millis(20),
+        }
+    }
+
+    fn delivery() -> WebhookDelivery {
+        WebhookDelivery::new(
+            WebhookId::new(),
+            NetworkEventKind::Updated,
+            NetworkId::new(),
+            "{}".to_string(),
+        )
+    }
+
+    #[test]
+    fn test_generates_secret_when_omitted() {
+        let webhook = Webhook::new(webhook_data(), "user-1".to_string(), &UrlPolicy::default()).unwrap();
+        assert!(webhook.secret().starts_with(GENERATED_SECRET_PREFIX));
+        assert!(webhook.secret().len() >= MIN_WEBHOOK_SECRET_LENGTH);
+
+        let renamed = webhook
+            .clone()
+            .with_updates(
+                WebhookData {
+                    url: "https://hooks.example.com/v2".to_string(),
+                    ..webhook_data()
+                },
+                &UrlPolicy::default(),
+            )
+            .unwrap();
+        assert_eq!(renamed.secret(), webhook.secret());
+    }
+
+    #[test]
+    fn test_rejects_invalid_webhooks() {
+        for data in [
+            WebhookData {
+                url: "http://127.0.0.1/hook".to_string(),
+                ..webhook_data()
+            },
+            WebhookData {
+                url: "not a url".to_string(),
+                ..webhook_data()
+            },
+            WebhookData {
+                secret: Some("short".to_string()),
+                ..webhook_data()
+            },
+            WebhookData {
+                network_ids: (0..=MAX_WEBHOOK_NETWORKS).map(|_| NetworkId::new()).collect(),
+                ..webhook_data()
+            },
+        ] {
+            assert!(Webhook::new(data, "user-1".to_string(), &UrlPolicy::default()).is_err());
+        }
+    }
+
+    #[test]
+    fn test_matches_filters() {
+        let network_id = NetworkId::new();
+        let webhook = Webhook::new(
+            WebhookData {
+                events: vec![NetworkEventKind::Updated, NetworkEventKind::Updated],
+                network_ids: vec![network_id.clone()],
+                ..webhook_data()
+            },
+            "user-1".to_string(),
+            &UrlPolicy::default(),
+        )
+        .unwrap();
+
+        assert_eq!(webhook.events(), [NetworkEventKind::Updated]);
+        assert!(webhook.matches(NetworkEventKind::Updated, &network_id));
+        assert!(!webhook.matches(NetworkEventKind::Created, &network_id));
+        assert!(!webhook.matches(NetworkEventKind::Updated, &NetworkId::new()));
+
+        let disabled = webhook
+            .with_updates(
+                WebhookData {
+                    enabled: false,
+                    ..webhook_data()
+                },
+                &UrlPolicy::default(),
+            )
+            .unwrap();
+        assert!(!disabled.matches(NetworkEventKind::Updated, &network_id));
+    }
+
+    #[test]
+    fn test_retry_delay_doubles_up_to_max() {
+        let policy = policy();
+        assert_eq!(policy.delay_after(1), Duration::from_secs(10));
+        assert_eq!(policy.delay_after(2), Duration::from_secs(20));
+        assert_eq!(policy.delay_after(3), Duration::from_secs(25));
+        assert_eq!(policy.delay_after(100), Duration::from_secs(25));
+    }
+
+    #[test]
+    fn test_records_attempts_until_failure() {
+        let policy = policy();
+
+        let (delivery, attempt) = delivery().record_attempt(outcome(Some(500)), &policy);
+        assert_eq!(delivery.status(), DeliveryStatus::Pending);
+        assert_eq!(delivery.attempts(), 1);
+        assert!(delivery.next_attempt_at() > Utc::now() + chrono::Duration::seconds(9));
+        assert_eq!(attempt.status_code, Some(500));
+
+        let (delivery, _) = delivery.record_attempt(outcome(None), &policy);
+        let (delivery, attempt) = delivery.record_attempt(outcome(None), &policy);
+        assert_eq!(delivery.status(), DeliveryStatus::Failed);
+        assert_eq!(attempt.error.as_deref(), Some("connection refused"));
+
+        let requeued = delivery.redeliver().unwrap();
+        assert_eq!(requeued.status(), DeliveryStatus::Pending);
+        assert_eq!(requeued.attempts(), 0);
+        assert!(requeued.redeliver().is_err());
+    }
+
+    #[test]
+    fn test_records_success() {
+        let (delivery, _) = delivery().record_attempt(outcome(Some(204)), &policy());
+        assert_eq!(delivery.status(), DeliveryStatus::Succeeded);
+        assert!(delivery.delivered_at().is_some());
+    }
+
+    #[test]
+    fn test_delivery_id_derived_from_event() {
+        let event_id = OutboxMessageId::new();
+        let webhook_id = WebhookId::new();
+
+        assert_eq!(
+            DeliveryId::for_event(&event_id, &webhook_id),
+            DeliveryId::for_event(&event_id, &webhook_id)
+        );
+        assert_ne!(
+            DeliveryId::for_event(&event_id, &webhook_id),
+            DeliveryId::for_event(&event_id, &WebhookId::new())
+        );
+        assert_ne!(
+            DeliveryId::for_event(&event_id, &webhook_id),
+            DeliveryId::for_event(&OutboxMessageId::new(), &webhook_id)
+        );
+    }
+
+    #[test]
+    fn test_delivery_status_roundtrip() {
+        for status in [
+            DeliveryStatus::Pending,
+            DeliveryStatus::Succeeded,
+            DeliveryStatus::Failed,
+        ] {
+            assert_eq!(status.as_str().parse::<DeliveryStatus>().unwrap(), status);
+        }
+        assert!("retrying".parse::<DeliveryStatus>().is_err());
+    }
+}
@@ -0,0 +1,5 @@
+//! API Key Repository Implementations
+
+mod postgres;
+
+pub use postgres::PostgresApiKeyRepository;
@@ -0,0 +1,159 @@
+//! PostgreSQL API Key Repository Implementation
+//!
+//! Implements the ApiKeyRepository trait using SQLx for PostgreSQL.
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::domain::gateways::ApiKeyRepository;
+use crate::domain::models::api_key::{ApiKey, ApiKeyId};
+use crate::shared::errors::RepositoryError;
+
+/// Columns selected for an API key row, shared by every query returning API keys
+macro_rules! api_key_columns {
+    () => {
+        "id, name, owner, prefix, key_hash, scopes, expires_at, last_used_at, revoked_at, created_by, created_at"
+    };
+}
+
+/// Database row representation for api_keys table
+#[derive(Debug, sqlx::FromRow)]
+struct ApiKeyRow {
+    id: Uuid,
+    name: String,
+    owner: String,
+    prefix: String,
+    key_hash: String,
+    scopes: serde_json::Value,
+    expires_at: Option<DateTime<Utc>>,
+    last_used_at: Option<DateTime<Utc>>,
+    revoked_at: Option<DateTime<Utc>>,
+    created_by: String,
+    created_at: DateTime<Utc>,
+}
+
+impl TryFrom<ApiKeyRow> for ApiKey {
+    type Error = RepositoryError;
+
+    fn try_from(row: ApiKeyRow) -> Result<Self, Self::Error> {
+        let scopes: Vec<String> = serde_json::from_value(row.scopes)
+            .map_err(|e| RepositoryError::Mapping(format!("Failed to parse scopes: {e}")))?;
+
+        Ok(ApiKey::restore(
+            ApiKeyId::from_uuid(row.id),
+            row.name,
+            row.owner,
+            row.prefix,
+            row.key_hash,
+            scopes,
+            row.expires_at,
+            row.last_used_at,
+            row.revoked_at,
+            row.created_by,
+            row.created_at,
+        ))
+    }
+}
+
+/// PostgreSQL implementation of ApiKeyRepository
+pub struct PostgresApiKeyRepository {
+    pool: PgPool,
+}
+
+impl PostgresApiKeyRepository {
+    /// Create a new PostgresApiKeyRepository
+    #[must_use]
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl ApiKeyRepository for PostgresApiKeyRepository {
+    async fn find_by_id(&self, id: &ApiKeyId) -> Result<Option<ApiKey>, RepositoryError> {
+        let row =
+            sqlx::query_as::<_, ApiKeyRow>(concat!("SELECT ", api_key_columns!(), " FROM api_keys WHERE id = $1"))
+                .bind(id.as_uuid())
+                .fetch_optional(&self.pool)
+                .await?;
+
+        row.map(TryInto::try_into).transpose()
+    }
+
+    async fn find_by_hash(&self, key_hash: &str) -> Result<Option<ApiKey>, RepositoryError> {
+        let row = sqlx::query_as::<_, ApiKeyRow>(concat!(
+            "SELECT ",
+            api_key_columns!(),
+            " FROM api_keys WHERE key_hash = $1"
+        ))
+        .bind(key_hash)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        row.map(TryInto::try_into).transpose()
+    }
+
+    async fn find_all(&self) -> Result<Vec<ApiKey>, RepositoryError> {
+        let rows = sqlx::query_as::<_, ApiKeyRow>(concat!(
+            "SELECT ",
+            api_key_columns!(),
+            " FROM api_keys ORDER BY created_at ASC"
+        ))
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.into_iter().map(TryInto::try_into).collect()
+    }
+
+    async fn create(&self, api_key: &ApiKey) -> Result<ApiKey, RepositoryError> {
+        let scopes = serde_json::to_value(api_key.scopes())
+            .map_err(|e| RepositoryError::Mapping(format!("Failed to serialize scopes: {e}")))?;
+
+        let row = sqlx::query_as::<_, ApiKeyRow>(concat!(
+            "INSERT INTO api_keys (",
+            api_key_columns!(),
+            ") VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11) RETURNING ",
+            api_key_columns!()
+        ))
+        .bind(api_key.id().as_uuid())
+        .bind(api_key.name())
+        .bind(api_key.owner())
+        .bind(api_key.prefix())
+        .bind(api_key.key_hash())
+        .bind(scopes)
+        .bind(api_key.expires_at())
+        .bind(api_key.last_used_at())
+        .bind(api_key.revoked_at())
+        .bind(api_key.created_by())
+        .bind(api_key.created_at())
+        .fetch_one(&self.pool)
+        .await?;
+
+        row.try_into()
+    }
+
+    async fn revoke(&self, id: &ApiKeyId, revoked_at: DateTime<Utc>) -> Result<Option<ApiKey>, RepositoryError> {
+        let row = sqlx::query_as::<_, ApiKeyRow>(concat!(
+            "UPDATE api_keys SET revoked_at = COALESCE(revoked_at, $2) WHERE id = $1 RETURNING ",
+            api_key_columns!()
+        ))
+        .bind(id.as_uuid())
+        .bind(revoked_at)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        row.map(TryInto::try_into).transpose()
+    }
+
+    async fn record_use(&self, id: &ApiKeyId, used_at: DateTime<Utc>) -> Result<(), RepositoryError> {
+        sqlx::query("UPDATE api_keys SET last_used_at = GREATEST(last_used_at, $2) WHERE id = $1")
+            .bind(id.as_uuid())
+            .bind(used_at)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+}
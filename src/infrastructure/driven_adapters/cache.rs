@@ -0,0 +1,286 @@
+//! In-Memory TTL Cache
+//!
+//! Bounded map shared by the read-through repository decorators. Entries
+//! expire after a TTL, and the oldest are evicted first when the map is full.
+//!
+//! Every invalidation bumps a generation read along with each miss, so that
+//! a value loaded while a write was in flight is not cached over it.
+
+use std::collections::HashMap;
+use std::future::Future;
+use std::hash::Hash;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Mutex, MutexGuard, PoisonError};
+use std::time::{Duration, Instant};
+
+/// Snapshot of the cache counters since startup
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CacheStats {
+    pub hits: u64,
+    pub misses: u64,
+    /// Entries dropped to make room for new ones
+    pub evictions: u64,
+    pub invalidations: u64,
+    /// Entries currently cached, expired ones included until looked up or evicted
+    pub entries: usize,
+}
+
+impl CacheStats {
+    /// Share of lookups served from the cache, between 0 and 1
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn hit_ratio(&self) -> f64 {
+        let lookups = self.hits + self.misses;
+        if lookups == 0 {
+            0.0
+        } else {
+            self.hits as f64 / lookups as f64
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Entry<V> {
+    value: V,
+    expires_at: Instant,
+    /// Insertion order, the lowest being evicted first
+    seq: u64,
+}
+
+#[derive(Debug)]
+struct CacheState<K, V> {
+    entries: HashMap<K, Entry<V>>,
+    generation: u64,
+    next_seq: u64,
+}
+
+/// Map of values expiring after a TTL, holding a bounded number of them
+#[derive(Debug)]
+pub struct TtlCache<K, V> {
+    ttl: Duration,
+    max_entries: usize,
+    state: Mutex<CacheState<K, V>>,
+    hits: AtomicU64,
+    misses: AtomicU64,
+    evictions: AtomicU64,
+    invalidations: AtomicU64,
+}
+
+impl<K, V> TtlCache<K, V>
+where
+    K: Eq + Hash + Clone,
+    V: Clone,
+{
+    /// Cache values for `ttl`, holding at most `max_entries`
+    #[must_use]
+    pub fn new(ttl: Duration, max_entries: usize) -> Self {
+        Self {
+            ttl,
+            max_entries: max_entries.max(1),
+            state: Mutex::new(CacheState {
+                entries: HashMap::new(),
+                generation: 0,
+                next_seq: 0,
+            }),
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+            evictions: AtomicU64::new(0),
+            invalidations: AtomicU64::new(0),
+        }
+    }
+
+    /// Get the cache counters
+    #[must_use]
+    pub fn stats(&self) -> CacheStats {
+        CacheStats {
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+            evictions: self.evictions.load(Ordering::Relaxed),
+            invalidations: self.invalidations.load(Ordering::Relaxed),
+            entries: self.lock().entries.len(),
+        }
+    }
+
+    /// Get the value cached for `key`, or load and cache it
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of `load`, caching nothing.
+    pub async fn get_or_load<F, E>(&self, key: K, load: F) -> Result<V, E>
+    where
+        F: Future<Output = Result<V, E>>,
+    {
+        let generation = match self.lookup(&key) {
+            Ok(value) => return Ok(value),
+            Err(generation) => generation,
+        };
+
+        let value = load.await?;
+        self.store(key, value.clone(), generation);
+        Ok(value)
+    }
+
+    /// Get the value cached for `key`, or load it and cache it if found
+    ///
+    /// Missing values are not cached, so they are loaded again on every lookup.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of `load`, caching nothing.
+    pub async fn get_or_load_found<F, E>(&self, key: K, load: F) -> Result<Option<V>, E>
+    where
+        F: Future<Output = Result<Option<V>, E>>,
+    {
+        let generation = match self.lookup(&key) {
+            Ok(value) => return Ok(Some(value)),
+            Err(generation) => generation,
+        };
+
+        let value = load.await?;
+        if let Some(value) = &value {
+            self.store(key, value.clone(), generation);
+        }
+        Ok(value)
+    }
+
+    /// Drop the entry of `key`
+    pub fn invalidate(&self, key: &K) {
+        self.invalidate_where(|k, _| k == key);
+    }
+
+    /// Drop the entries matching `predicate`
+    pub fn invalidate_where(&self, mut predicate: impl FnMut(&K, &V) -> bool) {
+        let mut state = self.lock();
+        state.entries.retain(|key, entry| !predicate(key, &entry.value));
+        state.generation += 1;
+        self.invalidations.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Drop every entry
+    pub fn invalidate_all(&self) {
+        self.invalidate_where(|_, _| true);
+    }
+
+    fn lock(&self) -> MutexGuard<'_, CacheState<K, V>> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Look up a live entry, or get the generation to cache the value read instead
+    fn lookup(&self, key: &K) -> Result<V, u64> {
+        let mut state = self.lock();
+        match state.entries.get(key) {
+            Some(entry) if entry.expires_at > Instant::now() => {
+                self.hits.fetch_add(1, Ordering::Relaxed);
+                Ok(entry.value.clone())
+            }
+            expired => {
+                if expired.is_some() {
+                    state.entries.remove(key);
+                }
+                self.misses.fetch_add(1, Ordering::Relaxed);
+                Err(state.generation)
+            }
+        }
+    }
+
+    /// Cache a value read at `generation`, unless something was invalidated since
+    fn store(&self, key: K, value: V, generation: u64) {
+        let mut state = self.lock();
+        if state.generation != generation || self.ttl.is_zero() {
+            return;
+        }
+
+        if !state.entries.contains_key(&key) && state.entries.len() >= self.max_entries {
+            let now = Instant::now();
+            state.entries.retain(|_, entry| entry.expires_at > now);
+            while state.entries.len() >= self.max_entries {
+                let Some(oldest) = state
+                    .entries
+                    .iter()
+                    .min_by_key(|(_, entry)| entry.seq)
+                    .map(|(k, _)| k.clone())
+                else {
+                    break;
+                };
+                state.entries.remove(&oldest);
+                self.evictions.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+
+        let seq = state.next_seq;
+        state.next_seq += 1;
+        state.entries.insert(
+            key,
+            Entry {
+                value,
+                expires_at: Instant::now() + self.ttl,
+                seq,
+            },
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::convert::Infallible;
+
+    async fn load(cache: &TtlCache<u32, u32>, key: u32, loads: &AtomicU64) -> u32 {
+        cache
+            .get_or_load(key, async {
+                loads.fetch_add(1, Ordering::Relaxed);
+                Ok::<_, Infallible>(key * 10)
+            })
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_values_are_loaded_once_until_evicted() {
+        let cache = TtlCache::new(Duration::from_secs(60), 2);
+        let loads = AtomicU64::new(0);
+
+        for key in [1, 2, 3, 3] {
+            assert_eq!(load(&cache, key, &loads).await, key * 10);
+        }
+        assert_eq!(loads.load(Ordering::Relaxed), 3);
+
+        // The first value was evicted, the last one is still cached
+        load(&cache, 1, &loads).await;
+        assert_eq!(loads.load(Ordering::Relaxed), 4);
+        let stats = cache.stats();
+        assert_eq!((stats.hits, stats.misses, stats.evictions, stats.entries), (1, 4, 2, 2));
+    }
+
+    #[tokio::test]
+    async fn test_missing_and_expired_values_are_not_cached() {
+        let cache = TtlCache::<u32, u32>::new(Duration::from_secs(60), 10);
+        for _ in 0..2 {
+            let found = cache.get_or_load_found(1, async { Ok::<_, Infallible>(None) }).await;
+            assert_eq!(found, Ok(None));
+        }
+        assert_eq!(cache.stats().misses, 2);
+
+        let cache = TtlCache::new(Duration::ZERO, 10);
+        let loads = AtomicU64::new(0);
+        load(&cache, 1, &loads).await;
+        load(&cache, 1, &loads).await;
+        assert_eq!(loads.load(Ordering::Relaxed), 2);
+        assert_eq!(cache.stats().entries, 0);
+    }
+
+    #[tokio::test]
+    async fn test_values_loaded_during_an_invalidation_are_not_cached() {
+        let cache = TtlCache::new(Duration::from_secs(60), 10);
+        let value = cache
+            .get_or_load(1, async {
+                cache.invalidate(&1);
+                Ok::<_, Infallible>(10)
+            })
+            .await;
+
+        assert_eq!(value, Ok(10));
+        assert_eq!(cache.stats().entries, 0);
+        assert_eq!(cache.stats().invalidations, 1);
+    }
+}
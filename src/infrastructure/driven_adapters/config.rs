@@ -8,9 +8,16 @@
 
 use config::{Config, ConfigError, Environment, File};
 use serde::Deserialize;
+use std::collections::HashMap;
 use std::fmt;
+use std::time::Duration;
 use zeroize::{Zeroize, ZeroizeOnDrop};
 
+use crate::domain::models::outbox::OutboxRetryPolicy;
+use crate::domain::models::service_account::ServiceAccount;
+use crate::domain::models::url_policy::UrlPolicy;
+use crate::domain::models::webhook::WebhookRetryPolicy;
+
 /// Server configuration
 #[derive(Debug, Clone, Deserialize)]
 pub struct ServerConfig {
@@ -94,6 +101,562 @@ impl Default for RateLimitConfig {
     }
 }
 
+/// SSRF-safe URL validation configuration
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct UrlPolicyConfig {
+    /// Reject plain `http://` RPC and explorer URLs
+    #[serde(default)]
+    pub require_https: bool,
+    /// Hosts or CIDR ranges exempt from the internal address checks (development only)
+    #[serde(default)]
+    pub allowlist: Vec<String>,
+}
+
+impl UrlPolicyConfig {
+    /// Build the domain URL policy, splitting the allowlist into host names and IP ranges
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if an allowlist entry looks like a CIDR range but cannot be parsed
+    pub fn to_policy(&self) -> Result<UrlPolicy, ConfigError> {
+        let mut policy = UrlPolicy {
+            require_https: self.require_https,
+            ..Default::default()
+        };
+
+        for entry in &self.allowlist {
+            if entry.contains('/') {
+                let network = entry
+                    .parse()
+                    .map_err(|_| ConfigError::Message(format!("Invalid url_policy allowlist range: {entry}")))?;
+                policy.allowed_networks.push(network);
+            } else {
+                policy.allowed_hosts.push(entry.clone());
+            }
+        }
+
+        Ok(policy)
+    }
+}
+
+/// Secrets configuration (not Clone to prevent secret exposure)
+#[derive(Debug, Default, Deserialize)]
+pub struct SecretsConfig {
+    /// Base64-encoded 256-bit key used to encrypt literal secrets at rest (unset = disabled)
+    #[serde(default)]
+    pub encryption_key: Option<SecretString>,
+    /// Values for `${NAME}` placeholders in RPC URLs (e.g., `APP__SECRETS__VALUES__ALCHEMY_KEY`)
+    #[serde(default)]
+    pub values: HashMap<String, SecretString>,
+}
+
+/// Permission allowing a caller to see unredacted RPC URL secrets
+pub const PERMISSION_SECRETS_READ: &str = "secrets:read";
+
+/// Permission allowing a caller to create, list and revoke API keys
+pub const PERMISSION_API_KEYS_MANAGE: &str = "api_keys:manage";
+
+/// Permission allowing a caller to list and revoke JWTs
+pub const PERMISSION_TOKENS_REVOKE: &str = "tokens:revoke";
+
+/// Scope allowing an API key to change networks, their lifecycle, relations and schedules
+pub const PERMISSION_NETWORKS_WRITE: &str = "networks:write";
+
+/// Scope allowing an API key to change the contracts of networks
+pub const PERMISSION_CONTRACTS_WRITE: &str = "contracts:write";
+
+/// Scope allowing an API key to change the signers of networks
+pub const PERMISSION_SIGNERS_WRITE: &str = "signers:write";
+
+/// Scope allowing an API key to change and import the tokens of networks
+pub const PERMISSION_TOKENS_WRITE: &str = "tokens:write";
+
+/// Scope allowing an API key to change webhooks and redeliver their events
+pub const PERMISSION_WEBHOOKS_WRITE: &str = "webhooks:write";
+
+/// Authorization configuration mapping JWT roles to permissions
+#[derive(Debug, Clone, Deserialize)]
+pub struct AuthorizationConfig {
+    /// Permissions granted to each role
+    #[serde(default)]
+    pub role_permissions: HashMap<String, Vec<String>>,
+}
+
+impl Default for AuthorizationConfig {
+    fn default() -> Self {
+        Self {
+            role_permissions: HashMap::from([(
+                "admin".to_string(),
+                vec![
+                    PERMISSION_SECRETS_READ.to_string(),
+                    PERMISSION_API_KEYS_MANAGE.to_string(),
+                    PERMISSION_TOKENS_REVOKE.to_string(),
+                    PERMISSION_NETWORKS_WRITE.to_string(),
+                    PERMISSION_CONTRACTS_WRITE.to_string(),
+                    PERMISSION_SIGNERS_WRITE.to_string(),
+                    PERMISSION_TOKENS_WRITE.to_string(),
+                    PERMISSION_WEBHOOKS_WRITE.to_string(),
+                ],
+            )]),
+        }
+    }
+}
+
+impl AuthorizationConfig {
+    /// Get the permissions granted to a role
+    #[must_use]
+    pub fn permissions_for(&self, role: &str) -> Vec<String> {
+        self.role_permissions.get(role).cloned().unwrap_or_default()
+    }
+}
+
+/// Lifecycle scheduler configuration
+#[derive(Debug, Clone, Deserialize)]
+pub struct SchedulerConfig {
+    /// Run the in-process scheduler applying scheduled lifecycle changes
+    #[serde(default = "default_scheduler_enabled")]
+    pub enabled: bool,
+    /// Seconds between checks for due schedules
+    #[serde(default = "default_scheduler_poll_interval_secs")]
+    pub poll_interval_secs: u64,
+    /// Maximum number of schedules applied per check
+    #[serde(default = "default_scheduler_batch_size")]
+    pub batch_size: u32,
+}
+
+fn default_scheduler_enabled() -> bool {
+    true
+}
+
+fn default_scheduler_poll_interval_secs() -> u64 {
+    30
+}
+
+fn default_scheduler_batch_size() -> u32 {
+    100
+}
+
+impl Default for SchedulerConfig {
+    fn default() -> Self {
+        Self {
+            enabled: default_scheduler_enabled(),
+            poll_interval_secs: default_scheduler_poll_interval_secs(),
+            batch_size: default_scheduler_batch_size(),
+        }
+    }
+}
+
+/// Network change event stream configuration
+#[derive(Debug, Clone, Deserialize)]
+pub struct EventsConfig {
+    /// Number of recent events kept for subscribers resuming with `Last-Event-ID`
+    #[serde(default = "default_events_replay_buffer_size")]
+    pub replay_buffer_size: usize,
+    /// Seconds between keep-alive comments on idle event streams
+    #[serde(default = "default_events_keep_alive_secs")]
+    pub keep_alive_secs: u64,
+    /// Seconds between re-checks of the credentials an event stream was opened with
+    #[serde(default = "default_events_reauthenticate_secs")]
+    pub reauthenticate_secs: u64,
+    /// Receive changes made by other replicas through Postgres `LISTEN`
+    #[serde(default = "default_events_listen")]
+    pub listen: bool,
+}
+
+fn default_events_replay_buffer_size() -> usize {
+    1024
+}
+
+fn default_events_keep_alive_secs() -> u64 {
+    15
+}
+
+fn default_events_reauthenticate_secs() -> u64 {
+    60
+}
+
+fn default_events_listen() -> bool {
+    true
+}
+
+impl Default for EventsConfig {
+    fn default() -> Self {
+        Self {
+            replay_buffer_size: default_events_replay_buffer_size(),
+            keep_alive_secs: default_events_keep_alive_secs(),
+            reauthenticate_secs: default_events_reauthenticate_secs(),
+            listen: default_events_listen(),
+        }
+    }
+}
+
+/// Webhook delivery configuration
+#[derive(Debug, Clone, Deserialize)]
+pub struct WebhooksConfig {
+    /// Run the in-process dispatcher sending webhook deliveries
+    #[serde(default = "default_webhooks_enabled")]
+    pub enabled: bool,
+    /// Seconds between checks for due deliveries
+    #[serde(default = "default_webhooks_poll_interval_secs")]
+    pub poll_interval_secs: u64,
+    /// Maximum number of deliveries sent per check
+    #[serde(default = "default_webhooks_batch_size")]
+    pub batch_size: u32,
+    /// Seconds to wait for a receiver to respond
+    #[serde(default = "default_webhooks_timeout_secs")]
+    pub timeout_secs: u64,
+    /// Attempts made before a delivery fails for good
+    #[serde(default = "default_webhooks_max_attempts")]
+    pub max_attempts: u32,
+    /// Seconds before the first retry, doubled for each further one
+    #[serde(default = "default_webhooks_retry_base_secs")]
+    pub retry_base_secs: u64,
+    /// Upper bound for the seconds between retries
+    #[serde(default = "default_webhooks_retry_max_secs")]
+    pub retry_max_secs: u64,
+}
+
+fn default_webhooks_enabled() -> bool {
+    true
+}
+
+fn default_webhooks_poll_interval_secs() -> u64 {
+    5
+}
+
+fn default_webhooks_batch_size() -> u32 {
+    50
+}
+
+fn default_webhooks_timeout_secs() -> u64 {
+    10
+}
+
+fn default_webhooks_max_attempts() -> u32 {
+    8
+}
+
+fn default_webhooks_retry_base_secs() -> u64 {
+    30
+}
+
+fn default_webhooks_retry_max_secs() -> u64 {
+    3600
+}
+
+impl Default for WebhooksConfig {
+    fn default() -> Self {
+        Self {
+            enabled: default_webhooks_enabled(),
+            poll_interval_secs: default_webhooks_poll_interval_secs(),
+            batch_size: default_webhooks_batch_size(),
+            timeout_secs: default_webhooks_timeout_secs(),
+            max_attempts: default_webhooks_max_attempts(),
+            retry_base_secs: default_webhooks_retry_base_secs(),
+            retry_max_secs: default_webhooks_retry_max_secs(),
+        }
+    }
+}
+
+impl WebhooksConfig {
+    /// Get the retry policy for failed deliveries
+    #[must_use]
+    pub fn retry_policy(&self) -> WebhookRetryPolicy {
+        WebhookRetryPolicy {
+            max_attempts: self.max_attempts,
+            base_delay: Duration::from_secs(self.retry_base_secs),
+            max_delay: Duration::from_secs(self.retry_max_secs),
+        }
+    }
+}
+
+/// Destination of events relayed from the outbox
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum OutboxSinkKind {
+    /// Structured log lines
+    Log,
+    /// Deliveries to the subscribed webhooks
+    Webhook,
+}
+
+/// Transactional outbox relay configuration
+#[derive(Debug, Clone, Deserialize)]
+pub struct OutboxConfig {
+    /// Run the in-process relay delivering outbox messages to the sinks
+    #[serde(default = "default_outbox_enabled")]
+    pub enabled: bool,
+    /// Seconds between checks for due messages
+    #[serde(default = "default_outbox_poll_interval_secs")]
+    pub poll_interval_secs: u64,
+    /// Maximum number of messages relayed per check
+    #[serde(default = "default_outbox_batch_size")]
+    pub batch_size: u32,
+    /// Seconds before the first retry, doubled for each further one
+    #[serde(default = "default_outbox_retry_base_secs")]
+    pub retry_base_secs: u64,
+    /// Upper bound for the seconds between retries
+    #[serde(default = "default_outbox_retry_max_secs")]
+    pub retry_max_secs: u64,
+    /// Hours published messages are kept before being purged
+    #[serde(default = "default_outbox_retention_hours")]
+    pub retention_hours: u64,
+    /// Sinks every message is relayed to
+    #[serde(default = "default_outbox_sinks")]
+    pub sinks: Vec<OutboxSinkKind>,
+}
+
+fn default_outbox_enabled() -> bool {
+    true
+}
+
+fn default_outbox_poll_interval_secs() -> u64 {
+    1
+}
+
+fn default_outbox_batch_size() -> u32 {
+    100
+}
+
+fn default_outbox_retry_base_secs() -> u64 {
+    5
+}
+
+fn default_outbox_retry_max_secs() -> u64 {
+    300
+}
+
+fn default_outbox_retention_hours() -> u64 {
+    168
+}
+
+fn default_outbox_sinks() -> Vec<OutboxSinkKind> {
+    vec![OutboxSinkKind::Webhook]
+}
+
+impl Default for OutboxConfig {
+    fn default() -> Self {
+        Self {
+            enabled: default_outbox_enabled(),
+            poll_interval_secs: default_outbox_poll_interval_secs(),
+            batch_size: default_outbox_batch_size(),
+            retry_base_secs: default_outbox_retry_base_secs(),
+            retry_max_secs: default_outbox_retry_max_secs(),
+            retention_hours: default_outbox_retention_hours(),
+            sinks: default_outbox_sinks(),
+        }
+    }
+}
+
+impl OutboxConfig {
+    /// Get the retry policy for messages not accepted by every sink
+    #[must_use]
+    pub fn retry_policy(&self) -> OutboxRetryPolicy {
+        OutboxRetryPolicy {
+            base_delay: Duration::from_secs(self.retry_base_secs),
+            max_delay: Duration::from_secs(self.retry_max_secs),
+        }
+    }
+}
+
+/// Network read cache configuration
+#[derive(Debug, Clone, Deserialize)]
+pub struct CacheConfig {
+    /// Cache network lookups by ID, slug and the active network list
+    #[serde(default = "default_cache_enabled")]
+    pub enabled: bool,
+    /// Seconds a cached network is served before being read again
+    #[serde(default = "default_cache_ttl_secs")]
+    pub ttl_secs: u64,
+    /// Maximum number of cached lookups of each kind, the oldest being evicted first
+    #[serde(default = "default_cache_max_entries")]
+    pub max_entries: usize,
+    /// Seconds between cache statistics log lines
+    #[serde(default = "default_cache_stats_log_interval_secs")]
+    pub stats_log_interval_secs: u64,
+}
+
+fn default_cache_enabled() -> bool {
+    true
+}
+
+fn default_cache_ttl_secs() -> u64 {
+    30
+}
+
+fn default_cache_max_entries() -> usize {
+    1000
+}
+
+fn default_cache_stats_log_interval_secs() -> u64 {
+    300
+}
+
+impl Default for CacheConfig {
+    fn default() -> Self {
+        Self {
+            enabled: default_cache_enabled(),
+            ttl_secs: default_cache_ttl_secs(),
+            max_entries: default_cache_max_entries(),
+            stats_log_interval_secs: default_cache_stats_log_interval_secs(),
+        }
+    }
+}
+
+/// HTTP caching configuration for read endpoints
+///
+/// Each value is sent as `Cache-Control` on its route, an empty one leaving
+/// the header out. Responses depend on the caller, so shared caches must
+/// not store them.
+#[derive(Debug, Clone, Deserialize)]
+pub struct HttpCacheConfig {
+    /// `GET /networks/:idOrSlug`
+    #[serde(default = "default_http_cache_control")]
+    pub network: String,
+    /// `GET /networks`
+    #[serde(default = "default_http_cache_control")]
+    pub network_list: String,
+}
+
+fn default_http_cache_control() -> String {
+    "private, no-cache".to_string()
+}
+
+impl Default for HttpCacheConfig {
+    fn default() -> Self {
+        Self {
+            network: default_http_cache_control(),
+            network_list: default_http_cache_control(),
+        }
+    }
+}
+
+/// Public read-only API configuration
+#[derive(Debug, Clone, Deserialize)]
+pub struct PublicApiConfig {
+    /// Serve the unauthenticated network endpoints under `/public`
+    #[serde(default)]
+    pub enabled: bool,
+    /// Maximum public requests per second per IP, on top of the authenticated limit
+    #[serde(default = "default_public_api_requests_per_second")]
+    pub requests_per_second: u32,
+    /// Burst size of the public rate limit
+    #[serde(default = "default_public_api_burst_size")]
+    pub burst_size: u32,
+    /// `Cache-Control` of the public responses, which shared caches may store
+    #[serde(default = "default_public_api_cache_control")]
+    pub cache_control: String,
+}
+
+fn default_public_api_requests_per_second() -> u32 {
+    2
+}
+
+fn default_public_api_burst_size() -> u32 {
+    10
+}
+
+fn default_public_api_cache_control() -> String {
+    "public, max-age=60".to_string()
+}
+
+impl Default for PublicApiConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            requests_per_second: default_public_api_requests_per_second(),
+            burst_size: default_public_api_burst_size(),
+            cache_control: default_public_api_cache_control(),
+        }
+    }
+}
+
+/// JWT revocation list configuration
+#[derive(Debug, Clone, Deserialize)]
+pub struct JwtRevocationConfig {
+    /// Seconds a revocation found is cached, delaying a subject's later cutoff made on other replicas
+    #[serde(default = "default_jwt_revocation_cache_ttl_secs")]
+    pub cache_ttl_secs: u64,
+    /// Maximum number of cached revocations of each kind, the oldest being evicted first
+    #[serde(default = "default_jwt_revocation_cache_max_entries")]
+    pub cache_max_entries: usize,
+}
+
+fn default_jwt_revocation_cache_ttl_secs() -> u64 {
+    10
+}
+
+fn default_jwt_revocation_cache_max_entries() -> usize {
+    10_000
+}
+
+impl Default for JwtRevocationConfig {
+    fn default() -> Self {
+        Self {
+            cache_ttl_secs: default_jwt_revocation_cache_ttl_secs(),
+            cache_max_entries: default_jwt_revocation_cache_max_entries(),
+        }
+    }
+}
+
+/// A service account allowed to obtain tokens
+#[derive(Debug, Clone, Deserialize)]
+pub struct ServiceAccountConfig {
+    /// Hex-encoded SHA-256 hash of the client secret
+    pub secret_hash: String,
+    /// Role carried by the account's tokens
+    pub role: String,
+    /// Email carried by the account's tokens, the client ID when unset
+    #[serde(default)]
+    pub email: Option<String>,
+}
+
+/// Token issuance configuration
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct TokenIssuerConfig {
+    /// Serve `POST /auth/token`, exchanging service account credentials for JWTs
+    #[serde(default)]
+    pub endpoint_enabled: bool,
+    /// Service accounts by client ID
+    #[serde(default)]
+    pub service_accounts: HashMap<String, ServiceAccountConfig>,
+}
+
+impl TokenIssuerConfig {
+    /// Get the configured service accounts
+    #[must_use]
+    pub fn service_accounts(&self) -> Vec<ServiceAccount> {
+        self.service_accounts
+            .iter()
+            .map(|(client_id, account)| {
+                ServiceAccount::new(
+                    client_id.clone(),
+                    account.secret_hash.clone(),
+                    account.role.clone(),
+                    account.email.clone(),
+                )
+            })
+            .collect()
+    }
+
+    /// Check that every service account has a SHA-256 secret hash and a role
+    fn validate(&self) -> Result<(), ConfigError> {
+        for (client_id, account) in &self.service_accounts {
+            if account.secret_hash.len() != 64 || !account.secret_hash.chars().all(|c| c.is_ascii_hexdigit()) {
+                return Err(ConfigError::Message(format!(
+                    "token_issuer.service_accounts.{client_id}.secret_hash must be a hex-encoded SHA-256 hash"
+                )));
+            }
+            if account.role.trim().is_empty() {
+                return Err(ConfigError::Message(format!(
+                    "token_issuer.service_accounts.{client_id}.role must not be empty"
+                )));
+            }
+        }
+        Ok(())
+    }
+}
+
 /// Application configuration (not Clone due to sensitive JWT config)
 #[derive(Debug, Deserialize)]
 pub struct AppConfig {
@@ -102,6 +665,30 @@ pub struct AppConfig {
     pub jwt: JwtConfig,
     #[serde(default)]
     pub rate_limit: RateLimitConfig,
+    #[serde(default)]
+    pub url_policy: UrlPolicyConfig,
+    #[serde(default)]
+    pub secrets: SecretsConfig,
+    #[serde(default)]
+    pub authorization: AuthorizationConfig,
+    #[serde(default)]
+    pub scheduler: SchedulerConfig,
+    #[serde(default)]
+    pub events: EventsConfig,
+    #[serde(default)]
+    pub webhooks: WebhooksConfig,
+    #[serde(default)]
+    pub outbox: OutboxConfig,
+    #[serde(default)]
+    pub cache: CacheConfig,
+    #[serde(default)]
+    pub http_cache: HttpCacheConfig,
+    #[serde(default)]
+    pub public_api: PublicApiConfig,
+    #[serde(default)]
+    pub jwt_revocation: JwtRevocationConfig,
+    #[serde(default)]
+    pub token_issuer: TokenIssuerConfig,
 }
 
 /// Minimum required length for JWT secret
@@ -116,6 +703,15 @@ impl AppConfig {
     /// - Configuration files cannot be loaded
     /// - JWT secret is not provided or too short
     /// - Database URL is not provided
+    /// - Scheduler poll interval or batch size is zero
+    /// - Event replay buffer size or keep-alive interval is zero
+    /// - Webhook poll interval, batch size, timeout, attempts or retry delays are zero
+    /// - Outbox poll interval, batch size, retry delays or retention are zero
+    /// - Cache TTL, size or statistics log interval is zero
+    /// - A `Cache-Control` value is not a valid header value
+    /// - Public API rate limit or burst size is zero
+    /// - JWT revocation cache size is zero
+    /// - A service account secret hash is not a hex-encoded SHA-256 hash, or its role is empty
     pub fn load() -> Result<Self, ConfigError> {
         let run_mode = std::env::var("RUN_MODE").unwrap_or_else(|_| "default".into());
 
@@ -151,6 +747,83 @@ impl AppConfig {
             ));
         }
 
+        if config.scheduler.poll_interval_secs == 0 || config.scheduler.batch_size == 0 {
+            return Err(ConfigError::Message(
+                "scheduler.poll_interval_secs and scheduler.batch_size must be greater than zero".to_string(),
+            ));
+        }
+
+        let events = &config.events;
+        if events.replay_buffer_size == 0 || events.keep_alive_secs == 0 || events.reauthenticate_secs == 0 {
+            return Err(ConfigError::Message(
+                "events.replay_buffer_size, keep_alive_secs and reauthenticate_secs must be greater than zero"
+                    .to_string(),
+            ));
+        }
+
+        let webhooks = &config.webhooks;
+        if webhooks.poll_interval_secs == 0
+            || webhooks.batch_size == 0
+            || webhooks.timeout_secs == 0
+            || webhooks.max_attempts == 0
+            || webhooks.retry_base_secs == 0
+            || webhooks.retry_max_secs == 0
+        {
+            return Err(ConfigError::Message(
+                "webhooks.poll_interval_secs, batch_size, timeout_secs, max_attempts, retry_base_secs and \
+                 retry_max_secs must be greater than zero"
+                    .to_string(),
+            ));
+        }
+
+        let outbox = &config.outbox;
+        if outbox.poll_interval_secs == 0
+            || outbox.batch_size == 0
+            || outbox.retry_base_secs == 0
+            || outbox.retry_max_secs == 0
+            || outbox.retention_hours == 0
+        {
+            return Err(ConfigError::Message(
+                "outbox.poll_interval_secs, batch_size, retry_base_secs, retry_max_secs and retention_hours must \
+                 be greater than zero"
+                    .to_string(),
+            ));
+        }
+
+        let cache = &config.cache;
+        if cache.ttl_secs == 0 || cache.max_entries == 0 || cache.stats_log_interval_secs == 0 {
+            return Err(ConfigError::Message(
+                "cache.ttl_secs, max_entries and stats_log_interval_secs must be greater than zero".to_string(),
+            ));
+        }
+
+        let http_cache = &config.http_cache;
+        for (name, value) in [
+            ("http_cache.network", &http_cache.network),
+            ("http_cache.network_list", &http_cache.network_list),
+            ("public_api.cache_control", &config.public_api.cache_control),
+        ] {
+            if axum::http::HeaderValue::from_str(value).is_err() {
+                return Err(ConfigError::Message(format!(
+                    "{name} is not a valid Cache-Control value"
+                )));
+            }
+        }
+
+        if config.public_api.requests_per_second == 0 || config.public_api.burst_size == 0 {
+            return Err(ConfigError::Message(
+                "public_api.requests_per_second and burst_size must be greater than zero".to_string(),
+            ));
+        }
+
+        if config.jwt_revocation.cache_max_entries == 0 {
+            return Err(ConfigError::Message(
+                "jwt_revocation.cache_max_entries must be greater than zero".to_string(),
+            ));
+        }
+
+        config.token_issuer.validate()?;
+
         // Warn if no CORS origins configured (likely development)
         if config.server.allowed_origins.is_empty() {
             eprintln!("WARNING: No CORS allowed_origins configured. Using restrictive defaults.");
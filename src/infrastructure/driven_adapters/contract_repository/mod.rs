@@ -0,0 +1,5 @@
+//! Contract Repository Implementations
+
+mod postgres;
+
+pub use postgres::PostgresContractRepository;
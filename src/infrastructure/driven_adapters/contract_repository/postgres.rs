@@ -0,0 +1,189 @@
+//! PostgreSQL Contract Repository Implementation
+//!
+//! Implements the ContractRepository trait using SQLx for PostgreSQL.
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::domain::gateways::ContractRepository;
+use crate::domain::models::contract::{ContractId, NetworkContract};
+use crate::domain::models::network::NetworkId;
+use crate::shared::errors::RepositoryError;
+
+/// Columns selected for a contract row, shared by every query returning contracts
+macro_rules! contract_columns {
+    () => {
+        "id, network_id, name, address, deployment_block, abi_ref, tags, created_at, updated_at"
+    };
+}
+
+/// Database row representation for network_contracts table
+#[derive(Debug, sqlx::FromRow)]
+struct ContractRow {
+    id: Uuid,
+    network_id: Uuid,
+    name: String,
+    address: String,
+    deployment_block: Option<i64>,
+    abi_ref: Option<String>,
+    tags: Vec<String>,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl From<ContractRow> for NetworkContract {
+    fn from(row: ContractRow) -> Self {
+        NetworkContract::restore(
+            ContractId::from_uuid(row.id),
+            NetworkId::from_uuid(row.network_id),
+            row.name,
+            row.address,
+            row.deployment_block,
+            row.abi_ref,
+            row.tags,
+            row.created_at,
+            row.updated_at,
+        )
+    }
+}
+
+/// Map a unique violation on the per-network name index to a conflict
+fn map_name_conflict(error: sqlx::Error) -> RepositoryError {
+    if let sqlx::Error::Database(db_err) = &error {
+        if db_err.code().is_some_and(|c| c == "23505") {
+            return RepositoryError::UniqueViolation("contract name".to_string());
+        }
+    }
+    RepositoryError::from(error)
+}
+
+/// PostgreSQL implementation of ContractRepository
+pub struct PostgresContractRepository {
+    pool: PgPool,
+}
+
+impl PostgresContractRepository {
+    /// Create a new PostgresContractRepository
+    #[must_use]
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl ContractRepository for PostgresContractRepository {
+    async fn find_by_id(&self, id: &ContractId) -> Result<Option<NetworkContract>, RepositoryError> {
+        let row = sqlx::query_as::<_, ContractRow>(concat!(
+            "SELECT ",
+            contract_columns!(),
+            " FROM network_contracts WHERE id = $1"
+        ))
+        .bind(id.as_uuid())
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(row.map(NetworkContract::from))
+    }
+
+    async fn find_by_network(&self, network_id: &NetworkId) -> Result<Vec<NetworkContract>, RepositoryError> {
+        let rows = sqlx::query_as::<_, ContractRow>(concat!(
+            "SELECT ",
+            contract_columns!(),
+            " FROM network_contracts WHERE network_id = $1 ORDER BY LOWER(name) ASC"
+        ))
+        .bind(network_id.as_uuid())
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(NetworkContract::from).collect())
+    }
+
+    async fn find_by_name(&self, name: &str) -> Result<Vec<NetworkContract>, RepositoryError> {
+        let rows = sqlx::query_as::<_, ContractRow>(concat!(
+            "SELECT ",
+            contract_columns!(),
+            " FROM network_contracts WHERE LOWER(name) = LOWER($1)"
+        ))
+        .bind(name)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(NetworkContract::from).collect())
+    }
+
+    async fn create(&self, contract: &NetworkContract) -> Result<NetworkContract, RepositoryError> {
+        let row = sqlx::query_as::<_, ContractRow>(concat!(
+            "INSERT INTO network_contracts (",
+            contract_columns!(),
+            ") VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9) RETURNING ",
+            contract_columns!()
+        ))
+        .bind(contract.id().as_uuid())
+        .bind(contract.network_id().as_uuid())
+        .bind(contract.name())
+        .bind(contract.address())
+        .bind(contract.deployment_block())
+        .bind(contract.abi_ref())
+        .bind(contract.tags())
+        .bind(contract.created_at())
+        .bind(contract.updated_at())
+        .fetch_one(&self.pool)
+        .await
+        .map_err(map_name_conflict)?;
+
+        Ok(NetworkContract::from(row))
+    }
+
+    async fn update(&self, contract: &NetworkContract) -> Result<Option<NetworkContract>, RepositoryError> {
+        let row = sqlx::query_as::<_, ContractRow>(concat!(
+            "UPDATE network_contracts \
+             SET name = $2, address = $3, deployment_block = $4, abi_ref = $5, tags = $6, updated_at = $7 \
+             WHERE id = $1 RETURNING ",
+            contract_columns!()
+        ))
+        .bind(contract.id().as_uuid())
+        .bind(contract.name())
+        .bind(contract.address())
+        .bind(contract.deployment_block())
+        .bind(contract.abi_ref())
+        .bind(contract.tags())
+        .bind(contract.updated_at())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(map_name_conflict)?;
+
+        Ok(row.map(NetworkContract::from))
+    }
+
+    async fn delete(&self, id: &ContractId) -> Result<bool, RepositoryError> {
+        let result = sqlx::query("DELETE FROM network_contracts WHERE id = $1")
+            .bind(id.as_uuid())
+            .execute(&self.pool)
+            .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn exists_by_name(
+        &self,
+        network_id: &NetworkId,
+        name: &str,
+        exclude_id: Option<&ContractId>,
+    ) -> Result<bool, RepositoryError> {
+        let exists: bool = sqlx::query_scalar(
+            "SELECT EXISTS(\
+                 SELECT 1 FROM network_contracts \
+                 WHERE network_id = $1 AND LOWER(name) = LOWER($2) AND ($3::uuid IS NULL OR id != $3)\
+             )",
+        )
+        .bind(network_id.as_uuid())
+        .bind(name)
+        .bind(exclude_id.map(ContractId::as_uuid))
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(exists)
+    }
+}
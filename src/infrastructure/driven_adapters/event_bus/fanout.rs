@@ -0,0 +1,98 @@
+//! Fan-out Event Publisher
+//!
+//! Publishes every network event to several publishers in turn, e.g. the
+//! in-process event bus and the webhook queue.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::domain::gateways::NetworkEventPublisher;
+use crate::domain::models::event::NetworkEvent;
+
+/// Publisher forwarding events to each of its publishers
+pub struct FanoutEventPublisher {
+    publishers: Vec<Arc<dyn NetworkEventPublisher>>,
+}
+
+impl FanoutEventPublisher {
+    /// Create a publisher forwarding to `publishers`, in order
+    #[must_use]
+    pub fn new(publishers: Vec<Arc<dyn NetworkEventPublisher>>) -> Self {
+        Self { publishers }
+    }
+}
+
+#[async_trait]
+impl NetworkEventPublisher for FanoutEventPublisher {
+    async fn publish(&self, event: NetworkEvent) {
+        for publisher in &self.publishers {
+            publisher.publish(event.clone()).await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::models::event::NetworkEventKind;
+    use crate::domain::models::explorer::ExplorerMetadata;
+    use crate::domain::models::fee_config::FeeConfig;
+    use crate::domain::models::finality::FinalityPolicy;
+    use crate::domain::models::labels::NetworkMetadata;
+    use crate::domain::models::lifecycle::NetworkStatus;
+    use crate::domain::models::network::{CreateNetworkData, Network};
+    use crate::domain::models::testnet::TestnetInfo;
+    use crate::domain::models::url_policy::UrlPolicy;
+    use rust_decimal_macros::dec;
+    use std::sync::Mutex;
+
+    #[derive(Default)]
+    struct MockEventPublisher {
+        events: Mutex<Vec<NetworkEventKind>>,
+    }
+
+    #[async_trait]
+    impl NetworkEventPublisher for MockEventPublisher {
+        async fn publish(&self, event: NetworkEvent) {
+            self.events.lock().unwrap().push(event.kind);
+        }
+    }
+
+    fn create_test_network() -> Network {
+        let data = CreateNetworkData {
+            chain_id: 1,
+            name: "Ethereum Mainnet".to_string(),
+            rpc_url: "https://rpc.example.com".to_string(),
+            other_rpc_urls: vec![],
+            test_net: false,
+            block_explorer_url: "https://explorer.example.com".to_string(),
+            fee_multiplier: dec!(1.0),
+            gas_limit_multiplier: dec!(1.2),
+            default_signer_address: "0x742d35Cc6634C0532925a3b844Bc9e7595f1dEaD".to_string(),
+            explorer: ExplorerMetadata::default(),
+            fee_config: FeeConfig::default(),
+            finality: FinalityPolicy::default(),
+            status: NetworkStatus::Active,
+            parent: None,
+            tags: vec![],
+            metadata: NetworkMetadata::new(),
+            slug: None,
+            aliases: vec![],
+            testnet: TestnetInfo::default(),
+        };
+        Network::new(data, &UrlPolicy::default()).expect("valid test data")
+    }
+
+    #[tokio::test]
+    async fn test_publishes_to_every_publisher() {
+        let first = Arc::new(MockEventPublisher::default());
+        let second = Arc::new(MockEventPublisher::default());
+        let fanout = FanoutEventPublisher::new(vec![first.clone(), second.clone()]);
+
+        fanout.publish(NetworkEvent::created(create_test_network())).await;
+
+        assert_eq!(*first.events.lock().unwrap(), [NetworkEventKind::Created]);
+        assert_eq!(*second.events.lock().unwrap(), [NetworkEventKind::Created]);
+    }
+}
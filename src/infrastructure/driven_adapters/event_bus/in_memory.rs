@@ -0,0 +1,200 @@
+//! In-Memory Event Bus
+//!
+//! Broadcasts network events to the subscribers of this process and keeps
+//! the most recent ones so that a subscriber can resume after a reconnect.
+
+use std::collections::VecDeque;
+use std::sync::{Mutex, MutexGuard, PoisonError};
+
+use async_trait::async_trait;
+use chrono::Utc;
+use tokio::sync::broadcast;
+use tokio_stream::wrappers::BroadcastStream;
+use tokio_stream::StreamExt;
+
+use crate::domain::gateways::network_events::{EventSubscription, NetworkEventPublisher, NetworkEventStream};
+use crate::domain::models::event::{NetworkEvent, SequencedEvent};
+
+/// Event bus backed by a broadcast channel and a replay buffer
+pub struct InMemoryEventBus {
+    buffer: Mutex<ReplayBuffer>,
+    sender: broadcast::Sender<SequencedEvent>,
+    capacity: usize,
+}
+
+/// Most recent events and the ID of the next one
+struct ReplayBuffer {
+    next_id: u64,
+    events: VecDeque<SequencedEvent>,
+}
+
+impl InMemoryEventBus {
+    /// Create a bus retaining the last `capacity` events
+    ///
+    /// IDs start at the current time in microseconds, so they keep increasing
+    /// across restarts and a stale `Last-Event-ID` is detected as a gap.
+    #[must_use]
+    pub fn new(capacity: usize) -> Self {
+        let capacity = capacity.max(1);
+        let (sender, _) = broadcast::channel(capacity);
+        Self {
+            buffer: Mutex::new(ReplayBuffer {
+                next_id: u64::try_from(Utc::now().timestamp_micros()).unwrap_or_default(),
+                events: VecDeque::with_capacity(capacity),
+            }),
+            sender,
+            capacity,
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, ReplayBuffer> {
+        self.buffer.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+#[async_trait]
+impl NetworkEventPublisher for InMemoryEventBus {
+    async fn publish(&self, event: NetworkEvent) {
+        // Sequencing and sending under the lock keeps replay and live delivery gap-free
+        let mut buffer = self.lock();
+        let sequenced = SequencedEvent {
+            id: buffer.next_id,
+            event,
+        };
+        buffer.next_id += 1;
+        if buffer.events.len() == self.capacity {
+            buffer.events.pop_front();
+        }
+        buffer.events.push_back(sequenced.clone());
+
+        tracing::debug!(
+            event_id = sequenced.id,
+            kind = %sequenced.event.kind,
+            network_id = %sequenced.event.network.id(),
+            "Publishing network event"
+        );
+        // An error only means nobody is subscribed
+        let _ = self.sender.send(sequenced);
+    }
+}
+
+impl NetworkEventStream for InMemoryEventBus {
+    fn subscribe(&self, after: Option<u64>) -> EventSubscription {
+        let buffer = self.lock();
+        let receiver = self.sender.subscribe();
+
+        let oldest = buffer.events.front().map_or(buffer.next_id, |event| event.id);
+        let (missed, replay): (bool, Vec<SequencedEvent>) = match after {
+            None => (false, vec![]),
+            Some(after) if after.saturating_add(1) < oldest || after >= buffer.next_id => (true, vec![]),
+            Some(after) => (false, buffer.events.iter().filter(|e| e.id > after).cloned().collect()),
+        };
+        drop(buffer);
+
+        // A lagging receiver ends the stream rather than silently skipping events
+        let live = BroadcastStream::new(receiver).map_while(Result::ok);
+        EventSubscription {
+            missed,
+            events: Box::pin(tokio_stream::iter(replay).chain(live)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::models::explorer::ExplorerMetadata;
+    use crate::domain::models::fee_config::FeeConfig;
+    use crate::domain::models::finality::FinalityPolicy;
+    use crate::domain::models::labels::NetworkMetadata;
+    use crate::domain::models::lifecycle::NetworkStatus;
+    use crate::domain::models::network::{CreateNetworkData, Network};
+    use crate::domain::models::testnet::TestnetInfo;
+    use crate::domain::models::url_policy::UrlPolicy;
+    use rust_decimal_macros::dec;
+
+    fn event(chain_id: i32) -> NetworkEvent {
+        let data = CreateNetworkData {
+            chain_id,
+            name: "Ethereum Mainnet".to_string(),
+            rpc_url: "https://rpc.example.com".to_string(),
+            other_rpc_urls: vec![],
+            test_net: false,
+            block_explorer_url: "https://explorer.example.com".to_string(),
+            fee_multiplier: dec!(1.0),
+            gas_limit_multiplier: dec!(1.2),
+            default_signer_address: "0x742d35Cc6634C0532925a3b844Bc9e7595f1dEaD".to_string(),
+            explorer: ExplorerMetadata::default(),
+            fee_config: FeeConfig::default(),
+            finality: FinalityPolicy::default(),
+            status: NetworkStatus::Active,
+            parent: None,
+            tags: vec![],
+            metadata: NetworkMetadata::new(),
+            slug: None,
+            aliases: vec![],
+            testnet: TestnetInfo::default(),
+        };
+        NetworkEvent::created(Network::new(data, &UrlPolicy::default()).expect("valid test data"))
+    }
+
+    async fn next_chain_id(subscription: &mut EventSubscription) -> (u64, i32) {
+        let sequenced = subscription.events.next().await.expect("an event");
+        (sequenced.id, sequenced.event.network.chain_id())
+    }
+
+    #[tokio::test]
+    async fn test_delivers_live_events() {
+        let bus = InMemoryEventBus::new(8);
+        bus.publish(event(1)).await;
+
+        let mut subscription = bus.subscribe(None);
+        bus.publish(event(2)).await;
+
+        assert!(!subscription.missed);
+        assert_eq!(next_chain_id(&mut subscription).await.1, 2);
+    }
+
+    #[tokio::test]
+    async fn test_replays_events_after_last_event_id() {
+        let bus = InMemoryEventBus::new(8);
+        let mut live = bus.subscribe(None);
+        for chain_id in 1..=3 {
+            bus.publish(event(chain_id)).await;
+        }
+        let (first_id, _) = next_chain_id(&mut live).await;
+
+        let mut subscription = bus.subscribe(Some(first_id));
+        assert!(!subscription.missed);
+        assert_eq!(next_chain_id(&mut subscription).await, (first_id + 1, 2));
+        assert_eq!(next_chain_id(&mut subscription).await, (first_id + 2, 3));
+
+        bus.publish(event(4)).await;
+        assert_eq!(next_chain_id(&mut subscription).await, (first_id + 3, 4));
+    }
+
+    #[tokio::test]
+    async fn test_reports_events_dropped_from_the_buffer() {
+        let bus = InMemoryEventBus::new(2);
+        let mut live = bus.subscribe(None);
+        bus.publish(event(1)).await;
+        let (first_id, _) = next_chain_id(&mut live).await;
+        bus.publish(event(2)).await;
+        bus.publish(event(3)).await;
+
+        assert!(!bus.subscribe(Some(first_id)).missed);
+        assert!(bus.subscribe(Some(first_id - 1)).missed);
+        assert!(bus.subscribe(Some(first_id + 10)).missed);
+    }
+
+    #[tokio::test]
+    async fn test_ends_stream_of_lagging_subscriber() {
+        let bus = InMemoryEventBus::new(2);
+        let mut subscription = bus.subscribe(None);
+        for chain_id in 1..=3 {
+            bus.publish(event(chain_id)).await;
+        }
+
+        assert!(subscription.events.next().await.is_none());
+    }
+}
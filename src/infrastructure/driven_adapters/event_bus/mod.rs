@@ -0,0 +1,11 @@
+//! Event Bus Adapters
+//!
+//! Implementations of the network event gateways:
+//! - In-process broadcast with a bounded replay buffer
+//! - Fan-out to several publishers
+
+mod fanout;
+mod in_memory;
+
+pub use fanout::FanoutEventPublisher;
+pub use in_memory::InMemoryEventBus;
@@ -0,0 +1,232 @@
+//! Caching JWT Revocation Repository
+//!
+//! Read-through cache in front of any `JwtRevocationRepository`, sparing
+//! the lookups of revoked tokens and subjects. Only revocations found are
+//! cached: unrevoked answers are read every time, so a revocation made by
+//! another replica applies at once.
+//!
+//! Token and subject revocations are held in their own `TtlCache`, expiring
+//! entries after a TTL and evicting the oldest first when full. A subject
+//! revoked again through the cache gets its new cutoff at once; when that
+//! happens on another replica, it is seen once the entry expires.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+use crate::domain::gateways::JwtRevocationRepository;
+use crate::domain::models::jwt_revocation::{JwtRevocation, SubjectRevocation};
+use crate::infrastructure::driven_adapters::cache::TtlCache;
+use crate::shared::errors::RepositoryError;
+
+/// Read-through caching decorator for a `JwtRevocationRepository`
+pub struct CachingJwtRevocationRepository {
+    inner: Arc<dyn JwtRevocationRepository>,
+    /// IDs of the revoked JWTs
+    revoked_jwts: TtlCache<String, ()>,
+    /// Cutoff of each revoked subject
+    revoked_subjects: TtlCache<String, DateTime<Utc>>,
+}
+
+impl CachingJwtRevocationRepository {
+    /// Cache revocations found on `inner` for `ttl`, holding at most `max_entries` of each kind
+    #[must_use]
+    pub fn new(inner: Arc<dyn JwtRevocationRepository>, ttl: Duration, max_entries: usize) -> Self {
+        Self {
+            inner,
+            revoked_jwts: TtlCache::new(ttl, max_entries),
+            revoked_subjects: TtlCache::new(ttl, max_entries),
+        }
+    }
+}
+
+#[async_trait]
+impl JwtRevocationRepository for CachingJwtRevocationRepository {
+    async fn is_jwt_revoked(&self, jti: &str) -> Result<bool, RepositoryError> {
+        let load = async {
+            self.inner
+                .is_jwt_revoked(jti)
+                .await
+                .map(|revoked| revoked.then_some(()))
+        };
+        let revoked = self.revoked_jwts.get_or_load_found(jti.to_string(), load).await?;
+        Ok(revoked.is_some())
+    }
+
+    async fn subject_revoked_before(&self, subject: &str) -> Result<Option<DateTime<Utc>>, RepositoryError> {
+        self.revoked_subjects
+            .get_or_load_found(subject.to_string(), self.inner.subject_revoked_before(subject))
+            .await
+    }
+
+    async fn find_jwt_revocations(&self, now: DateTime<Utc>) -> Result<Vec<JwtRevocation>, RepositoryError> {
+        self.inner.find_jwt_revocations(now).await
+    }
+
+    async fn find_subject_revocations(&self) -> Result<Vec<SubjectRevocation>, RepositoryError> {
+        self.inner.find_subject_revocations().await
+    }
+
+    async fn revoke_jwt(&self, revocation: &JwtRevocation) -> Result<JwtRevocation, RepositoryError> {
+        let result = self.inner.revoke_jwt(revocation).await;
+        self.revoked_jwts.invalidate(&revocation.jti().to_string());
+        result
+    }
+
+    async fn revoke_subject(&self, revocation: &SubjectRevocation) -> Result<SubjectRevocation, RepositoryError> {
+        let result = self.inner.revoke_subject(revocation).await;
+        self.revoked_subjects.invalidate(&revocation.subject().to_string());
+        result
+    }
+
+    async fn purge_expired_jwts(&self, now: DateTime<Utc>) -> Result<u64, RepositoryError> {
+        // Purged tokens have expired, so their cached answers no longer matter
+        self.inner.purge_expired_jwts(now).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::models::jwt_revocation::{JwtRevocationData, SubjectRevocationData};
+    use std::sync::atomic::{AtomicU64, Ordering};
+    use std::sync::Mutex;
+
+    /// Repository holding revocations in memory and counting the reads reaching it
+    #[derive(Default)]
+    struct CountingJwtRevocationRepository {
+        jwts: Mutex<Vec<JwtRevocation>>,
+        subjects: Mutex<Vec<SubjectRevocation>>,
+        reads: AtomicU64,
+    }
+
+    impl CountingJwtRevocationRepository {
+        fn reads(&self) -> u64 {
+            self.reads.load(Ordering::Relaxed)
+        }
+    }
+
+    #[async_trait]
+    impl JwtRevocationRepository for CountingJwtRevocationRepository {
+        async fn is_jwt_revoked(&self, jti: &str) -> Result<bool, RepositoryError> {
+            self.reads.fetch_add(1, Ordering::Relaxed);
+            Ok(self.jwts.lock().unwrap().iter().any(|t| t.jti() == jti))
+        }
+
+        async fn subject_revoked_before(&self, subject: &str) -> Result<Option<DateTime<Utc>>, RepositoryError> {
+            self.reads.fetch_add(1, Ordering::Relaxed);
+            Ok(self
+                .subjects
+                .lock()
+                .unwrap()
+                .iter()
+                .find(|s| s.subject() == subject)
+                .map(SubjectRevocation::revoked_before))
+        }
+
+        async fn find_jwt_revocations(&self, _now: DateTime<Utc>) -> Result<Vec<JwtRevocation>, RepositoryError> {
+            Ok(self.jwts.lock().unwrap().clone())
+        }
+
+        async fn find_subject_revocations(&self) -> Result<Vec<SubjectRevocation>, RepositoryError> {
+            Ok(self.subjects.lock().unwrap().clone())
+        }
+
+        async fn revoke_jwt(&self, revocation: &JwtRevocation) -> Result<JwtRevocation, RepositoryError> {
+            self.jwts.lock().unwrap().push(revocation.clone());
+            Ok(revocation.clone())
+        }
+
+        async fn revoke_subject(&self, revocation: &SubjectRevocation) -> Result<SubjectRevocation, RepositoryError> {
+            let mut subjects = self.subjects.lock().unwrap();
+            subjects.retain(|s| s.subject() != revocation.subject());
+            subjects.push(revocation.clone());
+            Ok(revocation.clone())
+        }
+
+        async fn purge_expired_jwts(&self, _now: DateTime<Utc>) -> Result<u64, RepositoryError> {
+            Ok(0)
+        }
+    }
+
+    fn setup(
+        ttl: Duration,
+        max_entries: usize,
+    ) -> (Arc<CountingJwtRevocationRepository>, CachingJwtRevocationRepository) {
+        let inner = Arc::new(CountingJwtRevocationRepository::default());
+        let cache = CachingJwtRevocationRepository::new(inner.clone(), ttl, max_entries);
+        (inner, cache)
+    }
+
+    fn jwt_revocation(jti: &str) -> JwtRevocation {
+        JwtRevocation::new(
+            JwtRevocationData {
+                jti: jti.to_string(),
+                subject: None,
+                reason: None,
+                expires_at: None,
+            },
+            "admin".to_string(),
+            Utc::now() + chrono::Duration::hours(1),
+        )
+        .unwrap()
+    }
+
+    fn subject_revocation(subject: &str) -> SubjectRevocation {
+        SubjectRevocation::new(
+            SubjectRevocationData {
+                subject: subject.to_string(),
+                revoked_before: None,
+                reason: None,
+            },
+            "admin".to_string(),
+        )
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_only_revocations_are_cached() {
+        let (inner, cache) = setup(Duration::from_secs(60), 10);
+
+        for _ in 0..3 {
+            assert!(!cache.is_jwt_revoked("jti-1").await.unwrap());
+            assert!(cache.subject_revoked_before("user-1").await.unwrap().is_none());
+        }
+        assert_eq!(inner.reads(), 6);
+
+        // Revocations made by another replica apply at once
+        inner.revoke_jwt(&jwt_revocation("jti-1")).await.unwrap();
+        let subject = subject_revocation("user-1");
+        inner.revoke_subject(&subject).await.unwrap();
+
+        for _ in 0..3 {
+            assert!(cache.is_jwt_revoked("jti-1").await.unwrap());
+            assert_eq!(
+                cache.subject_revoked_before("user-1").await.unwrap(),
+                Some(subject.revoked_before())
+            );
+        }
+        assert_eq!(inner.reads(), 8);
+    }
+
+    #[tokio::test]
+    async fn test_revoking_a_subject_again_moves_its_cached_cutoff() {
+        let (_inner, cache) = setup(Duration::from_secs(60), 10);
+        let first = subject_revocation("user-1");
+        cache.revoke_subject(&first).await.unwrap();
+        assert_eq!(
+            cache.subject_revoked_before("user-1").await.unwrap(),
+            Some(first.revoked_before())
+        );
+
+        let second = subject_revocation("user-1");
+        assert_ne!(second.revoked_before(), first.revoked_before());
+        cache.revoke_subject(&second).await.unwrap();
+        assert_eq!(
+            cache.subject_revoked_before("user-1").await.unwrap(),
+            Some(second.revoked_before())
+        );
+    }
+}
@@ -0,0 +1,7 @@
+//! JWT Revocation Repository Implementations
+
+mod caching;
+mod postgres;
+
+pub use caching::CachingJwtRevocationRepository;
+pub use postgres::PostgresJwtRevocationRepository;
@@ -0,0 +1,176 @@
+//! PostgreSQL JWT Revocation Repository Implementation
+//!
+//! Implements the JwtRevocationRepository trait using SQLx for PostgreSQL.
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+
+use crate::domain::gateways::JwtRevocationRepository;
+use crate::domain::models::jwt_revocation::{JwtRevocation, SubjectRevocation};
+use crate::shared::errors::RepositoryError;
+
+/// Database row representation for revoked_jwts table
+#[derive(Debug, sqlx::FromRow)]
+struct RevokedJwtRow {
+    jti: String,
+    subject: Option<String>,
+    reason: Option<String>,
+    expires_at: DateTime<Utc>,
+    revoked_by: String,
+    revoked_at: DateTime<Utc>,
+}
+
+impl From<RevokedJwtRow> for JwtRevocation {
+    fn from(row: RevokedJwtRow) -> Self {
+        JwtRevocation::restore(
+            row.jti,
+            row.subject,
+            row.reason,
+            row.expires_at,
+            row.revoked_by,
+            row.revoked_at,
+        )
+    }
+}
+
+/// Database row representation for revoked_subjects table
+#[derive(Debug, sqlx::FromRow)]
+struct RevokedSubjectRow {
+    subject: String,
+    revoked_before: DateTime<Utc>,
+    reason: Option<String>,
+    revoked_by: String,
+    revoked_at: DateTime<Utc>,
+}
+
+impl From<RevokedSubjectRow> for SubjectRevocation {
+    fn from(row: RevokedSubjectRow) -> Self {
+        SubjectRevocation::restore(
+            row.subject,
+            row.revoked_before,
+            row.reason,
+            row.revoked_by,
+            row.revoked_at,
+        )
+    }
+}
+
+/// PostgreSQL implementation of JwtRevocationRepository
+pub struct PostgresJwtRevocationRepository {
+    pool: PgPool,
+}
+
+impl PostgresJwtRevocationRepository {
+    /// Create a new PostgresJwtRevocationRepository
+    #[must_use]
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl JwtRevocationRepository for PostgresJwtRevocationRepository {
+    async fn is_jwt_revoked(&self, jti: &str) -> Result<bool, RepositoryError> {
+        let revoked: bool = sqlx::query_scalar("SELECT EXISTS(SELECT 1 FROM revoked_jwts WHERE jti = $1)")
+            .bind(jti)
+            .fetch_one(&self.pool)
+            .await?;
+
+        Ok(revoked)
+    }
+
+    async fn subject_revoked_before(&self, subject: &str) -> Result<Option<DateTime<Utc>>, RepositoryError> {
+        let revoked_before = sqlx::query_scalar("SELECT revoked_before FROM revoked_subjects WHERE subject = $1")
+            .bind(subject)
+            .fetch_optional(&self.pool)
+            .await?;
+
+        Ok(revoked_before)
+    }
+
+    async fn find_jwt_revocations(&self, now: DateTime<Utc>) -> Result<Vec<JwtRevocation>, RepositoryError> {
+        let rows = sqlx::query_as::<_, RevokedJwtRow>(
+            r#"
+            SELECT jti, subject, reason, expires_at, revoked_by, revoked_at
+            FROM revoked_jwts
+            WHERE expires_at > $1
+            ORDER BY revoked_at ASC
+            "#,
+        )
+        .bind(now)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+
+    async fn find_subject_revocations(&self) -> Result<Vec<SubjectRevocation>, RepositoryError> {
+        let rows = sqlx::query_as::<_, RevokedSubjectRow>(
+            r#"
+            SELECT subject, revoked_before, reason, revoked_by, revoked_at
+            FROM revoked_subjects
+            ORDER BY revoked_at ASC
+            "#,
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+
+    async fn revoke_jwt(&self, revocation: &JwtRevocation) -> Result<JwtRevocation, RepositoryError> {
+        // The no-op update makes RETURNING yield the existing row on conflict
+        let row = sqlx::query_as::<_, RevokedJwtRow>(
+            r#"
+            INSERT INTO revoked_jwts (jti, subject, reason, expires_at, revoked_by, revoked_at)
+            VALUES ($1, $2, $3, $4, $5, $6)
+            ON CONFLICT (jti) DO UPDATE SET jti = revoked_jwts.jti
+            RETURNING jti, subject, reason, expires_at, revoked_by, revoked_at
+            "#,
+        )
+        .bind(revocation.jti())
+        .bind(revocation.subject())
+        .bind(revocation.reason())
+        .bind(revocation.expires_at())
+        .bind(revocation.revoked_by())
+        .bind(revocation.revoked_at())
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(row.into())
+    }
+
+    async fn revoke_subject(&self, revocation: &SubjectRevocation) -> Result<SubjectRevocation, RepositoryError> {
+        let row = sqlx::query_as::<_, RevokedSubjectRow>(
+            r#"
+            INSERT INTO revoked_subjects (subject, revoked_before, reason, revoked_by, revoked_at)
+            VALUES ($1, $2, $3, $4, $5)
+            ON CONFLICT (subject) DO UPDATE SET
+                revoked_before = GREATEST(revoked_subjects.revoked_before, EXCLUDED.revoked_before),
+                reason = EXCLUDED.reason,
+                revoked_by = EXCLUDED.revoked_by,
+                revoked_at = EXCLUDED.revoked_at
+            RETURNING subject, revoked_before, reason, revoked_by, revoked_at
+            "#,
+        )
+        .bind(revocation.subject())
+        .bind(revocation.revoked_before())
+        .bind(revocation.reason())
+        .bind(revocation.revoked_by())
+        .bind(revocation.revoked_at())
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(row.into())
+    }
+
+    async fn purge_expired_jwts(&self, now: DateTime<Utc>) -> Result<u64, RepositoryError> {
+        let result = sqlx::query("DELETE FROM revoked_jwts WHERE expires_at <= $1")
+            .bind(now)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(result.rows_affected())
+    }
+}
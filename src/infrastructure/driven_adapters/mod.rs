@@ -2,12 +2,44 @@
 //!
 //! Implementations of gateway traits for external systems:
 //! - Database repositories
+//! - In-memory TTL cache for the caching decorators
+//! - Event bus
+//! - Outbox sinks
 //! - Configuration
+//! - Webhook sender
+//! - JWT issuer
 //! - External service clients
 
+pub mod api_key_repository;
+pub mod cache;
 pub mod config;
+pub mod contract_repository;
 pub mod database;
+pub mod event_bus;
+pub mod jwt_revocation_repository;
 pub mod network_repository;
+pub mod outbox_repository;
+pub mod outbox_sink;
+pub mod schedule_repository;
+pub mod secrets;
+pub mod signer_repository;
+pub mod token_issuer;
+pub mod token_repository;
+pub mod webhook_delivery_repository;
+pub mod webhook_repository;
+pub mod webhook_sender;
 
+pub use api_key_repository::PostgresApiKeyRepository;
 pub use config::AppConfig;
+pub use contract_repository::PostgresContractRepository;
+pub use event_bus::InMemoryEventBus;
+pub use jwt_revocation_repository::PostgresJwtRevocationRepository;
 pub use network_repository::PostgresNetworkRepository;
+pub use outbox_repository::PostgresOutboxRepository;
+pub use schedule_repository::PostgresScheduleRepository;
+pub use signer_repository::PostgresSignerRepository;
+pub use token_issuer::JwtTokenIssuer;
+pub use token_repository::PostgresTokenRepository;
+pub use webhook_delivery_repository::PostgresWebhookDeliveryRepository;
+pub use webhook_repository::PostgresWebhookRepository;
+pub use webhook_sender::HttpWebhookSender;
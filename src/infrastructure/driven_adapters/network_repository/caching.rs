@@ -0,0 +1,422 @@
+//! Caching Network Repository
+//!
+//! Read-through cache in front of any `NetworkRepository`, for the lookups
+//! serving most reads: by ID, by slug or alias, and the list of active
+//! networks. Other queries are passed through.
+//!
+//! Each kind of lookup is held in its own `TtlCache`, expiring entries after a
+//! TTL and evicting the oldest first when full. Writes made through the cache
+//! invalidate what they affect; changes made by other replicas are dropped
+//! through `NetworkCacheInvalidator` when wired to the change listener, and
+//! otherwise seen once the entries expire. Writes never start from a cached
+//! copy: `find_by_id_for_update` always reads the inner repository.
+
+use std::collections::BTreeSet;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::gateways::{NetworkCacheInvalidator, NetworkRepository};
+use crate::domain::models::labels::normalize_tags;
+use crate::domain::models::network::{Network, NetworkId};
+use crate::domain::models::search::SearchQuery;
+use crate::domain::models::slug::normalize_slug;
+use crate::infrastructure::driven_adapters::cache::{CacheStats, TtlCache};
+use crate::shared::errors::RepositoryError;
+
+/// Read-through caching decorator for a `NetworkRepository`
+pub struct CachingNetworkRepository {
+    inner: Arc<dyn NetworkRepository>,
+    by_id: TtlCache<Uuid, Network>,
+    /// Keyed by normalized slug or alias
+    by_slug: TtlCache<String, Network>,
+    /// Keyed by the normalized tags of the list
+    active: TtlCache<BTreeSet<String>, Vec<Network>>,
+    invalidations: AtomicU64,
+}
+
+impl CachingNetworkRepository {
+    /// Cache lookups on `inner` for `ttl`, holding at most `max_entries` of each kind
+    #[must_use]
+    pub fn new(inner: Arc<dyn NetworkRepository>, ttl: Duration, max_entries: usize) -> Self {
+        Self {
+            inner,
+            by_id: TtlCache::new(ttl, max_entries),
+            by_slug: TtlCache::new(ttl, max_entries),
+            active: TtlCache::new(ttl, max_entries),
+            invalidations: AtomicU64::new(0),
+        }
+    }
+
+    /// Get the cache counters
+    #[must_use]
+    pub fn stats(&self) -> CacheStats {
+        let kinds = [self.by_id.stats(), self.by_slug.stats(), self.active.stats()];
+        CacheStats {
+            hits: kinds.iter().map(|stats| stats.hits).sum(),
+            misses: kinds.iter().map(|stats| stats.misses).sum(),
+            evictions: kinds.iter().map(|stats| stats.evictions).sum(),
+            invalidations: self.invalidations.load(Ordering::Relaxed),
+            entries: kinds.iter().map(|stats| stats.entries).sum(),
+        }
+    }
+
+    /// Drop every cached entry
+    pub fn invalidate_all(&self) {
+        self.by_id.invalidate_all();
+        self.by_slug.invalidate_all();
+        self.active.invalidate_all();
+        self.invalidations.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Drop the entries of a network, and every list since it may enter or leave them
+    fn invalidate_network(&self, id: &NetworkId) {
+        self.by_id.invalidate(id.as_uuid());
+        self.by_slug.invalidate_where(|_, network| network.id() == id);
+        self.active.invalidate_all();
+        self.invalidations.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+impl NetworkCacheInvalidator for CachingNetworkRepository {
+    fn invalidate(&self, id: &NetworkId) {
+        self.invalidate_network(id);
+    }
+}
+
+#[async_trait]
+impl NetworkRepository for CachingNetworkRepository {
+    async fn find_by_id(&self, id: &NetworkId) -> Result<Option<Network>, RepositoryError> {
+        self.by_id
+            .get_or_load_found(*id.as_uuid(), self.inner.find_by_id(id))
+            .await
+    }
+
+    async fn find_by_id_for_update(&self, id: &NetworkId) -> Result<Option<Network>, RepositoryError> {
+        self.inner.find_by_id_for_update(id).await
+    }
+
+    async fn find_by_chain_id(&self, chain_id: i32) -> Result<Option<Network>, RepositoryError> {
+        self.inner.find_by_chain_id(chain_id).await
+    }
+
+    async fn find_by_slug(&self, slug: &str) -> Result<Option<Network>, RepositoryError> {
+        self.by_slug
+            .get_or_load_found(normalize_slug(slug), self.inner.find_by_slug(slug))
+            .await
+    }
+
+    async fn find_all_active(&self, tags: &[String]) -> Result<Vec<Network>, RepositoryError> {
+        self.active
+            .get_or_load(normalize_tags(tags), self.inner.find_all_active(tags))
+            .await
+    }
+
+    async fn search(&self, query: &SearchQuery) -> Result<Vec<Network>, RepositoryError> {
+        self.inner.search(query).await
+    }
+
+    async fn find_children(&self, parent_id: &NetworkId) -> Result<Vec<Network>, RepositoryError> {
+        self.inner.find_children(parent_id).await
+    }
+
+    async fn find_ancestors(&self, id: &NetworkId) -> Result<Vec<Network>, RepositoryError> {
+        self.inner.find_ancestors(id).await
+    }
+
+    async fn subtree_height(&self, id: &NetworkId) -> Result<usize, RepositoryError> {
+        self.inner.subtree_height(id).await
+    }
+
+    async fn find_testnets(&self, mainnet_id: &NetworkId) -> Result<Vec<Network>, RepositoryError> {
+        self.inner.find_testnets(mainnet_id).await
+    }
+
+    async fn create(&self, network: &Network) -> Result<Network, RepositoryError> {
+        let result = self.inner.create(network).await;
+        self.invalidate_network(network.id());
+        result
+    }
+
+    async fn update(&self, network: &Network) -> Result<Option<Network>, RepositoryError> {
+        let result = self.inner.update(network).await;
+        self.invalidate_network(network.id());
+        result
+    }
+
+    async fn exists_by_chain_id(&self, chain_id: i32, exclude_id: Option<&NetworkId>) -> Result<bool, RepositoryError> {
+        self.inner.exists_by_chain_id(chain_id, exclude_id).await
+    }
+
+    async fn exists_by_slug(&self, slug: &str, exclude_id: Option<&NetworkId>) -> Result<bool, RepositoryError> {
+        self.inner.exists_by_slug(slug, exclude_id).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::models::explorer::ExplorerMetadata;
+    use crate::domain::models::fee_config::FeeConfig;
+    use crate::domain::models::finality::FinalityPolicy;
+    use crate::domain::models::labels::NetworkMetadata;
+    use crate::domain::models::lifecycle::NetworkStatus;
+    use crate::domain::models::network::CreateNetworkData;
+    use crate::domain::models::testnet::TestnetInfo;
+    use crate::domain::models::url_policy::UrlPolicy;
+    use rust_decimal_macros::dec;
+    use std::sync::Mutex;
+
+    /// Repository holding networks in memory and counting the reads reaching it
+    #[derive(Default)]
+    struct CountingNetworkRepository {
+        networks: Mutex<Vec<Network>>,
+        reads: AtomicU64,
+    }
+
+    impl CountingNetworkRepository {
+        fn reads(&self) -> u64 {
+            self.reads.load(Ordering::Relaxed)
+        }
+
+        fn find(&self, predicate: impl Fn(&Network) -> bool) -> Option<Network> {
+            self.reads.fetch_add(1, Ordering::Relaxed);
+            self.networks.lock().unwrap().iter().find(|n| predicate(n)).cloned()
+        }
+    }
+
+    #[async_trait]
+    impl NetworkRepository for CountingNetworkRepository {
+        async fn find_by_id(&self, id: &NetworkId) -> Result<Option<Network>, RepositoryError> {
+            Ok(self.find(|n| n.id() == id))
+        }
+
+        async fn find_by_id_for_update(&self, id: &NetworkId) -> Result<Option<Network>, RepositoryError> {
+            Ok(self.find(|n| n.id() == id))
+        }
+
+        async fn find_by_chain_id(&self, chain_id: i32) -> Result<Option<Network>, RepositoryError> {
+            Ok(self.find(|n| n.chain_id() == chain_id))
+        }
+
+        async fn find_by_slug(&self, slug: &str) -> Result<Option<Network>, RepositoryError> {
+            Ok(self.find(|n| n.slug() == normalize_slug(slug)))
+        }
+
+        async fn find_all_active(&self, _tags: &[String]) -> Result<Vec<Network>, RepositoryError> {
+            self.reads.fetch_add(1, Ordering::Relaxed);
+            Ok(self
+                .networks
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|n| n.active())
+                .cloned()
+                .collect())
+        }
+
+        async fn search(&self, _query: &SearchQuery) -> Result<Vec<Network>, RepositoryError> {
+            Ok(vec![])
+        }
+
+        async fn find_children(&self, _parent_id: &NetworkId) -> Result<Vec<Network>, RepositoryError> {
+            Ok(vec![])
+        }
+
+        async fn find_ancestors(&self, _id: &NetworkId) -> Result<Vec<Network>, RepositoryError> {
+            Ok(vec![])
+        }
+
+        async fn subtree_height(&self, _id: &NetworkId) -> Result<usize, RepositoryError> {
+            Ok(0)
+        }
+
+        async fn find_testnets(&self, _mainnet_id: &NetworkId) -> Result<Vec<Network>, RepositoryError> {
+            Ok(vec![])
+        }
+
+        async fn create(&self, network: &Network) -> Result<Network, RepositoryError> {
+            self.networks.lock().unwrap().push(network.clone());
+            Ok(network.clone())
+        }
+
+        async fn update(&self, network: &Network) -> Result<Option<Network>, RepositoryError> {
+            let mut networks = self.networks.lock().unwrap();
+            let Some(existing) = networks.iter_mut().find(|n| n.id() == network.id()) else {
+                return Ok(None);
+            };
+            *existing = network.clone();
+            Ok(Some(network.clone()))
+        }
+
+        async fn exists_by_chain_id(
+            &self,
+            _chain_id: i32,
+            _exclude_id: Option<&NetworkId>,
+        ) -> Result<bool, RepositoryError> {
+            Ok(false)
+        }
+
+        async fn exists_by_slug(&self, _slug: &str, _exclude_id: Option<&NetworkId>) -> Result<bool, RepositoryError> {
+            Ok(false)
+        }
+    }
+
+    fn create_test_network(chain_id: i32) -> Network {
+        let data = CreateNetworkData {
+            chain_id,
+            name: format!("Network {chain_id}"),
+            rpc_url: "https://rpc.example.com".to_string(),
+            other_rpc_urls: vec![],
+            test_net: false,
+            block_explorer_url: "https://explorer.example.com".to_string(),
+            fee_multiplier: dec!(1.0),
+            gas_limit_multiplier: dec!(1.2),
+            default_signer_address: "0x742d35Cc6634C0532925a3b844Bc9e7595f1dEaD".to_string(),
+            explorer: ExplorerMetadata::default(),
+            fee_config: FeeConfig::default(),
+            finality: FinalityPolicy::default(),
+            status: NetworkStatus::Active,
+            parent: None,
+            tags: vec![],
+            metadata: NetworkMetadata::new(),
+            slug: Some(format!("network-{chain_id}")),
+            aliases: vec![],
+            testnet: TestnetInfo::default(),
+        };
+        Network::new(data, &UrlPolicy::default()).expect("valid test data")
+    }
+
+    async fn setup(
+        networks: &[Network],
+        ttl: Duration,
+        max_entries: usize,
+    ) -> (Arc<CountingNetworkRepository>, CachingNetworkRepository) {
+        let inner = Arc::new(CountingNetworkRepository::default());
+        for network in networks {
+            inner.create(network).await.unwrap();
+        }
+        let cache = CachingNetworkRepository::new(inner.clone(), ttl, max_entries);
+        (inner, cache)
+    }
+
+    #[tokio::test]
+    async fn test_reads_are_served_from_cache() {
+        let network = create_test_network(1);
+        let (inner, cache) = setup(&[network.clone()], Duration::from_secs(60), 10).await;
+
+        for _ in 0..3 {
+            assert!(cache.find_by_id(network.id()).await.unwrap().is_some());
+            assert_eq!(
+                cache.find_by_slug(" Network-1 ").await.unwrap().unwrap().id(),
+                network.id()
+            );
+            assert_eq!(cache.find_all_active(&[]).await.unwrap().len(), 1);
+        }
+
+        assert_eq!(inner.reads(), 3);
+        let stats = cache.stats();
+        assert_eq!((stats.hits, stats.misses, stats.entries), (6, 3, 3));
+        assert!((stats.hit_ratio() - 6.0 / 9.0).abs() < f64::EPSILON);
+    }
+
+    #[tokio::test]
+    async fn test_missing_networks_and_expired_entries_are_read_again() {
+        let network = create_test_network(1);
+        let (inner, cache) = setup(&[network.clone()], Duration::ZERO, 10).await;
+
+        cache.find_by_id(network.id()).await.unwrap();
+        cache.find_by_id(network.id()).await.unwrap();
+        cache.find_by_id(&NetworkId::new()).await.unwrap();
+        cache.find_by_id(&NetworkId::new()).await.unwrap();
+
+        assert_eq!(inner.reads(), 4);
+        assert_eq!(cache.stats().entries, 0);
+    }
+
+    #[tokio::test]
+    async fn test_oldest_entries_are_evicted_when_full() {
+        let networks: Vec<_> = (1..=3).map(create_test_network).collect();
+        let (inner, cache) = setup(&networks, Duration::from_secs(60), 2).await;
+
+        for network in &networks {
+            cache.find_by_id(network.id()).await.unwrap();
+        }
+        assert_eq!(cache.stats().entries, 2);
+        assert_eq!(cache.stats().evictions, 1);
+
+        // The first network was evicted, the last one is still cached
+        cache.find_by_id(networks[2].id()).await.unwrap();
+        assert_eq!(inner.reads(), 3);
+        cache.find_by_id(networks[0].id()).await.unwrap();
+        assert_eq!(inner.reads(), 4);
+    }
+
+    #[tokio::test]
+    async fn test_writes_invalidate_the_network_and_lists() {
+        let network = create_test_network(1);
+        let other = create_test_network(2);
+        let (inner, cache) = setup(&[network.clone(), other.clone()], Duration::from_secs(60), 10).await;
+
+        cache.find_by_id(network.id()).await.unwrap();
+        cache.find_by_slug("network-1").await.unwrap();
+        cache.find_by_id(other.id()).await.unwrap();
+        assert_eq!(cache.find_all_active(&[]).await.unwrap().len(), 2);
+
+        let retired = network.clone().transition_to(NetworkStatus::Retired, None).unwrap();
+        cache.update(&retired).await.unwrap();
+        assert_eq!(cache.stats().entries, 1);
+
+        let found = cache.find_by_id(network.id()).await.unwrap().unwrap();
+        assert_eq!(found.status(), NetworkStatus::Retired);
+        let found = cache.find_by_slug("network-1").await.unwrap().unwrap();
+        assert_eq!(found.status(), NetworkStatus::Retired);
+        assert_eq!(cache.find_all_active(&[]).await.unwrap().len(), 1);
+        let reads = inner.reads();
+        cache.find_by_id(other.id()).await.unwrap();
+        assert_eq!(inner.reads(), reads);
+
+        let added = create_test_network(3);
+        cache.create(&added).await.unwrap();
+        assert_eq!(cache.find_all_active(&[]).await.unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_invalidation_hook_drops_changes_made_elsewhere() {
+        let network = create_test_network(1);
+        let (inner, cache) = setup(&[network.clone()], Duration::from_secs(60), 10).await;
+        cache.find_by_id(network.id()).await.unwrap();
+
+        // Another replica retires the network
+        let retired = network.clone().transition_to(NetworkStatus::Retired, None).unwrap();
+        inner.update(&retired).await.unwrap();
+        let found = cache.find_by_id(network.id()).await.unwrap().unwrap();
+        assert_eq!(found.status(), NetworkStatus::Active);
+
+        NetworkCacheInvalidator::invalidate(&cache, network.id());
+        let found = cache.find_by_id(network.id()).await.unwrap().unwrap();
+        assert_eq!(found.status(), NetworkStatus::Retired);
+        assert_eq!(cache.stats().invalidations, 1);
+    }
+
+    #[tokio::test]
+    async fn test_reads_for_update_bypass_the_cache() {
+        let network = create_test_network(1);
+        let (inner, cache) = setup(&[network.clone()], Duration::from_secs(60), 10).await;
+        cache.find_by_id(network.id()).await.unwrap();
+
+        // Another replica retires the network, and no invalidation reaches this one
+        let retired = network.clone().transition_to(NetworkStatus::Retired, None).unwrap();
+        inner.update(&retired).await.unwrap();
+
+        let found = cache.find_by_id_for_update(network.id()).await.unwrap().unwrap();
+        assert_eq!(found.status(), NetworkStatus::Retired);
+        let found = cache.find_by_id_for_update(network.id()).await.unwrap().unwrap();
+        assert_eq!(found.status(), NetworkStatus::Retired);
+        assert_eq!(inner.reads(), 3);
+        assert_eq!(cache.stats().hits, 0);
+    }
+}
@@ -1,5 +1,9 @@
 //! Network Repository Implementations
 
+mod caching;
 mod postgres;
 
+pub use crate::infrastructure::driven_adapters::cache::CacheStats;
+pub use caching::CachingNetworkRepository;
 pub use postgres::PostgresNetworkRepository;
+pub(crate) use postgres::{open_network, NetworkRow};
@@ -1,20 +1,76 @@
 //! PostgreSQL Network Repository Implementation
 //!
 //! Implements the NetworkRepository trait using SQLx for PostgreSQL.
+//!
+//! When a `SecretCipher` is configured, RPC URLs embedding literal secrets
+//! are encrypted before being written and decrypted when read back.
+//!
+//! Every change is recorded in the outbox within the same transaction, so
+//! an event is relayed for each committed change, even after a crash.
+
+use std::collections::BTreeSet;
+use std::sync::Arc;
 
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 use rust_decimal::Decimal;
-use sqlx::PgPool;
+use sqlx::{PgConnection, PgPool};
 use uuid::Uuid;
 
-use crate::domain::gateways::NetworkRepository;
+use crate::domain::gateways::{NetworkRepository, SecretCipher};
+use crate::domain::models::event::NetworkEventKind;
+use crate::domain::models::explorer::ExplorerMetadata;
+use crate::domain::models::fee_config::{FeeBounds, FeeConfig};
+use crate::domain::models::finality::FinalityPolicy;
+use crate::domain::models::labels::{normalize_tags, NetworkMetadata};
+use crate::domain::models::lifecycle::NetworkStatus;
 use crate::domain::models::network::{Network, NetworkId};
+use crate::domain::models::outbox::OutboxMessage;
+use crate::domain::models::relation::{check_lineage, NetworkParent};
+use crate::domain::models::rpc_url;
+use crate::domain::models::search::{SearchQuery, SIMILARITY_THRESHOLD};
+use crate::domain::models::slug::normalize_slug;
+use crate::domain::models::testnet::TestnetInfo;
+use crate::infrastructure::driven_adapters::outbox_repository::insert_message;
 use crate::shared::errors::RepositoryError;
 
+/// Columns selected for a network row, shared by every query returning networks
+macro_rules! network_columns {
+    () => {
+        "id, chain_id, name, rpc_url, other_rpc_urls, test_net, \
+         block_explorer_url, fee_multiplier, gas_limit_multiplier, \
+         status, deprecated_at, sunset_at, default_signer_address, explorer_kind, explorer_api_url, \
+         explorer_standard, fee_model, max_fee_per_gas_floor, max_fee_per_gas_cap, \
+         max_priority_fee_per_gas_floor, max_priority_fee_per_gas_cap, \
+         base_fee_multiplier, gas_limit_cap, average_block_time_ms, required_confirmations, \
+         supports_safe_tag, supports_finalized_tag, max_reorg_depth, parent_network_id, parent_relation, \
+         tags, metadata, slug, aliases, faucet_urls, mainnet_network_id, expected_deprecation_at, \
+         created_at, updated_at"
+    };
+}
+
+/// Ancestors of the network bound to `$1` as `lineage (id, depth, path)`
+///
+/// The walk is not bounded by depth; the path of visited networks stops it
+/// if a cycle slipped into the table.
+macro_rules! ancestors_cte {
+    () => {
+        r#"
+            WITH RECURSIVE lineage (id, depth, path) AS (
+                SELECT parent_network_id, 1, ARRAY[id, parent_network_id] FROM networks
+                WHERE id = $1 AND parent_network_id IS NOT NULL
+                UNION ALL
+                SELECT n.parent_network_id, l.depth + 1, l.path || n.parent_network_id FROM networks n
+                JOIN lineage l ON n.id = l.id
+                WHERE n.parent_network_id IS NOT NULL AND n.parent_network_id <> ALL(l.path)
+            )
+            "#
+    };
+}
+
 /// Database row representation for network table
 #[derive(Debug, sqlx::FromRow)]
-struct NetworkRow {
+pub(crate) struct NetworkRow {
     id: Uuid,
     chain_id: i32,
     name: String,
@@ -24,8 +80,34 @@ struct NetworkRow {
     block_explorer_url: String,
     fee_multiplier: Decimal,
     gas_limit_multiplier: Decimal,
-    active: bool,
+    status: String,
+    deprecated_at: Option<DateTime<Utc>>,
+    sunset_at: Option<DateTime<Utc>>,
     default_signer_address: String,
+    explorer_kind: String,
+    explorer_api_url: Option<String>,
+    explorer_standard: String,
+    fee_model: String,
+    max_fee_per_gas_floor: Option<Decimal>,
+    max_fee_per_gas_cap: Option<Decimal>,
+    max_priority_fee_per_gas_floor: Option<Decimal>,
+    max_priority_fee_per_gas_cap: Option<Decimal>,
+    base_fee_multiplier: Option<Decimal>,
+    gas_limit_cap: Option<i64>,
+    average_block_time_ms: i32,
+    required_confirmations: i32,
+    supports_safe_tag: bool,
+    supports_finalized_tag: bool,
+    max_reorg_depth: Option<i32>,
+    parent_network_id: Option<Uuid>,
+    parent_relation: Option<String>,
+    tags: serde_json::Value,
+    metadata: serde_json::Value,
+    slug: String,
+    aliases: serde_json::Value,
+    faucet_urls: serde_json::Value,
+    mainnet_network_id: Option<Uuid>,
+    expected_deprecation_at: Option<DateTime<Utc>>,
     created_at: DateTime<Utc>,
     updated_at: DateTime<Utc>,
 }
@@ -37,6 +119,72 @@ impl TryFrom<NetworkRow> for Network {
         let other_rpc_urls: Vec<String> = serde_json::from_value(row.other_rpc_urls)
             .map_err(|e| RepositoryError::Mapping(format!("Failed to parse other_rpc_urls: {}", e)))?;
 
+        let explorer = ExplorerMetadata {
+            kind: row
+                .explorer_kind
+                .parse()
+                .map_err(|e| RepositoryError::Mapping(format!("Failed to parse explorer_kind: {e}")))?,
+            api_url: row.explorer_api_url,
+            standard: row
+                .explorer_standard
+                .parse()
+                .map_err(|e| RepositoryError::Mapping(format!("Failed to parse explorer_standard: {e}")))?,
+        };
+
+        let fee_config = FeeConfig {
+            model: row
+                .fee_model
+                .parse()
+                .map_err(|e| RepositoryError::Mapping(format!("Failed to parse fee_model: {e}")))?,
+            max_fee_per_gas: FeeBounds {
+                floor: row.max_fee_per_gas_floor,
+                cap: row.max_fee_per_gas_cap,
+            },
+            max_priority_fee_per_gas: FeeBounds {
+                floor: row.max_priority_fee_per_gas_floor,
+                cap: row.max_priority_fee_per_gas_cap,
+            },
+            base_fee_multiplier: row.base_fee_multiplier,
+            gas_limit_cap: row.gas_limit_cap,
+        };
+
+        let finality = FinalityPolicy {
+            average_block_time_ms: row.average_block_time_ms,
+            required_confirmations: row.required_confirmations,
+            supports_safe_tag: row.supports_safe_tag,
+            supports_finalized_tag: row.supports_finalized_tag,
+            max_reorg_depth: row.max_reorg_depth,
+        };
+
+        let status: NetworkStatus = row
+            .status
+            .parse()
+            .map_err(|e| RepositoryError::Mapping(format!("Failed to parse status: {e}")))?;
+
+        let parent = match (row.parent_network_id, row.parent_relation) {
+            (Some(parent_id), Some(relation)) => Some(NetworkParent {
+                network_id: NetworkId::from_uuid(parent_id),
+                kind: relation
+                    .parse()
+                    .map_err(|e| RepositoryError::Mapping(format!("Failed to parse parent_relation: {e}")))?,
+            }),
+            _ => None,
+        };
+
+        let tags: BTreeSet<String> = serde_json::from_value(row.tags)
+            .map_err(|e| RepositoryError::Mapping(format!("Failed to parse tags: {e}")))?;
+        let metadata: NetworkMetadata = serde_json::from_value(row.metadata)
+            .map_err(|e| RepositoryError::Mapping(format!("Failed to parse metadata: {e}")))?;
+        let aliases: BTreeSet<String> = serde_json::from_value(row.aliases)
+            .map_err(|e| RepositoryError::Mapping(format!("Failed to parse aliases: {e}")))?;
+
+        let testnet = TestnetInfo {
+            faucet_urls: serde_json::from_value(row.faucet_urls)
+                .map_err(|e| RepositoryError::Mapping(format!("Failed to parse faucet_urls: {e}")))?,
+            mainnet_id: row.mainnet_network_id.map(NetworkId::from_uuid),
+            expected_deprecation_at: row.expected_deprecation_at,
+        };
+
         Ok(Network::restore(
             NetworkId::from_uuid(row.id),
             row.chain_id,
@@ -47,122 +195,372 @@ impl TryFrom<NetworkRow> for Network {
             row.block_explorer_url,
             row.fee_multiplier,
             row.gas_limit_multiplier,
-            row.active,
+            status,
+            row.deprecated_at,
+            row.sunset_at,
             row.default_signer_address,
+            explorer,
+            fee_config,
+            finality,
+            parent,
+            tags,
+            metadata,
+            row.slug,
+            aliases,
+            testnet,
             row.created_at,
             row.updated_at,
         ))
     }
 }
 
+/// Decrypt a stored URL if it was sealed
+fn open_url(cipher: Option<&dyn SecretCipher>, value: String) -> Result<String, RepositoryError> {
+    match cipher {
+        Some(cipher) if cipher.is_encrypted(&value) => Ok(cipher.decrypt(&value)?),
+        _ => Ok(value),
+    }
+}
+
+/// Decrypt sealed URLs in a row and map it to a Network
+///
+/// Shared with the outbox, whose messages carry a snapshot of the row.
+pub(crate) fn open_network(mut row: NetworkRow, cipher: Option<&dyn SecretCipher>) -> Result<Network, RepositoryError> {
+    row.rpc_url = open_url(cipher, row.rpc_url)?;
+
+    let other_rpc_urls: Vec<String> = serde_json::from_value(row.other_rpc_urls)
+        .map_err(|e| RepositoryError::Mapping(format!("Failed to parse other_rpc_urls: {e}")))?;
+    let other_rpc_urls = other_rpc_urls
+        .into_iter()
+        .map(|url| open_url(cipher, url))
+        .collect::<Result<Vec<_>, _>>()?;
+    row.other_rpc_urls = serde_json::to_value(other_rpc_urls)
+        .map_err(|e| RepositoryError::Mapping(format!("Failed to serialize other_rpc_urls: {e}")))?;
+
+    Network::try_from(row)
+}
+
+/// Lock a network row for the rest of the transaction, returning its status
+/// before the change, or `None` if the network does not exist
+async fn lock_status(conn: &mut PgConnection, id: &NetworkId) -> Result<Option<NetworkStatus>, RepositoryError> {
+    let status = sqlx::query_scalar::<_, String>("SELECT status FROM networks WHERE id = $1 FOR UPDATE")
+        .bind(id.as_uuid())
+        .fetch_optional(conn)
+        .await?;
+
+    status
+        .map(|status| {
+            status
+                .parse()
+                .map_err(|e| RepositoryError::Mapping(format!("Failed to parse status: {e}")))
+        })
+        .transpose()
+}
+
+/// Key of the advisory lock serializing changes to network relations
+const RELATION_LOCK_KEY: i64 = 0x6e65_7477_6f72_6b73;
+
+/// Count the levels of descendants below a network, 0 for a leaf
+async fn subtree_height(conn: &mut PgConnection, id: &NetworkId) -> Result<usize, RepositoryError> {
+    let height = sqlx::query_scalar::<_, i32>(
+        r#"
+        WITH RECURSIVE subtree (id, depth, path) AS (
+            SELECT id, 0, ARRAY[id] FROM networks WHERE id = $1
+            UNION ALL
+            SELECT n.id, s.depth + 1, s.path || n.id FROM networks n
+            JOIN subtree s ON n.parent_network_id = s.id
+            WHERE n.id <> ALL(s.path)
+        )
+        SELECT COALESCE(MAX(depth), 0) FROM subtree
+        "#,
+    )
+    .bind(id.as_uuid())
+    .fetch_one(conn)
+    .await?;
+
+    usize::try_from(height).map_err(|e| RepositoryError::Mapping(format!("Invalid subtree height: {e}")))
+}
+
+/// Check the lineage of a network attached to a new parent, within the transaction writing it
+///
+/// Relation changes take a transaction-wide advisory lock first, so concurrent
+/// moves are checked one after the other and cannot combine into a cycle or an
+/// over-deep tree. The network row must already be locked.
+async fn check_relation(conn: &mut PgConnection, network: &Network) -> Result<(), RepositoryError> {
+    let Some(parent) = network.parent() else {
+        return Ok(());
+    };
+
+    let stored_parent = sqlx::query_scalar::<_, Option<Uuid>>("SELECT parent_network_id FROM networks WHERE id = $1")
+        .bind(network.id().as_uuid())
+        .fetch_optional(&mut *conn)
+        .await?
+        .flatten();
+    if stored_parent.as_ref() == Some(parent.network_id.as_uuid()) {
+        return Ok(());
+    }
+
+    sqlx::query("SELECT pg_advisory_xact_lock($1)")
+        .bind(RELATION_LOCK_KEY)
+        .execute(&mut *conn)
+        .await?;
+
+    let ancestors =
+        sqlx::query_scalar::<_, Uuid>(concat!(ancestors_cte!(), "SELECT id FROM lineage ORDER BY depth ASC"))
+            .bind(parent.network_id.as_uuid())
+            .fetch_all(&mut *conn)
+            .await?;
+    let lineage: Vec<NetworkId> = std::iter::once(parent.network_id.clone())
+        .chain(ancestors.into_iter().map(NetworkId::from_uuid))
+        .collect();
+    let height = subtree_height(conn, network.id()).await?;
+
+    check_lineage(network.id(), &lineage, height).map_err(|e| RepositoryError::Conflict(e.to_string()))
+}
+
 /// PostgreSQL implementation of NetworkRepository
 pub struct PostgresNetworkRepository {
     pool: PgPool,
+    cipher: Option<Arc<dyn SecretCipher>>,
 }
 
 impl PostgresNetworkRepository {
     /// Create a new PostgresNetworkRepository
     #[must_use]
     pub fn new(pool: PgPool) -> Self {
-        Self { pool }
+        Self { pool, cipher: None }
+    }
+
+    /// Encrypt RPC URLs that embed literal secrets using the given cipher
+    #[must_use]
+    pub fn with_secret_cipher(mut self, cipher: Arc<dyn SecretCipher>) -> Self {
+        self.cipher = Some(cipher);
+        self
+    }
+
+    /// Encrypt a URL for storage if it embeds a literal secret
+    fn seal_url(&self, url: &str) -> Result<String, RepositoryError> {
+        match &self.cipher {
+            Some(cipher) if rpc_url::contains_literal_secret(url) => Ok(cipher.encrypt(url)?),
+            _ => Ok(url.to_string()),
+        }
+    }
+
+    /// Seal the other RPC URLs and serialize them for the JSONB column
+    fn seal_other_rpc_urls(&self, network: &Network) -> Result<serde_json::Value, RepositoryError> {
+        let sealed = network
+            .other_rpc_urls()
+            .iter()
+            .map(|url| self.seal_url(url))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        serde_json::to_value(sealed)
+            .map_err(|e| RepositoryError::Mapping(format!("Failed to serialize other_rpc_urls: {e}")))
+    }
+
+    /// Decrypt sealed URLs in a row and map it to a Network
+    fn to_network(&self, row: NetworkRow) -> Result<Network, RepositoryError> {
+        open_network(row, self.cipher.as_deref())
     }
 }
 
 #[async_trait]
 impl NetworkRepository for PostgresNetworkRepository {
     async fn find_by_id(&self, id: &NetworkId) -> Result<Option<Network>, RepositoryError> {
-        let row = sqlx::query_as::<_, NetworkRow>(
-            r#"
-            SELECT id, chain_id, name, rpc_url, other_rpc_urls, test_net,
-                   block_explorer_url, fee_multiplier, gas_limit_multiplier,
-                   active, default_signer_address, created_at, updated_at
-            FROM networks
-            WHERE id = $1
-            "#,
-        )
-        .bind(id.as_uuid())
-        .fetch_optional(&self.pool)
-        .await?;
+        let row =
+            sqlx::query_as::<_, NetworkRow>(concat!("SELECT ", network_columns!(), " FROM networks WHERE id = $1"))
+                .bind(id.as_uuid())
+                .fetch_optional(&self.pool)
+                .await?;
+
+        row.map(|row| self.to_network(row)).transpose()
+    }
 
-        row.map(Network::try_from).transpose()
+    async fn find_by_id_for_update(&self, id: &NetworkId) -> Result<Option<Network>, RepositoryError> {
+        self.find_by_id(id).await
     }
 
     async fn find_by_chain_id(&self, chain_id: i32) -> Result<Option<Network>, RepositoryError> {
-        let row = sqlx::query_as::<_, NetworkRow>(
-            r#"
-            SELECT id, chain_id, name, rpc_url, other_rpc_urls, test_net,
-                   block_explorer_url, fee_multiplier, gas_limit_multiplier,
-                   active, default_signer_address, created_at, updated_at
-            FROM networks
-            WHERE chain_id = $1
-            "#,
-        )
+        let row = sqlx::query_as::<_, NetworkRow>(concat!(
+            "SELECT ",
+            network_columns!(),
+            " FROM networks WHERE chain_id = $1"
+        ))
         .bind(chain_id)
         .fetch_optional(&self.pool)
         .await?;
 
-        row.map(Network::try_from).transpose()
+        row.map(|row| self.to_network(row)).transpose()
+    }
+
+    async fn find_by_slug(&self, slug: &str) -> Result<Option<Network>, RepositoryError> {
+        let row = sqlx::query_as::<_, NetworkRow>(concat!(
+            "SELECT ",
+            network_columns!(),
+            " FROM networks WHERE id = (SELECT network_id FROM network_identifiers WHERE identifier = $1)"
+        ))
+        .bind(normalize_slug(slug))
+        .fetch_optional(&self.pool)
+        .await?;
+
+        row.map(|row| self.to_network(row)).transpose()
+    }
+
+    async fn find_all_active(&self, tags: &[String]) -> Result<Vec<Network>, RepositoryError> {
+        let rows = sqlx::query_as::<_, NetworkRow>(concat!(
+            "SELECT ",
+            network_columns!(),
+            " FROM networks WHERE status IN ('active', 'deprecated') AND tags @> $1 ORDER BY name ASC"
+        ))
+        .bind(serde_json::json!(normalize_tags(tags)))
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.into_iter().map(|row| self.to_network(row)).collect()
     }
 
-    async fn find_all_active(&self) -> Result<Vec<Network>, RepositoryError> {
-        let rows = sqlx::query_as::<_, NetworkRow>(
+    async fn search(&self, query: &SearchQuery) -> Result<Vec<Network>, RepositoryError> {
+        // Prefix full-text matches outrank fuzzy ones; word similarity breaks ties
+        let rows = sqlx::query_as::<_, NetworkRow>(concat!(
+            "SELECT ",
+            network_columns!(),
             r#"
-            SELECT id, chain_id, name, rpc_url, other_rpc_urls, test_net,
-                   block_explorer_url, fee_multiplier, gas_limit_multiplier,
-                   active, default_signer_address, created_at, updated_at
-            FROM networks
-            WHERE active = true
-            ORDER BY name ASC
-            "#,
-        )
+            FROM networks, to_tsquery('simple', $2) AS terms
+            WHERE search_document @@ terms OR word_similarity($1, search_text) >= $3
+            ORDER BY (CASE WHEN search_document @@ terms THEN 1 ELSE 0 END)
+                         + word_similarity($1, search_text) DESC,
+                     name ASC
+            LIMIT $4
+            "#
+        ))
+        .bind(query.text())
+        .bind(query.prefix_tsquery())
+        .bind(SIMILARITY_THRESHOLD)
+        .bind(i64::try_from(query.limit()).unwrap_or(i64::MAX))
         .fetch_all(&self.pool)
         .await?;
 
-        rows.into_iter().map(Network::try_from).collect()
+        rows.into_iter().map(|row| self.to_network(row)).collect()
+    }
+
+    async fn find_children(&self, parent_id: &NetworkId) -> Result<Vec<Network>, RepositoryError> {
+        let rows = sqlx::query_as::<_, NetworkRow>(concat!(
+            "SELECT ",
+            network_columns!(),
+            " FROM networks WHERE parent_network_id = $1 ORDER BY name ASC"
+        ))
+        .bind(parent_id.as_uuid())
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.into_iter().map(|row| self.to_network(row)).collect()
+    }
+
+    async fn find_ancestors(&self, id: &NetworkId) -> Result<Vec<Network>, RepositoryError> {
+        let rows = sqlx::query_as::<_, NetworkRow>(concat!(
+            ancestors_cte!(),
+            "SELECT ",
+            network_columns!(),
+            " FROM networks JOIN lineage USING (id) ORDER BY depth ASC"
+        ))
+        .bind(id.as_uuid())
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.into_iter().map(|row| self.to_network(row)).collect()
+    }
+
+    async fn subtree_height(&self, id: &NetworkId) -> Result<usize, RepositoryError> {
+        let mut conn = self.pool.acquire().await?;
+        subtree_height(&mut conn, id).await
+    }
+
+    async fn find_testnets(&self, mainnet_id: &NetworkId) -> Result<Vec<Network>, RepositoryError> {
+        let rows = sqlx::query_as::<_, NetworkRow>(concat!(
+            "SELECT ",
+            network_columns!(),
+            " FROM networks WHERE mainnet_network_id = $1 ORDER BY name ASC"
+        ))
+        .bind(mainnet_id.as_uuid())
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.into_iter().map(|row| self.to_network(row)).collect()
     }
 
     async fn create(&self, network: &Network) -> Result<Network, RepositoryError> {
-        let other_rpc_urls_json = serde_json::to_value(network.other_rpc_urls())
-            .map_err(|e| RepositoryError::Mapping(format!("Failed to serialize other_rpc_urls: {}", e)))?;
+        let rpc_url = self.seal_url(network.rpc_url())?;
+        let other_rpc_urls_json = self.seal_other_rpc_urls(network)?;
+        let mut tx = self.pool.begin().await?;
+        check_relation(&mut tx, network).await?;
 
-        let row = sqlx::query_as::<_, NetworkRow>(
-            r#"
-            INSERT INTO networks (
-                id, chain_id, name, rpc_url, other_rpc_urls, test_net,
-                block_explorer_url, fee_multiplier, gas_limit_multiplier,
-                active, default_signer_address, created_at, updated_at
-            )
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13)
-            RETURNING id, chain_id, name, rpc_url, other_rpc_urls, test_net,
-                      block_explorer_url, fee_multiplier, gas_limit_multiplier,
-                      active, default_signer_address, created_at, updated_at
-            "#,
-        )
+        let row = sqlx::query_as::<_, NetworkRow>(concat!(
+            "INSERT INTO networks (",
+            network_columns!(),
+            ") VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18, \
+             $19, $20, $21, $22, $23, $24, $25, $26, $27, $28, $29, $30, $31, $32, $33, $34, $35, $36, $37, $38, $39) RETURNING ",
+            network_columns!()
+        ))
         .bind(network.id().as_uuid())
         .bind(network.chain_id())
         .bind(network.name())
-        .bind(network.rpc_url())
+        .bind(&rpc_url)
         .bind(&other_rpc_urls_json)
         .bind(network.test_net())
         .bind(network.block_explorer_url())
         .bind(network.fee_multiplier())
         .bind(network.gas_limit_multiplier())
-        .bind(network.active())
+        .bind(network.status().as_str())
+        .bind(network.deprecated_at())
+        .bind(network.sunset_at())
         .bind(network.default_signer_address())
+        .bind(network.explorer().kind.as_str())
+        .bind(network.explorer().api_url.as_deref())
+        .bind(network.explorer().standard.as_str())
+        .bind(network.fee_config().model.as_str())
+        .bind(network.fee_config().max_fee_per_gas.floor)
+        .bind(network.fee_config().max_fee_per_gas.cap)
+        .bind(network.fee_config().max_priority_fee_per_gas.floor)
+        .bind(network.fee_config().max_priority_fee_per_gas.cap)
+        .bind(network.fee_config().base_fee_multiplier)
+        .bind(network.fee_config().gas_limit_cap)
+        .bind(network.finality().average_block_time_ms)
+        .bind(network.finality().required_confirmations)
+        .bind(network.finality().supports_safe_tag)
+        .bind(network.finality().supports_finalized_tag)
+        .bind(network.finality().max_reorg_depth)
+        .bind(network.parent().map(|parent| *parent.network_id.as_uuid()))
+        .bind(network.parent().map(|parent| parent.kind.as_str()))
+        .bind(serde_json::json!(network.tags()))
+        .bind(serde_json::Value::Object(network.metadata().clone()))
+        .bind(network.slug())
+        .bind(serde_json::json!(network.aliases()))
+        .bind(serde_json::json!(network.testnet().faucet_urls))
+        .bind(network.testnet().mainnet_id.as_ref().map(NetworkId::as_uuid))
+        .bind(network.testnet().expected_deprecation_at)
         .bind(network.created_at())
         .bind(network.updated_at())
-        .fetch_one(&self.pool)
+        .fetch_one(&mut *tx)
         .await
         .map_err(|e| RepositoryError::from(e).into_domain_error())?;
 
-        Network::try_from(row)
+        insert_message(&mut tx, &OutboxMessage::new(NetworkEventKind::Created, network.clone())).await?;
+
+        tx.commit().await?;
+        self.to_network(row)
     }
 
     async fn update(&self, network: &Network) -> Result<Option<Network>, RepositoryError> {
-        let other_rpc_urls_json = serde_json::to_value(network.other_rpc_urls())
-            .map_err(|e| RepositoryError::Mapping(format!("Failed to serialize other_rpc_urls: {}", e)))?;
+        let rpc_url = self.seal_url(network.rpc_url())?;
+        let other_rpc_urls_json = self.seal_other_rpc_urls(network)?;
+        let mut tx = self.pool.begin().await?;
 
-        let row = sqlx::query_as::<_, NetworkRow>(
+        let Some(before) = lock_status(&mut tx, network.id()).await? else {
+            return Ok(None);
+        };
+        check_relation(&mut tx, network).await?;
+
+        let row = sqlx::query_as::<_, NetworkRow>(concat!(
             r#"
             UPDATE networks
             SET chain_id = $2,
@@ -173,47 +571,86 @@ impl NetworkRepository for PostgresNetworkRepository {
                 block_explorer_url = $7,
                 fee_multiplier = $8,
                 gas_limit_multiplier = $9,
-                active = $10,
-                default_signer_address = $11,
-                updated_at = $12
+                status = $10,
+                deprecated_at = $11,
+                sunset_at = $12,
+                default_signer_address = $13,
+                explorer_kind = $14,
+                explorer_api_url = $15,
+                explorer_standard = $16,
+                fee_model = $17,
+                max_fee_per_gas_floor = $18,
+                max_fee_per_gas_cap = $19,
+                max_priority_fee_per_gas_floor = $20,
+                max_priority_fee_per_gas_cap = $21,
+                base_fee_multiplier = $22,
+                gas_limit_cap = $23,
+                average_block_time_ms = $24,
+                required_confirmations = $25,
+                supports_safe_tag = $26,
+                supports_finalized_tag = $27,
+                max_reorg_depth = $28,
+                parent_network_id = $29,
+                parent_relation = $30,
+                tags = $31,
+                metadata = $32,
+                slug = $33,
+                aliases = $34,
+                faucet_urls = $35,
+                mainnet_network_id = $36,
+                expected_deprecation_at = $37,
+                updated_at = $38
             WHERE id = $1
-            RETURNING id, chain_id, name, rpc_url, other_rpc_urls, test_net,
-                      block_explorer_url, fee_multiplier, gas_limit_multiplier,
-                      active, default_signer_address, created_at, updated_at
-            "#,
-        )
+            RETURNING "#,
+            network_columns!()
+        ))
         .bind(network.id().as_uuid())
         .bind(network.chain_id())
         .bind(network.name())
-        .bind(network.rpc_url())
+        .bind(&rpc_url)
         .bind(&other_rpc_urls_json)
         .bind(network.test_net())
         .bind(network.block_explorer_url())
         .bind(network.fee_multiplier())
         .bind(network.gas_limit_multiplier())
-        .bind(network.active())
+        .bind(network.status().as_str())
+        .bind(network.deprecated_at())
+        .bind(network.sunset_at())
         .bind(network.default_signer_address())
+        .bind(network.explorer().kind.as_str())
+        .bind(network.explorer().api_url.as_deref())
+        .bind(network.explorer().standard.as_str())
+        .bind(network.fee_config().model.as_str())
+        .bind(network.fee_config().max_fee_per_gas.floor)
+        .bind(network.fee_config().max_fee_per_gas.cap)
+        .bind(network.fee_config().max_priority_fee_per_gas.floor)
+        .bind(network.fee_config().max_priority_fee_per_gas.cap)
+        .bind(network.fee_config().base_fee_multiplier)
+        .bind(network.fee_config().gas_limit_cap)
+        .bind(network.finality().average_block_time_ms)
+        .bind(network.finality().required_confirmations)
+        .bind(network.finality().supports_safe_tag)
+        .bind(network.finality().supports_finalized_tag)
+        .bind(network.finality().max_reorg_depth)
+        .bind(network.parent().map(|parent| *parent.network_id.as_uuid()))
+        .bind(network.parent().map(|parent| parent.kind.as_str()))
+        .bind(serde_json::json!(network.tags()))
+        .bind(serde_json::Value::Object(network.metadata().clone()))
+        .bind(network.slug())
+        .bind(serde_json::json!(network.aliases()))
+        .bind(serde_json::json!(network.testnet().faucet_urls))
+        .bind(network.testnet().mainnet_id.as_ref().map(NetworkId::as_uuid))
+        .bind(network.testnet().expected_deprecation_at)
         .bind(network.updated_at())
-        .fetch_optional(&self.pool)
+        .fetch_one(&mut *tx)
         .await
         .map_err(|e| RepositoryError::from(e).into_domain_error())?;
 
-        row.map(Network::try_from).transpose()
-    }
-
-    async fn soft_delete(&self, id: &NetworkId) -> Result<bool, RepositoryError> {
-        let result = sqlx::query(
-            r#"
-            UPDATE networks
-            SET active = false, updated_at = NOW()
-            WHERE id = $1
-            "#,
-        )
-        .bind(id.as_uuid())
-        .execute(&self.pool)
-        .await?;
+        let kind = NetworkEventKind::for_status_change(before, network.status());
+        insert_message(&mut tx, &OutboxMessage::new(kind, network.clone())).await?;
 
-        Ok(result.rows_affected() > 0)
+        tx.commit().await?;
+        self.to_network(row).map(Some)
     }
 
     async fn exists_by_chain_id(
@@ -252,4 +689,21 @@ impl NetworkRepository for PostgresNetworkRepository {
 
         Ok(exists)
     }
+
+    async fn exists_by_slug(&self, slug: &str, exclude_id: Option<&NetworkId>) -> Result<bool, RepositoryError> {
+        let exists = sqlx::query_scalar::<_, bool>(
+            r#"
+            SELECT EXISTS(
+                SELECT 1 FROM network_identifiers
+                WHERE identifier = $1 AND ($2::uuid IS NULL OR network_id != $2)
+            )
+            "#,
+        )
+        .bind(normalize_slug(slug))
+        .bind(exclude_id.map(NetworkId::as_uuid))
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(exists)
+    }
 }
@@ -0,0 +1,6 @@
+//! Outbox Repository Implementations
+
+mod postgres;
+
+pub(crate) use postgres::insert_message;
+pub use postgres::PostgresOutboxRepository;
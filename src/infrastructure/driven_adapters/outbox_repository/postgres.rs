@@ -0,0 +1,183 @@
+//! PostgreSQL Outbox Repository Implementation
+//!
+//! Implements the OutboxRepository trait using SQLx for PostgreSQL.
+//!
+//! Messages are inserted by the network repository with [`insert_message`],
+//! inside the transaction making the change, along with a snapshot of the
+//! network row as written (RPC URL secrets stay sealed). Due messages are
+//! claimed with `FOR UPDATE SKIP LOCKED` and a time-bound lease, like webhook
+//! deliveries.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, Utc};
+use sqlx::{PgConnection, PgPool};
+use uuid::Uuid;
+
+use crate::domain::gateways::{OutboxRepository, SecretCipher};
+use crate::domain::models::outbox::{OutboxMessage, OutboxMessageId};
+use crate::infrastructure::driven_adapters::network_repository::{open_network, NetworkRow};
+use crate::shared::errors::RepositoryError;
+
+/// How long a claimed message is hidden from other relay instances
+const CLAIM_LEASE_SECS: i64 = 300;
+
+/// Columns selected for an outbox row, shared by every query returning messages
+macro_rules! outbox_columns {
+    () => {
+        "id, network_id, event, occurred_at, attempts, next_attempt_at, last_error, published_at"
+    };
+}
+
+/// Database row representation for outbox table, with the network snapshot
+/// expanded to a network row
+#[derive(Debug, sqlx::FromRow)]
+struct OutboxRow {
+    message_id: Uuid,
+    event: String,
+    occurred_at: DateTime<Utc>,
+    attempts: i32,
+    next_attempt_at: DateTime<Utc>,
+    last_error: Option<String>,
+    published_at: Option<DateTime<Utc>>,
+    #[sqlx(flatten)]
+    network: NetworkRow,
+}
+
+impl OutboxRow {
+    /// Map the row to a message, decrypting the sealed URLs of the snapshot
+    fn into_message(self, cipher: Option<&dyn SecretCipher>) -> Result<OutboxMessage, RepositoryError> {
+        let kind = self
+            .event
+            .parse()
+            .map_err(|e| RepositoryError::Mapping(format!("Failed to parse event: {e}")))?;
+        let attempts = u32::try_from(self.attempts)
+            .map_err(|e| RepositoryError::Mapping(format!("Failed to parse attempts: {e}")))?;
+
+        Ok(OutboxMessage::restore(
+            OutboxMessageId::from_uuid(self.message_id),
+            open_network(self.network, cipher)?,
+            kind,
+            self.occurred_at,
+            attempts,
+            self.next_attempt_at,
+            self.last_error,
+            self.published_at,
+        ))
+    }
+}
+
+/// Convert an attempt count to its column value
+fn attempts_value(message: &OutboxMessage) -> Result<i32, RepositoryError> {
+    i32::try_from(message.attempts()).map_err(|e| RepositoryError::Mapping(format!("Invalid attempts: {e}")))
+}
+
+/// Insert a message on the given connection, inside the transaction making
+/// the change it describes, once the network row has been written
+///
+/// The snapshot is taken from the stored row, so sealed secrets stay sealed.
+pub(crate) async fn insert_message(conn: &mut PgConnection, message: &OutboxMessage) -> Result<(), RepositoryError> {
+    sqlx::query(concat!(
+        "INSERT INTO outbox (",
+        outbox_columns!(),
+        ", payload) SELECT $1, $2, $3, $4, $5, $6, $7, $8, \
+         to_jsonb(networks) - 'search_text' - 'search_document' FROM networks WHERE id = $2"
+    ))
+    .bind(message.id().as_uuid())
+    .bind(message.network_id().as_uuid())
+    .bind(message.kind().as_str())
+    .bind(message.occurred_at())
+    .bind(attempts_value(message)?)
+    .bind(message.next_attempt_at())
+    .bind(message.last_error())
+    .bind(message.published_at())
+    .execute(conn)
+    .await?;
+
+    Ok(())
+}
+
+/// PostgreSQL implementation of OutboxRepository
+pub struct PostgresOutboxRepository {
+    pool: PgPool,
+    cipher: Option<Arc<dyn SecretCipher>>,
+}
+
+impl PostgresOutboxRepository {
+    /// Create a new PostgresOutboxRepository
+    #[must_use]
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool, cipher: None }
+    }
+
+    /// Decrypt the RPC URLs sealed in network snapshots using the given cipher
+    #[must_use]
+    pub fn with_secret_cipher(mut self, cipher: Arc<dyn SecretCipher>) -> Self {
+        self.cipher = Some(cipher);
+        self
+    }
+}
+
+#[async_trait]
+impl OutboxRepository for PostgresOutboxRepository {
+    async fn claim_due(&self, now: DateTime<Utc>, limit: u32) -> Result<Vec<OutboxMessage>, RepositoryError> {
+        let rows = sqlx::query_as::<_, OutboxRow>(
+            "WITH claimed AS (\
+                 UPDATE outbox SET locked_until = $2 \
+                 WHERE id IN (\
+                     SELECT id FROM outbox \
+                     WHERE published_at IS NULL AND next_attempt_at <= $1 \
+                     AND (locked_until IS NULL OR locked_until < $1) \
+                     ORDER BY next_attempt_at ASC, occurred_at ASC LIMIT $3 \
+                     FOR UPDATE SKIP LOCKED\
+                 ) RETURNING *\
+             ) \
+             SELECT claimed.id AS message_id, claimed.event, claimed.occurred_at, claimed.attempts, \
+             claimed.next_attempt_at, claimed.last_error, claimed.published_at, network.* \
+             FROM claimed, jsonb_populate_record(NULL::networks, claimed.payload) AS network",
+        )
+        .bind(now)
+        .bind(now + Duration::seconds(CLAIM_LEASE_SECS))
+        .bind(i64::from(limit))
+        .fetch_all(&self.pool)
+        .await?;
+
+        let mut messages = rows
+            .into_iter()
+            .map(|row| row.into_message(self.cipher.as_deref()))
+            .collect::<Result<Vec<_>, _>>()?;
+        // RETURNING does not preserve the subquery order
+        messages.sort_by_key(|message| (message.next_attempt_at(), message.occurred_at()));
+
+        Ok(messages)
+    }
+
+    async fn save(&self, message: &OutboxMessage) -> Result<(), RepositoryError> {
+        sqlx::query(
+            r#"
+            UPDATE outbox
+            SET attempts = $2, next_attempt_at = $3, last_error = $4, published_at = $5, locked_until = NULL
+            WHERE id = $1
+            "#,
+        )
+        .bind(message.id().as_uuid())
+        .bind(attempts_value(message)?)
+        .bind(message.next_attempt_at())
+        .bind(message.last_error())
+        .bind(message.published_at())
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn purge_published(&self, before: DateTime<Utc>) -> Result<u64, RepositoryError> {
+        let result = sqlx::query("DELETE FROM outbox WHERE published_at < $1")
+            .bind(before)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(result.rows_affected())
+    }
+}
@@ -0,0 +1,46 @@
+//! In-Memory Outbox Sink
+//!
+//! Collects relayed events in memory, so tests can assert on what was relayed.
+
+use std::sync::{Mutex, MutexGuard, PoisonError};
+
+use async_trait::async_trait;
+
+use crate::domain::gateways::OutboxSink;
+use crate::domain::models::outbox::OutboxEvent;
+
+/// Sink keeping every relayed event, duplicates included
+#[derive(Debug, Default)]
+pub struct InMemoryOutboxSink {
+    events: Mutex<Vec<OutboxEvent>>,
+}
+
+impl InMemoryOutboxSink {
+    /// Create an empty InMemoryOutboxSink
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Events relayed so far, in order
+    #[must_use]
+    pub fn events(&self) -> Vec<OutboxEvent> {
+        self.lock().clone()
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Vec<OutboxEvent>> {
+        self.events.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+#[async_trait]
+impl OutboxSink for InMemoryOutboxSink {
+    fn name(&self) -> &'static str {
+        "memory"
+    }
+
+    async fn deliver(&self, event: &OutboxEvent) -> Result<(), String> {
+        self.lock().push(event.clone());
+        Ok(())
+    }
+}
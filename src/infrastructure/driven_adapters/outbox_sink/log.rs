@@ -0,0 +1,39 @@
+//! Log Outbox Sink
+//!
+//! Writes every relayed event as a structured log line, for log-based
+//! pipelines and for debugging.
+
+use async_trait::async_trait;
+
+use crate::domain::gateways::OutboxSink;
+use crate::domain::models::outbox::OutboxEvent;
+
+/// Sink logging relayed events at info level
+#[derive(Debug, Default)]
+pub struct LogOutboxSink;
+
+impl LogOutboxSink {
+    /// Create a new LogOutboxSink
+    #[must_use]
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+#[async_trait]
+impl OutboxSink for LogOutboxSink {
+    fn name(&self) -> &'static str {
+        "log"
+    }
+
+    async fn deliver(&self, event: &OutboxEvent) -> Result<(), String> {
+        tracing::info!(
+            event_id = %event.id,
+            event = %event.event.kind,
+            network_id = %event.event.network.id(),
+            occurred_at = %event.event.occurred_at,
+            "Network event"
+        );
+        Ok(())
+    }
+}
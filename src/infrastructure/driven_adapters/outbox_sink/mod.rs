@@ -0,0 +1,14 @@
+//! Outbox Sink Adapters
+//!
+//! Destinations of events relayed from the outbox:
+//! - Structured log lines
+//! - In-memory collection, for tests
+//!
+//! Message brokers (NATS, Kafka, ...) plug in by implementing `OutboxSink`,
+//! using the event ID as the message ID for broker-side deduplication.
+
+mod in_memory;
+mod log;
+
+pub use in_memory::InMemoryOutboxSink;
+pub use log::LogOutboxSink;
@@ -0,0 +1,5 @@
+//! Schedule Repository Implementations
+
+mod postgres;
+
+pub use postgres::PostgresScheduleRepository;
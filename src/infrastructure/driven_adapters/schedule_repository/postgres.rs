@@ -0,0 +1,181 @@
+//! PostgreSQL Schedule Repository Implementation
+//!
+//! Implements the ScheduleRepository trait using SQLx for PostgreSQL.
+//!
+//! Due schedules are claimed with `FOR UPDATE SKIP LOCKED` and a time-bound
+//! lease, so several replicas can run the scheduler without applying a change
+//! twice, and a change claimed by a crashed instance is picked up again.
+
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, Utc};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::domain::gateways::ScheduleRepository;
+use crate::domain::models::network::NetworkId;
+use crate::domain::models::schedule::{LifecycleSchedule, ScheduleId};
+use crate::shared::errors::RepositoryError;
+
+/// How long a claimed schedule is hidden from other scheduler instances
+const CLAIM_LEASE_SECS: i64 = 300;
+
+/// Columns selected for a schedule row, shared by every query returning schedules
+macro_rules! schedule_columns {
+    () => {
+        "id, network_id, action, run_at, status, scheduled_by, cancelled_by, outcome, completed_at, created_at"
+    };
+}
+
+/// Database row representation for network_schedules table
+#[derive(Debug, sqlx::FromRow)]
+struct ScheduleRow {
+    id: Uuid,
+    network_id: Uuid,
+    action: String,
+    run_at: DateTime<Utc>,
+    status: String,
+    scheduled_by: String,
+    cancelled_by: Option<String>,
+    outcome: Option<String>,
+    completed_at: Option<DateTime<Utc>>,
+    created_at: DateTime<Utc>,
+}
+
+impl TryFrom<ScheduleRow> for LifecycleSchedule {
+    type Error = RepositoryError;
+
+    fn try_from(row: ScheduleRow) -> Result<Self, Self::Error> {
+        let action = row
+            .action
+            .parse()
+            .map_err(|e| RepositoryError::Mapping(format!("Failed to parse action: {e}")))?;
+        let status = row
+            .status
+            .parse()
+            .map_err(|e| RepositoryError::Mapping(format!("Failed to parse status: {e}")))?;
+
+        Ok(LifecycleSchedule::restore(
+            ScheduleId::from_uuid(row.id),
+            NetworkId::from_uuid(row.network_id),
+            action,
+            row.run_at,
+            status,
+            row.scheduled_by,
+            row.cancelled_by,
+            row.outcome,
+            row.completed_at,
+            row.created_at,
+        ))
+    }
+}
+
+/// PostgreSQL implementation of ScheduleRepository
+pub struct PostgresScheduleRepository {
+    pool: PgPool,
+}
+
+impl PostgresScheduleRepository {
+    /// Create a new PostgresScheduleRepository
+    #[must_use]
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl ScheduleRepository for PostgresScheduleRepository {
+    async fn find_by_id(&self, id: &ScheduleId) -> Result<Option<LifecycleSchedule>, RepositoryError> {
+        let row = sqlx::query_as::<_, ScheduleRow>(concat!(
+            "SELECT ",
+            schedule_columns!(),
+            " FROM network_schedules WHERE id = $1"
+        ))
+        .bind(id.as_uuid())
+        .fetch_optional(&self.pool)
+        .await?;
+
+        row.map(LifecycleSchedule::try_from).transpose()
+    }
+
+    async fn find_by_network(&self, network_id: &NetworkId) -> Result<Vec<LifecycleSchedule>, RepositoryError> {
+        let rows = sqlx::query_as::<_, ScheduleRow>(concat!(
+            "SELECT ",
+            schedule_columns!(),
+            " FROM network_schedules WHERE network_id = $1 ORDER BY run_at ASC, created_at ASC"
+        ))
+        .bind(network_id.as_uuid())
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.into_iter().map(LifecycleSchedule::try_from).collect()
+    }
+
+    async fn create(&self, schedule: &LifecycleSchedule) -> Result<LifecycleSchedule, RepositoryError> {
+        let row = sqlx::query_as::<_, ScheduleRow>(concat!(
+            "INSERT INTO network_schedules (",
+            schedule_columns!(),
+            ") VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10) RETURNING ",
+            schedule_columns!()
+        ))
+        .bind(schedule.id().as_uuid())
+        .bind(schedule.network_id().as_uuid())
+        .bind(schedule.action().as_str())
+        .bind(schedule.run_at())
+        .bind(schedule.status().as_str())
+        .bind(schedule.scheduled_by())
+        .bind(schedule.cancelled_by())
+        .bind(schedule.outcome())
+        .bind(schedule.completed_at())
+        .bind(schedule.created_at())
+        .fetch_one(&self.pool)
+        .await?;
+
+        LifecycleSchedule::try_from(row)
+    }
+
+    async fn complete(&self, schedule: &LifecycleSchedule) -> Result<Option<LifecycleSchedule>, RepositoryError> {
+        let row = sqlx::query_as::<_, ScheduleRow>(concat!(
+            "UPDATE network_schedules \
+             SET status = $2, cancelled_by = $3, outcome = $4, completed_at = $5, locked_until = NULL \
+             WHERE id = $1 AND status = 'pending' RETURNING ",
+            schedule_columns!()
+        ))
+        .bind(schedule.id().as_uuid())
+        .bind(schedule.status().as_str())
+        .bind(schedule.cancelled_by())
+        .bind(schedule.outcome())
+        .bind(schedule.completed_at())
+        .fetch_optional(&self.pool)
+        .await?;
+
+        row.map(LifecycleSchedule::try_from).transpose()
+    }
+
+    async fn claim_due(&self, now: DateTime<Utc>, limit: u32) -> Result<Vec<LifecycleSchedule>, RepositoryError> {
+        let rows = sqlx::query_as::<_, ScheduleRow>(concat!(
+            "UPDATE network_schedules SET locked_until = $2 \
+             WHERE id IN (\
+                 SELECT id FROM network_schedules \
+                 WHERE status = 'pending' AND run_at <= $1 \
+                 AND (locked_until IS NULL OR locked_until < $1) \
+                 ORDER BY run_at ASC LIMIT $3 \
+                 FOR UPDATE SKIP LOCKED\
+             ) RETURNING ",
+            schedule_columns!()
+        ))
+        .bind(now)
+        .bind(now + Duration::seconds(CLAIM_LEASE_SECS))
+        .bind(i64::from(limit))
+        .fetch_all(&self.pool)
+        .await?;
+
+        let mut schedules = rows
+            .into_iter()
+            .map(LifecycleSchedule::try_from)
+            .collect::<Result<Vec<_>, _>>()?;
+        // RETURNING does not preserve the subquery order
+        schedules.sort_by_key(LifecycleSchedule::run_at);
+
+        Ok(schedules)
+    }
+}
@@ -0,0 +1,98 @@
+//! Configuration Secret Resolver
+//!
+//! Resolves `${NAME}` placeholders from the `[secrets.values]` configuration table.
+//! Values can be supplied through environment variables such as
+//! `APP__SECRETS__VALUES__ALCHEMY_KEY`. Arbitrary process environment variables
+//! are deliberately not resolvable, so placeholders cannot exfiltrate other settings.
+
+use std::collections::HashMap;
+
+use sha2::{Digest, Sha256};
+use zeroize::Zeroizing;
+
+use crate::domain::gateways::SecretResolver;
+use crate::infrastructure::driven_adapters::config::SecretsConfig;
+
+/// Secret resolver backed by application configuration
+pub struct ConfigSecretResolver {
+    /// Secret values keyed by upper-cased name
+    values: HashMap<String, Zeroizing<String>>,
+    /// Truncated digest of the names and values, so it reveals nothing usable about them
+    version: String,
+}
+
+impl ConfigSecretResolver {
+    /// Create a resolver from the secrets configuration
+    #[must_use]
+    pub fn new(config: &SecretsConfig) -> Self {
+        // Environment-sourced keys are lower-cased by the config loader, so names are case-insensitive
+        let values: HashMap<String, Zeroizing<String>> = config
+            .values
+            .iter()
+            .map(|(name, value)| (name.to_ascii_uppercase(), Zeroizing::new(value.expose().to_string())))
+            .collect();
+
+        let mut names: Vec<&String> = values.keys().collect();
+        names.sort();
+        let mut digest = Sha256::new();
+        for name in names {
+            digest.update(name.as_bytes());
+            digest.update([0]);
+            digest.update(values[name].as_bytes());
+            digest.update([0]);
+        }
+        let version = hex::encode(&digest.finalize()[..8]);
+
+        Self { values, version }
+    }
+}
+
+impl SecretResolver for ConfigSecretResolver {
+    fn resolve(&self, name: &str) -> Option<String> {
+        self.values
+            .get(&name.to_ascii_uppercase())
+            .map(|value| value.as_str().to_string())
+    }
+
+    fn version(&self) -> String {
+        self.version.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use config::{Config, File, FileFormat};
+
+    #[test]
+    fn test_resolves_case_insensitively() {
+        let config: SecretsConfig = Config::builder()
+            .add_source(File::from_str("[values]\nalchemy_key = \"abc123\"", FileFormat::Toml))
+            .build()
+            .unwrap()
+            .try_deserialize()
+            .unwrap();
+
+        let resolver = ConfigSecretResolver::new(&config);
+        assert_eq!(resolver.resolve("ALCHEMY_KEY").as_deref(), Some("abc123"));
+        assert_eq!(resolver.resolve("OTHER_KEY"), None);
+    }
+
+    #[test]
+    fn test_version_changes_with_the_values() {
+        let resolver = |toml: &str| {
+            let config: SecretsConfig = Config::builder()
+                .add_source(File::from_str(toml, FileFormat::Toml))
+                .build()
+                .unwrap()
+                .try_deserialize()
+                .unwrap();
+            ConfigSecretResolver::new(&config)
+        };
+
+        let version = resolver("[values]\na = \"1\"\nb = \"2\"").version();
+        assert_eq!(resolver("[values]\nb = \"2\"\nA = \"1\"").version(), version);
+        assert_ne!(resolver("[values]\na = \"1\"\nb = \"3\"").version(), version);
+        assert_ne!(resolver("[values]\na = \"12\"\nb = \"\"").version(), version);
+    }
+}
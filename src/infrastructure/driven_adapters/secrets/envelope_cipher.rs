@@ -0,0 +1,155 @@
+//! Envelope Cipher
+//!
+//! AES-256-GCM envelope encryption: every value is encrypted with a fresh
+//! data key, which is itself encrypted ("wrapped") with the configured master key.
+//!
+//! Sealed format: `enc:v1:<base64(nonce || wrapped data key)>:<base64(nonce || ciphertext)>`
+
+use aes_gcm::aead::{Aead, AeadCore, KeyInit, OsRng};
+use aes_gcm::{Aes256Gcm, Key, Nonce};
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use zeroize::Zeroizing;
+
+use crate::domain::gateways::SecretCipher;
+use crate::shared::errors::SecretError;
+
+/// Prefix identifying values sealed by this cipher
+const SEALED_PREFIX: &str = "enc:v1:";
+/// AES-256 key length in bytes
+const KEY_LENGTH: usize = 32;
+/// AES-GCM nonce length in bytes
+const NONCE_LENGTH: usize = 12;
+
+/// AES-256-GCM envelope cipher keyed by a master key
+pub struct EnvelopeCipher {
+    master: Aes256Gcm,
+}
+
+impl EnvelopeCipher {
+    /// Create a cipher from a base64-encoded 256-bit master key
+    ///
+    /// # Errors
+    ///
+    /// Returns `SecretError::InvalidKey` if the key is not valid base64 or not 32 bytes long
+    pub fn from_base64_key(key: &str) -> Result<Self, SecretError> {
+        let bytes = Zeroizing::new(
+            BASE64
+                .decode(key.trim())
+                .map_err(|_| SecretError::InvalidKey("key must be base64 encoded".to_string()))?,
+        );
+
+        if bytes.len() != KEY_LENGTH {
+            return Err(SecretError::InvalidKey(format!("key must be {KEY_LENGTH} bytes")));
+        }
+
+        Ok(Self {
+            master: Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(&bytes)),
+        })
+    }
+}
+
+/// Encrypt `plaintext` with `cipher`, returning `nonce || ciphertext`
+fn seal(cipher: &Aes256Gcm, plaintext: &[u8]) -> Result<Vec<u8>, SecretError> {
+    let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
+    let ciphertext = cipher.encrypt(&nonce, plaintext).map_err(|_| SecretError::Encryption)?;
+
+    let mut sealed = nonce.to_vec();
+    sealed.extend_from_slice(&ciphertext);
+    Ok(sealed)
+}
+
+/// Decrypt a `nonce || ciphertext` blob produced by [`seal`]
+fn open(cipher: &Aes256Gcm, sealed: &[u8]) -> Result<Vec<u8>, SecretError> {
+    if sealed.len() <= NONCE_LENGTH {
+        return Err(SecretError::Decryption("sealed value is too short".to_string()));
+    }
+
+    let (nonce, ciphertext) = sealed.split_at(NONCE_LENGTH);
+    cipher
+        .decrypt(Nonce::from_slice(nonce), ciphertext)
+        .map_err(|_| SecretError::Decryption("authentication failed".to_string()))
+}
+
+impl SecretCipher for EnvelopeCipher {
+    fn encrypt(&self, plaintext: &str) -> Result<String, SecretError> {
+        let data_key = Aes256Gcm::generate_key(&mut OsRng);
+        let wrapped_key = seal(&self.master, data_key.as_slice())?;
+        let ciphertext = seal(&Aes256Gcm::new(&data_key), plaintext.as_bytes())?;
+
+        Ok(format!(
+            "{}{}:{}",
+            SEALED_PREFIX,
+            BASE64.encode(wrapped_key),
+            BASE64.encode(ciphertext)
+        ))
+    }
+
+    fn decrypt(&self, sealed: &str) -> Result<String, SecretError> {
+        let (wrapped_key, ciphertext) = sealed
+            .strip_prefix(SEALED_PREFIX)
+            .and_then(|body| body.split_once(':'))
+            .ok_or_else(|| SecretError::Decryption("unrecognized sealed format".to_string()))?;
+
+        let decode = |value: &str| {
+            BASE64
+                .decode(value)
+                .map_err(|_| SecretError::Decryption("invalid base64".to_string()))
+        };
+
+        let data_key = Zeroizing::new(open(&self.master, &decode(wrapped_key)?)?);
+        if data_key.len() != KEY_LENGTH {
+            return Err(SecretError::Decryption("invalid data key".to_string()));
+        }
+
+        let data_cipher = Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(&data_key));
+        let plaintext = open(&data_cipher, &decode(ciphertext)?)?;
+
+        String::from_utf8(plaintext).map_err(|_| SecretError::Decryption("plaintext is not UTF-8".to_string()))
+    }
+
+    fn is_encrypted(&self, value: &str) -> bool {
+        value.starts_with(SEALED_PREFIX)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_key() -> String {
+        BASE64.encode([7u8; KEY_LENGTH])
+    }
+
+    #[test]
+    fn test_encrypt_decrypt_roundtrip() {
+        let cipher = EnvelopeCipher::from_base64_key(&test_key()).unwrap();
+        let url = "https://mainnet.infura.io/v3/9aa3d95b3bc440fa88ea12eaa4456161";
+
+        let sealed = cipher.encrypt(url).unwrap();
+        assert!(cipher.is_encrypted(&sealed));
+        assert!(!sealed.contains("9aa3d95b"));
+        assert_eq!(cipher.decrypt(&sealed).unwrap(), url);
+    }
+
+    #[test]
+    fn test_encrypt_uses_fresh_data_key() {
+        let cipher = EnvelopeCipher::from_base64_key(&test_key()).unwrap();
+        assert_ne!(cipher.encrypt("secret").unwrap(), cipher.encrypt("secret").unwrap());
+    }
+
+    #[test]
+    fn test_decrypt_with_wrong_key_fails() {
+        let cipher = EnvelopeCipher::from_base64_key(&test_key()).unwrap();
+        let other = EnvelopeCipher::from_base64_key(&BASE64.encode([9u8; KEY_LENGTH])).unwrap();
+
+        let sealed = cipher.encrypt("secret").unwrap();
+        assert!(other.decrypt(&sealed).is_err());
+    }
+
+    #[test]
+    fn test_invalid_keys_rejected() {
+        assert!(EnvelopeCipher::from_base64_key("not base64!").is_err());
+        assert!(EnvelopeCipher::from_base64_key(&BASE64.encode([1u8; 16])).is_err());
+    }
+}
@@ -0,0 +1,11 @@
+//! Secret Adapters
+//!
+//! Implementations of the secret gateways:
+//! - AES-256-GCM envelope encryption for secrets at rest
+//! - Placeholder resolution backed by application configuration
+
+mod config_resolver;
+mod envelope_cipher;
+
+pub use config_resolver::ConfigSecretResolver;
+pub use envelope_cipher::EnvelopeCipher;
@@ -0,0 +1,5 @@
+//! Signer Repository Implementations
+
+mod postgres;
+
+pub use postgres::PostgresSignerRepository;
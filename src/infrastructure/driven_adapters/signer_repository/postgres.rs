@@ -0,0 +1,175 @@
+//! PostgreSQL Signer Repository Implementation
+//!
+//! Implements the SignerRepository trait using SQLx for PostgreSQL.
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::domain::gateways::SignerRepository;
+use crate::domain::models::network::NetworkId;
+use crate::domain::models::signer::{NetworkSigner, SignerId};
+use crate::shared::errors::RepositoryError;
+
+/// Columns selected for a signer row, shared by every query returning signers
+macro_rules! signer_columns {
+    () => {
+        "id, network_id, address, role, priority, enabled, created_at, updated_at"
+    };
+}
+
+/// Database row representation for network_signers table
+#[derive(Debug, sqlx::FromRow)]
+struct SignerRow {
+    id: Uuid,
+    network_id: Uuid,
+    address: String,
+    role: String,
+    priority: i32,
+    enabled: bool,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl From<SignerRow> for NetworkSigner {
+    fn from(row: SignerRow) -> Self {
+        NetworkSigner::restore(
+            SignerId::from_uuid(row.id),
+            NetworkId::from_uuid(row.network_id),
+            row.address,
+            row.role,
+            row.priority,
+            row.enabled,
+            row.created_at,
+            row.updated_at,
+        )
+    }
+}
+
+/// Map a unique violation on the per-network role/address index to a conflict
+fn map_role_conflict(error: sqlx::Error) -> RepositoryError {
+    if let sqlx::Error::Database(db_err) = &error {
+        if db_err.code().is_some_and(|c| c == "23505") {
+            return RepositoryError::UniqueViolation("signer role and address".to_string());
+        }
+    }
+    RepositoryError::from(error)
+}
+
+/// PostgreSQL implementation of SignerRepository
+pub struct PostgresSignerRepository {
+    pool: PgPool,
+}
+
+impl PostgresSignerRepository {
+    /// Create a new PostgresSignerRepository
+    #[must_use]
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl SignerRepository for PostgresSignerRepository {
+    async fn find_by_id(&self, id: &SignerId) -> Result<Option<NetworkSigner>, RepositoryError> {
+        let row = sqlx::query_as::<_, SignerRow>(concat!(
+            "SELECT ",
+            signer_columns!(),
+            " FROM network_signers WHERE id = $1"
+        ))
+        .bind(id.as_uuid())
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(row.map(NetworkSigner::from))
+    }
+
+    async fn find_by_network(&self, network_id: &NetworkId) -> Result<Vec<NetworkSigner>, RepositoryError> {
+        let rows = sqlx::query_as::<_, SignerRow>(concat!(
+            "SELECT ",
+            signer_columns!(),
+            " FROM network_signers WHERE network_id = $1 ORDER BY role ASC, priority ASC, created_at ASC"
+        ))
+        .bind(network_id.as_uuid())
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(NetworkSigner::from).collect())
+    }
+
+    async fn create(&self, signer: &NetworkSigner) -> Result<NetworkSigner, RepositoryError> {
+        let row = sqlx::query_as::<_, SignerRow>(concat!(
+            "INSERT INTO network_signers (",
+            signer_columns!(),
+            ") VALUES ($1, $2, $3, $4, $5, $6, $7, $8) RETURNING ",
+            signer_columns!()
+        ))
+        .bind(signer.id().as_uuid())
+        .bind(signer.network_id().as_uuid())
+        .bind(signer.address())
+        .bind(signer.role())
+        .bind(signer.priority())
+        .bind(signer.enabled())
+        .bind(signer.created_at())
+        .bind(signer.updated_at())
+        .fetch_one(&self.pool)
+        .await
+        .map_err(map_role_conflict)?;
+
+        Ok(NetworkSigner::from(row))
+    }
+
+    async fn update(&self, signer: &NetworkSigner) -> Result<Option<NetworkSigner>, RepositoryError> {
+        let row = sqlx::query_as::<_, SignerRow>(concat!(
+            "UPDATE network_signers \
+             SET address = $2, role = $3, priority = $4, enabled = $5, updated_at = $6 \
+             WHERE id = $1 RETURNING ",
+            signer_columns!()
+        ))
+        .bind(signer.id().as_uuid())
+        .bind(signer.address())
+        .bind(signer.role())
+        .bind(signer.priority())
+        .bind(signer.enabled())
+        .bind(signer.updated_at())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(map_role_conflict)?;
+
+        Ok(row.map(NetworkSigner::from))
+    }
+
+    async fn delete(&self, id: &SignerId) -> Result<bool, RepositoryError> {
+        let result = sqlx::query("DELETE FROM network_signers WHERE id = $1")
+            .bind(id.as_uuid())
+            .execute(&self.pool)
+            .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn exists_by_role_and_address(
+        &self,
+        network_id: &NetworkId,
+        role: &str,
+        address: &str,
+        exclude_id: Option<&SignerId>,
+    ) -> Result<bool, RepositoryError> {
+        let exists: bool = sqlx::query_scalar(
+            "SELECT EXISTS(\
+                 SELECT 1 FROM network_signers \
+                 WHERE network_id = $1 AND role = $2 AND LOWER(address) = LOWER($3) \
+                 AND ($4::uuid IS NULL OR id != $4)\
+             )",
+        )
+        .bind(network_id.as_uuid())
+        .bind(role)
+        .bind(address)
+        .bind(exclude_id.map(SignerId::as_uuid))
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(exists)
+    }
+}
@@ -0,0 +1,82 @@
+//! JWT Token Issuer
+//!
+//! Signs HS256 tokens with the configured JWT secret, carrying the same
+//! claims the authentication middleware decodes. Every token gets a random
+//! `jti`, so that it can be revoked on its own.
+
+use chrono::{Duration, Utc};
+use jsonwebtoken::{encode, EncodingKey, Header};
+use uuid::Uuid;
+
+use crate::domain::gateways::TokenIssuer;
+use crate::domain::models::service_account::IssuedToken;
+use crate::infrastructure::driving_adapters::api_rest::middleware::auth::Claims;
+use crate::shared::errors::SecretError;
+
+/// Issues HS256 JWTs valid for a fixed lifetime
+pub struct JwtTokenIssuer {
+    key: EncodingKey,
+    expires_in: Duration,
+}
+
+impl JwtTokenIssuer {
+    /// Create an issuer signing with `secret`
+    #[must_use]
+    pub fn new(secret: &str, expires_in: Duration) -> Self {
+        Self {
+            key: EncodingKey::from_secret(secret.as_bytes()),
+            expires_in,
+        }
+    }
+}
+
+impl TokenIssuer for JwtTokenIssuer {
+    fn issue(&self, subject: &str, email: &str, role: &str) -> Result<IssuedToken, SecretError> {
+        let issued_at = Utc::now();
+        let expires_at = issued_at + self.expires_in;
+        let jti = Uuid::new_v4().to_string();
+
+        let claims = Claims {
+            sub: subject.to_string(),
+            email: email.to_string(),
+            role: role.to_string(),
+            iat: issued_at.timestamp(),
+            exp: expires_at.timestamp(),
+            jti: Some(jti.clone()),
+        };
+        let token = encode(&Header::default(), &claims, &self.key).map_err(|e| SecretError::Signing(e.to_string()))?;
+
+        Ok(IssuedToken { token, jti, expires_at })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use jsonwebtoken::{decode, Algorithm, DecodingKey, Validation};
+
+    const SECRET: &str = "test-secret-key-that-is-at-least-32-characters";
+
+    #[test]
+    fn test_issued_tokens_decode_as_claims() {
+        let issuer = JwtTokenIssuer::new(SECRET, Duration::minutes(5));
+        let issued = issuer.issue("indexer", "indexer@example.com", "service").unwrap();
+
+        let claims = decode::<Claims>(
+            &issued.token,
+            &DecodingKey::from_secret(SECRET.as_bytes()),
+            &Validation::new(Algorithm::HS256),
+        )
+        .unwrap()
+        .claims;
+        assert_eq!(claims.sub, "indexer");
+        assert_eq!(claims.email, "indexer@example.com");
+        assert_eq!(claims.role, "service");
+        assert_eq!(claims.exp - claims.iat, 300);
+        assert_eq!(claims.exp, issued.expires_at.timestamp());
+        assert_eq!(claims.jti, Some(issued.jti.clone()));
+
+        let other = issuer.issue("indexer", "indexer@example.com", "service").unwrap();
+        assert_ne!(other.jti, issued.jti);
+    }
+}
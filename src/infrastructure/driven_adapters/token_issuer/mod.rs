@@ -0,0 +1,5 @@
+//! Token Issuer Implementations
+
+mod jwt;
+
+pub use jwt::JwtTokenIssuer;
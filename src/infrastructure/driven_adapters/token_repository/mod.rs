@@ -0,0 +1,5 @@
+//! Token Repository Implementations
+
+mod postgres;
+
+pub use postgres::PostgresTokenRepository;
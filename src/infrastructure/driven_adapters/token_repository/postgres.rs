@@ -0,0 +1,347 @@
+//! PostgreSQL Token Repository Implementation
+//!
+//! Implements the TokenRepository trait using SQLx for PostgreSQL.
+//!
+//! The token list version lives in `network_token_lists`, one row per network.
+//! Writes lock that row before changing tokens, so concurrent changes to the
+//! same list are serialized and each gets its own version.
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{PgPool, Postgres, Transaction};
+use uuid::Uuid;
+
+use crate::domain::gateways::TokenRepository;
+use crate::domain::models::network::NetworkId;
+use crate::domain::models::token::{Token, TokenId, TokenMetadata};
+use crate::domain::models::token_list::{TokenListChange, TokenListVersion};
+use crate::shared::errors::RepositoryError;
+
+/// Columns selected for a token row, shared by every query returning tokens
+macro_rules! token_columns {
+    () => {
+        "id, network_id, address, symbol, name, decimals, logo_uri, tags, created_at, updated_at"
+    };
+}
+
+/// Database row representation for network_tokens table
+#[derive(Debug, sqlx::FromRow)]
+struct TokenRow {
+    id: Uuid,
+    network_id: Uuid,
+    address: String,
+    symbol: String,
+    name: String,
+    decimals: i16,
+    logo_uri: Option<String>,
+    tags: Vec<String>,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl TryFrom<TokenRow> for Token {
+    type Error = RepositoryError;
+
+    fn try_from(row: TokenRow) -> Result<Self, Self::Error> {
+        let decimals = u8::try_from(row.decimals)
+            .map_err(|e| RepositoryError::Mapping(format!("Failed to parse decimals: {e}")))?;
+
+        Ok(Token::restore(
+            TokenId::from_uuid(row.id),
+            NetworkId::from_uuid(row.network_id),
+            row.address,
+            TokenMetadata {
+                symbol: row.symbol,
+                name: row.name,
+                decimals,
+                logo_uri: row.logo_uri,
+                tags: row.tags,
+            },
+            row.created_at,
+            row.updated_at,
+        ))
+    }
+}
+
+/// Database row representation for network_token_lists table
+#[derive(Debug, sqlx::FromRow)]
+struct VersionRow {
+    major: i64,
+    minor: i64,
+    patch: i64,
+    updated_at: DateTime<Utc>,
+}
+
+impl TryFrom<VersionRow> for TokenListVersion {
+    type Error = RepositoryError;
+
+    fn try_from(row: VersionRow) -> Result<Self, Self::Error> {
+        let part = |value: i64| {
+            u32::try_from(value).map_err(|e| RepositoryError::Mapping(format!("Failed to parse version: {e}")))
+        };
+
+        Ok(TokenListVersion {
+            major: part(row.major)?,
+            minor: part(row.minor)?,
+            patch: part(row.patch)?,
+            updated_at: row.updated_at,
+        })
+    }
+}
+
+/// Map a unique violation on the per-network address index to a conflict
+fn map_address_conflict(error: sqlx::Error) -> RepositoryError {
+    if let sqlx::Error::Database(db_err) = &error {
+        if db_err.code().is_some_and(|c| c == "23505") {
+            return RepositoryError::UniqueViolation("token address".to_string());
+        }
+    }
+    RepositoryError::from(error)
+}
+
+/// Lock the version row of a network, creating it at the initial version if needed
+async fn lock_version(
+    tx: &mut Transaction<'_, Postgres>,
+    network_id: &NetworkId,
+) -> Result<TokenListVersion, RepositoryError> {
+    let initial = TokenListVersion::initial(Utc::now());
+    sqlx::query(
+        "INSERT INTO network_token_lists (network_id, major, minor, patch, updated_at) \
+         VALUES ($1, $2, $3, $4, $5) ON CONFLICT (network_id) DO NOTHING",
+    )
+    .bind(network_id.as_uuid())
+    .bind(i64::from(initial.major))
+    .bind(i64::from(initial.minor))
+    .bind(i64::from(initial.patch))
+    .bind(initial.updated_at)
+    .execute(&mut **tx)
+    .await?;
+
+    let row = sqlx::query_as::<_, VersionRow>(
+        "SELECT major, minor, patch, updated_at FROM network_token_lists WHERE network_id = $1 FOR UPDATE",
+    )
+    .bind(network_id.as_uuid())
+    .fetch_one(&mut **tx)
+    .await?;
+
+    TokenListVersion::try_from(row)
+}
+
+/// Store the bumped version of a network token list
+async fn save_version(
+    tx: &mut Transaction<'_, Postgres>,
+    network_id: &NetworkId,
+    version: &TokenListVersion,
+) -> Result<(), RepositoryError> {
+    sqlx::query(
+        "UPDATE network_token_lists SET major = $2, minor = $3, patch = $4, updated_at = $5 WHERE network_id = $1",
+    )
+    .bind(network_id.as_uuid())
+    .bind(i64::from(version.major))
+    .bind(i64::from(version.minor))
+    .bind(i64::from(version.patch))
+    .bind(version.updated_at)
+    .execute(&mut **tx)
+    .await?;
+
+    Ok(())
+}
+
+/// PostgreSQL implementation of TokenRepository
+pub struct PostgresTokenRepository {
+    pool: PgPool,
+}
+
+impl PostgresTokenRepository {
+    /// Create a new PostgresTokenRepository
+    #[must_use]
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// Insert a token inside a transaction
+    async fn insert(tx: &mut Transaction<'_, Postgres>, token: &Token) -> Result<TokenRow, RepositoryError> {
+        sqlx::query_as::<_, TokenRow>(concat!(
+            "INSERT INTO network_tokens (",
+            token_columns!(),
+            ") VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10) RETURNING ",
+            token_columns!()
+        ))
+        .bind(token.id().as_uuid())
+        .bind(token.network_id().as_uuid())
+        .bind(token.address())
+        .bind(token.symbol())
+        .bind(token.name())
+        .bind(i16::from(token.decimals()))
+        .bind(token.logo_uri())
+        .bind(token.tags())
+        .bind(token.created_at())
+        .bind(token.updated_at())
+        .fetch_one(&mut **tx)
+        .await
+        .map_err(map_address_conflict)
+    }
+
+    /// Update the metadata of a token inside a transaction
+    async fn update_metadata(
+        tx: &mut Transaction<'_, Postgres>,
+        token: &Token,
+    ) -> Result<Option<TokenRow>, RepositoryError> {
+        let row = sqlx::query_as::<_, TokenRow>(concat!(
+            "UPDATE network_tokens \
+             SET symbol = $2, name = $3, decimals = $4, logo_uri = $5, tags = $6, updated_at = $7 \
+             WHERE id = $1 RETURNING ",
+            token_columns!()
+        ))
+        .bind(token.id().as_uuid())
+        .bind(token.symbol())
+        .bind(token.name())
+        .bind(i16::from(token.decimals()))
+        .bind(token.logo_uri())
+        .bind(token.tags())
+        .bind(token.updated_at())
+        .fetch_optional(&mut **tx)
+        .await?;
+
+        Ok(row)
+    }
+}
+
+#[async_trait]
+impl TokenRepository for PostgresTokenRepository {
+    async fn find_by_id(&self, id: &TokenId) -> Result<Option<Token>, RepositoryError> {
+        let row = sqlx::query_as::<_, TokenRow>(concat!(
+            "SELECT ",
+            token_columns!(),
+            " FROM network_tokens WHERE id = $1"
+        ))
+        .bind(id.as_uuid())
+        .fetch_optional(&self.pool)
+        .await?;
+
+        row.map(Token::try_from).transpose()
+    }
+
+    async fn find_by_network(&self, network_id: &NetworkId) -> Result<Vec<Token>, RepositoryError> {
+        let rows = sqlx::query_as::<_, TokenRow>(concat!(
+            "SELECT ",
+            token_columns!(),
+            " FROM network_tokens WHERE network_id = $1 ORDER BY UPPER(symbol) ASC, LOWER(address) ASC"
+        ))
+        .bind(network_id.as_uuid())
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.into_iter().map(Token::try_from).collect()
+    }
+
+    async fn exists_by_address(&self, network_id: &NetworkId, address: &str) -> Result<bool, RepositoryError> {
+        let exists: bool = sqlx::query_scalar(
+            "SELECT EXISTS(SELECT 1 FROM network_tokens WHERE network_id = $1 AND LOWER(address) = LOWER($2))",
+        )
+        .bind(network_id.as_uuid())
+        .bind(address)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(exists)
+    }
+
+    async fn create(&self, token: &Token) -> Result<Token, RepositoryError> {
+        let mut tx = self.pool.begin().await?;
+
+        let version = lock_version(&mut tx, token.network_id()).await?;
+        let row = Self::insert(&mut tx, token).await?;
+        save_version(
+            &mut tx,
+            token.network_id(),
+            &version.bump(TokenListChange::Minor, Utc::now()),
+        )
+        .await?;
+
+        tx.commit().await?;
+        Token::try_from(row)
+    }
+
+    async fn update(&self, token: &Token) -> Result<Option<Token>, RepositoryError> {
+        let mut tx = self.pool.begin().await?;
+
+        let version = lock_version(&mut tx, token.network_id()).await?;
+        let Some(row) = Self::update_metadata(&mut tx, token).await? else {
+            return Ok(None);
+        };
+        save_version(
+            &mut tx,
+            token.network_id(),
+            &version.bump(TokenListChange::Patch, Utc::now()),
+        )
+        .await?;
+
+        tx.commit().await?;
+        Token::try_from(row).map(Some)
+    }
+
+    async fn delete(&self, id: &TokenId) -> Result<bool, RepositoryError> {
+        let mut tx = self.pool.begin().await?;
+
+        let network_id: Option<Uuid> = sqlx::query_scalar("SELECT network_id FROM network_tokens WHERE id = $1")
+            .bind(id.as_uuid())
+            .fetch_optional(&mut *tx)
+            .await?;
+        let Some(network_id) = network_id.map(NetworkId::from_uuid) else {
+            return Ok(false);
+        };
+
+        let version = lock_version(&mut tx, &network_id).await?;
+        let result = sqlx::query("DELETE FROM network_tokens WHERE id = $1")
+            .bind(id.as_uuid())
+            .execute(&mut *tx)
+            .await?;
+        if result.rows_affected() == 0 {
+            return Ok(false);
+        }
+        save_version(&mut tx, &network_id, &version.bump(TokenListChange::Major, Utc::now())).await?;
+
+        tx.commit().await?;
+        Ok(true)
+    }
+
+    async fn import(
+        &self,
+        network_id: &NetworkId,
+        created: &[Token],
+        updated: &[Token],
+    ) -> Result<TokenListVersion, RepositoryError> {
+        let mut tx = self.pool.begin().await?;
+
+        let version = lock_version(&mut tx, network_id).await?;
+        for token in created {
+            Self::insert(&mut tx, token).await?;
+        }
+        for token in updated {
+            Self::update_metadata(&mut tx, token).await?;
+        }
+
+        let change = if created.is_empty() {
+            TokenListChange::Patch
+        } else {
+            TokenListChange::Minor
+        };
+        let version = version.bump(change, Utc::now());
+        save_version(&mut tx, network_id, &version).await?;
+
+        tx.commit().await?;
+        Ok(version)
+    }
+
+    async fn find_version(&self, network_id: &NetworkId) -> Result<Option<TokenListVersion>, RepositoryError> {
+        let row = sqlx::query_as::<_, VersionRow>(
+            "SELECT major, minor, patch, updated_at FROM network_token_lists WHERE network_id = $1",
+        )
+        .bind(network_id.as_uuid())
+        .fetch_optional(&self.pool)
+        .await?;
+
+        row.map(TokenListVersion::try_from).transpose()
+    }
+}
@@ -0,0 +1,5 @@
+//! Webhook Delivery Repository Implementations
+
+mod postgres;
+
+pub use postgres::PostgresWebhookDeliveryRepository;
@@ -0,0 +1,301 @@
+//! Postgres Webhook Delivery Repository Implementation
+//!
+//! Implements the `WebhookDeliveryRepository` trait using `SQLx` for Postgres.
+//!
+//! Due deliveries are claimed with `FOR UPDATE SKIP LOCKED` and a time-bound
+//! lease, so several replicas can run the dispatcher without sending a
+//! delivery twice, and a delivery claimed by a crashed instance is picked up
+//! again.
+
+use std::time::Duration as StdDuration;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, Utc};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::domain::gateways::WebhookDeliveryRepository;
+use crate::domain::models::network::NetworkId;
+use crate::domain::models::webhook::{DeliveryAttempt, DeliveryId, WebhookDelivery, WebhookId};
+use crate::shared::errors::RepositoryError;
+
+/// How long a claimed delivery is hidden from other dispatcher instances
+const CLAIM_LEASE_SECS: i64 = 300;
+
+/// Columns selected for a delivery row, shared by every query returning deliveries
+macro_rules! delivery_columns {
+    () => {
+        "id, webhook_id, event, network_id, payload, status, attempts, next_attempt_at, last_attempt_at, \
+         delivered_at, created_at"
+    };
+}
+
+/// Database row representation for `webhook_deliveries` table
+#[derive(Debug, sqlx::FromRow)]
+struct DeliveryRow {
+    id: Uuid,
+    webhook_id: Uuid,
+    event: String,
+    network_id: Uuid,
+    payload: String,
+    status: String,
+    attempts: i32,
+    next_attempt_at: DateTime<Utc>,
+    last_attempt_at: Option<DateTime<Utc>>,
+    delivered_at: Option<DateTime<Utc>>,
+    created_at: DateTime<Utc>,
+}
+
+impl TryFrom<DeliveryRow> for WebhookDelivery {
+    type Error = RepositoryError;
+
+    fn try_from(row: DeliveryRow) -> Result<Self, Self::Error> {
+        let event = row
+            .event
+            .parse()
+            .map_err(|e| RepositoryError::Mapping(format!("Failed to parse event: {e}")))?;
+        let status = row
+            .status
+            .parse()
+            .map_err(|e| RepositoryError::Mapping(format!("Failed to parse status: {e}")))?;
+        let attempts = u32::try_from(row.attempts)
+            .map_err(|e| RepositoryError::Mapping(format!("Failed to parse attempts: {e}")))?;
+
+        Ok(WebhookDelivery::restore(
+            DeliveryId::from_uuid(row.id),
+            WebhookId::from_uuid(row.webhook_id),
+            event,
+            NetworkId::from_uuid(row.network_id),
+            row.payload,
+            status,
+            attempts,
+            row.next_attempt_at,
+            row.last_attempt_at,
+            row.delivered_at,
+            row.created_at,
+        ))
+    }
+}
+
+/// Database row representation for `webhook_delivery_attempts` table
+#[derive(Debug, sqlx::FromRow)]
+struct AttemptRow {
+    delivery_id: Uuid,
+    attempted_at: DateTime<Utc>,
+    status_code: Option<i32>,
+    response_body: Option<String>,
+    error: Option<String>,
+    duration_ms: i64,
+}
+
+impl TryFrom<AttemptRow> for DeliveryAttempt {
+    type Error = RepositoryError;
+
+    fn try_from(row: AttemptRow) -> Result<Self, Self::Error> {
+        let status_code = row
+            .status_code
+            .map(u16::try_from)
+            .transpose()
+            .map_err(|e| RepositoryError::Mapping(format!("Failed to parse status_code: {e}")))?;
+        let duration_ms = u64::try_from(row.duration_ms)
+            .map_err(|e| RepositoryError::Mapping(format!("Failed to parse duration_ms: {e}")))?;
+
+        Ok(DeliveryAttempt {
+            delivery_id: DeliveryId::from_uuid(row.delivery_id),
+            attempted_at: row.attempted_at,
+            status_code,
+            response_body: row.response_body,
+            error: row.error,
+            duration: StdDuration::from_millis(duration_ms),
+        })
+    }
+}
+
+/// Convert an attempt count to its column value
+fn attempts_value(delivery: &WebhookDelivery) -> Result<i32, RepositoryError> {
+    i32::try_from(delivery.attempts()).map_err(|e| RepositoryError::Mapping(format!("Invalid attempts: {e}")))
+}
+
+/// Postgres implementation of `WebhookDeliveryRepository`
+pub struct PostgresWebhookDeliveryRepository {
+    pool: PgPool,
+}
+
+impl PostgresWebhookDeliveryRepository {
+    /// Create a new `PostgresWebhookDeliveryRepository`
+    #[must_use]
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl WebhookDeliveryRepository for PostgresWebhookDeliveryRepository {
+    async fn find_by_id(&self, id: &DeliveryId) -> Result<Option<WebhookDelivery>, RepositoryError> {
+        let row = sqlx::query_as::<_, DeliveryRow>(concat!(
+            "SELECT ",
+            delivery_columns!(),
+            " FROM webhook_deliveries WHERE id = $1"
+        ))
+        .bind(id.as_uuid())
+        .fetch_optional(&self.pool)
+        .await?;
+
+        row.map(WebhookDelivery::try_from).transpose()
+    }
+
+    async fn find_by_webhook(
+        &self,
+        webhook_id: &WebhookId,
+        limit: u32,
+    ) -> Result<Vec<WebhookDelivery>, RepositoryError> {
+        let rows = sqlx::query_as::<_, DeliveryRow>(concat!(
+            "SELECT ",
+            delivery_columns!(),
+            " FROM webhook_deliveries WHERE webhook_id = $1 ORDER BY created_at DESC LIMIT $2"
+        ))
+        .bind(webhook_id.as_uuid())
+        .bind(i64::from(limit))
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.into_iter().map(WebhookDelivery::try_from).collect()
+    }
+
+    async fn find_attempts(&self, id: &DeliveryId) -> Result<Vec<DeliveryAttempt>, RepositoryError> {
+        let rows = sqlx::query_as::<_, AttemptRow>(
+            r#"
+            SELECT delivery_id, attempted_at, status_code, response_body, error, duration_ms
+            FROM webhook_delivery_attempts
+            WHERE delivery_id = $1
+            ORDER BY attempted_at ASC
+            "#,
+        )
+        .bind(id.as_uuid())
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.into_iter().map(DeliveryAttempt::try_from).collect()
+    }
+
+    async fn enqueue(&self, deliveries: &[WebhookDelivery]) -> Result<(), RepositoryError> {
+        let mut tx = self.pool.begin().await?;
+
+        for delivery in deliveries {
+            sqlx::query(concat!(
+                "INSERT INTO webhook_deliveries (",
+                delivery_columns!(),
+                ") VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11) ON CONFLICT (id) DO NOTHING"
+            ))
+            .bind(delivery.id().as_uuid())
+            .bind(delivery.webhook_id().as_uuid())
+            .bind(delivery.event().as_str())
+            .bind(delivery.network_id().as_uuid())
+            .bind(delivery.payload())
+            .bind(delivery.status().as_str())
+            .bind(attempts_value(delivery)?)
+            .bind(delivery.next_attempt_at())
+            .bind(delivery.last_attempt_at())
+            .bind(delivery.delivered_at())
+            .bind(delivery.created_at())
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        tx.commit().await?;
+        Ok(())
+    }
+
+    async fn claim_due(&self, now: DateTime<Utc>, limit: u32) -> Result<Vec<WebhookDelivery>, RepositoryError> {
+        let rows = sqlx::query_as::<_, DeliveryRow>(concat!(
+            "UPDATE webhook_deliveries SET locked_until = $2 \
+             WHERE id IN (\
+                 SELECT id FROM webhook_deliveries \
+                 WHERE status = 'pending' AND next_attempt_at <= $1 \
+                 AND (locked_until IS NULL OR locked_until < $1) \
+                 ORDER BY next_attempt_at ASC LIMIT $3 \
+                 FOR UPDATE SKIP LOCKED\
+             ) RETURNING ",
+            delivery_columns!()
+        ))
+        .bind(now)
+        .bind(now + Duration::seconds(CLAIM_LEASE_SECS))
+        .bind(i64::from(limit))
+        .fetch_all(&self.pool)
+        .await?;
+
+        let mut deliveries = rows
+            .into_iter()
+            .map(WebhookDelivery::try_from)
+            .collect::<Result<Vec<_>, _>>()?;
+        // RETURNING does not preserve the subquery order
+        deliveries.sort_by_key(WebhookDelivery::next_attempt_at);
+
+        Ok(deliveries)
+    }
+
+    async fn record_attempt(
+        &self,
+        delivery: &WebhookDelivery,
+        attempt: &DeliveryAttempt,
+    ) -> Result<Option<WebhookDelivery>, RepositoryError> {
+        let mut tx = self.pool.begin().await?;
+
+        let row = sqlx::query_as::<_, DeliveryRow>(concat!(
+            "UPDATE webhook_deliveries \
+             SET status = $2, attempts = $3, next_attempt_at = $4, last_attempt_at = $5, delivered_at = $6, \
+                 locked_until = NULL \
+             WHERE id = $1 AND status = 'pending' RETURNING ",
+            delivery_columns!()
+        ))
+        .bind(delivery.id().as_uuid())
+        .bind(delivery.status().as_str())
+        .bind(attempts_value(delivery)?)
+        .bind(delivery.next_attempt_at())
+        .bind(delivery.last_attempt_at())
+        .bind(delivery.delivered_at())
+        .fetch_optional(&mut *tx)
+        .await?;
+
+        // The delivery was requeued or deleted meanwhile; its log starts afresh
+        let Some(row) = row else {
+            return Ok(None);
+        };
+
+        sqlx::query(
+            r#"
+            INSERT INTO webhook_delivery_attempts (delivery_id, attempted_at, status_code, response_body, error, duration_ms)
+            VALUES ($1, $2, $3, $4, $5, $6)
+            "#,
+        )
+        .bind(attempt.delivery_id.as_uuid())
+        .bind(attempt.attempted_at)
+        .bind(attempt.status_code.map(i32::from))
+        .bind(attempt.response_body.as_deref())
+        .bind(attempt.error.as_deref())
+        .bind(i64::try_from(attempt.duration.as_millis()).unwrap_or(i64::MAX))
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+        WebhookDelivery::try_from(row).map(Some)
+    }
+
+    async fn requeue(&self, delivery: &WebhookDelivery) -> Result<Option<WebhookDelivery>, RepositoryError> {
+        let row = sqlx::query_as::<_, DeliveryRow>(concat!(
+            "UPDATE webhook_deliveries \
+             SET status = $2, attempts = $3, next_attempt_at = $4, delivered_at = $5, locked_until = NULL \
+             WHERE id = $1 AND status <> 'pending' RETURNING ",
+            delivery_columns!()
+        ))
+        .bind(delivery.id().as_uuid())
+        .bind(delivery.status().as_str())
+        .bind(attempts_value(delivery)?)
+        .bind(delivery.next_attempt_at())
+        .bind(delivery.delivered_at())
+        .fetch_optional(&self.pool)
+        .await?;
+
+        row.map(WebhookDelivery::try_from).transpose()
+    }
+}
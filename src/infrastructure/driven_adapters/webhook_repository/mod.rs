@@ -0,0 +1,5 @@
+//! Webhook Repository Implementations
+
+mod postgres;
+
+pub use postgres::PostgresWebhookRepository;
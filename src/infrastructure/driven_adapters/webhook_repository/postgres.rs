@@ -0,0 +1,205 @@
+//! Postgres Webhook Repository Implementation
+//!
+//! Implements the `WebhookRepository` trait using `SQLx` for Postgres.
+//!
+//! Signing secrets are sealed with the secret cipher when one is configured.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::domain::gateways::{SecretCipher, WebhookRepository};
+use crate::domain::models::event::NetworkEventKind;
+use crate::domain::models::network::NetworkId;
+use crate::domain::models::webhook::{Webhook, WebhookId};
+use crate::shared::errors::RepositoryError;
+
+/// Columns selected for a webhook row, shared by every query returning webhooks
+macro_rules! webhook_columns {
+    () => {
+        "id, url, events, network_ids, secret, enabled, created_by, created_at, updated_at"
+    };
+}
+
+/// Database row representation for webhooks table
+#[derive(Debug, sqlx::FromRow)]
+struct WebhookRow {
+    id: Uuid,
+    url: String,
+    events: serde_json::Value,
+    network_ids: serde_json::Value,
+    secret: String,
+    enabled: bool,
+    created_by: String,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+/// Postgres implementation of `WebhookRepository`
+pub struct PostgresWebhookRepository {
+    pool: PgPool,
+    cipher: Option<Arc<dyn SecretCipher>>,
+}
+
+impl PostgresWebhookRepository {
+    /// Create a new `PostgresWebhookRepository`
+    #[must_use]
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool, cipher: None }
+    }
+
+    /// Encrypt signing secrets using the given cipher
+    #[must_use]
+    pub fn with_secret_cipher(mut self, cipher: Arc<dyn SecretCipher>) -> Self {
+        self.cipher = Some(cipher);
+        self
+    }
+
+    /// Encrypt a secret for storage
+    fn seal_secret(&self, secret: &str) -> Result<String, RepositoryError> {
+        match &self.cipher {
+            Some(cipher) => Ok(cipher.encrypt(secret)?),
+            None => Ok(secret.to_string()),
+        }
+    }
+
+    /// Decrypt a stored secret if it was sealed
+    fn open_secret(&self, value: String) -> Result<String, RepositoryError> {
+        match &self.cipher {
+            Some(cipher) if cipher.is_encrypted(&value) => Ok(cipher.decrypt(&value)?),
+            _ => Ok(value),
+        }
+    }
+
+    /// Serialize the event and network filters for their JSONB columns
+    fn filters(webhook: &Webhook) -> Result<(serde_json::Value, serde_json::Value), RepositoryError> {
+        let events: Vec<&str> = webhook.events().iter().map(NetworkEventKind::as_str).collect();
+        let network_ids: Vec<&Uuid> = webhook.network_ids().iter().map(NetworkId::as_uuid).collect();
+
+        let events = serde_json::to_value(events)
+            .map_err(|e| RepositoryError::Mapping(format!("Failed to serialize events: {e}")))?;
+        let network_ids = serde_json::to_value(network_ids)
+            .map_err(|e| RepositoryError::Mapping(format!("Failed to serialize network_ids: {e}")))?;
+        Ok((events, network_ids))
+    }
+
+    /// Decrypt the secret of a row and map it to a Webhook
+    fn to_webhook(&self, row: WebhookRow) -> Result<Webhook, RepositoryError> {
+        let events: Vec<String> = serde_json::from_value(row.events)
+            .map_err(|e| RepositoryError::Mapping(format!("Failed to parse events: {e}")))?;
+        let events = events
+            .iter()
+            .map(|event| event.parse::<NetworkEventKind>())
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| RepositoryError::Mapping(format!("Failed to parse events: {e}")))?;
+        let network_ids: Vec<Uuid> = serde_json::from_value(row.network_ids)
+            .map_err(|e| RepositoryError::Mapping(format!("Failed to parse network_ids: {e}")))?;
+
+        Ok(Webhook::restore(
+            WebhookId::from_uuid(row.id),
+            row.url,
+            events,
+            network_ids.into_iter().map(NetworkId::from_uuid).collect(),
+            self.open_secret(row.secret)?,
+            row.enabled,
+            row.created_by,
+            row.created_at,
+            row.updated_at,
+        ))
+    }
+}
+
+#[async_trait]
+impl WebhookRepository for PostgresWebhookRepository {
+    async fn find_by_id(&self, id: &WebhookId) -> Result<Option<Webhook>, RepositoryError> {
+        let row =
+            sqlx::query_as::<_, WebhookRow>(concat!("SELECT ", webhook_columns!(), " FROM webhooks WHERE id = $1"))
+                .bind(id.as_uuid())
+                .fetch_optional(&self.pool)
+                .await?;
+
+        row.map(|row| self.to_webhook(row)).transpose()
+    }
+
+    async fn find_all(&self) -> Result<Vec<Webhook>, RepositoryError> {
+        let rows = sqlx::query_as::<_, WebhookRow>(concat!(
+            "SELECT ",
+            webhook_columns!(),
+            " FROM webhooks ORDER BY created_at ASC"
+        ))
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.into_iter().map(|row| self.to_webhook(row)).collect()
+    }
+
+    async fn find_enabled(&self) -> Result<Vec<Webhook>, RepositoryError> {
+        let rows = sqlx::query_as::<_, WebhookRow>(concat!(
+            "SELECT ",
+            webhook_columns!(),
+            " FROM webhooks WHERE enabled ORDER BY created_at ASC"
+        ))
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.into_iter().map(|row| self.to_webhook(row)).collect()
+    }
+
+    async fn create(&self, webhook: &Webhook) -> Result<Webhook, RepositoryError> {
+        let (events, network_ids) = Self::filters(webhook)?;
+
+        let row = sqlx::query_as::<_, WebhookRow>(concat!(
+            "INSERT INTO webhooks (",
+            webhook_columns!(),
+            ") VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9) RETURNING ",
+            webhook_columns!()
+        ))
+        .bind(webhook.id().as_uuid())
+        .bind(webhook.url())
+        .bind(events)
+        .bind(network_ids)
+        .bind(self.seal_secret(webhook.secret())?)
+        .bind(webhook.enabled())
+        .bind(webhook.created_by())
+        .bind(webhook.created_at())
+        .bind(webhook.updated_at())
+        .fetch_one(&self.pool)
+        .await?;
+
+        self.to_webhook(row)
+    }
+
+    async fn update(&self, webhook: &Webhook) -> Result<Option<Webhook>, RepositoryError> {
+        let (events, network_ids) = Self::filters(webhook)?;
+
+        let row = sqlx::query_as::<_, WebhookRow>(concat!(
+            "UPDATE webhooks \
+             SET url = $2, events = $3, network_ids = $4, secret = $5, enabled = $6, updated_at = $7 \
+             WHERE id = $1 RETURNING ",
+            webhook_columns!()
+        ))
+        .bind(webhook.id().as_uuid())
+        .bind(webhook.url())
+        .bind(events)
+        .bind(network_ids)
+        .bind(self.seal_secret(webhook.secret())?)
+        .bind(webhook.enabled())
+        .bind(webhook.updated_at())
+        .fetch_optional(&self.pool)
+        .await?;
+
+        row.map(|row| self.to_webhook(row)).transpose()
+    }
+
+    async fn delete(&self, id: &WebhookId) -> Result<bool, RepositoryError> {
+        let result = sqlx::query("DELETE FROM webhooks WHERE id = $1")
+            .bind(id.as_uuid())
+            .execute(&self.pool)
+            .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
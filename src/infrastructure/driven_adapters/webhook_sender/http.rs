@@ -0,0 +1,188 @@
+//! HTTP Webhook Sender
+//!
+//! Posts deliveries to their receivers, signed with HMAC-SHA256.
+//!
+//! The signature covers `{timestamp}.{body}`, so receivers can reject
+//! replayed deliveries by checking the timestamp. Receiver URLs are checked
+//! against the URL policy again when sending, host names are only connected
+//! to when they resolve to public addresses, and redirects are not followed.
+
+use std::error::Error as StdError;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use async_trait::async_trait;
+use chrono::Utc;
+use hmac::{Hmac, Mac};
+use reqwest::dns::{Addrs, Name, Resolve, Resolving};
+use reqwest::header::CONTENT_TYPE;
+use reqwest::redirect::Policy;
+use sha2::Sha256;
+
+use crate::domain::gateways::WebhookSender;
+use crate::domain::models::url_policy::UrlPolicy;
+use crate::domain::models::webhook::{DeliveryOutcome, Webhook, WebhookDelivery, MAX_RESPONSE_BODY_LENGTH};
+
+/// Header carrying the delivery ID, stable across attempts and redeliveries
+pub const DELIVERY_HEADER: &str = "x-webhook-delivery";
+/// Header carrying the event kind
+pub const EVENT_HEADER: &str = "x-webhook-event";
+/// Header carrying the Unix timestamp covered by the signature
+pub const TIMESTAMP_HEADER: &str = "x-webhook-timestamp";
+/// Header carrying the `sha256=<hex>` signature
+pub const SIGNATURE_HEADER: &str = "x-webhook-signature";
+
+/// User agent sent with deliveries
+const USER_AGENT: &str = concat!("blockchain-network-registry-webhooks/", env!("CARGO_PKG_VERSION"));
+
+/// Compute the `sha256=<hex>` signature of a delivery body sent at `timestamp`
+#[must_use]
+pub fn sign_payload(secret: &str, timestamp: i64, payload: &str) -> String {
+    let Ok(mut mac) = Hmac::<Sha256>::new_from_slice(secret.as_bytes()) else {
+        unreachable!("HMAC accepts keys of any length");
+    };
+    mac.update(timestamp.to_string().as_bytes());
+    mac.update(b".");
+    mac.update(payload.as_bytes());
+    format!("sha256={}", hex::encode(mac.finalize().into_bytes()))
+}
+
+/// DNS resolver refusing host names that resolve to internal addresses
+struct PolicyResolver {
+    url_policy: Arc<UrlPolicy>,
+}
+
+impl Resolve for PolicyResolver {
+    fn resolve(&self, name: Name) -> Resolving {
+        let url_policy = self.url_policy.clone();
+        Box::pin(async move {
+            let host = name.as_str().to_string();
+            let addrs: Vec<SocketAddr> = tokio::net::lookup_host((host.as_str(), 0)).await?.collect();
+
+            for addr in &addrs {
+                url_policy.check_resolved(&host, addr.ip())?;
+            }
+
+            let addrs: Addrs = Box::new(addrs.into_iter());
+            Ok(addrs)
+        })
+    }
+}
+
+/// Describe an error along with its sources
+fn describe(error: &dyn StdError) -> String {
+    let mut message = error.to_string();
+    let mut source = error.source();
+    while let Some(cause) = source {
+        message.push_str(": ");
+        message.push_str(&cause.to_string());
+        source = cause.source();
+    }
+    message
+}
+
+/// Sends webhook deliveries over HTTP
+pub struct HttpWebhookSender {
+    client: reqwest::Client,
+    url_policy: Arc<UrlPolicy>,
+}
+
+impl HttpWebhookSender {
+    /// Create a new HttpWebhookSender giving up on receivers after `timeout`
+    /// and only connecting to receivers the URL policy accepts
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the HTTP client cannot be initialized
+    pub fn new(timeout: Duration, url_policy: Arc<UrlPolicy>) -> Result<Self, reqwest::Error> {
+        let client = reqwest::Client::builder()
+            .timeout(timeout)
+            .redirect(Policy::none())
+            .dns_resolver(Arc::new(PolicyResolver {
+                url_policy: url_policy.clone(),
+            }))
+            .user_agent(USER_AGENT)
+            .build()?;
+
+        Ok(Self { client, url_policy })
+    }
+
+    /// Read the beginning of a response body
+    async fn read_body(mut response: reqwest::Response) -> Option<String> {
+        let mut body = Vec::new();
+        while body.len() < MAX_RESPONSE_BODY_LENGTH {
+            match response.chunk().await {
+                Ok(Some(chunk)) => body.extend_from_slice(&chunk),
+                Ok(None) | Err(_) => break,
+            }
+        }
+        body.truncate(MAX_RESPONSE_BODY_LENGTH);
+
+        (!body.is_empty()).then(|| String::from_utf8_lossy(&body).into_owned())
+    }
+}
+
+#[async_trait]
+impl WebhookSender for HttpWebhookSender {
+    async fn send(&self, webhook: &Webhook, delivery: &WebhookDelivery) -> DeliveryOutcome {
+        let started = Instant::now();
+        let failed = |error: String| DeliveryOutcome {
+            status_code: None,
+            response_body: None,
+            error: Some(error),
+            duration: started.elapsed(),
+        };
+
+        // The policy may have been tightened since the webhook was created
+        if let Err(reason) = self.url_policy.check(webhook.url()) {
+            return failed(format!("url {reason}"));
+        }
+
+        let timestamp = Utc::now().timestamp();
+        let signature = sign_payload(webhook.secret(), timestamp, delivery.payload());
+        let request = self
+            .client
+            .post(webhook.url())
+            .header(CONTENT_TYPE, "application/json")
+            .header(DELIVERY_HEADER, delivery.id().to_string())
+            .header(EVENT_HEADER, delivery.event().as_str())
+            .header(TIMESTAMP_HEADER, timestamp.to_string())
+            .header(SIGNATURE_HEADER, signature)
+            .body(delivery.payload().to_string());
+
+        match request.send().await {
+            Ok(response) => {
+                let status = response.status();
+                let response_body = Self::read_body(response).await;
+                DeliveryOutcome {
+                    status_code: Some(status.as_u16()),
+                    response_body,
+                    error: None,
+                    duration: started.elapsed(),
+                }
+            }
+            Err(e) => failed(describe(&e)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sign_payload() {
+        // echo -n '1700000000.{"type":"created"}' | openssl dgst -sha256 -hmac 'whsec_test_secret_value'
+        let signature = sign_payload("whsec_test_secret_value", 1_700_000_000, r#"{"type":"created"}"#);
+        assert_eq!(
+            signature,
+            "sha256=697ff05f9f571c57ed3bf45a7c23f07a03e513906ae48f0d48acc01890be2ea2"
+        );
+
+        assert_ne!(
+            signature,
+            sign_payload("whsec_test_secret_value", 1_700_000_001, r#"{"type":"created"}"#)
+        );
+    }
+}
@@ -0,0 +1,5 @@
+//! Webhook Sender Implementations
+
+mod http;
+
+pub use http::{sign_payload, HttpWebhookSender, SIGNATURE_HEADER, TIMESTAMP_HEADER};
@@ -0,0 +1,284 @@
+//! Conditional Requests
+//!
+//! Validators and `304 Not Modified` answers for read endpoints (RFC 9110).
+//!
+//! Strong `ETag`s derive from the `updated_at` version of the networks
+//! served, bumped by every change, and name the representation since it
+//! depends on the caller's permissions, along with the version of the secret
+//! values when they are resolved. A list's `ETag` covers the ID and
+//! version of each member, so it also changes when a network enters or
+//! leaves it. `Last-Modified` is only given for single networks: the latest
+//! `updated_at` of a list does not move when a network leaves it.
+
+use axum::{
+    http::{header, HeaderMap, HeaderValue, StatusCode},
+    response::{IntoResponse, Response},
+};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+
+use crate::domain::models::network::Network;
+use crate::shared::errors::ApiError;
+
+/// Format of HTTP dates (IMF-fixdate)
+pub const HTTP_DATE_FORMAT: &str = "%a, %d %b %Y %H:%M:%S GMT";
+
+/// Strong `ETag` of a network served as `representation`
+#[must_use]
+pub fn network_etag(representation: &str, network: &Network) -> String {
+    format!(
+        "\"{representation}-{}-{}\"",
+        network.id(),
+        network.updated_at().timestamp_micros()
+    )
+}
+
+/// Strong `ETag` of a list of networks served as `representation`
+#[must_use]
+pub fn list_etag(representation: &str, networks: &[Network]) -> String {
+    let mut versions = Sha256::new();
+    for network in networks {
+        versions.update(network.id().as_uuid().as_bytes());
+        versions.update(network.updated_at().timestamp_micros().to_be_bytes());
+    }
+    format!("\"{representation}-{}\"", hex::encode(versions.finalize()))
+}
+
+/// Build a JSON response, or `304 Not Modified` when the client's copy is current
+///
+/// `headers` are sent in both cases, the body is only serialized when sent.
+/// An empty `cache_control` leaves the `Cache-Control` header out.
+///
+/// # Errors
+///
+/// Returns `ApiError::Internal` if the body cannot be serialized.
+pub fn conditional_json<T: Serialize>(
+    request_headers: &HeaderMap,
+    cache_control: &str,
+    etag: &str,
+    last_modified: Option<DateTime<Utc>>,
+    headers: HeaderMap,
+    body: &T,
+) -> Result<Response, ApiError> {
+    let mut validators = HeaderMap::new();
+    validators.insert(header::VARY, HeaderValue::from_static("authorization, x-api-key"));
+    if let Ok(value) = HeaderValue::from_str(etag) {
+        validators.insert(header::ETAG, value);
+    }
+    if let Some(last_modified) = last_modified {
+        if let Ok(value) = HeaderValue::from_str(&last_modified.format(HTTP_DATE_FORMAT).to_string()) {
+            validators.insert(header::LAST_MODIFIED, value);
+        }
+    }
+    if !cache_control.is_empty() {
+        if let Ok(value) = HeaderValue::from_str(cache_control) {
+            validators.insert(header::CACHE_CONTROL, value);
+        }
+    }
+
+    if is_not_modified(request_headers, etag, last_modified) {
+        return Ok((StatusCode::NOT_MODIFIED, validators, headers).into_response());
+    }
+
+    let body = serde_json::to_vec(body).map_err(|e| ApiError::Internal(e.into()))?;
+    Ok((
+        validators,
+        headers,
+        [(header::CONTENT_TYPE, HeaderValue::from_static("application/json"))],
+        body,
+    )
+        .into_response())
+}
+
+/// Evaluate the request preconditions against the current validators
+///
+/// `If-Modified-Since` is ignored when `If-None-Match` is present.
+fn is_not_modified(request_headers: &HeaderMap, etag: &str, last_modified: Option<DateTime<Utc>>) -> bool {
+    let mut if_none_match = request_headers.get_all(header::IF_NONE_MATCH).iter().peekable();
+    if if_none_match.peek().is_some() {
+        return if_none_match
+            .filter_map(|value| value.to_str().ok())
+            .any(|value| etag_matches(value, etag));
+    }
+
+    let since = request_headers
+        .get(header::IF_MODIFIED_SINCE)
+        .and_then(|value| value.to_str().ok())
+        .and_then(parse_http_date);
+    match (since, last_modified) {
+        // HTTP dates have a one second resolution
+        (Some(since), Some(last_modified)) => last_modified.timestamp() <= since.timestamp(),
+        _ => false,
+    }
+}
+
+/// Weak comparison of an `If-None-Match` list with the current `ETag`
+fn etag_matches(if_none_match: &str, etag: &str) -> bool {
+    if_none_match
+        .split(',')
+        .map(str::trim)
+        .any(|candidate| candidate == "*" || candidate.trim_start_matches("W/") == etag)
+}
+
+fn parse_http_date(value: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc2822(value)
+        .ok()
+        .map(|date| date.with_timezone(&Utc))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::models::explorer::ExplorerMetadata;
+    use crate::domain::models::fee_config::FeeConfig;
+    use crate::domain::models::finality::FinalityPolicy;
+    use crate::domain::models::labels::NetworkMetadata;
+    use crate::domain::models::lifecycle::NetworkStatus;
+    use crate::domain::models::network::CreateNetworkData;
+    use crate::domain::models::testnet::TestnetInfo;
+    use crate::domain::models::url_policy::UrlPolicy;
+    use chrono::TimeZone;
+    use rust_decimal_macros::dec;
+
+    const ETAG: &str = "\"abc\"";
+
+    fn request(name: header::HeaderName, value: &str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(name, HeaderValue::from_str(value).unwrap());
+        headers
+    }
+
+    fn create_test_network(chain_id: i32) -> Network {
+        let data = CreateNetworkData {
+            chain_id,
+            name: format!("Network {chain_id}"),
+            rpc_url: "https://rpc.example.com".to_string(),
+            other_rpc_urls: vec![],
+            test_net: false,
+            block_explorer_url: "https://explorer.example.com".to_string(),
+            fee_multiplier: dec!(1.0),
+            gas_limit_multiplier: dec!(1.2),
+            default_signer_address: "0x742d35Cc6634C0532925a3b844Bc9e7595f1dEaD".to_string(),
+            explorer: ExplorerMetadata::default(),
+            fee_config: FeeConfig::default(),
+            finality: FinalityPolicy::default(),
+            status: NetworkStatus::Active,
+            parent: None,
+            tags: vec![],
+            metadata: NetworkMetadata::new(),
+            slug: None,
+            aliases: vec![],
+            testnet: TestnetInfo::default(),
+        };
+        Network::new(data, &UrlPolicy::default()).expect("valid test data")
+    }
+
+    fn last_modified() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2025, 3, 1, 12, 0, 0).unwrap() + chrono::Duration::milliseconds(250)
+    }
+
+    #[test]
+    fn test_if_none_match() {
+        for value in ["\"abc\"", "W/\"abc\"", "\"old\", \"abc\"", "*"] {
+            assert!(
+                is_not_modified(&request(header::IF_NONE_MATCH, value), ETAG, None),
+                "{value}"
+            );
+        }
+        assert!(!is_not_modified(&request(header::IF_NONE_MATCH, "\"old\""), ETAG, None));
+        assert!(!is_not_modified(&HeaderMap::new(), ETAG, Some(last_modified())));
+    }
+
+    #[test]
+    fn test_if_modified_since() {
+        let unchanged = request(header::IF_MODIFIED_SINCE, "Sat, 01 Mar 2025 12:00:00 GMT");
+        assert!(is_not_modified(&unchanged, ETAG, Some(last_modified())));
+
+        let outdated = request(header::IF_MODIFIED_SINCE, "Sat, 01 Mar 2025 11:59:59 GMT");
+        assert!(!is_not_modified(&outdated, ETAG, Some(last_modified())));
+
+        // Lists have no Last-Modified, and malformed dates are ignored
+        assert!(!is_not_modified(&unchanged, ETAG, None));
+        let malformed = request(header::IF_MODIFIED_SINCE, "yesterday");
+        assert!(!is_not_modified(&malformed, ETAG, Some(last_modified())));
+    }
+
+    #[test]
+    fn test_if_none_match_takes_precedence() {
+        let mut headers = request(header::IF_NONE_MATCH, "\"old\"");
+        headers.insert(
+            header::IF_MODIFIED_SINCE,
+            HeaderValue::from_static("Sat, 01 Mar 2025 12:00:00 GMT"),
+        );
+        assert!(!is_not_modified(&headers, ETAG, Some(last_modified())));
+    }
+
+    #[test]
+    fn test_etags_follow_versions_and_membership() {
+        let network = create_test_network(1);
+        let other = create_test_network(2);
+        let etag = network_etag("redacted", &network);
+        assert_eq!(etag, network_etag("redacted", &network));
+        assert_ne!(etag, network_etag("resolved", &network));
+
+        // Versions have a microsecond resolution
+        std::thread::sleep(std::time::Duration::from_millis(1));
+        let retired = network.clone().transition_to(NetworkStatus::Retired, None).unwrap();
+        assert_ne!(etag, network_etag("redacted", &retired));
+
+        let list = list_etag("redacted", &[network.clone(), other.clone()]);
+        assert_eq!(list, list_etag("redacted", &[network.clone(), other.clone()]));
+        assert_ne!(list, list_etag("redacted", &[network.clone()]));
+        assert_ne!(list, list_etag("redacted", &[retired, other]));
+    }
+
+    #[test]
+    fn test_conditional_json() {
+        let mut lifecycle = HeaderMap::new();
+        lifecycle.insert("sunset", HeaderValue::from_static("Sat, 01 Mar 2025 12:00:00 GMT"));
+
+        let response = conditional_json(
+            &HeaderMap::new(),
+            "private, no-cache",
+            ETAG,
+            Some(last_modified()),
+            lifecycle.clone(),
+            &[1, 2],
+        )
+        .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers()[header::CACHE_CONTROL], "private, no-cache");
+        assert_eq!(response.headers()[header::ETAG], ETAG);
+        assert_eq!(
+            response.headers()[header::LAST_MODIFIED],
+            "Sat, 01 Mar 2025 12:00:00 GMT"
+        );
+        assert!(response.headers().contains_key("sunset"));
+
+        let response = conditional_json(
+            &request(header::IF_NONE_MATCH, ETAG),
+            "",
+            ETAG,
+            None,
+            lifecycle,
+            &[1, 2],
+        )
+        .unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_MODIFIED);
+        assert_eq!(response.headers()[header::ETAG], ETAG);
+        assert!(response.headers().contains_key("sunset"));
+        assert!(!response.headers().contains_key(header::CACHE_CONTROL));
+
+        let response = conditional_json(
+            &request(header::IF_NONE_MATCH, ETAG),
+            "",
+            "\"new\"",
+            None,
+            HeaderMap::new(),
+            &[1, 3],
+        )
+        .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+}
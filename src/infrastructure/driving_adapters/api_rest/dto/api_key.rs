@@ -0,0 +1,90 @@
+//! API Key DTOs
+//!
+//! Data transfer objects for the API key management endpoints.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+use crate::domain::models::api_key::{ApiKey, ApiKeyData};
+
+/// DTO for creating an API key
+#[derive(Debug, Clone, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateApiKeyDto {
+    #[validate(length(min = 1, max = 100, message = "name must be between 1 and 100 characters"))]
+    pub name: String,
+
+    /// Team or service the key is issued to
+    #[validate(length(min = 1, max = 255, message = "owner must be between 1 and 255 characters"))]
+    pub owner: String,
+
+    /// Permissions granted to the key, among those the caller holds
+    #[serde(default)]
+    #[validate(length(max = 20, message = "scopes can have at most 20 items"))]
+    pub scopes: Vec<String>,
+
+    /// Never expires when omitted
+    pub expires_at: Option<DateTime<Utc>>,
+}
+
+impl From<CreateApiKeyDto> for ApiKeyData {
+    fn from(dto: CreateApiKeyDto) -> Self {
+        Self {
+            name: dto.name,
+            owner: dto.owner,
+            scopes: dto.scopes,
+            expires_at: dto.expires_at,
+        }
+    }
+}
+
+/// API key response DTO
+///
+/// The key itself is only returned when the API key is created.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ApiKeyResponseDto {
+    pub id: String,
+    pub name: String,
+    pub owner: String,
+    /// First characters of the key, to recognize it
+    pub prefix: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub key: Option<String>,
+    pub scopes: Vec<String>,
+    pub expires_at: Option<DateTime<Utc>>,
+    pub last_used_at: Option<DateTime<Utc>>,
+    pub revoked_at: Option<DateTime<Utc>>,
+    pub created_by: String,
+    pub created_at: DateTime<Utc>,
+}
+
+impl ApiKeyResponseDto {
+    /// Build the response returned on creation, including the key
+    #[must_use]
+    pub fn with_key(api_key: ApiKey, key: String) -> Self {
+        Self {
+            key: Some(key),
+            ..Self::from(api_key)
+        }
+    }
+}
+
+impl From<ApiKey> for ApiKeyResponseDto {
+    fn from(api_key: ApiKey) -> Self {
+        Self {
+            id: api_key.id().to_string(),
+            name: api_key.name().to_string(),
+            owner: api_key.owner().to_string(),
+            prefix: api_key.prefix().to_string(),
+            key: None,
+            scopes: api_key.scopes().to_vec(),
+            expires_at: api_key.expires_at(),
+            last_used_at: api_key.last_used_at(),
+            revoked_at: api_key.revoked_at(),
+            created_by: api_key.created_by().to_string(),
+            created_at: api_key.created_at(),
+        }
+    }
+}
@@ -0,0 +1,41 @@
+//! Token Endpoint DTOs
+//!
+//! Data transfer objects for the client credentials grant. Field names
+//! follow OAuth 2.0 (RFC 6749) rather than the camelCase of the other DTOs,
+//! so that standard clients can use the endpoint.
+
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+
+use crate::domain::models::service_account::IssuedToken;
+
+/// Grant type accepted by the token endpoint
+pub const CLIENT_CREDENTIALS_GRANT: &str = "client_credentials";
+
+/// Form-encoded token request
+#[derive(Debug, Clone, Deserialize)]
+pub struct ClientCredentialsDto {
+    pub grant_type: String,
+    pub client_id: String,
+    pub client_secret: String,
+}
+
+/// Response DTO for an issued token
+#[derive(Debug, Clone, Serialize)]
+pub struct AccessTokenResponseDto {
+    pub access_token: String,
+    /// Always `Bearer`
+    pub token_type: &'static str,
+    /// Seconds until the token expires
+    pub expires_in: i64,
+}
+
+impl From<IssuedToken> for AccessTokenResponseDto {
+    fn from(issued: IssuedToken) -> Self {
+        Self {
+            expires_in: (issued.expires_at - Utc::now()).num_seconds().max(0),
+            access_token: issued.token,
+            token_type: "Bearer",
+        }
+    }
+}
@@ -0,0 +1,162 @@
+//! Network Contract DTOs
+//!
+//! Data transfer objects for the contract address book endpoints.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+use super::network::validate_ethereum_address;
+use crate::domain::models::contract::{ContractData, ContractDeployment, NetworkContract};
+
+/// Validates contract tags
+fn validate_tags(tags: &[String]) -> Result<(), validator::ValidationError> {
+    if tags.iter().all(|tag| !tag.trim().is_empty() && tag.len() <= 50) {
+        Ok(())
+    } else {
+        let mut error = validator::ValidationError::new("tags");
+        error.message = Some("each tag must be between 1 and 50 characters".into());
+        Err(error)
+    }
+}
+
+/// DTO for registering or replacing a contract
+#[derive(Debug, Clone, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct ContractDto {
+    #[validate(length(min = 1, max = 100, message = "name must be between 1 and 100 characters"))]
+    pub name: String,
+
+    #[validate(custom(function = "validate_ethereum_address"))]
+    pub address: String,
+
+    #[serde(default)]
+    #[validate(range(min = 0, message = "deployment_block must be at least 0"))]
+    pub deployment_block: Option<i64>,
+
+    /// Where to find the ABI (artifact name, URL, IPFS CID, ...)
+    #[serde(default)]
+    #[validate(length(min = 1, max = 500, message = "abi_ref must be between 1 and 500 characters"))]
+    pub abi_ref: Option<String>,
+
+    #[serde(default)]
+    #[validate(length(max = 20, message = "tags can have at most 20 items"))]
+    #[validate(custom(function = "validate_tags"))]
+    pub tags: Vec<String>,
+}
+
+impl From<ContractDto> for ContractData {
+    fn from(dto: ContractDto) -> Self {
+        Self {
+            name: dto.name,
+            address: dto.address,
+            deployment_block: dto.deployment_block,
+            abi_ref: dto.abi_ref,
+            tags: dto.tags,
+        }
+    }
+}
+
+/// Contract response DTO
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ContractResponseDto {
+    pub id: String,
+    pub network_id: String,
+    pub name: String,
+    pub address: String,
+    pub deployment_block: Option<i64>,
+    pub abi_ref: Option<String>,
+    pub tags: Vec<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<NetworkContract> for ContractResponseDto {
+    fn from(contract: NetworkContract) -> Self {
+        Self {
+            id: contract.id().to_string(),
+            network_id: contract.network_id().to_string(),
+            name: contract.name().to_string(),
+            address: contract.address().to_string(),
+            deployment_block: contract.deployment_block(),
+            abi_ref: contract.abi_ref().map(str::to_string),
+            tags: contract.tags().to_vec(),
+            created_at: contract.created_at(),
+            updated_at: contract.updated_at(),
+        }
+    }
+}
+
+/// Query parameters for the cross-network contract lookup
+#[derive(Debug, Clone, Deserialize)]
+pub struct ContractLookupQueryDto {
+    pub name: String,
+}
+
+/// A contract deployment found by name
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ContractDeploymentDto {
+    pub chain_id: i32,
+    pub network_name: String,
+    #[serde(flatten)]
+    pub contract: ContractResponseDto,
+}
+
+impl From<ContractDeployment> for ContractDeploymentDto {
+    fn from(deployment: ContractDeployment) -> Self {
+        Self {
+            chain_id: deployment.chain_id,
+            network_name: deployment.network_name,
+            contract: deployment.contract.into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_contract_dto_validation() {
+        let dto: ContractDto = serde_json::from_str(
+            r#"{"name": "Multicall3", "address": "0xcA11bde05977b3631167028862bE2a173976CA11", "deploymentBlock": 14353601}"#,
+        )
+        .unwrap();
+        assert!(dto.validate().is_ok());
+        assert!(dto.tags.is_empty());
+
+        let invalid: ContractDto =
+            serde_json::from_str(r#"{"name": "Multicall3", "address": "0x1234", "tags": [""]}"#).unwrap();
+        let errors = invalid.validate().unwrap_err();
+        assert!(errors.field_errors().contains_key("address"));
+        assert!(errors.field_errors().contains_key("tags"));
+    }
+
+    #[test]
+    fn test_deployment_serialization_is_flat() {
+        let contract = NetworkContract::new(
+            crate::domain::models::network::NetworkId::new(),
+            ContractDto {
+                name: "Multicall3".to_string(),
+                address: "0xcA11bde05977b3631167028862bE2a173976CA11".to_string(),
+                deployment_block: None,
+                abi_ref: None,
+                tags: vec!["utility".to_string()],
+            }
+            .into(),
+        )
+        .unwrap();
+
+        let json = serde_json::to_value(ContractDeploymentDto::from(ContractDeployment {
+            chain_id: 1,
+            network_name: "Ethereum Mainnet".to_string(),
+            contract,
+        }))
+        .unwrap();
+        assert_eq!(json["chainId"], 1);
+        assert_eq!(json["name"], "Multicall3");
+        assert_eq!(json["tags"][0], "utility");
+    }
+}
@@ -0,0 +1,35 @@
+//! Network Event DTOs
+//!
+//! Data transfer objects for the network change event stream.
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+use crate::domain::models::event::NetworkEventKind;
+use crate::infrastructure::driving_adapters::api_rest::dto::network::NetworkResponseDto;
+
+/// Payload of a network change event
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NetworkEventDto {
+    /// `created`, `updated`, `deactivated` or `restored`
+    #[serde(rename = "type")]
+    pub kind: String,
+    pub network_id: String,
+    pub occurred_at: DateTime<Utc>,
+    /// The network after the change
+    pub network: NetworkResponseDto,
+}
+
+impl NetworkEventDto {
+    /// Create the payload for an event about an already presented network
+    #[must_use]
+    pub fn new(kind: NetworkEventKind, occurred_at: DateTime<Utc>, network: NetworkResponseDto) -> Self {
+        Self {
+            kind: kind.to_string(),
+            network_id: network.id.clone(),
+            occurred_at,
+            network,
+        }
+    }
+}
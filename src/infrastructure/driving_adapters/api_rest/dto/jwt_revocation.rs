@@ -0,0 +1,123 @@
+//! JWT Revocation DTOs
+//!
+//! Data transfer objects for the JWT revocation endpoints.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+use crate::domain::models::jwt_revocation::{
+    JwtRevocation, JwtRevocationData, SubjectRevocation, SubjectRevocationData,
+};
+
+/// DTO for revoking a single JWT
+#[derive(Debug, Clone, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct RevokeJwtDto {
+    /// ID of the token (`jti` claim)
+    #[validate(length(min = 1, max = 128, message = "jti must be between 1 and 128 characters"))]
+    pub jti: String,
+
+    /// Subject of the token, for reference only
+    #[validate(length(min = 1, max = 255, message = "subject must be between 1 and 255 characters"))]
+    pub subject: Option<String>,
+
+    #[validate(length(max = 500, message = "reason must be at most 500 characters"))]
+    pub reason: Option<String>,
+
+    /// Expiry of the token (`exp` claim); the maximum token lifetime from now when omitted
+    pub expires_at: Option<DateTime<Utc>>,
+}
+
+impl From<RevokeJwtDto> for JwtRevocationData {
+    fn from(dto: RevokeJwtDto) -> Self {
+        Self {
+            jti: dto.jti,
+            subject: dto.subject,
+            reason: dto.reason,
+            expires_at: dto.expires_at,
+        }
+    }
+}
+
+/// DTO for revoking the JWTs of a subject
+#[derive(Debug, Clone, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct RevokeSubjectDto {
+    /// Subject of the tokens (`sub` claim)
+    #[validate(length(min = 1, max = 255, message = "subject must be between 1 and 255 characters"))]
+    pub subject: String,
+
+    /// Tokens issued at or before this time are revoked; now when omitted
+    pub revoked_before: Option<DateTime<Utc>>,
+
+    #[validate(length(max = 500, message = "reason must be at most 500 characters"))]
+    pub reason: Option<String>,
+}
+
+impl From<RevokeSubjectDto> for SubjectRevocationData {
+    fn from(dto: RevokeSubjectDto) -> Self {
+        Self {
+            subject: dto.subject,
+            revoked_before: dto.revoked_before,
+            reason: dto.reason,
+        }
+    }
+}
+
+/// Revoked JWT response DTO
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JwtRevocationResponseDto {
+    pub jti: String,
+    pub subject: Option<String>,
+    pub reason: Option<String>,
+    pub expires_at: DateTime<Utc>,
+    pub revoked_by: String,
+    pub revoked_at: DateTime<Utc>,
+}
+
+impl From<JwtRevocation> for JwtRevocationResponseDto {
+    fn from(revocation: JwtRevocation) -> Self {
+        Self {
+            jti: revocation.jti().to_string(),
+            subject: revocation.subject().map(ToString::to_string),
+            reason: revocation.reason().map(ToString::to_string),
+            expires_at: revocation.expires_at(),
+            revoked_by: revocation.revoked_by().to_string(),
+            revoked_at: revocation.revoked_at(),
+        }
+    }
+}
+
+/// Revoked subject response DTO
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SubjectRevocationResponseDto {
+    pub subject: String,
+    pub revoked_before: DateTime<Utc>,
+    pub reason: Option<String>,
+    pub revoked_by: String,
+    pub revoked_at: DateTime<Utc>,
+}
+
+impl From<SubjectRevocation> for SubjectRevocationResponseDto {
+    fn from(revocation: SubjectRevocation) -> Self {
+        Self {
+            subject: revocation.subject().to_string(),
+            revoked_before: revocation.revoked_before(),
+            reason: revocation.reason().map(ToString::to_string),
+            revoked_by: revocation.revoked_by().to_string(),
+            revoked_at: revocation.revoked_at(),
+        }
+    }
+}
+
+/// JWT revocation list response DTO
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JwtRevocationListResponseDto {
+    /// Revoked JWTs that have not expired yet
+    pub tokens: Vec<JwtRevocationResponseDto>,
+    pub subjects: Vec<SubjectRevocationResponseDto>,
+}
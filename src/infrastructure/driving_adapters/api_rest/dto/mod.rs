@@ -2,8 +2,31 @@
 //!
 //! Request and response DTOs for the REST API.
 
+pub mod api_key;
+pub mod auth;
+pub mod contract;
+pub mod event;
+pub mod jwt_revocation;
 pub mod network;
+pub mod schedule;
+pub mod signer;
+pub mod token;
+pub mod webhook;
 
+pub use api_key::{ApiKeyResponseDto, CreateApiKeyDto};
+pub use auth::{AccessTokenResponseDto, ClientCredentialsDto};
+pub use contract::{ContractDeploymentDto, ContractDto, ContractResponseDto};
+pub use event::NetworkEventDto;
+pub use jwt_revocation::{
+    JwtRevocationListResponseDto, JwtRevocationResponseDto, RevokeJwtDto, RevokeSubjectDto,
+    SubjectRevocationResponseDto,
+};
 pub use network::{
     CreateNetworkDto, NetworkResponseDto, PatchNetworkDto, UpdateNetworkDto,
 };
+pub use schedule::{CreateScheduleDto, ScheduleResponseDto};
+pub use signer::{ResolvedSignerDto, SignerDto, SignerResponseDto};
+pub use token::{
+    CreateTokenDto, ImportTokensDto, TokenImportResponseDto, TokenListDto, TokenResponseDto, UpdateTokenDto,
+};
+pub use webhook::{DeliveryResponseDto, WebhookDto, WebhookResponseDto};
@@ -9,7 +9,20 @@ use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use validator::Validate;
 
-use crate::domain::models::network::{CreateNetworkData, Network, UpdateNetworkData};
+use crate::domain::gateways::SecretResolver;
+use crate::domain::models::explorer::{
+    ExplorerKind, ExplorerLinkQuery, ExplorerLinks, ExplorerMetadata, ExplorerStandard,
+};
+use crate::domain::models::fee_config::{FeeBounds, FeeConfig, FeeModel, FeeUnit};
+use crate::domain::models::finality::{
+    FinalityPolicy, DEFAULT_BLOCK_TIME_MS, DEFAULT_REQUIRED_CONFIRMATIONS, MAX_BLOCK_TIME_MS, MAX_CONFIRMATIONS,
+};
+use crate::domain::models::labels::{MetadataUpdate, NetworkMetadata};
+use crate::domain::models::lifecycle::NetworkStatus;
+use crate::domain::models::network::{CreateNetworkData, Network, NetworkId, UpdateNetworkData};
+use crate::domain::models::relation::{NetworkParent, NetworkRelationKind};
+use crate::domain::models::rpc_url;
+use crate::domain::models::testnet::TestnetInfo;
 
 /// Regex for validating Ethereum addresses (0x followed by 40 hex characters)
 /// This regex is validated at compile time via the test suite.
@@ -19,7 +32,7 @@ static ETHEREUM_ADDRESS_REGEX: Lazy<Regex> = Lazy::new(|| {
 });
 
 /// Validates an Ethereum address format
-fn validate_ethereum_address(address: &str) -> Result<(), validator::ValidationError> {
+pub(crate) fn validate_ethereum_address(address: &str) -> Result<(), validator::ValidationError> {
     if ETHEREUM_ADDRESS_REGEX.is_match(address) {
         Ok(())
     } else {
@@ -30,6 +43,8 @@ fn validate_ethereum_address(address: &str) -> Result<(), validator::ValidationE
 }
 
 /// Validates a URL format (must start with http:// or https://)
+///
+/// Internal targets are rejected by the domain, which checks URLs against the URL policy.
 fn validate_url(url: &str) -> Result<(), validator::ValidationError> {
     // Check protocol
     if !url.starts_with("http://") && !url.starts_with("https://") {
@@ -39,7 +54,10 @@ fn validate_url(url: &str) -> Result<(), validator::ValidationError> {
     }
 
     // Check URL has a host (not just protocol)
-    let without_protocol = url.strip_prefix("https://").or_else(|| url.strip_prefix("http://")).unwrap_or("");
+    let without_protocol = url
+        .strip_prefix("https://")
+        .or_else(|| url.strip_prefix("http://"))
+        .unwrap_or("");
     if without_protocol.is_empty() || without_protocol.starts_with('/') {
         let mut error = validator::ValidationError::new("url");
         error.message = Some("URL must include a valid host".into());
@@ -83,6 +101,423 @@ fn f64_to_decimal(value: f64) -> Decimal {
     Decimal::try_from(value).expect("value should have been validated")
 }
 
+/// Explorer software family
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ExplorerKindDto {
+    Etherscan,
+    Blockscout,
+    #[default]
+    Other,
+}
+
+impl From<ExplorerKindDto> for ExplorerKind {
+    fn from(dto: ExplorerKindDto) -> Self {
+        match dto {
+            ExplorerKindDto::Etherscan => Self::Etherscan,
+            ExplorerKindDto::Blockscout => Self::Blockscout,
+            ExplorerKindDto::Other => Self::Other,
+        }
+    }
+}
+
+impl From<ExplorerKind> for ExplorerKindDto {
+    fn from(kind: ExplorerKind) -> Self {
+        match kind {
+            ExplorerKind::Etherscan => Self::Etherscan,
+            ExplorerKind::Blockscout => Self::Blockscout,
+            ExplorerKind::Other => Self::Other,
+        }
+    }
+}
+
+/// URL standard implemented by the explorer
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum ExplorerStandardDto {
+    #[default]
+    #[serde(rename = "EIP3091")]
+    Eip3091,
+    #[serde(rename = "none")]
+    None,
+}
+
+impl From<ExplorerStandardDto> for ExplorerStandard {
+    fn from(dto: ExplorerStandardDto) -> Self {
+        match dto {
+            ExplorerStandardDto::Eip3091 => Self::Eip3091,
+            ExplorerStandardDto::None => Self::None,
+        }
+    }
+}
+
+impl From<ExplorerStandard> for ExplorerStandardDto {
+    fn from(standard: ExplorerStandard) -> Self {
+        match standard {
+            ExplorerStandard::Eip3091 => Self::Eip3091,
+            ExplorerStandard::None => Self::None,
+        }
+    }
+}
+
+/// Block explorer metadata
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct ExplorerMetadataDto {
+    #[serde(default)]
+    pub kind: ExplorerKindDto,
+
+    #[validate(length(max = 500, message = "api_url must be at most 500 characters"))]
+    #[validate(custom(function = "validate_url"))]
+    pub api_url: Option<String>,
+
+    #[serde(default)]
+    pub standard: ExplorerStandardDto,
+}
+
+impl From<ExplorerMetadataDto> for ExplorerMetadata {
+    fn from(dto: ExplorerMetadataDto) -> Self {
+        Self {
+            kind: dto.kind.into(),
+            api_url: dto.api_url,
+            standard: dto.standard.into(),
+        }
+    }
+}
+
+impl From<&ExplorerMetadata> for ExplorerMetadataDto {
+    fn from(metadata: &ExplorerMetadata) -> Self {
+        Self {
+            kind: metadata.kind.into(),
+            api_url: metadata.api_url.clone(),
+            standard: metadata.standard.into(),
+        }
+    }
+}
+
+/// Transaction fee model
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FeeModelDto {
+    #[default]
+    Legacy,
+    Eip1559,
+}
+
+impl From<FeeModelDto> for FeeModel {
+    fn from(dto: FeeModelDto) -> Self {
+        match dto {
+            FeeModelDto::Legacy => Self::Legacy,
+            FeeModelDto::Eip1559 => Self::Eip1559,
+        }
+    }
+}
+
+impl From<FeeModel> for FeeModelDto {
+    fn from(model: FeeModel) -> Self {
+        match model {
+            FeeModel::Legacy => Self::Legacy,
+            FeeModel::Eip1559 => Self::Eip1559,
+        }
+    }
+}
+
+/// Unit of the fee amounts in a fee configuration
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FeeUnitDto {
+    Wei,
+    #[default]
+    Gwei,
+}
+
+impl From<FeeUnitDto> for FeeUnit {
+    fn from(dto: FeeUnitDto) -> Self {
+        match dto {
+            FeeUnitDto::Wei => Self::Wei,
+            FeeUnitDto::Gwei => Self::Gwei,
+        }
+    }
+}
+
+/// Fee configuration
+///
+/// Amounts are decimals in `unit` (gwei unless specified); responses always use wei.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct FeeConfigDto {
+    #[serde(default)]
+    pub model: FeeModelDto,
+
+    #[serde(default)]
+    pub unit: FeeUnitDto,
+
+    pub max_fee_per_gas_floor: Option<Decimal>,
+    pub max_fee_per_gas_cap: Option<Decimal>,
+    pub max_priority_fee_per_gas_floor: Option<Decimal>,
+    pub max_priority_fee_per_gas_cap: Option<Decimal>,
+
+    #[validate(custom(function = "validate_decimal"))]
+    pub base_fee_multiplier: Option<f64>,
+
+    pub gas_limit_cap: Option<i64>,
+}
+
+impl From<FeeConfigDto> for FeeConfig {
+    fn from(dto: FeeConfigDto) -> Self {
+        let unit = FeeUnit::from(dto.unit);
+        // Amounts overflowing on conversion become Decimal::MAX, which domain validation rejects
+        let to_wei =
+            |amount: Option<Decimal>| amount.map(|amount| unit.to_wei(amount).unwrap_or(Decimal::MAX).normalize());
+
+        Self {
+            model: dto.model.into(),
+            max_fee_per_gas: FeeBounds {
+                floor: to_wei(dto.max_fee_per_gas_floor),
+                cap: to_wei(dto.max_fee_per_gas_cap),
+            },
+            max_priority_fee_per_gas: FeeBounds {
+                floor: to_wei(dto.max_priority_fee_per_gas_floor),
+                cap: to_wei(dto.max_priority_fee_per_gas_cap),
+            },
+            base_fee_multiplier: dto.base_fee_multiplier.map(f64_to_decimal),
+            gas_limit_cap: dto.gas_limit_cap,
+        }
+    }
+}
+
+impl From<&FeeConfig> for FeeConfigDto {
+    fn from(config: &FeeConfig) -> Self {
+        Self {
+            model: config.model.into(),
+            unit: FeeUnitDto::Wei,
+            max_fee_per_gas_floor: config.max_fee_per_gas.floor,
+            max_fee_per_gas_cap: config.max_fee_per_gas.cap,
+            max_priority_fee_per_gas_floor: config.max_priority_fee_per_gas.floor,
+            max_priority_fee_per_gas_cap: config.max_priority_fee_per_gas.cap,
+            base_fee_multiplier: config.base_fee_multiplier.and_then(|m| m.try_into().ok()),
+            gas_limit_cap: config.gas_limit_cap,
+        }
+    }
+}
+
+/// Finality and confirmation policy
+///
+/// Omitted fields take the registry defaults.
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+#[serde(rename_all = "camelCase", default)]
+pub struct FinalityPolicyDto {
+    #[validate(range(
+        min = 1,
+        max = "MAX_BLOCK_TIME_MS",
+        message = "average_block_time_ms is out of range"
+    ))]
+    pub average_block_time_ms: i32,
+
+    #[validate(range(
+        min = 0,
+        max = "MAX_CONFIRMATIONS",
+        message = "required_confirmations is out of range"
+    ))]
+    pub required_confirmations: i32,
+
+    pub supports_safe_tag: bool,
+    pub supports_finalized_tag: bool,
+
+    #[validate(range(min = 0, max = "MAX_CONFIRMATIONS", message = "max_reorg_depth is out of range"))]
+    pub max_reorg_depth: Option<i32>,
+}
+
+impl Default for FinalityPolicyDto {
+    fn default() -> Self {
+        Self {
+            average_block_time_ms: DEFAULT_BLOCK_TIME_MS,
+            required_confirmations: DEFAULT_REQUIRED_CONFIRMATIONS,
+            supports_safe_tag: false,
+            supports_finalized_tag: false,
+            max_reorg_depth: None,
+        }
+    }
+}
+
+impl From<FinalityPolicyDto> for FinalityPolicy {
+    fn from(dto: FinalityPolicyDto) -> Self {
+        Self {
+            average_block_time_ms: dto.average_block_time_ms,
+            required_confirmations: dto.required_confirmations,
+            supports_safe_tag: dto.supports_safe_tag,
+            supports_finalized_tag: dto.supports_finalized_tag,
+            max_reorg_depth: dto.max_reorg_depth,
+        }
+    }
+}
+
+impl From<&FinalityPolicy> for FinalityPolicyDto {
+    fn from(policy: &FinalityPolicy) -> Self {
+        Self {
+            average_block_time_ms: policy.average_block_time_ms,
+            required_confirmations: policy.required_confirmations,
+            supports_safe_tag: policy.supports_safe_tag,
+            supports_finalized_tag: policy.supports_finalized_tag,
+            max_reorg_depth: policy.max_reorg_depth,
+        }
+    }
+}
+
+/// Finality policy response with derived recommendations
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FinalityResponseDto {
+    #[serde(flatten)]
+    pub policy: FinalityPolicyDto,
+    pub recommended_confirmations: i32,
+    pub recommended_wait_ms: i64,
+    pub recommended_block_tag: &'static str,
+}
+
+impl From<&FinalityPolicy> for FinalityResponseDto {
+    fn from(policy: &FinalityPolicy) -> Self {
+        Self {
+            policy: policy.into(),
+            recommended_confirmations: policy.recommended_confirmations(),
+            recommended_wait_ms: policy.recommended_wait_ms(),
+            recommended_block_tag: policy.recommended_block_tag().as_str(),
+        }
+    }
+}
+
+/// Network lifecycle state
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum NetworkStatusDto {
+    Draft,
+    #[default]
+    Active,
+    Deprecated,
+    Retired,
+}
+
+impl From<NetworkStatusDto> for NetworkStatus {
+    fn from(dto: NetworkStatusDto) -> Self {
+        match dto {
+            NetworkStatusDto::Draft => Self::Draft,
+            NetworkStatusDto::Active => Self::Active,
+            NetworkStatusDto::Deprecated => Self::Deprecated,
+            NetworkStatusDto::Retired => Self::Retired,
+        }
+    }
+}
+
+impl From<NetworkStatus> for NetworkStatusDto {
+    fn from(status: NetworkStatus) -> Self {
+        match status {
+            NetworkStatus::Draft => Self::Draft,
+            NetworkStatus::Active => Self::Active,
+            NetworkStatus::Deprecated => Self::Deprecated,
+            NetworkStatus::Retired => Self::Retired,
+        }
+    }
+}
+
+/// Relation of a network to its parent
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum NetworkRelationKindDto {
+    Rollup,
+    Sidechain,
+    TestnetOf,
+}
+
+impl From<NetworkRelationKindDto> for NetworkRelationKind {
+    fn from(dto: NetworkRelationKindDto) -> Self {
+        match dto {
+            NetworkRelationKindDto::Rollup => Self::Rollup,
+            NetworkRelationKindDto::Sidechain => Self::Sidechain,
+            NetworkRelationKindDto::TestnetOf => Self::TestnetOf,
+        }
+    }
+}
+
+impl From<NetworkRelationKind> for NetworkRelationKindDto {
+    fn from(kind: NetworkRelationKind) -> Self {
+        match kind {
+            NetworkRelationKind::Rollup => Self::Rollup,
+            NetworkRelationKind::Sidechain => Self::Sidechain,
+            NetworkRelationKind::TestnetOf => Self::TestnetOf,
+        }
+    }
+}
+
+/// Parent network and relation kind, used in requests and responses
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NetworkParentDto {
+    pub network_id: uuid::Uuid,
+    pub relation: NetworkRelationKindDto,
+}
+
+impl From<NetworkParentDto> for NetworkParent {
+    fn from(dto: NetworkParentDto) -> Self {
+        Self {
+            network_id: NetworkId::from_uuid(dto.network_id),
+            kind: dto.relation.into(),
+        }
+    }
+}
+
+impl From<&NetworkParent> for NetworkParentDto {
+    fn from(parent: &NetworkParent) -> Self {
+        Self {
+            network_id: *parent.network_id.as_uuid(),
+            relation: parent.kind.into(),
+        }
+    }
+}
+
+/// Testnet faucets, mirrored mainnet and expected deprecation, used in requests and responses
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct TestnetDto {
+    #[serde(default)]
+    #[validate(length(max = 10, message = "testnet.faucet_urls can have at most 10 items"))]
+    #[validate(custom(function = "validate_url_list"))]
+    pub faucet_urls: Vec<String>,
+
+    /// Mainnet this testnet mirrors
+    #[serde(default)]
+    pub mainnet_id: Option<uuid::Uuid>,
+
+    #[serde(default)]
+    pub expected_deprecation_at: Option<DateTime<Utc>>,
+}
+
+impl From<TestnetDto> for TestnetInfo {
+    fn from(dto: TestnetDto) -> Self {
+        Self {
+            faucet_urls: dto.faucet_urls,
+            mainnet_id: dto.mainnet_id.map(NetworkId::from_uuid),
+            expected_deprecation_at: dto.expected_deprecation_at,
+        }
+    }
+}
+
+impl From<&TestnetInfo> for TestnetDto {
+    fn from(info: &TestnetInfo) -> Self {
+        Self {
+            faucet_urls: info.faucet_urls.clone(),
+            mainnet_id: info.mainnet_id.as_ref().map(|id| *id.as_uuid()),
+            expected_deprecation_at: info.expected_deprecation_at,
+        }
+    }
+}
+
+/// DTO for deprecating a network
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeprecateNetworkDto {
+    /// Planned removal date, advertised in the `Sunset` header
+    pub sunset_at: Option<DateTime<Utc>>,
+}
+
 /// DTO for creating a new network
 #[derive(Debug, Clone, Deserialize, Validate)]
 #[serde(rename_all = "camelCase")]
@@ -118,6 +553,48 @@ pub struct CreateNetworkDto {
 
     #[validate(custom(function = "validate_ethereum_address"))]
     pub default_signer_address: String,
+
+    #[serde(default)]
+    #[validate(nested)]
+    pub explorer: Option<ExplorerMetadataDto>,
+
+    #[serde(default)]
+    #[validate(nested)]
+    pub fee_config: Option<FeeConfigDto>,
+
+    #[serde(default)]
+    #[validate(nested)]
+    pub finality: Option<FinalityPolicyDto>,
+
+    /// Initial lifecycle state (`draft` or `active`, defaults to `active`)
+    #[serde(default)]
+    pub status: Option<NetworkStatusDto>,
+
+    /// Network this one is a rollup, sidechain or testnet of
+    #[serde(default)]
+    pub parent: Option<NetworkParentDto>,
+
+    #[serde(default)]
+    #[validate(length(max = 32, message = "tags can have at most 32 items"))]
+    pub tags: Vec<String>,
+
+    #[serde(default)]
+    pub metadata: NetworkMetadata,
+
+    /// URL-safe identifier (`eth-mainnet`), derived from the name when absent
+    #[serde(default)]
+    #[validate(length(min = 1, max = 63, message = "slug must be between 1 and 63 characters"))]
+    pub slug: Option<String>,
+
+    /// Other identifiers the network answers to (`ethereum`, `matic`)
+    #[serde(default)]
+    #[validate(length(max = 16, message = "aliases can have at most 16 items"))]
+    pub aliases: Vec<String>,
+
+    /// Faucets, mirrored mainnet and expected deprecation (testnets only)
+    #[serde(default)]
+    #[validate(nested)]
+    pub testnet: Option<TestnetDto>,
 }
 
 impl From<CreateNetworkDto> for CreateNetworkData {
@@ -132,6 +609,16 @@ impl From<CreateNetworkDto> for CreateNetworkData {
             fee_multiplier: f64_to_decimal(dto.fee_multiplier),
             gas_limit_multiplier: f64_to_decimal(dto.gas_limit_multiplier),
             default_signer_address: dto.default_signer_address,
+            explorer: dto.explorer.map(Into::into).unwrap_or_default(),
+            fee_config: dto.fee_config.map(Into::into).unwrap_or_default(),
+            finality: dto.finality.map(Into::into).unwrap_or_default(),
+            status: dto.status.map(Into::into).unwrap_or_default(),
+            parent: dto.parent.map(Into::into),
+            tags: dto.tags,
+            metadata: dto.metadata,
+            slug: dto.slug,
+            aliases: dto.aliases,
+            testnet: dto.testnet.map(Into::into).unwrap_or_default(),
         }
     }
 }
@@ -171,6 +658,42 @@ pub struct UpdateNetworkDto {
 
     #[validate(custom(function = "validate_ethereum_address"))]
     pub default_signer_address: String,
+
+    #[serde(default)]
+    #[validate(nested)]
+    pub explorer: Option<ExplorerMetadataDto>,
+
+    #[serde(default)]
+    #[validate(nested)]
+    pub fee_config: Option<FeeConfigDto>,
+
+    #[serde(default)]
+    #[validate(nested)]
+    pub finality: Option<FinalityPolicyDto>,
+
+    /// Replaces the tags when present
+    #[serde(default)]
+    #[validate(length(max = 32, message = "tags can have at most 32 items"))]
+    pub tags: Option<Vec<String>>,
+
+    /// Replaces the metadata when present
+    #[serde(default)]
+    pub metadata: Option<NetworkMetadata>,
+
+    /// Replaces the slug when present
+    #[serde(default)]
+    #[validate(length(min = 1, max = 63, message = "slug must be between 1 and 63 characters"))]
+    pub slug: Option<String>,
+
+    /// Replaces the aliases when present
+    #[serde(default)]
+    #[validate(length(max = 16, message = "aliases can have at most 16 items"))]
+    pub aliases: Option<Vec<String>>,
+
+    /// Replaces the testnet metadata when present
+    #[serde(default)]
+    #[validate(nested)]
+    pub testnet: Option<TestnetDto>,
 }
 
 impl From<UpdateNetworkDto> for UpdateNetworkData {
@@ -185,6 +708,14 @@ impl From<UpdateNetworkDto> for UpdateNetworkData {
             fee_multiplier: Some(f64_to_decimal(dto.fee_multiplier)),
             gas_limit_multiplier: Some(f64_to_decimal(dto.gas_limit_multiplier)),
             default_signer_address: Some(dto.default_signer_address),
+            explorer: dto.explorer.map(Into::into),
+            fee_config: dto.fee_config.map(Into::into),
+            finality: dto.finality.map(Into::into),
+            tags: dto.tags,
+            metadata: dto.metadata.map(MetadataUpdate::Replace),
+            slug: dto.slug,
+            aliases: dto.aliases,
+            testnet: dto.testnet.map(Into::into),
             active: None, // Cannot update active via PUT
         }
     }
@@ -228,6 +759,33 @@ pub struct PatchNetworkDto {
     #[validate(custom(function = "validate_ethereum_address"))]
     pub default_signer_address: Option<String>,
 
+    #[validate(nested)]
+    pub explorer: Option<ExplorerMetadataDto>,
+
+    #[validate(nested)]
+    pub fee_config: Option<FeeConfigDto>,
+
+    #[validate(nested)]
+    pub finality: Option<FinalityPolicyDto>,
+
+    /// Replaces the tags
+    #[validate(length(max = 32, message = "tags can have at most 32 items"))]
+    pub tags: Option<Vec<String>>,
+
+    /// JSON merge patch applied to the metadata: `null` removes a key
+    pub metadata: Option<NetworkMetadata>,
+
+    #[validate(length(min = 1, max = 63, message = "slug must be between 1 and 63 characters"))]
+    pub slug: Option<String>,
+
+    /// Replaces the aliases
+    #[validate(length(max = 16, message = "aliases can have at most 16 items"))]
+    pub aliases: Option<Vec<String>>,
+
+    /// Replaces the testnet metadata
+    #[validate(nested)]
+    pub testnet: Option<TestnetDto>,
+
     pub active: Option<bool>,
 }
 
@@ -243,6 +801,14 @@ impl From<PatchNetworkDto> for UpdateNetworkData {
             fee_multiplier: dto.fee_multiplier.map(f64_to_decimal),
             gas_limit_multiplier: dto.gas_limit_multiplier.map(f64_to_decimal),
             default_signer_address: dto.default_signer_address,
+            explorer: dto.explorer.map(Into::into),
+            fee_config: dto.fee_config.map(Into::into),
+            finality: dto.finality.map(Into::into),
+            tags: dto.tags,
+            metadata: dto.metadata.map(MetadataUpdate::Merge),
+            slug: dto.slug,
+            aliases: dto.aliases,
+            testnet: dto.testnet.map(Into::into),
             active: dto.active,
         }
     }
@@ -262,11 +828,47 @@ pub struct NetworkResponseDto {
     pub fee_multiplier: f64,
     pub gas_limit_multiplier: f64,
     pub active: bool,
+    pub status: NetworkStatusDto,
+    pub deprecated_at: Option<DateTime<Utc>>,
+    pub sunset_at: Option<DateTime<Utc>>,
     pub default_signer_address: String,
+    pub explorer: ExplorerMetadataDto,
+    pub fee_config: FeeConfigDto,
+    pub finality: FinalityResponseDto,
+    pub parent: Option<NetworkParentDto>,
+    pub tags: Vec<String>,
+    pub metadata: NetworkMetadata,
+    pub slug: String,
+    pub aliases: Vec<String>,
+    /// Present on testnets only
+    pub testnet: Option<TestnetDto>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
 
+impl NetworkResponseDto {
+    /// Mask literal secrets embedded in the RPC URLs
+    #[must_use]
+    pub fn redact_secrets(mut self) -> Self {
+        self.rpc_url = rpc_url::redact_secrets(&self.rpc_url);
+        self.other_rpc_urls = self
+            .other_rpc_urls
+            .iter()
+            .map(|url| rpc_url::redact_secrets(url))
+            .collect();
+        self
+    }
+
+    /// Substitute `${NAME}` placeholders in the RPC URLs with their secret values
+    #[must_use]
+    pub fn resolve_secrets(mut self, resolver: &dyn SecretResolver) -> Self {
+        let resolve = |url: &str| rpc_url::resolve_placeholders(url, |name| resolver.resolve(name));
+        self.rpc_url = resolve(&self.rpc_url);
+        self.other_rpc_urls = self.other_rpc_urls.iter().map(|url| resolve(url)).collect();
+        self
+    }
+}
+
 impl From<Network> for NetworkResponseDto {
     fn from(network: Network) -> Self {
         Self {
@@ -280,7 +882,19 @@ impl From<Network> for NetworkResponseDto {
             fee_multiplier: network.fee_multiplier().try_into().unwrap_or(0.0),
             gas_limit_multiplier: network.gas_limit_multiplier().try_into().unwrap_or(0.0),
             active: network.active(),
+            status: network.status().into(),
+            deprecated_at: network.deprecated_at(),
+            sunset_at: network.sunset_at(),
             default_signer_address: network.default_signer_address().to_string(),
+            explorer: network.explorer().into(),
+            fee_config: network.fee_config().into(),
+            finality: network.finality().into(),
+            parent: network.parent().map(Into::into),
+            tags: network.tags().iter().cloned().collect(),
+            metadata: network.metadata().clone(),
+            slug: network.slug().to_string(),
+            aliases: network.aliases().iter().cloned().collect(),
+            testnet: network.test_net().then(|| network.testnet().into()),
             created_at: network.created_at(),
             updated_at: network.updated_at(),
         }
@@ -300,16 +914,143 @@ impl From<&Network> for NetworkResponseDto {
             fee_multiplier: network.fee_multiplier().try_into().unwrap_or(0.0),
             gas_limit_multiplier: network.gas_limit_multiplier().try_into().unwrap_or(0.0),
             active: network.active(),
+            status: network.status().into(),
+            deprecated_at: network.deprecated_at(),
+            sunset_at: network.sunset_at(),
             default_signer_address: network.default_signer_address().to_string(),
+            explorer: network.explorer().into(),
+            fee_config: network.fee_config().into(),
+            finality: network.finality().into(),
+            parent: network.parent().map(Into::into),
+            tags: network.tags().iter().cloned().collect(),
+            metadata: network.metadata().clone(),
+            slug: network.slug().to_string(),
+            aliases: network.aliases().iter().cloned().collect(),
+            testnet: network.test_net().then(|| network.testnet().into()),
             created_at: network.created_at(),
             updated_at: network.updated_at(),
         }
     }
 }
 
+/// Public network response DTO
+///
+/// Restricted projection served without authentication: RPC URLs, signer
+/// addresses, fee settings and free-form metadata are left out.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PublicNetworkResponseDto {
+    pub id: String,
+    pub chain_id: i32,
+    pub name: String,
+    pub slug: String,
+    pub aliases: Vec<String>,
+    pub test_net: bool,
+    pub status: NetworkStatusDto,
+    pub deprecated_at: Option<DateTime<Utc>>,
+    pub sunset_at: Option<DateTime<Utc>>,
+    pub block_explorer_url: String,
+    pub tags: Vec<String>,
+    /// Present on testnets only
+    pub testnet: Option<TestnetDto>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<&Network> for PublicNetworkResponseDto {
+    fn from(network: &Network) -> Self {
+        Self {
+            id: network.id().to_string(),
+            chain_id: network.chain_id(),
+            name: network.name().to_string(),
+            slug: network.slug().to_string(),
+            aliases: network.aliases().iter().cloned().collect(),
+            test_net: network.test_net(),
+            status: network.status().into(),
+            deprecated_at: network.deprecated_at(),
+            sunset_at: network.sunset_at(),
+            block_explorer_url: network.block_explorer_url().to_string(),
+            tags: network.tags().iter().cloned().collect(),
+            testnet: network.test_net().then(|| network.testnet().into()),
+            updated_at: network.updated_at(),
+        }
+    }
+}
+
+/// Query parameters for listing networks
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct NetworkListQueryDto {
+    /// Comma-separated tags the networks must all carry
+    pub tags: Option<String>,
+}
+
+impl NetworkListQueryDto {
+    /// Split the tag filter into individual tags
+    #[must_use]
+    pub fn tags(&self) -> Vec<String> {
+        self.tags
+            .as_deref()
+            .unwrap_or_default()
+            .split(',')
+            .map(str::trim)
+            .filter(|tag| !tag.is_empty())
+            .map(ToString::to_string)
+            .collect()
+    }
+}
+
+/// Query parameters for searching networks
+#[derive(Debug, Clone, Deserialize)]
+pub struct NetworkSearchQueryDto {
+    /// Free text matched against names, slugs, aliases and tags
+    pub q: String,
+    /// Maximum number of results
+    pub limit: Option<usize>,
+}
+
+/// Query parameters for explorer links
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct ExplorerLinksQueryDto {
+    pub tx: Option<String>,
+    pub address: Option<String>,
+    pub block: Option<String>,
+}
+
+impl From<ExplorerLinksQueryDto> for ExplorerLinkQuery {
+    fn from(dto: ExplorerLinksQueryDto) -> Self {
+        Self {
+            tx: dto.tx,
+            address: dto.address,
+            block: dto.block,
+        }
+    }
+}
+
+/// Explorer links response DTO
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExplorerLinksResponseDto {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tx: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub block: Option<String>,
+}
+
+impl From<ExplorerLinks> for ExplorerLinksResponseDto {
+    fn from(links: ExplorerLinks) -> Self {
+        Self {
+            tx: links.tx,
+            address: links.address,
+            block: links.block,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::domain::models::url_policy::UrlPolicy;
 
     #[test]
     fn test_ethereum_address_regex_is_valid() {
@@ -361,6 +1102,15 @@ mod tests {
         assert!(validate_decimal(f64::NAN).is_err());
     }
 
+    #[test]
+    fn test_network_list_query_tags() {
+        let query = NetworkListQueryDto {
+            tags: Some(" l2, tier:gold,,".to_string()),
+        };
+        assert_eq!(query.tags(), ["l2", "tier:gold"]);
+        assert!(NetworkListQueryDto::default().tags().is_empty());
+    }
+
     #[test]
     fn test_validate_url_list() {
         assert!(validate_url_list(&[]).is_ok());
@@ -368,6 +1118,108 @@ mod tests {
         assert!(validate_url_list(&["invalid".to_string()]).is_err());
     }
 
+    #[test]
+    fn test_response_redact_and_resolve_secrets() {
+        struct StaticResolver;
+        impl SecretResolver for StaticResolver {
+            fn resolve(&self, name: &str) -> Option<String> {
+                (name == "ALCHEMY_KEY").then(|| "resolved".to_string())
+            }
+
+            fn version(&self) -> String {
+                "static".to_string()
+            }
+        }
+
+        let network = Network::new(
+            CreateNetworkData {
+                chain_id: 1,
+                name: "Ethereum Mainnet".to_string(),
+                rpc_url: "https://mainnet.infura.io/v3/9aa3d95b3bc440fa88ea12eaa4456161".to_string(),
+                other_rpc_urls: vec!["https://eth-mainnet.g.alchemy.com/v2/${ALCHEMY_KEY}".to_string()],
+                test_net: false,
+                block_explorer_url: "https://etherscan.io".to_string(),
+                fee_multiplier: Decimal::ONE,
+                gas_limit_multiplier: Decimal::ONE,
+                default_signer_address: "0x742d35Cc6634C0532925a3b844Bc9e7595f1dEaD".to_string(),
+                explorer: ExplorerMetadata::default(),
+                fee_config: FeeConfig::default(),
+                finality: FinalityPolicy::default(),
+                status: NetworkStatus::Active,
+                parent: None,
+                tags: vec![],
+                metadata: NetworkMetadata::new(),
+                slug: None,
+                aliases: vec![],
+                testnet: TestnetInfo::default(),
+            },
+            &UrlPolicy::default(),
+        )
+        .expect("valid test data");
+
+        let redacted = NetworkResponseDto::from(&network).redact_secrets();
+        assert_eq!(redacted.rpc_url, "https://mainnet.infura.io/v3/***");
+        assert_eq!(
+            redacted.other_rpc_urls[0],
+            "https://eth-mainnet.g.alchemy.com/v2/${ALCHEMY_KEY}"
+        );
+
+        let resolved = NetworkResponseDto::from(&network).resolve_secrets(&StaticResolver);
+        assert_eq!(resolved.rpc_url, network.rpc_url());
+        assert_eq!(
+            resolved.other_rpc_urls[0],
+            "https://eth-mainnet.g.alchemy.com/v2/resolved"
+        );
+    }
+
+    #[test]
+    fn test_redacted_response_put_back_keeps_secrets() {
+        let data = CreateNetworkData {
+            chain_id: 1,
+            name: "Ethereum Mainnet".to_string(),
+            rpc_url: "https://mainnet.infura.io/v3/9aa3d95b3bc440fa88ea12eaa4456161".to_string(),
+            // Two keys on one host, redacted alike
+            other_rpc_urls: vec![
+                "https://eth.example.com/rpc?apiKey=0123456789".to_string(),
+                "https://eth.example.com/rpc?apiKey=9876543210".to_string(),
+            ],
+            test_net: false,
+            block_explorer_url: "https://etherscan.io".to_string(),
+            fee_multiplier: Decimal::ONE,
+            gas_limit_multiplier: Decimal::ONE,
+            default_signer_address: "0x742d35Cc6634C0532925a3b844Bc9e7595f1dEaD".to_string(),
+            explorer: ExplorerMetadata::default(),
+            fee_config: FeeConfig::default(),
+            finality: FinalityPolicy::default(),
+            status: NetworkStatus::Active,
+            parent: None,
+            tags: vec![],
+            metadata: NetworkMetadata::new(),
+            slug: None,
+            aliases: vec![],
+            testnet: TestnetInfo::default(),
+        };
+        let network = Network::new(data, &UrlPolicy::default()).expect("valid test data");
+
+        // GET returns the redacted network, which the client sends back with PUT
+        let response = serde_json::to_value(NetworkResponseDto::from(&network).redact_secrets()).unwrap();
+        let dto: UpdateNetworkDto = serde_json::from_value(response).expect("response is a valid PUT body");
+        assert!(dto.validate().is_ok());
+        let updated = network
+            .clone()
+            .with_updates(dto.into(), &UrlPolicy::default())
+            .expect("redacted URLs are restored");
+        assert_eq!(updated.rpc_url(), network.rpc_url());
+        assert_eq!(updated.other_rpc_urls(), network.other_rpc_urls());
+
+        // A marker not standing for a stored secret is rejected
+        let updates = UpdateNetworkData {
+            rpc_url: Some("https://other.infura.io/v3/***".to_string()),
+            ..Default::default()
+        };
+        assert!(network.with_updates(updates, &UrlPolicy::default()).is_err());
+    }
+
     #[test]
     fn test_patch_dto_validation() {
         // Empty DTO should be valid
@@ -416,4 +1268,71 @@ mod tests {
         };
         assert!(dto_with_infinity.validate().is_err());
     }
+
+    #[test]
+    fn test_explorer_metadata_dto() {
+        let dto: ExplorerMetadataDto = serde_json::from_str(
+            r#"{"kind":"blockscout","apiUrl":"https://eth.blockscout.com/api","standard":"none"}"#,
+        )
+        .expect("valid explorer metadata");
+        let metadata = ExplorerMetadata::from(dto);
+        assert_eq!(metadata.kind, ExplorerKind::Blockscout);
+        assert_eq!(metadata.standard, ExplorerStandard::None);
+
+        let defaults: ExplorerMetadataDto = serde_json::from_str("{}").expect("empty explorer metadata");
+        assert_eq!(ExplorerMetadata::from(defaults), ExplorerMetadata::default());
+
+        // Malformed explorer API URLs should fail
+        let dto_with_invalid_api = PatchNetworkDto {
+            explorer: Some(ExplorerMetadataDto {
+                api_url: Some("ftp://eth.blockscout.com/api".to_string()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        assert!(dto_with_invalid_api.validate().is_err());
+    }
+
+    #[test]
+    fn test_fee_config_dto_converts_gwei_to_wei() {
+        let dto: FeeConfigDto = serde_json::from_str(
+            r#"{"model":"eip1559","maxFeePerGasCap":"150.5","maxPriorityFeePerGasCap":2,"baseFeeMultiplier":1.25}"#,
+        )
+        .expect("valid fee config");
+        let config = FeeConfig::from(dto);
+        assert_eq!(config.model, FeeModel::Eip1559);
+        assert_eq!(config.max_fee_per_gas.cap, Some(Decimal::new(150_500_000_000, 0)));
+        assert_eq!(
+            config.max_priority_fee_per_gas.cap,
+            Some(Decimal::new(2_000_000_000, 0))
+        );
+        assert!(config.validate().is_ok());
+
+        let response = FeeConfigDto::from(&config);
+        assert_eq!(response.unit, FeeUnitDto::Wei);
+        assert_eq!(response.max_fee_per_gas_cap, config.max_fee_per_gas.cap);
+    }
+
+    #[test]
+    fn test_finality_dto_defaults_and_recommendations() {
+        let dto: FinalityPolicyDto =
+            serde_json::from_str(r#"{"averageBlockTimeMs":2000,"supportsSafeTag":true,"maxReorgDepth":10}"#)
+                .expect("valid finality policy");
+        assert!(dto.validate().is_ok());
+        assert_eq!(dto.required_confirmations, DEFAULT_REQUIRED_CONFIRMATIONS);
+
+        let policy = FinalityPolicy::from(dto);
+        assert!(policy.validate().is_ok());
+
+        let response = FinalityResponseDto::from(&policy);
+        assert_eq!(response.recommended_confirmations, DEFAULT_REQUIRED_CONFIRMATIONS);
+        assert_eq!(response.recommended_wait_ms, 24_000);
+        assert_eq!(response.recommended_block_tag, "safe");
+
+        let invalid = FinalityPolicyDto {
+            average_block_time_ms: 0,
+            ..Default::default()
+        };
+        assert!(invalid.validate().is_err());
+    }
 }
@@ -0,0 +1,145 @@
+//! Lifecycle Schedule DTOs
+//!
+//! Data transfer objects for the network lifecycle schedule endpoints.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::models::schedule::{LifecycleSchedule, ScheduleStatus, ScheduledAction};
+
+/// Scheduled lifecycle transition
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ScheduledActionDto {
+    Activate,
+    Deprecate,
+    /// `deactivate` is accepted for clients written before deprecation could be scheduled
+    #[serde(alias = "deactivate")]
+    Retire,
+}
+
+impl From<ScheduledActionDto> for ScheduledAction {
+    fn from(dto: ScheduledActionDto) -> Self {
+        match dto {
+            ScheduledActionDto::Activate => Self::Activate,
+            ScheduledActionDto::Deprecate => Self::Deprecate,
+            ScheduledActionDto::Retire => Self::Retire,
+        }
+    }
+}
+
+impl From<ScheduledAction> for ScheduledActionDto {
+    fn from(action: ScheduledAction) -> Self {
+        match action {
+            ScheduledAction::Activate => Self::Activate,
+            ScheduledAction::Deprecate => Self::Deprecate,
+            ScheduledAction::Retire => Self::Retire,
+        }
+    }
+}
+
+/// State of a lifecycle schedule
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ScheduleStatusDto {
+    Pending,
+    Applied,
+    Failed,
+    Cancelled,
+}
+
+impl From<ScheduleStatus> for ScheduleStatusDto {
+    fn from(status: ScheduleStatus) -> Self {
+        match status {
+            ScheduleStatus::Pending => Self::Pending,
+            ScheduleStatus::Applied => Self::Applied,
+            ScheduleStatus::Failed => Self::Failed,
+            ScheduleStatus::Cancelled => Self::Cancelled,
+        }
+    }
+}
+
+/// DTO for scheduling a lifecycle change
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateScheduleDto {
+    pub action: ScheduledActionDto,
+    /// When to apply the change (must be in the future)
+    pub run_at: DateTime<Utc>,
+}
+
+/// Lifecycle schedule response DTO
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScheduleResponseDto {
+    pub id: String,
+    pub network_id: String,
+    pub action: ScheduledActionDto,
+    pub run_at: DateTime<Utc>,
+    pub status: ScheduleStatusDto,
+    pub scheduled_by: String,
+    pub cancelled_by: Option<String>,
+    /// Failure reason when the change could not be applied
+    pub outcome: Option<String>,
+    pub completed_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<LifecycleSchedule> for ScheduleResponseDto {
+    fn from(schedule: LifecycleSchedule) -> Self {
+        Self {
+            id: schedule.id().to_string(),
+            network_id: schedule.network_id().to_string(),
+            action: schedule.action().into(),
+            run_at: schedule.run_at(),
+            status: schedule.status().into(),
+            scheduled_by: schedule.scheduled_by().to_string(),
+            cancelled_by: schedule.cancelled_by().map(str::to_string),
+            outcome: schedule.outcome().map(str::to_string),
+            completed_at: schedule.completed_at(),
+            created_at: schedule.created_at(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::models::network::NetworkId;
+
+    #[test]
+    fn test_create_schedule_dto_deserialization() {
+        let dto: CreateScheduleDto =
+            serde_json::from_str(r#"{"action": "deprecate", "runAt": "2030-01-01T00:00:00Z"}"#).unwrap();
+        assert_eq!(ScheduledAction::from(dto.action), ScheduledAction::Deprecate);
+        assert_eq!(dto.run_at.to_rfc3339(), "2030-01-01T00:00:00+00:00");
+
+        let dto: CreateScheduleDto =
+            serde_json::from_str(r#"{"action": "deactivate", "runAt": "2030-01-01T00:00:00Z"}"#).unwrap();
+        assert_eq!(ScheduledAction::from(dto.action), ScheduledAction::Retire);
+
+        assert!(
+            serde_json::from_str::<CreateScheduleDto>(r#"{"action": "delete", "runAt": "2030-01-01T00:00:00Z"}"#)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_schedule_response_serialization() {
+        let schedule = LifecycleSchedule::new(
+            NetworkId::new(),
+            ScheduledAction::Activate,
+            Utc::now() + chrono::Duration::hours(1),
+            "user-1".to_string(),
+        )
+        .unwrap()
+        .cancel("user-2".to_string())
+        .unwrap();
+
+        let json = serde_json::to_value(ScheduleResponseDto::from(schedule)).unwrap();
+        assert_eq!(json["action"], "activate");
+        assert_eq!(json["status"], "cancelled");
+        assert_eq!(json["scheduledBy"], "user-1");
+        assert_eq!(json["cancelledBy"], "user-2");
+    }
+}
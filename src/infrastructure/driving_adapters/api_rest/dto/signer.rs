@@ -0,0 +1,135 @@
+//! Network Signer DTOs
+//!
+//! Data transfer objects for the signer endpoints.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+use super::network::validate_ethereum_address;
+use crate::domain::models::signer::{NetworkSigner, ResolvedSigner, SignerData};
+
+/// Signers are enabled unless stated otherwise
+fn default_enabled() -> bool {
+    true
+}
+
+/// DTO for registering or replacing a signer
+#[derive(Debug, Clone, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct SignerDto {
+    #[validate(custom(function = "validate_ethereum_address"))]
+    pub address: String,
+
+    /// Role label, such as `deployer`, `relayer` or `payouts`
+    #[validate(length(min = 1, max = 50, message = "role must be between 1 and 50 characters"))]
+    pub role: String,
+
+    /// Lower values are preferred when several signers share a role
+    #[serde(default)]
+    #[validate(range(min = 0, max = 1000, message = "priority must be between 0 and 1000"))]
+    pub priority: i32,
+
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+}
+
+impl From<SignerDto> for SignerData {
+    fn from(dto: SignerDto) -> Self {
+        Self {
+            address: dto.address,
+            role: dto.role,
+            priority: dto.priority,
+            enabled: dto.enabled,
+        }
+    }
+}
+
+/// Signer response DTO
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SignerResponseDto {
+    pub id: String,
+    pub network_id: String,
+    pub address: String,
+    pub role: String,
+    pub priority: i32,
+    pub enabled: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<NetworkSigner> for SignerResponseDto {
+    fn from(signer: NetworkSigner) -> Self {
+        Self {
+            id: signer.id().to_string(),
+            network_id: signer.network_id().to_string(),
+            address: signer.address().to_string(),
+            role: signer.role().to_string(),
+            priority: signer.priority(),
+            enabled: signer.enabled(),
+            created_at: signer.created_at(),
+            updated_at: signer.updated_at(),
+        }
+    }
+}
+
+/// Query parameters for listing the signers of a network
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct SignerListQueryDto {
+    pub role: Option<String>,
+}
+
+/// Query parameters for resolving the signer of a role on a chain
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SignerLookupQueryDto {
+    pub chain_id: i32,
+    pub role: String,
+}
+
+/// The signer chosen for a role on a chain
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResolvedSignerDto {
+    pub chain_id: i32,
+    pub network_id: String,
+    pub role: String,
+    pub address: String,
+    /// ID of the registered signer, absent when the network's default signer address is used
+    pub signer_id: Option<String>,
+}
+
+impl From<ResolvedSigner> for ResolvedSignerDto {
+    fn from(resolved: ResolvedSigner) -> Self {
+        Self {
+            chain_id: resolved.chain_id,
+            network_id: resolved.network_id.to_string(),
+            role: resolved.role,
+            address: resolved.address,
+            signer_id: resolved.signer.map(|signer| signer.id().to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_signer_dto_defaults_and_validation() {
+        let dto: SignerDto =
+            serde_json::from_str(r#"{"address": "0x742d35Cc6634C0532925a3b844Bc9e7595f1dEaD", "role": "relayer"}"#)
+                .unwrap();
+        assert!(dto.validate().is_ok());
+        assert_eq!(dto.priority, 0);
+        assert!(dto.enabled);
+
+        let invalid: SignerDto =
+            serde_json::from_str(r#"{"address": "0x1234", "role": "", "priority": 1001, "enabled": false}"#).unwrap();
+        let errors = invalid.validate().unwrap_err();
+        assert!(errors.field_errors().contains_key("address"));
+        assert!(errors.field_errors().contains_key("role"));
+        assert!(errors.field_errors().contains_key("priority"));
+    }
+}
@@ -0,0 +1,358 @@
+//! Token DTOs
+//!
+//! Data transfer objects for the token endpoints and the Uniswap Token Lists
+//! export (<https://tokenlists.org>). Token fields use the Token Lists names,
+//! including `logoURI`, so an exported list can be imported back as-is.
+
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, SecondsFormat, Utc};
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+use super::network::validate_ethereum_address;
+use crate::domain::models::token::{ImportedToken, Token, TokenMetadata};
+use crate::domain::models::token_list::{TokenImportSummary, TokenList, TokenListVersion};
+
+/// Maximum length of a token list name in the Token Lists schema
+const MAX_LIST_NAME_LENGTH: usize = 30;
+
+/// DTO for adding a token to a network
+#[derive(Debug, Clone, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateTokenDto {
+    #[validate(custom(function = "validate_ethereum_address"))]
+    pub address: String,
+
+    #[validate(length(min = 1, max = 20, message = "symbol must be between 1 and 20 characters"))]
+    pub symbol: String,
+
+    #[validate(length(min = 1, max = 40, message = "name must be between 1 and 40 characters"))]
+    pub name: String,
+
+    pub decimals: u8,
+
+    #[serde(default, rename = "logoURI")]
+    #[validate(length(max = 500, message = "logoURI must be at most 500 characters"))]
+    pub logo_uri: Option<String>,
+
+    #[serde(default)]
+    #[validate(length(max = 10, message = "tags can have at most 10 items"))]
+    pub tags: Vec<String>,
+}
+
+impl From<CreateTokenDto> for TokenMetadata {
+    fn from(dto: CreateTokenDto) -> Self {
+        Self {
+            symbol: dto.symbol,
+            name: dto.name,
+            decimals: dto.decimals,
+            logo_uri: dto.logo_uri,
+            tags: dto.tags,
+        }
+    }
+}
+
+/// DTO for replacing the metadata of a token (the address cannot change)
+#[derive(Debug, Clone, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateTokenDto {
+    #[validate(length(min = 1, max = 20, message = "symbol must be between 1 and 20 characters"))]
+    pub symbol: String,
+
+    #[validate(length(min = 1, max = 40, message = "name must be between 1 and 40 characters"))]
+    pub name: String,
+
+    pub decimals: u8,
+
+    #[serde(default, rename = "logoURI")]
+    #[validate(length(max = 500, message = "logoURI must be at most 500 characters"))]
+    pub logo_uri: Option<String>,
+
+    #[serde(default)]
+    #[validate(length(max = 10, message = "tags can have at most 10 items"))]
+    pub tags: Vec<String>,
+}
+
+impl From<UpdateTokenDto> for TokenMetadata {
+    fn from(dto: UpdateTokenDto) -> Self {
+        Self {
+            symbol: dto.symbol,
+            name: dto.name,
+            decimals: dto.decimals,
+            logo_uri: dto.logo_uri,
+            tags: dto.tags,
+        }
+    }
+}
+
+/// A token entry of a bulk import, validated entry by entry by the use case
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportTokenEntryDto {
+    /// Entries for another chain are skipped
+    #[serde(default)]
+    pub chain_id: Option<i32>,
+    pub address: String,
+    pub symbol: String,
+    pub name: String,
+    pub decimals: u8,
+    #[serde(default, rename = "logoURI")]
+    pub logo_uri: Option<String>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+}
+
+impl From<ImportTokenEntryDto> for ImportedToken {
+    fn from(dto: ImportTokenEntryDto) -> Self {
+        Self {
+            chain_id: dto.chain_id,
+            address: dto.address,
+            metadata: TokenMetadata {
+                symbol: dto.symbol,
+                name: dto.name,
+                decimals: dto.decimals,
+                logo_uri: dto.logo_uri,
+                tags: dto.tags,
+            },
+        }
+    }
+}
+
+/// DTO for a bulk token import; a Token Lists document is accepted as-is
+#[derive(Debug, Clone, Deserialize)]
+pub struct ImportTokensDto {
+    pub tokens: Vec<ImportTokenEntryDto>,
+}
+
+/// Token list version in the Token Lists format
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct TokenListVersionDto {
+    pub major: u32,
+    pub minor: u32,
+    pub patch: u32,
+}
+
+impl From<TokenListVersion> for TokenListVersionDto {
+    fn from(version: TokenListVersion) -> Self {
+        Self {
+            major: version.major,
+            minor: version.minor,
+            patch: version.patch,
+        }
+    }
+}
+
+/// Result of a bulk token import
+#[derive(Debug, Clone, Serialize)]
+pub struct TokenImportResponseDto {
+    pub created: usize,
+    pub updated: usize,
+    pub unchanged: usize,
+    pub skipped: usize,
+    pub version: TokenListVersionDto,
+}
+
+impl From<TokenImportSummary> for TokenImportResponseDto {
+    fn from(summary: TokenImportSummary) -> Self {
+        Self {
+            created: summary.created,
+            updated: summary.updated,
+            unchanged: summary.unchanged,
+            skipped: summary.skipped,
+            version: summary.version.into(),
+        }
+    }
+}
+
+/// Token response DTO
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TokenResponseDto {
+    pub id: String,
+    pub network_id: String,
+    pub address: String,
+    pub symbol: String,
+    pub name: String,
+    pub decimals: u8,
+    #[serde(rename = "logoURI")]
+    pub logo_uri: Option<String>,
+    pub tags: Vec<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<Token> for TokenResponseDto {
+    fn from(token: Token) -> Self {
+        Self {
+            id: token.id().to_string(),
+            network_id: token.network_id().to_string(),
+            address: token.address().to_string(),
+            symbol: token.symbol().to_string(),
+            name: token.name().to_string(),
+            decimals: token.decimals(),
+            logo_uri: token.logo_uri().map(str::to_string),
+            tags: token.tags().to_vec(),
+            created_at: token.created_at(),
+            updated_at: token.updated_at(),
+        }
+    }
+}
+
+/// Token entry of an exported token list
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TokenListTokenDto {
+    pub chain_id: i32,
+    pub address: String,
+    pub name: String,
+    pub symbol: String,
+    pub decimals: u8,
+    #[serde(rename = "logoURI", skip_serializing_if = "Option::is_none")]
+    pub logo_uri: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+}
+
+/// Definition of a tag used by the tokens of a list
+#[derive(Debug, Clone, Serialize)]
+pub struct TokenListTagDto {
+    pub name: String,
+    pub description: String,
+}
+
+/// Uniswap Token Lists document
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TokenListDto {
+    pub name: String,
+    /// RFC 3339 time of the last change to the list
+    pub timestamp: String,
+    pub version: TokenListVersionDto,
+    pub tokens: Vec<TokenListTokenDto>,
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub tags: BTreeMap<String, TokenListTagDto>,
+}
+
+impl From<TokenList> for TokenListDto {
+    fn from(list: TokenList) -> Self {
+        let mut tags = BTreeMap::new();
+        for tag in list.tokens.iter().flat_map(Token::tags) {
+            tags.entry(tag.clone()).or_insert_with(|| TokenListTagDto {
+                name: tag.clone(),
+                description: tag.clone(),
+            });
+        }
+
+        let chain_id = list.chain_id;
+        Self {
+            name: token_list_name(&list.name),
+            timestamp: list.version.updated_at.to_rfc3339_opts(SecondsFormat::Millis, true),
+            version: list.version.into(),
+            tokens: list
+                .tokens
+                .into_iter()
+                .map(|token| TokenListTokenDto {
+                    chain_id,
+                    address: token.address().to_string(),
+                    name: token.name().to_string(),
+                    symbol: token.symbol().to_string(),
+                    decimals: token.decimals(),
+                    logo_uri: token.logo_uri().map(str::to_string),
+                    tags: token.tags().to_vec(),
+                })
+                .collect(),
+            tags,
+        }
+    }
+}
+
+/// Derive a list name accepted by the Token Lists schema (`^[\w ]+$`, at most
+/// 30 characters) from the network name
+fn token_list_name(network_name: &str) -> String {
+    let sanitized: String = network_name
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() || c == '_' { c } else { ' ' })
+        .collect();
+    let name = sanitized.split_whitespace().collect::<Vec<_>>().join(" ");
+    let name: String = name.chars().take(MAX_LIST_NAME_LENGTH).collect();
+    let name = name.trim_end();
+
+    if name.is_empty() {
+        "Token List".to_string()
+    } else {
+        name.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::models::network::NetworkId;
+
+    fn usdc() -> Token {
+        Token::new(
+            NetworkId::new(),
+            "0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48".to_string(),
+            TokenMetadata {
+                symbol: "USDC".to_string(),
+                name: "USD Coin".to_string(),
+                decimals: 6,
+                logo_uri: None,
+                tags: vec!["stablecoin".to_string()],
+            },
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_token_list_export_format() {
+        let list = TokenList {
+            name: "Arbitrum One (Nitro)".to_string(),
+            chain_id: 42161,
+            version: TokenListVersion::initial(Utc::now()),
+            tokens: vec![usdc()],
+        };
+
+        let json = serde_json::to_value(TokenListDto::from(list)).unwrap();
+        assert_eq!(json["name"], "Arbitrum One Nitro");
+        assert_eq!(json["version"], serde_json::json!({"major": 1, "minor": 0, "patch": 0}));
+        assert_eq!(json["tokens"][0]["chainId"], 42161);
+        assert_eq!(json["tokens"][0]["decimals"], 6);
+        assert!(json["tokens"][0].get("logoURI").is_none());
+        assert_eq!(json["tags"]["stablecoin"]["name"], "stablecoin");
+        assert!(DateTime::parse_from_rfc3339(json["timestamp"].as_str().unwrap()).is_ok());
+    }
+
+    #[test]
+    fn test_token_list_name_is_schema_safe() {
+        assert_eq!(token_list_name("Ethereum Mainnet"), "Ethereum Mainnet");
+        assert_eq!(token_list_name("zkSync Era - Sepolia!"), "zkSync Era Sepolia");
+        assert_eq!(token_list_name("***"), "Token List");
+        assert!(token_list_name(&"a".repeat(50)).len() <= MAX_LIST_NAME_LENGTH);
+    }
+
+    #[test]
+    fn test_import_accepts_token_list_document() {
+        let dto: ImportTokensDto = serde_json::from_str(
+            r#"{
+                "name": "Example",
+                "timestamp": "2025-01-01T00:00:00.000Z",
+                "version": {"major": 1, "minor": 0, "patch": 0},
+                "tokens": [{
+                    "chainId": 1,
+                    "address": "0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48",
+                    "name": "USD Coin",
+                    "symbol": "USDC",
+                    "decimals": 6,
+                    "logoURI": "https://example.com/usdc.png"
+                }]
+            }"#,
+        )
+        .unwrap();
+
+        let entry = ImportedToken::from(dto.tokens[0].clone());
+        assert_eq!(entry.chain_id, Some(1));
+        assert_eq!(entry.metadata.logo_uri.as_deref(), Some("https://example.com/usdc.png"));
+    }
+}
@@ -0,0 +1,284 @@
+//! Webhook DTOs
+//!
+//! Data transfer objects for the webhook subscription and delivery log endpoints.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::domain::models::event::NetworkEventKind;
+use crate::domain::models::network::NetworkId;
+use crate::domain::models::webhook::{DeliveryAttempt, DeliveryStatus, Webhook, WebhookData, WebhookDelivery};
+
+/// Default number of deliveries returned by the delivery log
+pub const DEFAULT_DELIVERY_LIMIT: u32 = 50;
+
+/// Network event a webhook subscribes to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum WebhookEventDto {
+    Created,
+    Updated,
+    Deactivated,
+    Restored,
+}
+
+impl From<WebhookEventDto> for NetworkEventKind {
+    fn from(dto: WebhookEventDto) -> Self {
+        match dto {
+            WebhookEventDto::Created => Self::Created,
+            WebhookEventDto::Updated => Self::Updated,
+            WebhookEventDto::Deactivated => Self::Deactivated,
+            WebhookEventDto::Restored => Self::Restored,
+        }
+    }
+}
+
+impl From<NetworkEventKind> for WebhookEventDto {
+    fn from(kind: NetworkEventKind) -> Self {
+        match kind {
+            NetworkEventKind::Created => Self::Created,
+            NetworkEventKind::Updated => Self::Updated,
+            NetworkEventKind::Deactivated => Self::Deactivated,
+            NetworkEventKind::Restored => Self::Restored,
+        }
+    }
+}
+
+/// State of a webhook delivery
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DeliveryStatusDto {
+    Pending,
+    Succeeded,
+    Failed,
+}
+
+impl From<DeliveryStatus> for DeliveryStatusDto {
+    fn from(status: DeliveryStatus) -> Self {
+        match status {
+            DeliveryStatus::Pending => Self::Pending,
+            DeliveryStatus::Succeeded => Self::Succeeded,
+            DeliveryStatus::Failed => Self::Failed,
+        }
+    }
+}
+
+fn default_enabled() -> bool {
+    true
+}
+
+/// DTO for creating or replacing a webhook
+#[derive(Debug, Clone, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct WebhookDto {
+    #[validate(url(message = "url must be a valid URL"))]
+    pub url: String,
+
+    /// Events to deliver; empty or omitted means all
+    #[serde(default)]
+    pub events: Vec<WebhookEventDto>,
+
+    /// Networks to deliver events for; empty or omitted means all
+    #[serde(default)]
+    #[validate(length(max = 100, message = "networkIds can have at most 100 items"))]
+    pub network_ids: Vec<Uuid>,
+
+    /// Signing secret; generated on creation and kept on update when omitted
+    pub secret: Option<String>,
+
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+}
+
+impl From<WebhookDto> for WebhookData {
+    fn from(dto: WebhookDto) -> Self {
+        Self {
+            url: dto.url,
+            events: dto.events.into_iter().map(Into::into).collect(),
+            network_ids: dto.network_ids.into_iter().map(NetworkId::from_uuid).collect(),
+            secret: dto.secret,
+            enabled: dto.enabled,
+        }
+    }
+}
+
+/// Webhook response DTO
+///
+/// The signing secret is only returned when the webhook is created.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WebhookResponseDto {
+    pub id: String,
+    pub url: String,
+    pub events: Vec<WebhookEventDto>,
+    pub network_ids: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub secret: Option<String>,
+    pub enabled: bool,
+    pub created_by: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl WebhookResponseDto {
+    /// Build the response returned on creation, including the signing secret
+    #[must_use]
+    pub fn with_secret(webhook: Webhook) -> Self {
+        let secret = webhook.secret().to_string();
+        Self {
+            secret: Some(secret),
+            ..Self::from(webhook)
+        }
+    }
+}
+
+impl From<Webhook> for WebhookResponseDto {
+    fn from(webhook: Webhook) -> Self {
+        Self {
+            id: webhook.id().to_string(),
+            url: webhook.url().to_string(),
+            events: webhook.events().iter().copied().map(Into::into).collect(),
+            network_ids: webhook.network_ids().iter().map(ToString::to_string).collect(),
+            secret: None,
+            enabled: webhook.enabled(),
+            created_by: webhook.created_by().to_string(),
+            created_at: webhook.created_at(),
+            updated_at: webhook.updated_at(),
+        }
+    }
+}
+
+/// Query parameters for the delivery log
+#[derive(Debug, Clone, Default, Deserialize, Validate)]
+pub struct DeliveryListQueryDto {
+    /// Maximum number of deliveries, newest first
+    #[validate(range(min = 1, max = 500, message = "limit must be between 1 and 500"))]
+    pub limit: Option<u32>,
+}
+
+/// One attempt at sending a delivery
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeliveryAttemptDto {
+    pub attempted_at: DateTime<Utc>,
+    pub status_code: Option<u16>,
+    /// Beginning of the response body
+    pub response_body: Option<String>,
+    pub error: Option<String>,
+    pub duration_ms: u64,
+}
+
+impl From<DeliveryAttempt> for DeliveryAttemptDto {
+    fn from(attempt: DeliveryAttempt) -> Self {
+        Self {
+            attempted_at: attempt.attempted_at,
+            status_code: attempt.status_code,
+            response_body: attempt.response_body,
+            error: attempt.error,
+            duration_ms: u64::try_from(attempt.duration.as_millis()).unwrap_or(u64::MAX),
+        }
+    }
+}
+
+/// Webhook delivery response DTO
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeliveryResponseDto {
+    pub id: String,
+    pub webhook_id: String,
+    pub event: WebhookEventDto,
+    pub network_id: String,
+    pub status: DeliveryStatusDto,
+    /// Attempts made since the delivery was (re)queued
+    pub attempts: u32,
+    /// When the next attempt is due, while pending
+    pub next_attempt_at: Option<DateTime<Utc>>,
+    pub last_attempt_at: Option<DateTime<Utc>>,
+    pub delivered_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    /// The signed JSON body, only included on the delivery detail
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payload: Option<serde_json::Value>,
+    /// The attempt log, only included on the delivery detail
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attempt_log: Option<Vec<DeliveryAttemptDto>>,
+}
+
+impl DeliveryResponseDto {
+    /// Build the delivery detail, with its payload and attempt log
+    #[must_use]
+    pub fn with_attempts(delivery: WebhookDelivery, attempts: Vec<DeliveryAttempt>) -> Self {
+        let payload = serde_json::from_str(delivery.payload()).ok();
+        Self {
+            payload,
+            attempt_log: Some(attempts.into_iter().map(Into::into).collect()),
+            ..Self::from(delivery)
+        }
+    }
+}
+
+impl From<WebhookDelivery> for DeliveryResponseDto {
+    fn from(delivery: WebhookDelivery) -> Self {
+        Self {
+            id: delivery.id().to_string(),
+            webhook_id: delivery.webhook_id().to_string(),
+            event: delivery.event().into(),
+            network_id: delivery.network_id().to_string(),
+            status: delivery.status().into(),
+            attempts: delivery.attempts(),
+            next_attempt_at: (delivery.status() == DeliveryStatus::Pending).then(|| delivery.next_attempt_at()),
+            last_attempt_at: delivery.last_attempt_at(),
+            delivered_at: delivery.delivered_at(),
+            created_at: delivery.created_at(),
+            payload: None,
+            attempt_log: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::models::url_policy::UrlPolicy;
+
+    #[test]
+    fn test_webhook_dto_defaults() {
+        let dto: WebhookDto = serde_json::from_str(r#"{"url": "https://hooks.example.com/networks"}"#).unwrap();
+        assert!(dto.validate().is_ok());
+
+        let data = WebhookData::from(dto);
+        assert!(data.events.is_empty());
+        assert!(data.network_ids.is_empty());
+        assert!(data.secret.is_none());
+        assert!(data.enabled);
+
+        assert!(
+            serde_json::from_str::<WebhookDto>(r#"{"url": "https://a.example.com", "events": ["deleted"]}"#).is_err()
+        );
+    }
+
+    #[test]
+    fn test_webhook_response_only_includes_secret_on_creation() {
+        let webhook = Webhook::new(
+            WebhookData {
+                url: "https://hooks.example.com/networks".to_string(),
+                events: vec![NetworkEventKind::Created],
+                network_ids: vec![],
+                secret: None,
+                enabled: true,
+            },
+            "user-1".to_string(),
+            &UrlPolicy::default(),
+        )
+        .unwrap();
+
+        let json = serde_json::to_value(WebhookResponseDto::from(webhook.clone())).unwrap();
+        assert!(json.get("secret").is_none());
+        assert_eq!(json["events"], serde_json::json!(["created"]));
+
+        let json = serde_json::to_value(WebhookResponseDto::with_secret(webhook.clone())).unwrap();
+        assert_eq!(json["secret"], webhook.secret());
+    }
+}
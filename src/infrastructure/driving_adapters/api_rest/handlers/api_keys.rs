@@ -0,0 +1,122 @@
+//! API Key Handlers
+//!
+//! HTTP handlers for issuing, listing and revoking the API keys used by
+//! service-to-service clients. All endpoints require authentication with the
+//! `api_keys:manage` permission.
+
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    routing::{delete, get, post},
+    Json, Router,
+};
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::domain::models::api_key::ApiKeyId;
+use crate::infrastructure::driven_adapters::config::PERMISSION_API_KEYS_MANAGE;
+use crate::infrastructure::driving_adapters::api_rest::dto::api_key::{ApiKeyResponseDto, CreateApiKeyDto};
+use crate::infrastructure::driving_adapters::api_rest::middleware::auth::JwtAuth;
+use crate::infrastructure::driving_adapters::api_rest::AppState;
+use crate::shared::errors::ApiError;
+
+/// Create the router for API key endpoints, nested under `/api-keys`
+pub fn router() -> Router<AppState> {
+    Router::new()
+        .route("/", post(create_api_key))
+        .route("/", get(list_api_keys))
+        .route("/:id", delete(revoke_api_key))
+}
+
+/// POST /api-keys - Issue an API key
+///
+/// The response includes the key, which is not returned by any other
+/// endpoint. Only scopes the caller holds can be granted.
+///
+/// # Authentication
+///
+/// Requires a JWT token or API key with the `api_keys:manage` permission.
+///
+/// # Responses
+///
+/// * 201 Created - API key created
+/// * 400 Bad Request - Invalid name, owner, scopes or expiry
+/// * 401 Unauthorized - Missing or invalid credentials
+/// * 403 Forbidden - Missing permission, or scope the caller does not hold
+#[axum::debug_handler]
+async fn create_api_key(
+    JwtAuth(user): JwtAuth,
+    State(state): State<AppState>,
+    Json(dto): Json<CreateApiKeyDto>,
+) -> Result<(StatusCode, Json<ApiKeyResponseDto>), ApiError> {
+    user.require_permission(PERMISSION_API_KEYS_MANAGE)?;
+
+    // Validate DTO
+    dto.validate()?;
+
+    // Execute use case
+    let (api_key, key) = state
+        .create_api_key_use_case
+        .execute(dto.into(), &user.id, &user.permissions)
+        .await?;
+
+    // Return response
+    Ok((StatusCode::CREATED, Json(ApiKeyResponseDto::with_key(api_key, key))))
+}
+
+/// GET /api-keys - List all API keys, revoked ones included
+///
+/// # Authentication
+///
+/// Requires a JWT token or API key with the `api_keys:manage` permission.
+///
+/// # Responses
+///
+/// * 200 OK - List of API keys
+/// * 401 Unauthorized - Missing or invalid credentials
+/// * 403 Forbidden - Missing permission
+#[axum::debug_handler]
+async fn list_api_keys(
+    JwtAuth(user): JwtAuth,
+    State(state): State<AppState>,
+) -> Result<Json<Vec<ApiKeyResponseDto>>, ApiError> {
+    user.require_permission(PERMISSION_API_KEYS_MANAGE)?;
+
+    // Execute use case
+    let api_keys = state.list_api_keys_use_case.execute().await?;
+
+    // Return response
+    Ok(Json(api_keys.into_iter().map(Into::into).collect()))
+}
+
+/// DELETE /api-keys/:id - Revoke an API key
+///
+/// The key stops authenticating immediately and is kept in the list.
+///
+/// # Authentication
+///
+/// Requires a JWT token or API key with the `api_keys:manage` permission.
+///
+/// # Responses
+///
+/// * 200 OK - API key revoked
+/// * 401 Unauthorized - Missing or invalid credentials
+/// * 403 Forbidden - Missing permission
+/// * 404 Not Found - API key does not exist
+#[axum::debug_handler]
+async fn revoke_api_key(
+    JwtAuth(user): JwtAuth,
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> Result<Json<ApiKeyResponseDto>, ApiError> {
+    user.require_permission(PERMISSION_API_KEYS_MANAGE)?;
+
+    // Parse UUID
+    let api_key_id = ApiKeyId::from_uuid(Uuid::parse_str(&id)?);
+
+    // Execute use case
+    let api_key = state.revoke_api_key_use_case.execute(&api_key_id).await?;
+
+    // Return response
+    Ok(Json(api_key.into()))
+}
@@ -0,0 +1,61 @@
+//! Token Handlers
+//!
+//! OAuth 2.0 client credentials grant, nested under `/auth` when
+//! `token_issuer.endpoint_enabled` is set. Service accounts declared in
+//! configuration exchange their client ID and secret for a JWT.
+
+use axum::{
+    extract::State,
+    http::{header, HeaderValue},
+    routing::post,
+    Form, Json, Router,
+};
+
+use crate::infrastructure::driving_adapters::api_rest::dto::auth::{
+    AccessTokenResponseDto, ClientCredentialsDto, CLIENT_CREDENTIALS_GRANT,
+};
+use crate::infrastructure::driving_adapters::api_rest::AppState;
+use crate::shared::errors::ApiError;
+
+/// Create the router for token endpoints
+pub fn router() -> Router<AppState> {
+    Router::new().route("/token", post(issue_token))
+}
+
+/// POST /auth/token - Issue a JWT to a service account
+///
+/// Takes a form-encoded body with `grant_type=client_credentials`,
+/// `client_id` and `client_secret`.
+///
+/// # Authentication
+///
+/// None: the client credentials are checked against the configured service accounts.
+///
+/// # Responses
+///
+/// * 200 OK - Token issued, not to be cached
+/// * 400 Bad Request - Unsupported grant type
+/// * 401 Unauthorized - Invalid client credentials
+#[axum::debug_handler]
+async fn issue_token(
+    State(state): State<AppState>,
+    Form(dto): Form<ClientCredentialsDto>,
+) -> Result<([(header::HeaderName, HeaderValue); 1], Json<AccessTokenResponseDto>), ApiError> {
+    // Validate DTO
+    if dto.grant_type != CLIENT_CREDENTIALS_GRANT {
+        return Err(ApiError::BadRequest(format!(
+            "grant_type must be '{CLIENT_CREDENTIALS_GRANT}'"
+        )));
+    }
+
+    // Execute use case
+    let issued = state
+        .issue_service_token_use_case
+        .execute(&dto.client_id, &dto.client_secret)?;
+
+    // Return response
+    Ok((
+        [(header::CACHE_CONTROL, HeaderValue::from_static("no-store"))],
+        Json(issued.into()),
+    ))
+}
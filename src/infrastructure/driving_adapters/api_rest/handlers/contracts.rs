@@ -0,0 +1,225 @@
+//! Network Contract Handlers
+//!
+//! HTTP handlers for the per-network contract address book and the
+//! cross-network lookup by contract name. All endpoints require JWT authentication.
+
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    routing::get,
+    Json, Router,
+};
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::domain::models::contract::ContractId;
+use crate::domain::models::network::NetworkId;
+use crate::infrastructure::driven_adapters::config::PERMISSION_CONTRACTS_WRITE;
+use crate::infrastructure::driving_adapters::api_rest::dto::contract::{
+    ContractDeploymentDto, ContractDto, ContractLookupQueryDto, ContractResponseDto,
+};
+use crate::infrastructure::driving_adapters::api_rest::middleware::auth::JwtAuth;
+use crate::infrastructure::driving_adapters::api_rest::AppState;
+use crate::shared::errors::ApiError;
+
+/// Create the router for contract endpoints, nested under `/networks`
+pub fn router() -> Router<AppState> {
+    Router::new()
+        .route("/contracts", get(find_contracts_by_name))
+        .route("/:id/contracts", get(list_contracts).post(create_contract))
+        .route(
+            "/:id/contracts/:contract_id",
+            get(get_contract).put(update_contract).delete(delete_contract),
+        )
+}
+
+/// POST /networks/:id/contracts - Register a contract on a network
+///
+/// # Authentication
+///
+/// Requires valid JWT token in Authorization header.
+///
+/// # Responses
+///
+/// * 201 Created - Contract registered
+/// * 400 Bad Request - Validation error
+/// * 401 Unauthorized - Missing or invalid JWT token
+/// * 403 Forbidden - API key without the `contracts:write` scope
+/// * 404 Not Found - Network does not exist
+/// * 409 Conflict - Network already has a contract with the same name
+#[axum::debug_handler]
+async fn create_contract(
+    JwtAuth(user): JwtAuth,
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+    Json(dto): Json<ContractDto>,
+) -> Result<(StatusCode, Json<ContractResponseDto>), ApiError> {
+    user.require_write_scope(PERMISSION_CONTRACTS_WRITE)?;
+
+    // Validate DTO
+    dto.validate()?;
+
+    // Parse UUID
+    let network_id = NetworkId::from_uuid(Uuid::parse_str(&id)?);
+
+    // Execute use case
+    let contract = state.create_contract_use_case.execute(&network_id, dto.into()).await?;
+
+    // Return response
+    Ok((StatusCode::CREATED, Json(contract.into())))
+}
+
+/// GET /networks/:id/contracts - List the contracts of a network
+///
+/// # Authentication
+///
+/// Requires valid JWT token in Authorization header.
+///
+/// # Responses
+///
+/// * 200 OK - List of contracts, sorted by name
+/// * 401 Unauthorized - Missing or invalid JWT token
+/// * 404 Not Found - Network does not exist
+#[axum::debug_handler]
+async fn list_contracts(
+    _auth: JwtAuth,
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> Result<Json<Vec<ContractResponseDto>>, ApiError> {
+    // Parse UUID
+    let network_id = NetworkId::from_uuid(Uuid::parse_str(&id)?);
+
+    // Execute use case
+    let contracts = state.list_contracts_use_case.execute(&network_id).await?;
+
+    // Return response
+    Ok(Json(contracts.into_iter().map(Into::into).collect()))
+}
+
+/// GET /networks/:id/contracts/:contract_id - Get a contract of a network
+///
+/// # Authentication
+///
+/// Requires valid JWT token in Authorization header.
+///
+/// # Responses
+///
+/// * 200 OK - Contract found
+/// * 401 Unauthorized - Missing or invalid JWT token
+/// * 404 Not Found - Contract does not exist for the network
+#[axum::debug_handler]
+async fn get_contract(
+    _auth: JwtAuth,
+    State(state): State<AppState>,
+    Path((id, contract_id)): Path<(String, String)>,
+) -> Result<Json<ContractResponseDto>, ApiError> {
+    // Parse UUIDs
+    let network_id = NetworkId::from_uuid(Uuid::parse_str(&id)?);
+    let contract_id = ContractId::from_uuid(Uuid::parse_str(&contract_id)?);
+
+    // Execute use case
+    let contract = state.get_contract_use_case.execute(&network_id, &contract_id).await?;
+
+    // Return response
+    Ok(Json(contract.into()))
+}
+
+/// PUT /networks/:id/contracts/:contract_id - Replace a contract of a network
+///
+/// # Authentication
+///
+/// Requires valid JWT token in Authorization header.
+///
+/// # Responses
+///
+/// * 200 OK - Contract updated
+/// * 400 Bad Request - Validation error
+/// * 401 Unauthorized - Missing or invalid JWT token
+/// * 403 Forbidden - API key without the `contracts:write` scope
+/// * 404 Not Found - Contract does not exist for the network
+/// * 409 Conflict - Another contract of the network has the same name
+#[axum::debug_handler]
+async fn update_contract(
+    JwtAuth(user): JwtAuth,
+    State(state): State<AppState>,
+    Path((id, contract_id)): Path<(String, String)>,
+    Json(dto): Json<ContractDto>,
+) -> Result<Json<ContractResponseDto>, ApiError> {
+    user.require_write_scope(PERMISSION_CONTRACTS_WRITE)?;
+
+    // Validate DTO
+    dto.validate()?;
+
+    // Parse UUIDs
+    let network_id = NetworkId::from_uuid(Uuid::parse_str(&id)?);
+    let contract_id = ContractId::from_uuid(Uuid::parse_str(&contract_id)?);
+
+    // Execute use case
+    let contract = state
+        .update_contract_use_case
+        .execute(&network_id, &contract_id, dto.into())
+        .await?;
+
+    // Return response
+    Ok(Json(contract.into()))
+}
+
+/// DELETE /networks/:id/contracts/:contract_id - Remove a contract from a network
+///
+/// # Authentication
+///
+/// Requires valid JWT token in Authorization header.
+///
+/// # Responses
+///
+/// * 204 No Content - Contract removed
+/// * 401 Unauthorized - Missing or invalid JWT token
+/// * 403 Forbidden - API key without the `contracts:write` scope
+/// * 404 Not Found - Contract does not exist for the network
+#[axum::debug_handler]
+async fn delete_contract(
+    JwtAuth(user): JwtAuth,
+    State(state): State<AppState>,
+    Path((id, contract_id)): Path<(String, String)>,
+) -> Result<StatusCode, ApiError> {
+    user.require_write_scope(PERMISSION_CONTRACTS_WRITE)?;
+
+    // Parse UUIDs
+    let network_id = NetworkId::from_uuid(Uuid::parse_str(&id)?);
+    let contract_id = ContractId::from_uuid(Uuid::parse_str(&contract_id)?);
+
+    // Execute use case
+    state
+        .delete_contract_use_case
+        .execute(&network_id, &contract_id)
+        .await?;
+
+    // Return response
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// GET /networks/contracts?name= - Find where a contract is deployed
+///
+/// Matches the name case-insensitively across all networks.
+///
+/// # Authentication
+///
+/// Requires valid JWT token in Authorization header.
+///
+/// # Responses
+///
+/// * 200 OK - Deployments of the contract, sorted by chain ID
+/// * 400 Bad Request - Missing or blank name
+/// * 401 Unauthorized - Missing or invalid JWT token
+#[axum::debug_handler]
+async fn find_contracts_by_name(
+    _auth: JwtAuth,
+    State(state): State<AppState>,
+    Query(query): Query<ContractLookupQueryDto>,
+) -> Result<Json<Vec<ContractDeploymentDto>>, ApiError> {
+    // Execute use case
+    let deployments = state.find_contracts_by_name_use_case.execute(&query.name).await?;
+
+    // Return response
+    Ok(Json(deployments.into_iter().map(Into::into).collect()))
+}
@@ -0,0 +1,150 @@
+//! Network Event Handlers
+//!
+//! Server-Sent Events stream of network changes, so that services can
+//! hot-reload network configuration instead of polling. Requires JWT
+//! authentication; RPC URL secrets are redacted as on the other endpoints.
+//! The credentials are re-checked periodically, and the stream ends once
+//! they are revoked or expired.
+
+use std::convert::Infallible;
+use std::time::Duration;
+
+use axum::{
+    extract::State,
+    http::HeaderMap,
+    response::sse::{Event, KeepAlive, Sse},
+    routing::get,
+    Router,
+};
+use tokio::sync::mpsc;
+use tokio::time::{interval_at, Instant};
+use tokio_stream::wrappers::ReceiverStream;
+use tokio_stream::{Stream, StreamExt};
+
+use super::networks::present;
+use crate::infrastructure::driving_adapters::api_rest::dto::event::NetworkEventDto;
+use crate::infrastructure::driving_adapters::api_rest::middleware::auth::{authenticate, JwtAuth};
+use crate::infrastructure::driving_adapters::api_rest::AppState;
+use crate::shared::errors::ApiError;
+
+/// Header sent by `EventSource` clients when reconnecting
+const LAST_EVENT_ID: &str = "last-event-id";
+/// Event telling the client that events were missed and the networks must be reloaded
+const RESET_EVENT: &str = "reset";
+/// Events buffered between the subscription and a slow client
+const STREAM_BUFFER: usize = 16;
+
+/// Create the router for event endpoints, nested under `/networks`
+pub fn router() -> Router<AppState> {
+    Router::new().route("/events", get(stream_events))
+}
+
+/// GET /networks/events - Stream network changes as Server-Sent Events
+///
+/// # Authentication
+///
+/// Requires valid JWT token in Authorization header.
+///
+/// # Resuming
+///
+/// Each event carries an `id`. Reconnecting with `Last-Event-ID` replays the
+/// retained events after it; when they are no longer retained, the stream
+/// starts with a `reset` event and the client must reload the networks.
+///
+/// # Revocation
+///
+/// The JWT or API key is re-checked every `events.reauthenticate_secs`; the
+/// stream ends as soon as it no longer authenticates.
+///
+/// # Responses
+///
+/// * 200 OK - `text/event-stream` of `created`, `updated`, `deactivated` and `restored` events
+/// * 400 Bad Request - Malformed `Last-Event-ID`
+/// * 401 Unauthorized - Missing or invalid JWT token
+#[axum::debug_handler]
+async fn stream_events(
+    JwtAuth(user): JwtAuth,
+    State(state): State<AppState>,
+    headers: HeaderMap,
+) -> Result<Sse<impl Stream<Item = Result<Event, Infallible>>>, ApiError> {
+    // Parse the position to resume from
+    let last_event_id = headers
+        .get(LAST_EVENT_ID)
+        .map(|value| {
+            value
+                .to_str()
+                .ok()
+                .and_then(|value| value.trim().parse::<u64>().ok())
+                .ok_or_else(|| ApiError::BadRequest("Last-Event-ID must be an event ID".to_string()))
+        })
+        .transpose()?;
+
+    // Subscribe
+    let subscription = state.network_events.subscribe(last_event_id);
+    tracing::debug!(
+        user_id = %user.id,
+        last_event_id = ?last_event_id,
+        missed = subscription.missed,
+        "Network event stream opened"
+    );
+
+    // Return response
+    let reset = subscription.missed.then(reset_event);
+    let keep_alive = KeepAlive::new().interval(Duration::from_secs(state.config.events.keep_alive_secs));
+    let reauthenticate = Duration::from_secs(state.config.events.reauthenticate_secs);
+    let recheck_state = state.clone();
+    let user_id = user.id.clone();
+    let events = subscription.events.map(move |sequenced| {
+        let network = present(sequenced.event.network, &user, &state);
+        let payload = NetworkEventDto::new(sequenced.event.kind, sequenced.event.occurred_at, network);
+        let event = Event::default()
+            .id(sequenced.id.to_string())
+            .event(sequenced.event.kind.as_str())
+            .json_data(&payload)
+            .unwrap_or_else(|e| {
+                tracing::error!(event_id = sequenced.id, error = %e, "Failed to serialize network event");
+                reset_event()
+            });
+        Ok(event)
+    });
+    let events = tokio_stream::iter(reset.map(Ok)).chain(events);
+
+    // Forward the events until the client leaves or its credentials stop authenticating
+    let (sender, receiver) = mpsc::channel(STREAM_BUFFER);
+    tokio::spawn(async move {
+        let state = recheck_state;
+        tokio::pin!(events);
+        let mut recheck = interval_at(Instant::now() + reauthenticate, reauthenticate);
+        loop {
+            tokio::select! {
+                event = events.next() => {
+                    let Some(event) = event else { break };
+                    if sender.send(event).await.is_err() {
+                        break;
+                    }
+                }
+                _ = recheck.tick() => {
+                    let authenticated = authenticate(
+                        &headers,
+                        &state.config,
+                        &state.authenticate_api_key_use_case,
+                        &state.check_jwt_revocation_use_case,
+                    )
+                    .await;
+                    if authenticated.is_err() {
+                        tracing::info!(user_id = %user_id, "Closing network event stream, credentials no longer valid");
+                        break;
+                    }
+                }
+                () = sender.closed() => break,
+            }
+        }
+    });
+
+    Ok(Sse::new(ReceiverStream::new(receiver)).keep_alive(keep_alive))
+}
+
+/// Build the event telling the client to reload the networks
+fn reset_event() -> Event {
+    Event::default().event(RESET_EVENT).data("{}")
+}
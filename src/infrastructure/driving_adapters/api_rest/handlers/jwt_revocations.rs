@@ -0,0 +1,123 @@
+//! JWT Revocation Handlers
+//!
+//! HTTP handlers for revoking JWTs before they expire, by token ID or by
+//! subject, nested under `/auth/revocations`. All endpoints require
+//! authentication with the `tokens:revoke` permission.
+
+use axum::{
+    extract::State,
+    http::StatusCode,
+    routing::{get, post},
+    Json, Router,
+};
+use validator::Validate;
+
+use crate::infrastructure::driven_adapters::config::PERMISSION_TOKENS_REVOKE;
+use crate::infrastructure::driving_adapters::api_rest::dto::jwt_revocation::{
+    JwtRevocationListResponseDto, JwtRevocationResponseDto, RevokeJwtDto, RevokeSubjectDto,
+    SubjectRevocationResponseDto,
+};
+use crate::infrastructure::driving_adapters::api_rest::middleware::auth::JwtAuth;
+use crate::infrastructure::driving_adapters::api_rest::AppState;
+use crate::shared::errors::ApiError;
+
+/// Create the router for JWT revocation endpoints
+pub fn router() -> Router<AppState> {
+    Router::new()
+        .route("/", get(list_revocations))
+        .route("/tokens", post(revoke_jwt))
+        .route("/subjects", post(revoke_subject))
+}
+
+/// GET /auth/revocations - List revoked JWTs and subjects
+///
+/// Revoked JWTs are listed until they expire.
+///
+/// # Authentication
+///
+/// Requires a JWT token or API key with the `tokens:revoke` permission.
+///
+/// # Responses
+///
+/// * 200 OK - Revoked JWTs and subjects
+/// * 401 Unauthorized - Missing or invalid credentials
+/// * 403 Forbidden - Missing permission
+#[axum::debug_handler]
+async fn list_revocations(
+    JwtAuth(user): JwtAuth,
+    State(state): State<AppState>,
+) -> Result<Json<JwtRevocationListResponseDto>, ApiError> {
+    user.require_permission(PERMISSION_TOKENS_REVOKE)?;
+
+    // Execute use case
+    let (tokens, subjects) = state.list_jwt_revocations_use_case.execute().await?;
+
+    // Return response
+    Ok(Json(JwtRevocationListResponseDto {
+        tokens: tokens.into_iter().map(Into::into).collect(),
+        subjects: subjects.into_iter().map(Into::into).collect(),
+    }))
+}
+
+/// POST /auth/revocations/tokens - Revoke a JWT by its `jti` claim
+///
+/// # Authentication
+///
+/// Requires a JWT token or API key with the `tokens:revoke` permission.
+///
+/// # Responses
+///
+/// * 201 Created - JWT revoked (or already revoked)
+/// * 400 Bad Request - Invalid token ID, subject or reason
+/// * 401 Unauthorized - Missing or invalid credentials
+/// * 403 Forbidden - Missing permission
+#[axum::debug_handler]
+async fn revoke_jwt(
+    JwtAuth(user): JwtAuth,
+    State(state): State<AppState>,
+    Json(dto): Json<RevokeJwtDto>,
+) -> Result<(StatusCode, Json<JwtRevocationResponseDto>), ApiError> {
+    user.require_permission(PERMISSION_TOKENS_REVOKE)?;
+
+    // Validate DTO
+    dto.validate()?;
+
+    // Execute use case
+    let revocation = state.revoke_jwt_use_case.execute(dto.into(), &user.id).await?;
+
+    // Return response
+    Ok((StatusCode::CREATED, Json(revocation.into())))
+}
+
+/// POST /auth/revocations/subjects - Revoke the JWTs of a subject
+///
+/// Revokes every JWT of the subject issued at or before `revokedBefore`,
+/// which defaults to now.
+///
+/// # Authentication
+///
+/// Requires a JWT token or API key with the `tokens:revoke` permission.
+///
+/// # Responses
+///
+/// * 201 Created - Subject revoked
+/// * 400 Bad Request - Invalid subject or reason, or `revokedBefore` in the future
+/// * 401 Unauthorized - Missing or invalid credentials
+/// * 403 Forbidden - Missing permission
+#[axum::debug_handler]
+async fn revoke_subject(
+    JwtAuth(user): JwtAuth,
+    State(state): State<AppState>,
+    Json(dto): Json<RevokeSubjectDto>,
+) -> Result<(StatusCode, Json<SubjectRevocationResponseDto>), ApiError> {
+    user.require_permission(PERMISSION_TOKENS_REVOKE)?;
+
+    // Validate DTO
+    dto.validate()?;
+
+    // Execute use case
+    let revocation = state.revoke_subject_jwts_use_case.execute(dto.into(), &user.id).await?;
+
+    // Return response
+    Ok((StatusCode::CREATED, Json(revocation.into())))
+}
@@ -2,4 +2,15 @@
 //!
 //! REST API handlers for different resources.
 
+pub mod api_keys;
+pub mod auth;
+pub mod contracts;
+pub mod events;
+pub mod jwt_revocations;
 pub mod networks;
+pub mod public;
+pub mod relations;
+pub mod schedules;
+pub mod signers;
+pub mod tokens;
+pub mod webhooks;
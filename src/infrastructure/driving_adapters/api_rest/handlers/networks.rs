@@ -2,21 +2,31 @@
 //!
 //! HTTP handlers for network CRUD operations.
 //! All endpoints require JWT authentication.
+//!
+//! Secrets embedded in RPC URLs are redacted in responses unless the caller
+//! holds the `secrets:read` permission, in which case placeholders are resolved.
 
 use axum::{
-    extract::{Path, State},
-    http::StatusCode,
+    extract::{Path, Query, State},
+    http::{HeaderMap, HeaderName, HeaderValue, StatusCode},
+    response::Response,
     routing::{delete, get, patch, post, put},
     Json, Router,
 };
 use uuid::Uuid;
 use validator::Validate;
 
-use crate::domain::models::network::NetworkId;
+use crate::domain::models::lifecycle::NetworkStatus;
+use crate::domain::models::network::{Network, NetworkId};
+use crate::infrastructure::driven_adapters::config::{PERMISSION_NETWORKS_WRITE, PERMISSION_SECRETS_READ};
+use crate::infrastructure::driving_adapters::api_rest::conditional::{
+    conditional_json, list_etag, network_etag, HTTP_DATE_FORMAT,
+};
 use crate::infrastructure::driving_adapters::api_rest::dto::network::{
-    CreateNetworkDto, NetworkResponseDto, PatchNetworkDto, UpdateNetworkDto,
+    CreateNetworkDto, DeprecateNetworkDto, ExplorerLinksQueryDto, ExplorerLinksResponseDto, NetworkListQueryDto,
+    NetworkResponseDto, NetworkSearchQueryDto, PatchNetworkDto, UpdateNetworkDto,
 };
-use crate::infrastructure::driving_adapters::api_rest::middleware::auth::JwtAuth;
+use crate::infrastructure::driving_adapters::api_rest::middleware::auth::{AuthenticatedUser, JwtAuth};
 use crate::infrastructure::driving_adapters::api_rest::AppState;
 use crate::shared::errors::ApiError;
 
@@ -24,14 +34,76 @@ use crate::shared::errors::ApiError;
 ///
 /// All routes require JWT authentication via the `JwtAuth` extractor.
 /// The `add_config_extension` middleware injects the config into request extensions.
+/// Static segments next to `/:id`, here or in the merged routers, must be listed in
+/// `RESERVED_SLUGS` so that no network slug is shadowed by them.
 pub fn router() -> Router<AppState> {
     Router::new()
         .route("/", post(create_network))
         .route("/", get(get_active_networks))
+        .route("/search", get(search_networks))
         .route("/:id", get(get_network_by_id))
         .route("/:id", put(update_network))
         .route("/:id", patch(partial_update_network))
         .route("/:id", delete(delete_network))
+        .route("/:id/explorer-links", get(get_explorer_links))
+        .route("/:id/activate", post(activate_network))
+        .route("/:id/deprecate", post(deprecate_network))
+        .route("/:id/retire", post(retire_network))
+        .merge(super::schedules::router())
+        .merge(super::contracts::router())
+        .merge(super::tokens::router())
+        .merge(super::signers::router())
+        .merge(super::relations::router())
+        .merge(super::events::router())
+}
+
+/// Build the response for a network, redacting or resolving RPC URL secrets for the caller
+pub(super) fn present(network: Network, user: &AuthenticatedUser, state: &AppState) -> NetworkResponseDto {
+    let response = NetworkResponseDto::from(network);
+    if user.has_permission(PERMISSION_SECRETS_READ) {
+        response.resolve_secrets(state.secret_resolver.as_ref())
+    } else {
+        response.redact_secrets()
+    }
+}
+
+/// Name the representation `present` builds for the caller, for `ETag`s
+///
+/// Resolved URLs also depend on the configured secret values, so that
+/// representation carries their version.
+fn representation(user: &AuthenticatedUser, state: &AppState) -> String {
+    if user.has_permission(PERMISSION_SECRETS_READ) {
+        format!("resolved.{}", state.secret_resolver.version())
+    } else {
+        "redacted".to_string()
+    }
+}
+
+/// `Deprecation` response header (RFC 9745)
+static DEPRECATION: HeaderName = HeaderName::from_static("deprecation");
+/// `Sunset` response header (RFC 8594)
+static SUNSET: HeaderName = HeaderName::from_static("sunset");
+
+/// Build the `Deprecation` and `Sunset` headers advertised for deprecated networks
+fn lifecycle_headers(network: &Network) -> HeaderMap {
+    let mut headers = HeaderMap::new();
+    if network.status() != NetworkStatus::Deprecated {
+        return headers;
+    }
+
+    if let Some(deprecated_at) = network.deprecated_at() {
+        if let Ok(value) = HeaderValue::from_str(&format!("@{}", deprecated_at.timestamp())) {
+            headers.insert(DEPRECATION.clone(), value);
+        }
+    }
+
+    if let Some(sunset_at) = network.sunset_at() {
+        if let Ok(value) = HeaderValue::from_str(&sunset_at.format(HTTP_DATE_FORMAT).to_string()) {
+            headers.insert(SUNSET.clone(), value);
+        }
+    }
+
+    headers
 }
 
 /// POST /networks - Create a new network
@@ -45,13 +117,16 @@ pub fn router() -> Router<AppState> {
 /// * 201 Created - Network created successfully
 /// * 400 Bad Request - Validation error
 /// * 401 Unauthorized - Missing or invalid JWT token
+/// * 403 Forbidden - API key without the `networks:write` scope
 /// * 409 Conflict - Network with same chain_id already exists
 #[axum::debug_handler]
 async fn create_network(
-    _auth: JwtAuth, // Require authentication
+    JwtAuth(user): JwtAuth,
     State(state): State<AppState>,
     Json(dto): Json<CreateNetworkDto>,
 ) -> Result<(StatusCode, Json<NetworkResponseDto>), ApiError> {
+    user.require_write_scope(PERMISSION_NETWORKS_WRITE)?;
+
     // Validate DTO
     dto.validate()?;
 
@@ -59,11 +134,14 @@ async fn create_network(
     let network = state.create_network_use_case.execute(dto.into()).await?;
 
     // Return response
-    Ok((StatusCode::CREATED, Json(NetworkResponseDto::from(network))))
+    Ok((StatusCode::CREATED, Json(present(network, &user, &state))))
 }
 
 /// GET /networks - Get all active networks
 ///
+/// Accepts a `tags` query parameter with comma-separated tags the networks must all carry.
+/// Honors `If-None-Match` with the list's `ETag`.
+///
 /// # Authentication
 ///
 /// Requires valid JWT token in Authorization header.
@@ -71,21 +149,70 @@ async fn create_network(
 /// # Responses
 ///
 /// * 200 OK - List of active networks (sorted by name)
+/// * 304 Not Modified - The list matches the `If-None-Match` `ETag`
+/// * 400 Bad Request - Malformed tag
 /// * 401 Unauthorized - Missing or invalid JWT token
 #[axum::debug_handler]
 async fn get_active_networks(
-    _auth: JwtAuth, // Require authentication
+    JwtAuth(user): JwtAuth,
     State(state): State<AppState>,
+    headers: HeaderMap,
+    Query(query): Query<NetworkListQueryDto>,
+) -> Result<Response, ApiError> {
+    // Execute use case
+    let networks = state.get_active_networks_use_case.execute(&query.tags()).await?;
+
+    // Return response
+    let etag = list_etag(&representation(&user, &state), &networks);
+    let response: Vec<NetworkResponseDto> = networks
+        .into_iter()
+        .map(|network| present(network, &user, &state))
+        .collect();
+    conditional_json(
+        &headers,
+        &state.config.http_cache.network_list,
+        &etag,
+        None,
+        HeaderMap::new(),
+        &response,
+    )
+}
+
+/// GET /networks/search - Search networks by name, slug, aliases and tags
+///
+/// Accepts a `q` query parameter matched by prefix and by similarity, so
+/// `arb` and `polygn` both find results, and an optional `limit`.
+///
+/// # Authentication
+///
+/// Requires valid JWT token in Authorization header.
+///
+/// # Responses
+///
+/// * 200 OK - Matching networks in any lifecycle state (best match first)
+/// * 400 Bad Request - Empty or too long query, or limit out of range
+/// * 401 Unauthorized - Missing or invalid JWT token
+#[axum::debug_handler]
+async fn search_networks(
+    JwtAuth(user): JwtAuth,
+    State(state): State<AppState>,
+    Query(query): Query<NetworkSearchQueryDto>,
 ) -> Result<Json<Vec<NetworkResponseDto>>, ApiError> {
     // Execute use case
-    let networks = state.get_active_networks_use_case.execute().await?;
+    let networks = state.search_networks_use_case.execute(&query.q, query.limit).await?;
 
     // Return response
-    let response: Vec<NetworkResponseDto> = networks.into_iter().map(NetworkResponseDto::from).collect();
+    let response: Vec<NetworkResponseDto> = networks
+        .into_iter()
+        .map(|network| present(network, &user, &state))
+        .collect();
     Ok(Json(response))
 }
 
-/// GET /networks/:id - Get a network by ID
+/// GET /networks/:idOrSlug - Get a network by ID, slug or alias
+///
+/// Honors `If-None-Match` with the network's `ETag`, and `If-Modified-Since`
+/// with its `Last-Modified` date unless secrets are resolved for the caller.
 ///
 /// # Authentication
 ///
@@ -94,23 +221,170 @@ async fn get_active_networks(
 /// # Responses
 ///
 /// * 200 OK - Network found
+/// * 304 Not Modified - The network matches the request preconditions
 /// * 401 Unauthorized - Missing or invalid JWT token
-/// * 404 Not Found - Network does not exist
+/// * 404 Not Found - No network has this ID, slug or alias
 #[axum::debug_handler]
 async fn get_network_by_id(
-    _auth: JwtAuth, // Require authentication
+    JwtAuth(user): JwtAuth,
     State(state): State<AppState>,
+    headers: HeaderMap,
     Path(id): Path<String>,
-) -> Result<Json<NetworkResponseDto>, ApiError> {
+) -> Result<Response, ApiError> {
+    // Execute use case (slugs can never parse as a UUID)
+    let network = match Uuid::parse_str(&id) {
+        Ok(uuid) => {
+            let network_id = NetworkId::from_uuid(uuid);
+            state.get_network_by_id_use_case.execute(&network_id).await?
+        }
+        Err(_) => state.get_network_by_slug_use_case.execute(&id).await?,
+    };
+
+    // Return response
+    let lifecycle_headers = lifecycle_headers(&network);
+    let etag = network_etag(&representation(&user, &state), &network);
+    // The date does not move when a secret is rotated, so resolved URLs are only validated by `ETag`
+    let last_modified = (!user.has_permission(PERMISSION_SECRETS_READ)).then(|| network.updated_at());
+    conditional_json(
+        &headers,
+        &state.config.http_cache.network,
+        &etag,
+        last_modified,
+        lifecycle_headers,
+        &present(network, &user, &state),
+    )
+}
+
+/// GET /networks/:id/explorer-links - Build block explorer links for a network
+///
+/// Accepts any combination of `tx`, `address` and `block` query parameters.
+///
+/// # Authentication
+///
+/// Requires valid JWT token in Authorization header.
+///
+/// # Responses
+///
+/// * 200 OK - Links built
+/// * 400 Bad Request - Malformed item or explorer without a known URL standard
+/// * 401 Unauthorized - Missing or invalid JWT token
+/// * 404 Not Found - Network does not exist
+#[axum::debug_handler]
+async fn get_explorer_links(
+    _auth: JwtAuth,
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+    Query(query): Query<ExplorerLinksQueryDto>,
+) -> Result<Json<ExplorerLinksResponseDto>, ApiError> {
     // Parse UUID
     let uuid = Uuid::parse_str(&id)?;
     let network_id = NetworkId::from_uuid(uuid);
 
     // Execute use case
-    let network = state.get_network_by_id_use_case.execute(&network_id).await?;
+    let links = state
+        .get_explorer_links_use_case
+        .execute(&network_id, &query.into())
+        .await?;
+
+    // Return response
+    Ok(Json(links.into()))
+}
+
+/// POST /networks/:id/activate - Activate a draft or deprecated network, or restore a retired one
+///
+/// # Authentication
+///
+/// Requires valid JWT token in Authorization header.
+///
+/// # Responses
+///
+/// * 200 OK - Network activated
+/// * 400 Bad Request - Transition not allowed from the current state
+/// * 401 Unauthorized - Missing or invalid JWT token
+/// * 403 Forbidden - API key without the `networks:write` scope
+/// * 404 Not Found - Network does not exist
+#[axum::debug_handler]
+async fn activate_network(
+    JwtAuth(user): JwtAuth,
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> Result<(HeaderMap, Json<NetworkResponseDto>), ApiError> {
+    user.require_write_scope(PERMISSION_NETWORKS_WRITE)?;
+
+    transition_network(&user, &state, &id, NetworkStatus::Active, None).await
+}
+
+/// POST /networks/:id/deprecate - Deprecate an active network
+///
+/// Accepts an optional `sunsetAt` planned removal date, advertised in the `Sunset` header.
+///
+/// # Authentication
+///
+/// Requires valid JWT token in Authorization header.
+///
+/// # Responses
+///
+/// * 200 OK - Network deprecated
+/// * 400 Bad Request - Transition not allowed or `sunsetAt` in the past
+/// * 401 Unauthorized - Missing or invalid JWT token
+/// * 403 Forbidden - API key without the `networks:write` scope
+/// * 404 Not Found - Network does not exist
+#[axum::debug_handler]
+async fn deprecate_network(
+    JwtAuth(user): JwtAuth,
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+    Json(dto): Json<DeprecateNetworkDto>,
+) -> Result<(HeaderMap, Json<NetworkResponseDto>), ApiError> {
+    user.require_write_scope(PERMISSION_NETWORKS_WRITE)?;
+
+    transition_network(&user, &state, &id, NetworkStatus::Deprecated, dto.sunset_at).await
+}
+
+/// POST /networks/:id/retire - Retire a network
+///
+/// # Authentication
+///
+/// Requires valid JWT token in Authorization header.
+///
+/// # Responses
+///
+/// * 200 OK - Network retired
+/// * 400 Bad Request - Network is already retired
+/// * 401 Unauthorized - Missing or invalid JWT token
+/// * 403 Forbidden - API key without the `networks:write` scope
+/// * 404 Not Found - Network does not exist
+#[axum::debug_handler]
+async fn retire_network(
+    JwtAuth(user): JwtAuth,
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> Result<(HeaderMap, Json<NetworkResponseDto>), ApiError> {
+    user.require_write_scope(PERMISSION_NETWORKS_WRITE)?;
+
+    transition_network(&user, &state, &id, NetworkStatus::Retired, None).await
+}
+
+/// Shared implementation of the lifecycle transition endpoints
+async fn transition_network(
+    user: &AuthenticatedUser,
+    state: &AppState,
+    id: &str,
+    target: NetworkStatus,
+    sunset_at: Option<chrono::DateTime<chrono::Utc>>,
+) -> Result<(HeaderMap, Json<NetworkResponseDto>), ApiError> {
+    // Parse UUID
+    let uuid = Uuid::parse_str(id)?;
+    let network_id = NetworkId::from_uuid(uuid);
+
+    // Execute use case
+    let network = state
+        .transition_network_use_case
+        .execute(&network_id, target, sunset_at)
+        .await?;
 
     // Return response
-    Ok(Json(NetworkResponseDto::from(network)))
+    Ok((lifecycle_headers(&network), Json(present(network, user, state))))
 }
 
 /// PUT /networks/:id - Full update of a network
@@ -124,15 +398,18 @@ async fn get_network_by_id(
 /// * 200 OK - Network updated successfully
 /// * 400 Bad Request - Validation error
 /// * 401 Unauthorized - Missing or invalid JWT token
+/// * 403 Forbidden - API key without the `networks:write` scope
 /// * 404 Not Found - Network does not exist
 /// * 409 Conflict - New chain_id already exists
 #[axum::debug_handler]
 async fn update_network(
-    _auth: JwtAuth, // Require authentication
+    JwtAuth(user): JwtAuth,
     State(state): State<AppState>,
     Path(id): Path<String>,
     Json(dto): Json<UpdateNetworkDto>,
-) -> Result<Json<NetworkResponseDto>, ApiError> {
+) -> Result<(HeaderMap, Json<NetworkResponseDto>), ApiError> {
+    user.require_write_scope(PERMISSION_NETWORKS_WRITE)?;
+
     // Validate DTO
     dto.validate()?;
 
@@ -147,7 +424,7 @@ async fn update_network(
         .await?;
 
     // Return response
-    Ok(Json(NetworkResponseDto::from(network)))
+    Ok((lifecycle_headers(&network), Json(present(network, &user, &state))))
 }
 
 /// PATCH /networks/:id - Partial update of a network
@@ -161,15 +438,18 @@ async fn update_network(
 /// * 200 OK - Network updated successfully
 /// * 400 Bad Request - Validation error
 /// * 401 Unauthorized - Missing or invalid JWT token
+/// * 403 Forbidden - API key without the `networks:write` scope
 /// * 404 Not Found - Network does not exist
 /// * 409 Conflict - New chain_id already exists
 #[axum::debug_handler]
 async fn partial_update_network(
-    _auth: JwtAuth, // Require authentication
+    JwtAuth(user): JwtAuth,
     State(state): State<AppState>,
     Path(id): Path<String>,
     Json(dto): Json<PatchNetworkDto>,
-) -> Result<Json<NetworkResponseDto>, ApiError> {
+) -> Result<(HeaderMap, Json<NetworkResponseDto>), ApiError> {
+    user.require_write_scope(PERMISSION_NETWORKS_WRITE)?;
+
     // Validate DTO
     dto.validate()?;
 
@@ -184,7 +464,7 @@ async fn partial_update_network(
         .await?;
 
     // Return response
-    Ok(Json(NetworkResponseDto::from(network)))
+    Ok((lifecycle_headers(&network), Json(present(network, &user, &state))))
 }
 
 /// DELETE /networks/:id - Soft delete a network
@@ -197,13 +477,16 @@ async fn partial_update_network(
 ///
 /// * 204 No Content - Network deleted successfully
 /// * 401 Unauthorized - Missing or invalid JWT token
+/// * 403 Forbidden - API key without the `networks:write` scope
 /// * 404 Not Found - Network does not exist
 #[axum::debug_handler]
 async fn delete_network(
-    _auth: JwtAuth, // Require authentication
+    JwtAuth(user): JwtAuth,
     State(state): State<AppState>,
     Path(id): Path<String>,
 ) -> Result<StatusCode, ApiError> {
+    user.require_write_scope(PERMISSION_NETWORKS_WRITE)?;
+
     // Parse UUID
     let uuid = Uuid::parse_str(&id)?;
     let network_id = NetworkId::from_uuid(uuid);
@@ -0,0 +1,117 @@
+//! Public Network Handlers
+//!
+//! Read-only endpoints served without authentication, nested under `/public`
+//! when `public_api.enabled` is set. They expose the usable networks only,
+//! through a projection leaving out RPC URLs, signer addresses and any other
+//! operational detail. Writes stay on the authenticated routes.
+
+use axum::{
+    extract::{Path, Query, State},
+    http::HeaderMap,
+    response::Response,
+    routing::get,
+    Router,
+};
+use uuid::Uuid;
+
+use crate::domain::models::network::NetworkId;
+use crate::infrastructure::driving_adapters::api_rest::conditional::{conditional_json, list_etag, network_etag};
+use crate::infrastructure::driving_adapters::api_rest::dto::network::{NetworkListQueryDto, PublicNetworkResponseDto};
+use crate::infrastructure::driving_adapters::api_rest::AppState;
+use crate::shared::errors::{ApiError, UseCaseError};
+
+/// Name of the public projection, for `ETag`s
+const PUBLIC_REPRESENTATION: &str = "public";
+
+/// Create the router for public network endpoints
+pub fn router() -> Router<AppState> {
+    Router::new()
+        .route("/networks", get(get_public_networks))
+        .route("/networks/:id", get(get_public_network))
+}
+
+/// GET /public/networks - Get all usable networks
+///
+/// Accepts a `tags` query parameter with comma-separated tags the networks must all carry.
+/// Honors `If-None-Match` with the list's `ETag`.
+///
+/// # Authentication
+///
+/// None.
+///
+/// # Responses
+///
+/// * 200 OK - List of active and deprecated networks (sorted by name)
+/// * 304 Not Modified - The list matches the `If-None-Match` `ETag`
+/// * 400 Bad Request - Malformed tag
+/// * 429 Too Many Requests - Public rate limit exceeded
+#[axum::debug_handler]
+async fn get_public_networks(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Query(query): Query<NetworkListQueryDto>,
+) -> Result<Response, ApiError> {
+    // Execute use case
+    let networks = state.get_active_networks_use_case.execute(&query.tags()).await?;
+
+    // Return response
+    let response: Vec<PublicNetworkResponseDto> = networks.iter().map(Into::into).collect();
+    conditional_json(
+        &headers,
+        &state.config.public_api.cache_control,
+        &list_etag(PUBLIC_REPRESENTATION, &networks),
+        None,
+        HeaderMap::new(),
+        &response,
+    )
+}
+
+/// GET /public/networks/:idOrSlug - Get a usable network by ID, slug or alias
+///
+/// Honors `If-None-Match` with the network's `ETag`, and `If-Modified-Since`
+/// with its `Last-Modified` date.
+///
+/// # Authentication
+///
+/// None.
+///
+/// # Responses
+///
+/// * 200 OK - Network found
+/// * 304 Not Modified - The network matches the request preconditions
+/// * 404 Not Found - No usable network has this ID, slug or alias
+/// * 429 Too Many Requests - Public rate limit exceeded
+#[axum::debug_handler]
+async fn get_public_network(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(id): Path<String>,
+) -> Result<Response, ApiError> {
+    // Execute use case (slugs can never parse as a UUID)
+    let network = match Uuid::parse_str(&id) {
+        Ok(uuid) => {
+            let network_id = NetworkId::from_uuid(uuid);
+            state.get_network_by_id_use_case.execute(&network_id).await?
+        }
+        Err(_) => state.get_network_by_slug_use_case.execute(&id).await?,
+    };
+
+    // Drafts and retired networks are not public
+    if !network.active() {
+        return Err(UseCaseError::NotFound {
+            resource: "Network".to_string(),
+            id,
+        }
+        .into());
+    }
+
+    // Return response
+    conditional_json(
+        &headers,
+        &state.config.public_api.cache_control,
+        &network_etag(PUBLIC_REPRESENTATION, &network),
+        Some(network.updated_at()),
+        HeaderMap::new(),
+        &PublicNetworkResponseDto::from(&network),
+    )
+}
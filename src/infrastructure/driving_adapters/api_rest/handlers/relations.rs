@@ -0,0 +1,192 @@
+//! Network Relation Handlers
+//!
+//! HTTP handlers for parent-chain relationships: attaching a network to the
+//! network it is a rollup, sidechain or testnet of, and walking the tree in
+//! both directions, plus the testnets paired with a mainnet. All endpoints
+//! require JWT authentication.
+
+use axum::{
+    extract::{Path, State},
+    routing::{get, put},
+    Json, Router,
+};
+use uuid::Uuid;
+
+use super::networks::present;
+use crate::domain::models::network::NetworkId;
+use crate::infrastructure::driven_adapters::config::PERMISSION_NETWORKS_WRITE;
+use crate::infrastructure::driving_adapters::api_rest::dto::network::{NetworkParentDto, NetworkResponseDto};
+use crate::infrastructure::driving_adapters::api_rest::middleware::auth::JwtAuth;
+use crate::infrastructure::driving_adapters::api_rest::AppState;
+use crate::shared::errors::ApiError;
+
+/// Create the router for relation endpoints, nested under `/networks`
+pub fn router() -> Router<AppState> {
+    Router::new()
+        .route("/:id/parent", put(set_parent).delete(remove_parent))
+        .route("/:id/children", get(list_children))
+        .route("/:id/ancestors", get(list_ancestors))
+        .route("/:id/testnets", get(list_testnets))
+}
+
+/// PUT /networks/:id/parent - Attach a network to its parent
+///
+/// # Authentication
+///
+/// Requires valid JWT token in Authorization header.
+///
+/// # Responses
+///
+/// * 200 OK - Parent set
+/// * 400 Bad Request - Unknown parent network, cycle or maximum depth exceeded
+/// * 401 Unauthorized - Missing or invalid JWT token
+/// * 403 Forbidden - API key without the `networks:write` scope
+/// * 404 Not Found - Network does not exist
+#[axum::debug_handler]
+async fn set_parent(
+    JwtAuth(user): JwtAuth,
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+    Json(dto): Json<NetworkParentDto>,
+) -> Result<Json<NetworkResponseDto>, ApiError> {
+    user.require_write_scope(PERMISSION_NETWORKS_WRITE)?;
+
+    // Parse UUID
+    let network_id = NetworkId::from_uuid(Uuid::parse_str(&id)?);
+
+    // Execute use case
+    let network = state
+        .set_network_parent_use_case
+        .execute(&network_id, Some(dto.into()))
+        .await?;
+
+    // Return response
+    Ok(Json(present(network, &user, &state)))
+}
+
+/// DELETE /networks/:id/parent - Detach a network from its parent
+///
+/// # Authentication
+///
+/// Requires valid JWT token in Authorization header.
+///
+/// # Responses
+///
+/// * 200 OK - Parent removed
+/// * 401 Unauthorized - Missing or invalid JWT token
+/// * 403 Forbidden - API key without the `networks:write` scope
+/// * 404 Not Found - Network does not exist
+#[axum::debug_handler]
+async fn remove_parent(
+    JwtAuth(user): JwtAuth,
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> Result<Json<NetworkResponseDto>, ApiError> {
+    user.require_write_scope(PERMISSION_NETWORKS_WRITE)?;
+
+    // Parse UUID
+    let network_id = NetworkId::from_uuid(Uuid::parse_str(&id)?);
+
+    // Execute use case
+    let network = state.set_network_parent_use_case.execute(&network_id, None).await?;
+
+    // Return response
+    Ok(Json(present(network, &user, &state)))
+}
+
+/// GET /networks/:id/children - List the networks whose parent is this network
+///
+/// # Authentication
+///
+/// Requires valid JWT token in Authorization header.
+///
+/// # Responses
+///
+/// * 200 OK - Direct children, sorted by name, whatever their lifecycle state
+/// * 401 Unauthorized - Missing or invalid JWT token
+/// * 404 Not Found - Network does not exist
+#[axum::debug_handler]
+async fn list_children(
+    JwtAuth(user): JwtAuth,
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> Result<Json<Vec<NetworkResponseDto>>, ApiError> {
+    // Parse UUID
+    let network_id = NetworkId::from_uuid(Uuid::parse_str(&id)?);
+
+    // Execute use case
+    let networks = state.list_network_children_use_case.execute(&network_id).await?;
+
+    // Return response
+    Ok(Json(
+        networks
+            .into_iter()
+            .map(|network| present(network, &user, &state))
+            .collect(),
+    ))
+}
+
+/// GET /networks/:id/ancestors - List the parents of a network up to the root
+///
+/// # Authentication
+///
+/// Requires valid JWT token in Authorization header.
+///
+/// # Responses
+///
+/// * 200 OK - Ancestors, nearest first
+/// * 401 Unauthorized - Missing or invalid JWT token
+/// * 404 Not Found - Network does not exist
+#[axum::debug_handler]
+async fn list_ancestors(
+    JwtAuth(user): JwtAuth,
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> Result<Json<Vec<NetworkResponseDto>>, ApiError> {
+    // Parse UUID
+    let network_id = NetworkId::from_uuid(Uuid::parse_str(&id)?);
+
+    // Execute use case
+    let networks = state.list_network_ancestors_use_case.execute(&network_id).await?;
+
+    // Return response
+    Ok(Json(
+        networks
+            .into_iter()
+            .map(|network| present(network, &user, &state))
+            .collect(),
+    ))
+}
+
+/// GET /networks/:id/testnets - List the testnets mirroring a mainnet
+///
+/// # Authentication
+///
+/// Requires valid JWT token in Authorization header.
+///
+/// # Responses
+///
+/// * 200 OK - Testnets paired with the network, sorted by name
+/// * 400 Bad Request - Invalid UUID
+/// * 401 Unauthorized - Missing or invalid JWT token
+/// * 404 Not Found - Network does not exist
+#[axum::debug_handler]
+async fn list_testnets(
+    JwtAuth(user): JwtAuth,
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> Result<Json<Vec<NetworkResponseDto>>, ApiError> {
+    // Parse UUID
+    let network_id = NetworkId::from_uuid(Uuid::parse_str(&id)?);
+
+    // Execute use case
+    let networks = state.list_network_testnets_use_case.execute(&network_id).await?;
+
+    // Return response
+    Ok(Json(
+        networks
+            .into_iter()
+            .map(|network| present(network, &user, &state))
+            .collect(),
+    ))
+}
@@ -0,0 +1,131 @@
+//! Lifecycle Schedule Handlers
+//!
+//! HTTP handlers for scheduling future lifecycle transitions of a network.
+//! All endpoints require JWT authentication; the caller is recorded on the schedule.
+
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    routing::{delete, get, post},
+    Json, Router,
+};
+use uuid::Uuid;
+
+use crate::domain::models::network::NetworkId;
+use crate::domain::models::schedule::ScheduleId;
+use crate::infrastructure::driven_adapters::config::PERMISSION_NETWORKS_WRITE;
+use crate::infrastructure::driving_adapters::api_rest::dto::schedule::{CreateScheduleDto, ScheduleResponseDto};
+use crate::infrastructure::driving_adapters::api_rest::middleware::auth::JwtAuth;
+use crate::infrastructure::driving_adapters::api_rest::AppState;
+use crate::shared::errors::ApiError;
+
+/// Create the router for lifecycle schedule endpoints, nested under `/networks`
+pub fn router() -> Router<AppState> {
+    Router::new()
+        .route("/:id/schedules", post(create_schedule))
+        .route("/:id/schedules", get(list_schedules))
+        .route("/:id/schedules/:schedule_id", delete(cancel_schedule))
+}
+
+/// POST /networks/:id/schedules - Schedule an activation, deprecation or retirement
+///
+/// # Authentication
+///
+/// Requires valid JWT token in Authorization header.
+///
+/// # Responses
+///
+/// * 201 Created - Change scheduled
+/// * 400 Bad Request - `runAt` not in the future or network retired
+/// * 401 Unauthorized - Missing or invalid JWT token
+/// * 403 Forbidden - API key without the `networks:write` scope
+/// * 404 Not Found - Network does not exist
+#[axum::debug_handler]
+async fn create_schedule(
+    JwtAuth(user): JwtAuth,
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+    Json(dto): Json<CreateScheduleDto>,
+) -> Result<(StatusCode, Json<ScheduleResponseDto>), ApiError> {
+    user.require_write_scope(PERMISSION_NETWORKS_WRITE)?;
+
+    // Parse UUID
+    let uuid = Uuid::parse_str(&id)?;
+    let network_id = NetworkId::from_uuid(uuid);
+
+    // Execute use case
+    let schedule = state
+        .schedule_lifecycle_change_use_case
+        .execute(&network_id, dto.action.into(), dto.run_at, user.id)
+        .await?;
+
+    // Return response
+    Ok((StatusCode::CREATED, Json(schedule.into())))
+}
+
+/// GET /networks/:id/schedules - List the lifecycle schedules of a network
+///
+/// Includes applied, failed and cancelled schedules, sorted by run time.
+///
+/// # Authentication
+///
+/// Requires valid JWT token in Authorization header.
+///
+/// # Responses
+///
+/// * 200 OK - List of schedules
+/// * 401 Unauthorized - Missing or invalid JWT token
+/// * 404 Not Found - Network does not exist
+#[axum::debug_handler]
+async fn list_schedules(
+    _auth: JwtAuth,
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> Result<Json<Vec<ScheduleResponseDto>>, ApiError> {
+    // Parse UUID
+    let uuid = Uuid::parse_str(&id)?;
+    let network_id = NetworkId::from_uuid(uuid);
+
+    // Execute use case
+    let schedules = state.list_lifecycle_schedules_use_case.execute(&network_id).await?;
+
+    // Return response
+    Ok(Json(schedules.into_iter().map(Into::into).collect()))
+}
+
+/// DELETE /networks/:id/schedules/:schedule_id - Cancel a pending schedule
+///
+/// The schedule is kept, marked as cancelled by the caller.
+///
+/// # Authentication
+///
+/// Requires valid JWT token in Authorization header.
+///
+/// # Responses
+///
+/// * 200 OK - Schedule cancelled
+/// * 400 Bad Request - Schedule already applied, failed or cancelled
+/// * 401 Unauthorized - Missing or invalid JWT token
+/// * 403 Forbidden - API key without the `networks:write` scope
+/// * 404 Not Found - Schedule does not exist for the network
+#[axum::debug_handler]
+async fn cancel_schedule(
+    JwtAuth(user): JwtAuth,
+    State(state): State<AppState>,
+    Path((id, schedule_id)): Path<(String, String)>,
+) -> Result<Json<ScheduleResponseDto>, ApiError> {
+    user.require_write_scope(PERMISSION_NETWORKS_WRITE)?;
+
+    // Parse UUIDs
+    let network_id = NetworkId::from_uuid(Uuid::parse_str(&id)?);
+    let schedule_id = ScheduleId::from_uuid(Uuid::parse_str(&schedule_id)?);
+
+    // Execute use case
+    let schedule = state
+        .cancel_lifecycle_schedule_use_case
+        .execute(&network_id, &schedule_id, user.id)
+        .await?;
+
+    // Return response
+    Ok(Json(schedule.into()))
+}
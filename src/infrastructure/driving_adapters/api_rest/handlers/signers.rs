@@ -0,0 +1,202 @@
+//! Network Signer Handlers
+//!
+//! HTTP handlers for the signers of a network and the lookup of the signer
+//! serving a role on a chain. All endpoints require JWT authentication.
+
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    routing::{get, put},
+    Json, Router,
+};
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::domain::models::network::NetworkId;
+use crate::domain::models::signer::SignerId;
+use crate::infrastructure::driven_adapters::config::PERMISSION_SIGNERS_WRITE;
+use crate::infrastructure::driving_adapters::api_rest::dto::signer::{
+    ResolvedSignerDto, SignerDto, SignerListQueryDto, SignerLookupQueryDto, SignerResponseDto,
+};
+use crate::infrastructure::driving_adapters::api_rest::middleware::auth::JwtAuth;
+use crate::infrastructure::driving_adapters::api_rest::AppState;
+use crate::shared::errors::ApiError;
+
+/// Create the router for signer endpoints, nested under `/networks`
+pub fn router() -> Router<AppState> {
+    Router::new()
+        .route("/signers", get(resolve_signer))
+        .route("/:id/signers", get(list_signers).post(create_signer))
+        .route("/:id/signers/:signer_id", put(update_signer).delete(delete_signer))
+}
+
+/// POST /networks/:id/signers - Register a signer for a role on a network
+///
+/// # Authentication
+///
+/// Requires valid JWT token in Authorization header.
+///
+/// # Responses
+///
+/// * 201 Created - Signer registered
+/// * 400 Bad Request - Validation error
+/// * 401 Unauthorized - Missing or invalid JWT token
+/// * 403 Forbidden - API key without the `signers:write` scope
+/// * 404 Not Found - Network does not exist
+/// * 409 Conflict - The address already has the role on the network
+#[axum::debug_handler]
+async fn create_signer(
+    JwtAuth(user): JwtAuth,
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+    Json(dto): Json<SignerDto>,
+) -> Result<(StatusCode, Json<SignerResponseDto>), ApiError> {
+    user.require_write_scope(PERMISSION_SIGNERS_WRITE)?;
+
+    // Validate DTO
+    dto.validate()?;
+
+    // Parse UUID
+    let network_id = NetworkId::from_uuid(Uuid::parse_str(&id)?);
+
+    // Execute use case
+    let signer = state.create_signer_use_case.execute(&network_id, dto.into()).await?;
+
+    // Return response
+    Ok((StatusCode::CREATED, Json(signer.into())))
+}
+
+/// GET /networks/:id/signers - List the signers of a network
+///
+/// Accepts an optional `role` query parameter to keep a single role.
+///
+/// # Authentication
+///
+/// Requires valid JWT token in Authorization header.
+///
+/// # Responses
+///
+/// * 200 OK - List of signers, sorted by role then priority
+/// * 401 Unauthorized - Missing or invalid JWT token
+/// * 404 Not Found - Network does not exist
+#[axum::debug_handler]
+async fn list_signers(
+    _auth: JwtAuth,
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+    Query(query): Query<SignerListQueryDto>,
+) -> Result<Json<Vec<SignerResponseDto>>, ApiError> {
+    // Parse UUID
+    let network_id = NetworkId::from_uuid(Uuid::parse_str(&id)?);
+
+    // Execute use case
+    let signers = state
+        .list_signers_use_case
+        .execute(&network_id, query.role.as_deref())
+        .await?;
+
+    // Return response
+    Ok(Json(signers.into_iter().map(Into::into).collect()))
+}
+
+/// PUT /networks/:id/signers/:signer_id - Replace a signer of a network
+///
+/// # Authentication
+///
+/// Requires valid JWT token in Authorization header.
+///
+/// # Responses
+///
+/// * 200 OK - Signer updated
+/// * 400 Bad Request - Validation error
+/// * 401 Unauthorized - Missing or invalid JWT token
+/// * 403 Forbidden - API key without the `signers:write` scope
+/// * 404 Not Found - Signer does not exist for the network
+/// * 409 Conflict - Another signer of the network has the same role and address
+#[axum::debug_handler]
+async fn update_signer(
+    JwtAuth(user): JwtAuth,
+    State(state): State<AppState>,
+    Path((id, signer_id)): Path<(String, String)>,
+    Json(dto): Json<SignerDto>,
+) -> Result<Json<SignerResponseDto>, ApiError> {
+    user.require_write_scope(PERMISSION_SIGNERS_WRITE)?;
+
+    // Validate DTO
+    dto.validate()?;
+
+    // Parse UUIDs
+    let network_id = NetworkId::from_uuid(Uuid::parse_str(&id)?);
+    let signer_id = SignerId::from_uuid(Uuid::parse_str(&signer_id)?);
+
+    // Execute use case
+    let signer = state
+        .update_signer_use_case
+        .execute(&network_id, &signer_id, dto.into())
+        .await?;
+
+    // Return response
+    Ok(Json(signer.into()))
+}
+
+/// DELETE /networks/:id/signers/:signer_id - Remove a signer from a network
+///
+/// # Authentication
+///
+/// Requires valid JWT token in Authorization header.
+///
+/// # Responses
+///
+/// * 204 No Content - Signer removed
+/// * 401 Unauthorized - Missing or invalid JWT token
+/// * 403 Forbidden - API key without the `signers:write` scope
+/// * 404 Not Found - Signer does not exist for the network
+#[axum::debug_handler]
+async fn delete_signer(
+    JwtAuth(user): JwtAuth,
+    State(state): State<AppState>,
+    Path((id, signer_id)): Path<(String, String)>,
+) -> Result<StatusCode, ApiError> {
+    user.require_write_scope(PERMISSION_SIGNERS_WRITE)?;
+
+    // Parse UUIDs
+    let network_id = NetworkId::from_uuid(Uuid::parse_str(&id)?);
+    let signer_id = SignerId::from_uuid(Uuid::parse_str(&signer_id)?);
+
+    // Execute use case
+    state.delete_signer_use_case.execute(&network_id, &signer_id).await?;
+
+    // Return response
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// GET /networks/signers?chainId=&role= - Resolve the signer of a role on a chain
+///
+/// Picks the enabled signer of the role with the lowest priority. The
+/// `default` role falls back to the network's `defaultSignerAddress`.
+///
+/// # Authentication
+///
+/// Requires valid JWT token in Authorization header.
+///
+/// # Responses
+///
+/// * 200 OK - Signer resolved
+/// * 400 Bad Request - Missing chain ID or blank role
+/// * 401 Unauthorized - Missing or invalid JWT token
+/// * 404 Not Found - Unknown chain ID or no enabled signer for the role
+#[axum::debug_handler]
+async fn resolve_signer(
+    _auth: JwtAuth,
+    State(state): State<AppState>,
+    Query(query): Query<SignerLookupQueryDto>,
+) -> Result<Json<ResolvedSignerDto>, ApiError> {
+    // Execute use case
+    let resolved = state
+        .resolve_signer_use_case
+        .execute(query.chain_id, &query.role)
+        .await?;
+
+    // Return response
+    Ok(Json(resolved.into()))
+}
@@ -0,0 +1,233 @@
+//! Token Handlers
+//!
+//! HTTP handlers for the per-network token list: token management, bulk import
+//! and export as a Uniswap Token Lists document. All endpoints require JWT
+//! authentication.
+
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    routing::{get, post, put},
+    Json, Router,
+};
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::domain::models::network::NetworkId;
+use crate::domain::models::token::TokenId;
+use crate::infrastructure::driven_adapters::config::PERMISSION_TOKENS_WRITE;
+use crate::infrastructure::driving_adapters::api_rest::dto::token::{
+    CreateTokenDto, ImportTokensDto, TokenImportResponseDto, TokenListDto, TokenResponseDto, UpdateTokenDto,
+};
+use crate::infrastructure::driving_adapters::api_rest::middleware::auth::JwtAuth;
+use crate::infrastructure::driving_adapters::api_rest::AppState;
+use crate::shared::errors::ApiError;
+
+/// Create the router for token endpoints, nested under `/networks`
+pub fn router() -> Router<AppState> {
+    Router::new()
+        .route("/:id/tokens", get(list_tokens).post(create_token))
+        .route("/:id/tokens/import", post(import_tokens))
+        .route("/:id/tokens/:token_id", put(update_token).delete(delete_token))
+        .route("/:id/token-list", get(export_token_list))
+}
+
+/// POST /networks/:id/tokens - Add a token to a network
+///
+/// # Authentication
+///
+/// Requires valid JWT token in Authorization header.
+///
+/// # Responses
+///
+/// * 201 Created - Token added (minor token list version bump)
+/// * 400 Bad Request - Validation error
+/// * 401 Unauthorized - Missing or invalid JWT token
+/// * 403 Forbidden - API key without the `tokens:write` scope
+/// * 404 Not Found - Network does not exist
+/// * 409 Conflict - Token address already listed on the network
+#[axum::debug_handler]
+async fn create_token(
+    JwtAuth(user): JwtAuth,
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+    Json(dto): Json<CreateTokenDto>,
+) -> Result<(StatusCode, Json<TokenResponseDto>), ApiError> {
+    user.require_write_scope(PERMISSION_TOKENS_WRITE)?;
+
+    // Validate DTO
+    dto.validate()?;
+
+    // Parse UUID
+    let network_id = NetworkId::from_uuid(Uuid::parse_str(&id)?);
+
+    // Execute use case
+    let address = dto.address.clone();
+    let token = state
+        .create_token_use_case
+        .execute(&network_id, address, dto.into())
+        .await?;
+
+    // Return response
+    Ok((StatusCode::CREATED, Json(token.into())))
+}
+
+/// GET /networks/:id/tokens - List the tokens of a network
+///
+/// # Authentication
+///
+/// Requires valid JWT token in Authorization header.
+///
+/// # Responses
+///
+/// * 200 OK - List of tokens, sorted by symbol
+/// * 401 Unauthorized - Missing or invalid JWT token
+/// * 404 Not Found - Network does not exist
+#[axum::debug_handler]
+async fn list_tokens(
+    _auth: JwtAuth,
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> Result<Json<Vec<TokenResponseDto>>, ApiError> {
+    // Parse UUID
+    let network_id = NetworkId::from_uuid(Uuid::parse_str(&id)?);
+
+    // Execute use case
+    let tokens = state.list_tokens_use_case.execute(&network_id).await?;
+
+    // Return response
+    Ok(Json(tokens.into_iter().map(Into::into).collect()))
+}
+
+/// PUT /networks/:id/tokens/:token_id - Replace the metadata of a token
+///
+/// # Authentication
+///
+/// Requires valid JWT token in Authorization header.
+///
+/// # Responses
+///
+/// * 200 OK - Token updated (patch token list version bump when it changed)
+/// * 400 Bad Request - Validation error
+/// * 401 Unauthorized - Missing or invalid JWT token
+/// * 403 Forbidden - API key without the `tokens:write` scope
+/// * 404 Not Found - Token does not exist for the network
+#[axum::debug_handler]
+async fn update_token(
+    JwtAuth(user): JwtAuth,
+    State(state): State<AppState>,
+    Path((id, token_id)): Path<(String, String)>,
+    Json(dto): Json<UpdateTokenDto>,
+) -> Result<Json<TokenResponseDto>, ApiError> {
+    user.require_write_scope(PERMISSION_TOKENS_WRITE)?;
+
+    // Validate DTO
+    dto.validate()?;
+
+    // Parse UUIDs
+    let network_id = NetworkId::from_uuid(Uuid::parse_str(&id)?);
+    let token_id = TokenId::from_uuid(Uuid::parse_str(&token_id)?);
+
+    // Execute use case
+    let token = state
+        .update_token_use_case
+        .execute(&network_id, &token_id, dto.into())
+        .await?;
+
+    // Return response
+    Ok(Json(token.into()))
+}
+
+/// DELETE /networks/:id/tokens/:token_id - Remove a token from a network
+///
+/// # Authentication
+///
+/// Requires valid JWT token in Authorization header.
+///
+/// # Responses
+///
+/// * 204 No Content - Token removed (major token list version bump)
+/// * 401 Unauthorized - Missing or invalid JWT token
+/// * 403 Forbidden - API key without the `tokens:write` scope
+/// * 404 Not Found - Token does not exist for the network
+#[axum::debug_handler]
+async fn delete_token(
+    JwtAuth(user): JwtAuth,
+    State(state): State<AppState>,
+    Path((id, token_id)): Path<(String, String)>,
+) -> Result<StatusCode, ApiError> {
+    user.require_write_scope(PERMISSION_TOKENS_WRITE)?;
+
+    // Parse UUIDs
+    let network_id = NetworkId::from_uuid(Uuid::parse_str(&id)?);
+    let token_id = TokenId::from_uuid(Uuid::parse_str(&token_id)?);
+
+    // Execute use case
+    state.delete_token_use_case.execute(&network_id, &token_id).await?;
+
+    // Return response
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// POST /networks/:id/tokens/import - Bulk add or update tokens
+///
+/// Accepts `{"tokens": [...]}`, including a whole Token Lists document; entries
+/// whose `chainId` belongs to another network are skipped.
+///
+/// # Authentication
+///
+/// Requires valid JWT token in Authorization header.
+///
+/// # Responses
+///
+/// * 200 OK - Import summary with the resulting token list version
+/// * 400 Bad Request - Any invalid or duplicated entry (nothing is imported)
+/// * 401 Unauthorized - Missing or invalid JWT token
+/// * 403 Forbidden - API key without the `tokens:write` scope
+/// * 404 Not Found - Network does not exist
+#[axum::debug_handler]
+async fn import_tokens(
+    JwtAuth(user): JwtAuth,
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+    Json(dto): Json<ImportTokensDto>,
+) -> Result<Json<TokenImportResponseDto>, ApiError> {
+    user.require_write_scope(PERMISSION_TOKENS_WRITE)?;
+
+    // Parse UUID
+    let network_id = NetworkId::from_uuid(Uuid::parse_str(&id)?);
+
+    // Execute use case
+    let entries = dto.tokens.into_iter().map(Into::into).collect();
+    let summary = state.import_tokens_use_case.execute(&network_id, entries).await?;
+
+    // Return response
+    Ok(Json(summary.into()))
+}
+
+/// GET /networks/:id/token-list - Export the tokens as a Uniswap Token Lists document
+///
+/// # Authentication
+///
+/// Requires valid JWT token in Authorization header.
+///
+/// # Responses
+///
+/// * 200 OK - Token list document
+/// * 401 Unauthorized - Missing or invalid JWT token
+/// * 404 Not Found - Network does not exist
+#[axum::debug_handler]
+async fn export_token_list(
+    _auth: JwtAuth,
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> Result<Json<TokenListDto>, ApiError> {
+    // Parse UUID
+    let network_id = NetworkId::from_uuid(Uuid::parse_str(&id)?);
+
+    // Execute use case
+    let list = state.export_token_list_use_case.execute(&network_id).await?;
+
+    // Return response
+    Ok(Json(list.into()))
+}
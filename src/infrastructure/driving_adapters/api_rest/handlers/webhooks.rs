@@ -0,0 +1,290 @@
+//! Webhook Handlers
+//!
+//! HTTP handlers for managing webhook subscriptions to network changes and
+//! inspecting their delivery log. All endpoints require JWT authentication.
+
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    routing::{delete, get, post, put},
+    Json, Router,
+};
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::domain::models::webhook::{DeliveryId, WebhookId};
+use crate::infrastructure::driven_adapters::config::PERMISSION_WEBHOOKS_WRITE;
+use crate::infrastructure::driving_adapters::api_rest::dto::webhook::{
+    DeliveryListQueryDto, DeliveryResponseDto, WebhookDto, WebhookResponseDto, DEFAULT_DELIVERY_LIMIT,
+};
+use crate::infrastructure::driving_adapters::api_rest::middleware::auth::JwtAuth;
+use crate::infrastructure::driving_adapters::api_rest::AppState;
+use crate::shared::errors::ApiError;
+
+/// Create the router for webhook endpoints, nested under `/webhooks`
+pub fn router() -> Router<AppState> {
+    Router::new()
+        .route("/", post(create_webhook))
+        .route("/", get(list_webhooks))
+        .route("/:id", get(get_webhook))
+        .route("/:id", put(update_webhook))
+        .route("/:id", delete(delete_webhook))
+        .route("/:id/deliveries", get(list_deliveries))
+        .route("/:id/deliveries/:delivery_id", get(get_delivery))
+        .route("/:id/deliveries/:delivery_id/redeliver", post(redeliver))
+}
+
+/// POST /webhooks - Subscribe a URL to network changes
+///
+/// The response includes the signing secret, generated when not provided.
+/// It is not returned by any other endpoint.
+///
+/// # Authentication
+///
+/// Requires valid JWT token in Authorization header.
+///
+/// # Responses
+///
+/// * 201 Created - Webhook created
+/// * 400 Bad Request - Invalid URL, filters or secret
+/// * 401 Unauthorized - Missing or invalid JWT token
+/// * 403 Forbidden - API key without the `webhooks:write` scope
+#[axum::debug_handler]
+async fn create_webhook(
+    JwtAuth(user): JwtAuth,
+    State(state): State<AppState>,
+    Json(dto): Json<WebhookDto>,
+) -> Result<(StatusCode, Json<WebhookResponseDto>), ApiError> {
+    user.require_write_scope(PERMISSION_WEBHOOKS_WRITE)?;
+
+    // Validate DTO
+    dto.validate()?;
+
+    // Execute use case
+    let webhook = state.create_webhook_use_case.execute(dto.into(), &user.id).await?;
+
+    // Return response
+    Ok((StatusCode::CREATED, Json(WebhookResponseDto::with_secret(webhook))))
+}
+
+/// GET /webhooks - List all webhooks
+///
+/// # Authentication
+///
+/// Requires valid JWT token in Authorization header.
+///
+/// # Responses
+///
+/// * 200 OK - List of webhooks
+/// * 401 Unauthorized - Missing or invalid JWT token
+#[axum::debug_handler]
+async fn list_webhooks(
+    _auth: JwtAuth,
+    State(state): State<AppState>,
+) -> Result<Json<Vec<WebhookResponseDto>>, ApiError> {
+    // Execute use case
+    let webhooks = state.list_webhooks_use_case.execute().await?;
+
+    // Return response
+    Ok(Json(webhooks.into_iter().map(Into::into).collect()))
+}
+
+/// GET /webhooks/:id - Get a webhook
+///
+/// # Authentication
+///
+/// Requires valid JWT token in Authorization header.
+///
+/// # Responses
+///
+/// * 200 OK - Webhook found
+/// * 401 Unauthorized - Missing or invalid JWT token
+/// * 404 Not Found - Webhook does not exist
+#[axum::debug_handler]
+async fn get_webhook(
+    _auth: JwtAuth,
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> Result<Json<WebhookResponseDto>, ApiError> {
+    // Parse UUID
+    let webhook_id = WebhookId::from_uuid(Uuid::parse_str(&id)?);
+
+    // Execute use case
+    let webhook = state.get_webhook_use_case.execute(&webhook_id).await?;
+
+    // Return response
+    Ok(Json(webhook.into()))
+}
+
+/// PUT /webhooks/:id - Replace a webhook
+///
+/// The signing secret is kept when omitted, and rotated when provided.
+///
+/// # Authentication
+///
+/// Requires valid JWT token in Authorization header.
+///
+/// # Responses
+///
+/// * 200 OK - Webhook updated
+/// * 400 Bad Request - Invalid URL, filters or secret
+/// * 401 Unauthorized - Missing or invalid JWT token
+/// * 403 Forbidden - API key without the `webhooks:write` scope
+/// * 404 Not Found - Webhook does not exist
+#[axum::debug_handler]
+async fn update_webhook(
+    JwtAuth(user): JwtAuth,
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+    Json(dto): Json<WebhookDto>,
+) -> Result<Json<WebhookResponseDto>, ApiError> {
+    user.require_write_scope(PERMISSION_WEBHOOKS_WRITE)?;
+
+    // Parse UUID
+    let webhook_id = WebhookId::from_uuid(Uuid::parse_str(&id)?);
+
+    // Validate DTO
+    dto.validate()?;
+
+    // Execute use case
+    let webhook = state.update_webhook_use_case.execute(&webhook_id, dto.into()).await?;
+
+    // Return response
+    Ok(Json(webhook.into()))
+}
+
+/// DELETE /webhooks/:id - Delete a webhook and its delivery log
+///
+/// # Authentication
+///
+/// Requires valid JWT token in Authorization header.
+///
+/// # Responses
+///
+/// * 204 No Content - Webhook deleted
+/// * 401 Unauthorized - Missing or invalid JWT token
+/// * 403 Forbidden - API key without the `webhooks:write` scope
+/// * 404 Not Found - Webhook does not exist
+#[axum::debug_handler]
+async fn delete_webhook(
+    JwtAuth(user): JwtAuth,
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> Result<StatusCode, ApiError> {
+    user.require_write_scope(PERMISSION_WEBHOOKS_WRITE)?;
+
+    // Parse UUID
+    let webhook_id = WebhookId::from_uuid(Uuid::parse_str(&id)?);
+
+    // Execute use case
+    state.delete_webhook_use_case.execute(&webhook_id).await?;
+
+    // Return response
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// GET /webhooks/:id/deliveries - List the most recent deliveries of a webhook
+///
+/// Supports an optional `limit` (default 50), newest first.
+///
+/// # Authentication
+///
+/// Requires valid JWT token in Authorization header.
+///
+/// # Responses
+///
+/// * 200 OK - List of deliveries
+/// * 400 Bad Request - Limit out of range
+/// * 401 Unauthorized - Missing or invalid JWT token
+/// * 404 Not Found - Webhook does not exist
+#[axum::debug_handler]
+async fn list_deliveries(
+    _auth: JwtAuth,
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+    Query(query): Query<DeliveryListQueryDto>,
+) -> Result<Json<Vec<DeliveryResponseDto>>, ApiError> {
+    // Parse UUID
+    let webhook_id = WebhookId::from_uuid(Uuid::parse_str(&id)?);
+
+    // Validate query
+    query.validate()?;
+
+    // Execute use case
+    let deliveries = state
+        .list_webhook_deliveries_use_case
+        .execute(&webhook_id, query.limit.unwrap_or(DEFAULT_DELIVERY_LIMIT))
+        .await?;
+
+    // Return response
+    Ok(Json(deliveries.into_iter().map(Into::into).collect()))
+}
+
+/// GET /webhooks/:id/deliveries/:delivery_id - Get a delivery with its payload and attempt log
+///
+/// # Authentication
+///
+/// Requires valid JWT token in Authorization header.
+///
+/// # Responses
+///
+/// * 200 OK - Delivery found
+/// * 401 Unauthorized - Missing or invalid JWT token
+/// * 404 Not Found - Delivery does not exist for the webhook
+#[axum::debug_handler]
+async fn get_delivery(
+    _auth: JwtAuth,
+    State(state): State<AppState>,
+    Path((id, delivery_id)): Path<(String, String)>,
+) -> Result<Json<DeliveryResponseDto>, ApiError> {
+    // Parse UUIDs
+    let webhook_id = WebhookId::from_uuid(Uuid::parse_str(&id)?);
+    let delivery_id = DeliveryId::from_uuid(Uuid::parse_str(&delivery_id)?);
+
+    // Execute use case
+    let (delivery, attempts) = state
+        .get_webhook_delivery_use_case
+        .execute(&webhook_id, &delivery_id)
+        .await?;
+
+    // Return response
+    Ok(Json(DeliveryResponseDto::with_attempts(delivery, attempts)))
+}
+
+/// POST /webhooks/:id/deliveries/:delivery_id/redeliver - Send a finished delivery again
+///
+/// The delivery is queued again with the same ID and payload, so receivers
+/// can deduplicate it.
+///
+/// # Authentication
+///
+/// Requires valid JWT token in Authorization header.
+///
+/// # Responses
+///
+/// * 202 Accepted - Delivery queued
+/// * 401 Unauthorized - Missing or invalid JWT token
+/// * 403 Forbidden - API key without the `webhooks:write` scope
+/// * 404 Not Found - Delivery does not exist for the webhook
+/// * 409 Conflict - Delivery is still pending
+#[axum::debug_handler]
+async fn redeliver(
+    JwtAuth(user): JwtAuth,
+    State(state): State<AppState>,
+    Path((id, delivery_id)): Path<(String, String)>,
+) -> Result<(StatusCode, Json<DeliveryResponseDto>), ApiError> {
+    user.require_write_scope(PERMISSION_WEBHOOKS_WRITE)?;
+
+    // Parse UUIDs
+    let webhook_id = WebhookId::from_uuid(Uuid::parse_str(&id)?);
+    let delivery_id = DeliveryId::from_uuid(Uuid::parse_str(&delivery_id)?);
+
+    // Execute use case
+    let delivery = state
+        .redeliver_webhook_use_case
+        .execute(&webhook_id, &delivery_id)
+        .await?;
+
+    // Return response
+    Ok((StatusCode::ACCEPTED, Json(delivery.into())))
+}
@@ -1,21 +1,36 @@
-//! JWT Authentication Middleware
+//! Authentication Middleware
 //!
-//! Extracts and validates JWT tokens from requests.
+//! Extracts and validates credentials from requests: a JWT in the
+//! `Authorization` header, or an API key in the `X-API-Key` header for
+//! service-to-service clients. JWTs are checked against the revocation list.
 
 use std::sync::Arc;
 
 use axum::{
     extract::{FromRequestParts, State},
-    http::{header::AUTHORIZATION, request::Parts, StatusCode},
+    http::{header::AUTHORIZATION, request::Parts, HeaderMap, HeaderName, StatusCode},
     response::{IntoResponse, Response},
     Json,
 };
+use chrono::DateTime;
 use jsonwebtoken::{decode, Algorithm, DecodingKey, Validation};
 use serde::{Deserialize, Serialize};
 
+use crate::application::use_cases::api_keys::AuthenticateApiKeyUseCase;
+use crate::application::use_cases::auth::CheckJwtRevocationUseCase;
+use crate::domain::models::api_key::ApiKey;
 use crate::infrastructure::driven_adapters::config::AppConfig;
 use crate::infrastructure::driving_adapters::api_rest::AppState;
-use crate::shared::errors::ErrorResponse;
+use crate::shared::errors::{ApiError, ErrorResponse, UseCaseError};
+
+/// Header carrying an API key
+pub const API_KEY_HEADER: HeaderName = HeaderName::from_static("x-api-key");
+
+/// Role of the users authenticated with an API key
+pub const API_KEY_ROLE: &str = "api_key";
+
+/// Clock skew tolerated when validating JWT expiry, in seconds
+pub const JWT_LEEWAY_SECS: u64 = 60;
 
 /// JWT claims structure
 #[derive(Debug, Serialize, Deserialize)]
@@ -30,14 +45,55 @@ pub struct Claims {
     pub iat: i64,
     /// Expiration timestamp
     pub exp: i64,
+    /// Token ID, allowing the token to be revoked on its own
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub jti: Option<String>,
 }
 
-/// Authenticated user extracted from JWT
+/// Authenticated user extracted from a JWT or an API key
 #[derive(Debug, Clone)]
 pub struct AuthenticatedUser {
     pub id: String,
     pub email: String,
     pub role: String,
+    /// Permissions granted to the user's role
+    pub permissions: Vec<String>,
+}
+
+impl AuthenticatedUser {
+    /// Check whether the user has been granted a permission
+    #[must_use]
+    pub fn has_permission(&self, permission: &str) -> bool {
+        self.permissions.iter().any(|p| p == permission)
+    }
+
+    /// Require a permission, failing with 403 Forbidden without it
+    ///
+    /// # Errors
+    ///
+    /// Returns `UseCaseError::Forbidden` if the permission has not been granted
+    pub fn require_permission(&self, permission: &str) -> Result<(), ApiError> {
+        if self.has_permission(permission) {
+            Ok(())
+        } else {
+            Err(UseCaseError::Forbidden(format!("Missing permission '{permission}'")).into())
+        }
+    }
+
+    /// Require a write scope from API key callers, failing with 403 Forbidden without it
+    ///
+    /// JWT callers may write whatever their role.
+    ///
+    /// # Errors
+    ///
+    /// Returns `UseCaseError::Forbidden` if an API key lacks the scope
+    pub fn require_write_scope(&self, scope: &str) -> Result<(), ApiError> {
+        if self.role == API_KEY_ROLE {
+            self.require_permission(scope)
+        } else {
+            Ok(())
+        }
+    }
 }
 
 impl From<Claims> for AuthenticatedUser {
@@ -46,23 +102,60 @@ impl From<Claims> for AuthenticatedUser {
             id: claims.sub,
             email: claims.email,
             role: claims.role,
+            permissions: Vec::new(),
         }
     }
 }
 
-/// JWT authentication extractor
+impl From<ApiKey> for AuthenticatedUser {
+    fn from(api_key: ApiKey) -> Self {
+        Self {
+            id: format!("api-key:{}", api_key.id()),
+            email: api_key.owner().to_string(),
+            role: API_KEY_ROLE.to_string(),
+            permissions: api_key.scopes().to_vec(),
+        }
+    }
+}
+
+/// Authentication extractor
+///
+/// Accepts a JWT bearer token, or an API key when no `Authorization` header
+/// is sent. API key users get the key's scopes as permissions.
 pub struct JwtAuth(pub AuthenticatedUser);
 
 /// Error type for authentication failures
 pub struct AuthError {
+    status: StatusCode,
     message: String,
 }
 
+impl AuthError {
+    fn unauthorized(message: &str) -> Self {
+        Self {
+            status: StatusCode::UNAUTHORIZED,
+            message: message.to_string(),
+        }
+    }
+
+    fn internal(message: &str) -> Self {
+        Self {
+            status: StatusCode::INTERNAL_SERVER_ERROR,
+            message: message.to_string(),
+        }
+    }
+}
+
 impl IntoResponse for AuthError {
     fn into_response(self) -> Response {
         let body = ErrorResponse {
             error: crate::shared::errors::ErrorDetail {
-                code: "UNAUTHORIZED".to_string(),
+                code: if self.status == StatusCode::UNAUTHORIZED {
+                    "UNAUTHORIZED"
+                } else {
+                    "INTERNAL_ERROR"
+                }
+                .to_string(),
                 message: self.message,
                 details: None,
             },
@@ -70,7 +163,7 @@ impl IntoResponse for AuthError {
             timestamp: chrono::Utc::now().to_rfc3339(),
         };
 
-        (StatusCode::UNAUTHORIZED, Json(body)).into_response()
+        (self.status, Json(body)).into_response()
     }
 }
 
@@ -90,59 +183,127 @@ where
         Self: 'async_trait,
     {
         Box::pin(async move {
-            // Get config from request extensions
+            // Get config and authenticators from request extensions
             let config = parts
                 .extensions
                 .get::<Arc<AppConfig>>()
-                .ok_or_else(|| AuthError {
-                    message: "Configuration not available".to_string(),
-                })?
-                .clone();
-
-            // Extract Authorization header
-            let auth_header = parts
-                .headers
-                .get(AUTHORIZATION)
-                .and_then(|h| h.to_str().ok())
-                .ok_or_else(|| AuthError {
-                    message: "Missing Authorization header".to_string(),
-                })?;
-
-            // Check Bearer prefix
-            let token = auth_header
-                .strip_prefix("Bearer ")
-                .ok_or_else(|| AuthError {
-                    message: "Invalid Authorization header format".to_string(),
-                })?;
-
-            // Decode and validate JWT with explicit algorithm to prevent algorithm confusion attacks
-            let mut validation = Validation::new(Algorithm::HS256);
-            // Require exp claim to be present and valid
-            validation.validate_exp = true;
-            // Optionally set clock skew tolerance (default is 60 seconds)
-            validation.leeway = 60;
-
-            let token_data = decode::<Claims>(
-                token,
-                &DecodingKey::from_secret(config.jwt.secret.expose().as_bytes()),
-                &validation,
-            )
-            .map_err(|_| AuthError {
-                // Don't expose internal token validation details
-                message: "Invalid or expired token".to_string(),
-            })?;
-
-            Ok(JwtAuth(token_data.claims.into()))
+                .ok_or_else(|| AuthError::internal("Configuration not available"))?;
+            let check_jwt_revocation = parts
+                .extensions
+                .get::<Arc<CheckJwtRevocationUseCase>>()
+                .ok_or_else(|| AuthError::internal("Token revocation check not available"))?;
+            let authenticate_api_key = parts
+                .extensions
+                .get::<Arc<AuthenticateApiKeyUseCase>>()
+                .ok_or_else(|| AuthError::internal("API key authentication not available"))?;
+
+            authenticate(&parts.headers, config, authenticate_api_key, check_jwt_revocation)
+                .await
+                .map(JwtAuth)
         })
     }
 }
 
-/// Middleware layer that adds config to request extensions for JWT validation
+/// Authenticate a request from its headers
+///
+/// Used by the extractor, and by long-lived streams re-checking the
+/// credentials they were opened with.
+///
+/// # Errors
+///
+/// Returns an unauthorized `AuthError` if the credentials are missing, invalid,
+/// expired or revoked, and an internal one if they cannot be checked.
+pub async fn authenticate(
+    headers: &HeaderMap,
+    config: &AppConfig,
+    authenticate_api_key: &AuthenticateApiKeyUseCase,
+    check_jwt_revocation: &CheckJwtRevocationUseCase,
+) -> Result<AuthenticatedUser, AuthError> {
+    // Fall back to the X-API-Key header without an Authorization header
+    let Some(auth_header) = headers.get(AUTHORIZATION) else {
+        return authenticate_key(headers, authenticate_api_key).await;
+    };
+    let auth_header = auth_header
+        .to_str()
+        .map_err(|_| AuthError::unauthorized("Invalid Authorization header format"))?;
+
+    // Check Bearer prefix
+    let token = auth_header
+        .strip_prefix("Bearer ")
+        .ok_or_else(|| AuthError::unauthorized("Invalid Authorization header format"))?;
+
+    // Decode and validate JWT with explicit algorithm to prevent algorithm confusion attacks
+    let mut validation = Validation::new(Algorithm::HS256);
+    // Require exp claim to be present and valid
+    validation.validate_exp = true;
+    // Optionally set clock skew tolerance (default is 60 seconds)
+    validation.leeway = JWT_LEEWAY_SECS;
+
+    let token_data = decode::<Claims>(
+        token,
+        &DecodingKey::from_secret(config.jwt.secret.expose().as_bytes()),
+        &validation,
+    )
+    // Don't expose internal token validation details
+    .map_err(|_| AuthError::unauthorized("Invalid or expired token"))?;
+
+    check_revocation(check_jwt_revocation, &token_data.claims).await?;
+
+    let mut user = AuthenticatedUser::from(token_data.claims);
+    user.permissions = config.authorization.permissions_for(&user.role);
+
+    Ok(user)
+}
+
+/// Reject a JWT revoked by its ID or subject
+async fn check_revocation(use_case: &CheckJwtRevocationUseCase, claims: &Claims) -> Result<(), AuthError> {
+    let issued_at =
+        DateTime::from_timestamp(claims.iat, 0).ok_or_else(|| AuthError::unauthorized("Invalid or expired token"))?;
+
+    match use_case.execute(claims.jti.as_deref(), &claims.sub, issued_at).await {
+        Ok(()) => Ok(()),
+        Err(UseCaseError::Unauthorized(message)) => Err(AuthError::unauthorized(&message)),
+        Err(e) => {
+            // Fail closed: a revoked token must not get through while the list is unavailable
+            tracing::error!("JWT revocation check failed: {e}");
+            Err(AuthError::internal("Authentication failed"))
+        }
+    }
+}
+
+/// Authenticate a request with the key in its `X-API-Key` header
+async fn authenticate_key(
+    headers: &HeaderMap,
+    use_case: &AuthenticateApiKeyUseCase,
+) -> Result<AuthenticatedUser, AuthError> {
+    let key = headers
+        .get(API_KEY_HEADER)
+        .and_then(|h| h.to_str().ok())
+        .ok_or_else(|| AuthError::unauthorized("Missing Authorization or X-API-Key header"))?;
+
+    match use_case.execute(key).await {
+        Ok(api_key) => Ok(api_key.into()),
+        Err(UseCaseError::Unauthorized(message)) => Err(AuthError::unauthorized(&message)),
+        Err(e) => {
+            tracing::error!("API key authentication failed: {e}");
+            Err(AuthError::internal("Authentication failed"))
+        }
+    }
+}
+
+/// Middleware layer that adds the config, the API key authenticator and the
+/// JWT revocation check to request extensions for authentication
 pub async fn add_config_extension(
     State(state): State<AppState>,
     mut request: axum::http::Request<axum::body::Body>,
     next: axum::middleware::Next,
 ) -> Response {
     request.extensions_mut().insert(state.config.clone());
+    request
+        .extensions_mut()
+        .insert(state.authenticate_api_key_use_case.clone());
+    request
+        .extensions_mut()
+        .insert(state.check_jwt_revocation_use_case.clone());
     next.run(request).await
 }
@@ -2,16 +2,35 @@
 //!
 //! Contains HTTP handlers, DTOs, and middleware for the REST API.
 
+pub mod conditional;
 pub mod dto;
 pub mod handlers;
 pub mod middleware;
 
 use std::sync::Arc;
 
+use crate::application::use_cases::api_keys::{
+    AuthenticateApiKeyUseCase, CreateApiKeyUseCase, ListApiKeysUseCase, RevokeApiKeyUseCase,
+};
+use crate::application::use_cases::auth::{
+    CheckJwtRevocationUseCase, IssueServiceTokenUseCase, ListJwtRevocationsUseCase, RevokeJwtUseCase,
+    RevokeSubjectJwtsUseCase,
+};
 use crate::application::use_cases::networks::{
-    CreateNetworkUseCase, DeleteNetworkUseCase, GetActiveNetworksUseCase, GetNetworkByIdUseCase,
-    PartialUpdateNetworkUseCase, UpdateNetworkUseCase,
+    CancelLifecycleScheduleUseCase, CreateContractUseCase, CreateNetworkUseCase, CreateSignerUseCase,
+    CreateTokenUseCase, DeleteContractUseCase, DeleteNetworkUseCase, DeleteSignerUseCase, DeleteTokenUseCase,
+    ExportTokenListUseCase, FindContractsByNameUseCase, GetActiveNetworksUseCase, GetContractUseCase,
+    GetExplorerLinksUseCase, GetNetworkByIdUseCase, GetNetworkBySlugUseCase, ImportTokensUseCase, ListContractsUseCase,
+    ListLifecycleSchedulesUseCase, ListNetworkAncestorsUseCase, ListNetworkChildrenUseCase, ListNetworkTestnetsUseCase,
+    ListSignersUseCase, ListTokensUseCase, PartialUpdateNetworkUseCase, ResolveSignerUseCase,
+    ScheduleLifecycleChangeUseCase, SearchNetworksUseCase, SetNetworkParentUseCase, TransitionNetworkUseCase,
+    UpdateContractUseCase, UpdateNetworkUseCase, UpdateSignerUseCase, UpdateTokenUseCase,
+};
+use crate::application::use_cases::webhooks::{
+    CreateWebhookUseCase, DeleteWebhookUseCase, GetWebhookDeliveryUseCase, GetWebhookUseCase,
+    ListWebhookDeliveriesUseCase, ListWebhooksUseCase, RedeliverWebhookUseCase, UpdateWebhookUseCase,
 };
+use crate::domain::gateways::{NetworkEventStream, SecretResolver};
 use crate::infrastructure::driven_adapters::config::AppConfig;
 
 /// Application state shared across all handlers
@@ -20,8 +39,55 @@ pub struct AppState {
     pub config: Arc<AppConfig>,
     pub create_network_use_case: Arc<CreateNetworkUseCase>,
     pub get_network_by_id_use_case: Arc<GetNetworkByIdUseCase>,
+    pub get_network_by_slug_use_case: Arc<GetNetworkBySlugUseCase>,
     pub get_active_networks_use_case: Arc<GetActiveNetworksUseCase>,
     pub update_network_use_case: Arc<UpdateNetworkUseCase>,
     pub partial_update_network_use_case: Arc<PartialUpdateNetworkUseCase>,
     pub delete_network_use_case: Arc<DeleteNetworkUseCase>,
+    pub get_explorer_links_use_case: Arc<GetExplorerLinksUseCase>,
+    pub transition_network_use_case: Arc<TransitionNetworkUseCase>,
+    pub schedule_lifecycle_change_use_case: Arc<ScheduleLifecycleChangeUseCase>,
+    pub list_lifecycle_schedules_use_case: Arc<ListLifecycleSchedulesUseCase>,
+    pub cancel_lifecycle_schedule_use_case: Arc<CancelLifecycleScheduleUseCase>,
+    pub create_contract_use_case: Arc<CreateContractUseCase>,
+    pub list_contracts_use_case: Arc<ListContractsUseCase>,
+    pub get_contract_use_case: Arc<GetContractUseCase>,
+    pub update_contract_use_case: Arc<UpdateContractUseCase>,
+    pub delete_contract_use_case: Arc<DeleteContractUseCase>,
+    pub find_contracts_by_name_use_case: Arc<FindContractsByNameUseCase>,
+    pub create_token_use_case: Arc<CreateTokenUseCase>,
+    pub list_tokens_use_case: Arc<ListTokensUseCase>,
+    pub update_token_use_case: Arc<UpdateTokenUseCase>,
+    pub delete_token_use_case: Arc<DeleteTokenUseCase>,
+    pub import_tokens_use_case: Arc<ImportTokensUseCase>,
+    pub export_token_list_use_case: Arc<ExportTokenListUseCase>,
+    pub create_signer_use_case: Arc<CreateSignerUseCase>,
+    pub list_signers_use_case: Arc<ListSignersUseCase>,
+    pub update_signer_use_case: Arc<UpdateSignerUseCase>,
+    pub delete_signer_use_case: Arc<DeleteSignerUseCase>,
+    pub resolve_signer_use_case: Arc<ResolveSignerUseCase>,
+    pub set_network_parent_use_case: Arc<SetNetworkParentUseCase>,
+    pub list_network_children_use_case: Arc<ListNetworkChildrenUseCase>,
+    pub list_network_testnets_use_case: Arc<ListNetworkTestnetsUseCase>,
+    pub list_network_ancestors_use_case: Arc<ListNetworkAncestorsUseCase>,
+    pub search_networks_use_case: Arc<SearchNetworksUseCase>,
+    pub create_webhook_use_case: Arc<CreateWebhookUseCase>,
+    pub list_webhooks_use_case: Arc<ListWebhooksUseCase>,
+    pub get_webhook_use_case: Arc<GetWebhookUseCase>,
+    pub update_webhook_use_case: Arc<UpdateWebhookUseCase>,
+    pub delete_webhook_use_case: Arc<DeleteWebhookUseCase>,
+    pub list_webhook_deliveries_use_case: Arc<ListWebhookDeliveriesUseCase>,
+    pub get_webhook_delivery_use_case: Arc<GetWebhookDeliveryUseCase>,
+    pub redeliver_webhook_use_case: Arc<RedeliverWebhookUseCase>,
+    pub create_api_key_use_case: Arc<CreateApiKeyUseCase>,
+    pub list_api_keys_use_case: Arc<ListApiKeysUseCase>,
+    pub revoke_api_key_use_case: Arc<RevokeApiKeyUseCase>,
+    pub authenticate_api_key_use_case: Arc<AuthenticateApiKeyUseCase>,
+    pub revoke_jwt_use_case: Arc<RevokeJwtUseCase>,
+    pub revoke_subject_jwts_use_case: Arc<RevokeSubjectJwtsUseCase>,
+    pub list_jwt_revocations_use_case: Arc<ListJwtRevocationsUseCase>,
+    pub check_jwt_revocation_use_case: Arc<CheckJwtRevocationUseCase>,
+    pub issue_service_token_use_case: Arc<IssueServiceTokenUseCase>,
+    pub secret_resolver: Arc<dyn SecretResolver>,
+    pub network_events: Arc<dyn NetworkEventStream>,
 }
@@ -0,0 +1,152 @@
+//! Network Change Listener
+//!
+//! Background task receiving network changes made by other registry
+//! replicas and publishing them on the local event bus.
+//!
+//! A database trigger sends a `NOTIFY` on the `network_changes` channel for
+//! every change, tagged with the `application_name` of the writing session.
+//! Each replica connects with its own instance id as application name, so it
+//! can skip the notifications for changes it already published itself.
+//!
+//! When given a cache invalidator, the listener also drops the cached copies
+//! of networks changed by other replicas before publishing their changes.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use serde::Deserialize;
+use sqlx::postgres::PgListener;
+use sqlx::PgPool;
+use tokio::task::JoinHandle;
+use uuid::Uuid;
+
+use crate::application::use_cases::networks::PropagateNetworkChangeUseCase;
+use crate::domain::gateways::NetworkCacheInvalidator;
+use crate::domain::models::event::NetworkEventKind;
+use crate::domain::models::network::NetworkId;
+
+/// Postgres channel the network change trigger notifies
+pub const NETWORK_CHANGES_CHANNEL: &str = "network_changes";
+
+/// Delay before the first reconnection attempt
+const INITIAL_BACKOFF: Duration = Duration::from_secs(1);
+
+/// Upper bound for the delay between reconnection attempts
+const MAX_BACKOFF: Duration = Duration::from_secs(30);
+
+/// Payload of a `network_changes` notification
+#[derive(Debug, Deserialize)]
+struct ChangeNotification {
+    id: Uuid,
+    kind: String,
+    origin: Option<String>,
+}
+
+/// Publishes changes notified by other replicas on the local event bus
+pub struct NetworkChangeListener {
+    propagate_network_change_use_case: Arc<PropagateNetworkChangeUseCase>,
+    pool: PgPool,
+    instance_id: String,
+    cache_invalidator: Option<Arc<dyn NetworkCacheInvalidator>>,
+}
+
+impl NetworkChangeListener {
+    /// Create a new NetworkChangeListener
+    ///
+    /// `instance_id` must be the `application_name` this replica's pool
+    /// connects with.
+    #[must_use]
+    pub fn new(
+        propagate_network_change_use_case: Arc<PropagateNetworkChangeUseCase>,
+        pool: PgPool,
+        instance_id: impl Into<String>,
+    ) -> Self {
+        Self {
+            propagate_network_change_use_case,
+            pool,
+            instance_id: instance_id.into(),
+            cache_invalidator: None,
+        }
+    }
+
+    /// Drop cached copies of the networks other replicas change
+    #[must_use]
+    pub fn with_cache_invalidator(mut self, invalidator: Arc<dyn NetworkCacheInvalidator>) -> Self {
+        self.cache_invalidator = Some(invalidator);
+        self
+    }
+
+    /// Spawn the listener loop on the Tokio runtime
+    ///
+    /// Connection failures are retried with exponential backoff. Changes made
+    /// while disconnected are not replayed.
+    #[must_use]
+    pub fn spawn(self) -> JoinHandle<()> {
+        tokio::spawn(async move {
+            let mut backoff = INITIAL_BACKOFF;
+
+            loop {
+                match self.listen().await {
+                    Ok(()) => backoff = INITIAL_BACKOFF,
+                    Err(e) => {
+                        tracing::error!(error = %e, retry_in = ?backoff, "Network change listener failed");
+                        tokio::time::sleep(backoff).await;
+                        backoff = (backoff * 2).min(MAX_BACKOFF);
+                    }
+                }
+            }
+        })
+    }
+
+    /// Listen until the connection is lost
+    ///
+    /// Returns `Ok` when a working connection drops, so the caller reconnects
+    /// straight away.
+    async fn listen(&self) -> Result<(), sqlx::Error> {
+        let mut listener = PgListener::connect_with(&self.pool).await?;
+        listener.listen(NETWORK_CHANGES_CHANNEL).await?;
+        tracing::info!(
+            channel = NETWORK_CHANGES_CHANNEL,
+            "Listening for network changes from other replicas"
+        );
+
+        while let Some(notification) = listener.try_recv().await? {
+            self.handle(notification.payload()).await;
+        }
+
+        tracing::warn!("Lost network change listener connection, changes made meanwhile are not propagated");
+        Ok(())
+    }
+
+    /// Publish a notified change unless this replica made it
+    async fn handle(&self, payload: &str) {
+        let change = match serde_json::from_str::<ChangeNotification>(payload) {
+            Ok(change) => change,
+            Err(e) => {
+                tracing::warn!(error = %e, payload, "Ignoring malformed network change");
+                return;
+            }
+        };
+
+        if change.origin.as_deref() == Some(self.instance_id.as_str()) {
+            return;
+        }
+
+        let id = NetworkId::from_uuid(change.id);
+        if let Some(invalidator) = &self.cache_invalidator {
+            invalidator.invalidate(&id);
+        }
+
+        let kind = match change.kind.parse::<NetworkEventKind>() {
+            Ok(kind) => kind,
+            Err(e) => {
+                tracing::warn!(error = %e, network_id = %change.id, "Ignoring network change");
+                return;
+            }
+        };
+
+        if let Err(e) = self.propagate_network_change_use_case.execute(&id, kind).await {
+            tracing::error!(error = %e, network_id = %id, "Failed to propagate network change");
+        }
+    }
+}
@@ -0,0 +1,133 @@
+//! Command Line
+//!
+//! Subcommands of the binary. Without one, the server is started.
+//!
+//! `issue-token` mints a JWT signed with the configured secret and prints
+//! it, for developers and operators who would otherwise hand-craft tokens.
+
+/// Usage of the binary
+pub const USAGE: &str = "\
+Usage:
+  blockchain-network-registry [serve]
+  blockchain-network-registry issue-token --subject <SUB> --role <ROLE> [--email <EMAIL>] [--expires-in <SECS>]
+
+Commands:
+  serve        Start the HTTP server (default)
+  issue-token  Print a JWT signed with the configured secret
+               --email defaults to the subject, --expires-in to jwt.expires_in_secs";
+
+/// A command given on the command line
+#[derive(Debug, PartialEq, Eq)]
+pub enum Command {
+    Serve,
+    IssueToken(IssueTokenArgs),
+    Help,
+}
+
+/// Arguments of the `issue-token` command
+#[derive(Debug, PartialEq, Eq)]
+pub struct IssueTokenArgs {
+    pub subject: String,
+    pub role: String,
+    pub email: Option<String>,
+    pub expires_in_secs: Option<i64>,
+}
+
+/// Parse the command line arguments, program name excluded
+///
+/// # Errors
+///
+/// Returns a message if the command or one of its options is unknown, missing or invalid
+pub fn parse<I>(args: I) -> Result<Command, String>
+where
+    I: IntoIterator<Item = String>,
+{
+    let mut args = args.into_iter();
+    match args.next().as_deref() {
+        None | Some("serve") => match args.next() {
+            None => Ok(Command::Serve),
+            Some(arg) => Err(format!("Unexpected argument '{arg}'")),
+        },
+        Some("issue-token") => parse_issue_token(args).map(Command::IssueToken),
+        Some("help" | "-h" | "--help") => Ok(Command::Help),
+        Some(command) => Err(format!("Unknown command '{command}'")),
+    }
+}
+
+fn parse_issue_token(mut args: impl Iterator<Item = String>) -> Result<IssueTokenArgs, String> {
+    let mut subject = None;
+    let mut role = None;
+    let mut email = None;
+    let mut expires_in_secs = None;
+
+    while let Some(option) = args.next() {
+        let value = args.next().ok_or_else(|| format!("Missing value for '{option}'"))?;
+        match option.as_str() {
+            "--subject" => subject = Some(value),
+            "--role" => role = Some(value),
+            "--email" => email = Some(value),
+            "--expires-in" => {
+                let secs = value
+                    .parse::<i64>()
+                    .ok()
+                    .filter(|secs| *secs > 0)
+                    .ok_or_else(|| format!("--expires-in must be a positive number of seconds, got '{value}'"))?;
+                expires_in_secs = Some(secs);
+            }
+            _ => return Err(format!("Unknown option '{option}'")),
+        }
+    }
+
+    let subject = subject
+        .filter(|s| !s.trim().is_empty())
+        .ok_or("--subject is required")?;
+    let role = role.filter(|r| !r.trim().is_empty()).ok_or("--role is required")?;
+    Ok(IssueTokenArgs {
+        subject,
+        role,
+        email,
+        expires_in_secs,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(line: &str) -> Vec<String> {
+        line.split_whitespace().map(str::to_string).collect()
+    }
+
+    #[test]
+    fn test_parse_serve() {
+        assert_eq!(parse(args("")), Ok(Command::Serve));
+        assert_eq!(parse(args("serve")), Ok(Command::Serve));
+        assert_eq!(parse(args("--help")), Ok(Command::Help));
+        assert!(parse(args("serve now")).is_err());
+        assert!(parse(args("migrate")).is_err());
+    }
+
+    #[test]
+    fn test_parse_issue_token() {
+        assert_eq!(
+            parse(args("issue-token --role admin --subject alice --expires-in 600")),
+            Ok(Command::IssueToken(IssueTokenArgs {
+                subject: "alice".to_string(),
+                role: "admin".to_string(),
+                email: None,
+                expires_in_secs: Some(600),
+            }))
+        );
+
+        for invalid in [
+            "issue-token --subject alice",
+            "issue-token --role admin",
+            "issue-token --subject alice --role",
+            "issue-token --subject alice --role admin --expires-in 0",
+            "issue-token --subject alice --role admin --expires-in soon",
+            "issue-token --subject alice --role admin --scope all",
+        ] {
+            assert!(parse(args(invalid)).is_err(), "{invalid}");
+        }
+    }
+}
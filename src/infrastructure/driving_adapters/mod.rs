@@ -1,7 +1,18 @@
 //! Driving Adapters
 //!
 //! Entry points that drive the application:
+//! - Command line subcommands
 //! - HTTP REST API handlers
 //! - DTOs for request/response
+//! - Background scheduler for lifecycle changes
+//! - Listener for network changes made by other replicas
+//! - Outbox relay to the configured sinks
+//! - Webhook delivery queueing and dispatch
 
 pub mod api_rest;
+pub mod change_listener;
+pub mod cli;
+pub mod outbox_relay;
+pub mod scheduler;
+pub mod webhook_dispatcher;
+pub mod webhook_publisher;
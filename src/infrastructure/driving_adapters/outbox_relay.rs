@@ -0,0 +1,93 @@
+//! Outbox Relay
+//!
+//! Background task relaying outbox messages to the configured sinks, and
+//! purging published messages past their retention.
+//!
+//! Messages live in the database, so nothing is lost on restart: the first
+//! check runs immediately at startup and relays anything recorded while the
+//! service was down, or left unpublished by a crash.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio::task::JoinHandle;
+use tokio::time::{Instant, MissedTickBehavior};
+
+use crate::application::use_cases::outbox::{PurgeOutboxUseCase, RelayOutboxUseCase};
+use crate::infrastructure::driven_adapters::config::OutboxConfig;
+
+/// Time between purges of published messages
+const PURGE_INTERVAL: Duration = Duration::from_secs(3600);
+
+/// Periodically relays due outbox messages
+pub struct OutboxRelay {
+    relay_outbox_use_case: Arc<RelayOutboxUseCase>,
+    purge_outbox_use_case: Arc<PurgeOutboxUseCase>,
+    poll_interval: Duration,
+    batch_size: u32,
+}
+
+impl OutboxRelay {
+    /// Create a new OutboxRelay
+    #[must_use]
+    pub fn new(
+        relay_outbox_use_case: Arc<RelayOutboxUseCase>,
+        purge_outbox_use_case: Arc<PurgeOutboxUseCase>,
+        config: &OutboxConfig,
+    ) -> Self {
+        Self {
+            relay_outbox_use_case,
+            purge_outbox_use_case,
+            poll_interval: Duration::from_secs(config.poll_interval_secs),
+            batch_size: config.batch_size,
+        }
+    }
+
+    /// Spawn the relay loop on the Tokio runtime
+    #[must_use]
+    pub fn spawn(self) -> JoinHandle<()> {
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(self.poll_interval);
+            interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+            let mut next_purge = Instant::now();
+
+            loop {
+                interval.tick().await;
+                self.run_once().await;
+
+                if Instant::now() >= next_purge {
+                    if let Err(e) = self.purge_outbox_use_case.execute().await {
+                        tracing::error!(error = %e, "Failed to purge published outbox messages");
+                    }
+                    next_purge = Instant::now() + PURGE_INTERVAL;
+                }
+            }
+        })
+    }
+
+    /// Relay all currently due messages, batch by batch
+    ///
+    /// Returns the number of messages handled. Errors are logged and the
+    /// remaining messages are left for the next check.
+    pub async fn run_once(&self) -> usize {
+        let mut total = 0;
+
+        loop {
+            match self.relay_outbox_use_case.execute(self.batch_size).await {
+                Ok(relayed) => {
+                    total += relayed.len();
+                    // A partial batch means nothing else is due right now
+                    if relayed.len() < self.batch_size as usize {
+                        break;
+                    }
+                }
+                Err(e) => {
+                    tracing::error!(error = %e, "Failed to relay due outbox messages");
+                    break;
+                }
+            }
+        }
+
+        total
+    }
+}
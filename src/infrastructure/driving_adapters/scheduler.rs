@@ -0,0 +1,75 @@
+//! Lifecycle Scheduler
+//!
+//! Background task applying scheduled network lifecycle changes.
+//!
+//! Pending schedules live in the database, so nothing is lost on restart: the
+//! first check runs immediately at startup and applies anything that fell due
+//! while the service was down.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio::task::JoinHandle;
+use tokio::time::MissedTickBehavior;
+
+use crate::application::use_cases::networks::ApplyDueSchedulesUseCase;
+use crate::infrastructure::driven_adapters::config::SchedulerConfig;
+
+/// Periodically applies due lifecycle schedules
+pub struct LifecycleScheduler {
+    apply_due_schedules_use_case: Arc<ApplyDueSchedulesUseCase>,
+    poll_interval: Duration,
+    batch_size: u32,
+}
+
+impl LifecycleScheduler {
+    /// Create a new LifecycleScheduler
+    #[must_use]
+    pub fn new(apply_due_schedules_use_case: Arc<ApplyDueSchedulesUseCase>, config: &SchedulerConfig) -> Self {
+        Self {
+            apply_due_schedules_use_case,
+            poll_interval: Duration::from_secs(config.poll_interval_secs),
+            batch_size: config.batch_size,
+        }
+    }
+
+    /// Spawn the scheduler loop on the Tokio runtime
+    #[must_use]
+    pub fn spawn(self) -> JoinHandle<()> {
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(self.poll_interval);
+            interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+            loop {
+                interval.tick().await;
+                self.run_once().await;
+            }
+        })
+    }
+
+    /// Apply all currently due schedules, batch by batch
+    ///
+    /// Returns the number of schedules completed. Errors are logged and the
+    /// remaining schedules are left for the next check.
+    pub async fn run_once(&self) -> usize {
+        let mut total = 0;
+
+        loop {
+            match self.apply_due_schedules_use_case.execute(self.batch_size).await {
+                Ok(completed) => {
+                    total += completed.len();
+                    // A partial batch means nothing else is due right now
+                    if completed.len() < self.batch_size as usize {
+                        break;
+                    }
+                }
+                Err(e) => {
+                    tracing::error!(error = %e, "Failed to apply due lifecycle schedules");
+                    break;
+                }
+            }
+        }
+
+        total
+    }
+}
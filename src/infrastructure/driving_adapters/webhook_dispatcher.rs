@@ -0,0 +1,75 @@
+//! Webhook Dispatcher
+//!
+//! Background task sending queued webhook deliveries and their retries.
+//!
+//! Pending deliveries live in the database, so nothing is lost on restart:
+//! the first check runs immediately at startup and sends anything queued or
+//! due while the service was down.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio::task::JoinHandle;
+use tokio::time::MissedTickBehavior;
+
+use crate::application::use_cases::webhooks::DeliverDueWebhooksUseCase;
+use crate::infrastructure::driven_adapters::config::WebhooksConfig;
+
+/// Periodically sends due webhook deliveries
+pub struct WebhookDispatcher {
+    deliver_due_webhooks_use_case: Arc<DeliverDueWebhooksUseCase>,
+    poll_interval: Duration,
+    batch_size: u32,
+}
+
+impl WebhookDispatcher {
+    /// Create a new WebhookDispatcher
+    #[must_use]
+    pub fn new(deliver_due_webhooks_use_case: Arc<DeliverDueWebhooksUseCase>, config: &WebhooksConfig) -> Self {
+        Self {
+            deliver_due_webhooks_use_case,
+            poll_interval: Duration::from_secs(config.poll_interval_secs),
+            batch_size: config.batch_size,
+        }
+    }
+
+    /// Spawn the dispatcher loop on the Tokio runtime
+    #[must_use]
+    pub fn spawn(self) -> JoinHandle<()> {
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(self.poll_interval);
+            interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+            loop {
+                interval.tick().await;
+                self.run_once().await;
+            }
+        })
+    }
+
+    /// Send all currently due deliveries, batch by batch
+    ///
+    /// Returns the number of attempts made. Errors are logged and the
+    /// remaining deliveries are left for the next check.
+    pub async fn run_once(&self) -> usize {
+        let mut total = 0;
+
+        loop {
+            match self.deliver_due_webhooks_use_case.execute(self.batch_size).await {
+                Ok(attempted) => {
+                    total += attempted.len();
+                    // A partial batch means nothing else is due right now
+                    if attempted.len() < self.batch_size as usize {
+                        break;
+                    }
+                }
+                Err(e) => {
+                    tracing::error!(error = %e, "Failed to send due webhook deliveries");
+                    break;
+                }
+            }
+        }
+
+        total
+    }
+}
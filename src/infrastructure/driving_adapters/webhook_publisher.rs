@@ -0,0 +1,53 @@
+//! Webhook Outbox Sink
+//!
+//! Queues every event relayed from the outbox for delivery to the subscribed
+//! webhooks. Delivery IDs derive from the event ID, so an event relayed more
+//! than once is only queued once per webhook.
+//!
+//! Payloads use the event stream format, with RPC URL secrets redacted.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::application::use_cases::webhooks::EnqueueWebhookDeliveriesUseCase;
+use crate::domain::gateways::OutboxSink;
+use crate::domain::models::outbox::OutboxEvent;
+use crate::infrastructure::driving_adapters::api_rest::dto::event::NetworkEventDto;
+use crate::infrastructure::driving_adapters::api_rest::dto::network::NetworkResponseDto;
+
+/// Sink queueing webhook deliveries for relayed events
+pub struct WebhookOutboxSink {
+    enqueue_webhook_deliveries_use_case: Arc<EnqueueWebhookDeliveriesUseCase>,
+}
+
+impl WebhookOutboxSink {
+    /// Create a new WebhookOutboxSink
+    #[must_use]
+    pub fn new(enqueue_webhook_deliveries_use_case: Arc<EnqueueWebhookDeliveriesUseCase>) -> Self {
+        Self {
+            enqueue_webhook_deliveries_use_case,
+        }
+    }
+}
+
+#[async_trait]
+impl OutboxSink for WebhookOutboxSink {
+    fn name(&self) -> &'static str {
+        "webhook"
+    }
+
+    async fn deliver(&self, event: &OutboxEvent) -> Result<(), String> {
+        let kind = event.event.kind;
+        let network_id = event.event.network.id().clone();
+        let network = NetworkResponseDto::from(event.event.network.clone()).redact_secrets();
+        let payload = serde_json::to_string(&NetworkEventDto::new(kind, event.event.occurred_at, network))
+            .map_err(|e| format!("failed to serialize payload: {e}"))?;
+
+        self.enqueue_webhook_deliveries_use_case
+            .execute(&event.id, kind, &network_id, &payload)
+            .await
+            .map(|_| ())
+            .map_err(|e| e.to_string())
+    }
+}
@@ -1,32 +1,92 @@
 //! Blockchain Network Registry API - Main Entry Point
 
-use std::{net::SocketAddr, sync::Arc};
+use std::{net::SocketAddr, str::FromStr, sync::Arc, time::Duration};
 
 use anyhow::Context;
 use axum::{middleware, Router};
-use sqlx::postgres::PgPoolOptions;
+use sqlx::postgres::{PgConnectOptions, PgPoolOptions};
 use tokio::net::TcpListener;
 use tower_governor::{governor::GovernorConfigBuilder, GovernorLayer};
-use tower_http::cors::CorsLayer;
+use tower_http::cors::{AllowOrigin, Any, CorsLayer};
 use tower_http::trace::TraceLayer;
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
+use uuid::Uuid;
 
+use blockchain_network_registry::application::use_cases::api_keys::{
+    AuthenticateApiKeyUseCase, CreateApiKeyUseCase, ListApiKeysUseCase, RevokeApiKeyUseCase,
+};
+use blockchain_network_registry::application::use_cases::auth::{
+    CheckJwtRevocationUseCase, IssueServiceTokenUseCase, ListJwtRevocationsUseCase, RevokeJwtUseCase,
+    RevokeSubjectJwtsUseCase,
+};
 use blockchain_network_registry::application::use_cases::networks::{
-    CreateNetworkUseCase, DeleteNetworkUseCase, GetActiveNetworksUseCase, GetNetworkByIdUseCase,
-    PartialUpdateNetworkUseCase, UpdateNetworkUseCase,
+    ApplyDueSchedulesUseCase, CancelLifecycleScheduleUseCase, CreateContractUseCase, CreateNetworkUseCase,
+    CreateSignerUseCase, CreateTokenUseCase, DeleteContractUseCase, DeleteNetworkUseCase, DeleteSignerUseCase,
+    DeleteTokenUseCase, ExportTokenListUseCase, FindContractsByNameUseCase, GetActiveNetworksUseCase,
+    GetContractUseCase, GetExplorerLinksUseCase, GetNetworkByIdUseCase, GetNetworkBySlugUseCase, ImportTokensUseCase,
+    ListContractsUseCase, ListLifecycleSchedulesUseCase, ListNetworkAncestorsUseCase, ListNetworkChildrenUseCase,
+    ListNetworkTestnetsUseCase, ListSignersUseCase, ListTokensUseCase, PartialUpdateNetworkUseCase,
+    PropagateNetworkChangeUseCase, ResolveSignerUseCase, ScheduleLifecycleChangeUseCase, SearchNetworksUseCase,
+    SetNetworkParentUseCase, TransitionNetworkUseCase, UpdateContractUseCase, UpdateNetworkUseCase,
+    UpdateSignerUseCase, UpdateTokenUseCase,
+};
+use blockchain_network_registry::application::use_cases::outbox::{PurgeOutboxUseCase, RelayOutboxUseCase};
+use blockchain_network_registry::application::use_cases::webhooks::{
+    CreateWebhookUseCase, DeleteWebhookUseCase, DeliverDueWebhooksUseCase, EnqueueWebhookDeliveriesUseCase,
+    GetWebhookDeliveryUseCase, GetWebhookUseCase, ListWebhookDeliveriesUseCase, ListWebhooksUseCase,
+    RedeliverWebhookUseCase, UpdateWebhookUseCase,
+};
+use blockchain_network_registry::domain::gateways::{NetworkRepository, OutboxSink, TokenIssuer};
+use blockchain_network_registry::infrastructure::driven_adapters::api_key_repository::PostgresApiKeyRepository;
+use blockchain_network_registry::infrastructure::driven_adapters::config::{AppConfig, OutboxSinkKind};
+use blockchain_network_registry::infrastructure::driven_adapters::contract_repository::PostgresContractRepository;
+use blockchain_network_registry::infrastructure::driven_adapters::event_bus::InMemoryEventBus;
+use blockchain_network_registry::infrastructure::driven_adapters::jwt_revocation_repository::{
+    CachingJwtRevocationRepository, PostgresJwtRevocationRepository,
+};
+use blockchain_network_registry::infrastructure::driven_adapters::network_repository::{
+    CachingNetworkRepository, PostgresNetworkRepository,
+};
+use blockchain_network_registry::infrastructure::driven_adapters::outbox_repository::PostgresOutboxRepository;
+use blockchain_network_registry::infrastructure::driven_adapters::outbox_sink::LogOutboxSink;
+use blockchain_network_registry::infrastructure::driven_adapters::schedule_repository::PostgresScheduleRepository;
+use blockchain_network_registry::infrastructure::driven_adapters::secrets::{ConfigSecretResolver, EnvelopeCipher};
+use blockchain_network_registry::infrastructure::driven_adapters::signer_repository::PostgresSignerRepository;
+use blockchain_network_registry::infrastructure::driven_adapters::token_issuer::JwtTokenIssuer;
+use blockchain_network_registry::infrastructure::driven_adapters::token_repository::PostgresTokenRepository;
+use blockchain_network_registry::infrastructure::driven_adapters::webhook_delivery_repository::PostgresWebhookDeliveryRepository;
+use blockchain_network_registry::infrastructure::driven_adapters::webhook_repository::PostgresWebhookRepository;
+use blockchain_network_registry::infrastructure::driven_adapters::webhook_sender::HttpWebhookSender;
+use blockchain_network_registry::infrastructure::driving_adapters::api_rest::handlers::{
+    api_keys, auth, jwt_revocations, networks, public, webhooks,
+};
+use blockchain_network_registry::infrastructure::driving_adapters::api_rest::middleware::auth::{
+    add_config_extension, API_KEY_HEADER, JWT_LEEWAY_SECS,
 };
-use blockchain_network_registry::infrastructure::driven_adapters::config::AppConfig;
-use blockchain_network_registry::infrastructure::driven_adapters::network_repository::PostgresNetworkRepository;
-use blockchain_network_registry::infrastructure::driving_adapters::api_rest::handlers::networks;
-use blockchain_network_registry::infrastructure::driving_adapters::api_rest::middleware::auth::add_config_extension;
 use blockchain_network_registry::infrastructure::driving_adapters::api_rest::middleware::request_id::request_id_middleware;
 use blockchain_network_registry::infrastructure::driving_adapters::api_rest::AppState;
+use blockchain_network_registry::infrastructure::driving_adapters::change_listener::NetworkChangeListener;
+use blockchain_network_registry::infrastructure::driving_adapters::cli::{self, Command, IssueTokenArgs};
+use blockchain_network_registry::infrastructure::driving_adapters::outbox_relay::OutboxRelay;
+use blockchain_network_registry::infrastructure::driving_adapters::scheduler::LifecycleScheduler;
+use blockchain_network_registry::infrastructure::driving_adapters::webhook_dispatcher::WebhookDispatcher;
+use blockchain_network_registry::infrastructure::driving_adapters::webhook_publisher::WebhookOutboxSink;
 
 /// Default CORS origin for development (when no origins configured)
 const DEFAULT_CORS_ORIGIN: &str = "http://localhost:3000";
 
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
+    // Run subcommands before logging starts, so that their output is not mixed with logs
+    match cli::parse(std::env::args().skip(1)).map_err(|e| anyhow::anyhow!("{e}\n\n{}", cli::USAGE))? {
+        Command::Serve => {}
+        Command::Help => {
+            println!("{}", cli::USAGE);
+            return Ok(());
+        }
+        Command::IssueToken(args) => return issue_token(&args),
+    }
+
     // Initialize tracing
     tracing_subscriber::registry()
         .with(
@@ -40,11 +100,17 @@ async fn main() -> anyhow::Result<()> {
     let config = Arc::new(AppConfig::load()?);
     tracing::info!("Configuration loaded successfully");
 
-    // Create database connection pool
+    // SSRF-safe URL policy, checked when networks and webhooks are written and when deliveries are sent
+    let url_policy = Arc::new(config.url_policy.to_policy()?);
+
+    // Create database connection pool, tagging this replica's sessions so it
+    // can tell its own change notifications from those of other replicas
+    let instance_id = format!("network-registry-{}", Uuid::new_v4());
+    let connect_options = PgConnectOptions::from_str(&config.database.url)?.application_name(&instance_id);
     let pool = PgPoolOptions::new()
         .max_connections(config.database.max_connections)
         .min_connections(config.database.min_connections)
-        .connect(&config.database.url)
+        .connect_with(connect_options)
         .await?;
     tracing::info!("Database connection pool created");
 
@@ -52,28 +118,362 @@ async fn main() -> anyhow::Result<()> {
     sqlx::migrate!("./migrations").run(&pool).await?;
     tracing::info!("Database migrations completed");
 
-    // Create repository
-    let network_repository = Arc::new(PostgresNetworkRepository::new(pool));
+    // Create repositories, encrypting RPC URL and webhook secrets at rest when a key is configured
+    let mut postgres_network_repository = PostgresNetworkRepository::new(pool.clone());
+    let mut postgres_webhook_repository = PostgresWebhookRepository::new(pool.clone());
+    let mut postgres_outbox_repository = PostgresOutboxRepository::new(pool.clone());
+    match config.secrets.encryption_key.as_ref().filter(|key| !key.is_empty()) {
+        Some(key) => {
+            let cipher =
+                Arc::new(EnvelopeCipher::from_base64_key(key.expose()).context("Invalid secrets encryption key")?);
+            postgres_network_repository = postgres_network_repository.with_secret_cipher(cipher.clone());
+            postgres_webhook_repository = postgres_webhook_repository.with_secret_cipher(cipher.clone());
+            postgres_outbox_repository = postgres_outbox_repository.with_secret_cipher(cipher);
+            tracing::info!("RPC URL and webhook secret encryption enabled");
+        }
+        None => {
+            tracing::warn!("No secrets encryption key configured, RPC URL and webhook secrets are stored in plaintext")
+        }
+    }
+    let postgres_network_repository = Arc::new(postgres_network_repository);
+    let webhook_repository = Arc::new(postgres_webhook_repository);
+    let webhook_delivery_repository = Arc::new(PostgresWebhookDeliveryRepository::new(pool.clone()));
+    let api_key_repository = Arc::new(PostgresApiKeyRepository::new(pool.clone()));
+    let jwt_revocation_repository = Arc::new(CachingJwtRevocationRepository::new(
+        Arc::new(PostgresJwtRevocationRepository::new(pool.clone())),
+        Duration::from_secs(config.jwt_revocation.cache_ttl_secs),
+        config.jwt_revocation.cache_max_entries,
+    ));
+    let outbox_repository = Arc::new(postgres_outbox_repository);
+    let schedule_repository = Arc::new(PostgresScheduleRepository::new(pool.clone()));
+    let contract_repository = Arc::new(PostgresContractRepository::new(pool.clone()));
+    let token_repository = Arc::new(PostgresTokenRepository::new(pool.clone()));
+    let signer_repository = Arc::new(PostgresSignerRepository::new(pool.clone()));
+    let secret_resolver = Arc::new(ConfigSecretResolver::new(&config.secrets));
+    let event_bus = Arc::new(InMemoryEventBus::new(config.events.replay_buffer_size));
+
+    // Serve network lookups from memory, logging how well the cache performs
+    let network_cache = config.cache.enabled.then(|| {
+        Arc::new(CachingNetworkRepository::new(
+            postgres_network_repository.clone(),
+            Duration::from_secs(config.cache.ttl_secs),
+            config.cache.max_entries,
+        ))
+    });
+    let network_repository: Arc<dyn NetworkRepository> = match &network_cache {
+        Some(cache) => {
+            let stats_cache = cache.clone();
+            let mut interval = tokio::time::interval(Duration::from_secs(config.cache.stats_log_interval_secs));
+            interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+            tokio::spawn(async move {
+                loop {
+                    interval.tick().await;
+                    let stats = stats_cache.stats();
+                    tracing::info!(
+                        hits = stats.hits,
+                        misses = stats.misses,
+                        hit_ratio = stats.hit_ratio(),
+                        evictions = stats.evictions,
+                        invalidations = stats.invalidations,
+                        entries = stats.entries,
+                        "Network cache statistics"
+                    );
+                }
+            });
+            tracing::info!(
+                "Network cache enabled: {}s TTL, up to {} entries",
+                config.cache.ttl_secs,
+                config.cache.max_entries
+            );
+            cache.clone()
+        }
+        None => {
+            tracing::warn!("Network cache disabled, every network lookup reads the database");
+            postgres_network_repository.clone()
+        }
+    };
 
     // Create use cases
-    let create_network_use_case = Arc::new(CreateNetworkUseCase::new(network_repository.clone()));
+    let create_network_use_case = Arc::new(CreateNetworkUseCase::new(
+        network_repository.clone(),
+        event_bus.clone(),
+        url_policy.clone(),
+    ));
     let get_network_by_id_use_case = Arc::new(GetNetworkByIdUseCase::new(network_repository.clone()));
+    let get_network_by_slug_use_case = Arc::new(GetNetworkBySlugUseCase::new(network_repository.clone()));
     let get_active_networks_use_case = Arc::new(GetActiveNetworksUseCase::new(network_repository.clone()));
-    let update_network_use_case = Arc::new(UpdateNetworkUseCase::new(network_repository.clone()));
-    let partial_update_network_use_case = Arc::new(PartialUpdateNetworkUseCase::new(network_repository.clone()));
-    let delete_network_use_case = Arc::new(DeleteNetworkUseCase::new(network_repository.clone()));
+    let update_network_use_case = Arc::new(UpdateNetworkUseCase::new(
+        network_repository.clone(),
+        event_bus.clone(),
+        url_policy.clone(),
+    ));
+    let partial_update_network_use_case = Arc::new(PartialUpdateNetworkUseCase::new(
+        network_repository.clone(),
+        event_bus.clone(),
+        url_policy.clone(),
+    ));
+    let delete_network_use_case = Arc::new(DeleteNetworkUseCase::new(network_repository.clone(), event_bus.clone()));
+    let get_explorer_links_use_case = Arc::new(GetExplorerLinksUseCase::new(network_repository.clone()));
+    let transition_network_use_case = Arc::new(TransitionNetworkUseCase::new(
+        network_repository.clone(),
+        event_bus.clone(),
+    ));
+    let schedule_lifecycle_change_use_case = Arc::new(ScheduleLifecycleChangeUseCase::new(
+        network_repository.clone(),
+        schedule_repository.clone(),
+    ));
+    let list_lifecycle_schedules_use_case = Arc::new(ListLifecycleSchedulesUseCase::new(
+        network_repository.clone(),
+        schedule_repository.clone(),
+    ));
+    let cancel_lifecycle_schedule_use_case = Arc::new(CancelLifecycleScheduleUseCase::new(schedule_repository.clone()));
+    let create_contract_use_case = Arc::new(CreateContractUseCase::new(
+        network_repository.clone(),
+        contract_repository.clone(),
+    ));
+    let list_contracts_use_case = Arc::new(ListContractsUseCase::new(
+        network_repository.clone(),
+        contract_repository.clone(),
+    ));
+    let get_contract_use_case = Arc::new(GetContractUseCase::new(contract_repository.clone()));
+    let update_contract_use_case = Arc::new(UpdateContractUseCase::new(contract_repository.clone()));
+    let delete_contract_use_case = Arc::new(DeleteContractUseCase::new(contract_repository.clone()));
+    let create_token_use_case = Arc::new(CreateTokenUseCase::new(
+        network_repository.clone(),
+        token_repository.clone(),
+    ));
+    let list_tokens_use_case = Arc::new(ListTokensUseCase::new(
+        network_repository.clone(),
+        token_repository.clone(),
+    ));
+    let update_token_use_case = Arc::new(UpdateTokenUseCase::new(token_repository.clone()));
+    let delete_token_use_case = Arc::new(DeleteTokenUseCase::new(token_repository.clone()));
+    let create_signer_use_case = Arc::new(CreateSignerUseCase::new(
+        network_repository.clone(),
+        signer_repository.clone(),
+    ));
+    let list_signers_use_case = Arc::new(ListSignersUseCase::new(
+        network_repository.clone(),
+        signer_repository.clone(),
+    ));
+    let update_signer_use_case = Arc::new(UpdateSignerUseCase::new(signer_repository.clone()));
+    let delete_signer_use_case = Arc::new(DeleteSignerUseCase::new(signer_repository.clone()));
+    let set_network_parent_use_case = Arc::new(SetNetworkParentUseCase::new(
+        network_repository.clone(),
+        event_bus.clone(),
+    ));
+    let list_network_children_use_case = Arc::new(ListNetworkChildrenUseCase::new(network_repository.clone()));
+    let list_network_testnets_use_case = Arc::new(ListNetworkTestnetsUseCase::new(network_repository.clone()));
+    let list_network_ancestors_use_case = Arc::new(ListNetworkAncestorsUseCase::new(network_repository.clone()));
+    let search_networks_use_case = Arc::new(SearchNetworksUseCase::new(network_repository.clone()));
+    let resolve_signer_use_case = Arc::new(ResolveSignerUseCase::new(
+        network_repository.clone(),
+        signer_repository.clone(),
+    ));
+    let import_tokens_use_case = Arc::new(ImportTokensUseCase::new(
+        network_repository.clone(),
+        token_repository.clone(),
+    ));
+    let export_token_list_use_case = Arc::new(ExportTokenListUseCase::new(
+        network_repository.clone(),
+        token_repository.clone(),
+    ));
+    let find_contracts_by_name_use_case = Arc::new(FindContractsByNameUseCase::new(
+        network_repository.clone(),
+        contract_repository.clone(),
+    ));
+    let create_webhook_use_case = Arc::new(CreateWebhookUseCase::new(
+        webhook_repository.clone(),
+        url_policy.clone(),
+    ));
+    let list_webhooks_use_case = Arc::new(ListWebhooksUseCase::new(webhook_repository.clone()));
+    let get_webhook_use_case = Arc::new(GetWebhookUseCase::new(webhook_repository.clone()));
+    let update_webhook_use_case = Arc::new(UpdateWebhookUseCase::new(
+        webhook_repository.clone(),
+        url_policy.clone(),
+    ));
+    let delete_webhook_use_case = Arc::new(DeleteWebhookUseCase::new(webhook_repository.clone()));
+    let list_webhook_deliveries_use_case = Arc::new(ListWebhookDeliveriesUseCase::new(
+        webhook_repository.clone(),
+        webhook_delivery_repository.clone(),
+    ));
+    let get_webhook_delivery_use_case = Arc::new(GetWebhookDeliveryUseCase::new(webhook_delivery_repository.clone()));
+    let redeliver_webhook_use_case = Arc::new(RedeliverWebhookUseCase::new(webhook_delivery_repository.clone()));
+    let create_api_key_use_case = Arc::new(CreateApiKeyUseCase::new(api_key_repository.clone()));
+    let list_api_keys_use_case = Arc::new(ListApiKeysUseCase::new(api_key_repository.clone()));
+    let revoke_api_key_use_case = Arc::new(RevokeApiKeyUseCase::new(api_key_repository.clone()));
+    let authenticate_api_key_use_case = Arc::new(AuthenticateApiKeyUseCase::new(api_key_repository));
+    // Revoked JWTs with an unknown expiry are kept for as long as a token can be accepted
+    let max_token_lifetime = chrono::Duration::seconds(config.jwt.expires_in_secs)
+        + chrono::Duration::from_std(Duration::from_secs(JWT_LEEWAY_SECS))?;
+    let revoke_jwt_use_case = Arc::new(RevokeJwtUseCase::new(
+        jwt_revocation_repository.clone(),
+        max_token_lifetime,
+    ));
+    let revoke_subject_jwts_use_case = Arc::new(RevokeSubjectJwtsUseCase::new(jwt_revocation_repository.clone()));
+    let list_jwt_revocations_use_case = Arc::new(ListJwtRevocationsUseCase::new(jwt_revocation_repository.clone()));
+    let check_jwt_revocation_use_case = Arc::new(CheckJwtRevocationUseCase::new(jwt_revocation_repository));
+    let token_issuer = Arc::new(JwtTokenIssuer::new(
+        config.jwt.secret.expose(),
+        chrono::Duration::seconds(config.jwt.expires_in_secs),
+    ));
+    let issue_service_token_use_case = Arc::new(IssueServiceTokenUseCase::new(
+        config.token_issuer.service_accounts(),
+        token_issuer,
+    ));
 
     // Create application state
     let app_state = AppState {
         config: config.clone(),
         create_network_use_case,
         get_network_by_id_use_case,
+        get_network_by_slug_use_case,
         get_active_networks_use_case,
         update_network_use_case,
         partial_update_network_use_case,
         delete_network_use_case,
+        get_explorer_links_use_case,
+        transition_network_use_case,
+        schedule_lifecycle_change_use_case,
+        list_lifecycle_schedules_use_case,
+        cancel_lifecycle_schedule_use_case,
+        create_contract_use_case,
+        list_contracts_use_case,
+        get_contract_use_case,
+        update_contract_use_case,
+        delete_contract_use_case,
+        find_contracts_by_name_use_case,
+        create_token_use_case,
+        list_tokens_use_case,
+        update_token_use_case,
+        delete_token_use_case,
+        import_tokens_use_case,
+        export_token_list_use_case,
+        create_signer_use_case,
+        list_signers_use_case,
+        update_signer_use_case,
+        delete_signer_use_case,
+        resolve_signer_use_case,
+        set_network_parent_use_case,
+        list_network_children_use_case,
+        list_network_testnets_use_case,
+        list_network_ancestors_use_case,
+        search_networks_use_case,
+        create_webhook_use_case,
+        list_webhooks_use_case,
+        get_webhook_use_case,
+        update_webhook_use_case,
+        delete_webhook_use_case,
+        list_webhook_deliveries_use_case,
+        get_webhook_delivery_use_case,
+        redeliver_webhook_use_case,
+        create_api_key_use_case,
+        list_api_keys_use_case,
+        revoke_api_key_use_case,
+        authenticate_api_key_use_case,
+        revoke_jwt_use_case,
+        revoke_subject_jwts_use_case,
+        list_jwt_revocations_use_case,
+        check_jwt_revocation_use_case,
+        issue_service_token_use_case,
+        secret_resolver,
+        network_events: event_bus.clone(),
     };
 
+    // Start the lifecycle scheduler, which picks up pending schedules persisted before a restart
+    if config.scheduler.enabled {
+        let apply_due_schedules_use_case = Arc::new(ApplyDueSchedulesUseCase::new(
+            network_repository.clone(),
+            schedule_repository.clone(),
+            event_bus.clone(),
+        ));
+        let _scheduler = LifecycleScheduler::new(apply_due_schedules_use_case, &config.scheduler).spawn();
+        tracing::info!(
+            "Lifecycle scheduler started: every {}s, batch size {}",
+            config.scheduler.poll_interval_secs,
+            config.scheduler.batch_size
+        );
+    } else {
+        tracing::warn!("Lifecycle scheduler disabled, scheduled network changes will not be applied");
+    }
+
+    // Relay the changes recorded in the outbox, including those left unpublished before a restart
+    if config.outbox.enabled {
+        let enqueue_webhook_deliveries_use_case = Arc::new(EnqueueWebhookDeliveriesUseCase::new(
+            webhook_repository.clone(),
+            webhook_delivery_repository.clone(),
+        ));
+        let sinks: Vec<Arc<dyn OutboxSink>> = config
+            .outbox
+            .sinks
+            .iter()
+            .map(|kind| -> Arc<dyn OutboxSink> {
+                match kind {
+                    OutboxSinkKind::Log => Arc::new(LogOutboxSink::new()),
+                    OutboxSinkKind::Webhook => {
+                        Arc::new(WebhookOutboxSink::new(enqueue_webhook_deliveries_use_case.clone()))
+                    }
+                }
+            })
+            .collect();
+        let relay_outbox_use_case = Arc::new(RelayOutboxUseCase::new(
+            outbox_repository.clone(),
+            sinks,
+            config.outbox.retry_policy(),
+        ));
+        let purge_outbox_use_case = Arc::new(PurgeOutboxUseCase::new(
+            outbox_repository.clone(),
+            Duration::from_secs(config.outbox.retention_hours * 3600),
+        ));
+        let _relay = OutboxRelay::new(relay_outbox_use_case, purge_outbox_use_case, &config.outbox).spawn();
+        tracing::info!(
+            "Outbox relay started: every {}s, batch size {}, sinks {:?}",
+            config.outbox.poll_interval_secs,
+            config.outbox.batch_size,
+            config.outbox.sinks
+        );
+    } else {
+        tracing::warn!("Outbox relay disabled, network changes will not be relayed to webhooks or other sinks");
+    }
+
+    // Send queued webhook deliveries and their retries
+    if config.webhooks.enabled {
+        let webhook_sender = Arc::new(
+            HttpWebhookSender::new(Duration::from_secs(config.webhooks.timeout_secs), url_policy.clone())
+                .context("Failed to build webhook HTTP client")?,
+        );
+        let deliver_due_webhooks_use_case = Arc::new(DeliverDueWebhooksUseCase::new(
+            webhook_repository.clone(),
+            webhook_delivery_repository.clone(),
+            webhook_sender,
+            config.webhooks.retry_policy(),
+        ));
+        let _dispatcher = WebhookDispatcher::new(deliver_due_webhooks_use_case, &config.webhooks).spawn();
+        tracing::info!(
+            "Webhook dispatcher started: every {}s, batch size {}",
+            config.webhooks.poll_interval_secs,
+            config.webhooks.batch_size
+        );
+    } else {
+        tracing::warn!("Webhook dispatcher disabled, queued webhook deliveries will not be sent");
+    }
+
+    // Publish changes made by other replicas to this replica's event subscribers
+    if config.events.listen {
+        let propagate_network_change_use_case = Arc::new(PropagateNetworkChangeUseCase::new(
+            network_repository.clone(),
+            event_bus.clone(),
+        ));
+        let mut change_listener = NetworkChangeListener::new(propagate_network_change_use_case, pool, instance_id);
+        if let Some(cache) = &network_cache {
+            change_listener = change_listener.with_cache_invalidator(cache.clone());
+        }
+        let _change_listener = change_listener.spawn();
+        tracing::info!("Network change listener started");
+    } else {
+        tracing::warn!("Network change listener disabled, changes made by other replicas will not be streamed");
+    }
+
     // Configure rate limiting with proper error handling
     let governor_conf = GovernorConfigBuilder::default()
         .per_second(config.rate_limit.requests_per_second.into())
@@ -93,16 +493,52 @@ async fn main() -> anyhow::Result<()> {
     // Build router with secure CORS configuration
     let cors = build_cors_layer(&config)?;
 
-    let app = Router::new()
+    let mut routes = Router::new()
         .nest("/networks", networks::router())
-        // Add config to request extensions for JWT validation
+        .nest("/webhooks", webhooks::router())
+        .nest("/api-keys", api_keys::router())
+        .nest("/auth/revocations", jwt_revocations::router());
+
+    // Exchange service account credentials for JWTs only where explicitly enabled
+    if config.token_issuer.endpoint_enabled {
+        routes = routes.nest("/auth", auth::router());
+        tracing::info!(
+            "Token endpoint enabled for {} service account(s)",
+            config.token_issuer.service_accounts.len()
+        );
+    }
+
+    let mut app = routes
+        // Add config and API key authenticator to request extensions for authentication
         .layer(middleware::from_fn_with_state(app_state.clone(), add_config_extension))
         // Add request ID for tracing and debugging
         .layer(middleware::from_fn(request_id_middleware))
         .layer(rate_limit_layer)
-        .layer(TraceLayer::new_for_http())
-        .layer(cors)
-        .with_state(app_state);
+        .layer(cors);
+
+    // Serve the unauthenticated read-only routes with their own, stricter rate limit and open CORS policy
+    if config.public_api.enabled {
+        let public_governor_conf = GovernorConfigBuilder::default()
+            .per_second(config.public_api.requests_per_second.into())
+            .burst_size(config.public_api.burst_size)
+            .finish()
+            .context("Failed to build public rate limiter configuration")?;
+        let public_router = Router::new()
+            .nest("/public", public::router())
+            .layer(middleware::from_fn(request_id_middleware))
+            .layer(GovernorLayer {
+                config: Arc::new(public_governor_conf),
+            })
+            .layer(build_public_cors_layer());
+        app = app.merge(public_router);
+        tracing::info!(
+            "Public API enabled under /public: {} req/s, burst: {}",
+            config.public_api.requests_per_second,
+            config.public_api.burst_size
+        );
+    }
+
+    let app = app.layer(TraceLayer::new_for_http()).with_state(app_state);
 
     // Start server
     let addr = format!("{}:{}", config.server.host, config.server.port);
@@ -118,25 +554,37 @@ async fn main() -> anyhow::Result<()> {
     Ok(())
 }
 
+/// Mint a JWT signed with the configured secret and print it
+///
+/// The token goes to stdout on its own, so that it can be captured by scripts.
+fn issue_token(args: &IssueTokenArgs) -> anyhow::Result<()> {
+    let config = AppConfig::load()?;
+    let expires_in = chrono::Duration::seconds(args.expires_in_secs.unwrap_or(config.jwt.expires_in_secs));
+    let token_issuer = JwtTokenIssuer::new(config.jwt.secret.expose(), expires_in);
+
+    let email = args.email.as_deref().unwrap_or(&args.subject);
+    let issued = token_issuer
+        .issue(&args.subject, email, &args.role)
+        .context("Failed to sign token")?;
+
+    eprintln!(
+        "Token ID (jti): {}, expires at {}",
+        issued.jti,
+        issued.expires_at.to_rfc3339()
+    );
+    println!("{}", issued.token);
+    Ok(())
+}
+
 /// Build CORS layer with proper error handling
 fn build_cors_layer(config: &AppConfig) -> anyhow::Result<CorsLayer> {
-    let cors = if config.server.allowed_origins.is_empty() {
+    let origin = if config.server.allowed_origins.is_empty() {
         // Development: restrictive default (localhost only)
         tracing::warn!("No allowed_origins configured, defaulting to localhost only");
         let origin = DEFAULT_CORS_ORIGIN
             .parse::<axum::http::HeaderValue>()
             .context("Failed to parse default CORS origin")?;
-        CorsLayer::new()
-            .allow_origin(origin)
-            .allow_methods([
-                axum::http::Method::GET,
-                axum::http::Method::POST,
-                axum::http::Method::PUT,
-                axum::http::Method::PATCH,
-                axum::http::Method::DELETE,
-            ])
-            .allow_headers([axum::http::header::CONTENT_TYPE, axum::http::header::AUTHORIZATION])
-            .allow_credentials(true)
+        AllowOrigin::exact(origin)
     } else {
         let origins: Vec<axum::http::HeaderValue> = config
             .server
@@ -149,18 +597,34 @@ fn build_cors_layer(config: &AppConfig) -> anyhow::Result<CorsLayer> {
             anyhow::bail!("No valid CORS origins could be parsed from configuration");
         }
 
-        CorsLayer::new()
-            .allow_origin(origins)
-            .allow_methods([
-                axum::http::Method::GET,
-                axum::http::Method::POST,
-                axum::http::Method::PUT,
-                axum::http::Method::PATCH,
-                axum::http::Method::DELETE,
-            ])
-            .allow_headers([axum::http::header::CONTENT_TYPE, axum::http::header::AUTHORIZATION])
-            .allow_credentials(true)
+        AllowOrigin::list(origins)
     };
 
-    Ok(cors)
+    Ok(CorsLayer::new()
+        .allow_origin(origin)
+        .allow_methods([
+            axum::http::Method::GET,
+            axum::http::Method::POST,
+            axum::http::Method::PUT,
+            axum::http::Method::PATCH,
+            axum::http::Method::DELETE,
+        ])
+        .allow_headers([
+            axum::http::header::CONTENT_TYPE,
+            axum::http::header::AUTHORIZATION,
+            axum::http::header::IF_NONE_MATCH,
+            axum::http::header::IF_MODIFIED_SINCE,
+            API_KEY_HEADER,
+        ])
+        .expose_headers([axum::http::header::ETAG, axum::http::header::LAST_MODIFIED])
+        .allow_credentials(true))
+}
+
+/// Build the CORS layer of the public routes: any origin may read them, without credentials
+fn build_public_cors_layer() -> CorsLayer {
+    CorsLayer::new()
+        .allow_origin(Any)
+        .allow_methods([axum::http::Method::GET])
+        .allow_headers([axum::http::header::IF_NONE_MATCH, axum::http::header::IF_MODIFIED_SINCE])
+        .expose_headers([axum::http::header::ETAG, axum::http::header::LAST_MODIFIED])
 }
@@ -19,6 +19,9 @@ pub enum DomainError {
     #[error("Network with chain_id {0} already exists")]
     ChainIdConflict(i32),
 
+    #[error("Network slug or alias '{0}' is already taken")]
+    SlugConflict(String),
+
     #[error("Invalid network state: {0}")]
     InvalidState(String),
 
@@ -40,6 +43,28 @@ pub enum RepositoryError {
 
     #[error("Unique constraint violation: {0}")]
     UniqueViolation(String),
+
+    #[error("Conflicting concurrent change: {0}")]
+    Conflict(String),
+
+    #[error(transparent)]
+    Secret(#[from] SecretError),
+}
+
+/// Errors raised while sealing or opening secrets stored at rest, or signing tokens
+#[derive(Debug, Error)]
+pub enum SecretError {
+    #[error("Invalid encryption key: {0}")]
+    InvalidKey(String),
+
+    #[error("Encryption failed")]
+    Encryption,
+
+    #[error("Decryption failed: {0}")]
+    Decryption(String),
+
+    #[error("Signing failed: {0}")]
+    Signing(String),
 }
 
 impl RepositoryError {
@@ -55,12 +80,26 @@ impl RepositoryError {
         false
     }
 
+    /// Check if this is a unique constraint violation for a network slug or alias
+    #[must_use]
+    pub fn is_slug_conflict(&self) -> bool {
+        if let RepositoryError::Database(sqlx::Error::Database(db_err)) = self {
+            if db_err.code().map_or(false, |c| c == "23505") {
+                return db_err.message().contains("slug") || db_err.constraint() == Some("network_identifiers_pkey");
+            }
+        }
+        false
+    }
+
     /// Convert database errors to appropriate domain errors
     #[must_use]
     pub fn into_domain_error(self) -> Self {
         if self.is_chain_id_conflict() {
             return RepositoryError::UniqueViolation("chain_id already exists".to_string());
         }
+        if self.is_slug_conflict() {
+            return RepositoryError::UniqueViolation("slug or alias".to_string());
+        }
         self
     }
 }
@@ -88,6 +127,9 @@ pub enum UseCaseError {
 
     #[error(transparent)]
     Repository(#[from] RepositoryError),
+
+    #[error(transparent)]
+    Secret(#[from] SecretError),
 }
 
 impl UseCaseError {
@@ -101,11 +143,13 @@ impl UseCaseError {
             Self::Unauthorized(_) => StatusCode::UNAUTHORIZED,
             Self::Forbidden(_) => StatusCode::FORBIDDEN,
             Self::Domain(DomainError::ChainIdConflict(_)) => StatusCode::CONFLICT,
+            Self::Domain(DomainError::SlugConflict(_)) => StatusCode::CONFLICT,
             Self::Domain(DomainError::InvalidState(_)) => StatusCode::BAD_REQUEST,
             Self::Domain(DomainError::ValidationError(_)) => StatusCode::BAD_REQUEST,
             Self::Repository(RepositoryError::UniqueViolation(_)) => StatusCode::CONFLICT,
+            Self::Repository(RepositoryError::Conflict(_)) => StatusCode::CONFLICT,
             Self::Repository(RepositoryError::NotFound(_)) => StatusCode::NOT_FOUND,
-            Self::Repository(_) => StatusCode::INTERNAL_SERVER_ERROR,
+            Self::Repository(_) | Self::Secret(_) => StatusCode::INTERNAL_SERVER_ERROR,
         }
     }
 
@@ -119,11 +163,13 @@ impl UseCaseError {
             Self::Unauthorized(_) => "UNAUTHORIZED",
             Self::Forbidden(_) => "FORBIDDEN",
             Self::Domain(DomainError::ChainIdConflict(_)) => "CONFLICT",
+            Self::Domain(DomainError::SlugConflict(_)) => "CONFLICT",
             Self::Domain(DomainError::InvalidState(_)) => "INVALID_STATE",
             Self::Domain(DomainError::ValidationError(_)) => "VALIDATION_ERROR",
             Self::Repository(RepositoryError::UniqueViolation(_)) => "CONFLICT",
+            Self::Repository(RepositoryError::Conflict(_)) => "CONFLICT",
             Self::Repository(RepositoryError::NotFound(_)) => "NOT_FOUND",
-            Self::Repository(_) => "INTERNAL_ERROR",
+            Self::Repository(_) | Self::Secret(_) => "INTERNAL_ERROR",
         }
     }
 
@@ -139,11 +185,15 @@ impl UseCaseError {
             Self::Domain(DomainError::ChainIdConflict(chain_id)) => {
                 format!("Network with chain_id {} already exists", chain_id)
             }
+            Self::Domain(DomainError::SlugConflict(slug)) => {
+                format!("Network slug or alias '{}' is already taken", slug)
+            }
             Self::Domain(DomainError::InvalidState(msg)) => msg.clone(),
             Self::Domain(DomainError::ValidationError(msg)) => msg.clone(),
             Self::Repository(RepositoryError::UniqueViolation(field)) => {
                 format!("A record with this {} already exists", field)
             }
+            Self::Repository(RepositoryError::Conflict(msg)) => msg.clone(),
             Self::Repository(RepositoryError::NotFound(resource)) => {
                 format!("{} not found", resource)
             }
@@ -154,6 +204,10 @@ impl UseCaseError {
             Self::Repository(RepositoryError::Mapping(_)) => {
                 "An internal error occurred. Please try again later.".to_string()
             }
+            Self::Repository(RepositoryError::Secret(_)) => {
+                "An internal error occurred. Please try again later.".to_string()
+            }
+            Self::Secret(_) => "An internal error occurred. Please try again later.".to_string(),
         }
     }
 }
@@ -186,6 +240,8 @@ impl ApiError {
             ApiError::Internal(_)
                 | ApiError::UseCase(UseCaseError::Repository(RepositoryError::Database(_)))
                 | ApiError::UseCase(UseCaseError::Repository(RepositoryError::Mapping(_)))
+                | ApiError::UseCase(UseCaseError::Repository(RepositoryError::Secret(_)))
+                | ApiError::UseCase(UseCaseError::Secret(_))
         )
     }
 }
@@ -4,4 +4,4 @@
 
 pub mod errors;
 
-pub use errors::{ApiError, DomainError, RepositoryError, UseCaseError};
+pub use errors::{ApiError, DomainError, RepositoryError, SecretError, UseCaseError};
@@ -3,28 +3,79 @@
 //! Provides test infrastructure for spinning up a PostgreSQL container,
 //! running migrations, and creating a test application.
 
+use std::str::FromStr;
 use std::sync::Arc;
+use std::time::Duration;
 
+use axum::body::{Body, BodyDataStream};
 use axum::{middleware, Router};
 use chrono::Utc;
 use jsonwebtoken::{encode, EncodingKey, Header};
 use serde::{Deserialize, Serialize};
-use sqlx::postgres::PgPoolOptions;
+use sqlx::postgres::{PgConnectOptions, PgPoolOptions};
+use sqlx::PgPool;
 use testcontainers::{runners::AsyncRunner, ContainerAsync, ImageExt};
 use testcontainers_modules::postgres::Postgres;
+use tokio_stream::StreamExt;
 use tower_http::trace::TraceLayer;
 
+use blockchain_network_registry::application::use_cases::api_keys::{
+    AuthenticateApiKeyUseCase, CreateApiKeyUseCase, ListApiKeysUseCase, RevokeApiKeyUseCase,
+};
+use blockchain_network_registry::application::use_cases::auth::{
+    CheckJwtRevocationUseCase, IssueServiceTokenUseCase, ListJwtRevocationsUseCase, RevokeJwtUseCase,
+    RevokeSubjectJwtsUseCase,
+};
 use blockchain_network_registry::application::use_cases::networks::{
-    CreateNetworkUseCase, DeleteNetworkUseCase, GetActiveNetworksUseCase, GetNetworkByIdUseCase,
-    PartialUpdateNetworkUseCase, UpdateNetworkUseCase,
+    ApplyDueSchedulesUseCase, CancelLifecycleScheduleUseCase, CreateContractUseCase, CreateNetworkUseCase,
+    CreateSignerUseCase, CreateTokenUseCase, DeleteContractUseCase, DeleteNetworkUseCase, DeleteSignerUseCase,
+    DeleteTokenUseCase, ExportTokenListUseCase, FindContractsByNameUseCase, GetActiveNetworksUseCase,
+    GetContractUseCase, GetExplorerLinksUseCase, GetNetworkByIdUseCase, GetNetworkBySlugUseCase, ImportTokensUseCase,
+    ListContractsUseCase, ListLifecycleSchedulesUseCase, ListNetworkAncestorsUseCase, ListNetworkChildrenUseCase,
+    ListNetworkTestnetsUseCase, ListSignersUseCase, ListTokensUseCase, PartialUpdateNetworkUseCase,
+    ResolveSignerUseCase, ScheduleLifecycleChangeUseCase, SearchNetworksUseCase, SetNetworkParentUseCase,
+    TransitionNetworkUseCase, UpdateContractUseCase, UpdateNetworkUseCase, UpdateSignerUseCase, UpdateTokenUseCase,
 };
+use blockchain_network_registry::application::use_cases::outbox::RelayOutboxUseCase;
+use blockchain_network_registry::application::use_cases::webhooks::{
+    CreateWebhookUseCase, DeleteWebhookUseCase, DeliverDueWebhooksUseCase, EnqueueWebhookDeliveriesUseCase,
+    GetWebhookDeliveryUseCase, GetWebhookUseCase, ListWebhookDeliveriesUseCase, ListWebhooksUseCase,
+    RedeliverWebhookUseCase, UpdateWebhookUseCase,
+};
+use blockchain_network_registry::domain::models::outbox::OutboxRetryPolicy;
+use blockchain_network_registry::domain::models::url_policy::UrlPolicy;
+use blockchain_network_registry::domain::models::webhook::WebhookRetryPolicy;
+use blockchain_network_registry::infrastructure::driven_adapters::api_key_repository::PostgresApiKeyRepository;
+use blockchain_network_registry::infrastructure::driven_adapters::contract_repository::PostgresContractRepository;
+use blockchain_network_registry::infrastructure::driven_adapters::event_bus::InMemoryEventBus;
+use blockchain_network_registry::infrastructure::driven_adapters::jwt_revocation_repository::PostgresJwtRevocationRepository;
 use blockchain_network_registry::infrastructure::driven_adapters::network_repository::PostgresNetworkRepository;
-use blockchain_network_registry::infrastructure::driving_adapters::api_rest::handlers::networks;
+use blockchain_network_registry::infrastructure::driven_adapters::outbox_repository::PostgresOutboxRepository;
+use blockchain_network_registry::infrastructure::driven_adapters::outbox_sink::InMemoryOutboxSink;
+use blockchain_network_registry::infrastructure::driven_adapters::schedule_repository::PostgresScheduleRepository;
+use blockchain_network_registry::infrastructure::driven_adapters::secrets::ConfigSecretResolver;
+use blockchain_network_registry::infrastructure::driven_adapters::signer_repository::PostgresSignerRepository;
+use blockchain_network_registry::infrastructure::driven_adapters::token_issuer::JwtTokenIssuer;
+use blockchain_network_registry::infrastructure::driven_adapters::token_repository::PostgresTokenRepository;
+use blockchain_network_registry::infrastructure::driven_adapters::webhook_delivery_repository::PostgresWebhookDeliveryRepository;
+use blockchain_network_registry::infrastructure::driven_adapters::webhook_repository::PostgresWebhookRepository;
+use blockchain_network_registry::infrastructure::driven_adapters::webhook_sender::HttpWebhookSender;
+use blockchain_network_registry::infrastructure::driving_adapters::api_rest::handlers::{
+    api_keys, auth, jwt_revocations, networks, public, webhooks,
+};
 use blockchain_network_registry::infrastructure::driving_adapters::api_rest::AppState;
+use blockchain_network_registry::infrastructure::driving_adapters::webhook_publisher::WebhookOutboxSink;
 
 /// Test JWT secret (minimum 32 characters)
 pub const TEST_JWT_SECRET: &str = "test-jwt-secret-key-for-e2e-testing-only-min-32-chars";
 
+/// Client ID and secret of the service account allowed to obtain tokens
+pub const TEST_CLIENT_ID: &str = "indexer";
+pub const TEST_CLIENT_SECRET: &str = "test-client-secret";
+
+/// Instance ID the test application's database sessions are tagged with
+pub const TEST_INSTANCE_ID: &str = "network-registry-test";
+
 /// JWT claims for test tokens
 #[derive(Debug, Serialize, Deserialize)]
 pub struct TestClaims {
@@ -33,18 +84,37 @@ pub struct TestClaims {
     pub role: String,
     pub iat: i64,
     pub exp: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub jti: Option<String>,
 }
 
 /// Test application context
+#[allow(dead_code)]
 pub struct TestApp {
     pub router: Router,
     pub jwt_token: String,
+    /// Applies due lifecycle schedules, standing in for the background scheduler
+    pub apply_due_schedules_use_case: Arc<ApplyDueSchedulesUseCase>,
+    /// Relays due outbox messages, standing in for the background relay
+    pub relay_outbox_use_case: Arc<RelayOutboxUseCase>,
+    /// Events relayed from the outbox so far
+    pub outbox_sink: Arc<InMemoryOutboxSink>,
+    /// Sends due webhook deliveries, standing in for the background dispatcher
+    pub deliver_due_webhooks_use_case: Arc<DeliverDueWebhooksUseCase>,
+    /// Pool of the test application, connecting as `TEST_INSTANCE_ID`
+    pub pool: PgPool,
     _container: ContainerAsync<Postgres>,
 }
 
 impl TestApp {
     /// Create a new test application with a fresh PostgreSQL database
     pub async fn new() -> Self {
+        Self::with_url_policy(UrlPolicy::default()).await
+    }
+
+    /// Create a new test application checking URLs against the given policy
+    #[allow(dead_code)]
+    pub async fn with_url_policy(url_policy: UrlPolicy) -> Self {
         // Start PostgreSQL container
         let container = Postgres::default()
             .with_tag("16-alpine")
@@ -64,10 +134,13 @@ impl TestApp {
         );
 
         // Create a connection pool
+        let connect_options = PgConnectOptions::from_str(&database_url)
+            .expect("Invalid test database URL")
+            .application_name(TEST_INSTANCE_ID);
         let pool = PgPoolOptions::new()
             .max_connections(5)
             .min_connections(1)
-            .connect(&database_url)
+            .connect_with(connect_options)
             .await
             .expect("Failed to connect to test database");
 
@@ -77,35 +150,253 @@ impl TestApp {
             .await
             .expect("Failed to run migrations");
 
-        // Create repository
+        // Create repositories
         let network_repository = Arc::new(PostgresNetworkRepository::new(pool.clone()));
+        let schedule_repository = Arc::new(PostgresScheduleRepository::new(pool.clone()));
+        let contract_repository = Arc::new(PostgresContractRepository::new(pool.clone()));
+        let token_repository = Arc::new(PostgresTokenRepository::new(pool.clone()));
+        let signer_repository = Arc::new(PostgresSignerRepository::new(pool.clone()));
+        let webhook_repository = Arc::new(PostgresWebhookRepository::new(pool.clone()));
+        let webhook_delivery_repository = Arc::new(PostgresWebhookDeliveryRepository::new(pool.clone()));
+        let api_key_repository = Arc::new(PostgresApiKeyRepository::new(pool.clone()));
+        let jwt_revocation_repository = Arc::new(PostgresJwtRevocationRepository::new(pool.clone()));
+        let event_bus = Arc::new(InMemoryEventBus::new(1024));
+        let outbox_repository = Arc::new(PostgresOutboxRepository::new(pool.clone()));
 
         // Create use cases
-        let create_network_use_case = Arc::new(CreateNetworkUseCase::new(network_repository.clone()));
+        let url_policy = Arc::new(url_policy);
+        let create_network_use_case = Arc::new(CreateNetworkUseCase::new(
+            network_repository.clone(),
+            event_bus.clone(),
+            url_policy.clone(),
+        ));
         let get_network_by_id_use_case = Arc::new(GetNetworkByIdUseCase::new(network_repository.clone()));
+        let get_network_by_slug_use_case = Arc::new(GetNetworkBySlugUseCase::new(network_repository.clone()));
         let get_active_networks_use_case = Arc::new(GetActiveNetworksUseCase::new(network_repository.clone()));
-        let update_network_use_case = Arc::new(UpdateNetworkUseCase::new(network_repository.clone()));
-        let partial_update_network_use_case = Arc::new(PartialUpdateNetworkUseCase::new(network_repository.clone()));
-        let delete_network_use_case = Arc::new(DeleteNetworkUseCase::new(network_repository.clone()));
+        let update_network_use_case = Arc::new(UpdateNetworkUseCase::new(
+            network_repository.clone(),
+            event_bus.clone(),
+            url_policy.clone(),
+        ));
+        let partial_update_network_use_case = Arc::new(PartialUpdateNetworkUseCase::new(
+            network_repository.clone(),
+            event_bus.clone(),
+            url_policy.clone(),
+        ));
+        let delete_network_use_case =
+            Arc::new(DeleteNetworkUseCase::new(network_repository.clone(), event_bus.clone()));
+        let get_explorer_links_use_case = Arc::new(GetExplorerLinksUseCase::new(network_repository.clone()));
+        let transition_network_use_case = Arc::new(TransitionNetworkUseCase::new(
+            network_repository.clone(),
+            event_bus.clone(),
+        ));
+        let schedule_lifecycle_change_use_case = Arc::new(ScheduleLifecycleChangeUseCase::new(
+            network_repository.clone(),
+            schedule_repository.clone(),
+        ));
+        let list_lifecycle_schedules_use_case = Arc::new(ListLifecycleSchedulesUseCase::new(
+            network_repository.clone(),
+            schedule_repository.clone(),
+        ));
+        let cancel_lifecycle_schedule_use_case =
+            Arc::new(CancelLifecycleScheduleUseCase::new(schedule_repository.clone()));
+        let create_contract_use_case = Arc::new(CreateContractUseCase::new(
+            network_repository.clone(),
+            contract_repository.clone(),
+        ));
+        let list_contracts_use_case = Arc::new(ListContractsUseCase::new(
+            network_repository.clone(),
+            contract_repository.clone(),
+        ));
+        let get_contract_use_case = Arc::new(GetContractUseCase::new(contract_repository.clone()));
+        let update_contract_use_case = Arc::new(UpdateContractUseCase::new(contract_repository.clone()));
+        let delete_contract_use_case = Arc::new(DeleteContractUseCase::new(contract_repository.clone()));
+        let create_token_use_case = Arc::new(CreateTokenUseCase::new(
+            network_repository.clone(),
+            token_repository.clone(),
+        ));
+        let list_tokens_use_case = Arc::new(ListTokensUseCase::new(
+            network_repository.clone(),
+            token_repository.clone(),
+        ));
+        let update_token_use_case = Arc::new(UpdateTokenUseCase::new(token_repository.clone()));
+        let delete_token_use_case = Arc::new(DeleteTokenUseCase::new(token_repository.clone()));
+        let create_signer_use_case = Arc::new(CreateSignerUseCase::new(
+            network_repository.clone(),
+            signer_repository.clone(),
+        ));
+        let list_signers_use_case = Arc::new(ListSignersUseCase::new(
+            network_repository.clone(),
+            signer_repository.clone(),
+        ));
+        let update_signer_use_case = Arc::new(UpdateSignerUseCase::new(signer_repository.clone()));
+        let delete_signer_use_case = Arc::new(DeleteSignerUseCase::new(signer_repository.clone()));
+        let set_network_parent_use_case = Arc::new(SetNetworkParentUseCase::new(
+            network_repository.clone(),
+            event_bus.clone(),
+        ));
+        let list_network_children_use_case = Arc::new(ListNetworkChildrenUseCase::new(network_repository.clone()));
+        let list_network_testnets_use_case = Arc::new(ListNetworkTestnetsUseCase::new(network_repository.clone()));
+        let list_network_ancestors_use_case = Arc::new(ListNetworkAncestorsUseCase::new(network_repository.clone()));
+        let search_networks_use_case = Arc::new(SearchNetworksUseCase::new(network_repository.clone()));
+        let resolve_signer_use_case = Arc::new(ResolveSignerUseCase::new(
+            network_repository.clone(),
+            signer_repository.clone(),
+        ));
+        let import_tokens_use_case = Arc::new(ImportTokensUseCase::new(
+            network_repository.clone(),
+            token_repository.clone(),
+        ));
+        let export_token_list_use_case = Arc::new(ExportTokenListUseCase::new(
+            network_repository.clone(),
+            token_repository.clone(),
+        ));
+        let find_contracts_by_name_use_case = Arc::new(FindContractsByNameUseCase::new(
+            network_repository.clone(),
+            contract_repository.clone(),
+        ));
+        let apply_due_schedules_use_case = Arc::new(ApplyDueSchedulesUseCase::new(
+            network_repository.clone(),
+            schedule_repository.clone(),
+            event_bus.clone(),
+        ));
+        let create_webhook_use_case = Arc::new(CreateWebhookUseCase::new(
+            webhook_repository.clone(),
+            url_policy.clone(),
+        ));
+        let list_webhooks_use_case = Arc::new(ListWebhooksUseCase::new(webhook_repository.clone()));
+        let get_webhook_use_case = Arc::new(GetWebhookUseCase::new(webhook_repository.clone()));
+        let update_webhook_use_case = Arc::new(UpdateWebhookUseCase::new(
+            webhook_repository.clone(),
+            url_policy.clone(),
+        ));
+        let delete_webhook_use_case = Arc::new(DeleteWebhookUseCase::new(webhook_repository.clone()));
+        let list_webhook_deliveries_use_case = Arc::new(ListWebhookDeliveriesUseCase::new(
+            webhook_repository.clone(),
+            webhook_delivery_repository.clone(),
+        ));
+        let get_webhook_delivery_use_case =
+            Arc::new(GetWebhookDeliveryUseCase::new(webhook_delivery_repository.clone()));
+        let redeliver_webhook_use_case = Arc::new(RedeliverWebhookUseCase::new(webhook_delivery_repository.clone()));
+        let create_api_key_use_case = Arc::new(CreateApiKeyUseCase::new(api_key_repository.clone()));
+        let list_api_keys_use_case = Arc::new(ListApiKeysUseCase::new(api_key_repository.clone()));
+        let revoke_api_key_use_case = Arc::new(RevokeApiKeyUseCase::new(api_key_repository.clone()));
+        let authenticate_api_key_use_case = Arc::new(AuthenticateApiKeyUseCase::new(api_key_repository));
+        // Test tokens live an hour, plus the validation leeway
+        let revoke_jwt_use_case = Arc::new(RevokeJwtUseCase::new(
+            jwt_revocation_repository.clone(),
+            chrono::Duration::seconds(3660),
+        ));
+        let revoke_subject_jwts_use_case = Arc::new(RevokeSubjectJwtsUseCase::new(jwt_revocation_repository.clone()));
+        let list_jwt_revocations_use_case = Arc::new(ListJwtRevocationsUseCase::new(jwt_revocation_repository.clone()));
+        let check_jwt_revocation_use_case = Arc::new(CheckJwtRevocationUseCase::new(jwt_revocation_repository));
+        // Relayed events are kept in memory and queued for webhooks; retries
+        // are due immediately so tests can drive them without waiting
+        let outbox_sink = Arc::new(InMemoryOutboxSink::new());
+        let enqueue_webhook_deliveries_use_case = Arc::new(EnqueueWebhookDeliveriesUseCase::new(
+            webhook_repository.clone(),
+            webhook_delivery_repository.clone(),
+        ));
+        let relay_outbox_use_case = Arc::new(RelayOutboxUseCase::new(
+            outbox_repository,
+            vec![
+                outbox_sink.clone(),
+                Arc::new(WebhookOutboxSink::new(enqueue_webhook_deliveries_use_case)),
+            ],
+            OutboxRetryPolicy {
+                base_delay: Duration::ZERO,
+                max_delay: Duration::ZERO,
+            },
+        ));
+        let deliver_due_webhooks_use_case = Arc::new(DeliverDueWebhooksUseCase::new(
+            webhook_repository,
+            webhook_delivery_repository,
+            Arc::new(
+                HttpWebhookSender::new(Duration::from_secs(5), url_policy).expect("Failed to build webhook client"),
+            ),
+            WebhookRetryPolicy {
+                max_attempts: 3,
+                base_delay: Duration::ZERO,
+                max_delay: Duration::ZERO,
+            },
+        ));
 
         // Create test config (we'll inject it directly into extensions)
         let test_config = create_test_config();
         let config = Arc::new(test_config);
 
+        let secret_resolver = Arc::new(ConfigSecretResolver::new(&config.secrets));
+        let issue_service_token_use_case = Arc::new(IssueServiceTokenUseCase::new(
+            config.token_issuer.service_accounts(),
+            Arc::new(JwtTokenIssuer::new(TEST_JWT_SECRET, chrono::Duration::seconds(3600))),
+        ));
+
         // Create an application state
         let app_state = AppState {
             config: config.clone(),
             create_network_use_case,
             get_network_by_id_use_case,
+            get_network_by_slug_use_case,
             get_active_networks_use_case,
             update_network_use_case,
             partial_update_network_use_case,
             delete_network_use_case,
+            get_explorer_links_use_case,
+            transition_network_use_case,
+            schedule_lifecycle_change_use_case,
+            list_lifecycle_schedules_use_case,
+            cancel_lifecycle_schedule_use_case,
+            create_contract_use_case,
+            list_contracts_use_case,
+            get_contract_use_case,
+            update_contract_use_case,
+            delete_contract_use_case,
+            find_contracts_by_name_use_case,
+            create_token_use_case,
+            list_tokens_use_case,
+            update_token_use_case,
+            delete_token_use_case,
+            import_tokens_use_case,
+            export_token_list_use_case,
+            create_signer_use_case,
+            list_signers_use_case,
+            update_signer_use_case,
+            delete_signer_use_case,
+            resolve_signer_use_case,
+            set_network_parent_use_case,
+            list_network_children_use_case,
+            list_network_testnets_use_case,
+            list_network_ancestors_use_case,
+            search_networks_use_case,
+            create_webhook_use_case,
+            list_webhooks_use_case,
+            get_webhook_use_case,
+            update_webhook_use_case,
+            delete_webhook_use_case,
+            list_webhook_deliveries_use_case,
+            get_webhook_delivery_use_case,
+            redeliver_webhook_use_case,
+            create_api_key_use_case,
+            list_api_keys_use_case,
+            revoke_api_key_use_case,
+            authenticate_api_key_use_case,
+            revoke_jwt_use_case,
+            revoke_subject_jwts_use_case,
+            list_jwt_revocations_use_case,
+            check_jwt_revocation_use_case,
+            issue_service_token_use_case,
+            secret_resolver,
+            network_events: event_bus,
         };
 
         // Build router (without rate limiting for tests)
         let router = Router::new()
             .nest("/networks", networks::router())
+            .nest("/webhooks", webhooks::router())
+            .nest("/api-keys", api_keys::router())
+            .nest("/auth/revocations", jwt_revocations::router())
+            .nest("/auth", auth::router())
+            .nest("/public", public::router())
             .layer(middleware::from_fn_with_state(
                 app_state.clone(),
                 blockchain_network_registry::infrastructure::driving_adapters::api_rest::middleware::auth::add_config_extension,
@@ -119,6 +410,11 @@ impl TestApp {
         Self {
             router,
             jwt_token,
+            apply_due_schedules_use_case,
+            relay_outbox_use_case,
+            outbox_sink,
+            deliver_due_webhooks_use_case,
+            pool,
             _container: container,
         }
     }
@@ -154,6 +450,14 @@ expires_in_secs = 3600
 [rate_limit]
 requests_per_second = 1000
 burst_size = 1000
+
+[token_issuer]
+endpoint_enabled = true
+
+[token_issuer.service_accounts.indexer]
+# SHA-256 of TEST_CLIENT_SECRET
+secret_hash = "8ac950188678f9bb3524b275130332b511bf5092394da6975b5fb9e84302f026"
+role = "admin"
 "#,
         TEST_JWT_SECRET
     );
@@ -168,13 +472,19 @@ burst_size = 1000
 
 /// Generate a valid JWT token for testing
 pub fn generate_test_token() -> String {
+    generate_token_with_role("admin")
+}
+
+/// Generate a valid JWT token for a specific role
+pub fn generate_token_with_role(role: &str) -> String {
     let now = Utc::now().timestamp();
     let claims = TestClaims {
         sub: "test-user-id".to_string(),
         email: "test@example.com".to_string(),
-        role: "admin".to_string(),
+        role: role.to_string(),
         iat: now,
         exp: now + 3600, // 1 hour from now
+        jti: None,
     };
 
     encode(
@@ -194,6 +504,7 @@ pub fn generate_expired_token() -> String {
         role: "admin".to_string(),
         iat: now - 7200, // 2 hours ago
         exp: now - 3600, // 1 hour ago (expired)
+        jti: None,
     };
 
     encode(
@@ -204,6 +515,93 @@ pub fn generate_expired_token() -> String {
     .expect("Failed to generate expired JWT token")
 }
 
+/// Generate a valid admin JWT token for a subject, with a token ID
+#[allow(dead_code)]
+pub fn generate_token_with_jti(sub: &str, jti: &str) -> String {
+    let now = Utc::now().timestamp();
+    let claims = TestClaims {
+        sub: sub.to_string(),
+        email: "test@example.com".to_string(),
+        role: "admin".to_string(),
+        iat: now,
+        exp: now + 3600,
+        jti: Some(jti.to_string()),
+    };
+
+    encode(
+        &Header::default(),
+        &claims,
+        &EncodingKey::from_secret(TEST_JWT_SECRET.as_bytes()),
+    )
+    .expect("Failed to generate test JWT token")
+}
+
+/// A Server-Sent Event read from a response body
+#[derive(Debug)]
+#[allow(dead_code)]
+pub struct SseEvent {
+    pub event: String,
+    pub id: Option<String>,
+    pub data: String,
+}
+
+/// Reads Server-Sent Events from a streaming response body
+#[allow(dead_code)]
+pub struct SseReader {
+    body: BodyDataStream,
+    buffer: String,
+}
+
+#[allow(dead_code)]
+impl SseReader {
+    pub fn new(body: Body) -> Self {
+        Self {
+            body: body.into_data_stream(),
+            buffer: String::new(),
+        }
+    }
+
+    /// Read the next event, skipping keep-alive comments; panics after 5 seconds without one
+    pub async fn next_event(&mut self) -> SseEvent {
+        loop {
+            if let Some(end) = self.buffer.find("\n\n") {
+                let frame: String = self.buffer.drain(..end + 2).collect();
+                let mut event = SseEvent {
+                    event: "message".to_string(),
+                    id: None,
+                    data: String::new(),
+                };
+                let mut has_fields = false;
+                for line in frame.lines() {
+                    let Some((field, value)) = line.split_once(':') else {
+                        continue;
+                    };
+                    let value = value.strip_prefix(' ').unwrap_or(value).to_string();
+                    match field {
+                        "event" => event.event = value,
+                        "id" => event.id = Some(value),
+                        "data" => event.data.push_str(&value),
+                        _ => continue,
+                    }
+                    has_fields = true;
+                }
+                if has_fields {
+                    return event;
+                }
+                continue;
+            }
+
+            let chunk = tokio::time::timeout(Duration::from_secs(5), self.body.next())
+                .await
+                .expect("Timed out waiting for a server-sent event")
+                .expect("Event stream ended")
+                .expect("Failed to read event stream");
+            self.buffer
+                .push_str(std::str::from_utf8(&chunk).expect("Event stream is not UTF-8"));
+        }
+    }
+}
+
 /// Helper struct for creating network request bodies
 #[derive(Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -218,6 +616,26 @@ pub struct CreateNetworkRequest {
     pub fee_multiplier: f64,
     pub gas_limit_multiplier: f64,
     pub default_signer_address: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub explorer: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fee_config: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub finality: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub slug: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub aliases: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub testnet: Option<serde_json::Value>,
 }
 
 impl Default for CreateNetworkRequest {
@@ -232,6 +650,16 @@ impl Default for CreateNetworkRequest {
             fee_multiplier: 1.0,
             gas_limit_multiplier: 1.2,
             default_signer_address: "0x742d35Cc6634C0532925a3b844Bc9e7595f1dEaD".to_string(),
+            explorer: None,
+            fee_config: None,
+            finality: None,
+            status: None,
+            parent: None,
+            tags: vec![],
+            metadata: None,
+            slug: None,
+            aliases: vec![],
+            testnet: None,
         }
     }
 }
@@ -303,6 +731,16 @@ pub struct PatchNetworkRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub default_signer_address: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub tags: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub slug: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub aliases: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub testnet: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub active: Option<bool>,
 }
 
@@ -321,11 +759,119 @@ pub struct NetworkResponse {
     pub fee_multiplier: f64,
     pub gas_limit_multiplier: f64,
     pub active: bool,
+    pub status: String,
     pub default_signer_address: String,
+    pub parent: Option<serde_json::Value>,
+    pub tags: Vec<String>,
+    pub metadata: serde_json::Value,
+    pub slug: String,
+    pub aliases: Vec<String>,
+    pub testnet: Option<serde_json::Value>,
     pub created_at: String,
     pub updated_at: String,
 }
 
+/// Lifecycle schedule response structure for deserialization
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[allow(dead_code)]
+pub struct ScheduleResponse {
+    pub id: String,
+    pub network_id: String,
+    pub action: String,
+    pub run_at: String,
+    pub status: String,
+    pub scheduled_by: String,
+    pub cancelled_by: Option<String>,
+    pub outcome: Option<String>,
+    pub completed_at: Option<String>,
+}
+
+/// Network contract response structure for deserialization
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[allow(dead_code)]
+pub struct ContractResponse {
+    pub id: String,
+    pub network_id: String,
+    pub name: String,
+    pub address: String,
+    pub deployment_block: Option<i64>,
+    pub abi_ref: Option<String>,
+    pub tags: Vec<String>,
+    /// Only present on cross-network lookups
+    pub chain_id: Option<i32>,
+}
+
+/// Token response structure for deserialization
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[allow(dead_code)]
+pub struct TokenResponse {
+    pub id: String,
+    pub network_id: String,
+    pub address: String,
+    pub symbol: String,
+    pub name: String,
+    pub decimals: u8,
+    #[serde(rename = "logoURI")]
+    pub logo_uri: Option<String>,
+    pub tags: Vec<String>,
+}
+
+/// Signer response structure for deserialization
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[allow(dead_code)]
+pub struct SignerResponse {
+    pub id: String,
+    pub network_id: String,
+    pub address: String,
+    pub role: String,
+    pub priority: i32,
+    pub enabled: bool,
+}
+
+/// Webhook response structure for deserialization
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[allow(dead_code)]
+pub struct WebhookResponse {
+    pub id: String,
+    pub url: String,
+    pub events: Vec<String>,
+    pub network_ids: Vec<String>,
+    pub secret: Option<String>,
+    pub enabled: bool,
+    pub created_by: String,
+}
+
+/// Webhook delivery response structure for deserialization
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[allow(dead_code)]
+pub struct DeliveryResponse {
+    pub id: String,
+    pub webhook_id: String,
+    pub event: String,
+    pub network_id: String,
+    pub status: String,
+    pub attempts: u32,
+    pub delivered_at: Option<String>,
+    pub payload: Option<serde_json::Value>,
+    pub attempt_log: Option<Vec<DeliveryAttemptResponse>>,
+}
+
+/// Webhook delivery attempt structure for deserialization
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[allow(dead_code)]
+pub struct DeliveryAttemptResponse {
+    pub status_code: Option<u16>,
+    pub response_body: Option<String>,
+    pub error: Option<String>,
+}
+
 /// Error response structure for deserialization
 #[derive(Debug, Deserialize)]
 #[allow(dead_code)]
@@ -0,0 +1,292 @@
+//! End-to-end tests for webhook endpoints and deliveries
+//!
+//! These tests run against a real PostgreSQL database and a local receiver.
+//! They live in their own test binary because the receiver listens on a
+//! loopback address, which requires a more permissive URL policy than the
+//! one the network tests check.
+
+mod common;
+
+use axum::{
+    body::Body,
+    http::{header, Method, Request, StatusCode},
+};
+use serde_json::json;
+use tower::util::ServiceExt;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use blockchain_network_registry::domain::models::url_policy::UrlPolicy;
+use blockchain_network_registry::infrastructure::driven_adapters::webhook_sender::{
+    sign_payload, SIGNATURE_HEADER, TIMESTAMP_HEADER,
+};
+use common::{CreateNetworkRequest, DeliveryResponse, ErrorResponse, NetworkResponse, TestApp, WebhookResponse};
+
+const TEST_WEBHOOK_SECRET: &str = "whsec_e2e_test_secret_value";
+
+/// Start the application allowing the loopback receiver, keeping every other
+/// internal address rejected
+async fn spawn_app() -> TestApp {
+    TestApp::with_url_policy(UrlPolicy {
+        require_https: false,
+        allowed_hosts: vec![],
+        allowed_networks: vec!["127.0.0.0/8".parse().unwrap()],
+    })
+    .await
+}
+
+/// Send a JSON request and return the status and body
+async fn send(app: &TestApp, method: Method, uri: &str, body: Option<serde_json::Value>) -> (StatusCode, Vec<u8>) {
+    let body = body.map_or_else(Body::empty, |body| Body::from(body.to_string()));
+    let response = app
+        .router
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method(method)
+                .uri(uri)
+                .header(header::CONTENT_TYPE, "application/json")
+                .header(header::AUTHORIZATION, app.auth_header())
+                .body(body)
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    let status = response.status();
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    (status, body.to_vec())
+}
+
+async fn create_webhook(app: &TestApp, body: serde_json::Value) -> WebhookResponse {
+    let (status, body) = send(app, Method::POST, "/webhooks", Some(body)).await;
+    assert_eq!(status, StatusCode::CREATED);
+    serde_json::from_slice(&body).unwrap()
+}
+
+/// Create a network and relay the change from the outbox, queueing its deliveries
+async fn create_network(app: &TestApp) -> NetworkResponse {
+    let request_body = serde_json::to_value(CreateNetworkRequest::default()).unwrap();
+    let (status, body) = send(app, Method::POST, "/networks", Some(request_body)).await;
+    assert_eq!(status, StatusCode::CREATED);
+    assert_eq!(app.relay_outbox_use_case.execute(10).await.unwrap().len(), 1);
+    serde_json::from_slice(&body).unwrap()
+}
+
+async fn list_deliveries(app: &TestApp, webhook_id: &str) -> Vec<DeliveryResponse> {
+    let (status, body) = send(app, Method::GET, &format!("/webhooks/{webhook_id}/deliveries"), None).await;
+    assert_eq!(status, StatusCode::OK);
+    serde_json::from_slice(&body).unwrap()
+}
+
+// ============================================================================
+// Webhook management
+// ============================================================================
+
+#[tokio::test]
+async fn test_create_webhook_returns_secret_only_once() {
+    let app = spawn_app().await;
+
+    let webhook = create_webhook(&app, json!({ "url": "https://hooks.example.com/networks" })).await;
+    let secret = webhook.secret.expect("secret returned on creation");
+    assert!(secret.starts_with("whsec_"));
+    assert!(webhook.enabled);
+    assert!(webhook.events.is_empty());
+
+    let (status, body) = send(&app, Method::GET, &format!("/webhooks/{}", webhook.id), None).await;
+    assert_eq!(status, StatusCode::OK);
+    let fetched: WebhookResponse = serde_json::from_slice(&body).unwrap();
+    assert!(fetched.secret.is_none());
+
+    let (status, body) = send(&app, Method::GET, "/webhooks", None).await;
+    assert_eq!(status, StatusCode::OK);
+    let webhooks: Vec<WebhookResponse> = serde_json::from_slice(&body).unwrap();
+    assert_eq!(webhooks.len(), 1);
+    assert!(webhooks[0].secret.is_none());
+}
+
+#[tokio::test]
+async fn test_create_webhook_rejects_internal_url() {
+    let app = spawn_app().await;
+
+    let (status, body) = send(
+        &app,
+        Method::POST,
+        "/webhooks",
+        Some(json!({ "url": "http://169.254.169.254/latest/meta-data" })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    let error: ErrorResponse = serde_json::from_slice(&body).unwrap();
+    assert_eq!(error.error.code, "VALIDATION_ERROR");
+}
+
+#[tokio::test]
+async fn test_delete_webhook() {
+    let app = spawn_app().await;
+
+    let webhook = create_webhook(&app, json!({ "url": "https://hooks.example.com/networks" })).await;
+
+    let (status, _) = send(&app, Method::DELETE, &format!("/webhooks/{}", webhook.id), None).await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+
+    let (status, _) = send(&app, Method::GET, &format!("/webhooks/{}", webhook.id), None).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
+// ============================================================================
+// Deliveries
+// ============================================================================
+
+#[tokio::test]
+async fn test_network_change_is_delivered_signed() {
+    let app = spawn_app().await;
+    let receiver = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/hook"))
+        .respond_with(ResponseTemplate::new(200).set_body_string("ok"))
+        .expect(1)
+        .mount(&receiver)
+        .await;
+
+    let webhook = create_webhook(
+        &app,
+        json!({ "url": format!("{}/hook", receiver.uri()), "secret": TEST_WEBHOOK_SECRET }),
+    )
+    .await;
+    let network = create_network(&app).await;
+
+    let attempted = app.deliver_due_webhooks_use_case.execute(10).await.unwrap();
+    assert_eq!(attempted.len(), 1);
+
+    // The receiver got the event, signed with the webhook secret
+    let requests = receiver.received_requests().await.unwrap();
+    let request = &requests[0];
+    let timestamp: i64 = request
+        .headers
+        .get(TIMESTAMP_HEADER)
+        .unwrap()
+        .to_str()
+        .unwrap()
+        .parse()
+        .unwrap();
+    let body = String::from_utf8(request.body.clone()).unwrap();
+    assert_eq!(
+        request.headers.get(SIGNATURE_HEADER).unwrap().to_str().unwrap(),
+        sign_payload(TEST_WEBHOOK_SECRET, timestamp, &body)
+    );
+    let payload: serde_json::Value = serde_json::from_str(&body).unwrap();
+    assert_eq!(payload["type"], "created");
+    assert_eq!(payload["networkId"], network.id);
+
+    // The delivery log records the successful attempt
+    let deliveries = list_deliveries(&app, &webhook.id).await;
+    assert_eq!(deliveries.len(), 1);
+    assert_eq!(deliveries[0].status, "succeeded");
+    assert!(deliveries[0].delivered_at.is_some());
+
+    let (status, body) = send(
+        &app,
+        Method::GET,
+        &format!("/webhooks/{}/deliveries/{}", webhook.id, deliveries[0].id),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let delivery: DeliveryResponse = serde_json::from_slice(&body).unwrap();
+    assert_eq!(delivery.payload.unwrap()["networkId"], network.id);
+    let attempts = delivery.attempt_log.unwrap();
+    assert_eq!(attempts.len(), 1);
+    assert_eq!(attempts[0].status_code, Some(200));
+    assert_eq!(attempts[0].response_body.as_deref(), Some("ok"));
+}
+
+#[tokio::test]
+async fn test_event_filter_skips_other_events() {
+    let app = spawn_app().await;
+
+    let webhook = create_webhook(
+        &app,
+        json!({ "url": "https://hooks.example.com/networks", "events": ["deactivated"] }),
+    )
+    .await;
+    create_network(&app).await;
+
+    assert!(list_deliveries(&app, &webhook.id).await.is_empty());
+}
+
+#[tokio::test]
+async fn test_failed_delivery_is_retried_then_redelivered() {
+    let app = spawn_app().await;
+    let receiver = MockServer::start().await;
+    Mock::given(method("POST"))
+        .respond_with(ResponseTemplate::new(500))
+        .mount(&receiver)
+        .await;
+
+    let webhook = create_webhook(&app, json!({ "url": format!("{}/hook", receiver.uri()) })).await;
+    create_network(&app).await;
+
+    // The test retry policy allows 3 attempts, due immediately
+    for _ in 0..3 {
+        assert_eq!(app.deliver_due_webhooks_use_case.execute(10).await.unwrap().len(), 1);
+    }
+    assert!(app.deliver_due_webhooks_use_case.execute(10).await.unwrap().is_empty());
+
+    let deliveries = list_deliveries(&app, &webhook.id).await;
+    assert_eq!(deliveries[0].status, "failed");
+    assert_eq!(deliveries[0].attempts, 3);
+
+    // Redeliver once the receiver recovers, keeping the delivery ID
+    receiver.reset().await;
+    Mock::given(method("POST"))
+        .respond_with(ResponseTemplate::new(204))
+        .mount(&receiver)
+        .await;
+
+    let redeliver_uri = format!("/webhooks/{}/deliveries/{}/redeliver", webhook.id, deliveries[0].id);
+    let (status, body) = send(&app, Method::POST, &redeliver_uri, None).await;
+    assert_eq!(status, StatusCode::ACCEPTED);
+    let delivery: DeliveryResponse = serde_json::from_slice(&body).unwrap();
+    assert_eq!(delivery.id, deliveries[0].id);
+    assert_eq!(delivery.status, "pending");
+
+    // A pending delivery cannot be redelivered again
+    let (status, _) = send(&app, Method::POST, &redeliver_uri, None).await;
+    assert_eq!(status, StatusCode::CONFLICT);
+
+    assert_eq!(app.deliver_due_webhooks_use_case.execute(10).await.unwrap().len(), 1);
+
+    let (status, body) = send(
+        &app,
+        Method::GET,
+        &format!("/webhooks/{}/deliveries/{}", webhook.id, deliveries[0].id),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let delivery: DeliveryResponse = serde_json::from_slice(&body).unwrap();
+    assert_eq!(delivery.status, "succeeded");
+    let attempts = delivery.attempt_log.unwrap();
+    assert_eq!(attempts.len(), 4);
+    assert_eq!(attempts.iter().filter(|a| a.status_code == Some(500)).count(), 3);
+}
+
+#[tokio::test]
+async fn test_event_relayed_twice_is_delivered_once() {
+    let app = spawn_app().await;
+
+    let webhook = create_webhook(&app, json!({ "url": "https://hooks.example.com/networks" })).await;
+    create_network(&app).await;
+
+    // Relaying the same outbox message again, as after a crash, queues nothing new
+    sqlx::query("UPDATE outbox SET published_at = NULL")
+        .execute(&app.pool)
+        .await
+        .unwrap();
+    assert_eq!(app.relay_outbox_use_case.execute(10).await.unwrap().len(), 1);
+
+    let deliveries = list_deliveries(&app, &webhook.id).await;
+    assert_eq!(deliveries.len(), 1);
+    assert_eq!(deliveries[0].status, "pending");
+}